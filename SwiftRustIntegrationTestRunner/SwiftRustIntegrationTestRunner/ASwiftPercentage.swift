@@ -0,0 +1,23 @@
+//
+//  ASwiftPercentage.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+/// A class with a failable, labeled initializer that Rust calls as `ASwiftPercentage::new(...)`.
+/// See crates/swift-integration-tests/src/import_opaque_swift_class.rs
+public class ASwiftPercentage {
+    private let percentage: UInt8
+
+    init?(validating value: UInt8) {
+        if value > 100 {
+            return nil
+        }
+        self.percentage = value
+    }
+
+    func value() -> UInt8 {
+        percentage
+    }
+}
@@ -0,0 +1,24 @@
+//
+//  Actor.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+actor SwiftCounterActor {
+    private var count: UInt32
+
+    init(start: UInt32) {
+        count = start
+    }
+
+    func increment(by: UInt32) -> UInt32 {
+        count += by
+        return count
+    }
+
+    func slow_count() async -> UInt32 {
+        await Task.yield()
+        return count
+    }
+}
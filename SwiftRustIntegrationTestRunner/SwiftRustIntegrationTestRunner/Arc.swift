@@ -0,0 +1,10 @@
+//
+//  Arc.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_reflect_arc_test_type(arg: ArcTestTypeArc) -> ArcTestTypeArc {
+    arg
+}
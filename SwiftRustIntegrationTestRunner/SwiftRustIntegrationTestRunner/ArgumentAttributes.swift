@@ -0,0 +1,10 @@
+//
+//  ArgumentAttributes.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_argument_label(_ someArg: Int32, to anotherArg: Int32) -> Int32 {
+    someArg + anotherArg
+}
@@ -0,0 +1,20 @@
+//
+//  AsyncFunction.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_async_return_null() async {
+    await Task.yield()
+}
+
+func swift_async_reflect_u32(arg: UInt32) async -> UInt32 {
+    await Task.yield()
+    return arg
+}
+
+func swift_async_reflect_string(arg: RustString) async -> RustString {
+    await Task.yield()
+    return arg
+}
@@ -0,0 +1,14 @@
+//
+//  CoreGraphics.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import CoreGraphics
+
+func swift_reflect_cg_rect(rect: CGRect) -> CGRect {
+    rect
+}
+
+func swift_cg_rect_union(a: CGRect, b: CGRect) -> CGRect {
+    a.union(b)
+}
@@ -0,0 +1,10 @@
+//
+//  Decimal.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_reflect_decimal(decimal: Decimal) -> Decimal {
+    decimal
+}
@@ -0,0 +1,23 @@
+//
+//  DispatchQueue.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+private let testQueueNameKey = DispatchSpecificKey<String>()
+
+enum TestQueues {
+    static let swiftQueue = makeQueue(name: "swiftQueue")
+    static let rustQueue = makeQueue(name: "rustQueue")
+
+    private static func makeQueue(name: String) -> DispatchQueue {
+        let queue = DispatchQueue(label: "swift-bridge.integration-tests.\(name)")
+        queue.setSpecific(key: testQueueNameKey, value: name)
+        return queue
+    }
+}
+
+func swift_current_queue() -> String {
+    DispatchQueue.getSpecific(key: testQueueNameKey) ?? "none"
+}
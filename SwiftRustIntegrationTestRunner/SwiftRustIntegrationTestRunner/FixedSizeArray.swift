@@ -0,0 +1,14 @@
+//
+//  FixedSizeArray.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_reflect_u8_array(arr: (UInt8, UInt8, UInt8, UInt8)) -> (UInt8, UInt8, UInt8, UInt8) {
+    arr
+}
+
+func swift_reverse_bool_array(arr: (Bool, Bool, Bool)) -> (Bool, Bool, Bool) {
+    (arr.2, arr.1, arr.0)
+}
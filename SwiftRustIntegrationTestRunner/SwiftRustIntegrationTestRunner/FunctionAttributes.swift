@@ -10,3 +10,25 @@ import Foundation
 func testCallSwiftFromRustByNameAttribute() -> RustString {
     return "StringFromSwift".intoRustString()
 }
+
+func testCallSwiftFromRustByRustNameAttribute() -> UInt8 {
+    return 123
+}
+
+enum CatchErrorsTestError: Error {
+    case emptyName
+}
+
+func testCatchErrorsReverseName(name: RustString) throws -> String {
+    let name = name.toString()
+    if name.isEmpty {
+        throw CatchErrorsTestError.emptyName
+    }
+    return String(name.reversed())
+}
+
+func testCatchErrorsRequireName(name: RustString) throws {
+    if name.toString().isEmpty {
+        throw CatchErrorsTestError.emptyName
+    }
+}
@@ -0,0 +1,17 @@
+//
+//  MainActor.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+@MainActor
+func swift_main_actor_is_main_thread() -> Bool {
+    Thread.isMainThread
+}
+
+@MainActor
+func swift_main_actor_is_main_thread_async() async -> Bool {
+    await Task.yield()
+    return Thread.isMainThread
+}
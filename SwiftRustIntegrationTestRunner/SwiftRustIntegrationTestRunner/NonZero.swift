@@ -0,0 +1,10 @@
+//
+//  NonZero.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_reflect_non_zero_u8(num: UInt8) -> UInt8 {
+    num
+}
@@ -0,0 +1,18 @@
+//
+//  NsError.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_read_file(path: RustString) throws -> String {
+    try String(contentsOfFile: path.toString())
+}
+
+func swift_throw_ns_error() throws {
+    throw NSError(
+        domain: "com.example.integration",
+        code: 7,
+        userInfo: [NSLocalizedDescriptionKey: "Something went wrong", "answer": 42]
+    )
+}
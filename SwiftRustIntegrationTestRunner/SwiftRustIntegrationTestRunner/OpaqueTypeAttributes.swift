@@ -0,0 +1,19 @@
+//
+//  OpaqueTypeAttributes.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+class AlreadyDeclaredSwiftTypeTest {
+    func a_swift_ref_method() -> Bool {
+        true
+    }
+}
+
+/// Called with whether or not a `DropOnMainType` was dropped on the main thread.
+var onDropOnMainTypeDropped: (Bool) -> () = { _ in }
+
+func drop_on_main_type_dropped() {
+    onDropOnMainTypeDropped(Thread.isMainThread)
+}
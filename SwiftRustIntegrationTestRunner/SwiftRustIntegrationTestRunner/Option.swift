@@ -47,3 +47,10 @@ func swift_reflect_option_bool(arg: Optional<Bool>) -> Optional<Bool> {
     arg
 }
 
+func swift_reflect_option_str(arg: Optional<RustStr>) -> Optional<RustStr> {
+    arg
+}
+func swift_reflect_option_ref_opaque_rust_type(arg: Optional<OptTestOpaqueRustTypeRef>) -> Optional<OptTestOpaqueRustTypeRef> {
+    arg
+}
+
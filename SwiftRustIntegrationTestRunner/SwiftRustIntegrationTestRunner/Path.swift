@@ -0,0 +1,14 @@
+//
+//  Path.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_reflect_path_buf(path: String) -> String {
+    path
+}
+
+func swift_append_path_component(path: String, component: RustStr) -> String {
+    (path as NSString).appendingPathComponent(component.toString())
+}
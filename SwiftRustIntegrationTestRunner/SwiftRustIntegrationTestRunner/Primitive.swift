@@ -52,6 +52,14 @@ func swift_negate_bool(arg: Bool) -> Bool {
     !arg
 }
 
+func swift_reflect_u128(arg: RustUInt128) -> RustUInt128 {
+    arg
+}
+
+func swift_reflect_i128(arg: RustInt128) -> RustInt128 {
+    arg
+}
+
 func swift_reflect_null(arg: ()) -> () {
     arg
 }
@@ -40,3 +40,6 @@ extension SameEnum: Error {}
 
 extension AsyncResultErrEnum: @unchecked Sendable {}
 extension AsyncResultErrEnum: Error {}
+
+extension RustStr: @unchecked Sendable {}
+extension RustStr: Error {}
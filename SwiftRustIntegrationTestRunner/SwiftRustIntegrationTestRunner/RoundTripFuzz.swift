@@ -0,0 +1,134 @@
+//
+//  RoundTripFuzz.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+// Values that are converted on their way to Swift are converted back again instead of being
+// handed back as they are, so that both directions of the conversion get exercised.
+
+func fuzz_swift_reflect_u8(arg: UInt8) -> UInt8 {
+    arg
+}
+
+func fuzz_swift_reflect_i8(arg: Int8) -> Int8 {
+    arg
+}
+
+func fuzz_swift_reflect_u16(arg: UInt16) -> UInt16 {
+    arg
+}
+
+func fuzz_swift_reflect_i16(arg: Int16) -> Int16 {
+    arg
+}
+
+func fuzz_swift_reflect_u32(arg: UInt32) -> UInt32 {
+    arg
+}
+
+func fuzz_swift_reflect_i32(arg: Int32) -> Int32 {
+    arg
+}
+
+func fuzz_swift_reflect_u64(arg: UInt64) -> UInt64 {
+    arg
+}
+
+func fuzz_swift_reflect_i64(arg: Int64) -> Int64 {
+    arg
+}
+
+func fuzz_swift_reflect_usize(arg: UInt) -> UInt {
+    arg
+}
+
+func fuzz_swift_reflect_isize(arg: Int) -> Int {
+    arg
+}
+
+func fuzz_swift_reflect_u128(arg: RustUInt128) -> RustUInt128 {
+    RustUInt128(low: arg.low, high: arg.high)
+}
+
+func fuzz_swift_reflect_i128(arg: RustInt128) -> RustInt128 {
+    RustInt128(low: arg.low, high: arg.high)
+}
+
+func fuzz_swift_reflect_f32(arg: Float) -> Float {
+    arg
+}
+
+func fuzz_swift_reflect_f64(arg: Double) -> Double {
+    arg
+}
+
+func fuzz_swift_reflect_bool(arg: Bool) -> Bool {
+    arg
+}
+
+func fuzz_swift_reflect_string(arg: RustString) -> String {
+    arg.toString()
+}
+
+func fuzz_swift_reflect_vec_u8(arg: RustVec<UInt8>) -> RustVec<UInt8> {
+    let vec = RustVec<UInt8>()
+    for value in Array(arg) {
+        vec.push(value: value)
+    }
+    return vec
+}
+
+func fuzz_swift_reflect_vec_i64(arg: RustVec<Int64>) -> RustVec<Int64> {
+    let vec = RustVec<Int64>()
+    for value in Array(arg) {
+        vec.push(value: value)
+    }
+    return vec
+}
+
+func fuzz_swift_reflect_option_u32(arg: Optional<UInt32>) -> Optional<UInt32> {
+    arg
+}
+
+func fuzz_swift_reflect_option_f64(arg: Optional<Double>) -> Optional<Double> {
+    arg
+}
+
+func fuzz_swift_reflect_uuid(arg: UUID) -> UUID {
+    UUID(uuid: arg.uuid)
+}
+
+func fuzz_swift_reflect_duration(arg: TimeInterval) -> TimeInterval {
+    arg
+}
+
+func fuzz_swift_reflect_u8_array(
+    arg: (UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8)
+) -> (UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8) {
+    arg
+}
+
+func fuzz_swift_reflect_f64_array(arg: (Double, Double, Double)) -> (Double, Double, Double) {
+    arg
+}
+
+func fuzz_swift_reflect_path_buf(arg: String) -> String {
+    arg
+}
+
+func fuzz_swift_reflect_cow_str(arg: RustCowStr) -> String {
+    arg.toString()
+}
+
+func fuzz_swift_reflect_arc(arg: RoundTripFuzzValueArc) -> RoundTripFuzzValueArc {
+    arg
+}
+
+func fuzz_swift_reflect_result_string(arg: RustString, code: Int) throws -> String {
+    if code == 0 {
+        return arg.toString()
+    }
+    throw NSError(domain: "RoundTripFuzz", code: code, userInfo: ["value": arg.toString()])
+}
@@ -0,0 +1,10 @@
+//
+//  SharedEnum.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_reflect_enum_with_no_data(arg: EnumWithNoData) -> EnumWithNoData {
+    arg
+}
@@ -0,0 +1,10 @@
+//
+//  Slice.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_sum_slice_of_opaque_rust_type(slice: RustSlice<SliceTestOpaqueRustType>) -> Int32 {
+    slice.reduce(0) { $0 + $1.val() }
+}
@@ -10,3 +10,7 @@ import Foundation
 func increment_some_owned_opaque_rust_type(arg: SomeRustType, amount: UInt32) {
     arg.increment_counter(amount)
 }
+
+func increment_some_mut_opaque_rust_type(arg: SomeRustTypeRefMut, amount: UInt32) {
+    arg.increment_counter(amount)
+}
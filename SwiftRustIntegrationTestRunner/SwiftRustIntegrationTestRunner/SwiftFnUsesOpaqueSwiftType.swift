@@ -17,6 +17,10 @@ class ASwiftType {
     func amount() -> UInt32 {
         self._amount
     }
+
+    func set_amount(amount: UInt32) {
+        self._amount = amount
+    }
     
     func call_swift_method_reflects_owned_opaque_swift_type(arg: ASwiftType) -> ASwiftType {
         arg
@@ -27,3 +31,11 @@ func call_swift_fn_reflects_owned_opaque_swift_type(arg: ASwiftType) -> ASwiftTy
     arg
 }
 
+func call_swift_fn_increments_mut_opaque_swift_type(arg: ASwiftType, amount: UInt32) {
+    arg.set_amount(amount: arg.amount() + amount)
+}
+
+func call_swift_fn_reads_ref_opaque_swift_type(arg: ASwiftType) -> UInt32 {
+    arg.amount()
+}
+
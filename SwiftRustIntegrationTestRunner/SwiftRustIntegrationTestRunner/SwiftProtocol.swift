@@ -0,0 +1,42 @@
+//
+//  SwiftProtocol.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+/// We expose this protocol to the `swift_protocol.rs` test.
+public protocol SwiftProtocolTestCounter {
+    func add(amount: UInt32)
+    func total() -> UInt32
+}
+
+/// A class that conforms to `SwiftProtocolTestCounter`.
+public class SwiftProtocolTestClassCounter: SwiftProtocolTestCounter {
+    var count: UInt32 = 0
+
+    public init() {}
+
+    public func add(amount: UInt32) {
+        count += amount
+    }
+
+    public func total() -> UInt32 {
+        count
+    }
+}
+
+/// A struct that conforms to `SwiftProtocolTestCounter` and doubles every amount that it is given.
+public struct SwiftProtocolTestDoublingCounter: SwiftProtocolTestCounter {
+    let inner = SwiftProtocolTestClassCounter()
+
+    public init() {}
+
+    public func add(amount: UInt32) {
+        inner.add(amount: amount * 2)
+    }
+
+    public func total() -> UInt32 {
+        inner.total()
+    }
+}
@@ -0,0 +1,14 @@
+//
+//  Time.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_reflect_duration(duration: TimeInterval) -> TimeInterval {
+    duration
+}
+
+func swift_reflect_system_time(time: Date) -> Date {
+    time
+}
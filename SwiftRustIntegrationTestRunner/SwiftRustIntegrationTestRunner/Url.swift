@@ -0,0 +1,10 @@
+//
+//  Url.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_reflect_url(url: URL) -> URL {
+    url
+}
@@ -0,0 +1,10 @@
+//
+//  Uuid.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_reflect_uuid(uuid: UUID) -> UUID {
+    uuid
+}
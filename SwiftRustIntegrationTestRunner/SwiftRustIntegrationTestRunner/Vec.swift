@@ -15,3 +15,10 @@ func swift_return_vec_u8() -> RustVec<UInt8> {
     }
     return vec
 }
+
+func swift_return_vec_string() -> RustVec<RustString> {
+    let vec = RustVec<RustString>()
+    vec.push(value: "hello".intoRustString())
+    vec.push(value: "world".intoRustString())
+    return vec
+}
@@ -0,0 +1,27 @@
+//
+//  ActorTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for `#[swift_bridge(actor)]` Swift types, whose methods Rust awaits.
+class ActorTests: XCTestCase {
+    /// Verify that Rust can call an actor's methods from many threads at once without losing any
+    /// of the updates to its state.
+    func testRustIncrementsActorConcurrently() async throws {
+        let counter = SwiftCounterActor(start: 5)
+
+        let count = await rust_increment_swift_actor_concurrently(counter, 100)
+
+        XCTAssertEqual(count, 105)
+    }
+
+    /// Verify that Rust can create an actor and call its methods.
+    func testRustCreatesActor() async throws {
+        let count = await rust_create_and_increment_swift_actor(10, 3)
+
+        XCTAssertEqual(count, 13)
+    }
+}
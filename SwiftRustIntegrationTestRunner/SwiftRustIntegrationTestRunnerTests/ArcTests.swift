@@ -0,0 +1,51 @@
+//
+//  ArcTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `Arc<T>`.
+class ArcTests: XCTestCase {
+
+    /// Verify that we can call `&self` methods on an `Arc<T>` that Rust returned.
+    func testRustReturnsArc() throws {
+        let arc = rust_make_arc_test_type(123)
+        XCTAssertEqual(arc.value(), 123)
+    }
+
+    /// Verify that passing an `Arc<T>` to Rust gives Rust its own strong reference while Swift
+    /// keeps the one that it holds.
+    func testSwiftPassesArcToRust() throws {
+        let arc = rust_make_arc_test_type(5)
+
+        XCTAssertEqual(rust_arc_test_type_strong_count(arc), 2)
+
+        let reflected = rust_reflect_arc_test_type(arc)
+        XCTAssertEqual(reflected.value(), 5)
+        XCTAssertEqual(arc.value(), 5)
+    }
+
+    /// Verify that the Rust value lives until both Swift and Rust have released their references.
+    func testArcIsDroppedOnceAllReferencesAreReleased() throws {
+        XCTAssertEqual(arc_test_type_live_count(), 0)
+
+        do {
+            let arc = rust_make_arc_test_type(1)
+            rust_store_arc_test_type(arc)
+            XCTAssertEqual(arc_test_type_live_count(), 1)
+        }
+
+        // Swift released its reference, but Rust is still holding on to one.
+        XCTAssertEqual(arc_test_type_live_count(), 1)
+
+        rust_drop_stored_arc_test_type()
+        XCTAssertEqual(arc_test_type_live_count(), 0)
+    }
+
+    /// Verify that Rust can pass an `Arc<T>` to Swift and get it back.
+    func testRustCallsSwiftFnReflectsArc() throws {
+        test_rust_calls_swift_fn_reflects_arc()
+    }
+}
@@ -18,4 +18,9 @@ final class ArgumentAttributesTest: XCTestCase {
         XCTAssertEqual(test_argument_label(someArg: 10, 100), 110)
     }
 
+    /// Verify that Rust calls an extern "Swift" function using its argument labels.
+    func testExternSwiftArgumentLabel() throws {
+        XCTAssertEqual(test_extern_swift_argument_label(), 110)
+    }
+
 }
@@ -0,0 +1,87 @@
+//
+//  AsyncSequenceTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+extension AsyncSequenceTestError: Error {}
+
+/// Tests for Rust functions that return a `Stream`, which is seen as an `AsyncSequence`.
+class AsyncSequenceTests: XCTestCase {
+    /// Verify that we iterate over every item of a Rust stream, and that the loop ends when the
+    /// stream ends.
+    func testIterateStreamItems() async throws {
+        var numbers: [UInt32] = []
+        for await number in rust_stream_count_to(5) {
+            numbers.append(number)
+        }
+
+        XCTAssertEqual(numbers, [1, 2, 3, 4, 5])
+    }
+
+    /// Verify that we can receive owned Rust values, such as `String`s.
+    func testIterateStrings() async throws {
+        var words: [String] = []
+        for await word in rust_stream_words("hello from rust") {
+            words.append(word.toString())
+        }
+
+        XCTAssertEqual(words, ["hello", "from", "rust"])
+    }
+
+    /// Verify that a stream of `Result`s throws its first error.
+    func testStreamThrowsFirstError() async throws {
+        var numbers: [UInt32] = []
+        do {
+            for try await number in rust_stream_count_to_then_fail(3) {
+                numbers.append(number)
+            }
+            XCTFail("The stream should have thrown")
+        } catch let error as AsyncSequenceTestError {
+            XCTAssertEqual(error.number(), 4)
+        }
+
+        XCTAssertEqual(numbers, [1, 2, 3])
+    }
+
+    /// Verify that the Rust stream gets dropped when we leave the loop early.
+    func testBreakDropsStream() async throws {
+        let droppedBefore = rust_stream_dropped_count()
+
+        for await number in rust_stream_count_forever() {
+            if number == 3 {
+                break
+            }
+        }
+
+        await waitForDroppedCount(droppedBefore + 1)
+    }
+
+    /// Verify that the Rust stream gets dropped when the task that iterates over it is cancelled.
+    func testCancelTaskDropsStream() async throws {
+        let droppedBefore = rust_stream_dropped_count()
+
+        let task = Task {
+            var count = 0
+            for await _ in rust_stream_count_forever() {
+                count += 1
+                await Task.yield()
+            }
+            return count
+        }
+        task.cancel()
+        _ = await task.value
+
+        await waitForDroppedCount(droppedBefore + 1)
+    }
+
+    /// Wait for Rust to drop the streams, which happens on the async runtime's threads.
+    private func waitForDroppedCount(_ expected: UInt32) async {
+        for _ in 0..<100 where rust_stream_dropped_count() < expected {
+            try? await Task.sleep(nanoseconds: 10_000_000)
+        }
+        XCTAssertEqual(rust_stream_dropped_count(), expected)
+    }
+}
@@ -32,6 +32,73 @@ class AsyncTests: XCTestCase {
         XCTAssertEqual(string.toString(), "hello world")
     }
     
+    /// Verify that we can pass and return an Option<u64> to an async Rust function
+    func testSwiftCallsRustAsyncFnReflectOptionU64() async throws {
+        let some = await rust_async_reflect_option_u64(123)
+        XCTAssertEqual(some, 123)
+
+        let none = await rust_async_reflect_option_u64(nil)
+        XCTAssertNil(none)
+    }
+
+    /// Verify that we can pass and return an Option<String> to an async Rust function
+    func testSwiftCallsRustAsyncFnReflectOptionString() async throws {
+        let some = await rust_async_reflect_option_string("hello")
+        XCTAssertEqual(some!.toString(), "hello")
+
+        let noneString: String? = nil
+        let none = await rust_async_reflect_option_string(noneString)
+        XCTAssertNil(none)
+    }
+
+    /// Verify that we can pass and return a Vec<u8> to an async Rust function
+    func testSwiftCallsRustAsyncFnReflectVecU8() async throws {
+        let vec = RustVec<UInt8>()
+        vec.push(value: 5)
+        vec.push(value: 6)
+
+        let reflected = await rust_async_reflect_vec_u8(vec)
+        XCTAssertEqual(reflected.len(), 2)
+        XCTAssertEqual(reflected.get(index: 1), 6)
+    }
+
+    /// Verify that we can return an opaque Rust type from an async Rust function
+    func testSwiftCallsRustAsyncFnReturnOpaqueRust() async throws {
+        let opaque = await rust_async_return_opaque_rust(123)
+        XCTAssertEqual(opaque.val(), 123)
+    }
+
+    /// Verify that we can return an enum with data from an async Rust function
+    func testSwiftCallsRustAsyncFnReturnEnum() async throws {
+        switch await rust_async_return_enum(true) {
+        case .WithData(let field):
+            XCTAssertEqual(field, 1.5)
+        case .NoData:
+            XCTFail()
+        }
+
+        switch await rust_async_return_enum(false) {
+        case .WithData(_):
+            XCTFail()
+        case .NoData:
+            break
+        }
+    }
+
+    /// Verify that we can pass and return a tuple to an async Rust function
+    func testSwiftCallsRustAsyncFnReflectTuple() async throws {
+        let tuple = await rust_async_reflect_tuple((-123, "hello"))
+        XCTAssertEqual(tuple.0, -123)
+        XCTAssertEqual(tuple.1.toString(), "hello")
+    }
+
+    /// Verify that Rust can call and await async Swift functions.
+    func testRustCallsSwiftAsyncFunctions() async throws {
+        let tuple = await rust_awaits_swift_async_functions(123, "hello")
+        XCTAssertEqual(tuple.0, 123)
+        XCTAssertEqual(tuple.1.toString(), "hello")
+    }
+
     /// Verify that we can call async Rust methods
     func testSwiftCallsRustAsyncMethodReflectU16() async throws {
         let test = TestRustAsyncSelf()
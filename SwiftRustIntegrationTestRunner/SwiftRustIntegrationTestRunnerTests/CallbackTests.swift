@@ -14,4 +14,78 @@ class CallbackTests: XCTestCase {
     func testRustCallsSwift() throws {
         test_callbacks_rust_calls_swift()
     }
+    
+    /// Verify that we can pass Swift closures to Rust functions that take boxed functions.
+    func testSwiftPassesClosuresToRust() throws {
+        var callCount = 0
+        rust_takes_callback_fn_no_args_no_return({ callCount += 1 })
+        XCTAssertEqual(callCount, 2)
+        
+        rust_takes_callback_fn_primitive({ num in num * 2 })
+        
+        let greeting = rust_takes_callback_fn_string({ hello in
+            (hello.toString() + " world").intoRustString()
+        })
+        XCTAssertEqual(greeting.toString(), "hello world")
+        
+        rust_takes_callback_fnonce_opaque_rust({ opaque in
+            opaque.double()
+            return opaque
+        })
+        
+        rust_takes_callback_fn_two_params({ num1, num2 in UInt32(Int32(num1) + Int32(num2)) })
+    }
+    
+    /// Verify that Rust can hold onto a Swift closure, call it multiple times and then release it.
+    func testRustStoresSwiftClosure() throws {
+        weak var weakCounter: CallCounter?
+        
+        do {
+            let counter = CallCounter()
+            weakCounter = counter
+            
+            rust_stores_callback({ num in
+                counter.count += 1
+                return num * 2
+            })
+        }
+        
+        XCTAssertEqual(rust_calls_stored_callback(5), 10)
+        XCTAssertEqual(rust_calls_stored_callback(6), 12)
+        XCTAssertEqual(weakCounter!.count, 2)
+        
+        rust_drops_stored_callback()
+        XCTAssertNil(weakCounter)
+    }
+    
+    /// Verify that a Swift closure can call a `return_scratch` function while a
+    /// `return_scratch` function is calling it.
+    func testCallbackCallsReturnScratchFunction() {
+        let value = rust_writes_scratch_with_callback({
+            let greeting = format_greeting("inner")
+            return greeting.intoRustString()
+        })
+        
+        XCTAssertEqual(value, "outer Hello, inner")
+        XCTAssertEqual(format_greeting("after"), "Hello, after")
+    }
+    
+    /// Verify that Swift closures and Rust functions can call each other in a nested chain,
+    /// Swift -> Rust -> Swift -> Rust -> ...
+    func testNestedCallbacks() {
+        func callRust(_ depth: UInt32) -> UInt32 {
+            rust_calls_nested_callback(depth, { depth in
+                if depth == 0 {
+                    return 0
+                }
+                return callRust(depth - 1) + 1
+            })
+        }
+        
+        XCTAssertEqual(callRust(5), 5)
+    }
+}
+
+private class CallCounter {
+    var count = 0
 }
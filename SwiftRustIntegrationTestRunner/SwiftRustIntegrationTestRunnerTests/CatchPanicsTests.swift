@@ -0,0 +1,40 @@
+//
+//  CatchPanicsTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for the `#[swift_bridge::bridge(catch_panics = "throw")]` attribute.
+class CatchPanicsTests: XCTestCase {
+    /// Verify that functions that don't panic return their values.
+    func testNoPanic() throws {
+        let counter = try PanickingCounter(1)
+
+        XCTAssertEqual(try counter.increment(2), 3)
+        XCTAssertEqual(try counter.name("a").toString(), "counter-a")
+    }
+
+    /// Verify that a Rust panic is thrown as a `RustPanic` that holds the panic message.
+    func testPanicIsThrown() throws {
+        let counter = try PanickingCounter(UInt32.max)
+
+        do {
+            let _ = try counter.increment(1)
+            XCTFail("The function should have thrown.")
+        } catch let error as RustPanic {
+            XCTAssertEqual(error.message, "count overflowed")
+        }
+
+        do {
+            let _ = try counter.name("")
+            XCTFail("The function should have thrown.")
+        } catch let error as RustPanic {
+            XCTAssertEqual(error.message, "suffix must not be empty, count is \(UInt32.max)")
+        }
+
+        // The panic message is taken, so the next call succeeds.
+        XCTAssertEqual(try counter.name("b").toString(), "counter-b")
+    }
+}
@@ -25,4 +25,11 @@ class ConditionalCompilationTests: XCTestCase {
     func testConditionalCompilation() throws {
         XCTAssertEqual(conditionally_exposed_fn(), 123)
     }
+
+    /// Call a function that has a `#[cfg(...)]` attribute that holds for the Rust crate.
+    /// Functions and types whose `#[cfg(...)]` attributes don't hold don't get generated, so
+    /// they can't be referenced here.
+    func testConditionalCompilationOfItems() throws {
+        XCTAssertEqual(conditionally_exposed_item_fn(), 45)
+    }
 }
@@ -0,0 +1,31 @@
+//
+//  CoreGraphicsTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for `CGFloat`, `CGPoint`, `CGSize` and `CGRect`.
+class CoreGraphicsTests: XCTestCase {
+    /// Verify that we can pass and return CoreGraphics types.
+    func testSwiftCallsRustCoreGraphics() throws {
+        let rect = CGRect(x: 10, y: 20, width: 30, height: 40)
+        XCTAssertEqual(rust_cg_rect_center(rect), CGPoint(x: rect.midX, y: rect.midY))
+
+        XCTAssertEqual(
+            rust_scale_cg_size(CGSize(width: 1.5, height: 2), 2),
+            CGSize(width: 3, height: 4)
+        )
+    }
+
+    /// Verify that Rust's `CGFloat` has the same size as Swift's.
+    func testCgFloatSize() throws {
+        XCTAssertEqual(rust_cg_float_is_double(), CGFloat.NativeType.self == Double.self)
+    }
+
+    /// Verify that Rust can call Swift functions that accept and return CoreGraphics types.
+    func testRustCallsSwiftCoreGraphics() throws {
+        test_rust_calls_swift_core_graphics()
+    }
+}
@@ -0,0 +1,21 @@
+//
+//  CowStrTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for returning a `Cow<'static, str>` as a `RustCowStr`.
+class CowStrTests: XCTestCase {
+    /// Verify that a borrowed `Cow` is seen as a `RustStr` and an owned `Cow` as a `RustString`.
+    func testSwiftCallsRustCowStr() throws {
+        let borrowed = rust_return_cow_str(false)
+        XCTAssertFalse(borrowed.isOwned)
+        XCTAssertEqual(borrowed.toString(), "borrowed cow")
+
+        let owned = rust_return_cow_str(true)
+        XCTAssertTrue(owned.isOwned)
+        XCTAssertEqual(owned.toString(), "owned cow")
+    }
+}
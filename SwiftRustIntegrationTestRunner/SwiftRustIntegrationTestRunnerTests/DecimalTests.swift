@@ -0,0 +1,37 @@
+//
+//  DecimalTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for `rust_decimal::Decimal` <-> `Decimal`.
+class DecimalTests: XCTestCase {
+    /// Verify that we can pass and return a `Decimal`.
+    func testSwiftCallsRustDecimal() throws {
+        let decimal = Decimal(string: "-1234.5678")!
+        XCTAssertEqual(rust_reflect_decimal(decimal), decimal)
+    }
+
+    /// Verify that values that a `Double` can't represent exactly keep all of their digits.
+    func testDecimalPrecision() throws {
+        let decimal = Decimal(string: "0.1")! + Decimal(string: "0.2")!
+        XCTAssertEqual(rust_decimal_to_string(decimal).toString(), "0.3")
+        XCTAssertEqual(
+            rust_decimal_to_string(Decimal(string: "79228162514264337593543950335")!).toString(),
+            "79228162514264337593543950335"
+        )
+    }
+
+    /// Verify that a `Decimal`'s scale and sign are preserved.
+    func testDecimalScaleAndSign() throws {
+        let decimal = Decimal(sign: .minus, exponent: -2, significand: 150)
+        XCTAssertEqual(rust_decimal_to_string(decimal).toString(), "-1.50")
+    }
+
+    /// Verify that Rust can call Swift functions that accept and return Decimals.
+    func testRustCallsSwiftDecimal() throws {
+        test_rust_calls_swift_decimal()
+    }
+}
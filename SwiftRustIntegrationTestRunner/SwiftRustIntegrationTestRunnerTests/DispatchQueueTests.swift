@@ -0,0 +1,30 @@
+//
+//  DispatchQueueTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for `#[swift_bridge(dispatch_queue = "...")]` functions, which get called on their queue.
+class DispatchQueueTests: XCTestCase {
+    /// Verify that Swift calls a `dispatch_queue` Rust function on its queue.
+    func testSwiftCallsRustFnOnQueue() throws {
+        XCTAssertEqual(rust_fn_on_queue_current_queue().toString(), "rustQueue")
+    }
+
+    /// Verify that Rust calls a `dispatch_queue` Swift function on its queue.
+    func testRustCallsSwiftFnOnQueue() throws {
+        XCTAssertEqual(rust_calls_swift_fn_on_queue().toString(), "swiftQueue")
+    }
+
+    /// Verify that a call that is already running on its queue runs right away instead of
+    /// deadlocking while it waits for the queue.
+    func testCallFromItsOwnQueue() throws {
+        let queue = TestQueues.rustQueue.sync {
+            rust_fn_on_queue_current_queue().toString()
+        }
+
+        XCTAssertEqual(queue, "rustQueue")
+    }
+}
@@ -0,0 +1,39 @@
+//
+//  FixedSizeArrayTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for fixed size arrays such as `[u8; 32]`.
+class FixedSizeArrayTests: XCTestCase {
+    /// Verify that we can pass and return fixed size arrays of primitives.
+    func testSwiftCallsRustFixedSizeArrays() throws {
+        let bytes = rust_reflect_u8_array((1, 2, 3, 255))
+        XCTAssertEqual(bytes.0, 1)
+        XCTAssertEqual(bytes.1, 2)
+        XCTAssertEqual(bytes.2, 3)
+        XCTAssertEqual(bytes.3, 255)
+
+        let floats = rust_reflect_f64_array((1.5, -2.5, 3.0))
+        XCTAssertEqual(floats.0, 1.5)
+        XCTAssertEqual(floats.1, -2.5)
+        XCTAssertEqual(floats.2, 3.0)
+
+        XCTAssertEqual(rust_sum_i32_array((1, 2, 3, 4, -5)), 5)
+    }
+
+    /// Verify that a 32 byte array, such as a hash, can be copied into a Swift `[UInt8]`.
+    func testReturn32ByteArray() throws {
+        var hash = rust_make_32_byte_array()
+        let bytes = withUnsafeBytes(of: &hash) { Array($0) }
+
+        XCTAssertEqual(bytes, Array(0..<32))
+    }
+
+    /// Verify that Rust can call Swift functions that accept and return fixed size arrays.
+    func testRustCallsSwiftFixedSizeArrays() throws {
+        test_rust_calls_swift_fixed_size_arrays()
+    }
+}
@@ -24,4 +24,66 @@ class FunctionAttributeTests: XCTestCase {
     func testSwiftNameAttribute() throws {
         XCTAssertEqual(testCallRustFromSwiftByNameAttribute().toString(), "StringFromRust")
     }
+
+    /// Verify that the `swift_bridge(rust_name = "x")` attribute works on extern "Swift" functions.
+    func testRustNameAttribute() throws {
+        XCTAssertEqual(testCallRustFromSwiftByRustNameAttribute(), 123)
+    }
+
+    /// Verify that the `swift_bridge(args_into = (...))` attribute works on methods.
+    /// See crates/swift-integration-tests/src/function_attributes/args_into.rs
+    func testArgsIntoAttributeOnMethods() throws {
+        let wallet = ArgsIntoWallet()
+        wallet.deposit(150, "USD")
+        wallet.deposit(20, "EUR")
+        wallet.deposit(50, "USD")
+
+        XCTAssertEqual(wallet.balance("USD"), 200)
+        XCTAssertEqual(wallet.balance("EUR"), 20)
+    }
+
+    /// Verify that we can use types and functions that have the `swift_bridge(available = "...")`
+    /// attribute.
+    /// See crates/swift-integration-tests/src/function_attributes/available.rs
+    func testAvailableAttribute() throws {
+        XCTAssertEqual(AvailableAttributeType().value(), 123)
+        XCTAssertEqual(available_attribute_function(), 123)
+    }
+
+    /// Verify that we can use `internal` types and functions that have the
+    /// `swift_bridge(swift_access = "...")` attribute.
+    /// See crates/swift-integration-tests/src/function_attributes/swift_access.rs
+    func testSwiftAccessAttribute() throws {
+        XCTAssertEqual(SwiftAccessAttributeType().value(), 45)
+        XCTAssertEqual(swift_access_attribute_function(), 45)
+    }
+
+    /// Verify that we can read and write through a `swift_bridge(subscript)` getter and setter.
+    /// See crates/swift-integration-tests/src/function_attributes/subscript.rs
+    func testSubscriptAttribute() throws {
+        let value = SubscriptAttributeType()
+        XCTAssertEqual(value[1], 2)
+
+        value[1] = 20
+        XCTAssertEqual(value[1], 20)
+        XCTAssertEqual(value[2], 3)
+    }
+
+    /// Verify that we can read and write `swift_bridge(swift_property)` computed properties.
+    /// See crates/swift-integration-tests/src/function_attributes/swift_property.rs
+    func testSwiftPropertyAttribute() throws {
+        let value = SwiftPropertyAttributeType()
+        XCTAssertEqual(value.name.toString(), "Rust")
+        XCTAssertEqual(value.nameLength, 4)
+
+        value.name = RustString("Swift")
+        XCTAssertEqual(value.name.toString(), "Swift")
+        XCTAssertEqual(value.nameLength, 5)
+    }
+
+    /// Verify that errors thrown by `swift_bridge(catch_errors)` functions are returned to Rust.
+    /// See crates/swift-integration-tests/src/function_attributes/catch_errors.rs
+    func testCatchErrorsAttribute() throws {
+        testCallSwiftThatThrowsFromRust()
+    }
 }
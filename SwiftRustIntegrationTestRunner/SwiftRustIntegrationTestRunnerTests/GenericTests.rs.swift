@@ -32,5 +32,23 @@ class GenericTests: XCTestCase {
         let val = new_generic_with_inner_opaque_type()
         let _: GenericWithOpaqueRustInnerTy<InnerTy> = reflect_generic_with_inner_opaque_type(val)
     }
-}
 
+    /// Verify that we can call initializers and methods on monomorphized generic types.
+    func testGenericOpaqueRustTypeMethods() {
+        let u8Stack = GenericStack<UInt8>()
+        u8Stack.push(1)
+        u8Stack.push(2)
+        XCTAssertEqual(u8Stack.len(), 2)
+
+        let u64Stack: GenericStack<UInt64> = new_generic_stack_u64()
+        u64Stack.push(UInt64.max)
+        XCTAssertEqual(u64Stack.len(), 1)
+    }
+
+    /// Verify that we can call each instantiation of a generic function.
+    func testInstantiatedGenericFunction() {
+        XCTAssertEqual(reflect_instantiated_generic(UInt8(5)), 5)
+        XCTAssertEqual(reflect_instantiated_generic(Int64(-10)), -10)
+        XCTAssertEqual(reflect_instantiated_generic("hello").toString(), "hello")
+    }
+}
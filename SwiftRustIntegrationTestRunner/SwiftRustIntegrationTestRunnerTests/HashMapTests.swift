@@ -0,0 +1,60 @@
+//
+//  HashMapTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `HashMap<K, V>` to and from `RustMap<K, V>`.
+class HashMapTests: XCTestCase {
+    /// Verify that we can index into and iterate over a HashMap that was returned from Rust.
+    func testRustReturnsHashMapStringU64() throws {
+        let map = rust_return_hash_map_string_u64()
+
+        XCTAssertEqual(map.len(), 2)
+        XCTAssertEqual(map.get("one"), 1)
+        XCTAssertEqual(map["two"], 2)
+        XCTAssertNil(map["three"])
+
+        let keys = map.keys().map { $0.toString() }.sorted()
+        XCTAssertEqual(keys, ["one", "two"])
+
+        XCTAssertEqual(map.toDictionary(), ["one": 1, "two": 2])
+    }
+
+    /// Verify that we can insert and remove entries from a RustMap.
+    func testRustMapInsertAndRemove() throws {
+        let map = RustMap<RustString, UInt64>()
+
+        XCTAssertNil(map.insert("hello", 5))
+        XCTAssertEqual(map.insert("hello", 6), 5)
+        XCTAssertEqual(map.len(), 1)
+
+        XCTAssertEqual(map.remove("hello"), 6)
+        XCTAssertNil(map.remove("hello"))
+        XCTAssertEqual(map.len(), 0)
+    }
+
+    /// Verify that we can pass a Swift Dictionary to Rust.
+    func testSwiftDictionaryPassedToRust() throws {
+        let map = RustMap<RustString, UInt64>(["a": 10, "b": 20, "c": 30])
+
+        XCTAssertEqual(rust_sum_hash_map_string_u64_values(map), 60)
+    }
+
+    /// Verify that we can pass a HashMap with primitive keys and values to Rust and get it back.
+    func testReflectHashMapU8Bool() throws {
+        let map = rust_reflect_hash_map_u8_bool(RustMap([1: true, 2: false]))
+
+        XCTAssertEqual(map.toDictionary(), [1: true, 2: false])
+    }
+
+    /// Verify that we can pass a HashMap with String values to Rust and get it back.
+    func testReflectHashMapStringString() throws {
+        let map = rust_reflect_hash_map_string_string(RustMap(["hello": "world"]))
+
+        XCTAssertEqual(map["hello"]!.toString(), "world")
+        XCTAssertEqual(map.toDictionary(), ["hello": "world"])
+    }
+}
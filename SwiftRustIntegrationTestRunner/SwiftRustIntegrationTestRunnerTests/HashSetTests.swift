@@ -0,0 +1,52 @@
+//
+//  HashSetTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `HashSet<T>` to and from `RustSet<T>`.
+class HashSetTests: XCTestCase {
+    /// Verify that we can query and iterate over a HashSet that was returned from Rust.
+    func testRustReturnsHashSetString() throws {
+        let set = rust_return_hash_set_string()
+
+        XCTAssertEqual(set.len(), 2)
+        XCTAssertTrue(set.contains("one"))
+        XCTAssertFalse(set.contains("three"))
+
+        let values = set.map { $0.toString() }.sorted()
+        XCTAssertEqual(values, ["one", "two"])
+
+        XCTAssertEqual(set.toSet(), ["one", "two"])
+    }
+
+    /// Verify that we can insert and remove values from a RustSet.
+    func testRustSetInsertAndRemove() throws {
+        let set = RustSet<UInt32>()
+
+        XCTAssertTrue(set.insert(5))
+        XCTAssertFalse(set.insert(5))
+        XCTAssertEqual(set.len(), 1)
+
+        XCTAssertTrue(set.remove(5))
+        XCTAssertFalse(set.remove(5))
+        XCTAssertEqual(set.len(), 0)
+    }
+
+    /// Verify that we can pass either a Swift Set or a RustSet to Rust.
+    func testSwiftSetPassedToRust() throws {
+        let swiftSet: Set<UInt32> = [10, 20, 30]
+        XCTAssertEqual(rust_sum_hash_set_u32(swiftSet), 60)
+
+        XCTAssertEqual(rust_sum_hash_set_u32(RustSet(swiftSet)), 60)
+    }
+
+    /// Verify that we can pass a HashSet of Strings to Rust and get it back.
+    func testReflectHashSetString() throws {
+        let set = rust_reflect_hash_set_string(Set(["hello", "world"]))
+
+        XCTAssertEqual(set.toSet(), ["hello", "world"])
+    }
+}
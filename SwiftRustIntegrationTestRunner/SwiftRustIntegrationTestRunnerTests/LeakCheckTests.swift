@@ -0,0 +1,46 @@
+//
+//  LeakCheckTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for the `leak-check` feature of the `swift-bridge` crate.
+class LeakCheckTests: XCTestCase {
+    /// Verify that the live count goes up when Swift takes ownership of a Rust type, and back down
+    /// when Swift frees it.
+    func testLiveCountTracksSwiftOwnedRustTypes() throws {
+        let before = leak_check_live_count()
+
+        withExtendedLifetime(LeakCheckType()) {
+            XCTAssertEqual(leak_check_live_count(), before + 1)
+        }
+
+        XCTAssertEqual(leak_check_live_count(), before)
+    }
+
+    /// Verify that passing ownership back to Rust is not counted as a leak.
+    func testPassingOwnershipToRustDecrementsLiveCount() throws {
+        let before = leak_check_live_count()
+
+        leak_check_take_ownership(LeakCheckType())
+
+        XCTAssertEqual(leak_check_live_count(), before)
+    }
+
+    /// Verify that values popped out of a Rust Vec are counted until Swift frees them.
+    func testVecPopIsCounted() throws {
+        let before = leak_check_live_count()
+
+        let vec = leak_check_vec(2)
+        XCTAssertEqual(leak_check_live_count(), before)
+
+        var popped = vec.pop()
+        XCTAssertEqual(leak_check_live_count(), before + 1)
+
+        popped = nil
+        XCTAssertNil(popped)
+        XCTAssertEqual(leak_check_live_count(), before)
+    }
+}
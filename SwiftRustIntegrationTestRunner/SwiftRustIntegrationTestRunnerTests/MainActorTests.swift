@@ -0,0 +1,32 @@
+//
+//  MainActorTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for `#[swift_bridge(main_actor)]` Swift functions, which Rust can call from any thread.
+class MainActorTests: XCTestCase {
+    /// Verify that a call that is already on the main thread runs right away instead of
+    /// deadlocking while it waits for the main queue.
+    func testRustCallsMainActorFnFromMainThread() throws {
+        XCTAssertTrue(Thread.isMainThread)
+
+        XCTAssertTrue(rust_calls_main_actor_fn())
+    }
+
+    /// Verify that a call from a background thread runs on the main thread.
+    func testRustCallsMainActorFnFromBackgroundThread() async throws {
+        let isMainThread = await rust_calls_main_actor_fn_from_background_thread()
+
+        XCTAssertTrue(isMainThread)
+    }
+
+    /// Verify that an async function runs on the main actor when Rust awaits it.
+    func testRustAwaitsMainActorAsyncFn() async throws {
+        let isMainThread = await rust_awaits_main_actor_async_fn()
+
+        XCTAssertTrue(isMainThread)
+    }
+}
@@ -0,0 +1,21 @@
+//
+//  NonZeroTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for `NonZero*` integers <-> plain Swift integers.
+class NonZeroTests: XCTestCase {
+    /// Verify that we can pass and return non zero integers as plain Swift integers.
+    func testSwiftCallsRustNonZero() throws {
+        XCTAssertEqual(rust_reflect_non_zero_u32(5), 5)
+        XCTAssertEqual(rust_double_non_zero_i64(-21), -42)
+    }
+
+    /// Verify that Rust can call Swift functions that accept and return non zero integers.
+    func testRustCallsSwiftNonZero() throws {
+        test_rust_calls_swift_non_zero()
+    }
+}
@@ -0,0 +1,46 @@
+//
+//  NsErrorTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for `swift_bridge::ns_error::NsError` <-> `NSError`.
+class NsErrorTests: XCTestCase {
+    /// Verify that a Rust function that returns a `Result<T, NsError>` throws an `NSError`.
+    func testRustThrowsNSError() throws {
+        XCTAssertEqual(try rust_parse_port("8080"), 8080)
+
+        do {
+            _ = try rust_parse_port("not a port")
+            XCTFail("Expected an error to be thrown")
+        } catch let error as NSError {
+            XCTAssertEqual(error.domain, "com.example.ports")
+            XCTAssertEqual(error.code, 1)
+            XCTAssertEqual(error.localizedDescription, "not a port is not a valid port")
+            XCTAssertEqual(error.userInfo["text"] as? String, "not a port")
+        }
+    }
+
+    /// Verify that an `NSError` keeps its domain, code and user info when it is passed to Rust and
+    /// back.
+    func testReflectNSError() throws {
+        let error = NSError(
+            domain: "com.example.reflect",
+            code: -3,
+            userInfo: [NSLocalizedFailureReasonErrorKey: "Because"]
+        )
+
+        let reflected = rust_reflect_ns_error(error)
+        XCTAssertEqual(reflected.domain, "com.example.reflect")
+        XCTAssertEqual(reflected.code, -3)
+        XCTAssertEqual(reflected.localizedFailureReason, "Because")
+        XCTAssertEqual(reflected.localizedDescription, error.localizedDescription)
+    }
+
+    /// Verify that Rust receives the `NSError`s that `catch_errors` Swift functions throw.
+    func testRustCallsSwiftNSError() throws {
+        test_rust_calls_swift_ns_error()
+    }
+}
@@ -0,0 +1,75 @@
+//
+//  ObservableTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import Combine
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for the `ObservableObject`s of `#[swift_bridge(observable)]` types.
+class ObservableTests: XCTestCase {
+    /// Verify that the published properties start out with the Rust value's properties.
+    func testPublishesRustProperties() throws {
+        let observable = ObservableSharedCounter(SharedCounter())
+
+        XCTAssertEqual(observable.count, 0)
+        XCTAssertEqual(observable.name, "counter")
+    }
+
+    /// Verify that setting a published property sets the Rust value's property.
+    func testSettingPublishedPropertySetsRustProperty() throws {
+        let observable = ObservableSharedCounter(SharedCounter())
+
+        observable.count = 5
+        observable.name = "renamed"
+
+        XCTAssertEqual(observable.value.count, 5)
+        XCTAssertEqual(observable.value.name.toString(), "renamed")
+    }
+
+    /// Verify that the published properties get refreshed after Rust notifies about a change.
+    func testRefreshesAfterRustChange() throws {
+        let observable = ObservableSharedCounter(SharedCounter())
+        let refreshed = expectation(description: "count was refreshed")
+        let cancellable = observable.$count.first(where: { $0 == 1 }).sink { _ in
+            refreshed.fulfill()
+        }
+
+        observable.value.increment()
+
+        wait(for: [refreshed], timeout: 5)
+        XCTAssertEqual(observable.count, 1)
+        cancellable.cancel()
+    }
+
+    /// Verify that Rust can notify about a change from a background thread, and that the
+    /// published properties still get refreshed on the main thread.
+    func testRefreshesOnMainThreadAfterBackgroundChange() throws {
+        let observable = ObservableSharedCounter(SharedCounter())
+        let refreshed = expectation(description: "count was refreshed")
+        let cancellable = observable.$count.first(where: { $0 == 1 }).sink { _ in
+            XCTAssertTrue(Thread.isMainThread)
+            refreshed.fulfill()
+        }
+
+        observable.value.increment_on_background_thread()
+
+        wait(for: [refreshed], timeout: 5)
+        XCTAssertEqual(observable.count, 1)
+        cancellable.cancel()
+    }
+
+    /// Verify that an `ObservableObject` stops observing the Rust value when it is
+    /// deinitialized, so re-creating it doesn't pile up observers on the Rust side.
+    func testDeinitUnsubscribesObserver() throws {
+        let counter = SharedCounter()
+
+        for _ in 0..<10 {
+            let observable = ObservableSharedCounter(counter)
+            XCTAssertEqual(observable.value.observer_count(), 1)
+        }
+
+        XCTAssertEqual(counter.observer_count(), 0)
+    }
+}
@@ -130,5 +130,37 @@ class OpaqueRustStructTests: XCTestCase {
             }
         }
     }
-}
 
+    /// Verify that a type annotated with only `Hashable` is also `Equatable`.
+    func testOpaqueRustTypeHashableImpliesEquatable() throws {
+        let val1 = RustHashableOnlyType(10)
+        let val2 = RustHashableOnlyType(10)
+        let val3 = RustHashableOnlyType(100)
+
+        XCTAssertEqual(val1, val2)
+        XCTAssertNotEqual(val1, val3)
+        XCTAssertEqual(Set([val1, val2, val3]).count, 2)
+    }
+
+    /// Verify that we can use the Swift operators generated by the `Comparable` and `Add`
+    /// attributes.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/operators.rs
+    func testOpaqueRustTypeOperators() throws {
+        let one = RustOperatorsType(1)
+        let two = RustOperatorsType(2)
+
+        XCTAssert(one < two)
+        XCTAssertFalse(two < one)
+        XCTAssertEqual(one, RustOperatorsType(1))
+        XCTAssertEqual([two, one].sorted().map { $0.value() }, [1, 2])
+
+        XCTAssertEqual((one + two).value(), 3)
+    }
+
+    /// Verify that we can construct a type using the `init()` generated by the `Default`
+    /// attribute.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/default.rs
+    func testOpaqueRustTypeDefault() throws {
+        XCTAssertEqual(RustDefaultType().value(), 123)
+    }
+}
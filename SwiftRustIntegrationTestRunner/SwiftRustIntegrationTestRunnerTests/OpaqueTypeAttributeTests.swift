@@ -42,6 +42,98 @@ class OpaqueTypeAttributeTests: XCTestCase {
         XCTAssert(AlreadyDeclaredCopyTypeTest.an_associated_function())
     }
 
+    /// Verify that we can use an opaque Rust type in a bridge module that lives in a different file
+    /// from the bridge module that declares the type.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/already_declared_in_other_file.rs
+    func testExternRustAlreadyDeclaredInOtherFile() throws {
+        let val = rust_reflect_already_declared_in_other_file(AlreadyDeclaredTypeTest())
+
+        XCTAssert(val.a_method_from_another_file())
+    }
+
+    /// Verify that we can call methods that were declared in a different module from where the
+    /// opaque Swift type was declared.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/already_declared.rs
+    func testExternSwiftAlreadyDeclaredOpaqueSwiftType() throws {
+        XCTAssert(rust_calls_already_declared_swift_type_method(AlreadyDeclaredSwiftTypeTest()))
+    }
+
+    /// Verify that an opaque Rust type can be renamed on the Swift side.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/swift_name.rs
+    func testExternRustSwiftNameOpaqueRustType() throws {
+        let val: RenamedOpaqueRustType = RenamedOpaqueRustType(5)
+
+        XCTAssertEqual(val.value(), 5)
+        XCTAssertEqual(val.incremented().value(), 6)
+    }
+
+    /// Verify that the `get` and `set` attributes expose an opaque Rust type's fields as Swift
+    /// properties.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/field_accessors.rs
+    func testExternRustFieldAccessors() throws {
+        let val = OpaqueRustTypeWithFields("Alice", 30)
+
+        XCTAssertEqual(val.name.toString(), "Alice")
+        XCTAssertEqual(val.age, 30)
+
+        val.age = 31
+        XCTAssertEqual(val.age, 31)
+    }
+
+    /// Verify that the `Debug` and `Display` attributes expose an opaque Rust type's `Debug` and
+    /// `Display` implementations to Swift.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/debug_display.rs
+    func testExternRustDebugAndDisplay() throws {
+        let val = RustDebugDisplayType("world")
+
+        XCTAssertEqual(val.description, "Hello, world")
+        XCTAssertEqual(String(describing: val), "Hello, world")
+        XCTAssertEqual(val.debugDescription, #"RustDebugDisplayType { name: "world" }"#)
+        XCTAssertEqual(String(reflecting: val), #"RustDebugDisplayType { name: "world" }"#)
+    }
+
+    /// Verify that the `Identifiable` attribute conforms an opaque Rust type to `Identifiable`
+    /// using its `get(id: ...)` field.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/identifiable.rs
+    func testExternRustIdentifiable() throws {
+        let val = IdentifiableOpaqueRustType(123)
+
+        XCTAssertEqual(verifyIsIdentifiable(val).id, 123)
+    }
+
+    /// Verify that the `Sendable` attribute lets opaque Rust types and shared structs be passed
+    /// to another task.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/sendable.rs
+    func testExternRustSendable() async throws {
+        let opaque = SendableOpaqueRustType(5)
+        let shared = SendableSharedStruct(value: 6)
+
+        let sum = await Task.detached {
+            opaque.value() + shared.value
+        }.value
+
+        XCTAssertEqual(verifyIsSendable(opaque).value(), 5)
+        XCTAssertEqual(sum, 11)
+    }
+
+    /// Verify that the `drop_on = "main"` attribute drops the Rust value on the main thread, even
+    /// when the Swift class is deinitialized on another thread.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/drop_on.rs
+    func testDropOnMain() throws {
+        let dropped = expectation(description: "Dropped")
+        onDropOnMainTypeDropped = { isMainThread in
+            XCTAssertTrue(isMainThread)
+            dropped.fulfill()
+        }
+
+        DispatchQueue.global().async {
+            _ = DropOnMainType()
+        }
+
+        wait(for: [dropped], timeout: 5)
+        onDropOnMainTypeDropped = { _ in }
+    }
+
     
     func testPerformanceExample() throws {
         // This is an example of a performance test case.
@@ -51,3 +143,7 @@ class OpaqueTypeAttributeTests: XCTestCase {
     }
 
 }
+
+func verifyIsSendable<T: Sendable>(_ arg: T) -> T {
+    arg
+}
@@ -52,6 +52,12 @@ class OptionTests: XCTestCase {
     func testRustCallSwiftOptionPrimitive() throws {
         test_rust_calls_swift_option_primitive()
     }
+
+    /// Verify that Rust can call Swift functions that accept and return Option<&str> and
+    /// Option<&T>.
+    func testRustCallSwiftOptionReferences() throws {
+        test_rust_calls_swift_option_references()
+    }
     
     /// Verify that Swift can call a Rust function that accepts and returns an Option<T>
     /// where T is a String.
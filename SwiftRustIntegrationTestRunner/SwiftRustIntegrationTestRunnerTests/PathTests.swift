@@ -0,0 +1,26 @@
+//
+//  PathTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for `PathBuf` and `&Path` <-> `String`.
+class PathTests: XCTestCase {
+    /// Verify that we can pass and return paths.
+    func testSwiftCallsRustPath() throws {
+        XCTAssertEqual(rust_reflect_path_buf("/tmp/some file.txt"), "/tmp/some file.txt")
+        XCTAssertEqual(rust_path_file_name("/tmp/some file.txt").toString(), "some file.txt")
+    }
+
+    /// Verify that a path that is not valid UTF-8 is lossily converted instead of crashing.
+    func testNonUtf8PathIsLossilyConverted() throws {
+        XCTAssertEqual(rust_make_non_utf8_path(), "/tmp/invalid-\u{FFFD}")
+    }
+
+    /// Verify that Rust can call Swift functions that accept and return paths.
+    func testRustCallsSwiftPath() throws {
+        test_rust_calls_swift_path()
+    }
+}
@@ -30,6 +30,14 @@ class PointerTests: XCTestCase {
         XCTAssertEqual(pointer_mut, pointer_mut_copy)
     }
     
+    /// Verify that we can use a fully qualified `std::ffi::c_void` in a bridge signature.
+    func testSwiftCallRustFullyQualifiedCvoid() throws {
+        let value = [1, 2, 3]
+        let pointer = UnsafeRawPointer(value)
+
+        XCTAssertEqual(rust_echo_fully_qualified_c_void(pointer), pointer)
+    }
+
     func testRustCallSwiftCvoid() throws {
         rust_run_opaque_pointer_tests()
     }
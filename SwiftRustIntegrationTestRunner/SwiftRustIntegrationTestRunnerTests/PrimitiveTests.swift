@@ -30,5 +30,21 @@ class PrimitiveTests: XCTestCase {
         XCTAssertEqual(rust_negate_bool(true), false);
         XCTAssertEqual(rust_negate_bool(false), true);
     }
+
+    /// Verify that we can pass and return 128 bit integers, including values that do not fit
+    /// in the lower 64 bits.
+    func testSwiftCallsRustInt128() throws {
+        XCTAssertEqual(rust_double_u128(RustUInt128(10)), RustUInt128(20))
+        XCTAssertEqual(
+            rust_double_u128(RustUInt128(low: UInt64.max, high: 0)),
+            RustUInt128(low: UInt64.max - 1, high: 1)
+        )
+
+        XCTAssertEqual(rust_double_i128(RustInt128(-10)), RustInt128(-20))
+        XCTAssertEqual(
+            rust_double_i128(RustInt128(low: 0, high: -1)),
+            RustInt128(low: 0, high: -2)
+        )
+    }
 }
 
@@ -0,0 +1,103 @@
+//
+//  PublisherTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import Combine
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+extension PublisherTestError: Error {}
+
+/// Tests for `#[swift_bridge(publisher)]` functions, whose Rust streams are seen as Combine
+/// publishers.
+class PublisherTests: XCTestCase {
+    private var cancellables: Set<AnyCancellable> = []
+
+    /// Verify that we receive every item of a Rust stream, followed by its completion.
+    func testReceiveStreamItems() throws {
+        let finished = expectation(description: "finished")
+        var numbers: [UInt32] = []
+
+        rust_count_to(5)
+            .sink(receiveCompletion: { _ in finished.fulfill() }, receiveValue: { numbers.append($0) })
+            .store(in: &cancellables)
+
+        wait(for: [finished], timeout: 5)
+        XCTAssertEqual(numbers, [1, 2, 3, 4, 5])
+    }
+
+    /// Verify that we can receive owned Rust values, such as `String`s.
+    func testReceiveStrings() throws {
+        let words = expectation(description: "words")
+
+        rust_words("hello from rust")
+            .map { $0.toString() }
+            .collect()
+            .sink { XCTAssertEqual($0, ["hello", "from", "rust"]); words.fulfill() }
+            .store(in: &cancellables)
+
+        wait(for: [words], timeout: 5)
+    }
+
+    /// Verify that a stream of `Result`s fails with its first error.
+    func testStreamFailsWithFirstError() throws {
+        let failed = expectation(description: "failed")
+        var numbers: [UInt32] = []
+
+        rust_count_to_then_fail(3)
+            .sink(
+                receiveCompletion: { completion in
+                    if case .failure(let error as PublisherTestError) = completion {
+                        XCTAssertEqual(error.number(), 4)
+                        failed.fulfill()
+                    }
+                },
+                receiveValue: { numbers.append($0) }
+            )
+            .store(in: &cancellables)
+
+        wait(for: [failed], timeout: 5)
+        XCTAssertEqual(numbers, [1, 2, 3])
+    }
+
+    /// Verify that the stream only produces as many items as the subscriber asks for.
+    func testStreamRespectsDemand() throws {
+        let received = expectation(description: "received")
+        received.expectedFulfillmentCount = 2
+        var numbers: [UInt32] = []
+
+        let subscriber = AnySubscriber<UInt32, Never>(
+            receiveSubscription: { $0.request(.max(2)) },
+            receiveValue: { number in
+                numbers.append(number)
+                received.fulfill()
+                return .none
+            },
+            receiveCompletion: { _ in XCTFail("The stream should not finish without demand") }
+        )
+        rust_count_to(10).subscribe(subscriber)
+
+        wait(for: [received], timeout: 5)
+        // Give the stream a chance to produce more items than were requested.
+        Thread.sleep(forTimeInterval: 0.1)
+        XCTAssertEqual(numbers, [1, 2])
+    }
+
+    /// Verify that we can cancel a subscription before the stream finishes.
+    func testCancelSubscription() throws {
+        let received = expectation(description: "received")
+        var cancellable: AnyCancellable?
+
+        cancellable = rust_count_to(UInt32.max).sink { number in
+            if number == 3 {
+                cancellable?.cancel()
+                received.fulfill()
+            }
+            XCTAssertLessThanOrEqual(number, 3)
+        }
+
+        wait(for: [received], timeout: 5)
+        _ = cancellable
+    }
+}
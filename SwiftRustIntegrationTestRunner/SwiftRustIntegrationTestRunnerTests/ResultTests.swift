@@ -65,6 +65,18 @@ class ResultTests: XCTestCase {
         }
     }
     
+    /// Verify that we can receive a Result<&'static str, &'static str> from Rust
+    func testSwiftCallRustResultStaticStr() throws {
+        XCTAssertEqual(try! rust_func_return_result_static_str(true).toString(), "OK")
+
+        do {
+            let _ = try rust_func_return_result_static_str(false)
+            XCTFail("The function should have returned an error.")
+        } catch let error as RustStr {
+            XCTAssertEqual(error.toString(), "E_INVALID")
+        }
+    }
+
     /// Verify that we can receive a Result<OpaqueRust, TransparentEnum> from Rust
     func testResultOpaqueRustTransparentEnum() throws {
         XCTContext.runActivity(named: "Should return a ResultTestOpaqueRustType") {
@@ -209,4 +221,16 @@ class ResultTests: XCTestCase {
             XCTAssertEqual(UInt32(i), value.val())
         }
     }
+
+    /// Verify that we can receive a Result<u32, OpaqueRust> from Rust
+    func testSwiftCallRustResultUInt32OpaqueRust() throws {
+        XCTAssertEqual(try! rust_func_return_result_u32_opaque_rust(true), 123)
+
+        do {
+            let _ = try rust_func_return_result_u32_opaque_rust(false)
+            XCTFail("The function should have returned an error.")
+        } catch let error as ResultTestOpaqueRustType {
+            XCTAssertEqual(error.val(), 456)
+        }
+    }
 }
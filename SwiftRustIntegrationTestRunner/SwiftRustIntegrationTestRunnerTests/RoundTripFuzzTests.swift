@@ -0,0 +1,154 @@
+//
+//  RoundTripFuzzTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Send random values of each built-in type across the bridge and back, and check that they come
+/// back unchanged.
+class RoundTripFuzzTests: XCTestCase {
+    let iterations = 1_000
+
+    /// Verify that the random values that Rust sends to Swift come back unchanged.
+    ///
+    /// A failure prints the seed, which can be hard coded here to reproduce it.
+    func testRustCallsSwiftRoundTrip() throws {
+        let seed = UInt64.random(in: .min ... .max)
+        fuzz_rust_calls_swift(seed, UInt32(iterations))
+    }
+
+    /// Verify that the random values that Swift sends to Rust come back unchanged.
+    func testSwiftCallsRustRoundTrip() throws {
+        for _ in 0..<iterations {
+            let u8 = UInt8.random(in: .min ... .max)
+            XCTAssertEqual(fuzz_rust_reflect_u8(u8), u8)
+            let i8 = Int8.random(in: .min ... .max)
+            XCTAssertEqual(fuzz_rust_reflect_i8(i8), i8)
+            let u16 = UInt16.random(in: .min ... .max)
+            XCTAssertEqual(fuzz_rust_reflect_u16(u16), u16)
+            let i16 = Int16.random(in: .min ... .max)
+            XCTAssertEqual(fuzz_rust_reflect_i16(i16), i16)
+            let u32 = UInt32.random(in: .min ... .max)
+            XCTAssertEqual(fuzz_rust_reflect_u32(u32), u32)
+            let i32 = Int32.random(in: .min ... .max)
+            XCTAssertEqual(fuzz_rust_reflect_i32(i32), i32)
+            let u64 = UInt64.random(in: .min ... .max)
+            XCTAssertEqual(fuzz_rust_reflect_u64(u64), u64)
+            let i64 = Int64.random(in: .min ... .max)
+            XCTAssertEqual(fuzz_rust_reflect_i64(i64), i64)
+            let usize = UInt.random(in: .min ... .max)
+            XCTAssertEqual(fuzz_rust_reflect_usize(usize), usize)
+            let isize = Int.random(in: .min ... .max)
+            XCTAssertEqual(fuzz_rust_reflect_isize(isize), isize)
+
+            let u128 = RustUInt128(
+                low: UInt64.random(in: .min ... .max),
+                high: UInt64.random(in: .min ... .max)
+            )
+            XCTAssertEqual(fuzz_rust_reflect_u128(u128), u128)
+            let i128 = RustInt128(
+                low: UInt64.random(in: .min ... .max),
+                high: Int64.random(in: .min ... .max)
+            )
+            XCTAssertEqual(fuzz_rust_reflect_i128(i128), i128)
+
+            // Floats are compared bit for bit, so that NaNs and negative zero are checked too.
+            let f32 = Float(bitPattern: UInt32.random(in: .min ... .max))
+            XCTAssertEqual(fuzz_rust_reflect_f32(f32).bitPattern, f32.bitPattern)
+            let f64 = Double(bitPattern: UInt64.random(in: .min ... .max))
+            XCTAssertEqual(fuzz_rust_reflect_f64(f64).bitPattern, f64.bitPattern)
+
+            let bool = Bool.random()
+            XCTAssertEqual(fuzz_rust_reflect_bool(bool), bool)
+
+            let string = randomString()
+            XCTAssertEqual(fuzz_rust_reflect_string(string).toString(), string)
+
+            let bytes = (0..<Int.random(in: 0..<64)).map { _ in UInt8.random(in: .min ... .max) }
+            let vecU8 = RustVec<UInt8>()
+            for byte in bytes {
+                vecU8.push(value: byte)
+            }
+            XCTAssertEqual(Array(fuzz_rust_reflect_vec_u8(vecU8)), bytes)
+
+            let ints = (0..<Int.random(in: 0..<64)).map { _ in Int64.random(in: .min ... .max) }
+            let vecI64 = RustVec<Int64>()
+            for int in ints {
+                vecI64.push(value: int)
+            }
+            XCTAssertEqual(Array(fuzz_rust_reflect_vec_i64(vecI64)), ints)
+
+            let optionU32: UInt32? = Int.random(in: 0..<4) == 0 ? nil : UInt32.random(in: .min ... .max)
+            XCTAssertEqual(fuzz_rust_reflect_option_u32(optionU32), optionU32)
+            let optionF64: Double? = Int.random(in: 0..<4) == 0 ? nil : Double(bitPattern: UInt64.random(in: .min ... .max))
+            XCTAssertEqual(fuzz_rust_reflect_option_f64(optionF64)?.bitPattern, optionF64?.bitPattern)
+            let optionString: String? = Int.random(in: 0..<4) == 0 ? nil : randomString()
+            XCTAssertEqual(fuzz_rust_reflect_option_string(optionString)?.toString(), optionString)
+
+            let uuid = UUID()
+            XCTAssertEqual(fuzz_rust_reflect_uuid(uuid), uuid)
+
+            // Durations are only passed with nanosecond precision.
+            let duration = TimeInterval.random(in: 0..<1_000_000)
+            XCTAssertEqual(fuzz_rust_reflect_duration(duration), duration, accuracy: 1e-9)
+
+            var byteArray: (UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8)
+                = (0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+            withUnsafeMutableBytes(of: &byteArray) { buffer in
+                for idx in buffer.indices {
+                    buffer[idx] = UInt8.random(in: .min ... .max)
+                }
+            }
+            let reflectedByteArray = fuzz_rust_reflect_u8_array(byteArray)
+            XCTAssertEqual(
+                withUnsafeBytes(of: reflectedByteArray) { Array($0) },
+                withUnsafeBytes(of: byteArray) { Array($0) }
+            )
+
+            let floatArray = (
+                Double(bitPattern: UInt64.random(in: .min ... .max)),
+                Double(bitPattern: UInt64.random(in: .min ... .max)),
+                Double(bitPattern: UInt64.random(in: .min ... .max))
+            )
+            let reflectedFloatArray = fuzz_rust_reflect_f64_array(floatArray)
+            XCTAssertEqual(reflectedFloatArray.0.bitPattern, floatArray.0.bitPattern)
+            XCTAssertEqual(reflectedFloatArray.1.bitPattern, floatArray.1.bitPattern)
+            XCTAssertEqual(reflectedFloatArray.2.bitPattern, floatArray.2.bitPattern)
+
+            let path = randomString()
+            XCTAssertEqual(fuzz_rust_reflect_path_buf(path), path)
+
+            let cowString = randomString()
+            let cow = fuzz_rust_owned_cow_str(cowString)
+            XCTAssertTrue(cow.isOwned)
+            XCTAssertEqual(cow.toString(), cowString)
+
+            let arcValue = UInt64.random(in: .min ... .max)
+            let arc = fuzz_rust_reflect_arc(fuzz_rust_make_arc(arcValue))
+            XCTAssertEqual(arc.value(), arcValue)
+
+            let resultString = randomString()
+            if Bool.random() {
+                XCTAssertEqual(try fuzz_rust_reflect_result_string(.Ok(resultString)).toString(), resultString)
+            } else {
+                XCTAssertThrowsError(try fuzz_rust_reflect_result_string(.Err(resultString))) { error in
+                    XCTAssertEqual((error as NSError).userInfo["value"] as? String, resultString)
+                }
+            }
+        }
+    }
+
+    /// A string mixing ASCII, NUL bytes and characters that take two, three and four bytes to
+    /// encode in UTF-8.
+    func randomString() -> String {
+        let ranges: [Range<UInt32>] = [0..<0x80, 0x80..<0x800, 0x800..<0xD800, 0x10000..<0x110000]
+        var scalars = String.UnicodeScalarView()
+        for _ in 0..<Int.random(in: 0..<32) {
+            let range = ranges.randomElement()!
+            scalars.append(Unicode.Scalar(UInt32.random(in: range))!)
+        }
+        return String(scalars)
+    }
+}
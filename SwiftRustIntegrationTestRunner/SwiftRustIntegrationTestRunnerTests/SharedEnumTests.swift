@@ -32,6 +32,11 @@ class SharedEnumTests: XCTestCase {
         }
     }
 
+    /// Verify that Rust can pass an enum with no data to Swift and get it back.
+    func testRustCallsSwiftEnumWithNoData() {
+        test_rust_calls_swift_enum_with_no_data()
+    }
+
     func testEnumWithUnnamedData() {
         let enumWithUnnamedData1 = EnumWithUnnamedData.TwoFields(create_string("hello"), OpaqueRustForEnumTest())
         switch reflect_enum_with_unnamed_data(enumWithUnnamedData1) {
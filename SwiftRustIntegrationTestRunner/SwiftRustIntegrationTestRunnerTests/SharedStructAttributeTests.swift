@@ -34,5 +34,13 @@ class SharedStructAttributeTests: XCTestCase {
     func testSharedStructAlreadyDeclared() throws {
         test_rust_calls_swift_already_declared_struct()
     }
+
+    /// Verify that the `Identifiable` attribute conforms a shared struct to `Identifiable`.
+    /// See crates/swift-integration-tests/src/struct_attributes/identifiable.rs
+    func testSharedStructIdentifiable() throws {
+        let val = IdentifiableStructTest(id: 123, name: "hello".intoRustString())
+
+        XCTAssertEqual(verifyIsIdentifiable(val).id, 123)
+    }
 }
 
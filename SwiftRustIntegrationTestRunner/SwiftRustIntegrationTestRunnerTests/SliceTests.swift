@@ -0,0 +1,44 @@
+//
+//  SliceTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for slices of opaque Rust types, such as `&[SomeType]`.
+class SliceTests: XCTestCase {
+    /// Verify that we can get the length of, index into and iterate over a `RustSlice`.
+    func testReturnSliceOfOpaqueRustType() throws {
+        let list = SliceTestOpaqueRustTypeList(3)
+        let slice = list.as_slice()
+
+        XCTAssertEqual(slice.count, 3)
+        XCTAssertEqual(slice[0].val(), 0)
+        XCTAssertEqual(slice[2].val(), 2)
+        XCTAssertEqual(slice.map { $0.val() }, [0, 1, 2])
+    }
+
+    /// Verify that a slice that we got from Rust can be passed back to Rust.
+    func testPassSliceOfOpaqueRustType() throws {
+        let list = SliceTestOpaqueRustTypeList(4)
+
+        XCTAssertEqual(rust_sum_slice_of_opaque_rust_type(list.as_slice()), 6)
+    }
+
+    /// Verify that we can use a `RustVec` of opaque Rust types as a slice.
+    func testRustVecAsSlice() throws {
+        let vec = rust_make_vec_of_slice_test_opaque_rust_type(3)
+        vec.push(value: SliceTestOpaqueRustType(10))
+
+        let slice = vec.as_slice()
+        XCTAssertEqual(slice.count, 4)
+        XCTAssertEqual(slice[3].val(), 10)
+        XCTAssertEqual(rust_sum_slice_of_opaque_rust_type(slice), 13)
+    }
+
+    /// Verify that Rust can pass a slice of opaque Rust types to Swift.
+    func testRustCallsSwiftSliceOfOpaqueRustType() throws {
+        test_rust_calls_swift_slice_of_opaque_rust_type()
+    }
+}
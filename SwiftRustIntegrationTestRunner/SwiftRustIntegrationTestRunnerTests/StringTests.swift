@@ -47,6 +47,91 @@ class StringTests: XCTestCase {
         )
     }
 
+    /// Verify that we can pass empty, short, long and non-ASCII Swift Strings to Rust.
+    func testPassSwiftStringsOfDifferentLengthsToRust() throws {
+        for string in ["", "a", "fifteen bytes!!", "sixteen bytes!!!", String(repeating: "long ", count: 100), "héllo 🌍"] {
+            XCTAssertEqual(create_string(string).toString(), string)
+            XCTAssertEqual(RustString(string).len(), UInt(string.utf8.count))
+        }
+    }
+
+    /// Verify that strings right around the 24 bytes that a `SmallRustStr` holds inline make it
+    /// to Rust intact when building with the `small-string-ffi` feature.
+    func testPassSwiftStringsAroundTheInlineCapacityToRust() throws {
+        for count in [23, 24, 25] {
+            let string = String(repeating: "a", count: count)
+            XCTAssertEqual(count_str_bytes(string), UInt(count))
+            XCTAssertEqual(create_string(string).toString(), string)
+        }
+        XCTAssertEqual(count_str_bytes("twenty two bytes: 🌍"), 22)
+    }
+
+    /// Benchmark passing short Strings to Rust.
+    ///
+    /// Compare the results with and without `CARGO_FEATURES=small-string-ffi` (see build-rust.sh)
+    /// to see what inlining the strings into the `SmallRustStr` saves.
+    func testPassShortSwiftStringsToRustPerformance() throws {
+        let names = ["id", "user_name", "created_at", "a_24_byte_identifier_xyz"]
+
+        self.measure {
+            var total: UInt = 0
+            for _ in 0..<25_000 {
+                for name in names {
+                    total += count_str_bytes(name)
+                }
+            }
+            XCTAssertEqual(total, 25_000 * 45)
+        }
+    }
+
+    /// Verify that we can pass Swift Strings to a function that skips UTF-8 validation.
+    func testPassSwiftStringsToUncheckedUtf8Function() throws {
+        XCTAssertEqual(count_str_bytes_unchecked("héllo", nil), 6)
+        XCTAssertEqual(count_str_bytes_unchecked("héllo", "🌍"), 10)
+    }
+
+    /// Verify that strings returned through the scratch buffer are copied before the buffer is reused.
+    func testReturnStringThroughScratchBuffer() throws {
+        let first = format_greeting("first")
+        let second = format_greeting("second")
+
+        XCTAssertEqual(first, "Hello, first")
+        XCTAssertEqual(second, "Hello, second")
+    }
+
+    /// Verify that we can convert an owned RustString into a Swift String using intoString,
+    /// including non-ASCII strings whose bytes Foundation might copy.
+    func testRustStringIntoString() throws {
+        XCTAssertEqual(create_string("hello world").intoString(), "hello world")
+        XCTAssertEqual(create_string("").intoString(), "")
+        XCTAssertEqual(create_string("héllo 🌍").intoString(), "héllo 🌍")
+
+        var string = create_string("hello").intoString()
+        string += " world"
+        XCTAssertEqual(string, "hello world")
+    }
+
+    /// Verify that we can pass owned Strings to and from methods on an opaque Rust type.
+    func testOpaqueRustTypeOwnedStringMethods() throws {
+        let value = RustTypeWithName("foo")
+        XCTAssertEqual(value.name().toString(), "foo")
+
+        value.set_name("bar")
+        XCTAssertEqual(value.name().toString(), "bar")
+
+        value.set_name(create_string("baz"))
+        XCTAssertEqual(value.name().toString(), "baz")
+    }
+
+    /// Verify that we can convert a borrowed RustString into a Swift String.
+    func testRustStringRefToString() throws {
+        let vec = RustVec<RustString>()
+        vec.push(value: create_string("hello"))
+
+        let string: RustStringRef = vec.get(index: 0)!
+        XCTAssertEqual(string.toString(), "hello")
+    }
+
     func testRustStrEqualityOperator() throws {
         XCTContext.runActivity(named: "Should be equal"){
             _ in
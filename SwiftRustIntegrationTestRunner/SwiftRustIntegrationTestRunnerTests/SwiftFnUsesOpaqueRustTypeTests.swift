@@ -22,4 +22,8 @@ class SwiftFnUsesOpaqueRustTypeTests: XCTestCase {
     func testRustFnCallsWithFnWithOwnedOpaqueArg() throws {
         test_call_swift_fn_with_owned_opaque_rust_arg()
     }
+
+    func testRustFnCallsWithFnWithMutOpaqueArg() throws {
+        test_call_swift_fn_with_mut_opaque_rust_arg()
+    }
 }
@@ -25,4 +25,16 @@ class SwiftFnReturnOpaqueSwiftTypeTests: XCTestCase {
     func testSwiftMethodReturnOpaqueSwiftType() throws {
         test_rust_calls_swift_method_reflects_owned_opaque_swift_type()
     }
+
+    func testSwiftFnTakesMutOpaqueSwiftType() throws {
+        test_rust_calls_swift_fn_with_mut_opaque_swift_type()
+    }
+
+    /// Verify that Rust can mutate a Swift class instance that it borrowed from Swift, and that
+    /// Swift still owns the instance afterwards.
+    func testRustFnTakesMutOpaqueSwiftType() throws {
+        let swiftType = ASwiftType(amount: 21)
+        rust_fn_doubles_mut_opaque_swift_type(swiftType)
+        XCTAssertEqual(swiftType.amount(), 42)
+    }
 }
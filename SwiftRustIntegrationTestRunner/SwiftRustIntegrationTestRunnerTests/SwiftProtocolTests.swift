@@ -0,0 +1,44 @@
+//
+//  SwiftProtocolTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for Swift protocols that are declared with `#[swift_bridge(protocol)]`.
+class SwiftProtocolTests: XCTestCase {
+    /// Verify that Rust can call a Swift protocol's methods on a class that conforms to it.
+    func testRustCallsProtocolMethodsOnClass() throws {
+        let counter = SwiftProtocolTestClassCounter()
+
+        XCTAssertEqual(rust_add_to_swift_protocol_test_counter(counter, vecOfAmounts([1, 2, 3])), 6)
+        XCTAssertEqual(counter.total(), 6)
+    }
+
+    /// Verify that Rust can call a Swift protocol's methods on a struct that conforms to it.
+    func testRustCallsProtocolMethodsOnStruct() throws {
+        let counter = SwiftProtocolTestDoublingCounter()
+
+        XCTAssertEqual(rust_add_to_swift_protocol_test_counter(counter, vecOfAmounts([5])), 10)
+    }
+
+    /// Verify that a protocol existential that was passed to Rust can be returned back to Swift.
+    func testReflectProtocolExistential() throws {
+        let counter = SwiftProtocolTestClassCounter()
+        counter.add(amount: 7)
+
+        let reflected = rust_reflect_swift_protocol_test_counter(counter)
+
+        XCTAssertEqual(reflected.total(), 7)
+        XCTAssertTrue((reflected as! SwiftProtocolTestClassCounter) === counter)
+    }
+
+    private func vecOfAmounts(_ amounts: [UInt32]) -> RustVec<UInt32> {
+        let vec = RustVec<UInt32>()
+        for amount in amounts {
+            vec.push(value: amount)
+        }
+        return vec
+    }
+}
@@ -0,0 +1,24 @@
+//
+//  SwiftTypePrefixTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for the `#[swift_bridge::bridge(swift_type_prefix = "...")]` attribute.
+class SwiftTypePrefixTests: XCTestCase {
+    /// Verify that we can use the prefixed Swift types.
+    func testPrefixedTypes() throws {
+        let session = PrefixTestSession(PrefixTestUser(id: 5))
+
+        XCTAssertEqual(session.user_id(), 5)
+
+        switch session.greet(PrefixTestMessage.Hello) {
+        case .Goodbye:
+            break
+        default:
+            XCTFail()
+        }
+    }
+}
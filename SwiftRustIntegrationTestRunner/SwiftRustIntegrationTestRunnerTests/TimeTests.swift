@@ -0,0 +1,37 @@
+//
+//  TimeTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for `Duration` <-> `TimeInterval` and `SystemTime` <-> `Date`.
+class TimeTests: XCTestCase {
+    /// Verify that we can pass and return a `TimeInterval`.
+    func testSwiftCallsRustDuration() throws {
+        XCTAssertEqual(rust_reflect_duration(5.25), 5.25)
+        XCTAssertEqual(rust_reflect_duration(0), 0)
+    }
+
+    /// Verify that negative intervals become zero, since a Rust `Duration` cannot be negative.
+    func testNegativeDurationIsClampedToZero() throws {
+        XCTAssertEqual(rust_reflect_duration(-3), 0)
+    }
+
+    /// Verify that we can pass and return a `Date`, including dates before the UNIX epoch.
+    func testSwiftCallsRustSystemTime() throws {
+        let date = Date(timeIntervalSince1970: 1_000_000.5)
+        XCTAssertEqual(rust_reflect_system_time(date), date)
+
+        let beforeEpoch = Date(timeIntervalSince1970: -1.5)
+        XCTAssertEqual(rust_reflect_system_time(beforeEpoch), beforeEpoch)
+
+        XCTAssertEqual(rust_make_system_time(86_400), Date(timeIntervalSince1970: 86_400))
+    }
+
+    /// Verify that Rust can call Swift functions that accept and return times.
+    func testRustCallsSwiftTime() throws {
+        test_rust_calls_swift_time()
+    }
+}
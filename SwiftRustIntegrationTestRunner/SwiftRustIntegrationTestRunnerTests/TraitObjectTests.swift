@@ -0,0 +1,48 @@
+//
+//  TraitObjectTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for Rust traits that are bridged as Swift protocols.
+class TraitObjectTests: XCTestCase {
+    /// Verify that we can call a trait's methods on a `Box<dyn Trait>` that was returned by Rust.
+    func testCallTraitObjectMethods() throws {
+        let square = rust_make_trait_object_test_square(2)
+
+        XCTAssertEqual(square.area(), 4)
+        XCTAssertEqual(square.name().toString(), "square")
+
+        square.scale(3)
+        XCTAssertEqual(square.area(), 36)
+    }
+
+    /// Verify that different implementations can be used through the generated protocol.
+    func testUseTraitObjectsThroughProtocol() throws {
+        let shapes: [TraitObjectTestShapeProtocol] = [
+            rust_make_trait_object_test_square(1),
+            rust_make_trait_object_test_circle(1),
+        ]
+
+        XCTAssertEqual(shapes.map { $0.name().toString() }, ["square", "circle"])
+        XCTAssertEqual(shapes[1].area(), Double.pi, accuracy: 0.0001)
+    }
+
+    /// Verify that we can pass a `Box<dyn Trait>` back to Rust.
+    func testPassTraitObjectToRust() throws {
+        let shape = rust_reflect_trait_object_test_shape(rust_make_trait_object_test_square(5))
+
+        XCTAssertEqual(shape.area(), 25)
+    }
+
+    /// Verify that we can pass a `Vec<Box<dyn Trait>>` to Rust.
+    func testPassVecOfTraitObjectsToRust() throws {
+        let shapes = RustVec<TraitObjectTestShape>()
+        shapes.push(value: rust_make_trait_object_test_square(2))
+        shapes.push(value: rust_make_trait_object_test_square(3))
+
+        XCTAssertEqual(rust_total_area_of_trait_object_test_shapes(shapes), 13)
+    }
+}
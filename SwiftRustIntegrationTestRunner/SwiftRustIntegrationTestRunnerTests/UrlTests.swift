@@ -0,0 +1,30 @@
+//
+//  UrlTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for `url::Url` <-> `URL`.
+class UrlTests: XCTestCase {
+    /// Verify that we can pass and return a `URL`, and that its percent-encoding is preserved.
+    func testSwiftCallsRustUrl() throws {
+        let url = URL(string: "https://example.com/a%20path/?q=caf%C3%A9&x=1#frag")!
+        let reflected = rust_reflect_url(url)
+        XCTAssertEqual(reflected, url)
+        XCTAssertEqual(reflected.absoluteString, url.absoluteString)
+    }
+
+    /// Verify that Rust decodes percent-encoded query values.
+    func testRustParsesUrl() throws {
+        let url = URL(string: "https://example.com/?q=caf%C3%A9")!
+        XCTAssertEqual(rust_url_query_value(url, "q")?.toString(), "café")
+        XCTAssertNil(rust_url_query_value(url, "missing"))
+    }
+
+    /// Verify that Rust can call Swift functions that accept and return URLs.
+    func testRustCallsSwiftUrl() throws {
+        test_rust_calls_swift_url()
+    }
+}
@@ -0,0 +1,27 @@
+//
+//  UuidTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for `uuid::Uuid` <-> `UUID`.
+class UuidTests: XCTestCase {
+    /// Verify that we can pass and return a `UUID`.
+    func testSwiftCallsRustUuid() throws {
+        let uuid = UUID()
+        XCTAssertEqual(rust_reflect_uuid(uuid), uuid)
+    }
+
+    /// Verify that the bytes keep their order, so that both languages see the same UUID.
+    func testUuidByteOrder() throws {
+        let uuid = UUID(uuidString: "01234567-89AB-CDEF-FEDC-BA9876543210")!
+        XCTAssertEqual(rust_uuid_to_string(uuid).toString(), uuid.uuidString)
+    }
+
+    /// Verify that Rust can call Swift functions that accept and return UUIDs.
+    func testRustCallsSwiftUuid() throws {
+        test_rust_calls_swift_uuid()
+    }
+}
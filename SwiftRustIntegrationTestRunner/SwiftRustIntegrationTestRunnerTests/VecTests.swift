@@ -36,12 +36,28 @@ class VecTests: XCTestCase {
         vec.push(value: 222)
         XCTAssertEqual(vec.get(index: 1), 222)
     }
+    func testRustVecU8GetMut() throws {
+        let vec = RustVec<UInt8>()
+        vec.push(value: 111)
+        vec.push(value: 222)
+        XCTAssertEqual(vec.get_mut(index: 1), 222)
+        XCTAssertNil(vec.get_mut(index: 2))
+    }
     func testRustVecU8AsPtr() throws {
         let vec = RustVec<UInt8>()
         vec.push(value: 10)
         let ptr = vec.as_ptr()
         XCTAssertEqual(ptr.pointee, 10)
     }
+    func testRustVecF64AsBufferPointer() throws {
+        let vec = RustVec<Double>()
+        vec.push(value: 1.5)
+        vec.push(value: 2.5)
+
+        let buffer = vec.as_buffer_pointer()
+        XCTAssertEqual(buffer.count, 2)
+        XCTAssertEqual(Array(buffer), [1.5, 2.5])
+    }
     func testRustVecU8Iterator() throws {
         let vec = RustVec<UInt8>()
         vec.push(value: 111)
@@ -87,6 +103,32 @@ class VecTests: XCTestCase {
         XCTAssertEqual(reflected.get(index: 0)!.text().toString(), "hello world")
     }
     
+    /// Verify that a Rust method can return a Vec<T> of opaque Rust types.
+    func testRustMethodReturnsVecOfOpaqueRustType() throws {
+        let texts = RustVec<RustString>()
+        texts.push(value: "first".intoRustString())
+        texts.push(value: "second".intoRustString())
+
+        let items = ARustTypeWithItems(texts).items()
+        XCTAssertEqual(items.len(), 2)
+        XCTAssertEqual(items.get(index: 0)!.text().toString(), "first")
+        XCTAssertEqual(items.get(index: 1)!.text().toString(), "second")
+    }
+
+    /// Verify that a Vec<String> can be used as an argument and return type for extern "Rust"
+    /// functions.
+    func testReflectVecOfString() throws {
+        let vec = RustVec<RustString>()
+        vec.push(value: "hello".intoRustString())
+        vec.push(value: "world".intoRustString())
+
+        let reflected = rust_reflect_vec_string(vec)
+        XCTAssertEqual(reflected.len(), 2)
+        XCTAssertEqual(reflected.get(index: 0)!.toString(), "hello")
+        XCTAssertEqual(reflected.pop()!.toString(), "world")
+        XCTAssertEqual(reflected.len(), 1)
+    }
+
     /// Verify that a Vec<T> of transparent enums can be used as an argument and return
     /// type for extern "Rust" functions.
     func testReflectVecOfTransparentEnum() throws {
@@ -124,6 +166,31 @@ class VecTests: XCTestCase {
         XCTAssertEqual(RustVec<Double>().len(), 0);
     }
 
+    /// Verify that we can convert between `Data` and `RustVec<UInt8>`, and that `intoData`
+    /// doesn't copy the Rust vector's bytes.
+    func testRustVecU8IntoData() throws {
+        let bytes = Data((0..<100_000).map { UInt8($0 % 256) })
+
+        let vec = rust_reflect_vec_u8(bytes.intoRustVec())
+        XCTAssertEqual(vec.len(), bytes.count)
+
+        let ptr = UnsafeRawPointer(vec.as_ptr())
+        let data = vec.intoData()
+        XCTAssertEqual(data, bytes)
+        data.withUnsafeBytes { XCTAssertEqual($0.baseAddress, ptr) }
+
+        XCTAssertEqual(Data().intoRustVec().len(), 0)
+        XCTAssertEqual(RustVec<UInt8>().intoData(), Data())
+    }
+
+    /// Verify that Swift can lend a `Data`'s bytes to a Rust function that takes a `&[u8]`.
+    func testDataAsRustSlice() throws {
+        let bytes = Data([1, 2, 3, 250])
+
+        let sum = bytes.withUnsafeBytes { rust_sum_bytes($0.bindMemory(to: UInt8.self)) }
+        XCTAssertEqual(sum, 256)
+    }
+
     /// Verify that Rust can pass `RustVec`s to and receive `RustVec`s from Swift.
     func testRustCallsSwiftRustVecFunctions() {
         run_vec_tests()
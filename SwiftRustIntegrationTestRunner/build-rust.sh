@@ -17,12 +17,18 @@ fi
 
 cd "$PROJECT_DIR"
 
+# For example `CARGO_FEATURES=small-string-ffi` to benchmark the tests with inline strings.
+FEATURES=()
+if [[ -n "${CARGO_FEATURES:-}" ]]; then
+    FEATURES=(--features "$CARGO_FEATURES")
+fi
+
 if [[ $CONFIGURATION == "Release" ]]; then
     echo "BUIlDING FOR RELEASE"
     
-    cargo build --release --manifest-path ../crates/swift-integration-tests/Cargo.toml
+    cargo build --release --manifest-path ../crates/swift-integration-tests/Cargo.toml "${FEATURES[@]}"
 else
     echo "BUIlDING FOR DEBUG"
 
-    cargo build --manifest-path ../crates/swift-integration-tests/Cargo.toml
+    cargo build --manifest-path ../crates/swift-integration-tests/Cargo.toml "${FEATURES[@]}"
 fi
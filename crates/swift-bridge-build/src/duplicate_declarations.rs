@@ -0,0 +1,53 @@
+use crate::source_location;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use swift_bridge_ir::{GeneratedDeclaration, GeneratedDeclarationKind};
+
+/// Panic if two bridge modules declare the same Swift type or symbol, pointing at both of the
+/// declarations.
+///
+/// Otherwise the mistake would only show up later on as a Swift compiler error about a
+/// redeclared type, or as a duplicate symbol error from the linker.
+pub(crate) fn assert_no_duplicate_declarations(declarations: &[(PathBuf, GeneratedDeclaration)]) {
+    let mut locations: HashMap<(GeneratedDeclarationKind, &str), Vec<String>> = HashMap::new();
+    let mut order = vec![];
+
+    for (rust_file, declaration) in declarations {
+        let key = (declaration.kind, declaration.name.as_str());
+        let declared_at = locations.entry(key).or_insert_with(|| {
+            order.push(key);
+            vec![]
+        });
+        declared_at.push(source_location(rust_file, declaration.span));
+    }
+
+    let mut duplicates = "".to_string();
+    for key in order {
+        let declared_at = &locations[&key];
+        if declared_at.len() < 2 {
+            continue;
+        }
+
+        let (kind, name) = key;
+        let kind = match kind {
+            GeneratedDeclarationKind::SwiftType => "Swift type",
+            GeneratedDeclarationKind::Symbol => "symbol",
+        };
+        duplicates += &format!("\nThe {} `{}` is declared more than once:\n", kind, name);
+        for location in declared_at {
+            duplicates += &format!("  {}\n", location);
+        }
+    }
+
+    if !duplicates.is_empty() {
+        panic!(
+            r#"
+Bridge modules declare the same name more than once. Rename one of the declarations using
+`#[swift_bridge(swift_name = "...")]`, or mark it as `#[swift_bridge(already_declared)]` if it
+refers to a type that another bridge module declares. The symbols of built-in types such as
+`HashMap<K, V>` include the name of the bridge module, so for those rename one of the modules.
+{}"#,
+            duplicates
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{normalize_whitespace, GeneratedCode};
+use std::ffi::OsString;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// The code that a single bridge module expands to.
+#[derive(Debug)]
+pub(crate) struct ModuleExpansion {
+    /// The file that the bridge module was declared in.
+    pub rust_file: PathBuf,
+    /// The bridge module's name, such as `ffi`.
+    pub module_name: String,
+    /// The Rust tokens that the `#[swift_bridge::bridge]` macro expands the module to.
+    pub rust: String,
+    /// The Swift code generated for the module.
+    pub swift: String,
+    /// The C header generated for the module.
+    pub c_header: String,
+}
+
+impl GeneratedCode {
+    /// Write the Rust tokens that the `#[swift_bridge::bridge]` macro expands each bridge module
+    /// to, along with the Swift code and C header generated for the module, to the `out_dir`.
+    ///
+    /// This is meant for inspecting what a bridge module does when debugging it. The files are
+    /// placed in a directory that mirrors the bridge module's source file, such as
+    /// `out_dir/src/lib.rs/ffi.rs`, `out_dir/src/lib.rs/ffi.swift` and `out_dir/src/lib.rs/ffi.h`.
+    ///
+    /// The Rust code is formatted using `rustfmt` when it is installed.
+    pub fn write_expansions(&self, out_dir: impl AsRef<Path>) {
+        for expansion in &self.expansions {
+            let dir = out_dir.as_ref().join(relative_path(&expansion.rust_file));
+            std::fs::create_dir_all(&dir).unwrap();
+
+            let file =
+                |extension: &str| dir.join(format!("{}.{}", expansion.module_name, extension));
+            std::fs::write(file("rs"), format_rust(&expansion.rust)).unwrap();
+            std::fs::write(file("swift"), normalize_whitespace(&expansion.swift)).unwrap();
+            std::fs::write(file("h"), &expansion.c_header).unwrap();
+        }
+    }
+}
+
+/// The path with its root and any `..` escaped, so that it can be joined onto the output
+/// directory.
+///
+/// `../src/lib.rs` becomes `@parent/src/lib.rs` and `/src/lib.rs` becomes `@root/src/lib.rs`.
+/// Components that already start with an `@` get another one, so different paths never end up
+/// being written to the same place.
+pub(crate) fn relative_path(path: &Path) -> PathBuf {
+    path.components()
+        .filter_map(|component| match component {
+            Component::Normal(part) if part.to_string_lossy().starts_with('@') => {
+                let mut escaped = OsString::from("@");
+                escaped.push(part);
+                Some(escaped)
+            }
+            Component::Normal(part) => Some(part.to_os_string()),
+            Component::CurDir => None,
+            Component::ParentDir => Some(OsString::from("@parent")),
+            Component::RootDir => Some(OsString::from("@root")),
+            // `C:` or `\\server\share` on Windows.
+            Component::Prefix(prefix) => {
+                let prefix: String = prefix
+                    .as_os_str()
+                    .to_string_lossy()
+                    .chars()
+                    .map(|c| if c.is_alphanumeric() { c } else { '_' })
+                    .collect();
+                Some(OsString::from(format!("@prefix_{}", prefix)))
+            }
+        })
+        .collect()
+}
+
+/// Format the Rust code using `rustfmt`, falling back to the unformatted code if `rustfmt` isn't
+/// available or fails.
+fn format_rust(rust: &str) -> String {
+    let child = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(_) => return rust.to_string(),
+    };
+
+    if child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(rust.as_bytes())
+        .is_err()
+    {
+        return rust.to_string();
+    }
+
+    match child.wait_with_output() {
+        Ok(output) if output.status.success() => {
+            String::from_utf8(output.stdout).unwrap_or_else(|_| rust.to_string())
+        }
+        _ => rust.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we escape the components that can't be joined onto the output directory.
+    #[test]
+    fn escapes_parent_and_root_components() {
+        let relative = |path: &str| relative_path(Path::new(path));
+
+        assert_eq!(relative("src/lib.rs"), Path::new("src/lib.rs"));
+        assert_eq!(relative("./src/lib.rs"), Path::new("src/lib.rs"));
+        assert_eq!(relative("../src/lib.rs"), Path::new("@parent/src/lib.rs"));
+        assert_eq!(relative("/src/lib.rs"), Path::new("@root/src/lib.rs"));
+        assert_eq!(relative("@root/src/lib.rs"), Path::new("@@root/src/lib.rs"));
+    }
+
+    /// Verify that paths that only differ by their `..` or root don't end up in the same place.
+    #[test]
+    fn different_paths_stay_different() {
+        let paths = [
+            "src/lib.rs",
+            "../src/lib.rs",
+            "../../src/lib.rs",
+            "/src/lib.rs",
+            "@parent/src/lib.rs",
+            "@root/src/lib.rs",
+        ];
+        let relative: std::collections::HashSet<PathBuf> = paths
+            .iter()
+            .map(|path| relative_path(Path::new(path)))
+            .collect();
+
+        assert_eq!(relative.len(), paths.len());
+    }
+}
@@ -1,10 +1,28 @@
 use crate::generate_core::boxed_fn_support::{
     C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN, SWIFT_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN,
+    SWIFT_CLOSURE_SUPPORT,
 };
+use crate::generate_core::cow_str_support::{C_COW_STR_SUPPORT, SWIFT_COW_STR_SUPPORT};
+use crate::generate_core::decimal_support::{C_DECIMAL_SUPPORT, SWIFT_DECIMAL_SUPPORT};
+use crate::generate_core::dispatch_queue_support::SWIFT_DISPATCH_QUEUE_SUPPORT;
+use crate::generate_core::int128_support::{C_INT128_SUPPORT, SWIFT_INT128_SUPPORT};
+use crate::generate_core::main_actor_support::SWIFT_MAIN_ACTOR_SUPPORT;
+use crate::generate_core::ns_error_support::{C_NS_ERROR_SUPPORT, SWIFT_NS_ERROR_SUPPORT};
+use crate::generate_core::observable_support::SWIFT_OBSERVABLE_SUPPORT;
 use crate::generate_core::option_support::{
     swift_option_primitive_support, C_OPTION_PRIMITIVE_SUPPORT,
 };
+use crate::generate_core::panic_support::{C_PANIC_SUPPORT, SWIFT_PANIC_SUPPORT};
+use crate::generate_core::pointer_support::SWIFT_POINTER_SUPPORT;
 use crate::generate_core::result_support::{C_RESULT_SUPPORT, SWIFT_RUST_RESULT};
+use crate::generate_core::small_string_support::{
+    c_rust_string_new_with_str, swift_rust_string_new_with_str, C_SMALL_STRING_SUPPORT,
+};
+use crate::generate_core::stream_support::{C_STREAM_SUPPORT, SWIFT_STREAM_SUPPORT};
+use crate::generate_core::time_support::{C_TIME_SUPPORT, SWIFT_TIME_SUPPORT};
+use crate::generate_core::url_support::SWIFT_URL_SUPPORT;
+use crate::generate_core::uuid_support::{C_UUID_SUPPORT, SWIFT_UUID_SUPPORT};
+use crate::write_if_changed;
 use std::path::Path;
 
 const RUST_STRING_SWIFT: &'static str = include_str!("./generate_core/rust_string.swift");
@@ -12,35 +30,84 @@ const RUST_STRING_C: &'static str = include_str!("./generate_core/rust_string.c.
 
 const STRING_SWIFT: &'static str = include_str!("./generate_core/string.swift");
 const RUST_VEC_SWIFT: &'static str = include_str!("./generate_core/rust_vec.swift");
+const RUST_MAP_SWIFT: &'static str = include_str!("./generate_core/rust_map.swift");
+const RUST_SET_SWIFT: &'static str = include_str!("./generate_core/rust_set.swift");
+const RUST_SLICE_SWIFT: &'static str = include_str!("./generate_core/rust_slice.swift");
 
 mod boxed_fn_support;
+mod cow_str_support;
+mod decimal_support;
+mod dispatch_queue_support;
+mod int128_support;
+mod main_actor_support;
+mod ns_error_support;
+mod observable_support;
 mod option_support;
+mod panic_support;
+mod pointer_support;
 mod result_support;
+mod small_string_support;
+mod stream_support;
+mod time_support;
+mod url_support;
+mod uuid_support;
 
 pub(super) fn write_core_swift_and_c(out_dir: &Path) {
     let core_swift_out = out_dir.join("SwiftBridgeCore.swift");
     let mut swift = core_swift();
     swift += "\n";
     swift += &RUST_STRING_SWIFT;
+    swift += &swift_rust_string_new_with_str();
     swift += "\n";
     swift += &SWIFT_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN;
     swift += "\n";
+    swift += SWIFT_CLOSURE_SUPPORT;
+    swift += "\n";
     swift += &SWIFT_RUST_RESULT;
     swift += "\n";
     swift += &swift_option_primitive_support();
+    swift += "\n";
+    swift += SWIFT_INT128_SUPPORT;
+    swift += "\n";
+    swift += SWIFT_TIME_SUPPORT;
+    swift += "\n";
+    swift += SWIFT_UUID_SUPPORT;
+    swift += "\n";
+    swift += SWIFT_DECIMAL_SUPPORT;
+    swift += "\n";
+    swift += SWIFT_URL_SUPPORT;
+    swift += "\n";
+    swift += SWIFT_NS_ERROR_SUPPORT;
+    swift += "\n";
+    swift += SWIFT_COW_STR_SUPPORT;
+    swift += "\n";
+    swift += SWIFT_PANIC_SUPPORT;
+    swift += "\n";
+    swift += SWIFT_POINTER_SUPPORT;
+    swift += "\n";
+    swift += SWIFT_STREAM_SUPPORT;
+    swift += "\n";
+    swift += SWIFT_MAIN_ACTOR_SUPPORT;
+    swift += "\n";
+    swift += SWIFT_DISPATCH_QUEUE_SUPPORT;
+    swift += "\n";
+    swift += SWIFT_OBSERVABLE_SUPPORT;
 
-    std::fs::write(core_swift_out, swift).unwrap();
+    write_if_changed(core_swift_out, swift);
 
     let core_c_header_out = out_dir.join("SwiftBridgeCore.h");
     let mut c_header = core_c_header().to_string();
     c_header += "\n";
     c_header += &RUST_STRING_C;
+    c_header += &c_rust_string_new_with_str();
     c_header += "\n";
     c_header += &C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN;
     c_header += "\n";
     c_header += &C_RESULT_SUPPORT;
+    c_header += "\n";
+    c_header += C_PANIC_SUPPORT;
 
-    std::fs::write(core_c_header_out, c_header).unwrap();
+    write_if_changed(core_c_header_out, c_header);
 }
 
 fn core_swift() -> String {
@@ -48,6 +115,9 @@ fn core_swift() -> String {
 
     core_swift += STRING_SWIFT;
     core_swift += RUST_VEC_SWIFT;
+    core_swift += RUST_MAP_SWIFT;
+    core_swift += RUST_SET_SWIFT;
+    core_swift += RUST_SLICE_SWIFT;
 
     for (swift_ty, rust_ty) in vec![
         ("UInt8", "u8"),
@@ -86,6 +156,14 @@ void* __swift_bridge__null_pointer(void);
 "#
     .to_string();
     header += &C_OPTION_PRIMITIVE_SUPPORT;
+    header += C_SMALL_STRING_SUPPORT;
+    header += C_INT128_SUPPORT;
+    header += C_TIME_SUPPORT;
+    header += C_UUID_SUPPORT;
+    header += C_DECIMAL_SUPPORT;
+    header += C_NS_ERROR_SUPPORT;
+    header += C_COW_STR_SUPPORT;
+    header += C_STREAM_SUPPORT;
 
     for (rust_ty, c_ty) in vec![
         ("u8", "uint8_t"),
@@ -107,6 +185,7 @@ void* __swift_bridge__null_pointer(void);
     ] {
         header += &vec_of_primitive_headers(rust_ty, c_ty);
     }
+    header += "void* __swift_bridge__$Vec_u8$from_bytes(uint8_t const * bytes, uintptr_t len);\n";
 
     header
 }
@@ -31,3 +31,18 @@ pub const C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN: &'static str = r#"
 void __swift_bridge__$call_boxed_fn_once_no_args_no_return(void* boxed_fnonce);
 void __swift_bridge__$free_boxed_fn_once_no_args_no_return(void* boxed_fnonce);
 "#;
+
+/// Holds a Swift closure that was passed to Rust as a `Box<dyn Fn(A, B) -> C>` or
+/// `Box<dyn FnOnce(A, B) -> C>`.
+///
+/// Rust holds onto a retained pointer to an instance of this class and releases it when the
+/// boxed function is dropped.
+pub const SWIFT_CLOSURE_SUPPORT: &str = r#"
+public class __private__SwiftClosure<F> {
+    let closure: F
+
+    init(_ closure: F) {
+        self.closure = closure
+    }
+}
+"#;
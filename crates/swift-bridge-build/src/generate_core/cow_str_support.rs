@@ -0,0 +1,43 @@
+pub(super) const C_COW_STR_SUPPORT: &str = r#"
+typedef struct __private__FfiCowStr { bool is_owned; void* owned; struct RustStr borrowed; } __private__FfiCowStr;
+"#;
+
+pub(super) const SWIFT_COW_STR_SUPPORT: &str = r#"
+/// A Rust `Cow<'static, str>`.
+///
+/// A borrowed string points directly into Rust's static memory, while an owned string is a
+/// `RustString` that Swift owns.
+public enum RustCowStr {
+    case borrowed(RustStr)
+    case owned(RustString)
+}
+extension RustCowStr {
+    public func toString() -> String {
+        switch self {
+        case .borrowed(let str):
+            return str.toString()
+        case .owned(let string):
+            return string.toString()
+        }
+    }
+
+    public var isOwned: Bool {
+        switch self {
+        case .borrowed:
+            return false
+        case .owned:
+            return true
+        }
+    }
+}
+
+extension __private__FfiCowStr {
+    func intoSwiftRepr() -> RustCowStr {
+        if is_owned {
+            return .owned(RustString(ptr: owned!))
+        } else {
+            return .borrowed(borrowed)
+        }
+    }
+}
+"#;
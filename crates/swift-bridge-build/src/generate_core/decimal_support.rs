@@ -0,0 +1,28 @@
+pub(super) const C_DECIMAL_SUPPORT: &str = r#"
+typedef struct __private__FfiDecimal { uint16_t mantissa[8]; int32_t exponent; bool is_negative; } __private__FfiDecimal;
+"#;
+
+// C arrays are imported into Swift as tuples, so `mantissa` has the same type as Foundation's
+// `Decimal._mantissa`.
+// A zero with `_isNegative` set is Foundation's NaN, so a negative zero becomes a positive zero.
+pub(super) const SWIFT_DECIMAL_SUPPORT: &str = r#"
+extension __private__FfiDecimal {
+    init(_ decimal: Decimal) {
+        precondition(!decimal.isNaN, "A NaN Decimal can't be passed to Rust")
+        self.init(mantissa: decimal._mantissa, exponent: decimal._exponent, is_negative: decimal._isNegative != 0)
+    }
+
+    func intoSwiftRepr() -> Decimal {
+        let words = [mantissa.0, mantissa.1, mantissa.2, mantissa.3, mantissa.4, mantissa.5, mantissa.6, mantissa.7]
+        let length = (words.lastIndex(where: { $0 != 0 }) ?? -1) + 1
+        return Decimal(
+            _exponent: exponent,
+            _length: UInt32(length),
+            _isNegative: is_negative && length > 0 ? 1 : 0,
+            _isCompact: 0,
+            _reserved: 0,
+            _mantissa: mantissa
+        )
+    }
+}
+"#;
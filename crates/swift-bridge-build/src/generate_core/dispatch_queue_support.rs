@@ -0,0 +1,19 @@
+pub(super) const SWIFT_DISPATCH_QUEUE_SUPPORT: &str = r#"
+/// Marks the queues that `#[swift_bridge(dispatch_queue = "...")]` functions get called on, so
+/// that we can tell when a call is already running on its queue.
+private let __swift_bridge__dispatchQueueKey = DispatchSpecificKey<ObjectIdentifier>()
+
+/// Call a `#[swift_bridge(dispatch_queue = "...")]` function on its queue and wait for it to
+/// return.
+///
+/// Calls that are already running on the queue run right away, since waiting on a serial queue
+/// from the queue itself would deadlock.
+func __swift_bridge__callOnQueue<T>(_ queue: DispatchQueue, _ call: () throws -> T) rethrows -> T {
+    let id = ObjectIdentifier(queue)
+    queue.setSpecific(key: __swift_bridge__dispatchQueueKey, value: id)
+    if DispatchQueue.getSpecific(key: __swift_bridge__dispatchQueueKey) == id {
+        return try call()
+    }
+    return try queue.sync(execute: call)
+}
+"#;
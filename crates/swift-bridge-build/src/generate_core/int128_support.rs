@@ -0,0 +1,64 @@
+pub(super) const C_INT128_SUPPORT: &str = r#"
+typedef struct RustUInt128 { uint64_t low; uint64_t high; } RustUInt128;
+typedef struct RustInt128 { uint64_t low; int64_t high; } RustInt128;
+"#;
+
+pub(super) const SWIFT_INT128_SUPPORT: &str = r#"
+extension RustUInt128 {
+    public init(_ value: UInt64) {
+        self.init(low: value, high: 0)
+    }
+}
+extension RustUInt128: Equatable {
+    public static func == (lhs: RustUInt128, rhs: RustUInt128) -> Bool {
+        lhs.low == rhs.low && lhs.high == rhs.high
+    }
+}
+extension RustUInt128: Hashable {
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(low)
+        hasher.combine(high)
+    }
+}
+
+extension RustInt128 {
+    public init(_ value: Int64) {
+        self.init(low: UInt64(bitPattern: value), high: value < 0 ? -1 : 0)
+    }
+}
+extension RustInt128: Equatable {
+    public static func == (lhs: RustInt128, rhs: RustInt128) -> Bool {
+        lhs.low == rhs.low && lhs.high == rhs.high
+    }
+}
+extension RustInt128: Hashable {
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(low)
+        hasher.combine(high)
+    }
+}
+
+#if compiler(>=6.0)
+@available(macOS 15.0, iOS 18.0, watchOS 11.0, tvOS 18.0, visionOS 2.0, *)
+extension RustUInt128 {
+    public init(_ value: UInt128) {
+        self.init(low: UInt64(truncatingIfNeeded: value), high: UInt64(truncatingIfNeeded: value >> 64))
+    }
+
+    public var value: UInt128 {
+        UInt128(high) << 64 | UInt128(low)
+    }
+}
+
+@available(macOS 15.0, iOS 18.0, watchOS 11.0, tvOS 18.0, visionOS 2.0, *)
+extension RustInt128 {
+    public init(_ value: Int128) {
+        self.init(low: UInt64(truncatingIfNeeded: value), high: Int64(truncatingIfNeeded: value >> 64))
+    }
+
+    public var value: Int128 {
+        Int128(high) << 64 | Int128(low)
+    }
+}
+#endif
+"#;
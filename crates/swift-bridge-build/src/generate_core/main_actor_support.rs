@@ -0,0 +1,18 @@
+pub(super) const SWIFT_MAIN_ACTOR_SUPPORT: &str = r#"
+/// Call a `#[swift_bridge(main_actor)]` function on the main actor and wait for it to return.
+///
+/// Rust can call from any thread. Calls that are already on the main thread run right away,
+/// since waiting on the main queue from the main thread would deadlock.
+@available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+func __swift_bridge__callOnMainActor<T>(_ call: @MainActor () -> T) -> T {
+    withoutActuallyEscaping(call) { call in
+        // The main actor runs on the main thread, so once we are on the main thread it's safe to
+        // call the closure as if it weren't isolated to the main actor.
+        let call = unsafeBitCast(call, to: (() -> T).self)
+        if Thread.isMainThread {
+            return call()
+        }
+        return DispatchQueue.main.sync(execute: call)
+    }
+}
+"#;
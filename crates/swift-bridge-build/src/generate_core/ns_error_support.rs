@@ -0,0 +1,58 @@
+pub(super) const C_NS_ERROR_SUPPORT: &str = r#"
+void* __swift_bridge__$NsError$new(void* domain, intptr_t code);
+void __swift_bridge__$NsError$insert_user_info(void* ptr, void* key, void* value);
+void* __swift_bridge__$NsError$domain(void* ptr);
+intptr_t __swift_bridge__$NsError$code(void* ptr);
+uintptr_t __swift_bridge__$NsError$user_info_len(void* ptr);
+void* __swift_bridge__$NsError$user_info_key(void* ptr, uintptr_t index);
+void* __swift_bridge__$NsError$user_info_value(void* ptr, uintptr_t index);
+void __swift_bridge__$NsError$free(void* ptr);
+"#;
+
+pub(super) const SWIFT_NS_ERROR_SUPPORT: &str = r#"
+/// Create a Rust `NsError` with the same domain, code and user info as the `NSError`.
+///
+/// User info values that aren't strings are converted using `String(describing:)`.
+func __swift_bridge__NSErrorIntoRust(_ error: NSError) -> UnsafeMutableRawPointer {
+    func intoRust(_ string: String) -> UnsafeMutableRawPointer {
+        let rustString = string.intoRustString()
+        rustString.isOwned = false
+        return rustString.ptr
+    }
+
+    let ptr = __swift_bridge__$NsError$new(intoRust(error.domain), error.code)!
+    var userInfo = error.userInfo
+    userInfo[NSLocalizedDescriptionKey] = error.localizedDescription
+    for (key, value) in userInfo {
+        let value = value as? String ?? String(describing: value)
+        __swift_bridge__$NsError$insert_user_info(ptr, intoRust(key), intoRust(value))
+    }
+    return ptr
+}
+
+/// Create an `NSError` out of a Rust `NsError`, freeing the `NsError`.
+func __swift_bridge__NSErrorFromRust(_ ptr: UnsafeMutableRawPointer) -> NSError {
+    defer { __swift_bridge__$NsError$free(ptr) }
+
+    var userInfo: [String: Any] = [:]
+    for index in 0..<__swift_bridge__$NsError$user_info_len(ptr) {
+        let key = RustString(ptr: __swift_bridge__$NsError$user_info_key(ptr, index)).toString()
+        let value = RustString(ptr: __swift_bridge__$NsError$user_info_value(ptr, index)).toString()
+        userInfo[key] = value
+    }
+    return NSError(
+        domain: RustString(ptr: __swift_bridge__$NsError$domain(ptr)).toString(),
+        code: __swift_bridge__$NsError$code(ptr),
+        userInfo: userInfo
+    )
+}
+
+// Used by `#[swift_bridge(catch_errors)]` functions that return a `Result<T, NsError>`.
+func __swift_bridge__catchSwiftNSError<T>(_ body: () throws -> T) -> RustResult<T, NSError> {
+    do {
+        return .Ok(try body())
+    } catch {
+        return .Err(error as NSError)
+    }
+}
+"#;
@@ -0,0 +1,47 @@
+pub(super) const SWIFT_OBSERVABLE_SUPPORT: &str = r#"
+/// Implemented by the `ObservableObject`s of `#[swift_bridge(observable)]` types.
+protocol __private__RefreshableObservableObject: AnyObject {
+    /// Re-read the published properties from Rust.
+    func refresh()
+}
+
+/// Listens to the `ChangeNotifier` of a `#[swift_bridge(observable)]` type on behalf of its
+/// `ObservableObject`.
+///
+/// Rust keeps the observer retained until the `ObservableObject` unsubscribes it in its `deinit`,
+/// or until the Rust value is dropped. Rust can notify the observer from another thread while the
+/// `ObservableObject` is being deinitialized, so the observer only holds a weak reference to it.
+final class __private__ObservableObjectObserver {
+    private weak var object: __private__RefreshableObservableObject?
+
+    private init(_ object: __private__RefreshableObservableObject) {
+        self.object = object
+    }
+
+    /// Hand a new observer of the `object` to Rust, along with the functions that Rust calls when
+    /// the value changes and when it is done with the observer.
+    ///
+    /// Returns the observer, which the `object` passes back to Rust to unsubscribe.
+    static func observe(
+        _ object: __private__RefreshableObservableObject,
+        _ subscribe: (
+            UnsafeMutableRawPointer,
+            @escaping @convention(c) (UnsafeMutableRawPointer?) -> Void,
+            @escaping @convention(c) (UnsafeMutableRawPointer?) -> Void
+        ) -> Void
+    ) -> UnsafeMutableRawPointer {
+        let observer = Unmanaged.passRetained(__private__ObservableObjectObserver(object)).toOpaque()
+        subscribe(
+            observer,
+            { ptr in
+                let observer = Unmanaged<__private__ObservableObjectObserver>.fromOpaque(ptr!).takeUnretainedValue()
+                // `ObservableObject`s publish their changes on the main queue. Rust can notify
+                // from any thread.
+                DispatchQueue.main.async { observer.object?.refresh() }
+            },
+            { ptr in Unmanaged<AnyObject>.fromOpaque(ptr!).release() }
+        )
+        return observer
+    }
+}
+"#;
@@ -0,0 +1,33 @@
+pub(super) const C_PANIC_SUPPORT: &str = r#"
+void* __swift_bridge__$take_panic_message(void);
+"#;
+
+pub(super) const SWIFT_PANIC_SUPPORT: &str = r#"
+/// Thrown when a Rust function from a `#[swift_bridge::bridge(catch_panics = "throw")]` module
+/// panics.
+public struct RustPanic: Error {
+    /// The message that the Rust function panicked with.
+    public let message: String
+}
+
+func __swift_bridge__takeRustPanicMessage() -> String? {
+    guard let message = __swift_bridge__$take_panic_message() else {
+        return nil
+    }
+    return RustString(ptr: message).toString()
+}
+
+func __swift_bridge__rethrowRustPanic<T>(_ value: T) throws -> T {
+    if let message = __swift_bridge__takeRustPanicMessage() {
+        throw RustPanic(message: message)
+    }
+    return value
+}
+
+func __swift_bridge__fatalErrorOnRustPanic<T>(_ value: T) -> T {
+    if let message = __swift_bridge__takeRustPanicMessage() {
+        fatalError(message)
+    }
+    return value
+}
+"#;
@@ -0,0 +1,6 @@
+pub(super) const SWIFT_POINTER_SUPPORT: &str = r#"
+// Generated classes overwrite their pointer with this address when they are deinitialized in debug
+// builds, so that Rust can recognize a use after free.
+// Must match `swift_bridge::pointer_support::FREED_POINTER`.
+let __swift_bridge__freedPointer = UnsafeMutableRawPointer(bitPattern: 0xdeadbeef)!
+"#;
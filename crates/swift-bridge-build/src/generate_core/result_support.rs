@@ -33,6 +33,15 @@ extension RustResult {
         }
     }
 }
+
+// Used by `#[swift_bridge(catch_errors)]` functions to hand the errors that they throw to Rust.
+func __swift_bridge__catchSwiftError<T>(_ body: () throws -> T) -> RustResult<T, String> {
+    do {
+        return .Ok(try body())
+    } catch {
+        return .Err(String(describing: error))
+    }
+}
 "#;
 
 pub const C_RESULT_SUPPORT: &'static str = r#"
@@ -0,0 +1,18 @@
+public class RustMap<K, V> {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+    let freeFn: (UnsafeMutableRawPointer) -> ()
+
+    /// The methods for a particular `RustMap<K, V>`, such as `len()`, `get()` and `insert()`,
+    /// are generated alongside the bridge module that uses the corresponding `HashMap<K, V>`.
+    public init(ptr: UnsafeMutableRawPointer, freeFn: @escaping (UnsafeMutableRawPointer) -> ()) {
+        self.ptr = ptr
+        self.freeFn = freeFn
+    }
+
+    deinit {
+        if isOwned {
+            freeFn(ptr)
+        }
+    }
+}
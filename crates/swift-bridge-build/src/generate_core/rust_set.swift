@@ -0,0 +1,31 @@
+public class RustSet<T: Vectorizable> {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+    let freeFn: (UnsafeMutableRawPointer) -> ()
+    let valuesFn: (UnsafeMutableRawPointer) -> UnsafeMutableRawPointer
+
+    /// The methods for a particular `RustSet<T>`, such as `len()`, `contains()` and `insert()`,
+    /// are generated alongside the bridge module that uses the corresponding `HashSet<T>`.
+    public init(
+        ptr: UnsafeMutableRawPointer,
+        freeFn: @escaping (UnsafeMutableRawPointer) -> (),
+        valuesFn: @escaping (UnsafeMutableRawPointer) -> UnsafeMutableRawPointer
+    ) {
+        self.ptr = ptr
+        self.freeFn = freeFn
+        self.valuesFn = valuesFn
+    }
+
+    deinit {
+        if isOwned {
+            freeFn(ptr)
+        }
+    }
+}
+
+extension RustSet: Sequence {
+    /// Iterates over a copy of the set's values, in no particular order.
+    public func makeIterator() -> RustVecIterator<T> {
+        RustVec<T>(ptr: valuesFn(ptr)).makeIterator()
+    }
+}
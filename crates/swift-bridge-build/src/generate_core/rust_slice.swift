@@ -0,0 +1,51 @@
+/// A read-only view into a Rust slice of opaque Rust types, such as a `&[SomeType]`.
+///
+/// The elements are borrowed from Rust, so the view must not be used after the Rust data that it
+/// points to has been mutated or dropped.
+public struct RustSlice<T: RustSliceElement> {
+    let slice: __private__FfiSlice
+
+    public init(_ slice: __private__FfiSlice) {
+        self.slice = slice
+    }
+
+    func toFfiSlice() -> __private__FfiSlice {
+        slice
+    }
+}
+
+extension RustSlice: RandomAccessCollection {
+    public typealias Index = Int
+
+    public var startIndex: Int {
+        0
+    }
+
+    public var endIndex: Int {
+        Int(slice.len)
+    }
+
+    public subscript(position: Int) -> T.SelfRef {
+        precondition(position >= 0 && position < endIndex, "Index out of bounds")
+        return T.sliceOfSelfGet(start: slice.start, index: UInt(position))
+    }
+}
+
+/// Implemented for opaque Rust types that are used within a `&[T]`.
+public protocol RustSliceElement {
+    associatedtype SelfRef
+
+    static func sliceOfSelfGet(start: UnsafeMutableRawPointer, index: UInt) -> SelfRef
+}
+
+extension RustVec where T: RustSliceElement {
+    /// A view into the Vec's elements that does not copy any of them, which can be passed to Rust
+    /// functions that accept a `&[T]`.
+    ///
+    /// The slice is only valid for as long as the `RustVec` is alive and is not pushed to or
+    /// popped from.
+    public func as_slice() -> RustSlice<T> {
+        let start = UnsafeMutableRawPointer(mutating: UnsafeRawPointer(T.vecOfSelfAsPtr(vecPtr: ptr)))
+        return RustSlice(__private__FfiSlice(start: start, len: UInt(self.len())))
+    }
+}
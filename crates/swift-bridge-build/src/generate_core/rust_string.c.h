@@ -12,7 +12,6 @@ uintptr_t __swift_bridge__$Vec_RustString$len(void* vec_ptr);
 void* __swift_bridge__$Vec_RustString$as_ptr(void* vec_ptr);
 
 void* __swift_bridge__$RustString$new(void);
-void* __swift_bridge__$RustString$new_with_str(struct RustStr str);
 uintptr_t __swift_bridge__$RustString$len(void* self);
 struct RustStr __swift_bridge__$RustString$as_str(void* self);
 struct RustStr __swift_bridge__$RustString$trim(void* self);
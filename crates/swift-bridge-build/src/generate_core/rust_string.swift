@@ -15,12 +15,6 @@ extension RustString {
     public convenience init() {
         self.init(ptr: __swift_bridge__$RustString$new())
     }
-
-    public convenience init<GenericToRustStr: ToRustStr>(_ str: GenericToRustStr) {
-        self.init(ptr: str.toRustStr({ strAsRustStr in
-            __swift_bridge__$RustString$new_with_str(strAsRustStr)
-        }))
-    }
 }
 public class RustStringRefMut: RustStringRef {
     public override init(ptr: UnsafeMutableRawPointer) {
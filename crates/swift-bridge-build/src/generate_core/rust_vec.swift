@@ -23,10 +23,22 @@ public class RustVec<T: Vectorizable> {
          T.vecOfSelfGet(vecPtr: ptr, index: index)
     }
 
+    public func get_mut(index: UInt) -> Optional<T.SelfRefMut> {
+         T.vecOfSelfGetMut(vecPtr: ptr, index: index)
+    }
+
     public func as_ptr() -> UnsafePointer<T.SelfRef> {
         UnsafePointer<T.SelfRef>(OpaquePointer(T.vecOfSelfAsPtr(vecPtr: ptr)))
     }
 
+    /// A view into the Vec's buffer that does not copy any of its elements.
+    ///
+    /// The buffer is only valid for as long as the `RustVec` is alive and is not pushed to or
+    /// popped from.
+    public func as_buffer_pointer() -> UnsafeBufferPointer<T.SelfRef> {
+        UnsafeBufferPointer(start: self.as_ptr(), count: self.len())
+    }
+
     /// Rust returns a UInt, but we cast to an Int because many Swift APIs such as
     /// `ForEach(0..rustVec.len())` expect Int.
     public func len() -> Int {
@@ -40,6 +52,41 @@ public class RustVec<T: Vectorizable> {
     }
 }
 
+extension RustVec where T == UInt8 {
+    /// Wrap the Rust `Vec<u8>`'s buffer in a `Data` without copying it.
+    ///
+    /// The buffer is freed once both the `Data` and the `RustVec` are no longer in use. The
+    /// `RustVec` must not be pushed to or popped from while the `Data` is alive.
+    public func intoData() -> Data {
+        let count = self.len()
+        if count == 0 {
+            return Data()
+        }
+
+        let owner = self
+        return Data(
+            bytesNoCopy: UnsafeMutableRawPointer(mutating: self.as_ptr()),
+            count: count,
+            deallocator: .custom({ _, _ in withExtendedLifetime(owner) {} })
+        )
+    }
+}
+
+extension Data {
+    /// Copy the bytes into a new Rust `Vec<u8>` using a single allocation.
+    ///
+    /// Functions that take a `&[u8]` can borrow the bytes without copying them instead, using
+    /// `withUnsafeBytes`.
+    public func intoRustVec() -> RustVec<UInt8> {
+        self.withUnsafeBytes({ bytes in
+            RustVec(ptr: __swift_bridge__$Vec_u8$from_bytes(
+                bytes.baseAddress?.assumingMemoryBound(to: UInt8.self),
+                UInt(bytes.count)
+            ))
+        })
+    }
+}
+
 extension RustVec: Sequence {
     public func makeIterator() -> RustVecIterator<T> {
         return RustVecIterator(self)
@@ -0,0 +1,47 @@
+use swift_bridge_ir::SMALL_STRING_FFI;
+
+pub(super) const C_SMALL_STRING_SUPPORT: &str = r#"
+typedef struct SmallRustStr { uint8_t* start; uintptr_t len; uint8_t inline_bytes[24]; } SmallRustStr;
+"#;
+
+/// `RustString(str)`, which calls the `swift_bridge` crate's own `RustString::new_with_str`.
+///
+/// That function was generated with the same `small-string-ffi` setting as this crate, so it
+/// takes a `SmallRustStr` if the feature is enabled.
+pub(super) fn swift_rust_string_new_with_str() -> String {
+    let (to_rust_str, link_name) = new_with_str_conversion_and_link_name();
+
+    format!(
+        r#"
+extension RustString {{
+    public convenience init<GenericToRustStr: ToRustStr>(_ str: GenericToRustStr) {{
+        self.init(ptr: str.{to_rust_str}({{ strAsRustStr in
+            {link_name}(strAsRustStr)
+        }}))
+    }}
+}}
+"#
+    )
+}
+
+pub(super) fn c_rust_string_new_with_str() -> String {
+    let (_, link_name) = new_with_str_conversion_and_link_name();
+    let str_ty = if SMALL_STRING_FFI {
+        "SmallRustStr"
+    } else {
+        "RustStr"
+    };
+
+    format!("void* {link_name}(struct {str_ty} str);\n")
+}
+
+fn new_with_str_conversion_and_link_name() -> (&'static str, &'static str) {
+    if SMALL_STRING_FFI {
+        (
+            "toSmallRustStr",
+            "__swift_bridge__$RustString$new_with_str$small_str",
+        )
+    } else {
+        ("toRustStr", "__swift_bridge__$RustString$new_with_str")
+    }
+}
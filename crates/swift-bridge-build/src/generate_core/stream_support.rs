@@ -0,0 +1,249 @@
+pub(super) const C_STREAM_SUPPORT: &str = r#"
+void* __swift_bridge__$RustStream$subscribe(void* stream, void* subscriber, void (*free_subscriber)(void*));
+void __swift_bridge__$RustStream$free(void* stream);
+void __swift_bridge__$StreamSubscription$request(void* subscription, uintptr_t demand);
+void __swift_bridge__$StreamSubscription$cancel(void* subscription);
+"#;
+
+pub(super) const SWIFT_STREAM_SUPPORT: &str = r#"
+#if canImport(Combine)
+import Combine
+
+/// Publishes the items of a Rust stream that a `#[swift_bridge(publisher)]` function returned.
+///
+/// A Rust stream can only be polled once, so only the first subscriber receives its items. Later
+/// subscribers finish right away.
+@available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+final class __private__RustStreamPublisher<Output, Failure: Error>: Publisher {
+    private let lock = NSLock()
+    private var stream: UnsafeMutableRawPointer?
+
+    init(stream: UnsafeMutableRawPointer) {
+        self.stream = stream
+    }
+
+    deinit {
+        if let stream = stream {
+            __swift_bridge__$RustStream$free(stream)
+        }
+    }
+
+    func receive<S: Subscriber>(subscriber: S) where S.Input == Output, S.Failure == Failure {
+        lock.lock()
+        let stream = self.stream
+        self.stream = nil
+        lock.unlock()
+
+        guard let stream = stream else {
+            subscriber.receive(subscription: Subscriptions.empty)
+            subscriber.receive(completion: .finished)
+            return
+        }
+
+        let subscription = __private__RustStreamSubscription<Output, Failure>(
+            subscriber: AnySubscriber(subscriber)
+        )
+        // The stream doesn't produce anything until the subscriber requests items, so it is safe
+        // to start it before handing out the subscription.
+        subscription.start(stream)
+        subscriber.receive(subscription: subscription)
+    }
+}
+
+/// Forwards the subscriber's demand to a Rust stream and the stream's items to the subscriber.
+@available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+final class __private__RustStreamSubscription<Output, Failure: Error>: Subscription {
+    private let lock = NSLock()
+    private var subscriber: AnySubscriber<Output, Failure>?
+    private var subscription: UnsafeMutableRawPointer?
+
+    init(subscriber: AnySubscriber<Output, Failure>) {
+        self.subscriber = subscriber
+    }
+
+    static func fromOpaque(_ ptr: UnsafeMutableRawPointer) -> __private__RustStreamSubscription {
+        Unmanaged<__private__RustStreamSubscription>.fromOpaque(ptr).takeUnretainedValue()
+    }
+
+    /// Start polling the stream. Rust keeps this object retained until it is done calling it.
+    func start(_ stream: UnsafeMutableRawPointer) {
+        subscription = __swift_bridge__$RustStream$subscribe(
+            stream,
+            Unmanaged.passRetained(self).toOpaque(),
+            { ptr in Unmanaged<AnyObject>.fromOpaque(ptr!).release() }
+        )
+    }
+
+    func request(_ demand: Subscribers.Demand) {
+        lock.lock()
+        defer { lock.unlock() }
+
+        guard let subscription = subscription, demand > 0 else {
+            return
+        }
+        __swift_bridge__$StreamSubscription$request(subscription, demand.max.map(UInt.init) ?? UInt.max)
+    }
+
+    func cancel() {
+        finish()
+    }
+
+    func receive(_ item: Output) {
+        lock.lock()
+        let subscriber = self.subscriber
+        lock.unlock()
+
+        if let subscriber = subscriber {
+            request(subscriber.receive(item))
+        }
+    }
+
+    func receive(completion: Subscribers.Completion<Failure>) {
+        finish()?.receive(completion: completion)
+    }
+
+    /// Stop the stream and hand back the subscriber, unless the subscription already finished.
+    private func finish() -> AnySubscriber<Output, Failure>? {
+        lock.lock()
+        let subscriber = self.subscriber
+        let subscription = self.subscription
+        self.subscriber = nil
+        self.subscription = nil
+        lock.unlock()
+
+        if let subscription = subscription {
+            __swift_bridge__$StreamSubscription$cancel(subscription)
+        }
+        return subscriber
+    }
+}
+#endif
+
+/// Reads the items of a Rust stream that a function returned, one item per `next()` call.
+///
+/// Rust only polls the stream for an item once `next()` asks for it. The stream is dropped when
+/// the iterator is deinitialized or when the task that is waiting on `next()` is cancelled.
+@available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+final class __private__RustStreamIterator<Element, Failure: Error> {
+    private let lock = NSLock()
+    private var stream: UnsafeMutableRawPointer?
+    private var subscription: UnsafeMutableRawPointer?
+    private let receiver = __private__RustStreamReceiver<Element, Failure>()
+
+    init(stream: UnsafeMutableRawPointer) {
+        self.stream = stream
+    }
+
+    deinit {
+        cancel()
+    }
+
+    /// The stream's next item, `nil` once it ended or was cancelled, or the error that it failed
+    /// with.
+    func next() async -> Result<Element?, Failure> {
+        await withTaskCancellationHandler {
+            await withCheckedContinuation { continuation in
+                receiver.wait(continuation)
+
+                lock.lock()
+                defer { lock.unlock() }
+
+                if let stream = stream {
+                    self.stream = nil
+                    subscription = __swift_bridge__$RustStream$subscribe(
+                        stream,
+                        Unmanaged.passRetained(receiver).toOpaque(),
+                        { ptr in Unmanaged<AnyObject>.fromOpaque(ptr!).release() }
+                    )
+                }
+                if let subscription = subscription {
+                    __swift_bridge__$StreamSubscription$request(subscription, 1)
+                }
+            }
+        } onCancel: {
+            cancel()
+        }
+    }
+
+    private func cancel() {
+        lock.lock()
+        let stream = self.stream
+        let subscription = self.subscription
+        self.stream = nil
+        self.subscription = nil
+        lock.unlock()
+
+        if let stream = stream {
+            __swift_bridge__$RustStream$free(stream)
+        }
+        if let subscription = subscription {
+            __swift_bridge__$StreamSubscription$cancel(subscription)
+        }
+        receiver.receive(.success(nil))
+    }
+}
+
+@available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+extension __private__RustStreamIterator where Failure == Never {
+    func asyncStream() -> AsyncStream<Element> {
+        AsyncStream(unfolding: {
+            switch await self.next() {
+            case .success(let item):
+                return item
+            case .failure(let never):
+                switch never {}
+            }
+        })
+    }
+}
+
+@available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+extension __private__RustStreamIterator where Failure == Error {
+    func asyncThrowingStream() -> AsyncThrowingStream<Element, Error> {
+        AsyncThrowingStream(unfolding: {
+            try await self.next().get()
+        })
+    }
+}
+
+/// Hands the items that Rust produces to the `__private__RustStreamIterator.next()` call that is
+/// waiting for them. Rust keeps this object retained until it is done calling it.
+@available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+final class __private__RustStreamReceiver<Element, Failure: Error> {
+    private let lock = NSLock()
+    private var continuation: CheckedContinuation<Result<Element?, Failure>, Never>?
+    /// Set once the stream ended, failed or was cancelled.
+    private var done = false
+
+    static func fromOpaque(_ ptr: UnsafeMutableRawPointer) -> __private__RustStreamReceiver {
+        Unmanaged<__private__RustStreamReceiver>.fromOpaque(ptr).takeUnretainedValue()
+    }
+
+    func wait(_ continuation: CheckedContinuation<Result<Element?, Failure>, Never>) {
+        lock.lock()
+        if done {
+            lock.unlock()
+            continuation.resume(returning: .success(nil))
+            return
+        }
+        self.continuation = continuation
+        lock.unlock()
+    }
+
+    /// Receive an item, or `.success(nil)` when the stream ended.
+    func receive(_ result: Result<Element?, Failure>) {
+        lock.lock()
+        let continuation = self.continuation
+        self.continuation = nil
+        switch result {
+        case .success(.some):
+            break
+        default:
+            done = true
+        }
+        lock.unlock()
+
+        continuation?.resume(returning: result)
+    }
+}
+"#;
@@ -1,6 +1,6 @@
 import Foundation
 
-extension RustString {
+extension RustStringRef {
     public func toString() -> String {
         let str = self.as_str()
         let string = str.toString()
@@ -9,6 +9,34 @@ extension RustString {
     }
 }
 
+extension RustString {
+    /// Convert into a Swift `String` without copying the Rust `String`'s bytes up front.
+    ///
+    /// The buffer is handed to `NSString(bytesNoCopy:)`, which keeps the `RustString` alive
+    /// until Foundation is done with it. Foundation decides whether to read the bytes in place
+    /// or copy them, such as when the string isn't ASCII or gets mutated, so this only avoids
+    /// the eager copy that `toString()` makes.
+    public func intoString() -> String {
+        let str = self.as_str()
+        if str.len == 0 {
+            return ""
+        }
+
+        let owner = self
+        let string = NSString(
+            bytesNoCopy: UnsafeMutableRawPointer(str.start!),
+            length: Int(str.len),
+            encoding: String.Encoding.utf8.rawValue,
+            deallocator: { _, _ in withExtendedLifetime(owner) {} }
+        )
+        if let string = string {
+            return string as String
+        }
+
+        return str.toString()
+    }
+}
+
 extension RustStr {
     func toBufferPointer() -> UnsafeBufferPointer<UInt8> {
         let bytes = UnsafeBufferPointer(start: self.start, count: Int(self.len))
@@ -36,7 +64,21 @@ public protocol IntoRustString {
 }
 
 public protocol ToRustStr {
-    func toRustStr<T> (_ withUnsafeRustStr: (RustStr) -> T) -> T;
+    func toRustStr<T> (_ withUnsafeRustStr: (RustStr) throws -> T) rethrows -> T;
+    func toSmallRustStr<T> (_ withUnsafeSmallRustStr: (SmallRustStr) throws -> T) rethrows -> T;
+}
+
+extension ToRustStr {
+    /// Used instead of `toRustStr` when `swift-bridge` is built with the `small-string-ffi`
+    /// feature. By default the `SmallRustStr` points to the same bytes as the `RustStr`.
+    public func toSmallRustStr<T> (_ withUnsafeSmallRustStr: (SmallRustStr) throws -> T) rethrows -> T {
+        return try self.toRustStr({ rustStr in
+            var smallRustStr = SmallRustStr()
+            smallRustStr.start = rustStr.start
+            smallRustStr.len = rustStr.len
+            return try withUnsafeSmallRustStr(smallRustStr)
+        })
+    }
 }
 
 extension String: IntoRustString {
@@ -75,28 +117,48 @@ func optionalStringIntoRustString<S: IntoRustString>(_ string: Optional<S>) -> R
 extension String: ToRustStr {
     /// Safely get a scoped pointer to the String and then call the callback with a RustStr
     /// that uses that pointer.
-    public func toRustStr<T> (_ withUnsafeRustStr: (RustStr) -> T) -> T {
-        return self.utf8CString.withUnsafeBufferPointer({ bufferPtr in
+    public func toRustStr<T> (_ withUnsafeRustStr: (RustStr) throws -> T) rethrows -> T {
+        return try self.utf8CString.withUnsafeBufferPointer({ bufferPtr in
             let rustStr = RustStr(
                 start: UnsafeMutableRawPointer(mutating: bufferPtr.baseAddress!).assumingMemoryBound(to: UInt8.self),
                 // Subtract 1 because of the null termination character at the end
                 len: UInt(bufferPtr.count - 1)
             )
-            return withUnsafeRustStr(rustStr)
+            return try withUnsafeRustStr(rustStr)
         })
     }
+
+    /// Copy the String into the `SmallRustStr` if it fits, which avoids the heap allocation
+    /// that `toRustStr` needs for the null terminated copy of the String.
+    public func toSmallRustStr<T> (_ withUnsafeSmallRustStr: (SmallRustStr) throws -> T) rethrows -> T {
+        var smallRustStr = SmallRustStr()
+        if self.utf8.count > MemoryLayout.size(ofValue: smallRustStr.inline_bytes) {
+            return try self.toRustStr({ rustStr in
+                var smallRustStr = SmallRustStr()
+                smallRustStr.start = rustStr.start
+                smallRustStr.len = rustStr.len
+                return try withUnsafeSmallRustStr(smallRustStr)
+            })
+        }
+
+        smallRustStr.len = UInt(self.utf8.count)
+        withUnsafeMutableBytes(of: &smallRustStr.inline_bytes) { inlineBytes in
+            inlineBytes.copyBytes(from: self.utf8)
+        }
+        return try withUnsafeSmallRustStr(smallRustStr)
+    }
 }
 
 extension RustStr: ToRustStr {
-    public func toRustStr<T> (_ withUnsafeRustStr: (RustStr) -> T) -> T {
-        return withUnsafeRustStr(self)
+    public func toRustStr<T> (_ withUnsafeRustStr: (RustStr) throws -> T) rethrows -> T {
+        return try withUnsafeRustStr(self)
     }
 }
 
-func optionalRustStrToRustStr<S: ToRustStr, T>(_ str: Optional<S>, _ withUnsafeRustStr: (RustStr) -> T) -> T {
+func optionalRustStrToRustStr<S: ToRustStr, T>(_ str: Optional<S>, _ withUnsafeRustStr: (RustStr) throws -> T) rethrows -> T {
     if let val = str {
-        return val.toRustStr(withUnsafeRustStr)
+        return try val.toRustStr(withUnsafeRustStr)
     } else {
-        return withUnsafeRustStr(RustStr(start: nil, len: 0))
+        return try withUnsafeRustStr(RustStr(start: nil, len: 0))
     }
 }
@@ -0,0 +1,41 @@
+pub(super) const C_TIME_SUPPORT: &str = r#"
+typedef struct __private__FfiDuration { uint64_t secs; uint32_t nanos; } __private__FfiDuration;
+typedef struct __private__FfiSystemTime { int64_t secs; uint32_t nanos; } __private__FfiSystemTime;
+"#;
+
+pub(super) const SWIFT_TIME_SUPPORT: &str = r#"
+extension __private__FfiDuration {
+    init(_ interval: TimeInterval) {
+        // Rust's Duration cannot be negative, so negative and NaN intervals become zero.
+        guard interval > 0 else {
+            self.init(secs: 0, nanos: 0)
+            return
+        }
+        guard interval < TimeInterval(UInt64.max) else {
+            self.init(secs: UInt64.max, nanos: 999_999_999)
+            return
+        }
+
+        let secs = interval.rounded(.down)
+        let nanos = min(((interval - secs) * 1_000_000_000).rounded(), 999_999_999)
+        self.init(secs: UInt64(secs), nanos: UInt32(nanos))
+    }
+
+    func intoSwiftRepr() -> TimeInterval {
+        TimeInterval(secs) + TimeInterval(nanos) / 1_000_000_000
+    }
+}
+
+extension __private__FfiSystemTime {
+    init(_ date: Date) {
+        let interval = date.timeIntervalSince1970
+        let secs = interval.rounded(.down)
+        let nanos = min(((interval - secs) * 1_000_000_000).rounded(), 999_999_999)
+        self.init(secs: Int64(secs), nanos: UInt32(nanos))
+    }
+
+    func intoSwiftRepr() -> Date {
+        Date(timeIntervalSince1970: TimeInterval(secs) + TimeInterval(nanos) / 1_000_000_000)
+    }
+}
+"#;
@@ -0,0 +1,17 @@
+pub(super) const SWIFT_URL_SUPPORT: &str = r#"
+/// Create a `URL` out of a `url::Url`'s serialization, freeing the `RustString` that holds it.
+func __swift_bridge__urlFromRust(_ ptr: UnsafeMutableRawPointer) -> URL {
+    let string = RustString(ptr: ptr).toString()
+    guard let url = URL(string: string) else {
+        fatalError("Rust passed a url::Url that is not a valid URL: \(string)")
+    }
+    return url
+}
+
+/// Pass the `URL`'s `absoluteString` to Rust, which parses it into a `url::Url`.
+func __swift_bridge__urlIntoRust(_ url: URL) -> UnsafeMutableRawPointer {
+    let rustString = url.absoluteString.intoRustString()
+    rustString.isOwned = false
+    return rustString.ptr
+}
+"#;
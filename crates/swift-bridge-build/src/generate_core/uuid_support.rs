@@ -0,0 +1,16 @@
+pub(super) const C_UUID_SUPPORT: &str = r#"
+typedef struct __private__FfiUuid { uint8_t bytes[16]; } __private__FfiUuid;
+"#;
+
+// C arrays are imported into Swift as tuples, so `bytes` has the same type as Foundation's `uuid_t`.
+pub(super) const SWIFT_UUID_SUPPORT: &str = r#"
+extension __private__FfiUuid {
+    init(_ uuid: UUID) {
+        self.init(bytes: uuid.uuid)
+    }
+
+    func intoSwiftRepr() -> UUID {
+        UUID(uuid: bytes)
+    }
+}
+"#;
@@ -4,27 +4,97 @@
 #![deny(missing_docs)]
 
 mod package;
+use crate::duplicate_declarations::assert_no_duplicate_declarations;
+use crate::expansion::ModuleExpansion;
 use crate::generate_core::write_core_swift_and_c;
+use crate::snapshots::{assert_matches_snapshots, Snapshot};
+use crate::swift_signatures::check_swift_signatures;
 pub use package::*;
-use std::path::Path;
-use swift_bridge_ir::{CodegenConfig, SwiftBridgeModule};
+use std::collections::HashSet;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use swift_bridge_ir::{
+    CodegenConfig, ExternSwiftSignature, GeneratedDeclaration, SwiftBridgeModule,
+    SwiftBridgeModuleAttrs,
+};
 use syn::__private::ToTokens;
 use syn::{File, Item};
 
+mod duplicate_declarations;
+mod expansion;
 mod generate_core;
+mod snapshots;
+mod swift_signatures;
 
 /// Parse rust sources files for `#\[swift_bridge::bridge\]` headers and generate the corresponding
 /// Swift files.
 pub fn parse_bridges(
     rust_source_files: impl IntoIterator<Item = impl AsRef<Path>>,
+) -> GeneratedCode {
+    parse_bridges_with_config(rust_source_files, &BridgesConfig::default())
+}
+
+/// Configuration for how the Swift code for the bridge modules gets generated.
+#[derive(Default)]
+pub struct BridgesConfig {
+    /// The Swift access level, such as `"internal"`, of generated declarations that don't have a
+    /// `#[swift_bridge(swift_access = "...")]` attribute.
+    ///
+    /// Defaults to `public`.
+    pub default_swift_access: Option<String>,
+    /// A command, such as `["swift-format", "format"]`, that the generated Swift code gets piped
+    /// through. The command reads the Swift code from its stdin and writes the formatted code to
+    /// its stdout.
+    ///
+    /// The generated code's whitespace is normalized whether or not a formatter is set.
+    pub swift_formatter: Option<Vec<String>>,
+    /// The Swift source files, `.swiftinterface` files or directories containing them, that
+    /// implement the bridge modules' `extern "Swift"` functions.
+    ///
+    /// When set, a warning is printed for every `extern "Swift"` function that the Swift sources
+    /// don't declare, or that they declare with different argument labels or types.
+    pub swift_sources: Vec<PathBuf>,
+    /// A directory of checked in snapshots of the generated Swift code and C headers, such as
+    /// `snapshots/src/lib.rs.swift` and `snapshots/src/lib.rs.h` for `src/lib.rs`.
+    ///
+    /// When set, the generated code is compared against the snapshots and a unified diff of
+    /// every difference is printed in a panic, so that changes to the generated code, such as
+    /// those that come from upgrading `swift-bridge`, can be reviewed before they're accepted.
+    /// Setting the `SWIFT_BRIDGE_UPDATE_SNAPSHOTS` environment variable writes the generated
+    /// code to the snapshots instead.
+    pub snapshot_dir: Option<PathBuf>,
+}
+
+/// Like [`parse_bridges`], but with a [`BridgesConfig`].
+///
+/// # Panics
+///
+/// Panics if a file can't be parsed, or if two bridge modules declare the same Swift type or
+/// symbol, since the generated code would then fail to compile or to link.
+///
+/// Also panics if [`BridgesConfig::snapshot_dir`] is set and the generated code doesn't match
+/// the snapshots.
+///
+/// Swift code that several bridge modules generate for the same type, such as the extension of a
+/// `HashMap<K, V>`'s `RustMap`, only gets emitted once per call. Crates are parsed by their own
+/// build scripts though, so two crates whose generated Swift gets compiled into the same Swift
+/// target can't use the same `HashMap<K, V>`.
+pub fn parse_bridges_with_config(
+    rust_source_files: impl IntoIterator<Item = impl AsRef<Path>>,
+    config: &BridgesConfig,
 ) -> GeneratedCode {
     let mut generated_code = GeneratedCode::new();
+    let mut declarations = vec![];
+    let mut swift_signatures = vec![];
+    let mut snapshots = vec![];
+    let mut emitted_shared_swift = HashSet::new();
 
     for rust_file in rust_source_files.into_iter() {
         let rust_file: &Path = rust_file.as_ref();
 
         let file = std::fs::read_to_string(rust_file).unwrap();
-        let gen = match parse_file_contents(&file) {
+        let mut gen = match parse_file_contents(&file, config, &mut emitted_shared_swift) {
             Ok(generated) => generated,
             Err(e) => {
                 // TODO: Return an error...
@@ -38,36 +108,82 @@ Error while parsing {:?}
             }
         };
 
+        declarations.extend(
+            gen.declarations
+                .drain(..)
+                .map(|declaration| (rust_file.to_path_buf(), declaration)),
+        );
+        swift_signatures.extend(
+            gen.swift_signatures
+                .drain(..)
+                .map(|signature| (rust_file.to_path_buf(), signature)),
+        );
+        for mut expansion in gen.expansions.drain(..) {
+            expansion.rust_file = rust_file.to_path_buf();
+            generated_code.expansions.push(expansion);
+        }
+
+        gen.swift = normalize_whitespace(&gen.swift);
+        if let Some(formatter) = config.swift_formatter.as_ref() {
+            gen.swift = run_swift_formatter(formatter, &gen.swift);
+        }
+
+        if config.snapshot_dir.is_some() {
+            snapshots.push(Snapshot {
+                rust_file: rust_file.to_path_buf(),
+                swift: gen.swift.clone(),
+                c_header: gen.c_header.clone(),
+            });
+        }
+
         generated_code.generated.push(gen);
     }
 
+    assert_no_duplicate_declarations(&declarations);
+
+    if let Some(snapshot_dir) = config.snapshot_dir.as_ref() {
+        assert_matches_snapshots(snapshot_dir, &snapshots);
+    }
+
+    if !config.swift_sources.is_empty() {
+        for warning in check_swift_signatures(&swift_signatures, &config.swift_sources) {
+            print_warning(&warning);
+        }
+    }
+
     generated_code
 }
 
 /// Generated Swift files and C headers.
 pub struct GeneratedCode {
     generated: Vec<GeneratedFromSwiftBridgeModule>,
+    expansions: Vec<ModuleExpansion>,
 }
 
 impl GeneratedCode {
     fn new() -> Self {
-        GeneratedCode { generated: vec![] }
+        GeneratedCode {
+            generated: vec![],
+            expansions: vec![],
+        }
     }
 }
 
 impl GeneratedCode {
     /// Write all of the generated Swift to a single Swift file and all of the generated C headers
     /// to a single header file.
+    ///
+    /// Files whose contents haven't changed since the previous run are left untouched, so that
+    /// their modification times don't cause Xcode to rebuild the Swift target.
+    ///
+    /// An umbrella header that includes the C headers of every crate in the `swift_bridge_out_dir`,
+    /// along with a `module.modulemap` that exposes it as the `SwiftBridgeGenerated` Clang module,
+    /// are also written to the `swift_bridge_out_dir`.
     pub fn write_all_concatenated(&self, swift_bridge_out_dir: impl AsRef<Path>, crate_name: &str) {
         let swift_bridge_out_dir = swift_bridge_out_dir.as_ref();
 
-        let mut concatenated_swift = "".to_string();
-        let mut concatenated_c = "".to_string();
-
-        for gen in &self.generated {
-            concatenated_swift += &gen.swift;
-            concatenated_c += &gen.c_header;
-        }
+        let concatenated_swift = self.concat_swift();
+        let concatenated_c = self.concat_c();
 
         let out = swift_bridge_out_dir.join(&crate_name);
         match std::fs::create_dir_all(&out) {
@@ -75,25 +191,25 @@ impl GeneratedCode {
             Err(_) => {}
         };
 
-        std::fs::write(out.join(format!("{}.h", crate_name)), concatenated_c).unwrap();
-        std::fs::write(
+        write_if_changed(out.join(format!("{}.h", crate_name)), concatenated_c);
+        write_if_changed(
             out.join(format!("{}.swift", crate_name)),
             concatenated_swift,
-        )
-        .unwrap();
+        );
 
         write_core_swift_and_c(swift_bridge_out_dir.as_ref());
+        write_umbrella_header_and_module_map(swift_bridge_out_dir);
     }
 
     /// Concatenate all of the generated Swift code into one file.
     pub fn concat_swift(&self) -> String {
-        let mut swift = "".to_string();
-
-        for gen in &self.generated {
-            swift += &gen.swift;
-        }
+        let swift: Vec<&str> = self
+            .generated
+            .iter()
+            .map(|gen| gen.swift.as_str())
+            .collect();
 
-        swift
+        swift.join("\n")
     }
 
     /// Concatenate all of the generated C code into one file.
@@ -108,12 +224,152 @@ impl GeneratedCode {
     }
 }
 
-fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule> {
+/// Remove trailing whitespace, collapse consecutive blank lines into one and end the code with a
+/// single newline, so that the generated Swift has the same shape no matter which parts of the
+/// bridge modules produced it.
+fn normalize_whitespace(swift: &str) -> String {
+    let mut normalized = String::with_capacity(swift.len());
+    let mut previous_line_was_blank = true;
+
+    for line in swift.lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            if previous_line_was_blank {
+                continue;
+            }
+            previous_line_was_blank = true;
+        } else {
+            previous_line_was_blank = false;
+        }
+
+        normalized += line;
+        normalized += "\n";
+    }
+
+    while normalized.ends_with("\n\n") {
+        normalized.pop();
+    }
+
+    normalized
+}
+
+/// Pipe the Swift code through the formatter's stdin and return what it wrote to its stdout.
+fn run_swift_formatter(formatter: &[String], swift: &str) -> String {
+    let (program, args) = formatter
+        .split_first()
+        .expect("The Swift formatter command is empty");
+
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|_| panic!("Failed to spawn Swift formatter {}", program));
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(swift.as_bytes())
+        .expect("Failed to write to the Swift formatter's stdin");
+
+    let output = child
+        .wait_with_output()
+        .expect("Failed to execute Swift formatter");
+    if !output.status.success() {
+        let stderr = std::str::from_utf8(&output.stderr).unwrap();
+        panic!("{}", stderr);
+    }
+
+    String::from_utf8(output.stdout).expect("The Swift formatter's output wasn't UTF-8")
+}
+
+/// Write `SwiftBridgeGenerated.h`, which includes the core C header and the C header of every
+/// crate that has written its generated code to the `out_dir`, and a `module.modulemap` for it.
+fn write_umbrella_header_and_module_map(out_dir: &Path) {
+    let mut crate_headers: Vec<String> = std::fs::read_dir(out_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|dir| dir.is_dir())
+        .filter_map(|dir| {
+            let crate_name = dir.file_name()?.to_str()?.to_string();
+            let header = format!("{}/{}.h", crate_name, crate_name);
+            out_dir.join(&header).is_file().then_some(header)
+        })
+        .collect();
+    crate_headers.sort();
+
+    let mut umbrella_header = r#"// File automatically generated by swift-bridge.
+#ifndef SwiftBridgeGenerated_h
+#define SwiftBridgeGenerated_h
+
+#include "SwiftBridgeCore.h"
+"#
+    .to_string();
+    for header in crate_headers {
+        umbrella_header += &format!("#include \"{}\"\n", header);
+    }
+    umbrella_header += "\n#endif /* SwiftBridgeGenerated_h */\n";
+    write_if_changed(out_dir.join("SwiftBridgeGenerated.h"), umbrella_header);
+
+    let module_map = r#"module SwiftBridgeGenerated {
+    umbrella header "SwiftBridgeGenerated.h"
+    export *
+}
+"#;
+    write_if_changed(out_dir.join("module.modulemap"), module_map.to_string());
+}
+
+/// Print the warning as a `cargo:warning` when running inside of a build script, or to stderr
+/// otherwise.
+fn print_warning(warning: &str) {
+    if std::env::var("OUT_DIR").is_ok() {
+        println!("cargo:warning={}", warning);
+    } else {
+        eprintln!("warning: {}", warning);
+    }
+}
+
+/// `src/lib.rs:12:10`
+pub(crate) fn source_location(rust_file: &Path, span: proc_macro2::Span) -> String {
+    let start = span.start();
+    format!(
+        "{}:{}:{}",
+        rust_file.display(),
+        start.line,
+        start.column + 1
+    )
+}
+
+/// Write the contents to the file, unless the file already contains exactly those contents.
+pub(crate) fn write_if_changed(path: impl AsRef<Path>, contents: String) {
+    let path = path.as_ref();
+
+    if let Ok(existing) = std::fs::read(path) {
+        if existing == contents.as_bytes() {
+            return;
+        }
+    }
+
+    std::fs::write(path, contents).unwrap();
+}
+
+/// `emitted_shared_swift` holds the [`swift_bridge_ir::SharedSwiftCode::key`]s of the shared
+/// Swift code that previously parsed bridge modules generated, which gets left out of this file's
+/// Swift code.
+fn parse_file_contents(
+    file: &str,
+    bridges_config: &BridgesConfig,
+    emitted_shared_swift: &mut HashSet<String>,
+) -> syn::Result<GeneratedFromSwiftBridgeModule> {
     let file: File = syn::parse_str(file)?;
 
     let mut generated = GeneratedFromSwiftBridgeModule {
         c_header: "".to_string(),
         swift: "".to_string(),
+        declarations: vec![],
+        swift_signatures: vec![],
+        expansions: vec![],
     };
 
     for item in file.items {
@@ -121,27 +377,70 @@ fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule
             Item::Mod(module) => {
                 // TODO: Move this check into the `impl Parse for SwiftBridgeModule`.. Modify our
                 //  tests in swift-bridge-ir to annotate modules with `#[swift_bridge::bridge]`
-                if module.attrs.iter().any(|a| {
+                let bridge_attr = module.attrs.iter().find(|a| {
                     let attrib = a.path.to_token_stream().to_string();
                     attrib == "swift_bridge :: bridge" || attrib == "swift_bridge_macro :: bridge"
-                }) {
-                    let module: SwiftBridgeModule = syn::parse2(module.to_token_stream())?;
+                });
+                if let Some(bridge_attr) = bridge_attr {
+                    let module_attrs: SwiftBridgeModuleAttrs = if bridge_attr.tokens.is_empty() {
+                        SwiftBridgeModuleAttrs { attributes: vec![] }
+                    } else {
+                        bridge_attr.parse_args()?
+                    };
 
-                    let config = CodegenConfig {
-                        crate_feature_lookup: Box::new(|feature_name| {
-                            let normalized_feature_name = feature_name.replace("-", "_");
-                            let normalized_feature_name = normalized_feature_name.to_uppercase();
+                    let module_name = module.ident.to_string();
+                    let mut module: SwiftBridgeModule = syn::parse2(module.to_token_stream())?;
+                    module.apply_attributes(module_attrs);
 
-                            let env_var_name = format!("CARGO_FEATURE_{}", normalized_feature_name);
-                            std::env::var(env_var_name).is_ok()
-                        }),
-                    };
+                    let config = CodegenConfig::new(Box::new(|feature_name| {
+                        let normalized_feature_name = feature_name.replace("-", "_");
+                        let normalized_feature_name = normalized_feature_name.to_uppercase();
+
+                        let env_var_name = format!("CARGO_FEATURE_{}", normalized_feature_name);
+                        std::env::var(env_var_name).is_ok()
+                    }))
+                    .with_cfg_lookup(Box::new(|cfg_name, cfg_value| {
+                        // Cargo sets `CARGO_CFG_TARGET_OS=ios` for `target_os = "ios"`, and
+                        // comma separates the values of cfgs that have more than one.
+                        let env_var_name = format!("CARGO_CFG_{}", cfg_name.to_uppercase());
+                        match (std::env::var(env_var_name), cfg_value) {
+                            (Ok(_), None) => true,
+                            (Ok(values), Some(cfg_value)) => {
+                                values.split(',').any(|value| value == cfg_value)
+                            }
+                            (Err(_), _) => false,
+                        }
+                    }))
+                    .with_default_swift_access(bridges_config.default_swift_access.clone());
+                    generated
+                        .declarations
+                        .extend(module.generated_declarations(&config));
+                    generated
+                        .swift_signatures
+                        .extend(module.extern_swift_signatures(&config));
                     let swift_and_c = module.generate_swift_code_and_c_header(config);
 
+                    // Leave out the shared code that another module that uses the same
+                    // `HashMap<K, V>`, for example, already emitted.
+                    let mut swift = swift_and_c.swift;
+                    for shared in swift_and_c.shared_swift {
+                        if emitted_shared_swift.insert(shared.key) {
+                            swift += &shared.code;
+                            swift += "\n";
+                        }
+                    }
+
+                    generated.expansions.push(ModuleExpansion {
+                        rust_file: PathBuf::new(),
+                        module_name,
+                        rust: module.to_token_stream().to_string(),
+                        swift: swift.clone(),
+                        c_header: swift_and_c.c_header.clone(),
+                    });
+
                     generated.c_header += &swift_and_c.c_header;
                     generated.c_header += "\n\n";
 
-                    let swift = &swift_and_c.swift;
                     generated.swift += &swift;
                     generated.swift += "\n\n";
                 }
@@ -157,4 +456,178 @@ fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule
 struct GeneratedFromSwiftBridgeModule {
     c_header: String,
     swift: String,
+    /// The Swift types and symbols that the file's bridge modules declare.
+    declarations: Vec<GeneratedDeclaration>,
+    /// The `extern "Swift"` functions that the file's bridge modules call.
+    swift_signatures: Vec<ExternSwiftSignature>,
+    expansions: Vec<ModuleExpansion>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that only one of the bridge modules that use the same `HashMap<K, V>` keeps its
+    /// Swift extension, since the extensions declare the same members.
+    #[test]
+    fn keeps_one_extension_for_a_hash_map_that_several_modules_use() {
+        let file = r#"
+#[swift_bridge::bridge]
+mod first {
+    extern "Rust" {
+        fn first() -> HashMap<String, u64>;
+    }
+}
+
+#[swift_bridge::bridge]
+mod second {
+    extern "Rust" {
+        fn second() -> HashMap<String, u64>;
+    }
+}
+"#;
+        let generated =
+            parse_file_contents(file, &BridgesConfig::default(), &mut HashSet::new()).unwrap();
+
+        let extension = "extension RustMap where K == RustString, V == UInt64 {";
+        assert_eq!(generated.swift.matches(extension).count(), 1);
+        assert!(generated
+            .swift
+            .contains("__swift_bridge__$first$HashMap$String$U64$new()"));
+        assert!(!generated
+            .swift
+            .contains("__swift_bridge__$second$HashMap$String$U64$new()"));
+        assert!(generated
+            .c_header
+            .contains("__swift_bridge__$second$HashMap$String$U64$new(void);"));
+    }
+
+    /// Verify that only one of the bridge modules that use the same `HashSet<T>` keeps its Swift
+    /// extension and `IntoRustSetOf..` protocol.
+    #[test]
+    fn keeps_one_extension_for_a_hash_set_that_several_modules_use() {
+        let file = r#"
+#[swift_bridge::bridge]
+mod first {
+    extern "Rust" {
+        fn first() -> HashSet<u32>;
+    }
+}
+
+#[swift_bridge::bridge]
+mod second {
+    extern "Rust" {
+        fn second(set: HashSet<u32>);
+    }
+}
+"#;
+        let generated =
+            parse_file_contents(file, &BridgesConfig::default(), &mut HashSet::new()).unwrap();
+
+        let extension = "extension RustSet where T == UInt32 {";
+        assert_eq!(generated.swift.matches(extension).count(), 1);
+        assert_eq!(
+            generated
+                .swift
+                .matches("protocol IntoRustSetOfUInt32 {")
+                .count(),
+            1
+        );
+        assert!(generated
+            .swift
+            .contains("public func second(_ set: IntoRustSetOfUInt32) {"));
+    }
+
+    /// Verify that a file leaves out the shared Swift code that a previously parsed file already
+    /// emitted, while keeping the rest of its own Swift code.
+    #[test]
+    fn leaves_out_shared_swift_that_a_previous_file_emitted() {
+        let first = r#"
+#[swift_bridge::bridge]
+mod first {
+    extern "Rust" {
+        fn first() -> HashMap<String, u64>;
+    }
+}
+"#;
+        let second = r#"
+#[swift_bridge::bridge]
+mod second {
+    extern "Rust" {
+        fn second() -> HashMap<String, u64>;
+        fn other() -> HashMap<u8, u8>;
+    }
+}
+"#;
+        let mut emitted_shared_swift = HashSet::new();
+        let first =
+            parse_file_contents(first, &BridgesConfig::default(), &mut emitted_shared_swift)
+                .unwrap();
+        let second =
+            parse_file_contents(second, &BridgesConfig::default(), &mut emitted_shared_swift)
+                .unwrap();
+
+        assert!(first
+            .swift
+            .contains("extension RustMap where K == RustString, V == UInt64 {"));
+        assert!(!second
+            .swift
+            .contains("extension RustMap where K == RustString, V == UInt64 {"));
+        assert!(second
+            .swift
+            .contains("extension RustMap where K == UInt8, V == UInt8 {"));
+        assert!(second
+            .swift
+            .contains("public func second() -> RustMap<RustString, UInt64> {"));
+    }
+
+    /// Verify that a bridge module can use an opaque type that a bridge module in another file
+    /// declares, without the type's Swift class being declared a second time.
+    #[test]
+    fn uses_an_opaque_type_that_another_file_declares() {
+        let app = r#"
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type App;
+    }
+}
+"#;
+        let users = r#"
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(already_declared)]
+        type App;
+
+        fn user_count(&self) -> u32;
+        fn app_with_users(users: u32) -> App;
+    }
+}
+"#;
+        let mut emitted_shared_swift = HashSet::new();
+        let app =
+            parse_file_contents(app, &BridgesConfig::default(), &mut emitted_shared_swift).unwrap();
+        let users =
+            parse_file_contents(users, &BridgesConfig::default(), &mut emitted_shared_swift)
+                .unwrap();
+
+        assert!(app.swift.contains("public class App: AppRefMut {"));
+        assert!(!users.swift.contains("public class App"));
+        assert!(users.swift.contains("public func user_count() -> UInt32 {"));
+        assert!(users
+            .swift
+            .contains("public func app_with_users(_ users: UInt32) -> App {"));
+
+        let declarations: Vec<_> = [("src/app.rs", app), ("src/users.rs", users)]
+            .into_iter()
+            .flat_map(|(path, generated)| {
+                generated
+                    .declarations
+                    .into_iter()
+                    .map(move |declaration| (PathBuf::from(path), declaration))
+            })
+            .collect();
+        assert_no_duplicate_declarations(&declarations);
+    }
 }
@@ -2,8 +2,6 @@
 
 use std::collections::HashMap;
 use std::fs;
-use std::fs::OpenOptions;
-use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use tempfile::tempdir;
@@ -111,79 +109,64 @@ pub fn create_package(config: CreatePackageConfig) {
     gen_package(&output_dir, &config);
 }
 
-/// Generates the RustXcframework
-fn gen_xcframework(output_dir: &Path, config: &CreatePackageConfig) {
+/// Generates a Swift Package without building the xcframework that its binary target points to.
+///
+/// The `Package.swift` and the Swift sources are written to the `out_dir`, along with an `include`
+/// directory that contains the generated C headers and the `module.modulemap` for the
+/// `RustXcframework` module. Pass the `include` directory as the `-headers` of each library when
+/// building the `RustXcframework.xcframework` elsewhere, such as on a machine that has `xcodebuild`,
+/// and place the xcframework in the `out_dir`.
+///
+/// The `paths` of the config are not used.
+pub fn create_package_sources(config: &CreatePackageConfig) {
+    let output_dir: &Path = config.out_dir.as_ref();
+    if !output_dir.exists() {
+        fs::create_dir_all(output_dir).expect("Couldn't create output directory");
+    }
+
+    let include_dir = output_dir.join("include");
+    if !include_dir.exists() {
+        fs::create_dir(&include_dir).expect("Couldn't create include directory");
+    }
+    gen_headers(&include_dir, config);
+
+    gen_package(output_dir, config);
+}
+
+/// Config for assembling an xcframework from static libraries.
+pub struct CreateXcframeworkConfig {
+    /// Static library per platform. e.g. `(ApplePlatform::iOS, "target/aarch64-apple-ios/release/libmy_rust_lib.a")`
+    pub libraries: HashMap<ApplePlatform, PathBuf>,
+    /// The directory containing the C headers and the `module.modulemap` that will be bundled with
+    /// each library, such as the `include` directory written by [`create_package_sources`].
+    pub headers_dir: PathBuf,
+    /// The path of the xcframework, i.e. `MySwiftPackage/RustXcframework.xcframework`
+    pub out_path: PathBuf,
+}
+
+/// Assembles an xcframework out of static libraries and their headers using
+/// `xcodebuild -create-xcframework`.
+///
+/// Any existing xcframework at the `out_path` is replaced.
+pub fn create_xcframework(config: &CreateXcframeworkConfig) {
     // Create directories
     let temp_dir = tempdir().expect("Couldn't create temporary directory");
     let tmp_framework_path = &temp_dir.path().join("swiftbridge._tmp_framework");
     fs::create_dir(&tmp_framework_path).expect("Couldn't create framework directory");
 
     let include_dir = tmp_framework_path.join("include");
-    if !include_dir.exists() {
-        fs::create_dir(&include_dir).expect("Couldn't create inlcude directory for xcframework");
+    fs::create_dir(&include_dir).expect("Couldn't create inlcude directory for xcframework");
+    for header in fs::read_dir(&config.headers_dir).expect("Couldn't read headers directory") {
+        let header = header.unwrap().path();
+        if header.is_file() {
+            fs::copy(&header, include_dir.join(header.file_name().unwrap())).unwrap_or_else(|_| {
+                panic!("Couldn't copy header file {}", header.to_str().unwrap())
+            });
+        }
     }
 
-    // Create modulemap
-    let modulemap_path = include_dir.join("module.modulemap");
-    fs::write(
-        &modulemap_path,
-        "module RustXcframework {\n    header \"SwiftBridgeCore.h\"\n",
-    )
-    .expect("Couldn't write modulemap file");
-    let mut modulemap_file = OpenOptions::new()
-        .write(true)
-        .append(true)
-        .open(&modulemap_path)
-        .expect("Couldn't open modulemap file for writing");
-
-    // copy headers
-    let bridge_dir: &Path = config.bridge_dir.as_ref();
-    fs::copy(
-        bridge_dir.join("SwiftBridgeCore.h"),
-        &include_dir.join("SwiftBridgeCore.h"),
-    )
-    .expect("Couldn't copy SwiftBirdgeCore header file");
-    let bridge_project_dir = fs::read_dir(&bridge_dir)
-        .expect("Couldn't read generated directory")
-        .find_map(|file| {
-            let file = file.unwrap().path();
-            if file.is_dir() {
-                Some(file)
-            } else {
-                None
-            }
-        })
-        .expect("Couldn't find project directory inside of generated directory");
-    let bridge_project_header_dir = fs::read_dir(&bridge_project_dir)
-        .expect("Couldn't read generated directory")
-        .find_map(|file| {
-            let file = file.unwrap().path();
-            if file.extension().unwrap() == "h" {
-                Some(file)
-            } else {
-                None
-            }
-        })
-        .expect("Couldn't find project's header file");
-    fs::copy(
-        &bridge_project_header_dir,
-        &include_dir.join(&bridge_project_header_dir.file_name().unwrap()),
-    )
-    .expect("Couldn't copy project's header file");
-    writeln!(
-        modulemap_file,
-        "    header \"{}\"",
-        bridge_project_header_dir
-            .file_name()
-            .unwrap()
-            .to_str()
-            .unwrap()
-    )
-    .expect("Couldn't write to modulemap");
-    writeln!(modulemap_file, "    export *\n}}").expect("Couldn't write to modulemap");
-
     // Copy libraries
-    for platform in &config.paths {
+    for platform in &config.libraries {
         let platform_path = &tmp_framework_path.join(platform.0.dir_name());
         if !platform_path.exists() {
             fs::create_dir(&platform_path).expect(&format!(
@@ -200,15 +183,15 @@ fn gen_xcframework(output_dir: &Path, config: &CreatePackageConfig) {
     }
 
     // build xcframework
-    let xcframework_dir = output_dir.join("RustXcframework.xcframework");
+    let xcframework_dir: &Path = config.out_path.as_ref();
     if xcframework_dir.exists() {
-        fs::remove_dir_all(&xcframework_dir).expect("Couldn't delete previous xcframework file");
+        fs::remove_dir_all(xcframework_dir).expect("Couldn't delete previous xcframework file");
     }
-    fs::create_dir(&xcframework_dir).expect("Couldn't create directory for xcframework");
+    fs::create_dir_all(xcframework_dir).expect("Couldn't create directory for xcframework");
 
     let mut args: Vec<String> = Vec::new();
     args.push("-create-xcframework".to_string());
-    for platform in &config.paths {
+    for platform in &config.libraries {
         let file_path = Path::new(platform.0.dir_name())
             .join((platform.1.as_ref() as &Path).file_name().unwrap());
 
@@ -250,6 +233,71 @@ fn gen_xcframework(output_dir: &Path, config: &CreatePackageConfig) {
     }
 }
 
+/// Generates the RustXcframework
+fn gen_xcframework(output_dir: &Path, config: &CreatePackageConfig) {
+    let temp_dir = tempdir().expect("Couldn't create temporary directory");
+    gen_headers(temp_dir.path(), config);
+
+    create_xcframework(&CreateXcframeworkConfig {
+        libraries: config.paths.clone(),
+        headers_dir: temp_dir.path().to_path_buf(),
+        out_path: output_dir.join("RustXcframework.xcframework"),
+    });
+}
+
+/// Copies the generated C headers into the `include_dir` and writes a `module.modulemap` that
+/// exposes them as the `RustXcframework` module.
+fn gen_headers(include_dir: &Path, config: &CreatePackageConfig) {
+    let bridge_dir: &Path = config.bridge_dir.as_ref();
+
+    let mut headers = vec![bridge_dir.join("SwiftBridgeCore.h")];
+    headers.extend(bridge_project_files(bridge_dir, "h"));
+
+    let mut modulemap = "module RustXcframework {\n".to_string();
+    for header in headers {
+        let file_name = header.file_name().unwrap();
+        fs::copy(&header, include_dir.join(file_name))
+            .unwrap_or_else(|_| panic!("Couldn't copy header file {}", header.to_str().unwrap()));
+
+        modulemap += &format!("    header \"{}\"\n", file_name.to_str().unwrap());
+    }
+    modulemap += "    export *\n}\n";
+
+    fs::write(include_dir.join("module.modulemap"), modulemap)
+        .expect("Couldn't write modulemap file");
+}
+
+/// The files with the given extension in each of the project directories inside of the generated
+/// bridges directory, i.e. `generated/my-crate/my-crate.swift`.
+fn bridge_project_files(bridge_dir: &Path, extension: &str) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(bridge_dir)
+        .expect("Couldn't read generated directory")
+        .map(|dir| dir.unwrap().path())
+        .filter(|dir| dir.is_dir())
+        .flat_map(|dir| {
+            fs::read_dir(&dir)
+                .expect("Couldn't read generated project directory")
+                .map(|file| file.unwrap().path())
+                .collect::<Vec<PathBuf>>()
+        })
+        .filter(|file| {
+            file.extension()
+                .map(|ext| ext == extension)
+                .unwrap_or(false)
+        })
+        .collect();
+    files.sort();
+
+    if files.is_empty() {
+        panic!(
+            "Couldn't find any .{} files in the project directories inside of the generated directory",
+            extension
+        );
+    }
+
+    files
+}
+
 /// Generates the Swift Package.
 ///
 /// We copy the Swift files from our generated bridge dir into the Swift Package's Sources
@@ -277,37 +325,17 @@ fn gen_package(output_dir: &Path, config: &CreatePackageConfig) {
     )
     .expect("Couldn't write core bridging swift file");
 
-    let bridge_project_dir = fs::read_dir(&bridge_dir)
-        .expect("Couldn't read generated directory")
-        .find_map(|file| {
-            let file = file.unwrap().path();
-            if file.is_dir() {
-                Some(file)
-            } else {
-                None
-            }
-        })
-        .expect("Couldn't find project directory inside of generated directory");
-    let bridge_project_swift_dir = fs::read_dir(&bridge_project_dir)
-        .expect("Couldn't read generated directory")
-        .find_map(|file| {
-            let file = file.unwrap().path();
-            if file.extension().unwrap() == "swift" {
-                Some(file)
-            } else {
-                None
-            }
-        })
-        .expect("Couldn't find project's bridging swift file");
-    fs::write(
-        sources_dir.join(&bridge_project_swift_dir.file_name().unwrap()),
-        format!(
-            "import RustXcframework\n{}",
-            fs::read_to_string(&bridge_project_swift_dir)
-                .expect("Couldn't read project's bridging swift file")
-        ),
-    )
-    .expect("Couldn't copy project's bridging swift file to the package");
+    for swift_file in bridge_project_files(bridge_dir, "swift") {
+        fs::write(
+            sources_dir.join(swift_file.file_name().unwrap()),
+            format!(
+                "import RustXcframework\n{}",
+                fs::read_to_string(&swift_file)
+                    .expect("Couldn't read project's bridging swift file")
+            ),
+        )
+        .expect("Couldn't copy project's bridging swift file to the package");
+    }
 
     // Generate Package.swift
     let package_name = &config.package_name;
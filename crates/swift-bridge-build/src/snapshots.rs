@@ -0,0 +1,351 @@
+use crate::expansion::relative_path;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// Setting this environment variable writes the generated code to the snapshot files instead of
+/// comparing it against them.
+const UPDATE_SNAPSHOTS_ENV_VAR: &str = "SWIFT_BRIDGE_UPDATE_SNAPSHOTS";
+
+/// Lines of unchanged code shown around each change.
+const DIFF_CONTEXT_LINES: usize = 3;
+
+/// The code generated for a single Rust source file.
+pub(crate) struct Snapshot {
+    pub rust_file: PathBuf,
+    pub swift: String,
+    pub c_header: String,
+}
+
+/// Panic with a unified diff for every generated Swift file or C header that differs from its
+/// snapshot in the `snapshot_dir`.
+///
+/// The snapshots for `src/lib.rs` are `snapshot_dir/src/lib.rs.swift` and
+/// `snapshot_dir/src/lib.rs.h`. When the `SWIFT_BRIDGE_UPDATE_SNAPSHOTS` environment variable is
+/// set the snapshots are overwritten with the generated code instead.
+pub(crate) fn assert_matches_snapshots(snapshot_dir: &Path, snapshots: &[Snapshot]) {
+    let update = std::env::var_os(UPDATE_SNAPSHOTS_ENV_VAR).is_some();
+    let mut mismatches = "".to_string();
+
+    for snapshot in snapshots {
+        let base = snapshot_dir.join(relative_path(&snapshot.rust_file));
+
+        for (extension, generated) in [("swift", &snapshot.swift), ("h", &snapshot.c_header)] {
+            let snapshot_file = with_extra_extension(&base, extension);
+
+            if update {
+                if generated.is_empty() && !snapshot_file.exists() {
+                    continue;
+                }
+                std::fs::create_dir_all(snapshot_file.parent().unwrap()).unwrap();
+                std::fs::write(&snapshot_file, generated).unwrap();
+                continue;
+            }
+
+            match std::fs::read_to_string(&snapshot_file) {
+                Ok(expected) if &expected == generated => {}
+                Ok(expected) => {
+                    let name = snapshot_file.display().to_string();
+                    mismatches += &unified_diff(&expected, generated, &name, &name);
+                }
+                Err(_) if generated.is_empty() => {}
+                Err(_) => {
+                    mismatches += &format!("{} does not exist\n", snapshot_file.display());
+                }
+            }
+        }
+    }
+
+    if !mismatches.is_empty() {
+        panic!(
+            r#"
+The generated code doesn't match its snapshots.
+
+{}
+Rerun with {}=1 to update the snapshots.
+"#,
+            mismatches, UPDATE_SNAPSHOTS_ENV_VAR
+        );
+    }
+}
+
+/// `src/lib.rs` -> `src/lib.rs.swift`
+fn with_extra_extension(path: &Path, extension: &str) -> PathBuf {
+    let mut path = OsString::from(path);
+    path.push(".");
+    path.push(extension);
+    PathBuf::from(path)
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Edit {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// A unified diff, like the output of `diff -u`, that turns `old` into `new`.
+fn unified_diff(old: &str, new: &str, old_name: &str, new_name: &str) -> String {
+    let edits = line_edits(old, new);
+
+    let changes: Vec<usize> = edits
+        .iter()
+        .enumerate()
+        .filter(|(_, (edit, _))| *edit != Edit::Equal)
+        .map(|(idx, _)| idx)
+        .collect();
+
+    // Group the changes that are close enough to share their context lines into hunks.
+    let mut hunks: Vec<(usize, usize)> = vec![];
+    for idx in changes {
+        let start = idx.saturating_sub(DIFF_CONTEXT_LINES);
+        let end = (idx + DIFF_CONTEXT_LINES + 1).min(edits.len());
+        match hunks.last_mut() {
+            Some(hunk) if start <= hunk.1 => hunk.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut diff = format!("--- {}\n+++ {}\n", old_name, new_name);
+    for (start, end) in hunks {
+        let old_lines_before = count(&edits[..start], Edit::Insert);
+        let new_lines_before = count(&edits[..start], Edit::Delete);
+        let old_len = count(&edits[start..end], Edit::Insert);
+        let new_len = count(&edits[start..end], Edit::Delete);
+        diff += &format!(
+            "@@ -{},{} +{},{} @@\n",
+            old_lines_before + 1,
+            old_len,
+            new_lines_before + 1,
+            new_len
+        );
+
+        for (edit, line) in &edits[start..end] {
+            let prefix = match edit {
+                Edit::Equal => ' ',
+                Edit::Delete => '-',
+                Edit::Insert => '+',
+            };
+            diff.push(prefix);
+            diff += line;
+            diff.push('\n');
+        }
+    }
+
+    diff
+}
+
+/// The number of edits that aren't `excluded`.
+fn count(edits: &[(Edit, &str)], excluded: Edit) -> usize {
+    edits.iter().filter(|(edit, _)| *edit != excluded).count()
+}
+
+/// The largest number of inserted and deleted lines that we search for the shortest diff of.
+/// Finding the shortest diff takes memory that grows with the square of the number of changes,
+/// so files that differ by more than this are diffed as a whole instead.
+const MAX_DIFF_EDITS: usize = 1000;
+
+/// The line by line edits that turn `old` into `new`, using Myers' algorithm to find the fewest
+/// inserted and deleted lines.
+fn line_edits<'a>(old: &'a str, new: &'a str) -> Vec<(Edit, &'a str)> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    let prefix = old
+        .iter()
+        .zip(new.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let mut edits: Vec<(Edit, &str)> = old[..prefix].iter().map(|l| (Edit::Equal, *l)).collect();
+    match shortest_edits(old_middle, new_middle, MAX_DIFF_EDITS) {
+        Some(middle) => edits.extend(middle),
+        None => {
+            edits.extend(old_middle.iter().map(|l| (Edit::Delete, *l)));
+            edits.extend(new_middle.iter().map(|l| (Edit::Insert, *l)));
+        }
+    }
+    edits.extend(old[old.len() - suffix..].iter().map(|l| (Edit::Equal, *l)));
+    edits
+}
+
+/// The fewest edits that turn `old` into `new`, or `None` if that takes more than `max_edits`
+/// inserted and deleted lines.
+///
+/// See "An O(ND) Difference Algorithm and Its Variations" by Eugene W. Myers.
+fn shortest_edits<'a>(
+    old: &[&'a str],
+    new: &[&'a str],
+    max_edits: usize,
+) -> Option<Vec<(Edit, &'a str)>> {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let max = (n + m).min(max_edits as isize);
+
+    // furthest[k + offset] is how far into `old` we got along diagonal `k`, where `k` is the
+    // index into `old` minus the index into `new`.
+    let offset = max + 1;
+    let mut furthest = vec![0isize; 2 * offset as usize + 1];
+    // The furthest points, from diagonals `-d - 1` to `d + 1`, before each round of `d` edits.
+    let mut trace: Vec<Vec<isize>> = vec![];
+
+    let mut edit_count = None;
+    'search: for d in 0..=max {
+        trace.push(furthest[(offset - d - 1) as usize..=(offset + d + 1) as usize].to_vec());
+
+        for k in (-d..=d).step_by(2) {
+            let at = |k: isize| furthest[(k + offset) as usize];
+            let mut x = if came_from_insert(d, k, at(k - 1), at(k + 1)) {
+                at(k + 1)
+            } else {
+                at(k - 1) + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            furthest[(k + offset) as usize] = x;
+
+            if x >= n && y >= m {
+                edit_count = Some(d);
+                break 'search;
+            }
+        }
+    }
+    let edit_count = edit_count?;
+
+    // Walk back from the end to the start, following the edits that got us furthest.
+    let mut edits = vec![];
+    let (mut x, mut y) = (n, m);
+    for d in (0..=edit_count).rev() {
+        let before = &trace[d as usize];
+        let at = |k: isize| before[(k + d + 1) as usize];
+        let k = x - y;
+        let prev_k = if came_from_insert(d, k, at(k - 1), at(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = at(prev_k);
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            edits.push((Edit::Equal, old[x as usize - 1]));
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            if x == prev_x {
+                edits.push((Edit::Insert, new[y as usize - 1]));
+            } else {
+                edits.push((Edit::Delete, old[x as usize - 1]));
+            }
+            x = prev_x;
+            y = prev_y;
+        }
+    }
+
+    edits.reverse();
+    Some(edits)
+}
+
+/// Whether the furthest path along diagonal `k` after `d` edits ends with an inserted line,
+/// coming from diagonal `k + 1`, instead of a deleted line, coming from diagonal `k - 1`.
+fn came_from_insert(d: isize, k: isize, furthest_below: isize, furthest_above: isize) -> bool {
+    k == -d || (k != d && furthest_below < furthest_above)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `["-a", "+b", " c"]`
+    fn edits(old: &str, new: &str) -> Vec<String> {
+        line_edits(old, new)
+            .into_iter()
+            .map(|(edit, line)| {
+                let prefix = match edit {
+                    Edit::Equal => ' ',
+                    Edit::Delete => '-',
+                    Edit::Insert => '+',
+                };
+                format!("{}{}", prefix, line)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn diffs_identical_files() {
+        assert_eq!(edits("a\nb\nc", "a\nb\nc"), vec![" a", " b", " c"]);
+        assert!(edits("", "").is_empty());
+    }
+
+    #[test]
+    fn diffs_inserted_lines() {
+        assert_eq!(
+            edits("a\nc", "x\na\nb\nc\ny"),
+            vec!["+x", " a", "+b", " c", "+y"]
+        );
+        assert_eq!(edits("", "a\nb"), vec!["+a", "+b"]);
+    }
+
+    #[test]
+    fn diffs_deleted_lines() {
+        assert_eq!(
+            edits("x\na\nb\nc\ny", "a\nc"),
+            vec!["-x", " a", "-b", " c", "-y"]
+        );
+        assert_eq!(edits("a\nb", ""), vec!["-a", "-b"]);
+    }
+
+    #[test]
+    fn diffs_mixed_edits() {
+        assert_eq!(
+            edits("a\nb\nc\nd\ne", "a\nB\nc\ne\nf"),
+            vec![" a", "-b", "+B", " c", "-d", " e", "+f"]
+        );
+    }
+
+    /// Verify that we find the fewest edits, keeping the longest run of common lines.
+    #[test]
+    fn diffs_with_the_fewest_edits() {
+        let diff = edits("a\nb\nc\na\nb\nb\na", "c\nb\na\nb\na\nc");
+        let changed = diff.iter().filter(|line| !line.starts_with(' ')).count();
+        assert_eq!(changed, 5);
+    }
+
+    /// Verify that files that differ by more than `MAX_DIFF_EDITS` lines are diffed as a whole.
+    #[test]
+    fn diffs_files_with_many_changes_as_a_whole() {
+        let old: Vec<String> = (0..MAX_DIFF_EDITS).map(|i| format!("old {}", i)).collect();
+        let new: Vec<String> = (0..MAX_DIFF_EDITS).map(|i| format!("new {}", i)).collect();
+        let (old, new) = (
+            format!("same\n{}\nsame", old.join("\n")),
+            format!("same\n{}\nsame", new.join("\n")),
+        );
+
+        let diff = edits(&old, &new);
+
+        assert_eq!(diff.len(), 2 * MAX_DIFF_EDITS + 2);
+        assert_eq!(diff[1], "-old 0");
+        assert_eq!(diff[MAX_DIFF_EDITS], format!("-old {}", MAX_DIFF_EDITS - 1));
+        assert_eq!(diff[MAX_DIFF_EDITS + 1], "+new 0");
+        assert_eq!(diff.last().unwrap(), " same");
+    }
+
+    #[test]
+    fn formats_unified_diff() {
+        let diff = unified_diff("a\nb\nc", "a\nB\nc", "old.swift", "new.swift");
+        assert_eq!(
+            diff,
+            "--- old.swift\n+++ new.swift\n@@ -1,3 +1,3 @@\n a\n-b\n+B\n c\n"
+        );
+    }
+}
@@ -0,0 +1,715 @@
+use crate::source_location;
+use std::path::{Path, PathBuf};
+use swift_bridge_ir::ExternSwiftSignature;
+
+/// A function or initializer declared in a Swift source file.
+struct SwiftFunction {
+    /// The type, or extension of a type, that the function is declared in.
+    owner: Option<String>,
+    /// The function's name, or `init` for an initializer.
+    name: String,
+    labels: Vec<Option<String>>,
+    arg_types: Vec<String>,
+    return_type: Option<String>,
+    /// `Sources/MyApp/Functions.swift:12`
+    location: String,
+}
+
+/// Compare the `extern "Swift"` functions that the bridge modules call with the functions that
+/// the Swift sources declare, returning a warning for every function that is missing or whose
+/// argument labels or types differ.
+///
+/// A mistake in an `extern "Swift"` declaration otherwise only shows up as a linker error about
+/// an undefined `__swift_bridge__$...` symbol, or as a Swift compiler error in the generated code.
+pub(crate) fn check_swift_signatures(
+    signatures: &[(PathBuf, ExternSwiftSignature)],
+    swift_sources: &[PathBuf],
+) -> Vec<String> {
+    let mut swift_functions = vec![];
+    let mut declared_types = vec![];
+    for swift_file in swift_sources.iter().flat_map(|path| swift_files(path)) {
+        let source = std::fs::read_to_string(&swift_file).unwrap_or_else(|err| {
+            panic!(
+                "Failed to read the Swift source {}: {}",
+                swift_file.display(),
+                err
+            )
+        });
+        let (functions, types) = parse_swift_functions(&source, &swift_file);
+        swift_functions.extend(functions);
+        declared_types.extend(types);
+    }
+
+    let mut warnings = vec![];
+    for (rust_file, signature) in signatures {
+        let declared_at = source_location(rust_file, signature.span);
+        let expected = selector(&signature.name, &signature.labels);
+        let expected = match signature.associated_type.as_ref() {
+            Some(ty) => format!("{}.{}", ty, expected),
+            None => expected,
+        };
+
+        let same_name: Vec<&SwiftFunction> = swift_functions
+            .iter()
+            .filter(|func| func.owner == signature.associated_type && func.name == signature.name)
+            .collect();
+        // Classes and structs that don't declare an initializer get one from the Swift compiler.
+        let has_implicit_init = signature.name == "init"
+            && signature
+                .associated_type
+                .as_ref()
+                .map(|ty| declared_types.contains(ty))
+                .unwrap_or(false)
+            && !swift_functions
+                .iter()
+                .any(|func| func.owner == signature.associated_type && func.name == "init");
+        if same_name.is_empty() && has_implicit_init {
+            continue;
+        }
+        if same_name.is_empty() {
+            warnings.push(format!(
+                "The Swift function `{}` that is declared in the `extern \"Swift\"` block at {} was not found in the Swift sources.",
+                expected, declared_at
+            ));
+            continue;
+        }
+
+        let same_labels: Vec<&SwiftFunction> = same_name
+            .iter()
+            .copied()
+            .filter(|func| func.labels == signature.labels)
+            .collect();
+        if same_labels.is_empty() {
+            let found: Vec<String> = same_name
+                .iter()
+                .map(|func| {
+                    format!(
+                        "`{}` at {}",
+                        selector(&func.name, &func.labels),
+                        func.location
+                    )
+                })
+                .collect();
+            warnings.push(format!(
+                "The Swift function `{}` that is declared in the `extern \"Swift\"` block at {} doesn't match the arguments of {}.",
+                expected,
+                declared_at,
+                found.join(", ")
+            ));
+            continue;
+        }
+
+        let type_mismatches: Vec<Vec<String>> = same_labels
+            .iter()
+            .map(|func| type_mismatches(signature, func))
+            .collect();
+        if type_mismatches
+            .iter()
+            .all(|mismatches| !mismatches.is_empty())
+        {
+            warnings.push(format!(
+                "The Swift function `{}` that is declared in the `extern \"Swift\"` block at {} has different types at {}: {}.",
+                expected,
+                declared_at,
+                same_labels[0].location,
+                type_mismatches[0].join(", ")
+            ));
+        }
+    }
+
+    warnings
+}
+
+/// The checked argument and return types that the Swift function declares differently.
+fn type_mismatches(signature: &ExternSwiftSignature, func: &SwiftFunction) -> Vec<String> {
+    let mut mismatches = vec![];
+
+    for (idx, expected) in signature.arg_types.iter().enumerate() {
+        let expected = match expected {
+            Some(expected) => expected,
+            None => continue,
+        };
+        if &func.arg_types[idx] != expected {
+            mismatches.push(format!(
+                "argument {} is `{}` instead of `{}`",
+                idx + 1,
+                func.arg_types[idx],
+                expected
+            ));
+        }
+    }
+
+    if let Some(expected) = signature.return_type.as_ref() {
+        if func.return_type.as_ref() != Some(expected) {
+            mismatches.push(format!(
+                "it returns `{}` instead of `{}`",
+                func.return_type.as_deref().unwrap_or("Void"),
+                expected
+            ));
+        }
+    }
+
+    mismatches
+}
+
+/// `some_function(arg:_:)`
+fn selector(name: &str, labels: &[Option<String>]) -> String {
+    let labels: String = labels
+        .iter()
+        .map(|label| format!("{}:", label.as_deref().unwrap_or("_")))
+        .collect();
+    format!("{}({})", name, labels)
+}
+
+/// The `.swift` and `.swiftinterface` files at the path, which is either one of those files or a
+/// directory that they are in.
+fn swift_files(path: &Path) -> Vec<PathBuf> {
+    if !path.is_dir() {
+        return vec![path.to_path_buf()];
+    }
+
+    let read_dir_failed = |err: std::io::Error| -> ! {
+        panic!(
+            "Failed to read the Swift sources directory {}: {}",
+            path.display(),
+            err
+        )
+    };
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(path)
+        .unwrap_or_else(|err| read_dir_failed(err))
+        .map(|entry| entry.unwrap_or_else(|err| read_dir_failed(err)).path())
+        .collect();
+    entries.sort();
+
+    entries
+        .into_iter()
+        .filter(|entry| {
+            entry.is_dir()
+                || matches!(
+                    entry.extension().and_then(|e| e.to_str()),
+                    Some("swift") | Some("swiftinterface")
+                )
+        })
+        .flat_map(|entry| swift_files(&entry))
+        .collect()
+}
+
+#[derive(Debug, PartialEq)]
+struct Token {
+    text: String,
+    line: usize,
+}
+
+/// Find the functions and initializers that a Swift file declares, along with the classes,
+/// structs and actors that it declares.
+///
+/// This only understands as much of Swift's syntax as it takes to find the name, argument labels
+/// and types of functions, and the type that they are declared in.
+fn parse_swift_functions(source: &str, file: &Path) -> (Vec<SwiftFunction>, Vec<String>) {
+    let tokens = tokenize(source);
+    let mut functions = vec![];
+    let mut declared_types = vec![];
+
+    // The type of each `{ ... }` scope that we're in, or `None` for a scope that isn't a type,
+    // such as a function body.
+    let mut scopes: Vec<Option<String>> = vec![];
+    let mut pending_type: Option<String> = None;
+
+    let mut idx = 0;
+    while idx < tokens.len() {
+        let token = tokens[idx].text.as_str();
+        let next = tokens.get(idx + 1).map(|t| t.text.as_str());
+
+        match token {
+            "class" | "struct" | "enum" | "extension" | "actor" | "protocol"
+                if next.map(is_type_name).unwrap_or(false) =>
+            {
+                pending_type = next.map(String::from);
+                if ["class", "struct", "actor"].contains(&token) {
+                    declared_types.extend(pending_type.clone());
+                }
+                idx += 2;
+                continue;
+            }
+            "{" => scopes.push(pending_type.take()),
+            "}" => {
+                scopes.pop();
+            }
+            "func" | "init" => {
+                let owner = match scopes.last() {
+                    None => None,
+                    Some(Some(owner)) => Some(owner.clone()),
+                    // Functions that are declared inside of function bodies can't be called by
+                    // the generated code.
+                    Some(None) => {
+                        idx += 1;
+                        continue;
+                    }
+                };
+
+                let (name, mut cursor) = if token == "init" {
+                    ("init".to_string(), idx + 1)
+                } else {
+                    match next {
+                        Some(name) if is_identifier(name) => (name.to_string(), idx + 2),
+                        _ => {
+                            idx += 1;
+                            continue;
+                        }
+                    }
+                };
+
+                // Failable initializers, `init?(...)`, and generic parameters, `<T>`.
+                while cursor < tokens.len() && ["?", "!"].contains(&tokens[cursor].text.as_str()) {
+                    cursor += 1;
+                }
+                if tokens.get(cursor).map(|t| t.text.as_str()) == Some("<") {
+                    cursor = skip_balanced(&tokens, cursor, "<", ">");
+                }
+                if tokens.get(cursor).map(|t| t.text.as_str()) != Some("(") {
+                    idx += 1;
+                    continue;
+                }
+
+                let params_end = skip_balanced(&tokens, cursor, "(", ")");
+                let params = &tokens[cursor + 1..params_end.saturating_sub(1)];
+                let (labels, arg_types) = parse_params(params);
+                let (return_type, end) = parse_return_type(&tokens, params_end);
+
+                functions.push(SwiftFunction {
+                    owner,
+                    name,
+                    labels,
+                    arg_types,
+                    return_type,
+                    location: format!("{}:{}", file.display(), tokens[idx].line),
+                });
+
+                idx = end;
+                continue;
+            }
+            _ => {}
+        }
+
+        idx += 1;
+    }
+
+    (functions, declared_types)
+}
+
+/// The argument labels and types of `label name: Type, _ other: Type = default`.
+fn parse_params(params: &[Token]) -> (Vec<Option<String>>, Vec<String>) {
+    let mut labels = vec![];
+    let mut types = vec![];
+
+    for param in split_top_level(params, ",") {
+        let colon = match param.iter().position(|t| t.text == ":") {
+            Some(colon) => colon,
+            None => continue,
+        };
+
+        let names = &param[..colon];
+        let label = match names.first().map(|t| t.text.as_str()) {
+            Some("_") | None => None,
+            Some(label) => Some(label.to_string()),
+        };
+        labels.push(label);
+
+        let ty = &param[colon + 1..];
+        let ty = split_top_level(ty, "=").into_iter().next().unwrap_or(&[]);
+        types.push(type_string(ty));
+    }
+
+    (labels, types)
+}
+
+/// The return type that follows a function's parameters, such as the `UInt8` in
+/// `) async throws -> UInt8 {`, along with the index of the token after the declaration.
+fn parse_return_type(tokens: &[Token], mut idx: usize) -> (Option<String>, usize) {
+    while idx < tokens.len() && ["async", "throws", "rethrows"].contains(&tokens[idx].text.as_str())
+    {
+        idx += 1;
+    }
+    if tokens.get(idx).map(|t| t.text.as_str()) != Some("->") {
+        return (None, idx);
+    }
+    idx += 1;
+
+    let start = idx;
+    let line = tokens.get(start).map(|t| t.line);
+    let mut depth = 0;
+    while idx < tokens.len() {
+        let token = &tokens[idx];
+        match token.text.as_str() {
+            "(" | "[" | "<" => depth += 1,
+            ")" | "]" | ">" => depth -= 1,
+            "{" | "where" if depth == 0 => break,
+            // Protocol requirements don't have a body, so the next declaration is on a new line.
+            _ if depth == 0 && Some(token.line) != line => break,
+            _ => {}
+        }
+        idx += 1;
+    }
+
+    (Some(type_string(&tokens[start..idx])), idx)
+}
+
+/// `UInt8`, or `[UInt8]` for `@escaping [ UInt8 ]`.
+fn type_string(tokens: &[Token]) -> String {
+    let mut ty = "".to_string();
+    let mut idx = 0;
+    while idx < tokens.len() {
+        match tokens[idx].text.as_str() {
+            // `@escaping`, `@Sendable` and other attributes.
+            "@" => idx += 1,
+            "inout" | "borrowing" | "consuming" | "__owned" | "__shared" => {}
+            text => ty += text,
+        }
+        idx += 1;
+    }
+    ty
+}
+
+/// Split the tokens at the separator wherever it isn't inside of brackets.
+fn split_top_level<'a>(tokens: &'a [Token], separator: &str) -> Vec<&'a [Token]> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (idx, token) in tokens.iter().enumerate() {
+        match token.text.as_str() {
+            "(" | "[" | "<" => depth += 1,
+            ")" | "]" | ">" => depth -= 1,
+            text if text == separator && depth == 0 => {
+                parts.push(&tokens[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    if start < tokens.len() {
+        parts.push(&tokens[start..]);
+    }
+
+    parts
+}
+
+/// The index of the token after the bracket that closes the one at `idx`.
+fn skip_balanced(tokens: &[Token], mut idx: usize, open: &str, close: &str) -> usize {
+    let mut depth = 0;
+    while idx < tokens.len() {
+        if tokens[idx].text == open {
+            depth += 1;
+        } else if tokens[idx].text == close {
+            depth -= 1;
+            if depth == 0 {
+                return idx + 1;
+            }
+        }
+        idx += 1;
+    }
+    idx
+}
+
+fn is_identifier(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_alphabetic() || c == '_')
+}
+
+/// Whether the token after a `class`, `struct`, ... keyword is the name of the type that it
+/// declares, and not something like the `func` in `class func`.
+fn is_type_name(text: &str) -> bool {
+    const DECLARATION_KEYWORDS: [&str; 16] = [
+        "func",
+        "var",
+        "let",
+        "subscript",
+        "init",
+        "deinit",
+        "static",
+        "final",
+        "override",
+        "public",
+        "private",
+        "internal",
+        "fileprivate",
+        "open",
+        "convenience",
+        "required",
+    ];
+    is_identifier(text) && !DECLARATION_KEYWORDS.contains(&text)
+}
+
+/// Split Swift source code into identifiers and punctuation, skipping comments and the contents
+/// of string literals.
+fn tokenize(source: &str) -> Vec<Token> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = vec![];
+    let mut line = 1;
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        let c = chars[idx];
+        let starts_with = |s: &str| chars[idx..].iter().take(s.len()).copied().eq(s.chars());
+
+        if c == '\n' {
+            line += 1;
+            idx += 1;
+        } else if c.is_whitespace() {
+            idx += 1;
+        } else if starts_with("//") {
+            while idx < chars.len() && chars[idx] != '\n' {
+                idx += 1;
+            }
+        } else if starts_with("/*") {
+            let mut depth = 0;
+            while idx < chars.len() {
+                if chars[idx..].starts_with(&['/', '*']) {
+                    depth += 1;
+                    idx += 2;
+                } else if chars[idx..].starts_with(&['*', '/']) {
+                    depth -= 1;
+                    idx += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    if chars[idx] == '\n' {
+                        line += 1;
+                    }
+                    idx += 1;
+                }
+            }
+        } else if c == '"' {
+            let delimiter: &[char] = if starts_with("\"\"\"") {
+                &['"', '"', '"']
+            } else {
+                &['"']
+            };
+            idx += delimiter.len();
+            while idx < chars.len() && !chars[idx..].starts_with(delimiter) {
+                match chars[idx] {
+                    '\\' => idx += 1,
+                    '\n' => line += 1,
+                    _ => {}
+                }
+                idx += 1;
+            }
+            idx += delimiter.len();
+            tokens.push(Token {
+                text: "\"\"".to_string(),
+                line,
+            });
+        } else if c.is_alphanumeric() || c == '_' || c == '`' {
+            let start = idx;
+            while idx < chars.len()
+                && (chars[idx].is_alphanumeric() || chars[idx] == '_' || chars[idx] == '`')
+            {
+                idx += 1;
+            }
+            let text: String = chars[start..idx].iter().filter(|c| **c != '`').collect();
+            tokens.push(Token { text, line });
+        } else if starts_with("->") {
+            tokens.push(Token {
+                text: "->".to_string(),
+                line,
+            });
+            idx += 2;
+        } else {
+            tokens.push(Token {
+                text: c.to_string(),
+                line,
+            });
+            idx += 1;
+        }
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str) -> Vec<SwiftFunction> {
+        parse_swift_functions(source, Path::new("Foo.swift")).0
+    }
+
+    fn signature(
+        name: &str,
+        labels: Vec<Option<&str>>,
+        arg_types: Vec<&str>,
+    ) -> ExternSwiftSignature {
+        ExternSwiftSignature {
+            associated_type: None,
+            name: name.to_string(),
+            labels: labels.into_iter().map(|l| l.map(String::from)).collect(),
+            arg_types: arg_types.into_iter().map(|t| Some(t.to_string())).collect(),
+            return_type: None,
+            span: proc_macro2::Span::call_site(),
+        }
+    }
+
+    /// Verify that we skip over generic parameters and keep the generic arguments of types.
+    #[test]
+    fn parses_generic_functions() {
+        let functions = parse("func foo<T: Equatable>(a: T, b: Array<UInt8>) -> [T] {}");
+
+        assert_eq!(functions.len(), 1);
+        assert_eq!(functions[0].name, "foo");
+        assert_eq!(functions[0].arg_types, vec!["T", "Array<UInt8>"]);
+        assert_eq!(functions[0].return_type.as_deref(), Some("[T]"));
+    }
+
+    /// Verify that we use the external label of each argument, or `None` for `_`.
+    #[test]
+    fn parses_argument_labels() {
+        let functions = parse("func foo(_ a: UInt8, with b: UInt8, c: UInt8 = 5) {}");
+
+        assert_eq!(
+            functions[0].labels,
+            vec![None, Some("with".to_string()), Some("c".to_string())]
+        );
+        assert_eq!(functions[0].arg_types, vec!["UInt8", "UInt8", "UInt8"]);
+        assert_eq!(functions[0].return_type, None);
+    }
+
+    /// Verify that `async`, `throws` and `rethrows` don't end up in the return type.
+    #[test]
+    fn parses_async_and_throwing_functions() {
+        let functions = parse(
+            r#"
+func a() async throws -> UInt8 {}
+func b() throws {}
+func c(cb: () throws -> Void) rethrows -> Bool where T: Equatable {}
+"#,
+        );
+
+        assert_eq!(functions[0].return_type.as_deref(), Some("UInt8"));
+        assert_eq!(functions[1].return_type, None);
+        assert_eq!(functions[2].arg_types, vec!["()throws->Void"]);
+        assert_eq!(functions[2].return_type.as_deref(), Some("Bool"));
+    }
+
+    /// Verify that we skip declaration and type attributes.
+    #[test]
+    fn parses_functions_with_attributes() {
+        let functions = parse(
+            r#"
+@available(macOS 10.15, *)
+@discardableResult
+func foo(cb: @escaping @Sendable (UInt8) -> Void) -> UInt8 {}
+"#,
+        );
+
+        assert_eq!(functions.len(), 1);
+        assert_eq!(functions[0].arg_types, vec!["(UInt8)->Void"]);
+        assert_eq!(functions[0].location, "Foo.swift:4");
+    }
+
+    /// Verify that we ignore functions that are commented out or inside of string literals.
+    #[test]
+    fn skips_comments_and_strings() {
+        let functions = parse(
+            r#"
+// func a() {}
+/* func b() {} /* nested */ func c() {} */
+let d = "func d() {}"
+let e = """
+func e() {}
+"""
+func f() {}
+"#,
+        );
+
+        assert_eq!(functions.len(), 1);
+        assert_eq!(functions[0].name, "f");
+        assert_eq!(functions[0].location, "Foo.swift:8");
+    }
+
+    /// Verify that we record the type that methods and initializers are declared in, and ignore
+    /// functions that are declared inside of function bodies.
+    #[test]
+    fn parses_methods_and_initializers() {
+        let (functions, declared_types) = parse_swift_functions(
+            r#"
+class Foo {
+    init?(a: UInt8) {}
+    class func bar() {
+        func nested() {}
+    }
+}
+extension Baz {
+    func qux() {}
+}
+"#,
+            Path::new("Foo.swift"),
+        );
+
+        let names: Vec<(Option<&str>, &str)> = functions
+            .iter()
+            .map(|f| (f.owner.as_deref(), f.name.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                (Some("Foo"), "init"),
+                (Some("Foo"), "bar"),
+                (Some("Baz"), "qux")
+            ]
+        );
+        assert_eq!(declared_types, vec!["Foo"]);
+    }
+
+    /// Verify that we don't panic on source code that we can't parse, and skip the declarations
+    /// that we can't make sense of.
+    #[test]
+    fn skips_unparseable_declarations() {
+        let functions = parse(
+            r#"
+func
+func (a: UInt8) {}
+func missingParams -> UInt8
+func unterminated(a: [UInt8, b: (UInt8
+let s = "unterminated
+"#,
+        );
+
+        assert!(functions.iter().all(|f| f.name != "missingParams"));
+        assert_eq!(parse("func a(b: UInt8").len(), 1);
+        assert!(parse("/* unterminated").is_empty());
+    }
+
+    /// Verify that we warn about missing functions and functions whose labels or types differ.
+    #[test]
+    fn warns_about_mismatched_signatures() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("Foo.swift"),
+            "func a(arg: UInt8) {}\nfunc b(_ arg: UInt8) {}\nfunc c(arg: UInt16) {}",
+        )
+        .unwrap();
+        let signatures: Vec<(PathBuf, ExternSwiftSignature)> = vec![
+            signature("a", vec![Some("arg")], vec!["UInt8"]),
+            signature("b", vec![Some("arg")], vec!["UInt8"]),
+            signature("c", vec![Some("arg")], vec!["UInt8"]),
+            signature("d", vec![], vec![]),
+        ]
+        .into_iter()
+        .map(|signature| (PathBuf::from("src/lib.rs"), signature))
+        .collect();
+
+        let warnings = check_swift_signatures(&signatures, &[dir.path().to_path_buf()]);
+
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings[0].contains("`b(arg:)`") && warnings[0].contains("`b(_:)`"));
+        assert!(warnings[1].contains("argument 1 is `UInt16` instead of `UInt8`"));
+        assert!(warnings[2].contains("`d()`") && warnings[2].contains("was not found"));
+    }
+
+    /// Verify that we name the Swift source that we couldn't read.
+    #[test]
+    #[should_panic(expected = "Failed to read the Swift source does/not/exist.swift")]
+    fn panics_with_the_path_of_a_missing_swift_source() {
+        check_swift_signatures(&[], &[PathBuf::from("does/not/exist.swift")]);
+    }
+}
@@ -6,12 +6,89 @@ pub fn cli() -> Command<'static> {
         .about("facilitates Rust and Swift interop.")
         .version(env!("CARGO_PKG_VERSION"))
         .subcommand_required(true)
+        .subcommand(parse_bridges_command())
         .subcommand(create_package_command())
+        .subcommand(create_xcframework_command())
+}
+
+/// The command for generating the Swift and C code for bridge modules
+fn parse_bridges_command() -> Command<'static> {
+    Command::new("parse-bridges")
+        .about("Parse Rust files for bridge modules and generate the corresponding Swift and C code.")
+        .arg(
+            Arg::new("crate-name")
+                .long("crate-name")
+                .takes_value(true)
+                .value_name("NAME")
+                .required(true)
+                .help("The name of the crate, used to name the generated files"),
+        )
+        .arg(
+            Arg::new("file")
+                .long("file")
+                .short('f')
+                .takes_value(true)
+                .value_name("PATH")
+                .multiple_occurrences(true)
+                .required(true)
+                .help("A Rust source file containing bridge modules"),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .short('o')
+                .takes_value(true)
+                .value_name("PATH")
+                .required(true)
+                .help("The directory to write the generated Swift and C code to"),
+        )
+        .arg(
+            Arg::new("default-swift-access")
+                .long("default-swift-access")
+                .takes_value(true)
+                .value_name("ACCESS")
+                .help("The Swift access level of generated declarations, such as \"internal\". Defaults to \"public\""),
+        )
+        .arg(
+            Arg::new("swift-formatter")
+                .long("swift-formatter")
+                .takes_value(true)
+                .value_name("COMMAND")
+                .help("A command, such as \"swift-format format\", to pipe the generated Swift code through"),
+        )
+        .arg(
+            Arg::new("swift-source")
+                .long("swift-source")
+                .takes_value(true)
+                .value_name("PATH")
+                .multiple_occurrences(true)
+                .help("A Swift file, .swiftinterface file or directory of them to check the extern \"Swift\" declarations against"),
+        )
+        .arg(
+            Arg::new("snapshot-dir")
+                .long("snapshot-dir")
+                .takes_value(true)
+                .value_name("PATH")
+                .help("A directory of snapshots to compare the generated Swift and C code against. Set SWIFT_BRIDGE_UPDATE_SNAPSHOTS=1 to update them"),
+        )
+        .arg(
+            Arg::new("emit-expansion")
+                .long("emit-expansion")
+                .takes_value(true)
+                .value_name("PATH")
+                .help("Also write the Rust code that each bridge module expands to, along with its Swift and C code, to this directory"),
+        )
+        .arg(
+            Arg::new("watch")
+                .long("watch")
+                .takes_value(false)
+                .help("Keep running and regenerate the Swift and C code whenever one of the files changes"),
+        )
 }
 
 /// The command for creating a Swift Package
 fn create_package_command() -> Command<'static> {
-    Command::new("create-package")
+    with_library_args(Command::new("create-package"))
         .about("Create a Swift Package from Rust code.")
         .arg(
             Arg::new("bridges-dir")
@@ -21,6 +98,55 @@ fn create_package_command() -> Command<'static> {
                 .required(true)
                 .help("The path to the generated bridge files"),
         )
+        .arg(
+            Arg::new("out-dir")
+                .long("out-dir")
+                .takes_value(true)
+                .value_name("PATH")
+                .required(true)
+                .help("The path of the Swift Package"),
+        )
+        .arg(
+            Arg::new("name")
+                .long("name")
+                .takes_value(true)
+                .value_name("PATH")
+                .required(true)
+                .help("The name for the Swift Package"),
+        )
+        .arg(
+            Arg::new("skip-xcframework")
+                .long("skip-xcframework")
+                .takes_value(false)
+                .help("Generate the Package.swift, Swift sources, C headers and module map without building the xcframework"),
+        )
+}
+
+/// The command for assembling an xcframework
+fn create_xcframework_command() -> Command<'static> {
+    with_library_args(Command::new("create-xcframework"))
+        .about("Assemble an xcframework from compiled Rust libraries.")
+        .arg(
+            Arg::new("headers-dir")
+                .long("headers-dir")
+                .takes_value(true)
+                .value_name("PATH")
+                .required(true)
+                .help("The path to the directory containing the C headers and module.modulemap"),
+        )
+        .arg(
+            Arg::new("out")
+                .long("out")
+                .takes_value(true)
+                .value_name("PATH")
+                .required(true)
+                .help("The path of the xcframework"),
+        )
+}
+
+/// Adds an argument for the compiled Rust library of each supported platform
+fn with_library_args(command: Command<'static>) -> Command<'static> {
+    command
         .arg(
             Arg::new("ios")
                 .long("ios")
@@ -84,20 +210,4 @@ fn create_package_command() -> Command<'static> {
                 .value_name("PATH")
                 .help("The path to the compiled Rust library for AppleCarplaySimulator"),
         )
-        .arg(
-            Arg::new("out-dir")
-                .long("out-dir")
-                .takes_value(true)
-                .value_name("PATH")
-                .required(true)
-                .help("The path of the Swift Package"),
-        )
-        .arg(
-            Arg::new("name")
-                .long("name")
-                .takes_value(true)
-                .value_name("PATH")
-                .required(true)
-                .help("The name for the Swift Package"),
-        )
 }
@@ -1,36 +1,132 @@
 use clap::ArgMatches;
 use std::collections::HashMap;
+use std::fs;
+use std::panic::AssertUnwindSafe;
 use std::path::{Path, PathBuf};
-use swift_bridge_build::{create_package, ApplePlatform, CreatePackageConfig};
+use std::time::{Duration, SystemTime};
+use swift_bridge_build::{
+    create_package, create_package_sources, create_xcframework, parse_bridges_with_config,
+    ApplePlatform, BridgesConfig, CreatePackageConfig, CreateXcframeworkConfig,
+};
 
 /// Executes the correct function depending on the cli input
 pub fn handle_matches(matches: ArgMatches) {
     match matches.subcommand_name() {
+        Some("parse-bridges") => {
+            handle_parse_bridges(matches.subcommand_matches("parse-bridges").unwrap())
+        }
         Some("create-package") => {
             handle_create_package(matches.subcommand_matches("create-package").unwrap())
         }
+        Some("create-xcframework") => {
+            handle_create_xcframework(matches.subcommand_matches("create-xcframework").unwrap())
+        }
         _ => unreachable!("No subcommand or unknown subcommand given"), // Shouldn't happen
     }
 }
 
+/// Executes the `parse-bridges` command
+fn handle_parse_bridges(matches: &ArgMatches) {
+    let crate_name = matches.value_of("crate-name").unwrap(); // required
+    let files: Vec<&str> = matches.values_of("file").unwrap().collect(); // required
+    let output = matches.value_of("output").unwrap(); // required
+
+    let config = BridgesConfig {
+        default_swift_access: matches.value_of("default-swift-access").map(String::from),
+        swift_formatter: matches
+            .value_of("swift-formatter")
+            .map(|command| command.split_whitespace().map(String::from).collect()),
+        swift_sources: matches
+            .values_of("swift-source")
+            .map(|sources| sources.map(PathBuf::from).collect())
+            .unwrap_or_default(),
+        snapshot_dir: matches.value_of("snapshot-dir").map(PathBuf::from),
+    };
+
+    let emit_expansion = matches.value_of("emit-expansion");
+
+    let generate = || {
+        let generated = parse_bridges_with_config(&files, &config);
+        generated.write_all_concatenated(output, crate_name);
+        if let Some(expansion_dir) = emit_expansion {
+            generated.write_expansions(expansion_dir);
+        }
+    };
+
+    if matches.is_present("watch") {
+        watch(&files, generate);
+    } else {
+        generate();
+    }
+}
+
+/// Calls `generate` whenever one of the files is modified, until the process is killed.
+///
+/// A bridge module that fails to parse while it is being edited is reported, and we keep
+/// watching for the next change.
+fn watch(files: &[&str], generate: impl Fn()) -> ! {
+    let modified_times = || -> Vec<Option<SystemTime>> {
+        files
+            .iter()
+            .map(|file| fs::metadata(file).and_then(|m| m.modified()).ok())
+            .collect()
+    };
+
+    let mut last_modified = None;
+    loop {
+        let modified = modified_times();
+        if last_modified.as_ref() != Some(&modified) {
+            if std::panic::catch_unwind(AssertUnwindSafe(&generate)).is_ok() {
+                eprintln!("Generated Swift and C code. Watching for changes...");
+            }
+            last_modified = Some(modified);
+        }
+
+        std::thread::sleep(Duration::from_millis(250));
+    }
+}
+
 /// Executes the `create-package` command
 fn handle_create_package(matches: &ArgMatches) {
     let bridges_dir = matches.value_of("bridges-dir").unwrap(); // required
     let out_dir = matches.value_of("out-dir").map(|p| Path::new(p)).unwrap(); // required
     let name = matches.value_of("name").unwrap(); // required
 
-    let mut config = CreatePackageConfig {
+    let config = CreatePackageConfig {
         bridge_dir: PathBuf::from(bridges_dir),
-        paths: HashMap::new(),
+        paths: library_paths(matches),
         out_dir: out_dir.to_path_buf(),
         package_name: name.to_string(),
     };
 
+    if matches.is_present("skip-xcframework") {
+        create_package_sources(&config);
+    } else {
+        create_package(config);
+    }
+}
+
+/// Executes the `create-xcframework` command
+fn handle_create_xcframework(matches: &ArgMatches) {
+    let headers_dir = matches.value_of("headers-dir").unwrap(); // required
+    let out = matches.value_of("out").unwrap(); // required
+
+    create_xcframework(&CreateXcframeworkConfig {
+        libraries: library_paths(matches),
+        headers_dir: PathBuf::from(headers_dir),
+        out_path: PathBuf::from(out),
+    });
+}
+
+/// The compiled Rust library of each platform that was passed to the command
+fn library_paths(matches: &ArgMatches) -> HashMap<ApplePlatform, PathBuf> {
+    let mut paths = HashMap::new();
+
     for platform in ApplePlatform::ALL {
         if let Some(path) = matches.value_of(platform.dir_name()) {
-            config.paths.insert(*platform, PathBuf::from(path));
+            paths.insert(*platform, PathBuf::from(path));
         }
     }
 
-    create_package(config);
+    paths
 }
@@ -1,6 +1,6 @@
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
-use syn::{Path, Token};
+use syn::{LitStr, Path, Token};
 
 /// The `...` in
 /// `#\[swift_bridge::bridge(...)\]`
@@ -15,6 +15,28 @@ pub enum SwiftBridgeModuleAttr {
     /// as `RustString`.
     /// `#\[swift_bridge::bridge(swift_bridge_path = swift_bridge)\]`
     SwiftBridgePath(Path),
+    /// Prefixes the Swift names of the module's Rust types.
+    /// `#\[swift_bridge::bridge(swift_type_prefix = "MyLib")\]`
+    SwiftTypePrefix(LitStr),
+    /// Adds inlining hints to the generated Rust and Swift wrapper functions.
+    /// `#\[swift_bridge::bridge(inline_shims)\]`
+    InlineShims,
+    /// Catches panics in the generated Rust extern functions instead of letting them unwind
+    /// into Swift.
+    /// `#\[swift_bridge::bridge(catch_panics = "throw")\]`
+    CatchPanics(PanicBehavior),
+}
+
+/// What the generated Swift code does when a Rust function panics.
+/// Set using `#\[swift_bridge::bridge(catch_panics = "...")\]`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PanicBehavior {
+    /// Call Swift's `fatalError` with the panic message.
+    /// `catch_panics = "fatal_error"`
+    FatalError,
+    /// Throw a `RustPanic` error that holds the panic message.
+    /// `catch_panics = "throw"`
+    Throw,
 }
 
 impl Parse for SwiftBridgeModuleAttrs {
@@ -34,10 +56,33 @@ impl Parse for SwiftBridgeModuleAttrs {
 impl Parse for SwiftBridgeModuleAttr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let key: Ident = input.parse()?;
-        let _equals = input.parse::<Token![=]>()?;
 
         let attr = match key.to_string().as_str() {
-            "swift_bridge_path" => SwiftBridgeModuleAttr::SwiftBridgePath(input.parse()?),
+            "swift_bridge_path" => {
+                input.parse::<Token![=]>()?;
+                SwiftBridgeModuleAttr::SwiftBridgePath(input.parse()?)
+            }
+            "swift_type_prefix" => {
+                input.parse::<Token![=]>()?;
+                SwiftBridgeModuleAttr::SwiftTypePrefix(input.parse()?)
+            }
+            "inline_shims" => SwiftBridgeModuleAttr::InlineShims,
+            "catch_panics" => {
+                input.parse::<Token![=]>()?;
+                let behavior: LitStr = input.parse()?;
+
+                let behavior = match behavior.value().as_str() {
+                    "fatal_error" => PanicBehavior::FatalError,
+                    "throw" => PanicBehavior::Throw,
+                    _ => {
+                        return Err(syn::Error::new(
+                            behavior.span(),
+                            r#"Unsupported panic behavior. Expected "fatal_error" or "throw"."#,
+                        ));
+                    }
+                };
+                SwiftBridgeModuleAttr::CatchPanics(behavior)
+            }
             _ => {
                 return Err(syn::Error::new(input.span(), "Unknown attribute."));
             }
@@ -1,9 +1,12 @@
-use proc_macro2::Ident;
+use crate::codegen::CodegenConfig;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use syn::LitStr;
 use syn::Token;
 
-/// A `cfg` attribute on a bridge module.
+/// A `cfg` attribute on a bridge module, or on an item within a bridge module.
 ///
 /// ```no_run
 /// #[swift_bridge::bridge]
@@ -12,25 +15,101 @@ use syn::Token;
 /// mod ffi {
 /// }
 /// ```
+#[derive(Clone)]
 pub enum CfgAttr {
     /// #\[cfg(feature = "...")\]
     Feature(LitStr),
+    /// #\[cfg(some_cfg)\], such as #\[cfg(debug_assertions)\]
+    Key(Ident),
+    /// #\[cfg(some_cfg = "...")\], such as #\[cfg(target_os = "ios")\]
+    KeyValue(Ident, LitStr),
+    /// #\[cfg(not(...))\]
+    Not(Box<CfgAttr>),
+    /// #\[cfg(all(...))\]
+    All(Vec<CfgAttr>),
+    /// #\[cfg(any(...))\]
+    Any(Vec<CfgAttr>),
+}
+
+impl CfgAttr {
+    /// Whether or not the predicate holds for the crate that contains the bridge module.
+    pub(crate) fn is_active(&self, config: &CodegenConfig) -> bool {
+        match self {
+            CfgAttr::Feature(feature_name) => (config.crate_feature_lookup)(&feature_name.value()),
+            CfgAttr::Key(key) => (config.crate_cfg_lookup)(&key.to_string(), None),
+            CfgAttr::KeyValue(key, value) => {
+                (config.crate_cfg_lookup)(&key.to_string(), Some(&value.value()))
+            }
+            CfgAttr::Not(cfg) => !cfg.is_active(config),
+            CfgAttr::All(cfgs) => cfgs.iter().all(|cfg| cfg.is_active(config)),
+            CfgAttr::Any(cfgs) => cfgs.iter().any(|cfg| cfg.is_active(config)),
+        }
+    }
+
+    /// Parse the `#[cfg(...)]` attributes out of a list of attributes.
+    pub(crate) fn from_attributes(attrs: &[syn::Attribute]) -> syn::Result<Vec<CfgAttr>> {
+        attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("cfg"))
+            .map(|attr| syn::parse2(attr.tokens.clone()))
+            .collect()
+    }
+
+    /// Parse a predicate such as `feature = "..."` or `not(...)`.
+    fn parse_predicate(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            let value = input.parse::<LitStr>()?;
+
+            if &ident == "feature" {
+                return Ok(CfgAttr::Feature(value));
+            }
+            return Ok(CfgAttr::KeyValue(ident, value));
+        }
+
+        if !input.peek(syn::token::Paren) {
+            return Ok(CfgAttr::Key(ident));
+        }
+
+        let content;
+        syn::parenthesized!(content in input);
+        let predicates: Punctuated<CfgAttr, Token![,]> =
+            content.parse_terminated(CfgAttr::parse_predicate)?;
+        let mut predicates: Vec<CfgAttr> = predicates.into_iter().collect();
+
+        match ident.to_string().as_str() {
+            "not" if predicates.len() == 1 => Ok(CfgAttr::Not(Box::new(predicates.remove(0)))),
+            "all" => Ok(CfgAttr::All(predicates)),
+            "any" => Ok(CfgAttr::Any(predicates)),
+            _ => Err(syn::Error::new(
+                ident.span(),
+                "Unsupported cfg predicate. Expected `not(...)`, `all(...)` or `any(...)`.",
+            )),
+        }
+    }
 }
 
 impl Parse for CfgAttr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let content;
         syn::parenthesized!(content in input);
-        let ident: Ident = content.parse()?;
-
-        if &ident == "feature" {
-            content.parse::<Token![=]>()?;
 
-            let feature_name = content.parse::<LitStr>()?;
+        CfgAttr::parse_predicate(&content)
+    }
+}
 
-            Ok(CfgAttr::Feature(feature_name))
-        } else {
-            todo!("Return an unsupported cfg kind error")
-        }
+impl ToTokens for CfgAttr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let predicate = match self {
+            CfgAttr::Feature(feature_name) => quote! { feature = #feature_name },
+            CfgAttr::Key(key) => quote! { #key },
+            CfgAttr::KeyValue(key, value) => quote! { #key = #value },
+            CfgAttr::Not(cfg) => quote! { not(#cfg) },
+            CfgAttr::All(cfgs) => quote! { all(#(#cfgs),*) },
+            CfgAttr::Any(cfgs) => quote! { any(#(#cfgs),*) },
+        };
+        predicate.to_tokens(tokens);
     }
 }
@@ -12,7 +12,22 @@ use crate::bridged_type::boxed_fn::BridgeableBoxedFnOnce;
 use crate::bridged_type::bridgeable_pointer::{BuiltInPointer, Pointee, PointerKind};
 use crate::bridged_type::bridgeable_result::BuiltInResult;
 use crate::bridged_type::bridgeable_string::BridgedString;
+use crate::bridged_type::built_in_arc::BuiltInArc;
+use crate::bridged_type::built_in_array::BuiltInArray;
+use crate::bridged_type::built_in_core_graphics::BuiltInCoreGraphics;
+use crate::bridged_type::built_in_cow_str::BuiltInCowStr;
+use crate::bridged_type::built_in_decimal::BuiltInDecimal;
+use crate::bridged_type::built_in_hash_map::BuiltInHashMap;
+use crate::bridged_type::built_in_hash_set::BuiltInHashSet;
+use crate::bridged_type::built_in_int128::BuiltInInt128;
+use crate::bridged_type::built_in_non_zero::BuiltInNonZero;
+use crate::bridged_type::built_in_ns_error::BuiltInNsError;
+use crate::bridged_type::built_in_opaque_slice::BuiltInOpaqueSlice;
+use crate::bridged_type::built_in_path::BuiltInPath;
+use crate::bridged_type::built_in_time::BuiltInTime;
 use crate::bridged_type::built_in_tuple::BuiltInTuple;
+use crate::bridged_type::built_in_url::BuiltInUrl;
+use crate::bridged_type::built_in_uuid::BuiltInUuid;
 
 use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
 
@@ -27,8 +42,23 @@ pub mod bridgeable_str;
 pub mod bridgeable_string;
 pub mod bridged_opaque_type;
 mod bridged_option;
+mod built_in_arc;
+mod built_in_array;
+mod built_in_core_graphics;
+mod built_in_cow_str;
+mod built_in_decimal;
+mod built_in_hash_map;
+mod built_in_hash_set;
+mod built_in_int128;
+mod built_in_non_zero;
+mod built_in_ns_error;
+mod built_in_opaque_slice;
+mod built_in_path;
 mod built_in_primitive;
+mod built_in_time;
 mod built_in_tuple;
+mod built_in_url;
+mod built_in_uuid;
 mod shared_enum;
 pub(crate) mod shared_struct;
 
@@ -110,6 +140,16 @@ pub(crate) trait BridgeableType: Debug {
     /// Some(vec![typedef struct __swift_bridge__$ResultVoidAndTransparentEnum { //... };])
     fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct>;
 
+    /// Generate the type's Swift support code if needed.
+    ///
+    /// # Examples
+    /// String -> None
+    /// HashMap<String, u64> ->
+    /// Some("extension RustMap where K == RustString, V == UInt64 { //... }")
+    fn generate_custom_swift_code(&self, _types: &TypeDeclarations) -> Option<String> {
+        None
+    }
+
     /// Get the Rust representation of this type.
     /// For a string this might be `std::string::String`.
     fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream;
@@ -136,22 +176,79 @@ pub(crate) trait BridgeableType: Debug {
         types: &TypeDeclarations,
     ) -> TokenStream;
 
+    /// Whether or not `Option<Self>` can be bridged.
+    ///
+    /// `Option`s of types that return `false` are rejected while parsing, so the `Option` methods
+    /// below are never called for them and can be left unimplemented.
+    fn supports_option(&self) -> bool {
+        true
+    }
+
+    /// Whether or not `Vec<Self>` can be bridged.
+    ///
+    /// `Vec`s of types that return `false` are rejected while parsing.
+    fn supports_vec(&self) -> bool {
+        true
+    }
+
+    /// Whether or not `Self` can be the `T` or the `E` of a `Result<T, E>`.
+    ///
+    /// `Result`s of types that return `false` are rejected while parsing.
+    fn supports_result(&self) -> bool {
+        true
+    }
+
+    /// Whether or not Swift can pass `Self` to Rust, such as an `extern "Rust"` function's argument
+    /// or an `extern "Swift"` function's return value.
+    ///
+    /// Types that return `false` are rejected in those positions while parsing, so the methods
+    /// that convert Swift values into Rust values are never called for them.
+    fn supports_swift_to_rust(&self) -> bool {
+        true
+    }
+
+    /// Whether or not `Self` can be the type of a shared struct's or shared enum's field.
+    ///
+    /// Fields of types that return `false` are rejected while parsing, so
+    /// [`TypePosition::SharedStructField`] is never used for them.
+    fn supports_shared_struct_field(&self) -> bool {
+        true
+    }
+
+    /// The symbols that [`BridgeableType::generate_custom_rust_ffi_types`] exports, such as the
+    /// `__swift_bridge__$ffi$HashMap$String$U64$new` of a `HashMap<String, u64>`.
+    fn custom_ffi_symbols(&self, _types: &TypeDeclarations) -> Vec<String> {
+        vec![]
+    }
+
+    /// The Swift type, such as `RustMap<RustString, UInt64>`, that
+    /// [`BridgeableType::generate_custom_swift_code`] extends for types whose support code every
+    /// bridge module that uses the type generates.
+    ///
+    /// When the Swift code of several bridge modules gets compiled together only one of the
+    /// extensions should be kept, since they declare the same members.
+    fn shared_swift_code_key(&self, _types: &TypeDeclarations) -> Option<String> {
+        None
+    }
+
     /// Get the FFI compatible Option<Self> representation.
     fn to_ffi_compatible_option_rust_type(
         &self,
-        swift_bridge_path: &Path,
-        types: &TypeDeclarations,
-    ) -> TokenStream;
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        option_rejected_while_parsing(self)
+    }
 
     /// Get the FFI compatible Option<Self> representation.
-    fn to_ffi_compatible_option_swift_type(
-        &self,
-        swift_bridge_path: &Path,
-        types: &TypeDeclarations,
-    ) -> String;
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        option_rejected_while_parsing(self)
+    }
 
     /// Get the FFI compatible Option<Self> representation.
-    fn to_ffi_compatible_option_c_type(&self) -> String;
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        option_rejected_while_parsing(self)
+    }
 
     /// Convert a Rust expression to an FFI compatible type.
     fn convert_rust_expression_to_ffi_type(
@@ -165,9 +262,11 @@ pub(crate) trait BridgeableType: Debug {
     /// Convert a an `Option<Self>` Rust expression to an FFI compatible type.
     fn convert_option_rust_expression_to_ffi_type(
         &self,
-        expression: &TokenStream,
-        swift_bridge_path: &Path,
-    ) -> TokenStream;
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        option_rejected_while_parsing(self)
+    }
 
     /// Convert a Swift expression into an FFI compatible type.
     fn convert_swift_expression_to_ffi_type(
@@ -180,9 +279,11 @@ pub(crate) trait BridgeableType: Debug {
     /// Convert a an `Option<Self>` Rust expression to an FFI compatible type.
     fn convert_option_swift_expression_to_ffi_type(
         &self,
-        expression: &str,
-        type_pos: TypePosition,
-    ) -> String;
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        option_rejected_while_parsing(self)
+    }
 
     /// Convert an FFI expression to this type's Rust representation.
     ///
@@ -199,7 +300,13 @@ pub(crate) trait BridgeableType: Debug {
     ) -> TokenStream;
 
     /// Convert an Option<Self> FFI representation to the Rust representation.
-    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream;
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        option_rejected_while_parsing(self)
+    }
 
     /// Convert an FFI expression to this type's Swift representation.
     fn convert_ffi_expression_to_swift_type(
@@ -210,7 +317,9 @@ pub(crate) trait BridgeableType: Debug {
     ) -> String;
 
     /// Convert an Option<Self> FFI representation to the Rust representation.
-    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String;
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        option_rejected_while_parsing(self)
+    }
 
     /// Convert an FFI Result::Ok value to Rust value.
     ///
@@ -234,9 +343,6 @@ pub(crate) trait BridgeableType: Debug {
         types: &TypeDeclarations,
     ) -> TokenStream;
 
-    /// The value used to represent `Option<Self>::None` over FFI.
-    fn unused_option_none_val(&self, swift_bridge_path: &Path) -> UnusedOptionNoneValue;
-
     /// Whether or not a string can be parsed by this type.
     ///
     /// # Examples
@@ -414,6 +520,12 @@ pub(crate) struct BuiltInVec {
     pub ty: Box<BridgedType>,
 }
 
+/// The `Option` methods of types that don't support `Option`. See
+/// [`BridgeableType::supports_option`].
+fn option_rejected_while_parsing<T: BridgeableType + ?Sized>(ty: &T) -> ! {
+    unreachable!("Option<{:?}> should have been rejected while parsing", ty)
+}
+
 impl BridgedType {
     pub fn is_null(&self) -> bool {
         matches!(self, BridgedType::StdLib(StdLibType::Null))
@@ -524,7 +636,28 @@ impl BridgeableType for BridgedType {
                 _ => None,
             },
             BridgedType::Foreign(_) => None,
-            BridgedType::Bridgeable(_) => None,
+            BridgedType::Bridgeable(ty) => ty.generate_custom_c_ffi_types(types),
+        }
+    }
+
+    fn generate_custom_swift_code(&self, types: &TypeDeclarations) -> Option<String> {
+        match self {
+            BridgedType::Bridgeable(ty) => ty.generate_custom_swift_code(types),
+            _ => None,
+        }
+    }
+
+    fn custom_ffi_symbols(&self, types: &TypeDeclarations) -> Vec<String> {
+        match self {
+            BridgedType::Bridgeable(ty) => ty.custom_ffi_symbols(types),
+            _ => vec![],
+        }
+    }
+
+    fn shared_swift_code_key(&self, types: &TypeDeclarations) -> Option<String> {
+        match self {
+            BridgedType::Bridgeable(ty) => ty.shared_swift_code_key(types),
+            _ => None,
         }
     }
 
@@ -560,11 +693,7 @@ impl BridgeableType for BridgedType {
         todo!()
     }
 
-    fn to_ffi_compatible_option_swift_type(
-        &self,
-        _swift_bridge_path: &Path,
-        _types: &TypeDeclarations,
-    ) -> String {
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
         todo!()
     }
 
@@ -609,15 +738,19 @@ impl BridgeableType for BridgedType {
 
     fn convert_ffi_expression_to_rust_type(
         &self,
-        _expression: &TokenStream,
-        _span: Span,
-        _swift_bridge_path: &Path,
-        _types: &TypeDeclarations,
+        expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
     ) -> TokenStream {
-        todo!()
+        self.convert_ffi_expression_to_rust_type(expression, span, swift_bridge_path, types)
     }
 
-    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
         todo!()
     }
 
@@ -652,10 +785,6 @@ impl BridgeableType for BridgedType {
         self.convert_ffi_result_err_value_to_rust_value(err_ffi_value, swift_bridge_path, types)
     }
 
-    fn unused_option_none_val(&self, swift_bridge_path: &Path) -> UnusedOptionNoneValue {
-        self.unused_option_none_val(swift_bridge_path)
-    }
-
     fn can_parse_token_stream_str(_tokens: &str) -> bool
     where
         Self: Sized,
@@ -750,15 +879,33 @@ impl BridgedType {
                         if path == "str" {
                             return Some(BridgedType::StdLib(StdLibType::Str));
                         }
+                        if BuiltInPath::is_path(&path) && ty_ref.mutability.is_none() {
+                            return Some(BridgedType::Bridgeable(Box::new(BuiltInPath {
+                                reference: true,
+                            })));
+                        }
 
                         None
                     }
                 }
-                Type::Slice(slice) => Self::new_with_type(&slice.elem, types).map(|ty| {
-                    BridgedType::StdLib(StdLibType::RefSlice(BuiltInRefSlice { ty: Box::new(ty) }))
-                }),
+                Type::Slice(slice) => {
+                    if ty_ref.mutability.is_none() {
+                        if let Some(ty) = BuiltInOpaqueSlice::from_slice_elem(&slice.elem, types) {
+                            return Some(BridgedType::Bridgeable(Box::new(ty)));
+                        }
+                    }
+
+                    Self::new_with_type(&slice.elem, types).map(|ty| {
+                        BridgedType::StdLib(StdLibType::RefSlice(BuiltInRefSlice {
+                            ty: Box::new(ty),
+                        }))
+                    })
+                }
                 _ => None,
             },
+            Type::Array(_) => {
+                BuiltInArray::from_type(ty, types).map(|ty| BridgedType::Bridgeable(Box::new(ty)))
+            }
             Type::Tuple(tuple) => {
                 if tuple.elems.len() == 0 {
                     Some(BridgedType::StdLib(StdLibType::Null))
@@ -792,7 +939,9 @@ impl BridgedType {
         let tokens = tokens.as_str();
         if tokens.starts_with("Vec < ") {
             let inner = tokens.trim_start_matches("Vec < ");
-            let inner = inner.trim_end_matches(" >");
+            // Only strip the one closing bracket so that nested generics such as
+            // "Vec < Box < dyn SomeTrait > >" keep their own brackets.
+            let inner = inner.strip_suffix(" >").unwrap_or(inner);
 
             let inner = if let Some(declared_ty) = types.get(inner) {
                 declared_ty.to_bridged_type(false, false)
@@ -800,6 +949,11 @@ impl BridgedType {
                 let inner: Type = syn::parse2(TokenStream::from_str(inner).unwrap()).unwrap();
                 BridgedType::new_with_type(&inner, types)?
             };
+            if let BridgedType::Bridgeable(b) = &inner {
+                if !b.supports_vec() {
+                    return None;
+                }
+            }
 
             return Some(BridgedType::StdLib(StdLibType::Vec(BuiltInVec {
                 ty: Box::new(inner),
@@ -808,7 +962,9 @@ impl BridgedType {
             let last_bracket = tokens.rfind(">")?;
 
             let inner = &tokens[0..last_bracket];
-            let inner = inner.trim_start_matches("Option < ");
+            // Only strip the one `Option`, so that `Option<Option<u8>>` has the inner type
+            // `Option<u8>`.
+            let inner = inner.strip_prefix("Option < ")?;
 
             // Remove spaces from generics. i.e. "SomeType < u32 > " -> "SomeType<u32>"
             let inner = if inner.contains("<") {
@@ -817,8 +973,18 @@ impl BridgedType {
                 inner.to_string()
             };
 
-            let inner: Type = syn::parse2(TokenStream::from_str(&inner).unwrap()).unwrap();
+            let inner: Type = syn::parse2(TokenStream::from_str(&inner).ok()?).ok()?;
             let inner = BridgedType::new_with_type(&inner, types)?;
+            if let BridgedType::Bridgeable(b) = &inner {
+                if !b.supports_option() {
+                    return None;
+                }
+            }
+            // `Option<Option<T>>` has no FFI representation, since both `None`s would share the
+            // same one.
+            if let BridgedType::StdLib(StdLibType::Option(_)) = &inner {
+                return None;
+            }
 
             return Some(BridgedType::StdLib(StdLibType::Option(BridgedOption {
                 ty: Box::new(inner),
@@ -827,16 +993,96 @@ impl BridgedType {
             return Some(BridgedType::StdLib(StdLibType::Result(
                 BuiltInResult::from_str_tokens(&tokens, types)?,
             )));
-        } else if tokens.starts_with("Box < dyn FnOnce") {
+        } else if BridgeableBoxedFnOnce::can_parse_token_stream_str(tokens) {
             return Some(BridgedType::StdLib(StdLibType::BoxedFnOnce(
                 BridgeableBoxedFnOnce::from_str_tokens(&tokens, types)?,
             )));
+        } else if BuiltInArc::can_parse_token_stream_str(tokens) {
+            return Some(BridgedType::Bridgeable(Box::new(
+                BuiltInArc::parse_token_stream_str(tokens, types)?,
+            )));
+        } else if BuiltInArray::can_parse_token_stream_str(tokens) {
+            return Some(BridgedType::Bridgeable(Box::new(
+                BuiltInArray::parse_token_stream_str(tokens, types)?,
+            )));
+        } else if BuiltInCowStr::can_parse_token_stream_str(tokens) {
+            return Some(BridgedType::Bridgeable(Box::new(
+                BuiltInCowStr::parse_token_stream_str(tokens, types)?,
+            )));
+        } else if BuiltInHashMap::can_parse_token_stream_str(tokens) {
+            return Some(BridgedType::Bridgeable(Box::new(
+                BuiltInHashMap::parse_token_stream_str(tokens, types)?,
+            )));
+        } else if BuiltInHashSet::can_parse_token_stream_str(tokens) {
+            return Some(BridgedType::Bridgeable(Box::new(
+                BuiltInHashSet::parse_token_stream_str(tokens, types)?,
+            )));
+        } else if BuiltInInt128::can_parse_token_stream_str(tokens) {
+            return Some(BridgedType::Bridgeable(Box::new(
+                BuiltInInt128::parse_token_stream_str(tokens, types)?,
+            )));
+        } else if BuiltInNonZero::can_parse_token_stream_str(tokens) {
+            return Some(BridgedType::Bridgeable(Box::new(
+                BuiltInNonZero::parse_token_stream_str(tokens, types)?,
+            )));
+        } else if BuiltInTime::can_parse_token_stream_str(tokens) {
+            return Some(BridgedType::Bridgeable(Box::new(
+                BuiltInTime::parse_token_stream_str(tokens, types)?,
+            )));
+        } else if BuiltInPath::can_parse_token_stream_str(tokens) {
+            return Some(BridgedType::Bridgeable(Box::new(
+                BuiltInPath::parse_token_stream_str(tokens, types)?,
+            )));
+        } else if BuiltInUrl::can_parse_token_stream_str(tokens) && types.get(tokens).is_none() {
+            // A bridge module can declare its own `Url` type, which takes precedence.
+            return Some(BridgedType::Bridgeable(Box::new(
+                BuiltInUrl::parse_token_stream_str(tokens, types)?,
+            )));
+        } else if BuiltInUuid::can_parse_token_stream_str(tokens) && types.get(tokens).is_none() {
+            // A bridge module can declare its own `Uuid` type, which takes precedence.
+            return Some(BridgedType::Bridgeable(Box::new(
+                BuiltInUuid::parse_token_stream_str(tokens, types)?,
+            )));
+        } else if BuiltInDecimal::can_parse_token_stream_str(tokens) && types.get(tokens).is_none()
+        {
+            // A bridge module can declare its own `Decimal` type, which takes precedence.
+            return Some(BridgedType::Bridgeable(Box::new(
+                BuiltInDecimal::parse_token_stream_str(tokens, types)?,
+            )));
+        } else if BuiltInCoreGraphics::can_parse_token_stream_str(tokens)
+            && types.get(tokens).is_none()
+        {
+            // A bridge module can declare its own `CGPoint` type, for example, which takes
+            // precedence.
+            return Some(BridgedType::Bridgeable(Box::new(
+                BuiltInCoreGraphics::parse_token_stream_str(tokens, types)?,
+            )));
+        } else if BuiltInNsError::can_parse_token_stream_str(tokens) && types.get(tokens).is_none()
+        {
+            // A bridge module can declare its own `NsError` type, which takes precedence.
+            return Some(BridgedType::Bridgeable(Box::new(
+                BuiltInNsError::parse_token_stream_str(tokens, types)?,
+            )));
+        } else if tokens.starts_with("Box < dyn ") {
+            let inner = tokens.trim_start_matches("Box < dyn ");
+            let inner = inner.trim_end_matches(" >");
+
+            let declared_ty = types.get(inner)?;
+            return match declared_ty {
+                TypeDeclaration::Opaque(opaque) if opaque.trait_object => {
+                    Some(declared_ty.to_bridged_type(false, false))
+                }
+                _ => None,
+            };
         } else if tokens.starts_with("(") {
             let tuple: Type = syn::parse2(TokenStream::from_str(&tokens).unwrap()).unwrap();
             return BridgedType::new_with_type(&tuple, types);
         }
 
         let ty = match tokens {
+            // Only `'static` borrows can be named inside of generics such as `Result`, since an
+            // elided lifetime would have nothing to borrow from.
+            "& 'static str" => BridgedType::StdLib(StdLibType::Str),
             "u8" => BridgedType::StdLib(StdLibType::U8),
             "i8" => BridgedType::StdLib(StdLibType::I8),
             "u16" => BridgedType::StdLib(StdLibType::U16),
@@ -1167,7 +1413,10 @@ impl BridgedType {
                 StdLibType::Vec(ty) => match type_pos {
                     TypePosition::FnArg(func_host_lang, _) => {
                         if func_host_lang.is_rust() {
-                            format!("RustVec<{}>", ty.ty.to_swift_type(type_pos, types))
+                            // The elements are owned by the RustVec, so they're seen as the type
+                            // that Rust would return. i.e. `Vec<String>` -> `RustVec<RustString>`.
+                            let elem_pos = TypePosition::FnReturn(HostLang::Rust);
+                            format!("RustVec<{}>", ty.ty.to_swift_type(elem_pos, types))
                         } else {
                             "UnsafeMutableRawPointer".to_string()
                         }
@@ -1179,13 +1428,16 @@ impl BridgedType {
                             "UnsafeMutableRawPointer".to_string()
                         }
                     }
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                        "UnsafeMutableRawPointer?".to_string()
+                    }
                     _ => {
                         format!("RustVec<{}>", ty.ty.to_swift_type(type_pos, types))
                     }
                 },
                 StdLibType::Option(opt) => opt.to_swift_type(type_pos, types),
                 StdLibType::Result(result) => result.to_swift_type(type_pos, types),
-                StdLibType::BoxedFnOnce(boxed_fn) => boxed_fn.to_swift_type().to_string(),
+                StdLibType::BoxedFnOnce(boxed_fn) => boxed_fn.to_swift_type(type_pos, types),
                 StdLibType::Tuple(tuple) => tuple.to_swift_type(type_pos, types),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
@@ -1216,7 +1468,7 @@ impl BridgedType {
                     }
                     TypePosition::SharedStructField => shared_enum.swift_name_string(),
                     TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
-                        unimplemented!()
+                        shared_enum.ffi_name_string()
                     }
                 }
             }
@@ -1440,7 +1692,7 @@ impl BridgedType {
                     }
                 }
                 StdLibType::Option(bridged_option) => {
-                    bridged_option.convert_ffi_expression_to_rust_type(value)
+                    bridged_option.convert_ffi_expression_to_rust_type(value, swift_bridge_path)
                 }
                 StdLibType::Result(result) => {
                     result.convert_ffi_value_to_rust_value(value, span, swift_bridge_path, types)
@@ -1534,9 +1786,12 @@ impl BridgedType {
                        )
                 }
                 StdLibType::Str => expression.to_string(),
-                StdLibType::Vec(_ty) => {
-                    format!("RustVec(ptr: {})", expression)
-                }
+                StdLibType::Vec(_ty) => match type_pos {
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                        format!("RustVec(ptr: {}!)", expression)
+                    }
+                    _ => format!("RustVec(ptr: {})", expression),
+                },
                 StdLibType::Option(opt) => opt.convert_ffi_expression_to_swift_type(expression),
                 StdLibType::Result(result) => {
                     result.convert_ffi_value_to_swift_value(expression, type_pos, types)
@@ -1639,8 +1894,8 @@ impl BridgedType {
                 StdLibType::Result(result) => {
                     result.convert_swift_expression_to_ffi_compatible(expression, types, type_pos)
                 }
-                StdLibType::BoxedFnOnce(_) => {
-                    todo!("Support Box<dyn FnOnce(A, B) -> C>")
+                StdLibType::BoxedFnOnce(boxed_fn) => {
+                    boxed_fn.convert_swift_closure_to_ffi_type(expression, types)
                 }
                 StdLibType::Tuple(tuple) => {
                     tuple.convert_swift_expression_to_ffi_type(expression, types, type_pos)
@@ -1720,92 +1975,15 @@ impl BridgedType {
         }
     }
 
-    /// When we want to return an Option::None we still need to return a dummy value to appease the
-    /// type checker, even though it never gets used by the caller.
-    fn unused_option_none_val(&self, swift_bridge_path: &Path) -> UnusedOptionNoneValue {
-        match self {
-            BridgedType::Bridgeable(b) => b.unused_option_none_val(swift_bridge_path),
-            BridgedType::StdLib(stdlib_type) => match stdlib_type {
-                StdLibType::Null => UnusedOptionNoneValue {
-                    rust: quote! { () },
-                    swift: "()".into(),
-                },
-                StdLibType::U8
-                | StdLibType::I8
-                | StdLibType::U16
-                | StdLibType::I16
-                | StdLibType::U32
-                | StdLibType::I32
-                | StdLibType::U64
-                | StdLibType::I64
-                | StdLibType::Usize
-                | StdLibType::Isize => UnusedOptionNoneValue {
-                    rust: quote! { 123 },
-                    swift: "123".into(),
-                },
-                StdLibType::F32 | StdLibType::F64 => UnusedOptionNoneValue {
-                    rust: quote! { 0.123 },
-                    swift: "0.123".into(),
-                },
-                StdLibType::Bool => UnusedOptionNoneValue {
-                    rust: quote! { bool },
-                    swift: "bool".into(),
-                },
-                StdLibType::Pointer(_) => {
-                    todo!("Support Option<*const T> and Option<*mut T>")
-                }
-                StdLibType::RefSlice(_) => {
-                    todo!("Support Option<&[T]>")
-                }
-                StdLibType::Str => {
-                    UnusedOptionNoneValue {
-                        rust: quote! {
-                            #swift_bridge_path::string::RustStr {start: std::ptr::null::<u8>(), len: 0}
-                        },
-                        // TODO: Add integration tests:
-                        //  Rust: crates/swift-integration-tests/src/option.rs
-                        //  Swift: OptionTests.swift
-                        swift: "TODO_SWIFT_OPTIONAL_STR_SUPPORT".to_string(),
-                    }
-                }
-                StdLibType::Vec(_) => {
-                    todo!("Support Option<Vec<T>>")
-                }
-                StdLibType::Option(_) => {
-                    todo!("Support nested Option<Option<T>>")
-                }
-                StdLibType::Result(_) => {
-                    todo!("Result<T, E> is not yet supported")
-                }
-                StdLibType::BoxedFnOnce(_) => {
-                    todo!("Support Box<dyn FnOnce(A, B) -> C>")
-                }
-                StdLibType::Tuple(_tuple) => todo!(),
-            },
-            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
-                let option_name = shared_struct.ffi_option_name_tokens();
-                UnusedOptionNoneValue {
-                    rust: quote! { #option_name { is_some: false, val: std::mem::MaybeUninit::uninit() } },
-                    swift: "TODO..Support Swift Option<Enum>::None value".into(),
-                }
-            }
-            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
-                let option_name = shared_enum.ffi_option_name_tokens();
-                UnusedOptionNoneValue {
-                    rust: quote! { #option_name { is_some: false, val: std::mem::MaybeUninit::uninit() } },
-                    swift: "TODO..Support Swift Option<Enum>::None value".into(),
-                }
-            }
-        }
-    }
-
     /// Whether or not the type is a `String`, or a type that contains an owned String such as
     /// `Option<String>` or `struct Foo { field: String } `
     pub fn contains_owned_string_recursive(&self, types: &TypeDeclarations) -> bool {
         match self {
             BridgedType::Bridgeable(b) => b.contains_owned_string_recursive(types),
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
-                StdLibType::Vec(inner) => inner.ty.contains_owned_string_recursive(types),
+                // A `Vec<String>` is seen by Swift as a `RustVec<RustString>`, not as a
+                // collection of `IntoRustString`s.
+                StdLibType::Vec(_) => false,
                 StdLibType::Option(inner) => inner.ty.contains_owned_string_recursive(types),
                 StdLibType::Result(inner) => {
                     inner.ok_ty.contains_owned_string_recursive(types)
@@ -1892,14 +2070,17 @@ impl BridgedType {
                 StdLibType::U8 => "U8".to_string(),
                 StdLibType::U16 => "U16".to_string(),
                 StdLibType::U32 => "U32".to_string(),
+                StdLibType::U64 => "U64".to_string(),
                 StdLibType::Usize => "UInt".to_string(),
                 StdLibType::I8 => "I8".to_string(),
                 StdLibType::I16 => "I16".to_string(),
                 StdLibType::I32 => "I32".to_string(),
+                StdLibType::I64 => "I64".to_string(),
                 StdLibType::Isize => "Int".to_string(),
                 StdLibType::Bool => "Bool".to_string(),
                 StdLibType::F32 => "F32".to_string(),
                 StdLibType::F64 => "F64".to_string(),
+                StdLibType::Str => "RustStr".to_string(),
                 StdLibType::Tuple(ty) => ty.to_alpha_numeric_underscore_name(types),
                 _ => todo!(),
             },
@@ -1922,12 +2103,6 @@ impl BridgedType {
     }
 }
 
-pub(crate) struct UnusedOptionNoneValue {
-    rust: TokenStream,
-    #[allow(unused)]
-    swift: String,
-}
-
 #[cfg(test)]
 mod tests {
 
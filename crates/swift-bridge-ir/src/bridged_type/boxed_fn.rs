@@ -10,15 +10,33 @@ use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::{Path, Type};
 
-/// Box<dyn FnOnce(A, B, C) -> ()>
+/// Box<dyn FnOnce(A, B, C) -> ()> or Box<dyn Fn(A, B, C) -> ()>
 #[derive(Debug)]
 pub(crate) struct BridgeableBoxedFnOnce {
+    /// Whether this is a `dyn Fn` or a `dyn FnOnce`.
+    pub kind: BoxedFnKind,
     /// The functions parameters.
     pub params: Vec<BridgedType>,
     /// The functions return type.
     pub ret: Box<BridgedType>,
 }
 
+/// The function trait that a boxed function implements.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum BoxedFnKind {
+    Fn,
+    FnOnce,
+}
+
+impl BoxedFnKind {
+    fn to_tokens(self) -> TokenStream {
+        match self {
+            BoxedFnKind::Fn => quote! { Fn },
+            BoxedFnKind::FnOnce => quote! { FnOnce },
+        }
+    }
+}
+
 /// example: Vec<SomeType, AnotherType, u32>
 pub(crate) struct FunctionArguments(pub Vec<Type>);
 impl Parse for FunctionArguments {
@@ -41,8 +59,9 @@ impl BridgeableBoxedFnOnce {
             .map(|a| a.to_rust_type_path(types))
             .collect();
         let ret = &self.ret.to_rust_type_path(types);
+        let kind = self.kind.to_tokens();
         quote! {
-            Box<dyn FnOnce(#(#args),*) -> #ret>
+            Box<dyn #kind(#(#args),*) -> #ret>
         }
     }
 
@@ -57,9 +76,10 @@ impl BridgeableBoxedFnOnce {
             .map(|a| a.to_rust_type_path(types))
             .collect();
         let ret = &self.ret.to_rust_type_path(types);
+        let kind = self.kind.to_tokens();
 
         quote! {
-            Box::into_raw(Box::new(#expression)) as *mut Box<dyn FnOnce(#(#args),*) -> #ret>
+            Box::into_raw(Box::new(#expression)) as *mut Box<dyn #kind(#(#args),*) -> #ret>
         }
     }
 
@@ -70,8 +90,9 @@ impl BridgeableBoxedFnOnce {
             .map(|a| a.to_rust_type_path(types))
             .collect();
         let ret = &self.ret.to_rust_type_path(types);
+        let kind = self.kind.to_tokens();
         quote! {
-            *mut Box<dyn FnOnce(#(#params),*) -> #ret>
+            *mut Box<dyn #kind(#(#params),*) -> #ret>
         }
     }
 
@@ -188,8 +209,83 @@ impl BridgeableBoxedFnOnce {
         args
     }
 
-    pub fn to_swift_type(&self) -> &'static str {
-        "UnsafeMutableRawPointer"
+    /// Swift passes closures to Rust functions. Everywhere else the boxed function is an opaque
+    /// pointer.
+    pub fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(HostLang::Rust, _) => {
+                format!("@escaping {}", self.to_swift_closure_type(types))
+            }
+            _ => "UnsafeMutableRawPointer".to_string(),
+        }
+    }
+
+    /// The Swift closure type that corresponds to this boxed function.
+    ///
+    /// For example, `Box<dyn Fn(u8, String) -> u16>` would give us:
+    /// "(UInt8, RustString) -> UInt16"
+    pub fn to_swift_closure_type(&self, types: &TypeDeclarations) -> String {
+        let params: Vec<String> = self
+            .params
+            .iter()
+            .map(|ty| ty.to_swift_type(TypePosition::FnReturn(HostLang::Rust), types))
+            .collect();
+        let ret = self
+            .ret
+            .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types);
+
+        format!("({}) -> {}", params.join(", "), ret)
+    }
+
+    /// Retain a Swift closure so that it can be passed to Rust.
+    ///
+    /// Rust releases the closure when it drops the boxed function.
+    pub fn convert_swift_closure_to_ffi_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+    ) -> String {
+        format!(
+            "Unmanaged.passRetained(__private__SwiftClosure<{}>({expression})).toOpaque()",
+            self.to_swift_closure_type(types)
+        )
+    }
+
+    /// Wrap a pointer to a retained Swift closure in a boxed Rust function.
+    ///
+    /// For example, `Box<dyn Fn(u8)>` would give us:
+    /// ```ignore
+    /// {
+    ///     let callback = SwiftClosureWrapper(callback);
+    ///     Box::new(move |arg0: u8| callback.call(arg0))
+    /// }
+    /// ```
+    pub fn convert_ffi_swift_closure_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        closure_wrapper: &Ident,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let params: Vec<TokenStream> = self
+            .params
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| {
+                let param_name = Ident::new(&format!("arg{}", idx), Span::call_site());
+                let param_ty = ty.to_rust_type_path(types);
+                quote! { #param_name: #param_ty }
+            })
+            .collect();
+        let args: Vec<Ident> = (0..self.params.len())
+            .map(|idx| Ident::new(&format!("arg{}", idx), Span::call_site()))
+            .collect();
+
+        quote! {
+            {
+                let #expression = #closure_wrapper(#expression);
+                Box::new(move |#(#params),*| #expression.call(#(#args),*))
+            }
+        }
     }
 
     pub fn convert_ffi_value_to_swift_value(&self, type_pos: TypePosition) -> String {
@@ -241,11 +337,26 @@ impl BridgeableBoxedFnOnce {
 }
 
 impl BridgeableBoxedFnOnce {
+    /// Whether the tokens are a `Box<dyn Fn(A, B) -> C>` or a `Box<dyn FnOnce(A, B) -> C>`.
+    pub fn can_parse_token_stream_str(tokens: &str) -> bool {
+        tokens.starts_with("Box < dyn FnOnce")
+            || tokens.starts_with("Box < dyn Fn(")
+            || tokens.starts_with("Box < dyn Fn (")
+    }
+
     pub fn from_str_tokens(string: &str, types: &TypeDeclarations) -> Option<Self> {
+        let (kind, signature) = if string.starts_with("Box < dyn FnOnce") {
+            (
+                BoxedFnKind::FnOnce,
+                string.trim_start_matches("Box < dyn FnOnce"),
+            )
+        } else {
+            (BoxedFnKind::Fn, string.trim_start_matches("Box < dyn Fn"))
+        };
+
         // ( A , B , C ) -> D >
         //   OR
         // ( A , B , C ) >
-        let signature = string.trim_start_matches("Box < dyn FnOnce");
 
         let open_parens = signature.find("(").unwrap();
         let closing_parens = signature.find(")").unwrap();
@@ -277,6 +388,7 @@ impl BridgeableBoxedFnOnce {
         }
 
         return Some(BridgeableBoxedFnOnce {
+            kind,
             params: args_bridged_tys,
             ret: Box::new(ret),
         });
@@ -346,6 +458,29 @@ mod tests {
         );
     }
 
+    /// Verify that we can parse a boxed `Fn`, and that we don't mistake it for a boxed `FnOnce`.
+    #[test]
+    fn boxed_fn_from_string() {
+        let tokens = quote! {Box<dyn Fn(u8) -> u16>}
+            .to_token_stream()
+            .to_string();
+        assert!(BridgeableBoxedFnOnce::can_parse_token_stream_str(&tokens));
+
+        let boxed_fn =
+            BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default()).unwrap();
+        assert_eq!(boxed_fn.kind, BoxedFnKind::Fn);
+        assert_eq!(boxed_fn.params.len(), 1);
+        assert!(matches!(
+            *boxed_fn.ret,
+            BridgedType::StdLib(StdLibType::U16)
+        ));
+
+        let tokens = quote! {Box<dyn FnOnce(u8)>}.to_token_stream().to_string();
+        let boxed_fn =
+            BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default()).unwrap();
+        assert_eq!(boxed_fn.kind, BoxedFnKind::FnOnce);
+    }
+
     /// Verify that we can parse a boxed fn that has a comma after the FnOnce.
     /// rustfmt adds a trailing comma when it puts a long function signature on its own line.
     #[test]
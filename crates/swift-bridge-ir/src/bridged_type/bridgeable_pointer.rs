@@ -1,6 +1,4 @@
-use crate::bridged_type::{
-    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, TypePosition, UnusedOptionNoneValue,
-};
+use crate::bridged_type::{BridgeableType, BridgedType, BuiltInResult, CFfiStruct, TypePosition};
 use crate::parse::TypeDeclarations;
 use crate::Path;
 use proc_macro2::{Span, TokenStream};
@@ -64,11 +62,11 @@ impl BridgeableType for BuiltInPointer {
                 let ty = ty.to_rust_type_path(types);
                 quote! { #pointer_kind #ty}
             }
-            Pointee::Void(_ty) => {
+            Pointee::Void(ty) => {
                 let pointer_kind = self.kind.to_ffi_compatible_rust_type();
-                let pointee = self.pointee.to_rust_type_path(types);
+                let pointee = Pointee::void_pointee_path(ty);
 
-                quote! { #pointer_kind super:: #pointee }
+                quote! { #pointer_kind #pointee }
             }
         }
     }
@@ -94,9 +92,7 @@ impl BridgeableType for BuiltInPointer {
 
         let ty = match &self.pointee {
             Pointee::BuiltIn(ty) => ty.to_ffi_compatible_rust_type(swift_bridge_path, types),
-            Pointee::Void(ty) => {
-                quote! { super::#ty }
-            }
+            Pointee::Void(ty) => Pointee::void_pointee_path(ty),
         };
 
         quote! { #kind #ty}
@@ -110,11 +106,7 @@ impl BridgeableType for BuiltInPointer {
         todo!()
     }
 
-    fn to_ffi_compatible_option_swift_type(
-        &self,
-        _swift_bridge_path: &Path,
-        _types: &TypeDeclarations,
-    ) -> String {
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
         todo!()
     }
 
@@ -167,7 +159,11 @@ impl BridgeableType for BuiltInPointer {
         todo!()
     }
 
-    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
         todo!()
     }
 
@@ -202,10 +198,6 @@ impl BridgeableType for BuiltInPointer {
         todo!()
     }
 
-    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
-        todo!()
-    }
-
     fn can_parse_token_stream_str(_tokens: &str) -> bool
     where
         Self: Sized,
@@ -270,10 +262,26 @@ impl PointerKind {
 }
 
 impl Pointee {
-    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
-        match self {
-            Pointee::BuiltIn(built_in) => built_in.to_rust_type_path(types),
-            Pointee::Void(ty) => ty.to_token_stream(),
+    /// `c_void` -> `super::c_void`
+    ///
+    /// Pointees that are already fully qualified, such as `std::ffi::c_void`, are used as is since
+    /// they would not resolve relative to the parent module.
+    fn void_pointee_path(ty: &Type) -> TokenStream {
+        let is_fully_qualified = match ty {
+            Type::Path(path) => {
+                path.path.leading_colon.is_some()
+                    || (path.path.segments.len() > 1
+                        && ["std", "core", "alloc"]
+                            .iter()
+                            .any(|krate| path.path.segments[0].ident == krate))
+            }
+            _ => false,
+        };
+
+        if is_fully_qualified {
+            quote! { #ty }
+        } else {
+            quote! { super::#ty }
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::bridged_type::{BridgeableType, BridgedType, CFfiStruct, TypePosition};
+use crate::parse::HostLang;
 use crate::{TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
@@ -145,6 +146,24 @@ impl BuiltInResult {
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
     ) -> TokenStream {
+        // A null pointer means `Ok(())`, matching `to_ffi_compatible_rust_type`.
+        if self.ok_ty.can_be_encoded_with_zero_bytes() && !self.is_custom_result_type() {
+            let convert_err = self.err_ty.convert_ffi_expression_to_rust_type(
+                expression,
+                span,
+                swift_bridge_path,
+                types,
+            );
+
+            return quote_spanned! {span=>
+                if #expression.is_null() {
+                    std::result::Result::Ok(())
+                } else {
+                    std::result::Result::Err(#convert_err)
+                }
+            };
+        }
+
         let convert_ok = self.ok_ty.convert_ffi_result_ok_value_to_rust_value(
             expression,
             swift_bridge_path,
@@ -175,6 +194,9 @@ impl BuiltInResult {
 
     pub fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
         match type_pos {
+            // Swift functions hand their `Result` straight to Rust, so they return its FFI
+            // representation.
+            TypePosition::FnReturn(HostLang::Swift) => self.to_swift_ffi_type(types),
             TypePosition::FnReturn(_) => self.ok_ty.to_swift_type(type_pos, types),
             TypePosition::FnArg(_, _) | TypePosition::SharedStructField => {
                 format!(
@@ -183,23 +205,26 @@ impl BuiltInResult {
                     self.err_ty.to_swift_type(type_pos, types),
                 )
             }
-            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
-                if self.err_ty.can_be_encoded_with_zero_bytes() {
-                    todo!()
-                }
-                if self.is_custom_result_type() {
-                    return format!(
-                        "{}${}",
-                        SWIFT_BRIDGE_PREFIX,
-                        self.custom_c_struct_name(types)
-                    );
-                }
-                if self.ok_ty.can_be_encoded_with_zero_bytes() {
-                    return "UnsafeMutableRawPointer?".to_string();
-                }
-                "__private__ResultPtrAndPtr".to_string()
-            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => self.to_swift_ffi_type(types),
+        }
+    }
+
+    /// The Swift type of the `Result`'s FFI representation.
+    fn to_swift_ffi_type(&self, types: &TypeDeclarations) -> String {
+        if self.err_ty.can_be_encoded_with_zero_bytes() {
+            todo!()
         }
+        if self.is_custom_result_type() {
+            return format!(
+                "{}${}",
+                SWIFT_BRIDGE_PREFIX,
+                self.custom_c_struct_name(types)
+            );
+        }
+        if self.ok_ty.can_be_encoded_with_zero_bytes() {
+            return "UnsafeMutableRawPointer?".to_string();
+        }
+        "__private__ResultPtrAndPtr".to_string()
     }
 
     pub fn convert_ffi_value_to_swift_value(
@@ -300,8 +325,9 @@ impl BuiltInResult {
             .convert_swift_expression_to_ffi_type("err", types, type_pos);
 
         if self.ok_ty.can_be_encoded_with_zero_bytes() {
+            // A null pointer means `Ok(())`, matching `to_swift_ffi_type`.
             format!(
-                "{{ switch {val} {{ case .Ok(let ok): return __private__ResultPtrAndPtr(is_ok: true, ok_or_err: {convert_ok}) case .Err(let err): return __private__ResultPtrAndPtr(is_ok: false, ok_or_err: {convert_err}) }} }}()",
+                "{{ switch {val} {{ case .Ok: return nil case .Err(let err): return {convert_err} }} }}()",
                 val = expression
             )
         } else {
@@ -518,6 +544,13 @@ impl BuiltInResult {
 
         let ok = BridgedType::new_with_str(ok, types)?;
         let err = BridgedType::new_with_str(err, types)?;
+        for ty in [&ok, &err] {
+            if let BridgedType::Bridgeable(b) = ty {
+                if !b.supports_result() {
+                    return None;
+                }
+            }
+        }
 
         Some(BuiltInResult {
             ok_ty: Box::new(ok),
@@ -568,4 +601,17 @@ mod tests {
         assert!(result.ok_ty.is_null());
         assert!(result.err_ty.is_null());
     }
+
+    /// Verify that we can parse a `Result` that holds a `&'static str`.
+    #[test]
+    fn result_from_static_str() {
+        let tokens = quote! { Result<&'static str, &'static str> }
+            .to_token_stream()
+            .to_string();
+
+        let result = BuiltInResult::from_str_tokens(&tokens, &TypeDeclarations::default()).unwrap();
+
+        assert!(result.ok_ty.is_str());
+        assert!(result.err_ty.is_str());
+    }
 }
@@ -1,6 +1,4 @@
-use crate::bridged_type::{
-    BridgeableType, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
-};
+use crate::bridged_type::{BridgeableType, CFfiStruct, OnlyEncoding, TypePosition};
 use crate::TypeDeclarations;
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
@@ -94,12 +92,8 @@ impl BridgeableType for BridgedString {
         self.to_ffi_compatible_rust_type(swift_bridge_path, types)
     }
 
-    fn to_ffi_compatible_option_swift_type(
-        &self,
-        _swift_bridge_path: &Path,
-        _types: &TypeDeclarations,
-    ) -> String {
-        todo!()
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        "UnsafeMutableRawPointer?".to_string()
     }
 
     fn to_ffi_compatible_option_c_type(&self) -> String {
@@ -123,14 +117,8 @@ impl BridgeableType for BridgedString {
         expression: &TokenStream,
         swift_bridge_path: &Path,
     ) -> TokenStream {
-        let unused_none_value = BridgedString.unused_option_none_val(swift_bridge_path).rust;
-
         quote! {
-            if let Some(val) = #expression {
-                #swift_bridge_path::string::RustString(val).box_into_raw()
-            } else {
-                #unused_none_value
-            }
+            #swift_bridge_path::string::RustString::option_box_into_raw(#expression)
         }
     }
 
@@ -182,13 +170,13 @@ impl BridgeableType for BridgedString {
         }
     }
 
-    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
         quote! {
-            if #expression.is_null() {
-                None
-            } else {
-                Some(unsafe { Box::from_raw(#expression).0 } )
-            }
+            unsafe { #swift_bridge_path::string::RustString::option_from_raw(#expression) }
         }
     }
 
@@ -240,18 +228,6 @@ impl BridgeableType for BridgedString {
         }
     }
 
-    fn unused_option_none_val(&self, swift_bridge_path: &Path) -> UnusedOptionNoneValue {
-        UnusedOptionNoneValue {
-            rust: quote! {
-                std::ptr::null::<#swift_bridge_path::string::RustString>() as *mut #swift_bridge_path::string::RustString
-            },
-            // TODO: Add integration tests:
-            //  Rust: crates/swift-integration-tests/src/option.rs
-            //  Swift: OptionTests.swift
-            swift: "TODO_SWIFT_OPTIONAL_STRING_SUPPORT".to_string(),
-        }
-    }
-
     fn can_parse_token_stream_str(tokens: &str) -> bool
     where
         Self: Sized,
@@ -1,7 +1,5 @@
-use crate::bridged_type::{
-    BridgeableType, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
-};
-use crate::parse::{HostLang, OpaqueRustTypeGenerics};
+use crate::bridged_type::{BridgeableType, CFfiStruct, OnlyEncoding, TypePosition};
+use crate::parse::{HostLang, OpaqueForeignTypeDeclaration, OpaqueRustTypeGenerics};
 use crate::{TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
@@ -12,11 +10,15 @@ use syn::{Path, Type};
 #[derive(Clone)]
 pub(crate) struct OpaqueForeignType {
     pub ty: Ident,
+    /// The name of the type on the Swift side. See
+    /// [`OpaqueForeignTypeDeclaration::swift_name_string`].
+    pub swift_ty_name: String,
     pub host_lang: HostLang,
     pub reference: bool,
     pub mutable: bool,
     pub has_swift_bridge_copy_annotation: bool,
     pub generics: OpaqueRustTypeGenerics,
+    pub trait_object: bool,
 }
 
 impl BridgeableType for OpaqueForeignType {
@@ -54,17 +56,25 @@ impl BridgeableType for OpaqueForeignType {
 
     fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
         let ty_name = &self.ty;
-        let generics = self
-            .generics
-            .angle_bracketed_concrete_generics_tokens(types);
+
+        let maybe_ref = if self.reference {
+            if self.mutable {
+                quote! { &mut }
+            } else {
+                quote! { & }
+            }
+        } else {
+            quote! {}
+        };
 
         if self.host_lang.is_rust() {
+            let ty = self.rust_type_tokens(types);
             quote! {
-                super:: #ty_name #generics
+                #maybe_ref #ty
             }
         } else {
             quote! {
-                #ty_name
+                #maybe_ref #ty_name
             }
         }
     }
@@ -74,7 +84,7 @@ impl BridgeableType for OpaqueForeignType {
             match type_pos {
                 TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
                     if func_host_lang.is_rust() {
-                        let mut class_name = self.ty.to_string();
+                        let mut class_name = self.swift_ty_name.clone();
 
                         if !self.has_swift_bridge_copy_annotation {
                             if self.reference {
@@ -97,7 +107,7 @@ impl BridgeableType for OpaqueForeignType {
                     }
                 }
                 TypePosition::SharedStructField => {
-                    let class_name = self.ty.to_string();
+                    let class_name = self.swift_ty_name.clone();
                     if !self.has_swift_bridge_copy_annotation {
                         if self.mutable || self.reference {
                             todo!();
@@ -112,14 +122,18 @@ impl BridgeableType for OpaqueForeignType {
                     )
                 }
                 TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
-                    unimplemented!()
+                    if self.has_swift_bridge_copy_annotation {
+                        self.copy_ffi_repr_type_string()
+                    } else {
+                        "UnsafeMutableRawPointer?".to_string()
+                    }
                 }
             }
         } else {
             match type_pos {
                 TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
                     if func_host_lang.is_rust() {
-                        self.ty.to_string()
+                        self.swift_ty_name.clone()
                     } else {
                         "UnsafeMutableRawPointer".to_string()
                     }
@@ -153,7 +167,7 @@ impl BridgeableType for OpaqueForeignType {
 
     fn to_ffi_compatible_rust_type(
         &self,
-        _swift_bridge_path: &Path,
+        swift_bridge_path: &Path,
         types: &TypeDeclarations,
     ) -> TokenStream {
         let ty_name = &self.ty;
@@ -163,21 +177,21 @@ impl BridgeableType for OpaqueForeignType {
             quote! { #ty }
         } else {
             if self.host_lang.is_rust() {
-                let generics = self
-                    .generics
-                    .angle_bracketed_concrete_generics_tokens(types);
-
                 if self.reference {
                     let ptr = if self.mutable {
                         quote! { *mut }
                     } else {
                         quote! { *const }
                     };
+                    let ty = self.rust_type_tokens_without_generics();
 
-                    quote_spanned! {ty_name.span()=> #ptr super::#ty_name }
+                    quote_spanned! {ty_name.span()=> #ptr #ty }
                 } else {
-                    quote! { *mut super::#ty_name #generics }
+                    let ty = self.rust_type_tokens(types);
+                    quote! { *mut #ty }
                 }
+            } else if self.reference {
+                quote! { #swift_bridge_path::PointerToSwiftType }
             } else {
                 quote! { #ty_name }
             }
@@ -189,30 +203,28 @@ impl BridgeableType for OpaqueForeignType {
         _swift_bridge_path: &Path,
         types: &TypeDeclarations,
     ) -> TokenStream {
-        let type_name = &self.ty;
-
         if self.has_swift_bridge_copy_annotation {
             let option_ty = self.option_copy_rust_repr_type();
             quote! { #option_ty }
+        } else if self.host_lang.is_swift() && !self.reference {
+            quote! { *mut std::ffi::c_void }
         } else {
-            let generics = self
-                .generics
-                .angle_bracketed_concrete_generics_tokens(types);
+            let ty = self.rust_type_tokens(types);
 
             if self.reference {
-                quote! { *const super::#type_name #generics }
+                quote! { *const #ty }
             } else {
-                quote! { *mut super::#type_name #generics }
+                quote! { *mut #ty }
             }
         }
     }
 
-    fn to_ffi_compatible_option_swift_type(
-        &self,
-        _swift_bridge_path: &Path,
-        _types: &TypeDeclarations,
-    ) -> String {
-        todo!()
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        if self.has_swift_bridge_copy_annotation {
+            self.option_copy_ffi_repr_type_string()
+        } else {
+            "UnsafeMutableRawPointer?".to_string()
+        }
     }
 
     fn to_ffi_compatible_option_c_type(&self) -> String {
@@ -226,12 +238,10 @@ impl BridgeableType for OpaqueForeignType {
     fn convert_rust_expression_to_ffi_type(
         &self,
         expression: &TokenStream,
-        _swift_bridge_path: &Path,
+        swift_bridge_path: &Path,
         types: &TypeDeclarations,
         span: Span,
     ) -> TokenStream {
-        let ty_name = &self.ty;
-
         if self.host_lang.is_rust() {
             if self.has_swift_bridge_copy_annotation {
                 let copy_ty = self.copy_rust_repr_type();
@@ -245,20 +255,24 @@ impl BridgeableType for OpaqueForeignType {
                     quote! { *const }
                 };
 
+                let ty = self.rust_type_tokens_without_generics();
+
                 quote! {
-                    #expression as #ptr super::#ty_name
+                    #expression as #ptr #ty
                 }
             } else {
-                let generics = self
-                    .generics
-                    .angle_bracketed_concrete_generics_tokens(types);
+                let ty = self.rust_type_tokens(types);
                 quote_spanned! {span=>
-                    Box::into_raw(Box::new({
-                        let val: super::#ty_name #generics = #expression;
+                    #swift_bridge_path::leak_check::box_into_raw({
+                        let val: #ty = #expression;
                         val
-                    })) as *mut super::#ty_name #generics
+                    })
                 }
             }
+        } else if self.reference {
+            quote! {
+                #swift_bridge_path::PointerToSwiftType(#expression.0)
+            }
         } else {
             quote! {
                 #expression
@@ -269,7 +283,7 @@ impl BridgeableType for OpaqueForeignType {
     fn convert_option_rust_expression_to_ffi_type(
         &self,
         expression: &TokenStream,
-        _swift_bridge_path: &Path,
+        swift_bridge_path: &Path,
     ) -> TokenStream {
         if self.has_swift_bridge_copy_annotation {
             let copy_repr = self.copy_rust_repr_type();
@@ -289,19 +303,28 @@ impl BridgeableType for OpaqueForeignType {
                 }
             }
         } else if self.reference {
-            let ty = &self.ty;
+            let ty = self.rust_type_tokens_without_generics();
 
             quote! {
                 if let Some(val) = #expression {
-                    val as *const super::#ty
+                    val as *const #ty
                 } else {
                     std::ptr::null()
                 }
             }
+        } else if self.host_lang.is_swift() {
+            // Swift takes ownership of the retained Swift object, so Rust must not release it.
+            quote! {
+                if let Some(val) = #expression {
+                    std::mem::ManuallyDrop::new(val).0
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
         } else {
             quote! {
                 if let Some(val) = #expression {
-                    Box::into_raw(Box::new(val))
+                    #swift_bridge_path::leak_check::box_into_raw(val)
                 } else {
                     std::ptr::null_mut()
                 }
@@ -312,11 +335,9 @@ impl BridgeableType for OpaqueForeignType {
     fn convert_swift_expression_to_ffi_type(
         &self,
         expression: &str,
-        _types: &TypeDeclarations,
+        types: &TypeDeclarations,
         type_pos: TypePosition,
     ) -> String {
-        let ty_name = &self.ty;
-
         if self.host_lang.is_rust() {
             if self.has_swift_bridge_copy_annotation {
                 format!("{}.intoFfiRepr()", expression)
@@ -360,19 +381,24 @@ impl BridgeableType for OpaqueForeignType {
         } else {
             match type_pos {
                 TypePosition::FnArg(func_host_lang, _) => {
+                    let declaration = self.swift_type_declaration(types);
+
                     if func_host_lang.is_rust() {
-                        format!("Unmanaged.passRetained({}).toOpaque()", expression)
+                        if self.reference {
+                            if declaration.attributes.protocol {
+                                todo!("Support passing references to Swift protocols to Rust")
+                            }
+                            format!("Unmanaged.passUnretained({}).toOpaque()", expression)
+                        } else {
+                            declaration.swift_pass_retained(expression)
+                        }
                     } else {
-                        format!(
-                            "Unmanaged<{type_name}>.fromOpaque({value}).takeRetainedValue()",
-                            type_name = ty_name,
-                            value = expression
-                        )
+                        declaration.swift_take_retained_value(expression)
                     }
                 }
-                TypePosition::FnReturn(_func_host_lang) => {
-                    format!("Unmanaged.passRetained({}).toOpaque()", expression)
-                }
+                TypePosition::FnReturn(_func_host_lang) => self
+                    .swift_type_declaration(types)
+                    .swift_pass_retained(expression),
                 TypePosition::SharedStructField => {
                     todo!("Opaque types in shared struct fields are not yet supported")
                 }
@@ -401,6 +427,11 @@ impl BridgeableType for OpaqueForeignType {
                 "{{ if let val = {expression} {{ return val.ptr }} else {{ return nil }} }}()",
                 expression = expression,
             )
+        } else if self.host_lang.is_swift() {
+            format!(
+                "{{ if let val = {expression} {{ return Unmanaged.passRetained(val).toOpaque() }} else {{ return nil }} }}()",
+                expression = expression,
+            )
         } else {
             format!("{{ if let val = {expression} {{ val.isOwned = false; return val.ptr }} else {{ return nil }} }}()", expression = expression,)
         }
@@ -410,7 +441,7 @@ impl BridgeableType for OpaqueForeignType {
         &self,
         expression: &TokenStream,
         _span: Span,
-        _swift_bridge_path: &Path,
+        swift_bridge_path: &Path,
         _types: &TypeDeclarations,
     ) -> TokenStream {
         if self.host_lang.is_rust() {
@@ -435,43 +466,77 @@ impl BridgeableType for OpaqueForeignType {
                 }
             } else {
                 quote! {
-                    unsafe { * Box::from_raw(  #expression ) }
+                    unsafe { * #swift_bridge_path::leak_check::box_from_raw(  #expression ) }
                 }
             }
-        } else {
-            if self.reference {
-                todo!("Handle referenced self Swift types")
+        } else if self.reference {
+            // Swift retains ownership of the instance, so we make sure that we don't release it
+            // when the borrowed handle goes out of scope.
+            let ty_name = &self.ty;
+            let maybe_mut = if self.mutable {
+                quote! { mut }
             } else {
-                quote! {
-                    #expression
-                }
+                quote! {}
+            };
+
+            quote! {
+                & #maybe_mut *std::mem::ManuallyDrop::new(#ty_name(#expression.0))
+            }
+        } else {
+            quote! {
+                #expression
             }
         }
     }
 
-    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
         if self.has_swift_bridge_copy_annotation {
             quote! {
-                if #expression.is_some {
-                    Some(unsafe{ #expression.val.assume_init() }.into_rust_repr())
-                } else {
-                    None
+                {
+                    let val = #expression;
+                    if val.is_some {
+                        Some(unsafe{ val.val.assume_init() }.into_rust_repr())
+                    } else {
+                        None
+                    }
                 }
             }
         } else if self.reference {
             quote! {
-                if #expression.is_null() {
-                    None
-                } else {
-                    Some(unsafe {& * #expression} )
+                {
+                    let val = #expression;
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some(unsafe {& * val} )
+                    }
+                }
+            }
+        } else if self.host_lang.is_swift() {
+            let ty = &self.ty;
+            quote! {
+                {
+                    let val = #expression;
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some(#ty(val))
+                    }
                 }
             }
         } else {
             quote! {
-                if #expression.is_null() {
-                    None
-                } else {
-                    Some(unsafe { * Box::from_raw(#expression) } )
+                {
+                    let val = #expression;
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some(unsafe { * #swift_bridge_path::leak_check::box_from_raw(val) })
+                    }
                 }
             }
         }
@@ -481,9 +546,9 @@ impl BridgeableType for OpaqueForeignType {
         &self,
         expression: &str,
         type_pos: TypePosition,
-        _types: &TypeDeclarations,
+        types: &TypeDeclarations,
     ) -> String {
-        let mut ty_name = self.ty.to_string();
+        let mut ty_name = self.swift_ty_name.clone();
 
         if self.reference {
             ty_name += "Ref";
@@ -504,6 +569,9 @@ impl BridgeableType for OpaqueForeignType {
                     TypePosition::FnReturn(fn_host_lang) if fn_host_lang.is_swift() => {
                         format!("Unmanaged.passRetained({expression}).toOpaque()")
                     }
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                        format!("{ty_name}(ptr: {expression}!)")
+                    }
                     _ => {
                         format!(
                             "{ty_name}(ptr: {value})",
@@ -513,12 +581,12 @@ impl BridgeableType for OpaqueForeignType {
                     }
                 }
             }
+        } else if self.reference {
+            self.swift_type_declaration(types)
+                .swift_take_unretained_value(expression)
         } else {
-            format!(
-                "Unmanaged<{ty_name}>.fromOpaque({value}).takeRetainedValue()",
-                ty_name = ty_name,
-                value = expression
-            )
+            self.swift_type_declaration(types)
+                .swift_take_retained_value(expression)
         }
     }
 
@@ -530,6 +598,13 @@ impl BridgeableType for OpaqueForeignType {
                 expression = expression,
                 type_name = type_name
             )
+        } else if self.host_lang.is_swift() && !self.reference {
+            let type_name = self.swift_name();
+            format!(
+                "{{ let val = {expression}; if val != nil {{ return Unmanaged<{type_name}>.fromOpaque(val!).takeRetainedValue() }} else {{ return nil }} }}()",
+                expression = expression,
+                type_name = type_name
+            )
         } else {
             let type_name = self.swift_name();
             format!(
@@ -543,15 +618,20 @@ impl BridgeableType for OpaqueForeignType {
     fn convert_ffi_result_ok_value_to_rust_value(
         &self,
         result: &TokenStream,
-        _swift_bridge_path: &Path,
+        swift_bridge_path: &Path,
         _types: &TypeDeclarations,
     ) -> TokenStream {
         let ty = &self.ty;
 
         match self.host_lang {
             HostLang::Rust => {
+                let rust_ty = self.rust_type_tokens_without_generics();
                 quote! {
-                    unsafe { *Box::from_raw(#result.ok_or_err as *mut super::#ty) }
+                    unsafe {
+                        *#swift_bridge_path::leak_check::box_from_raw(
+                            #result.ok_or_err as *mut #rust_ty
+                        )
+                    }
                 }
             }
             HostLang::Swift => {
@@ -565,15 +645,20 @@ impl BridgeableType for OpaqueForeignType {
     fn convert_ffi_result_err_value_to_rust_value(
         &self,
         result: &TokenStream,
-        _swift_bridge_path: &Path,
+        swift_bridge_path: &Path,
         _types: &TypeDeclarations,
     ) -> TokenStream {
         let ty = &self.ty;
 
         match self.host_lang {
             HostLang::Rust => {
+                let rust_ty = self.rust_type_tokens_without_generics();
                 quote! {
-                    unsafe { *Box::from_raw(#result.ok_or_err as *mut super::#ty) }
+                    unsafe {
+                        *#swift_bridge_path::leak_check::box_from_raw(
+                            #result.ok_or_err as *mut #rust_ty
+                        )
+                    }
                 }
             }
             HostLang::Swift => {
@@ -584,19 +669,6 @@ impl BridgeableType for OpaqueForeignType {
         }
     }
 
-    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
-        let ty_name = &self.ty;
-
-        if self.reference {
-            todo!("Support returning Option<&T> where T is an opaque type")
-        } else {
-            UnusedOptionNoneValue {
-                rust: quote! { std::ptr::null::<#ty_name>() as *mut super::#ty_name },
-                swift: "TODO..Support Swift Option<T>::None value".into(),
-            }
-        }
-    }
-
     fn can_parse_token_stream_str(_tokens: &str) -> bool
     where
         Self: Sized,
@@ -673,11 +745,47 @@ impl BridgeableType for OpaqueForeignType {
 }
 
 impl OpaqueForeignType {
+    /// The `type SomeType` declaration for this opaque Swift type.
+    fn swift_type_declaration<'a>(
+        &self,
+        types: &'a TypeDeclarations,
+    ) -> &'a OpaqueForeignTypeDeclaration {
+        types
+            .get(&self.ty.to_string())
+            .and_then(|ty| ty.as_opaque())
+            .unwrap()
+    }
+
+    /// The Rust type that values of this opaque Rust type are stored as.
+    ///
+    /// `super::SomeType<u32>`, or `Box<dyn super::SomeTrait>` for a bridged trait.
+    fn rust_type_tokens(&self, types: &TypeDeclarations) -> TokenStream {
+        let ty_name = &self.ty;
+        if self.trait_object {
+            quote! { Box<dyn super::#ty_name> }
+        } else {
+            let generics = self
+                .generics
+                .angle_bracketed_concrete_generics_tokens(types);
+            quote! { super::#ty_name #generics }
+        }
+    }
+
+    /// Same as [`OpaqueForeignType::rust_type_tokens`], but leaves off any generics.
+    fn rust_type_tokens_without_generics(&self) -> TokenStream {
+        let ty_name = &self.ty;
+        if self.trait_object {
+            quote! { Box<dyn super::#ty_name> }
+        } else {
+            quote! { super::#ty_name }
+        }
+    }
+
     pub fn swift_name(&self) -> String {
         if self.reference {
-            format!("{}Ref", self.ty)
+            format!("{}Ref", self.swift_ty_name)
         } else {
-            format!("{}", self.ty)
+            self.swift_ty_name.clone()
         }
     }
 
@@ -18,16 +18,11 @@ impl BridgedOption {
         expression: &TokenStream,
         swift_bridge_path: &Path,
     ) -> TokenStream {
-        let option_rust_primitive_to_ffi_primitive =
-            move |ffi_option_name: TokenStream, unused_none: TokenStream| {
-                quote! {
-                    if let Some(val) = #expression {
-                        #swift_bridge_path::option::#ffi_option_name { val, is_some: true }
-                    } else {
-                        #swift_bridge_path::option::#ffi_option_name { val: #unused_none, is_some: false }
-                    }
-                }
-            };
+        let option_rust_primitive_to_ffi_primitive = move |ffi_option_name: TokenStream| {
+            quote! {
+                #swift_bridge_path::option::#ffi_option_name::from_option(#expression)
+            }
+        };
 
         match self.ty.deref() {
             BridgedType::Bridgeable(b) => {
@@ -37,45 +32,19 @@ impl BridgedOption {
                 StdLibType::Null => {
                     todo!("Option<()> is not yet supported")
                 }
-                StdLibType::U8 => {
-                    option_rust_primitive_to_ffi_primitive(quote! {OptionU8}, quote! {123})
-                }
-                StdLibType::I8 => {
-                    option_rust_primitive_to_ffi_primitive(quote! {OptionI8}, quote! {123})
-                }
-                StdLibType::U16 => {
-                    option_rust_primitive_to_ffi_primitive(quote! {OptionU16}, quote! {123})
-                }
-                StdLibType::I16 => {
-                    option_rust_primitive_to_ffi_primitive(quote! {OptionI16}, quote! {123})
-                }
-                StdLibType::U32 => {
-                    option_rust_primitive_to_ffi_primitive(quote! {OptionU32}, quote! {123})
-                }
-                StdLibType::I32 => {
-                    option_rust_primitive_to_ffi_primitive(quote! {OptionI32}, quote! {123})
-                }
-                StdLibType::U64 => {
-                    option_rust_primitive_to_ffi_primitive(quote! {OptionU64}, quote! {123})
-                }
-                StdLibType::I64 => {
-                    option_rust_primitive_to_ffi_primitive(quote! {OptionI64}, quote! {123})
-                }
-                StdLibType::Usize => {
-                    option_rust_primitive_to_ffi_primitive(quote! {OptionUsize}, quote! {123})
-                }
-                StdLibType::Isize => {
-                    option_rust_primitive_to_ffi_primitive(quote! {OptionIsize}, quote! {123})
-                }
-                StdLibType::F32 => {
-                    option_rust_primitive_to_ffi_primitive(quote! {OptionF32}, quote! {123.4})
-                }
-                StdLibType::F64 => {
-                    option_rust_primitive_to_ffi_primitive(quote! {OptionF64}, quote! {123.4})
-                }
-                StdLibType::Bool => {
-                    option_rust_primitive_to_ffi_primitive(quote! {OptionBool}, quote! {false})
-                }
+                StdLibType::U8 => option_rust_primitive_to_ffi_primitive(quote! {OptionU8}),
+                StdLibType::I8 => option_rust_primitive_to_ffi_primitive(quote! {OptionI8}),
+                StdLibType::U16 => option_rust_primitive_to_ffi_primitive(quote! {OptionU16}),
+                StdLibType::I16 => option_rust_primitive_to_ffi_primitive(quote! {OptionI16}),
+                StdLibType::U32 => option_rust_primitive_to_ffi_primitive(quote! {OptionU32}),
+                StdLibType::I32 => option_rust_primitive_to_ffi_primitive(quote! {OptionI32}),
+                StdLibType::U64 => option_rust_primitive_to_ffi_primitive(quote! {OptionU64}),
+                StdLibType::I64 => option_rust_primitive_to_ffi_primitive(quote! {OptionI64}),
+                StdLibType::Usize => option_rust_primitive_to_ffi_primitive(quote! {OptionUsize}),
+                StdLibType::Isize => option_rust_primitive_to_ffi_primitive(quote! {OptionIsize}),
+                StdLibType::F32 => option_rust_primitive_to_ffi_primitive(quote! {OptionF32}),
+                StdLibType::F64 => option_rust_primitive_to_ffi_primitive(quote! {OptionF64}),
+                StdLibType::Bool => option_rust_primitive_to_ffi_primitive(quote! {OptionBool}),
                 StdLibType::Pointer(_) => {
                     todo!("Support Option<*const T> and Option<*mut T>")
                 }
@@ -84,20 +53,12 @@ impl BridgedOption {
                 }
                 StdLibType::Str => {
                     quote! {
-                        if let Some(val) = #expression {
-                            #swift_bridge_path::string::RustStr::from_str(val)
-                        } else {
-                            #swift_bridge_path::string::RustStr { start: std::ptr::null::<u8>(), len: 0}
-                        }
+                        #swift_bridge_path::string::RustStr::from_option_str(#expression)
                     }
                 }
                 StdLibType::Vec(_) => {
                     quote! {
-                        if let Some(value) = #expression {
-                            Box::into_raw(Box::new(value))
-                        } else {
-                            std::ptr::null_mut()
-                        }
+                        #swift_bridge_path::option::box_into_raw_or_null(#expression)
                     }
                 }
                 StdLibType::Option(_) => {
@@ -129,9 +90,12 @@ impl BridgedOption {
     pub(super) fn convert_ffi_expression_to_rust_type(
         &self,
         expression: &TokenStream,
+        swift_bridge_path: &Path,
     ) -> TokenStream {
         match self.ty.deref() {
-            BridgedType::Bridgeable(b) => b.convert_ffi_option_expression_to_rust_type(expression),
+            BridgedType::Bridgeable(b) => {
+                b.convert_ffi_option_expression_to_rust_type(expression, swift_bridge_path)
+            }
             BridgedType::StdLib(stdlib_ty) => match stdlib_ty {
                 StdLibType::Null => {
                     todo!("Option<()> is not yet supported")
@@ -150,14 +114,7 @@ impl BridgedOption {
                 | StdLibType::F64
                 | StdLibType::Bool => {
                     quote! {
-                        {
-                            let val = #expression;
-                            if val.is_some {
-                                Some(val.val)
-                            } else {
-                                None
-                            }
-                        }
+                        #expression.into_option()
                     }
                 }
                 StdLibType::Pointer(_) => {
@@ -168,12 +125,12 @@ impl BridgedOption {
                 }
                 StdLibType::Str => {
                     quote! {
-                        if #expression.start.is_null() { None } else { Some(#expression.to_str()) }
+                        #expression.to_option_str()
                     }
                 }
                 StdLibType::Vec(_) => {
                     quote! {
-                        if #expression.is_null() { None } else { Some( unsafe { * Box::from_raw(#expression) } ) }
+                        unsafe { #swift_bridge_path::option::unbox_raw_or_null(#expression) }
                     }
                 }
                 StdLibType::Option(_) => {
@@ -295,9 +252,15 @@ impl BridgedOption {
                 StdLibType::RefSlice(_) => {
                     todo!("Option<&[T]> is not yet supported")
                 }
-                StdLibType::Str => {
-                    format!("{expression}AsRustStr", expression = expression)
-                }
+                StdLibType::Str => match type_pos {
+                    TypePosition::FnArg(func_host_lang, _)
+                    | TypePosition::FnReturn(func_host_lang)
+                        if func_host_lang.is_swift() =>
+                    {
+                        format!("optionalRustStrToRustStr({expression}, {{ $0 }})")
+                    }
+                    _ => format!("{expression}AsRustStr", expression = expression),
+                },
                 StdLibType::Vec(_) => {
                     format!(
                         "{{ if let val = {expression} {{ val.isOwned = false; return val.ptr }} else {{ return nil }} }}()"
@@ -354,12 +317,12 @@ impl BridgedOption {
                 format!("Optional<{}>", self.ty.to_swift_type(type_pos, types))
             }
             TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
-                unimplemented!()
+                self.to_ffi_compatible_swift_type(types)
             }
         }
     }
 
-    fn to_ffi_compatible_swift_type(&self, _types: &TypeDeclarations) -> String {
+    fn to_ffi_compatible_swift_type(&self, types: &TypeDeclarations) -> String {
         match self.ty.deref() {
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
                 StdLibType::Null => {
@@ -387,12 +350,8 @@ impl BridgedOption {
                 StdLibType::RefSlice(_) => {
                     todo!()
                 }
-                StdLibType::Str => {
-                    todo!()
-                }
-                StdLibType::Vec(_) => {
-                    todo!()
-                }
+                StdLibType::Str => "RustStr".to_string(),
+                StdLibType::Vec(_) => "UnsafeMutableRawPointer?".to_string(),
                 StdLibType::BoxedFnOnce(_) => {
                     todo!()
                 }
@@ -406,12 +365,13 @@ impl BridgedOption {
                     todo!()
                 }
             },
-            BridgedType::Foreign(_) => {
-                todo!()
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
+                shared_struct.ffi_option_name_string()
             }
-            BridgedType::Bridgeable(_) => {
-                todo!()
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
+                shared_enum.ffi_option_name_string()
             }
+            BridgedType::Bridgeable(b) => b.to_ffi_compatible_option_swift_type(types),
         }
     }
 }
@@ -0,0 +1,352 @@
+use crate::bridged_type::{BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition};
+use crate::parse::{TypeDeclaration, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Path, Type};
+
+/// Arc<T> where T is an opaque Rust type.
+///
+/// Each Swift `{T}Arc` instance holds one strong reference to the Rust `Arc`. Handing an instance
+/// to Rust first takes out another strong reference for Rust to own, and the Swift instance's
+/// `deinit` releases the reference that it holds.
+///
+/// The Swift class inherits from `{T}Ref`, so all of `T`'s `&self` methods can be called on it.
+///
+/// The `{T}Arc` class and its FFI functions are generated by the bridge module that declares
+/// `type T`, so `Arc<T>` can't be used with a `#[swift_bridge(already_declared)]` type. It can't yet
+/// be used inside of an `Option`, a `Vec` or a `Result`, or as a struct field either.
+#[derive(Debug)]
+pub(crate) struct BuiltInArc {
+    pub ty: Ident,
+    /// The name of `T` on the Swift side.
+    pub swift_name: String,
+}
+
+impl BuiltInArc {
+    /// Parse from a stringified token stream such as "Arc < SomeType >".
+    pub fn from_str_tokens(string: &str, types: &TypeDeclarations) -> Option<Self> {
+        let trimmed = string.trim_start_matches("std :: sync :: ");
+        let trimmed = trimmed.trim_start_matches("Arc < ");
+        let inner = trimmed.trim_end_matches(" >").trim();
+
+        match types.get(inner)? {
+            TypeDeclaration::Opaque(opaque)
+                if opaque.host_lang.is_rust()
+                    && opaque.attributes.copy.is_none()
+                    && !opaque.attributes.already_declared
+                    && opaque.generics.is_empty() =>
+            {
+                Some(BuiltInArc {
+                    ty: opaque.ty.clone(),
+                    swift_name: opaque.swift_name_string(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// `Arc<SomeType>` -> `__swift_bridge__$Arc$SomeType`
+    fn ffi_name_prefix(&self) -> String {
+        format!("{}$Arc${}", SWIFT_BRIDGE_PREFIX, self.ty)
+    }
+
+    /// `Arc<SomeType>` -> `SomeTypeArc`
+    fn swift_class_name(&self) -> String {
+        format!("{}Arc", self.swift_name)
+    }
+
+    fn generate_swift_class(&self) -> String {
+        let ty = &self.swift_name;
+        let class_name = self.swift_class_name();
+        let ffi_prefix = self.ffi_name_prefix();
+
+        format!(
+            r#"
+public class {class_name}: {ty}Ref {{
+    public override init(ptr: UnsafeMutableRawPointer) {{
+        super.init(ptr: ptr)
+    }}
+
+    deinit {{
+        {ffi_prefix}$_free(ptr)
+    }}
+}}"#
+        )
+    }
+}
+
+impl BridgeableType for BuiltInArc {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let ty = &self.ty;
+        let retain_export = format!("{}$_retain", self.ffi_name_prefix());
+        let free_export = format!("{}$_free", self.ffi_name_prefix());
+
+        Some(vec![quote! {
+            const _: () = {
+                #[export_name = #retain_export]
+                #[doc(hidden)]
+                pub extern "C" fn _retain(arc: *const super::#ty) -> *const super::#ty {
+                    unsafe { std::sync::Arc::increment_strong_count(arc) };
+                    arc
+                }
+
+                #[export_name = #free_export]
+                #[doc(hidden)]
+                pub extern "C" fn _free(arc: *const super::#ty) {
+                    let _ = unsafe { std::sync::Arc::from_raw(arc) };
+                }
+            };
+        }])
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        let ffi_prefix = self.ffi_name_prefix();
+
+        Some(CFfiStruct {
+            c_ffi_type: format!(
+                r#"void* {ffi_prefix}$_retain(void* arc);
+void {ffi_prefix}$_free(void* arc);"#
+            ),
+            fields: vec![],
+        })
+    }
+
+    fn generate_custom_swift_code(&self, _types: &TypeDeclarations) -> Option<String> {
+        Some(self.generate_swift_class())
+    }
+
+    fn custom_ffi_symbols(&self, _types: &TypeDeclarations) -> Vec<String> {
+        vec![
+            format!("{}$_retain", self.ffi_name_prefix()),
+            format!("{}$_free", self.ffi_name_prefix()),
+        ]
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        let ty = &self.ty;
+        quote! { std::sync::Arc<super::#ty> }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_class_name()
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => {
+                unreachable!("Arc<T> fields are rejected while parsing")
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "UnsafeMutableRawPointer?".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = &self.ty;
+        quote! { *const super::#ty }
+    }
+
+    fn supports_option(&self) -> bool {
+        false
+    }
+
+    fn supports_vec(&self) -> bool {
+        false
+    }
+
+    fn supports_result(&self) -> bool {
+        false
+    }
+
+    fn supports_shared_struct_field(&self) -> bool {
+        false
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            std::sync::Arc::into_raw(#expression)
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        // The Swift instance keeps its own strong reference, so we take out another one for Rust.
+        format!(
+            "{{ let val = {expression}; return withExtendedLifetime(val) {{ {}$_retain(val.ptr)! }} }}()",
+            self.ffi_name_prefix()
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            unsafe { std::sync::Arc::from_raw(#expression) }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        match type_pos {
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                format!("{}(ptr: {}!)", self.swift_class_name(), expression)
+            }
+            _ => format!("{}(ptr: {})", self.swift_class_name(), expression),
+        }
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<Arc<T>, E> is rejected while parsing")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<T, Arc<E>> is rejected while parsing")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("Arc < ") || tokens.starts_with("std :: sync :: Arc < ")
+    }
+
+    fn from_type(_ty: &Type, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        todo!()
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::from_str_tokens(tokens, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        format!("Arc{}", self.ty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse_ok;
+    use quote::quote;
+
+    /// Verify that we only parse `Arc<T>` when `T` is a non-generic, non-Copy opaque Rust type.
+    #[test]
+    fn parse_arc() {
+        let module = parse_ok(quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    #[swift_bridge(Copy(4))]
+                    type SomeCopyType;
+                }
+                extern "Swift" {
+                    type SomeSwiftType;
+                }
+            }
+        });
+        let types = &module.types;
+
+        assert!(BuiltInArc::from_str_tokens("Arc < SomeType >", types).is_some());
+        assert!(BuiltInArc::from_str_tokens("std :: sync :: Arc < SomeType >", types).is_some());
+
+        assert!(BuiltInArc::from_str_tokens("Arc < SomeCopyType >", types).is_none());
+        assert!(BuiltInArc::from_str_tokens("Arc < SomeSwiftType >", types).is_none());
+        assert!(BuiltInArc::from_str_tokens("Arc < u8 >", types).is_none());
+    }
+}
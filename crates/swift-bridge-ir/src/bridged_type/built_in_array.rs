@@ -0,0 +1,327 @@
+use crate::bridged_type::built_in_primitive::BuiltInPrimitive;
+use crate::bridged_type::{
+    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, OnlyEncoding, StdLibType, TypePosition,
+};
+use crate::parse::{HostLang, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Expr, Lit, Path, Type, TypeArray};
+
+/// [T; N] where T is a primitive such as `u8` or `f64`.
+///
+/// Arrays are passed by value inside of a `#[repr(C)]` struct, since C does not allow passing
+/// arrays by value. Swift imports the fixed size C array inside of that struct as an N-element
+/// tuple, so `[u8; 4]` is seen on the Swift side as `(UInt8, UInt8, UInt8, UInt8)`.
+#[derive(Debug)]
+pub(crate) struct BuiltInArray {
+    pub ty: Box<BridgedType>,
+    pub len: usize,
+}
+
+impl BuiltInArray {
+    /// Returns `None` if the array's element type is not a primitive or its length is not an
+    /// integer literal greater than zero.
+    pub fn from_type_array(array: &TypeArray, types: &TypeDeclarations) -> Option<Self> {
+        let len = match &array.len {
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Int(int) => int.base10_parse::<usize>().ok()?,
+                _ => None?,
+            },
+            _ => None?,
+        };
+        if len == 0 {
+            return None;
+        }
+
+        let ty = BridgedType::new_with_type(&array.elem, types)?;
+        match &ty {
+            BridgedType::StdLib(StdLibType::Null) => return None,
+            BridgedType::StdLib(stdlib_ty) => {
+                BuiltInPrimitive::new_with_stdlib_type(stdlib_ty)?;
+            }
+            _ => return None,
+        };
+
+        Some(BuiltInArray {
+            ty: Box::new(ty),
+            len,
+        })
+    }
+
+    /// `[u8; 32]` -> `U8$32`
+    fn ffi_name_suffix(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "{}${}",
+            self.ty.to_alpha_numeric_underscore_name(types),
+            self.len
+        )
+    }
+
+    /// `[u8; 32]` -> `__swift_bridge__$array$U8$32`
+    fn ffi_name_string(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "{}$array${}",
+            SWIFT_BRIDGE_PREFIX,
+            self.ffi_name_suffix(types)
+        )
+    }
+
+    /// `[u8; 32]` -> `__swift_bridge__array_U8_32`
+    fn prefixed_ty_name(&self, types: &TypeDeclarations) -> Ident {
+        Ident::new(
+            &format!(
+                "{}array_{}_{}",
+                SWIFT_BRIDGE_PREFIX,
+                self.ty.to_alpha_numeric_underscore_name(types),
+                self.len
+            ),
+            Span::call_site(),
+        )
+    }
+
+    /// `[u8; 3]` -> `(UInt8, UInt8, UInt8)`
+    fn swift_tuple_type(&self, types: &TypeDeclarations) -> String {
+        let element = self
+            .ty
+            .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types);
+        let elements = vec![element; self.len];
+        format!("({})", elements.join(", "))
+    }
+}
+
+impl BridgeableType for BuiltInArray {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let prefixed_ty_name = self.prefixed_ty_name(types);
+        let rust_ty = self.to_rust_type_path(types);
+
+        Some(vec![quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct #prefixed_ty_name(#rust_ty);
+        }])
+    }
+
+    fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct> {
+        let ffi_name = self.ffi_name_string(types);
+        let c_ty = self.ty.to_c(types);
+        let len = self.len;
+
+        Some(CFfiStruct {
+            c_ffi_type: format!("typedef struct {ffi_name} {{ {c_ty} _0[{len}]; }} {ffi_name};"),
+            fields: vec![],
+        })
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let ty = self.ty.to_rust_type_path(types);
+        let len = Literal::usize_unsuffixed(self.len);
+        quote! { [#ty; #len] }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_tuple_type(types)
+                } else {
+                    self.ffi_name_string(types)
+                }
+            }
+            TypePosition::SharedStructField => {
+                todo!("Arrays in shared struct fields are not yet supported.")
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => self.ffi_name_string(types),
+        }
+    }
+
+    fn to_c_type(&self, types: &TypeDeclarations) -> String {
+        format!("struct {}", self.ffi_name_string(types))
+    }
+
+    fn to_c_include(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        self.ty.to_c_include(types)
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let prefixed_ty_name = self.prefixed_ty_name(types);
+        quote! { #prefixed_ty_name }
+    }
+
+    fn supports_option(&self) -> bool {
+        false
+    }
+
+    fn supports_vec(&self) -> bool {
+        false
+    }
+
+    fn supports_shared_struct_field(&self) -> bool {
+        false
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        let prefixed_ty_name = self.prefixed_ty_name(types);
+        quote_spanned! {span=>
+            #prefixed_ty_name(#expression)
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{}(_0: {expression})", self.ffi_name_string(types))
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            #expression.0
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("{expression}._0")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<[T; N], E>")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<T, [E; N]>")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("[")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Array(array) => Self::from_type_array(array, types),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ty: Type = syn::parse_str(tokens).ok()?;
+        Self::from_type(&ty, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "Array{}x{}",
+            self.ty.to_alpha_numeric_underscore_name(types),
+            self.len
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we only parse arrays of primitives that have a literal length.
+    #[test]
+    fn parse_array() {
+        let types = &TypeDeclarations::default();
+
+        let array = BuiltInArray::parse_token_stream_str("[u8 ; 32]", types).unwrap();
+        assert_eq!(array.len, 32);
+        assert!(matches!(*array.ty, BridgedType::StdLib(StdLibType::U8)));
+
+        assert!(BuiltInArray::parse_token_stream_str("[f64 ; 3]", types).is_some());
+
+        assert!(BuiltInArray::parse_token_stream_str("[u8 ; 0]", types).is_none());
+        assert!(BuiltInArray::parse_token_stream_str("[u8 ; LEN]", types).is_none());
+        assert!(BuiltInArray::parse_token_stream_str("[String ; 2]", types).is_none());
+        assert!(BuiltInArray::parse_token_stream_str("[() ; 2]", types).is_none());
+    }
+}
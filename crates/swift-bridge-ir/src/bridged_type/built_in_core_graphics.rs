@@ -0,0 +1,243 @@
+use crate::bridged_type::{BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition};
+use crate::parse::TypeDeclarations;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Path, Type};
+
+/// swift_bridge::core_graphics::{CGFloat, CGPoint, CGSize, CGRect}
+///
+/// The types have the same layout as their CoreGraphics counterparts, so they are passed over FFI
+/// by value and the C header uses CoreGraphics' own declarations. This means that no conversion
+/// code is needed on either side.
+#[derive(Debug)]
+pub(crate) enum BuiltInCoreGraphics {
+    Float,
+    Point,
+    Size,
+    Rect,
+}
+
+impl BuiltInCoreGraphics {
+    /// `CGFloat`, `CGPoint`, `CGSize` or `CGRect`
+    fn name(&self) -> &'static str {
+        match self {
+            BuiltInCoreGraphics::Float => "CGFloat",
+            BuiltInCoreGraphics::Point => "CGPoint",
+            BuiltInCoreGraphics::Size => "CGSize",
+            BuiltInCoreGraphics::Rect => "CGRect",
+        }
+    }
+}
+
+impl BridgeableType for BuiltInCoreGraphics {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn generate_custom_swift_code(&self, _types: &TypeDeclarations) -> Option<String> {
+        Some("import CoreGraphics".to_string())
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        let name = syn::Ident::new(self.name(), Span::call_site());
+        quote! { swift_bridge::core_graphics::#name }
+    }
+
+    fn to_swift_type(&self, _type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        self.name().to_string()
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        self.name().to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        Some(vec!["CoreGraphics/CoreGraphics.h"])
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let name = syn::Ident::new(self.name(), Span::call_site());
+        quote! { #swift_bridge_path::core_graphics::#name }
+    }
+
+    fn supports_option(&self) -> bool {
+        false
+    }
+
+    fn supports_vec(&self) -> bool {
+        false
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            #expression
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        expression.to_string()
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            #expression
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        expression.to_string()
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<CGPoint, E> and the other CoreGraphics types")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<T, CGPoint> and the other CoreGraphics types")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        let tokens = tokens.trim_start_matches("swift_bridge :: ");
+        let tokens = tokens.trim_start_matches("core_graphics :: ");
+        matches!(tokens, "CGFloat" | "CGPoint" | "CGSize" | "CGRect")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(&quote! { #path }.to_string(), types),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let tokens = tokens.trim_start_matches("swift_bridge :: ");
+        let tokens = tokens.trim_start_matches("core_graphics :: ");
+        match tokens {
+            "CGFloat" => Some(BuiltInCoreGraphics::Float),
+            "CGPoint" => Some(BuiltInCoreGraphics::Point),
+            "CGSize" => Some(BuiltInCoreGraphics::Size),
+            "CGRect" => Some(BuiltInCoreGraphics::Rect),
+            _ => None,
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        self.name().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we parse the CoreGraphics types with or without their module path.
+    #[test]
+    fn parse_core_graphics_types() {
+        let types = &TypeDeclarations::default();
+
+        for tokens in [
+            "CGFloat",
+            "CGPoint",
+            "CGSize",
+            "core_graphics :: CGRect",
+            "swift_bridge :: core_graphics :: CGRect",
+        ] {
+            assert!(BuiltInCoreGraphics::parse_token_stream_str(tokens, types).is_some());
+        }
+        assert!(BuiltInCoreGraphics::parse_token_stream_str("CGVector", types).is_none());
+    }
+}
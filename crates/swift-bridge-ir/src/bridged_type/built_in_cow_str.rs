@@ -0,0 +1,246 @@
+use crate::bridged_type::{BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition};
+use crate::parse::TypeDeclarations;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Path, Type};
+
+/// std::borrow::Cow<'static, str>
+///
+/// On the Swift side this is seen as a `RustCowStr`. A borrowed string gets passed as a `RustStr`
+/// that points into the `'static` string so that no allocation is needed, and an owned string
+/// gets passed as a `RustString` that Swift takes ownership of. Which of these was passed is
+/// decided at runtime by the `is_owned` tag of the `__private__FfiCowStr`.
+#[derive(Debug)]
+pub(crate) struct BuiltInCowStr;
+
+impl BuiltInCowStr {
+    const FFI_NAME: &'static str = "__private__FfiCowStr";
+    const SWIFT_NAME: &'static str = "RustCowStr";
+
+    /// "Cow < 'static , str >" -> "Cow<'static,str>"
+    fn normalize_token_stream_str(tokens: &str) -> String {
+        let tokens = tokens.trim_start_matches("std :: ");
+        let tokens = tokens.trim_start_matches("borrow :: ");
+        tokens.replace(" ", "")
+    }
+}
+
+impl BridgeableType for BuiltInCowStr {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        quote! { std::borrow::Cow<'static, str> }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    Self::SWIFT_NAME.to_string()
+                } else {
+                    Self::FFI_NAME.to_string()
+                }
+            }
+            TypePosition::SharedStructField => {
+                todo!("Cow<'static, str> fields in structs are not yet supported.")
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => Self::FFI_NAME.to_string(),
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        format!("struct {}", Self::FFI_NAME)
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #swift_bridge_path::string::FfiCowStr }
+    }
+
+    fn supports_option(&self) -> bool {
+        false
+    }
+
+    fn supports_vec(&self) -> bool {
+        false
+    }
+
+    fn supports_swift_to_rust(&self) -> bool {
+        false
+    }
+
+    fn supports_shared_struct_field(&self) -> bool {
+        false
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        let ffi_ty = self.to_ffi_compatible_rust_type(swift_bridge_path, types);
+        quote_spanned! {span=>
+            #ffi_ty::from(#expression)
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        todo!("Support passing Cow<'static, str> from Swift to Rust")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support passing Cow<'static, str> from Swift to Rust")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("{expression}.intoSwiftRepr()")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<Cow<'static, str>, E>")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<T, Cow<'static, str>>")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        Self::normalize_token_stream_str(tokens) == "Cow<'static,str>"
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(&quote! { #path }.to_string(), types),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_parse_token_stream_str(tokens) {
+            Some(BuiltInCowStr)
+        } else {
+            None
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        "CowStr".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we parse `Cow<'static, str>` with or without its module path.
+    #[test]
+    fn parse_cow_str() {
+        let types = &TypeDeclarations::default();
+
+        for tokens in [
+            "Cow < 'static , str >",
+            "borrow :: Cow < 'static , str >",
+            "std :: borrow :: Cow < 'static , str >",
+        ] {
+            assert!(BuiltInCowStr::parse_token_stream_str(tokens, types).is_some());
+        }
+
+        assert!(BuiltInCowStr::parse_token_stream_str("Cow < 'a , str >", types).is_none());
+        assert!(BuiltInCowStr::parse_token_stream_str("Cow < 'static , [u8] >", types).is_none());
+    }
+}
@@ -0,0 +1,223 @@
+use crate::bridged_type::{BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition};
+use crate::parse::TypeDeclarations;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Path, Type};
+
+/// rust_decimal::Decimal
+///
+/// A `Decimal` is seen on the Swift side as a `Decimal`. It is passed over FFI by value as its
+/// mantissa, exponent and sign, so its scale is preserved. Using it requires `swift-bridge`'s
+/// `rust_decimal` feature.
+#[derive(Debug)]
+pub(crate) struct BuiltInDecimal;
+
+const FFI_NAME: &str = "__private__FfiDecimal";
+
+impl BridgeableType for BuiltInDecimal {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn generate_custom_swift_code(&self, _types: &TypeDeclarations) -> Option<String> {
+        // Swift's `Decimal` is declared in Foundation.
+        Some("import Foundation".to_string())
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        quote! { rust_decimal::Decimal }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    "Decimal".to_string()
+                } else {
+                    FFI_NAME.to_string()
+                }
+            }
+            TypePosition::SharedStructField => "Decimal".to_string(),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => FFI_NAME.to_string(),
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        format!("struct {}", FFI_NAME)
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #swift_bridge_path::decimal_support::FfiDecimal }
+    }
+
+    fn supports_option(&self) -> bool {
+        false
+    }
+
+    fn supports_vec(&self) -> bool {
+        false
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        let ffi_ty = self.to_ffi_compatible_rust_type(swift_bridge_path, types);
+        quote_spanned! {span=>
+            #ffi_ty::from(#expression)
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{FFI_NAME}({expression})")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let rust_ty = self.to_rust_type_path(types);
+        quote_spanned! {span=>
+            #rust_ty::from(#expression)
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("{expression}.intoSwiftRepr()")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<Decimal, E>")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<T, Decimal>")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens == "Decimal" || tokens == "rust_decimal :: Decimal"
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(&quote! { #path }.to_string(), types),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::can_parse_token_stream_str(tokens).then_some(BuiltInDecimal)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        "Decimal".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we parse `Decimal` with or without its crate path.
+    #[test]
+    fn parse_decimal() {
+        let types = &TypeDeclarations::default();
+
+        for tokens in ["Decimal", "rust_decimal :: Decimal"] {
+            assert!(BuiltInDecimal::parse_token_stream_str(tokens, types).is_some());
+        }
+        assert!(BuiltInDecimal::parse_token_stream_str("other :: Decimal", types).is_none());
+    }
+}
@@ -0,0 +1,639 @@
+use crate::bridged_type::{
+    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, OnlyEncoding, StdLibType, TypePosition,
+};
+use crate::parse::{HostLang, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Path, Type};
+
+/// The names of the FFI functions that every `HashMap<K, V>` gets.
+const FFI_FUNCTIONS: [&str; 8] = [
+    "new", "_free", "len", "get", "insert", "remove", "keys", "values",
+];
+
+/// HashMap<K, V>
+///
+/// Keys can be any primitive that implements `Hash + Eq` or a `String`.
+/// Values can be any primitive or a `String`.
+///
+/// Every `HashMap<K, V>` that appears in a bridge module gets its own set of FFI functions
+/// along with a constrained `extension RustMap where K == .., V == ..` on the Swift side.
+/// The functions' symbols include the module's name so that two modules can use the same
+/// `HashMap<K, V>`, and only one of the modules' Swift extensions gets kept when their Swift code
+/// gets compiled together.
+///
+/// Maps can't yet be used inside of an `Option`, a `Vec` or a `Result`, or as a struct field.
+#[derive(Debug)]
+pub(crate) struct BuiltInHashMap {
+    pub key: Box<BridgedType>,
+    pub value: Box<BridgedType>,
+}
+
+impl BuiltInHashMap {
+    /// Parse from a stringified token stream such as "HashMap < String , u64 >".
+    pub fn from_str_tokens(string: &str, types: &TypeDeclarations) -> Option<Self> {
+        // K , V >
+        let trimmed = string.trim_start_matches("std :: collections :: ");
+        let trimmed = trimmed.trim_start_matches("HashMap < ");
+        // K , V
+        let trimmed = trimmed.trim_end_matches(" >");
+
+        // [K, V]
+        let key_and_value = trimmed.split_once(",")?;
+        let key = key_and_value.0.trim();
+        let value = key_and_value.1.trim();
+
+        let key = BridgedType::new_with_str(key, types)?;
+        let value = BridgedType::new_with_str(value, types)?;
+
+        if !is_supported_key(&key, types) || !is_supported_value(&value, types) {
+            return None;
+        }
+
+        Some(BuiltInHashMap {
+            key: Box::new(key),
+            value: Box::new(value),
+        })
+    }
+
+    /// `HashMap<String, u64>` in `mod ffi` -> `__swift_bridge__$ffi$HashMap$String$U64`
+    fn ffi_name_prefix(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "{}${}$HashMap${}${}",
+            SWIFT_BRIDGE_PREFIX,
+            types.module_name(),
+            self.key.to_alpha_numeric_underscore_name(types),
+            self.value.to_alpha_numeric_underscore_name(types)
+        )
+    }
+
+    /// The `Option<V>` that gets returned when looking up a key.
+    fn option_value(&self, types: &TypeDeclarations) -> BridgedType {
+        let value = self.value.to_rust_type_path(types).to_string();
+        BridgedType::new_with_str(&format!("Option < {} >", value), types).unwrap()
+    }
+
+    /// RustMap<RustString, UInt64>
+    fn swift_map_type(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "RustMap<{}, {}>",
+            self.key
+                .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types),
+            self.value
+                .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types)
+        )
+    }
+
+    /// The Swift extension that exposes the map's methods for this particular `K` and `V`.
+    fn generate_swift_extension(&self, types: &TypeDeclarations) -> String {
+        let ffi_prefix = self.ffi_name_prefix(types);
+        let key_swift = self
+            .key
+            .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types);
+        let value_swift = self
+            .value
+            .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types);
+
+        let key_arg = TypePosition::FnArg(HostLang::Rust, 0);
+        let value_arg = TypePosition::FnArg(HostLang::Rust, 1);
+        let key_param = self.key.to_swift_type(key_arg, types);
+        let value_param = self.value.to_swift_type(value_arg, types);
+        let convert_key = self
+            .key
+            .convert_swift_expression_to_ffi_type("key", types, key_arg);
+        let convert_value = self
+            .value
+            .convert_swift_expression_to_ffi_type("value", types, value_arg);
+
+        let option_value = self.option_value(types);
+        let returned_option = |expression: &str| -> String {
+            option_value.convert_ffi_value_to_swift_value(
+                expression,
+                TypePosition::FnReturn(HostLang::Rust),
+                types,
+            )
+        };
+        let get = returned_option(&format!("{ffi_prefix}$get(ptr, {convert_key})"));
+        let insert = returned_option(&format!(
+            "{ffi_prefix}$insert(ptr, {convert_key}, {convert_value})"
+        ));
+        let remove = returned_option(&format!("{ffi_prefix}$remove(ptr, {convert_key})"));
+
+        let key_generics = if self.key.contains_owned_string_recursive(types) {
+            "<GenericIntoRustString: IntoRustString>"
+        } else {
+            ""
+        };
+        let key_or_value_generics = if self.key.contains_owned_string_recursive(types)
+            || self.value.contains_owned_string_recursive(types)
+        {
+            "<GenericIntoRustString: IntoRustString>"
+        } else {
+            ""
+        };
+
+        let key_native = swift_native_type(&self.key, types);
+        let value_native = swift_native_type(&self.value, types);
+        let key_to_native = rust_vec_item_to_swift_native("key", &self.key, types);
+        let value_to_native = rust_vec_item_to_swift_native("value", &self.value, types);
+
+        format!(
+            r#"
+extension RustMap where K == {key_swift}, V == {value_swift} {{
+    public convenience init(ptr: UnsafeMutableRawPointer) {{
+        self.init(ptr: ptr, freeFn: {{ {ffi_prefix}$_free($0) }})
+    }}
+
+    public convenience init() {{
+        self.init(ptr: {ffi_prefix}$new())
+    }}
+
+    public convenience init(_ dictionary: Dictionary<{key_native}, {value_native}>) {{
+        self.init()
+        for (key, value) in dictionary {{
+            let _ = self.insert(key, value)
+        }}
+    }}
+
+    /// Rust returns a UInt, but we cast to an Int because many Swift APIs expect Int.
+    public func len() -> Int {{
+        Int({ffi_prefix}$len(ptr))
+    }}
+
+    public func get{key_generics}(_ key: {key_param}) -> Optional<{value_swift}> {{
+        {get}
+    }}
+
+    public subscript{key_generics}(key: {key_param}) -> Optional<{value_swift}> {{
+        self.get(key)
+    }}
+
+    public func insert{key_or_value_generics}(_ key: {key_param}, _ value: {value_param}) -> Optional<{value_swift}> {{
+        {insert}
+    }}
+
+    public func remove{key_generics}(_ key: {key_param}) -> Optional<{value_swift}> {{
+        {remove}
+    }}
+
+    public func keys() -> RustVec<{key_swift}> {{
+        RustVec(ptr: {ffi_prefix}$keys(ptr))
+    }}
+
+    public func values() -> RustVec<{value_swift}> {{
+        RustVec(ptr: {ffi_prefix}$values(ptr))
+    }}
+
+    public func toDictionary() -> Dictionary<{key_native}, {value_native}> {{
+        var dictionary = Dictionary<{key_native}, {value_native}>()
+        for (key, value) in zip(self.keys(), self.values()) {{
+            dictionary[{key_to_native}] = {value_to_native}
+        }}
+        return dictionary
+    }}
+}}"#
+        )
+    }
+}
+
+impl BridgeableType for BuiltInHashMap {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let ffi_prefix = self.ffi_name_prefix(types);
+        let export_name = |function: &str| format!("{ffi_prefix}${function}");
+
+        let map_ty = self.to_rust_type_path(types);
+        let key_ffi = self
+            .key
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let value_ffi = self
+            .value
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+
+        let option_value = self.option_value(types);
+        let option_value_ffi = option_value.to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let returned_option = option_value.convert_rust_expression_to_ffi_type(
+            &quote! { val },
+            swift_bridge_path,
+            types,
+            Span::call_site(),
+        );
+
+        let key = self.key.convert_ffi_expression_to_rust_type(
+            &quote! { key },
+            Span::call_site(),
+            swift_bridge_path,
+            types,
+        );
+        let value = self.value.convert_ffi_expression_to_rust_type(
+            &quote! { value },
+            Span::call_site(),
+            swift_bridge_path,
+            types,
+        );
+        let key_vec_item = rust_vec_item(&quote! { key }, &self.key, swift_bridge_path, types);
+        let key_vec_item_ty = rust_vec_item_type(&self.key, swift_bridge_path, types);
+        let value_vec_item =
+            rust_vec_item(&quote! { value }, &self.value, swift_bridge_path, types);
+        let value_vec_item_ty = rust_vec_item_type(&self.value, swift_bridge_path, types);
+
+        let new_export = export_name("new");
+        let free_export = export_name("_free");
+        let len_export = export_name("len");
+        let get_export = export_name("get");
+        let insert_export = export_name("insert");
+        let remove_export = export_name("remove");
+        let keys_export = export_name("keys");
+        let values_export = export_name("values");
+
+        Some(vec![quote! {
+            const _: () = {
+                #[export_name = #new_export]
+                #[doc(hidden)]
+                pub extern "C" fn _new() -> *mut #map_ty {
+                    Box::into_raw(Box::new(std::collections::HashMap::new()))
+                }
+
+                #[export_name = #free_export]
+                #[doc(hidden)]
+                pub extern "C" fn _free(map: *mut #map_ty) {
+                    let _ = unsafe { Box::from_raw(map) };
+                }
+
+                #[export_name = #len_export]
+                #[doc(hidden)]
+                pub extern "C" fn _len(map: *const #map_ty) -> usize {
+                    unsafe { &*map }.len()
+                }
+
+                #[export_name = #get_export]
+                #[doc(hidden)]
+                pub extern "C" fn _get(map: *const #map_ty, key: #key_ffi) -> #option_value_ffi {
+                    let val = unsafe { &*map }.get(&#key).cloned();
+                    #returned_option
+                }
+
+                #[export_name = #insert_export]
+                #[doc(hidden)]
+                pub extern "C" fn _insert(
+                    map: *mut #map_ty,
+                    key: #key_ffi,
+                    value: #value_ffi
+                ) -> #option_value_ffi {
+                    let val = unsafe { &mut *map }.insert(#key, #value);
+                    #returned_option
+                }
+
+                #[export_name = #remove_export]
+                #[doc(hidden)]
+                pub extern "C" fn _remove(map: *mut #map_ty, key: #key_ffi) -> #option_value_ffi {
+                    let val = unsafe { &mut *map }.remove(&#key);
+                    #returned_option
+                }
+
+                #[export_name = #keys_export]
+                #[doc(hidden)]
+                pub extern "C" fn _keys(map: *const #map_ty) -> *mut Vec<#key_vec_item_ty> {
+                    let keys: Vec<#key_vec_item_ty> = unsafe { &*map }
+                        .keys()
+                        .cloned()
+                        .map(|key| #key_vec_item)
+                        .collect();
+                    Box::into_raw(Box::new(keys))
+                }
+
+                #[export_name = #values_export]
+                #[doc(hidden)]
+                pub extern "C" fn _values(map: *const #map_ty) -> *mut Vec<#value_vec_item_ty> {
+                    let values: Vec<#value_vec_item_ty> = unsafe { &*map }
+                        .values()
+                        .cloned()
+                        .map(|value| #value_vec_item)
+                        .collect();
+                    Box::into_raw(Box::new(values))
+                }
+            };
+        }])
+    }
+
+    fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct> {
+        let ffi_prefix = self.ffi_name_prefix(types);
+        let key = self.key.to_c(types);
+        let value = self.value.to_c(types);
+        let option_value = self.option_value(types).to_c(types);
+
+        let c_decl = format!(
+            r#"void* {ffi_prefix}$new(void);
+void {ffi_prefix}$_free(void* map);
+uintptr_t {ffi_prefix}$len(void* map);
+{option_value} {ffi_prefix}$get(void* map, {key} key);
+{option_value} {ffi_prefix}$insert(void* map, {key} key, {value} value);
+{option_value} {ffi_prefix}$remove(void* map, {key} key);
+void* {ffi_prefix}$keys(void* map);
+void* {ffi_prefix}$values(void* map);"#
+        );
+
+        Some(CFfiStruct {
+            c_ffi_type: c_decl,
+            fields: vec![],
+        })
+    }
+
+    fn generate_custom_swift_code(&self, types: &TypeDeclarations) -> Option<String> {
+        Some(self.generate_swift_extension(types))
+    }
+
+    fn custom_ffi_symbols(&self, types: &TypeDeclarations) -> Vec<String> {
+        let ffi_prefix = self.ffi_name_prefix(types);
+        FFI_FUNCTIONS
+            .iter()
+            .map(|function| format!("{ffi_prefix}${function}"))
+            .collect()
+    }
+
+    fn shared_swift_code_key(&self, types: &TypeDeclarations) -> Option<String> {
+        Some(self.swift_map_type(types))
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let key = self.key.to_rust_type_path(types);
+        let value = self.value.to_rust_type_path(types);
+        quote! { std::collections::HashMap<#key, #value> }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_map_type(types)
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => {
+                unreachable!("HashMap<K, V> fields are rejected while parsing")
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "UnsafeMutableRawPointer?".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        Some(vec!["stdint.h"])
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! { *mut #ty }
+    }
+
+    fn supports_option(&self) -> bool {
+        false
+    }
+
+    fn supports_vec(&self) -> bool {
+        false
+    }
+
+    fn supports_result(&self) -> bool {
+        false
+    }
+
+    fn supports_shared_struct_field(&self) -> bool {
+        false
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            Box::into_raw(Box::new( #expression ))
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ let val = {}; val.isOwned = false; return val.ptr }}()",
+            expression
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            unsafe { * Box::from_raw(#expression) }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+    ) -> String {
+        match type_pos {
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                format!("{}(ptr: {}!)", self.swift_map_type(types), expression)
+            }
+            _ => format!("{}(ptr: {})", self.swift_map_type(types), expression),
+        }
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<HashMap<K, V>, E> is rejected while parsing")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<T, HashMap<K, V>> is rejected while parsing")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("HashMap < ") || tokens.starts_with("std :: collections :: HashMap < ")
+    }
+
+    fn from_type(_ty: &Type, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        todo!()
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::from_str_tokens(tokens, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        // String keys and values are passed to Rust through the `RustMap`'s own methods, so
+        // functions that accept a `RustMap` don't need a `GenericIntoRustString` parameter.
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "HashMap{}{}",
+            self.key.to_alpha_numeric_underscore_name(types),
+            self.value.to_alpha_numeric_underscore_name(types)
+        )
+    }
+}
+
+pub(super) fn is_supported_key(ty: &BridgedType, types: &TypeDeclarations) -> bool {
+    match ty {
+        BridgedType::StdLib(
+            StdLibType::U8
+            | StdLibType::I8
+            | StdLibType::U16
+            | StdLibType::I16
+            | StdLibType::U32
+            | StdLibType::I32
+            | StdLibType::U64
+            | StdLibType::I64
+            | StdLibType::Usize
+            | StdLibType::Isize
+            | StdLibType::Bool,
+        ) => true,
+        _ => is_string(ty, types),
+    }
+}
+
+fn is_supported_value(ty: &BridgedType, types: &TypeDeclarations) -> bool {
+    match ty {
+        BridgedType::StdLib(StdLibType::F32 | StdLibType::F64) => true,
+        _ => is_supported_key(ty, types),
+    }
+}
+
+fn is_string(ty: &BridgedType, types: &TypeDeclarations) -> bool {
+    matches!(ty, BridgedType::Bridgeable(b) if b.is_built_in_type() && b.contains_owned_string_recursive(types))
+}
+
+/// Convert a key or value into the item type of the `Vec` that we hand over to Swift.
+///
+/// Swift's `RustVec<RustString>` is backed by a `Vec<RustString>`, so Strings get wrapped.
+pub(super) fn rust_vec_item(
+    expression: &TokenStream,
+    ty: &BridgedType,
+    swift_bridge_path: &Path,
+    types: &TypeDeclarations,
+) -> TokenStream {
+    if is_string(ty, types) {
+        quote! { #swift_bridge_path::string::RustString(#expression) }
+    } else {
+        quote! { #expression }
+    }
+}
+
+/// The item type of the `Vec` that holds a map's keys or values.
+pub(super) fn rust_vec_item_type(
+    ty: &BridgedType,
+    swift_bridge_path: &Path,
+    types: &TypeDeclarations,
+) -> TokenStream {
+    if is_string(ty, types) {
+        quote! { #swift_bridge_path::string::RustString }
+    } else {
+        ty.to_rust_type_path(types)
+    }
+}
+
+/// The type that a key or value has inside of a Swift `Dictionary`.
+pub(super) fn swift_native_type(ty: &BridgedType, types: &TypeDeclarations) -> String {
+    if is_string(ty, types) {
+        "String".to_string()
+    } else {
+        ty.to_swift_type(TypePosition::FnReturn(HostLang::Rust), types)
+    }
+}
+
+/// Convert an item of a `RustVec` into the type that it has inside of a Swift `Dictionary`.
+pub(super) fn rust_vec_item_to_swift_native(
+    expression: &str,
+    ty: &BridgedType,
+    types: &TypeDeclarations,
+) -> String {
+    if is_string(ty, types) {
+        format!("{}.toString()", expression)
+    } else {
+        expression.to_string()
+    }
+}
@@ -0,0 +1,513 @@
+use crate::bridged_type::built_in_hash_map::{
+    is_supported_key, rust_vec_item, rust_vec_item_to_swift_native, rust_vec_item_type,
+    swift_native_type,
+};
+use crate::bridged_type::{
+    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition,
+};
+use crate::parse::{HostLang, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Path, Type};
+
+/// The names of the FFI functions that every `HashSet<T>` gets.
+const FFI_FUNCTIONS: [&str; 7] = [
+    "new", "_free", "len", "contains", "insert", "remove", "values",
+];
+
+/// HashSet<T>
+///
+/// Values can be any primitive that implements `Hash + Eq` or a `String`.
+///
+/// Every `HashSet<T>` that appears in a bridge module gets its own set of FFI functions
+/// along with a constrained `extension RustSet where T == ..` on the Swift side.
+/// Like a `HashMap<K, V>`'s, the functions' symbols include the module's name and only one of the
+/// modules' Swift extensions gets kept when their Swift code gets compiled together.
+///
+/// Sets can't yet be used inside of an `Option`, a `Vec` or a `Result`, or as a struct field.
+#[derive(Debug)]
+pub(crate) struct BuiltInHashSet {
+    pub value: Box<BridgedType>,
+}
+
+impl BuiltInHashSet {
+    /// Parse from a stringified token stream such as "HashSet < String >".
+    pub fn from_str_tokens(string: &str, types: &TypeDeclarations) -> Option<Self> {
+        let trimmed = string.trim_start_matches("std :: collections :: ");
+        let trimmed = trimmed.trim_start_matches("HashSet < ");
+        let value = trimmed.trim_end_matches(" >").trim();
+
+        let value = BridgedType::new_with_str(value, types)?;
+        if !is_supported_key(&value, types) {
+            return None;
+        }
+
+        Some(BuiltInHashSet {
+            value: Box::new(value),
+        })
+    }
+
+    /// `HashSet<String>` in `mod ffi` -> `__swift_bridge__$ffi$HashSet$String`
+    fn ffi_name_prefix(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "{}${}$HashSet${}",
+            SWIFT_BRIDGE_PREFIX,
+            types.module_name(),
+            self.value.to_alpha_numeric_underscore_name(types),
+        )
+    }
+
+    /// RustSet<RustString>
+    fn swift_set_type(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "RustSet<{}>",
+            self.value
+                .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types),
+        )
+    }
+
+    /// IntoRustSetOfUInt32
+    ///
+    /// Functions that take a `HashSet<T>` accept anything that conforms to this protocol, which
+    /// lets Swift pass either a `RustSet<T>` or a Swift `Set`.
+    fn swift_into_set_protocol(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "IntoRustSetOf{}",
+            self.value
+                .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types),
+        )
+    }
+
+    /// The Swift extension that exposes the set's methods for this particular `T`.
+    fn generate_swift_extension(&self, types: &TypeDeclarations) -> String {
+        let ffi_prefix = self.ffi_name_prefix(types);
+        let value_swift = self
+            .value
+            .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types);
+
+        let value_arg = TypePosition::FnArg(HostLang::Rust, 0);
+        let value_param = self.value.to_swift_type(value_arg, types);
+        let convert_value = self
+            .value
+            .convert_swift_expression_to_ffi_type("value", types, value_arg);
+
+        let generics = if self.value.contains_owned_string_recursive(types) {
+            "<GenericIntoRustString: IntoRustString>"
+        } else {
+            ""
+        };
+
+        let value_native = swift_native_type(&self.value, types);
+        let value_to_native = rust_vec_item_to_swift_native("value", &self.value, types);
+        let set_type = self.swift_set_type(types);
+        let into_set_protocol = self.swift_into_set_protocol(types);
+
+        format!(
+            r#"
+extension RustSet where T == {value_swift} {{
+    public convenience init(ptr: UnsafeMutableRawPointer) {{
+        self.init(ptr: ptr, freeFn: {{ {ffi_prefix}$_free($0) }}, valuesFn: {{ {ffi_prefix}$values($0) }})
+    }}
+
+    public convenience init() {{
+        self.init(ptr: {ffi_prefix}$new())
+    }}
+
+    public convenience init(_ set: Set<{value_native}>) {{
+        self.init()
+        for value in set {{
+            self.insert(value)
+        }}
+    }}
+
+    /// Rust returns a UInt, but we cast to an Int because many Swift APIs expect Int.
+    public func len() -> Int {{
+        Int({ffi_prefix}$len(ptr))
+    }}
+
+    public func contains{generics}(_ value: {value_param}) -> Bool {{
+        {ffi_prefix}$contains(ptr, {convert_value})
+    }}
+
+    @discardableResult
+    public func insert{generics}(_ value: {value_param}) -> Bool {{
+        {ffi_prefix}$insert(ptr, {convert_value})
+    }}
+
+    @discardableResult
+    public func remove{generics}(_ value: {value_param}) -> Bool {{
+        {ffi_prefix}$remove(ptr, {convert_value})
+    }}
+
+    public func values() -> RustVec<{value_swift}> {{
+        RustVec(ptr: {ffi_prefix}$values(ptr))
+    }}
+
+    public func toSet() -> Set<{value_native}> {{
+        var set = Set<{value_native}>()
+        for value in self.values() {{
+            set.insert({value_to_native})
+        }}
+        return set
+    }}
+}}
+
+public protocol {into_set_protocol} {{
+    func intoRustSet() -> {set_type}
+}}
+extension RustSet: {into_set_protocol} where T == {value_swift} {{
+    public func intoRustSet() -> {set_type} {{
+        self
+    }}
+}}
+extension Set: {into_set_protocol} where Element == {value_native} {{
+    public func intoRustSet() -> {set_type} {{
+        RustSet(self)
+    }}
+}}"#
+        )
+    }
+}
+
+impl BridgeableType for BuiltInHashSet {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let ffi_prefix = self.ffi_name_prefix(types);
+        let export_name = |function: &str| format!("{ffi_prefix}${function}");
+
+        let set_ty = self.to_rust_type_path(types);
+        let value_ffi = self
+            .value
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+
+        let value = self.value.convert_ffi_expression_to_rust_type(
+            &quote! { value },
+            Span::call_site(),
+            swift_bridge_path,
+            types,
+        );
+        let value_vec_item =
+            rust_vec_item(&quote! { value }, &self.value, swift_bridge_path, types);
+        let value_vec_item_ty = rust_vec_item_type(&self.value, swift_bridge_path, types);
+
+        let new_export = export_name("new");
+        let free_export = export_name("_free");
+        let len_export = export_name("len");
+        let contains_export = export_name("contains");
+        let insert_export = export_name("insert");
+        let remove_export = export_name("remove");
+        let values_export = export_name("values");
+
+        Some(vec![quote! {
+            const _: () = {
+                #[export_name = #new_export]
+                #[doc(hidden)]
+                pub extern "C" fn _new() -> *mut #set_ty {
+                    Box::into_raw(Box::new(std::collections::HashSet::new()))
+                }
+
+                #[export_name = #free_export]
+                #[doc(hidden)]
+                pub extern "C" fn _free(set: *mut #set_ty) {
+                    let _ = unsafe { Box::from_raw(set) };
+                }
+
+                #[export_name = #len_export]
+                #[doc(hidden)]
+                pub extern "C" fn _len(set: *const #set_ty) -> usize {
+                    unsafe { &*set }.len()
+                }
+
+                #[export_name = #contains_export]
+                #[doc(hidden)]
+                pub extern "C" fn _contains(set: *const #set_ty, value: #value_ffi) -> bool {
+                    unsafe { &*set }.contains(&#value)
+                }
+
+                #[export_name = #insert_export]
+                #[doc(hidden)]
+                pub extern "C" fn _insert(set: *mut #set_ty, value: #value_ffi) -> bool {
+                    unsafe { &mut *set }.insert(#value)
+                }
+
+                #[export_name = #remove_export]
+                #[doc(hidden)]
+                pub extern "C" fn _remove(set: *mut #set_ty, value: #value_ffi) -> bool {
+                    unsafe { &mut *set }.remove(&#value)
+                }
+
+                #[export_name = #values_export]
+                #[doc(hidden)]
+                pub extern "C" fn _values(set: *const #set_ty) -> *mut Vec<#value_vec_item_ty> {
+                    let values: Vec<#value_vec_item_ty> = unsafe { &*set }
+                        .iter()
+                        .cloned()
+                        .map(|value| #value_vec_item)
+                        .collect();
+                    Box::into_raw(Box::new(values))
+                }
+            };
+        }])
+    }
+
+    fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct> {
+        let ffi_prefix = self.ffi_name_prefix(types);
+        let value = self.value.to_c(types);
+
+        let c_decl = format!(
+            r#"void* {ffi_prefix}$new(void);
+void {ffi_prefix}$_free(void* set);
+uintptr_t {ffi_prefix}$len(void* set);
+bool {ffi_prefix}$contains(void* set, {value} value);
+bool {ffi_prefix}$insert(void* set, {value} value);
+bool {ffi_prefix}$remove(void* set, {value} value);
+void* {ffi_prefix}$values(void* set);"#
+        );
+
+        Some(CFfiStruct {
+            c_ffi_type: c_decl,
+            fields: vec![],
+        })
+    }
+
+    fn generate_custom_swift_code(&self, types: &TypeDeclarations) -> Option<String> {
+        Some(self.generate_swift_extension(types))
+    }
+
+    fn custom_ffi_symbols(&self, types: &TypeDeclarations) -> Vec<String> {
+        let ffi_prefix = self.ffi_name_prefix(types);
+        FFI_FUNCTIONS
+            .iter()
+            .map(|function| format!("{ffi_prefix}${function}"))
+            .collect()
+    }
+
+    fn shared_swift_code_key(&self, types: &TypeDeclarations) -> Option<String> {
+        Some(self.swift_set_type(types))
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let value = self.value.to_rust_type_path(types);
+        quote! { std::collections::HashSet<#value> }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    match type_pos {
+                        TypePosition::FnArg(_, _) => self.swift_into_set_protocol(types),
+                        _ => self.swift_set_type(types),
+                    }
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => {
+                unreachable!("HashSet<T> fields are rejected while parsing")
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "UnsafeMutableRawPointer?".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        Some(vec!["stdint.h", "stdbool.h"])
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! { *mut #ty }
+    }
+
+    fn supports_option(&self) -> bool {
+        false
+    }
+
+    fn supports_vec(&self) -> bool {
+        false
+    }
+
+    fn supports_result(&self) -> bool {
+        false
+    }
+
+    fn supports_shared_struct_field(&self) -> bool {
+        false
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            Box::into_raw(Box::new( #expression ))
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ let val = {}.intoRustSet(); val.isOwned = false; return val.ptr }}()",
+            expression
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            unsafe { * Box::from_raw(#expression) }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+    ) -> String {
+        match type_pos {
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                format!("{}(ptr: {}!)", self.swift_set_type(types), expression)
+            }
+            _ => format!("{}(ptr: {})", self.swift_set_type(types), expression),
+        }
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<HashSet<T>, E> is rejected while parsing")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<T, HashSet<E>> is rejected while parsing")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("HashSet < ") || tokens.starts_with("std :: collections :: HashSet < ")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(&quote! { #path }.to_string(), types),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::from_str_tokens(tokens, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        // String values are passed to Rust through the `RustSet`'s own methods, so functions
+        // that accept a `RustSet` don't need a `GenericIntoRustString` parameter.
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "HashSet{}",
+            self.value.to_alpha_numeric_underscore_name(types)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we only parse sets of hashable primitives and Strings.
+    #[test]
+    fn parse_hash_set() {
+        let types = &TypeDeclarations::default();
+
+        assert!(BuiltInHashSet::from_str_tokens("HashSet < u32 >", types).is_some());
+        assert!(BuiltInHashSet::from_str_tokens("HashSet < String >", types).is_some());
+        assert!(
+            BuiltInHashSet::from_str_tokens("std :: collections :: HashSet < bool >", types)
+                .is_some()
+        );
+
+        assert!(BuiltInHashSet::from_str_tokens("HashSet < f64 >", types).is_none());
+    }
+}
@@ -0,0 +1,218 @@
+use crate::bridged_type::{BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition};
+use crate::parse::TypeDeclarations;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Path, Type};
+
+/// u128 or i128
+///
+/// 128 bit integers do not have a stable C ABI, so they get passed over FFI as a struct of two
+/// 64 bit limbs. On the Swift side they are seen as `RustUInt128` and `RustInt128`.
+#[derive(Debug)]
+pub(crate) struct BuiltInInt128 {
+    pub signed: bool,
+}
+
+impl BuiltInInt128 {
+    /// `RustUInt128` or `RustInt128`
+    fn ffi_name(&self) -> &'static str {
+        if self.signed {
+            "RustInt128"
+        } else {
+            "RustUInt128"
+        }
+    }
+}
+
+impl BridgeableType for BuiltInInt128 {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        if self.signed {
+            quote! { i128 }
+        } else {
+            quote! { u128 }
+        }
+    }
+
+    fn to_swift_type(&self, _type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        self.ffi_name().to_string()
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        format!("struct {}", self.ffi_name())
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        if self.signed {
+            quote! { #swift_bridge_path::int128::RustInt128 }
+        } else {
+            quote! { #swift_bridge_path::int128::RustUInt128 }
+        }
+    }
+
+    fn supports_option(&self) -> bool {
+        false
+    }
+
+    fn supports_vec(&self) -> bool {
+        false
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        let ffi_ty = self.to_ffi_compatible_rust_type(swift_bridge_path, types);
+        quote_spanned! {span=>
+            #ffi_ty::from(#expression)
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        expression.to_string()
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let rust_ty = self.to_rust_type_path(types);
+        quote_spanned! {span=>
+            #rust_ty::from(#expression)
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        expression.to_string()
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<u128, E> and Result<i128, E>")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<T, u128> and Result<T, i128>")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens == "u128" || tokens == "i128"
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(&quote! { #path }.to_string(), types),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match tokens {
+            "u128" => Some(BuiltInInt128 { signed: false }),
+            "i128" => Some(BuiltInInt128 { signed: true }),
+            _ => None,
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        if self.signed {
+            "I128".to_string()
+        } else {
+            "U128".to_string()
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use crate::bridged_type::{
+    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition,
+};
+use crate::parse::{HostLang, TypeDeclarations};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Path, Type};
+
+const INTEGERS: [&str; 10] = [
+    "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "usize", "isize",
+];
+
+/// std::num::NonZeroU32 and friends.
+///
+/// A non zero integer is passed over FFI as its plain integer and is seen on the Swift side as
+/// that plain integer, so `NonZeroU32` is seen as a `UInt32`.
+///
+/// Swift asserts that the integer is not zero before handing it to Rust, so passing a zero is
+/// caught in debug builds at the Swift call site. Rust still checks the integer before
+/// constructing the `NonZero*`.
+#[derive(Debug)]
+pub(crate) struct BuiltInNonZero {
+    /// The integer type, such as `u32`.
+    pub integer: &'static str,
+}
+
+impl BuiltInNonZero {
+    /// Parse from a stringified token stream such as "NonZeroU32",
+    /// "std :: num :: NonZeroU32" or "NonZero < u32 >".
+    pub fn from_str_tokens(tokens: &str) -> Option<Self> {
+        let tokens = tokens.trim_start_matches("std :: ");
+        let tokens = tokens.trim_start_matches("core :: ");
+        let tokens = tokens.trim_start_matches("num :: ");
+
+        let integer = if let Some(generic) = tokens.strip_prefix("NonZero < ") {
+            generic.strip_suffix(" >")?.to_string()
+        } else {
+            tokens.strip_prefix("NonZero")?.to_lowercase()
+        };
+
+        INTEGERS
+            .iter()
+            .find(|i| **i == integer)
+            .map(|integer| BuiltInNonZero { integer })
+    }
+
+    /// `u32` -> `U32`
+    fn capitalized_integer(&self) -> String {
+        let mut chars = self.integer.chars();
+        let first = chars.next().unwrap().to_ascii_uppercase();
+        format!("{first}{}", chars.as_str())
+    }
+
+    /// `NonZeroU32`
+    fn non_zero_ident(&self) -> Ident {
+        Ident::new(
+            &format!("NonZero{}", self.capitalized_integer()),
+            Span::call_site(),
+        )
+    }
+
+    fn integer_ty(&self) -> BridgedType {
+        BridgedType::new_with_str(self.integer, &TypeDeclarations::default()).unwrap()
+    }
+}
+
+impl BridgeableType for BuiltInNonZero {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        let non_zero = self.non_zero_ident();
+        quote! { std::num::#non_zero }
+    }
+
+    fn to_swift_type(&self, _type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        self.integer_ty()
+            .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types)
+    }
+
+    fn to_c_type(&self, types: &TypeDeclarations) -> String {
+        self.integer_ty().to_c(types)
+    }
+
+    fn to_c_include(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        self.integer_ty().to_c_include(types)
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.integer_ty().to_rust_type_path(types)
+    }
+
+    fn supports_option(&self) -> bool {
+        false
+    }
+
+    fn supports_vec(&self) -> bool {
+        false
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            #expression.get()
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            r#"{{ let val = {expression}; assert(val != 0, "{} cannot be zero"); return val }}()"#,
+            self.non_zero_ident()
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let rust_ty = self.to_rust_type_path(types);
+        let message = format!("{} cannot be zero", self.non_zero_ident());
+        quote_spanned! {span=>
+            #rust_ty::new(#expression).expect(#message)
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        expression.to_string()
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<NonZero*, E>")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<T, NonZero*>")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        Self::from_str_tokens(tokens).is_some()
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(&quote! { #path }.to_string(), types),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::from_str_tokens(tokens)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        self.non_zero_ident().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we parse the `NonZero*` integers with or without their module path.
+    #[test]
+    fn parse_non_zero() {
+        for (tokens, integer) in [
+            ("NonZeroU32", "u32"),
+            ("std :: num :: NonZeroI64", "i64"),
+            ("num :: NonZeroUsize", "usize"),
+            ("NonZero < u8 >", "u8"),
+            ("std :: num :: NonZero < isize >", "isize"),
+        ] {
+            assert_eq!(
+                BuiltInNonZero::from_str_tokens(tokens).unwrap().integer,
+                integer
+            );
+        }
+
+        assert!(BuiltInNonZero::from_str_tokens("NonZeroU128").is_none());
+        assert!(BuiltInNonZero::from_str_tokens("NonZero < f32 >").is_none());
+        assert!(BuiltInNonZero::from_str_tokens("NonZero").is_none());
+    }
+}
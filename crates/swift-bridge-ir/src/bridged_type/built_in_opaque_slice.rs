@@ -0,0 +1,322 @@
+use crate::bridged_type::{BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition};
+use crate::parse::{TypeDeclaration, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Path, Type};
+
+/// &[T] where T is an opaque Rust type.
+///
+/// The slice is seen on the Swift side as a `RustSlice<T>`, a read-only collection whose elements
+/// are `{T}Ref`s that point directly into the Rust slice, so no elements are cloned.
+#[derive(Debug)]
+pub(crate) struct BuiltInOpaqueSlice {
+    pub ty: Ident,
+    /// The name of `T` on the Swift side.
+    pub swift_name: String,
+}
+
+impl BuiltInOpaqueSlice {
+    /// Returns `None` if the slice's element type is not a non-generic, non-Copy opaque Rust type.
+    pub fn from_slice_elem(elem: &Type, types: &TypeDeclarations) -> Option<Self> {
+        let elem = match elem {
+            Type::Path(path) => path,
+            _ => return None,
+        };
+
+        match types.get_with_type_path(elem)? {
+            TypeDeclaration::Opaque(opaque)
+                if opaque.host_lang.is_rust()
+                    && opaque.attributes.copy.is_none()
+                    && opaque.generics.is_empty() =>
+            {
+                Some(BuiltInOpaqueSlice {
+                    ty: opaque.ty.clone(),
+                    swift_name: opaque.swift_name_string(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// `&[SomeType]` -> `__swift_bridge__$SomeType$_slice_get`
+    fn slice_get_fn_name(&self) -> String {
+        format!("{}${}$_slice_get", SWIFT_BRIDGE_PREFIX, self.ty)
+    }
+
+    fn generate_swift_extension(&self) -> String {
+        let ty = &self.swift_name;
+        let slice_get = self.slice_get_fn_name();
+
+        format!(
+            r#"
+extension {ty}: RustSliceElement {{
+    public static func sliceOfSelfGet(start: UnsafeMutableRawPointer, index: UInt) -> {ty}Ref {{
+        {ty}Ref(ptr: {slice_get}(start, index)!)
+    }}
+}}"#
+        )
+    }
+}
+
+impl BridgeableType for BuiltInOpaqueSlice {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let ty = &self.ty;
+        let slice_get = self.slice_get_fn_name();
+
+        Some(vec![quote! {
+            const _: () = {
+                #[export_name = #slice_get]
+                #[doc(hidden)]
+                pub extern "C" fn _slice_get(
+                    start: *const super::#ty,
+                    index: usize
+                ) -> *const super::#ty {
+                    unsafe { start.add(index) }
+                }
+            };
+        }])
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        Some(CFfiStruct {
+            c_ffi_type: format!(
+                "void* {}(void* start, uintptr_t index);",
+                self.slice_get_fn_name()
+            ),
+            fields: vec![],
+        })
+    }
+
+    fn generate_custom_swift_code(&self, _types: &TypeDeclarations) -> Option<String> {
+        Some(self.generate_swift_extension())
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        let ty = &self.ty;
+        quote! { &[super::#ty] }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    format!("RustSlice<{}>", self.swift_name)
+                } else {
+                    "__private__FfiSlice".to_string()
+                }
+            }
+            TypePosition::SharedStructField => {
+                todo!("Slices in shared struct fields are not yet supported.")
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                todo!("Returning a slice from an async function is not yet supported.")
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "struct __private__FfiSlice".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        Some(vec!["stdint.h"])
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = &self.ty;
+        quote! { #swift_bridge_path::FfiSlice<super::#ty> }
+    }
+
+    fn supports_option(&self) -> bool {
+        false
+    }
+
+    fn supports_vec(&self) -> bool {
+        false
+    }
+
+    fn supports_shared_struct_field(&self) -> bool {
+        false
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            #swift_bridge_path::FfiSlice::from_slice(#expression)
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{expression}.toFfiSlice()")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            #expression.as_slice()
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("RustSlice({expression})")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<&[T], E>")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<T, &[E]>")
+    }
+
+    fn can_parse_token_stream_str(_tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        false
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Reference(reference) if reference.mutability.is_none() => {
+                match reference.elem.as_ref() {
+                    Type::Slice(slice) => Self::from_slice_elem(&slice.elem, types),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ty: Type = syn::parse_str(tokens).ok()?;
+        Self::from_type(&ty, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        format!("Slice{}", self.ty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse_ok;
+    use quote::quote;
+
+    /// Verify that we only parse `&[T]` when `T` is a non-generic, non-Copy opaque Rust type.
+    #[test]
+    fn parse_opaque_slice() {
+        let module = parse_ok(quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    #[swift_bridge(Copy(4))]
+                    type SomeCopyType;
+                }
+                extern "Swift" {
+                    type SomeSwiftType;
+                }
+            }
+        });
+        let types = &module.types;
+
+        let parse = |tokens: &str| {
+            let ty: Type = syn::parse_str(tokens).unwrap();
+            BuiltInOpaqueSlice::from_type(&ty, types)
+        };
+
+        assert_eq!(parse("&[SomeType]").unwrap().ty.to_string(), "SomeType");
+
+        assert!(parse("&mut [SomeType]").is_none());
+        assert!(parse("&[SomeCopyType]").is_none());
+        assert!(parse("&[SomeSwiftType]").is_none());
+        assert!(parse("&[u8]").is_none());
+    }
+}
@@ -0,0 +1,295 @@
+use crate::bridged_type::{BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition};
+use crate::parse::TypeDeclarations;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Path, Type};
+
+/// std::path::PathBuf or &std::path::Path
+///
+/// Paths are seen on the Swift side as a `String` and are passed over FFI as a `RustString`.
+/// Swift strings are always valid UTF-8, but Rust paths are not, so a path that is not valid UTF-8
+/// is lossily converted (invalid sequences become `U+FFFD`) before it is passed to Swift.
+#[derive(Debug)]
+pub(crate) struct BuiltInPath {
+    /// `&Path` if true, `PathBuf` otherwise.
+    pub reference: bool,
+}
+
+impl BuiltInPath {
+    /// Whether or not the stringified path is `Path`, such as "std :: path :: Path".
+    pub fn is_path(tokens: &str) -> bool {
+        trim_module_path(tokens) == "Path"
+    }
+}
+
+fn trim_module_path(tokens: &str) -> &str {
+    let tokens = tokens.trim_start_matches("std :: ");
+    tokens.trim_start_matches("path :: ")
+}
+
+impl BridgeableType for BuiltInPath {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        if self.reference {
+            quote! { &std::path::Path }
+        } else {
+            quote! { std::path::PathBuf }
+        }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    "String".to_string()
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => {
+                todo!("Paths in shared struct fields are not yet supported.")
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "UnsafeMutableRawPointer?".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { *mut #swift_bridge_path::string::RustString }
+    }
+
+    fn supports_option(&self) -> bool {
+        false
+    }
+
+    fn supports_vec(&self) -> bool {
+        false
+    }
+
+    fn supports_shared_struct_field(&self) -> bool {
+        false
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        let string = if self.reference {
+            quote_spanned! {span=>
+                #expression.to_string_lossy().into_owned()
+            }
+        } else {
+            // Avoid copying the path when it is already valid UTF-8.
+            quote_spanned! {span=>
+                #expression
+                    .into_os_string()
+                    .into_string()
+                    .unwrap_or_else(|path| path.to_string_lossy().into_owned())
+            }
+        };
+
+        quote_spanned! {span=>
+            #swift_bridge_path::string::RustString(#string).box_into_raw()
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ let rustString = {expression}.intoRustString(); rustString.isOwned = false; return rustString.ptr }}()"
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let path_buf = quote_spanned! {span=>
+            std::path::PathBuf::from(unsafe { Box::from_raw(#expression).0 })
+        };
+
+        if self.reference {
+            quote_spanned! {span=>
+                &#path_buf
+            }
+        } else {
+            path_buf
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        match type_pos {
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                format!("RustString(ptr: {expression}!).toString()")
+            }
+            _ => format!("RustString(ptr: {expression}).toString()"),
+        }
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<PathBuf, E>")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<T, PathBuf>")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        trim_module_path(tokens) == "PathBuf"
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(&quote! { #path }.to_string(), types),
+            Type::Reference(reference) if reference.mutability.is_none() => {
+                let elem = &reference.elem;
+                if Self::is_path(&quote! { #elem }.to_string()) {
+                    Some(BuiltInPath { reference: true })
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_parse_token_stream_str(tokens) {
+            Some(BuiltInPath { reference: false })
+        } else {
+            None
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        if self.reference {
+            "Path".to_string()
+        } else {
+            "PathBuf".to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we parse `PathBuf` and `&Path` with or without their module path.
+    #[test]
+    fn parse_path_types() {
+        let types = &TypeDeclarations::default();
+
+        for tokens in ["PathBuf", "path::PathBuf", "std::path::PathBuf"] {
+            let ty: Type = syn::parse_str(tokens).unwrap();
+            assert!(!BuiltInPath::from_type(&ty, types).unwrap().reference);
+        }
+        for tokens in ["&Path", "&std::path::Path"] {
+            let ty: Type = syn::parse_str(tokens).unwrap();
+            assert!(BuiltInPath::from_type(&ty, types).unwrap().reference);
+        }
+
+        for tokens in ["Path", "&mut Path", "&PathBuf"] {
+            let ty: Type = syn::parse_str(tokens).unwrap();
+            assert!(BuiltInPath::from_type(&ty, types).is_none());
+        }
+    }
+}
@@ -0,0 +1,269 @@
+use crate::bridged_type::{BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition};
+use crate::parse::TypeDeclarations;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Path, Type};
+
+/// std::time::Duration or std::time::SystemTime
+///
+/// A `Duration` is seen on the Swift side as a `TimeInterval` and a `SystemTime` is seen as a
+/// `Date`. Both are passed over FFI as whole seconds plus nanoseconds so that no precision is lost
+/// when a value is passed from Rust to Swift and back.
+#[derive(Debug)]
+pub(crate) enum BuiltInTime {
+    Duration,
+    SystemTime,
+}
+
+impl BuiltInTime {
+    /// `__private__FfiDuration` or `__private__FfiSystemTime`
+    fn ffi_name(&self) -> &'static str {
+        match self {
+            BuiltInTime::Duration => "__private__FfiDuration",
+            BuiltInTime::SystemTime => "__private__FfiSystemTime",
+        }
+    }
+
+    /// `TimeInterval` or `Date`
+    fn swift_name(&self) -> &'static str {
+        match self {
+            BuiltInTime::Duration => "TimeInterval",
+            BuiltInTime::SystemTime => "Date",
+        }
+    }
+}
+
+impl BridgeableType for BuiltInTime {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn generate_custom_swift_code(&self, _types: &TypeDeclarations) -> Option<String> {
+        // `TimeInterval` and `Date` are declared in Foundation.
+        Some("import Foundation".to_string())
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        match self {
+            BuiltInTime::Duration => quote! { std::time::Duration },
+            BuiltInTime::SystemTime => quote! { std::time::SystemTime },
+        }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_name().to_string()
+                } else {
+                    self.ffi_name().to_string()
+                }
+            }
+            TypePosition::SharedStructField => self.swift_name().to_string(),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => self.ffi_name().to_string(),
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        format!("struct {}", self.ffi_name())
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        match self {
+            BuiltInTime::Duration => quote! { #swift_bridge_path::time::FfiDuration },
+            BuiltInTime::SystemTime => quote! { #swift_bridge_path::time::FfiSystemTime },
+        }
+    }
+
+    fn supports_option(&self) -> bool {
+        false
+    }
+
+    fn supports_vec(&self) -> bool {
+        false
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        let ffi_ty = self.to_ffi_compatible_rust_type(swift_bridge_path, types);
+        quote_spanned! {span=>
+            #ffi_ty::from(#expression)
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{}({expression})", self.ffi_name())
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let rust_ty = self.to_rust_type_path(types);
+        quote_spanned! {span=>
+            #rust_ty::from(#expression)
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("{expression}.intoSwiftRepr()")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<Duration, E> and Result<SystemTime, E>")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<T, Duration> and Result<T, SystemTime>")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        let tokens = tokens.trim_start_matches("std :: ");
+        let tokens = tokens.trim_start_matches("time :: ");
+        tokens == "Duration" || tokens == "SystemTime"
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(&quote! { #path }.to_string(), types),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let tokens = tokens.trim_start_matches("std :: ");
+        let tokens = tokens.trim_start_matches("time :: ");
+        match tokens {
+            "Duration" => Some(BuiltInTime::Duration),
+            "SystemTime" => Some(BuiltInTime::SystemTime),
+            _ => None,
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        match self {
+            BuiltInTime::Duration => "Duration".to_string(),
+            BuiltInTime::SystemTime => "SystemTime".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we parse `Duration` and `SystemTime` with or without their module path.
+    #[test]
+    fn parse_time_types() {
+        let types = &TypeDeclarations::default();
+
+        for tokens in ["Duration", "time :: Duration", "std :: time :: Duration"] {
+            assert!(matches!(
+                BuiltInTime::parse_token_stream_str(tokens, types),
+                Some(BuiltInTime::Duration)
+            ));
+        }
+        for tokens in ["SystemTime", "std :: time :: SystemTime"] {
+            assert!(matches!(
+                BuiltInTime::parse_token_stream_str(tokens, types),
+                Some(BuiltInTime::SystemTime)
+            ));
+        }
+
+        assert!(BuiltInTime::parse_token_stream_str("Instant", types).is_none());
+    }
+}
@@ -1,7 +1,5 @@
 use crate::bridged_type::shared_struct::UnnamedStructFields;
-use crate::bridged_type::{
-    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
-};
+use crate::bridged_type::{BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition};
 use crate::parse::TypeDeclarations;
 use crate::SWIFT_BRIDGE_PREFIX;
 use std::fmt::Debug;
@@ -123,7 +121,10 @@ impl BridgeableType for BuiltInTuple {
                 }
             }
             TypePosition::SharedStructField => todo!(),
-            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => todo!(),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                let field_signatures = self.0.combine_field_types_into_ffi_name_string(types);
+                format!("__swift_bridge__$tuple${field_signatures}")
+            }
         }
     }
 
@@ -159,11 +160,7 @@ impl BridgeableType for BuiltInTuple {
         todo!()
     }
 
-    fn to_ffi_compatible_option_swift_type(
-        &self,
-        _swift_bridge_path: &Path,
-        _types: &TypeDeclarations,
-    ) -> String {
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
         todo!()
     }
 
@@ -247,7 +244,11 @@ impl BridgeableType for BuiltInTuple {
         };
     }
 
-    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
         todo!()
     }
 
@@ -290,10 +291,6 @@ impl BridgeableType for BuiltInTuple {
         todo!();
     }
 
-    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
-        todo!();
-    }
-
     fn can_parse_token_stream_str(_tokens: &str) -> bool
     where
         Self: Sized,
@@ -1,3 +1,5 @@
+use crate::bridge_module_attributes::CfgAttr;
+use crate::parse::SwiftSendable;
 use crate::SWIFT_BRIDGE_PREFIX;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
@@ -21,6 +23,13 @@ pub(crate) struct SharedEnum {
     pub already_declared: bool,
     pub swift_name: Option<LitStr>,
     pub derive: DeriveAttrs,
+    /// `#[swift_bridge(Sendable)]`
+    /// How the Swift enum conforms to `Sendable`, if it does.
+    pub sendable: Option<SwiftSendable>,
+    /// The enum's `/// ...` doc comment, which gets emitted as Swift documentation.
+    pub doc_comment: Option<String>,
+    /// The enum's `#[cfg(...)]` attributes.
+    pub cfg_attrs: Vec<CfgAttr>,
 }
 
 impl SharedEnum {
@@ -1,8 +1,9 @@
 pub(crate) use self::struct_field::StructField;
 pub(crate) use self::struct_field::StructFields;
 use self::struct_field::UnnamedStructField;
+use crate::bridge_module_attributes::CfgAttr;
 use crate::bridged_type::{BridgedType, OnlyEncoding, TypePosition};
-use crate::parse::TypeDeclarations;
+use crate::parse::{SwiftSendable, TypeDeclarations};
 use crate::SWIFT_BRIDGE_PREFIX;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
@@ -251,6 +252,16 @@ pub(crate) struct SharedStruct {
     pub swift_name: Option<LitStr>,
     pub already_declared: bool,
     pub derives: StructDerives,
+    /// `#[swift_bridge(Identifiable)]`
+    /// Whether the Swift struct should conform to `Identifiable` using its `id` field.
+    pub identifiable: bool,
+    /// `#[swift_bridge(Sendable)]`
+    /// How the Swift struct conforms to `Sendable`, if it does.
+    pub sendable: Option<SwiftSendable>,
+    /// The struct's `/// ...` doc comment, which gets emitted as Swift documentation.
+    pub doc_comment: Option<String>,
+    /// The struct's `#[cfg(...)]` attributes.
+    pub cfg_attrs: Vec<CfgAttr>,
 }
 
 #[derive(Clone)]
@@ -581,6 +592,7 @@ impl PartialEq for SharedStruct {
             && self.swift_name.as_ref().map(|l| l.value())
                 == other.swift_name.as_ref().map(|l| l.value())
             && self.already_declared == other.already_declared
+            && self.identifiable == other.identifiable
     }
 }
 
@@ -592,6 +604,7 @@ impl Debug for SharedStruct {
             .field("fields", &self.fields)
             .field("swift_name", &self.swift_name.as_ref().map(|l| l.value()))
             .field("already_declared", &self.already_declared)
+            .field("identifiable", &self.identifiable)
             .finish()
     }
 }
@@ -1,44 +1,128 @@
 use crate::bridge_module_attributes::CfgAttr;
 use crate::SwiftBridgeModule;
 
+pub use self::extern_swift_signatures::ExternSwiftSignature;
+pub use self::generated_declarations::{GeneratedDeclaration, GeneratedDeclarationKind};
+
+mod extern_swift_signatures;
 mod generate_c_header;
 mod generate_rust_tokens;
 mod generate_swift;
+mod generated_declarations;
 
 #[cfg(test)]
 mod codegen_tests;
 
 /// The corresponding Swift code and C header for a bridge module.
 pub struct SwiftCodeAndCHeader {
-    /// The generated Swift code.
+    /// The generated Swift code, not including the [`SwiftCodeAndCHeader::shared_swift`].
     pub swift: String,
     /// The generated C header.
     pub c_header: String,
+    /// The Swift code that other bridge modules that use the same types generate as well, which
+    /// is kept out of [`SwiftCodeAndCHeader::swift`] so that only one copy of it gets emitted.
+    pub shared_swift: Vec<SharedSwiftCode>,
+}
+
+impl SwiftCodeAndCHeader {
+    /// The generated Swift code followed by all of its shared code, for when the module's Swift
+    /// code won't be compiled together with another module's.
+    pub fn swift_with_shared_code(&self) -> String {
+        let mut swift = self.swift.clone();
+
+        for shared in &self.shared_swift {
+            swift += &shared.code;
+            swift += "\n";
+        }
+
+        swift
+    }
+}
+
+/// Swift support code, such as the `extension RustMap where K == .., V == ..` of a
+/// `HashMap<K, V>`, that every bridge module that uses the type generates.
+///
+/// The extensions declare the same members, so when the Swift code of several bridge modules gets
+/// compiled together only one `code` for each `key` should be emitted.
+pub struct SharedSwiftCode {
+    /// The extended Swift type, such as `RustMap<RustString, UInt64>`.
+    pub key: String,
+    /// The code, with the module's default Swift access level applied.
+    pub code: String,
 }
 
 /// Configuration for how we will generate our Swift code.
+///
+/// Settings other than `crate_feature_lookup` are private and set with the `with_*` methods, so
+/// that adding a setting doesn't break code that creates a `CodegenConfig`.
+#[non_exhaustive]
 pub struct CodegenConfig {
     /// Look up whether or not a feature is enabled for the crate that holds the bridge module.
     /// This helps us decide whether or not to generate code for parts of the module
     /// that are annotated with `#[cfg(feature = "some-feature")]`
     pub crate_feature_lookup: Box<dyn Fn(&str) -> bool>,
+    /// Look up whether or not a `#[cfg(some_cfg)]` or `#[cfg(some_cfg = "value")]` is set for
+    /// the target that the crate that holds the bridge module is being compiled for.
+    /// This helps us skip generating code for functions and types that won't be compiled, such
+    /// as those annotated with `#[cfg(target_os = "ios")]` when building for macOS.
+    pub(crate) crate_cfg_lookup: CfgLookup,
+    /// The Swift access level, such as `"internal"`, of generated declarations that don't have
+    /// a `#[swift_bridge(swift_access = "...")]` attribute.
+    /// `None` makes them `public`.
+    pub(crate) default_swift_access: Option<String>,
+}
+
+/// Given a cfg name such as `target_os` and an optional value such as `"ios"`, returns whether or
+/// not the cfg is set.
+pub type CfgLookup = Box<dyn Fn(&str, Option<&str>) -> bool>;
+
+/// No features or cfgs are enabled and generated declarations are `public`.
+impl Default for CodegenConfig {
+    fn default() -> Self {
+        CodegenConfig::new(Box::new(|_| false))
+    }
 }
 
-#[cfg(test)]
 impl CodegenConfig {
-    pub(crate) fn no_features_enabled() -> Self {
+    /// Look up whether features are enabled with the `crate_feature_lookup`. No cfgs are enabled
+    /// and generated declarations are `public`.
+    pub fn new(crate_feature_lookup: Box<dyn Fn(&str) -> bool>) -> Self {
         CodegenConfig {
-            crate_feature_lookup: Box::new(|_| false),
+            crate_feature_lookup,
+            crate_cfg_lookup: Box::new(|_, _| false),
+            default_swift_access: None,
         }
     }
+
+    /// Look up whether cfgs such as `#[cfg(target_os = "ios")]` are set with the
+    /// `crate_cfg_lookup`.
+    pub fn with_cfg_lookup(mut self, crate_cfg_lookup: CfgLookup) -> Self {
+        self.crate_cfg_lookup = crate_cfg_lookup;
+        self
+    }
+
+    /// Give generated declarations that don't have a `#[swift_bridge(swift_access = "...")]`
+    /// attribute this Swift access level, such as `"internal"`. `None` makes them `public`.
+    pub fn with_default_swift_access(mut self, default_swift_access: Option<String>) -> Self {
+        self.default_swift_access = default_swift_access;
+        self
+    }
+}
+
+#[cfg(test)]
+impl CodegenConfig {
+    pub(crate) fn no_features_enabled() -> Self {
+        CodegenConfig::default()
+    }
 }
 
 impl SwiftBridgeModule {
     /// Generate the corresponding Swift code and C header for a bridge module.
     pub fn generate_swift_code_and_c_header(&self, config: CodegenConfig) -> SwiftCodeAndCHeader {
         SwiftCodeAndCHeader {
-            swift: self.generate_swift(&config),
+            swift: self.generate_swift_without_shared_code(&config),
             c_header: self.generate_c_header(&config),
+            shared_swift: self.shared_swift_code(&config),
         }
     }
 
@@ -46,16 +130,13 @@ impl SwiftBridgeModule {
     /// in the final binary.
     /// If not, when we won't generate any C or Swift code for it.
     fn module_will_be_compiled(&self, config: &CodegenConfig) -> bool {
-        for cfg_attr in &self.cfg_attrs {
-            match cfg_attr {
-                CfgAttr::Feature(feature_name) => {
-                    if !(config.crate_feature_lookup)(&feature_name.value()) {
-                        return false;
-                    }
-                }
-            }
-        }
-
-        true
+        cfg_attrs_are_active(&self.cfg_attrs, config)
     }
 }
+
+/// Whether or not an item's conditional compilation flags will lead it to being included in the
+/// final binary.
+/// If not, then we won't generate any C or Swift code for it.
+fn cfg_attrs_are_active(cfg_attrs: &[CfgAttr], config: &CodegenConfig) -> bool {
+    cfg_attrs.iter().all(|cfg_attr| cfg_attr.is_active(config))
+}
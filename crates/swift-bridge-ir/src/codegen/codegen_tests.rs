@@ -27,30 +27,56 @@ use crate::test_utils::{
     assert_trimmed_generated_equals_trimmed_expected, parse_ok,
 };
 
+mod actor_codegen_tests;
 mod already_declared_attribute_codegen_tests;
+mod arc_codegen_tests;
 mod argument_label_codegen_tests;
 mod async_function_codegen_tests;
+mod async_sequence_codegen_tests;
+mod boxed_fn_codegen_tests;
 mod boxed_fnonce_codegen_tests;
+mod built_in_array_codegen_tests;
 mod built_in_tuple_codegen_tests;
 mod c_header_declaration_order_codegen_tests;
 mod conditional_compilation_codegen_tests;
+mod core_graphics_codegen_tests;
+mod cow_str_codegen_tests;
+mod decimal_codegen_tests;
 mod derive_attribute_codegen_tests;
 mod derive_struct_attribute_codegen_tests;
+mod dispatch_queue_codegen_tests;
+mod doc_comment_codegen_tests;
 mod extern_rust_function_opaque_rust_type_argument_codegen_tests;
 mod extern_rust_function_opaque_rust_type_return_codegen_tests;
 mod extern_rust_method_swift_class_placement_codegen_tests;
 mod extern_swift_function_opaque_swift_type_return_codegen_tests;
 mod function_attribute_codegen_tests;
 mod generic_opaque_rust_type_codegen_tests;
+mod hash_map_codegen_tests;
+mod hash_set_codegen_tests;
+mod int128_codegen_tests;
+mod main_actor_codegen_tests;
+mod non_zero_codegen_tests;
+mod ns_error_codegen_tests;
+mod observable_codegen_tests;
 mod opaque_rust_type_codegen_tests;
 mod opaque_swift_type_codegen_tests;
 mod option_codegen_tests;
+mod path_codegen_tests;
+mod pointer_codegen_tests;
+mod publisher_codegen_tests;
 mod result_codegen_tests;
 mod return_into_attribute_codegen_tests;
 mod single_representation_type_elision_codegen_tests;
+mod slice_codegen_tests;
 mod string_codegen_tests;
+mod swift_protocol_codegen_tests;
+mod time_codegen_tests;
+mod trait_codegen_tests;
 mod transparent_enum_codegen_tests;
 mod transparent_struct_codegen_tests;
+mod url_codegen_tests;
+mod uuid_codegen_tests;
 mod vec_codegen_tests;
 
 struct CodegenTest {
@@ -69,6 +95,12 @@ struct BridgeModule {
     /// A mock representation of the features that are enabled for the crate that contains the
     /// bridge module.
     pub enabled_crate_features: Vec<&'static str>,
+    /// A mock representation of the `#[cfg(...)]`s that are set for the target that the crate
+    /// that contains the bridge module is being compiled for, such as
+    /// `("target_os", Some("ios"))` or `("debug_assertions", None)`.
+    pub enabled_cfgs: Vec<(&'static str, Option<&'static str>)>,
+    /// The access level that generated Swift declarations default to.
+    pub default_swift_access: Option<&'static str>,
 }
 
 impl From<TokenStream> for BridgeModule {
@@ -76,6 +108,8 @@ impl From<TokenStream> for BridgeModule {
         BridgeModule {
             tokens,
             enabled_crate_features: vec![],
+            enabled_cfgs: vec![],
+            default_swift_access: None,
         }
     }
 }
@@ -169,9 +203,16 @@ impl CodegenTest {
             .collect();
         let lookup = move |feature: &str| enabled_crate_features.contains(feature);
         let crate_feature_lookup = Box::new(lookup);
-        let codegen_config = CodegenConfig {
-            crate_feature_lookup,
-        };
+        let enabled_cfgs: HashSet<(&'static str, Option<&'static str>)> =
+            self.bridge_module.enabled_cfgs.into_iter().collect();
+        let crate_cfg_lookup = Box::new(move |cfg_name: &str, cfg_value: Option<&str>| {
+            enabled_cfgs
+                .iter()
+                .any(|(name, value)| *name == cfg_name && *value == cfg_value)
+        });
+        let codegen_config = CodegenConfig::new(crate_feature_lookup)
+            .with_cfg_lookup(crate_cfg_lookup)
+            .with_default_swift_access(self.bridge_module.default_swift_access.map(str::to_string));
 
         let swift = module.generate_swift(&codegen_config);
         match self.expected_swift_code {
@@ -0,0 +1,145 @@
+//! See also: crates/swift-integration-tests/src/actor.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that Rust awaits the methods of a Swift actor, even the ones that aren't `async`, and
+/// that the actor's Rust type is `Send + Sync`.
+mod extern_swift_actor_methods {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(actor)]
+                    type Counter;
+
+                    #[swift_bridge(init)]
+                    fn new(start: u32) -> Counter;
+
+                    fn increment(&self, by: u32) -> u32;
+                    async fn reset(&self);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn increment(&self, by: u32) -> impl std::future::Future<Output = u32> {
+                    extern "C" fn on_complete(completion: *mut std::ffi::c_void, val: u32) {
+                        unsafe { swift_bridge::async_support::SwiftFuture::<u32>::complete(completion, val) }
+                    }
+
+                    let (future, completion) = swift_bridge::async_support::SwiftFuture::<u32>::new();
+                    unsafe { __swift_bridge__Counter_increment(completion, on_complete, swift_bridge::PointerToSwiftType(self.0), by) };
+                    future
+                }
+            },
+            quote! {
+                pub fn reset(&self) -> impl std::future::Future<Output = ()> {
+                    extern "C" fn on_complete(completion: *mut std::ffi::c_void) {
+                        unsafe { swift_bridge::async_support::SwiftFuture::<()>::complete(completion, ()) }
+                    }
+
+                    let (future, completion) = swift_bridge::async_support::SwiftFuture::<()>::new();
+                    unsafe { __swift_bridge__Counter_reset(completion, on_complete, swift_bridge::PointerToSwiftType(self.0)) };
+                    future
+                }
+            },
+            quote! {
+                unsafe impl Send for Counter {}
+                unsafe impl Sync for Counter {}
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_cdecl("__swift_bridge__$Counter$increment")
+func __swift_bridge__Counter_increment (_ completion: UnsafeMutableRawPointer, _ onComplete: @escaping @convention(c) (UnsafeMutableRawPointer, UInt32) -> Void, _ this: UnsafeMutableRawPointer, _ by: UInt32) {
+    let instance = Unmanaged<Counter>.fromOpaque(this).takeUnretainedValue()
+    Task {
+        let val = await instance.increment(by: by)
+        onComplete(completion, val)
+    }
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$Counter$new")
+func __swift_bridge__Counter_new (_ start: UInt32) -> UnsafeMutableRawPointer {
+    Unmanaged.passRetained(Counter(start: start)).toOpaque()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_swift_actor_methods() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that Swift types that aren't actors don't get `Send` and `Sync` implementations, and
+/// that their methods stay synchronous.
+mod extern_swift_non_actor_methods {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    type Counter;
+
+                    fn increment(&self, by: u32) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsManyAndDoesNotContainMany {
+            contains: vec![quote! {
+                pub fn increment(&self, by: u32) -> u32 {
+                    unsafe { __swift_bridge__Counter_increment(swift_bridge::PointerToSwiftType(self.0), by) }
+                }
+            }],
+            does_not_contain: vec![quote! {
+                unsafe impl Send for Counter {}
+            }],
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::SkipTest
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_swift_non_actor_methods() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -321,3 +321,101 @@ mod already_declared_rust_copy_type_methods {
         .test();
     }
 }
+
+/// Verify that we do not re-declare an already declared opaque Swift type, and that we still
+/// generate its methods.
+mod extern_swift_already_declared_type {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(already_declared)]
+                    type SomeType;
+
+                    fn some_method(&self) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsManyAndDoesNotContainMany {
+            contains: vec![quote! {
+                use super::SomeType;
+
+                impl SomeType {
+                    pub fn some_method(&self) -> u8 {
+                        unsafe { __swift_bridge__SomeType_some_method(swift_bridge::PointerToSwiftType(self.0)) }
+                    }
+                }
+            }],
+            does_not_contain: vec![
+                quote! { pub struct SomeType },
+                quote! { impl Drop for SomeType },
+                quote! { fn __swift_bridge__SomeType__free },
+            ],
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$SomeType$some_method")
+func __swift_bridge__SomeType_some_method (_ this: UnsafeMutableRawPointer) -> UInt8 {
+    Unmanaged<SomeType>.fromOpaque(this).takeUnretainedValue().some_method()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_already_declared_type() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a second function for releasing an already declared opaque
+/// Swift type, since that would be a duplicate symbol.
+mod extern_swift_already_declared_type_does_not_redeclare_free {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(already_declared)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim(
+            r#"
+__swift_bridge__$SomeType$_free
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_already_declared_type_does_not_redeclare_free() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,218 @@
+//! See also: crates/swift-integration-tests/src/arc.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can return an Arc<T> from Rust and that we generate the Swift class that
+/// releases the Rust reference count when it is deinitialized.
+mod extern_rust_fn_return_arc {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function() -> Arc<SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() -> *const super::SomeType {
+                    std::sync::Arc::into_raw(super::some_function())
+                }
+            },
+            quote! {
+                const _: () = {
+                    #[export_name = "__swift_bridge__$Arc$SomeType$_retain"]
+                    #[doc(hidden)]
+                    pub extern "C" fn _retain(arc: *const super::SomeType) -> *const super::SomeType {
+                        unsafe { std::sync::Arc::increment_strong_count(arc) };
+                        arc
+                    }
+
+                    #[export_name = "__swift_bridge__$Arc$SomeType$_free"]
+                    #[doc(hidden)]
+                    pub extern "C" fn _free(arc: *const super::SomeType) {
+                        let _ = unsafe { std::sync::Arc::from_raw(arc) };
+                    }
+                };
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function() -> SomeTypeArc {
+    SomeTypeArc(ptr: __swift_bridge__$some_function())
+}
+"#,
+            r#"
+public class SomeTypeArc: SomeTypeRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        __swift_bridge__$Arc$SomeType$_free(ptr)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+void* __swift_bridge__$some_function(void);
+"#,
+            r#"
+void* __swift_bridge__$Arc$SomeType$_retain(void* arc);
+void __swift_bridge__$Arc$SomeType$_free(void* arc);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_arc() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that Swift takes out a new strong reference for Rust when it passes an Arc<T> to Rust.
+mod extern_rust_fn_arc_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function(arg: Arc<SomeType>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *const super::SomeType) {
+                super::some_function(unsafe { std::sync::Arc::from_raw(arg) })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: SomeTypeArc) {
+    __swift_bridge__$some_function({ let val = arg; return withExtendedLifetime(val) { __swift_bridge__$Arc$SomeType$_retain(val.ptr)! } }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arc_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that Rust hands its strong reference over to Swift when it passes an Arc<T> to an
+/// extern "Swift" function.
+mod extern_swift_fn_arc_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                }
+
+                extern "Swift" {
+                    fn some_function(arg: Arc<SomeType>) -> Arc<SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: std::sync::Arc<super::SomeType>) -> std::sync::Arc<super::SomeType> {
+                    unsafe {
+                        std::sync::Arc::from_raw(unsafe {
+                            __swift_bridge__some_function(std::sync::Arc::into_raw(arg))
+                        })
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: *const super::SomeType) -> *const super::SomeType;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+    { let val = some_function(arg: SomeTypeArc(ptr: arg)); return withExtendedLifetime(val) { __swift_bridge__$Arc$SomeType$_retain(val.ptr)! } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$Arc$SomeType$_retain(void* arc);
+void __swift_bridge__$Arc$SomeType$_free(void* arc);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_arc_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -115,3 +115,84 @@ void __swift_bridge__$some_function(int32_t parameter_name1, uint32_t parameter_
         .test();
     }
 }
+
+/// Verify that a `#[swift_bridge(label = "...")]` attribute that matches the parameter's name
+/// generates a regular labeled Swift parameter.
+mod argument_label_same_as_parameter_name {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn transfer(
+                        #[swift_bridge(label = "from")] from: u32,
+                        #[swift_bridge(label = "to")] to: u32,
+                    );
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func transfer(from: UInt32, to: UInt32) {
+    __swift_bridge__$transfer(from, to)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn argument_label_same_as_parameter_name() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we use the `#[swift_bridge(label = "...")]` attributes when Rust calls an
+/// extern "Swift" function, including `_` for an unlabeled argument.
+mod extern_swift_argument_label {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    fn transfer(
+                        #[swift_bridge(label = "_")] amount: u32,
+                        #[swift_bridge(label = "to")] destination: u32,
+                        from: u32,
+                    );
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    transfer(amount, to: destination, from: from)
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_argument_label() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -267,6 +267,94 @@ void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge_
     }
 }
 
+/// Verify that we generate the correct code for extern "Rust" async functions that returns an
+/// Option<T> where T is a primitive.
+mod extern_rust_async_function_returns_option_primitive {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    async fn some_function() -> Option<u64>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+             pub extern "C" fn __swift_bridge__some_function(
+                callback_wrapper: *mut std::ffi::c_void,
+                callback: extern "C" fn(*mut std::ffi::c_void, swift_bridge::option::OptionU64) -> (),
+            ) {
+                let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
+                let fut = super::some_function();
+                let task = async move {
+                    let val = swift_bridge::option::OptionU64::from_option(fut.await);
+
+                    let callback_wrapper = callback_wrapper;
+                    let callback_wrapper = callback_wrapper.0;
+
+                    (callback)(callback_wrapper, val)
+                };
+                swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(Box::pin(task))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function() async -> Optional<UInt64> {
+    func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: __private__OptionU64) {
+        let wrapper = Unmanaged<CbWrapper$some_function>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+        wrapper.cb(.success(rustFnRetVal.intoSwiftRepr()))
+    }
+
+    return await withCheckedContinuation({ (continuation: CheckedContinuation<Optional<UInt64>, Never>) in
+        let callback = { rustFnRetVal in
+            continuation.resume(with: rustFnRetVal)
+        }
+
+        let wrapper = CbWrapper$some_function(cb: callback)
+        let wrapperPtr = Unmanaged.passRetained(wrapper).toOpaque()
+
+        __swift_bridge__$some_function(wrapperPtr, onComplete)
+    })
+}
+class CbWrapper$some_function {
+    var cb: (Result<Optional<UInt64>, Never>) -> ()
+
+    public init(cb: @escaping (Result<Optional<UInt64>, Never>) -> ()) {
+        self.cb = cb
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge__$some_function$async(void* callback_wrapper, struct __private__OptionU64 ret));
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_async_function_returns_option_primitive() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we generate the correct code for extern "Rust" async functions that returns a u8.
 mod extern_rust_async_function_returns_string {
     use super::*;
@@ -354,6 +442,152 @@ void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge_
     }
 }
 
+/// Verify that we generate the correct code for extern "Rust" async functions that return an
+/// opaque Rust type.
+mod extern_rust_async_function_returns_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    async fn some_function() -> SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+             pub extern "C" fn __swift_bridge__some_function(
+                callback_wrapper: *mut std::ffi::c_void,
+                callback: extern "C" fn(*mut std::ffi::c_void, *mut super::SomeType) -> (),
+            ) {
+                let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
+                let fut = super::some_function();
+                let task = async move {
+                    let val = swift_bridge::leak_check::box_into_raw({
+                        let val: super::SomeType = fut.await;
+                        val
+                    });
+
+                    let callback_wrapper = callback_wrapper;
+                    let callback_wrapper = callback_wrapper.0;
+
+                    (callback)(callback_wrapper, val)
+                };
+                swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(Box::pin(task))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() async -> SomeType {
+    func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: UnsafeMutableRawPointer?) {
+        let wrapper = Unmanaged<CbWrapper$some_function>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+        wrapper.cb(.success(SomeType(ptr: rustFnRetVal!)))
+    }
+
+    return await withCheckedContinuation({ (continuation: CheckedContinuation<SomeType, Never>) in
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge__$some_function$async(void* callback_wrapper, void* ret));
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_async_function_returns_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate the correct code for extern "Rust" async functions that return an
+/// Option<String> or a Vec<T>.
+mod extern_rust_async_function_returns_option_string_and_vec {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    async fn some_function() -> Option<String>;
+                    async fn another_function() -> Vec<u8>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                let val = swift_bridge::string::RustString::option_box_into_raw(fut.await);
+            },
+            quote! {
+                callback: extern "C" fn(*mut std::ffi::c_void, *mut Vec<u8>) -> (),
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function() async -> Optional<RustString> {
+    func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: UnsafeMutableRawPointer?) {
+        let wrapper = Unmanaged<CbWrapper$some_function>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+        wrapper.cb(.success({ let val = rustFnRetVal; if val != nil { return RustString(ptr: val!) } else { return nil } }()))
+    }
+"#,
+            r#"
+public func another_function() async -> RustVec<UInt8> {
+    func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: UnsafeMutableRawPointer?) {
+        let wrapper = Unmanaged<CbWrapper$another_function>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+        wrapper.cb(.success(RustVec(ptr: rustFnRetVal!)))
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge__$some_function$async(void* callback_wrapper, void* ret));
+    "#,
+            r#"
+void __swift_bridge__$another_function(void* callback_wrapper, void __swift_bridge__$another_function$async(void* callback_wrapper, void* ret));
+    "#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_async_function_returns_option_string_and_vec() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we generate the correct code for extern "Rust" async functions that returns a struct.
 mod extern_rust_async_function_returns_struct {
     use super::*;
@@ -445,6 +679,181 @@ void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge_
     }
 }
 
+/// Verify that we generate the correct code for extern "Rust" async functions that returns an
+/// enum with data.
+mod extern_rust_async_function_returns_enum_with_data {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum SomeEnum {
+                    Variant1 { field: f64 },
+                    Variant2,
+                }
+
+                extern "Rust" {
+                    async fn some_function() -> SomeEnum;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+             pub extern "C" fn __swift_bridge__some_function(
+                callback_wrapper: *mut std::ffi::c_void,
+                callback: extern "C" fn(*mut std::ffi::c_void, __swift_bridge__SomeEnum) -> (),
+            ) {
+                let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
+                let fut = super::some_function();
+                let task = async move {
+                    let val = fut.await.into_ffi_repr();
+
+                    let callback_wrapper = callback_wrapper;
+                    let callback_wrapper = callback_wrapper.0;
+
+                    (callback)(callback_wrapper, val)
+                };
+                swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(Box::pin(task))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function() async -> SomeEnum {
+    func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: __swift_bridge__$SomeEnum) {
+        let wrapper = Unmanaged<CbWrapper$some_function>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+        wrapper.cb(.success(rustFnRetVal.intoSwiftRepr()))
+    }
+
+    return await withCheckedContinuation({ (continuation: CheckedContinuation<SomeEnum, Never>) in
+        let callback = { rustFnRetVal in
+            continuation.resume(with: rustFnRetVal)
+        }
+
+        let wrapper = CbWrapper$some_function(cb: callback)
+        let wrapperPtr = Unmanaged.passRetained(wrapper).toOpaque()
+
+        __swift_bridge__$some_function(wrapperPtr, onComplete)
+    })
+}
+class CbWrapper$some_function {
+    var cb: (Result<SomeEnum, Never>) -> ()
+
+    public init(cb: @escaping (Result<SomeEnum, Never>) -> ()) {
+        self.cb = cb
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge__$some_function$async(void* callback_wrapper, struct __swift_bridge__$SomeEnum ret));
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_async_function_returns_enum_with_data() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate the correct code for extern "Rust" async functions that returns a
+/// tuple.
+mod extern_rust_async_function_returns_tuple {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    async fn some_function() -> (i32, String);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__tuple_I32String(i32, *mut swift_bridge::string::RustString);
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(
+                    callback_wrapper: *mut std::ffi::c_void,
+                    callback: extern "C" fn(*mut std::ffi::c_void, __swift_bridge__tuple_I32String) -> (),
+                ) {
+                    let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
+                    let fut = super::some_function();
+                    let task = async move {
+                        let val = {
+                            let val = fut.await;
+                            __swift_bridge__tuple_I32String(val.0, swift_bridge::string::RustString(val.1).box_into_raw())
+                        };
+
+                        let callback_wrapper = callback_wrapper;
+                        let callback_wrapper = callback_wrapper.0;
+
+                        (callback)(callback_wrapper, val)
+                    };
+                    swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(Box::pin(task))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function() async -> (Int32, RustString) {
+    func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: __swift_bridge__$tuple$I32String) {
+        let wrapper = Unmanaged<CbWrapper$some_function>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+        wrapper.cb(.success({ let val = rustFnRetVal; return (val._0, RustString(ptr: val._1!)); }()))
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge__$some_function$async(void* callback_wrapper, struct __swift_bridge__$tuple$I32String ret));
+    "#,
+            r#"
+typedef struct __swift_bridge__$tuple$I32String { int32_t _0; void* _1; } __swift_bridge__$tuple$I32String;
+    "#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_async_function_returns_tuple() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we generate the correct code for extern "Rust" async method.
 mod extern_rust_async_method {
     use super::*;
@@ -468,6 +877,8 @@ mod extern_rust_async_method {
                 callback: extern "C" fn(*mut std::ffi::c_void) -> (),
                 this: *mut super::SomeType
             ) {
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
                 let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
                 let fut = (unsafe {&*this}).some_method();
                 let task = async move {
@@ -566,19 +977,19 @@ mod extern_rust_async_function_returns_result_opaque {
                     Ok(ok) => {
                         swift_bridge::result::ResultPtrAndPtr {
                             is_ok: true,
-                            ok_or_err: Box::into_raw(Box::new({
+                            ok_or_err: swift_bridge::leak_check::box_into_raw({
                                 let val: super::OkType = ok;
                                 val
-                            })) as *mut super::OkType as *mut std::ffi::c_void
+                            }) as *mut std::ffi::c_void
                         }
                     }
                     Err(err) => {
                         swift_bridge::result::ResultPtrAndPtr {
                             is_ok: false,
-                            ok_or_err: Box::into_raw(Box::new({
+                            ok_or_err: swift_bridge::leak_check::box_into_raw({
                                 let val: super::ErrorType = err;
                                 val
-                            })) as *mut super::ErrorType as *mut std::ffi::c_void
+                            }) as *mut std::ffi::c_void
                         }
                     }
                 };
@@ -782,10 +1193,10 @@ mod extern_rust_async_function_returns_result_opaque_rust_transparent_enum {
                 let fut = super::some_function();
                 let task = async move {
                 let val = match fut.await {
-                    Ok(ok) => ResultSomeTypeAndErrEnum::Ok(Box::into_raw(Box::new({
+                    Ok(ok) => ResultSomeTypeAndErrEnum::Ok(swift_bridge::leak_check::box_into_raw({
                         let val: super::SomeType = ok;
                         val
-                    })) as *mut super::SomeType),
+                    })),
                     Err(err) => ResultSomeTypeAndErrEnum::Err(err.into_ffi_repr()),
                 };
                     let callback_wrapper = callback_wrapper;
@@ -977,10 +1388,10 @@ mod extern_rust_async_function_returns_result_null_opaque {
                 let task = async move {
                     let val = match fut.await {
                         Ok(ok) => std::ptr::null_mut(),
-                        Err(err) => Box::into_raw(Box::new({
+                        Err(err) => swift_bridge::leak_check::box_into_raw({
                             let val: super::ErrorType = err;
                             val
-                        })) as *mut super::ErrorType
+                        })
                     };
                     let callback_wrapper = callback_wrapper;
                     let callback_wrapper = callback_wrapper.0;
@@ -1049,3 +1460,217 @@ void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge_
         .test();
     }
 }
+
+/// Verify that we generate the correct code for extern "Swift" async functions.
+/// Rust gets a function that returns a future that resolves once the Swift function completes.
+mod extern_swift_async_function {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    async fn some_function(arg: u8) -> String;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: u8) -> impl std::future::Future<Output = String> {
+                    extern "C" fn on_complete(completion: *mut std::ffi::c_void, val: *mut swift_bridge::string::RustString) {
+                        let val = unsafe { Box::from_raw(val).0 };
+                        unsafe { swift_bridge::async_support::SwiftFuture::<String>::complete(completion, val) }
+                    }
+
+                    let (future, completion) = swift_bridge::async_support::SwiftFuture::<String>::new();
+                    unsafe { __swift_bridge__some_function(completion, on_complete, arg) };
+                    future
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    completion: *mut std::ffi::c_void,
+                    on_complete: extern "C" fn(*mut std::ffi::c_void, *mut swift_bridge::string::RustString),
+                    arg: u8
+                );
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ completion: UnsafeMutableRawPointer, _ onComplete: @escaping @convention(c) (UnsafeMutableRawPointer, UnsafeMutableRawPointer) -> Void, _ arg: UInt8) {
+    Task {
+        let val = await some_function(arg: arg)
+        onComplete(completion, { let rustString = val.intoRustString(); rustString.isOwned = false; return rustString.ptr }())
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim("")
+    }
+
+    #[test]
+    fn extern_swift_async_function() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an async extern "Swift" function that takes a `String` hands ownership of the
+/// string to Swift, since the Swift `Task` keeps using it after the Rust call returns.
+/// Borrowed arguments such as `&str` get rejected while parsing.
+mod extern_swift_async_function_string_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    async fn some_function(arg: String);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: String) -> impl std::future::Future<Output = ()> {
+                    extern "C" fn on_complete(completion: *mut std::ffi::c_void) {
+                        unsafe { swift_bridge::async_support::SwiftFuture::<()>::complete(completion, ()) }
+                    }
+
+                    let (future, completion) = swift_bridge::async_support::SwiftFuture::<()>::new();
+                    unsafe { __swift_bridge__some_function(completion, on_complete, swift_bridge::string::RustString(arg).box_into_raw()) };
+                    future
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    completion: *mut std::ffi::c_void,
+                    on_complete: extern "C" fn(*mut std::ffi::c_void),
+                    arg: *mut swift_bridge::string::RustString
+                );
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ completion: UnsafeMutableRawPointer, _ onComplete: @escaping @convention(c) (UnsafeMutableRawPointer) -> Void, _ arg: UnsafeMutableRawPointer) {
+    Task {
+        await some_function(arg: RustString(ptr: arg))
+        onComplete(completion)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_swift_async_function_string_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we retain the Swift instance before calling an async Swift method, since Rust can
+/// drop its handle to the instance before the method completes.
+mod extern_swift_async_method_no_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    type SomeType;
+
+                    async fn some_method(&self);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                impl SomeType {
+                    pub fn some_method(&self) -> impl std::future::Future<Output = ()> {
+                        extern "C" fn on_complete(completion: *mut std::ffi::c_void) {
+                            unsafe { swift_bridge::async_support::SwiftFuture::<()>::complete(completion, ()) }
+                        }
+
+                        let (future, completion) = swift_bridge::async_support::SwiftFuture::<()>::new();
+                        unsafe { __swift_bridge__SomeType_some_method(completion, on_complete, swift_bridge::PointerToSwiftType(self.0)) };
+                        future
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$SomeType$some_method"]
+                fn __swift_bridge__SomeType_some_method(
+                    completion: *mut std::ffi::c_void,
+                    on_complete: extern "C" fn(*mut std::ffi::c_void),
+                    this: swift_bridge::PointerToSwiftType
+                );
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$SomeType$some_method")
+func __swift_bridge__SomeType_some_method (_ completion: UnsafeMutableRawPointer, _ onComplete: @escaping @convention(c) (UnsafeMutableRawPointer) -> Void, _ this: UnsafeMutableRawPointer) {
+    let instance = Unmanaged<SomeType>.fromOpaque(this).takeUnretainedValue()
+    Task {
+        await instance.some_method()
+        onComplete(completion)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_swift_async_method_no_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
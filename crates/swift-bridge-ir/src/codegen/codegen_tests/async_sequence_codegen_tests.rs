@@ -0,0 +1,211 @@
+//! See also: crates/swift-integration-tests/src/async_sequence.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a function that returns a stream gets handed to Swift as an `AsyncStream`.
+mod extern_rust_fn_returns_stream {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn ticks(count: u32) -> impl Stream<Item = u32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$ticks"]
+            pub extern "C" fn __swift_bridge__ticks(
+                on_item: extern "C" fn(*mut std::ffi::c_void, u32),
+                on_complete: extern "C" fn(*mut std::ffi::c_void),
+                count: u32
+            ) -> *mut swift_bridge::stream_support::RustStream {
+                swift_bridge::stream_support::RustStream::new(
+                    super::ticks(count),
+                    move |subscriber, item| {
+                        on_item(subscriber, item);
+                        true
+                    },
+                    move |subscriber| on_complete(subscriber),
+                    |task| swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(task),
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+public func ticks(_ count: UInt32) -> AsyncStream<UInt32> {
+    func onItem(subscriberPtr: UnsafeMutableRawPointer?, item: UInt32) {
+        __private__RustStreamReceiver<UInt32, Never>.fromOpaque(subscriberPtr!).receive(.success(item))
+    }
+    func onComplete(subscriberPtr: UnsafeMutableRawPointer?) {
+        __private__RustStreamReceiver<UInt32, Never>.fromOpaque(subscriberPtr!).receive(.success(nil))
+    }
+
+    return __private__RustStreamIterator<UInt32, Never>(stream: __swift_bridge__$ticks(onItem, onComplete, count)).asyncStream()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            "void* __swift_bridge__$ticks(void __swift_bridge__$ticks$on_item(void* subscriber, uint32_t item), void __swift_bridge__$ticks$on_complete(void* subscriber), uint32_t count);",
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_returns_stream() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a function that returns a stream of `Result`s gets handed to Swift as an
+/// `AsyncThrowingStream` that throws the first error.
+mod extern_rust_fn_returns_stream_of_results {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type ParseError;
+
+                    fn lines() -> impl Stream<Item = Result<String, ParseError>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__lines(
+                on_item: extern "C" fn(*mut std::ffi::c_void, *mut swift_bridge::string::RustString),
+                on_error: extern "C" fn(*mut std::ffi::c_void, *mut super::ParseError),
+                on_complete: extern "C" fn(*mut std::ffi::c_void),
+            ) -> *mut swift_bridge::stream_support::RustStream
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+public func lines() -> AsyncThrowingStream<RustString, Error> {
+    func onItem(subscriberPtr: UnsafeMutableRawPointer?, item: UnsafeMutableRawPointer?) {
+        __private__RustStreamReceiver<RustString, Error>.fromOpaque(subscriberPtr!).receive(.success(RustString(ptr: item!)))
+    }
+    func onError(subscriberPtr: UnsafeMutableRawPointer?, error: UnsafeMutableRawPointer?) {
+        __private__RustStreamReceiver<RustString, Error>.fromOpaque(subscriberPtr!).receive(.failure(ParseError(ptr: error!)))
+    }
+    func onComplete(subscriberPtr: UnsafeMutableRawPointer?) {
+        __private__RustStreamReceiver<RustString, Error>.fromOpaque(subscriberPtr!).receive(.success(nil))
+    }
+
+    return __private__RustStreamIterator<RustString, Error>(stream: __swift_bridge__$lines(onItem, onError, onComplete)).asyncThrowingStream()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            "void* __swift_bridge__$lines(void __swift_bridge__$lines$on_item(void* subscriber, void* item), void __swift_bridge__$lines$on_error(void* subscriber, void* error), void __swift_bridge__$lines$on_complete(void* subscriber));",
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_returns_stream_of_results() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a method that returns a stream gets handed to Swift as a method that returns an
+/// `AsyncStream`.
+mod extern_rust_method_returns_stream {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type EventSource;
+
+                    fn events(&self, topic: &str) -> impl Stream<Item = u8>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            swift_bridge::stream_support::RustStream::new(
+                (unsafe { &*this }).events(topic.to_str()),
+                move |subscriber, item| {
+                    on_item(subscriber, item);
+                    true
+                },
+                move |subscriber| on_complete(subscriber),
+                |task| swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(task),
+            )
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    @available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+    public func events<GenericToRustStr: ToRustStr>(_ topic: GenericToRustStr) -> AsyncStream<UInt8> {
+        func onItem(subscriberPtr: UnsafeMutableRawPointer?, item: UInt8) {
+            __private__RustStreamReceiver<UInt8, Never>.fromOpaque(subscriberPtr!).receive(.success(item))
+        }
+        func onComplete(subscriberPtr: UnsafeMutableRawPointer?) {
+            __private__RustStreamReceiver<UInt8, Never>.fromOpaque(subscriberPtr!).receive(.success(nil))
+        }
+
+        return topic.toRustStr({ topicAsRustStr in
+            __private__RustStreamIterator<UInt8, Never>(stream: __swift_bridge__$EventSource$events(onItem, onComplete, ptr, topicAsRustStr)).asyncStream()
+        })
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            "void* __swift_bridge__$EventSource$events(void __swift_bridge__$EventSource$events$on_item(void* subscriber, uint8_t item), void __swift_bridge__$EventSource$events$on_complete(void* subscriber), void* self, struct RustStr topic);",
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_returns_stream() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,190 @@
+//! Tests for passing Swift closures to Rust functions that take a `Box<dyn Fn(A, B) -> C>`.
+//!
+//! Swift retains the closure and passes Rust a pointer to it. Rust wraps the pointer in a type
+//! that calls the closure through an exported Swift function and releases the closure when it
+//! gets dropped.
+//!
+//! See also: crates/swift-integration-tests/src/boxed_functions.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can pass a Swift closure to a Rust function that takes a
+/// `Box<dyn Fn(A) -> B>`.
+mod extern_rust_fn_takes_boxed_fn {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(callback: Box<dyn Fn(u8) -> u16>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(callback: *mut std::ffi::c_void) {
+                    super::some_function({
+                        let callback = __swift_bridge__some_function_param0(callback);
+                        Box::new(move |arg0: u8| callback.call(arg0))
+                    })
+                }
+            },
+            quote! {
+                #[doc(hidden)]
+                #[allow(non_camel_case_types)]
+                pub struct __swift_bridge__some_function_param0(*mut std::ffi::c_void);
+                impl __swift_bridge__some_function_param0 {
+                    fn call(&self, arg0: u8) -> u16 {
+                        unsafe { __swift_bridge__some_function_param0__call(self.0, arg0) }
+                    }
+                }
+                impl Drop for __swift_bridge__some_function_param0 {
+                    fn drop(&mut self) {
+                        unsafe { __swift_bridge__some_function_param0__free(self.0) }
+                    }
+                }
+                extern "C" {
+                    #[link_name = "__swift_bridge__$some_function$param0"]
+                    fn __swift_bridge__some_function_param0__call(
+                        callback: *mut std::ffi::c_void,
+                        arg0: u8
+                    ) -> u16;
+                    #[link_name = "__swift_bridge__$some_function$_free$param0"]
+                    fn __swift_bridge__some_function_param0__free(callback: *mut std::ffi::c_void);
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ callback: @escaping (UInt8) -> UInt16) {
+    __swift_bridge__$some_function(Unmanaged.passRetained(__private__SwiftClosure<(UInt8) -> UInt16>(callback)).toOpaque())
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$some_function$param0")
+func __swift_bridge__some_function_param0 (_ callback: UnsafeMutableRawPointer, _ arg0: UInt8) -> UInt16 {
+    let ret = Unmanaged<__private__SwiftClosure<(UInt8) -> UInt16>>.fromOpaque(callback).takeUnretainedValue().closure(arg0)
+    return ret
+}
+
+@_cdecl("__swift_bridge__$some_function$_free$param0")
+func __swift_bridge__some_function_param0__free (_ callback: UnsafeMutableRawPointer) {
+    let _ = Unmanaged<__private__SwiftClosure<(UInt8) -> UInt16>>.fromOpaque(callback).takeRetainedValue()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_takes_boxed_fn() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a Swift closure that takes and returns non-primitive types to a Rust
+/// method. The boxed function is an `FnOnce`, which we support in the same way as an `Fn`.
+mod extern_rust_method_takes_boxed_fnonce_with_opaque_types {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_method(&self, callback: Box<dyn FnOnce(String) -> SomeType>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__SomeType_some_method(
+                    this: *mut super::SomeType,
+                    callback: *mut std::ffi::c_void
+                ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
+                    (unsafe { &*this }).some_method({
+                        let callback = __swift_bridge__SomeType_some_method_param1(callback);
+                        Box::new(move |arg0: String| callback.call(arg0))
+                    })
+                }
+            },
+            quote! {
+                fn call(&self, arg0: String) -> super::SomeType {
+                    unsafe {
+                        *swift_bridge::leak_check::box_from_raw(unsafe {
+                            __swift_bridge__SomeType_some_method_param1__call(
+                                self.0,
+                                swift_bridge::string::RustString(arg0).box_into_raw()
+                            )
+                        })
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public func some_method(_ callback: @escaping (RustString) -> SomeType) {
+        __swift_bridge__$SomeType$some_method(ptr, Unmanaged.passRetained(__private__SwiftClosure<(RustString) -> SomeType>(callback)).toOpaque())
+    }
+"#,
+            r#"
+@_cdecl("__swift_bridge__$SomeType$some_method$param1")
+func __swift_bridge__SomeType_some_method_param1 (_ callback: UnsafeMutableRawPointer, _ arg0: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+    let ret = Unmanaged<__private__SwiftClosure<(RustString) -> SomeType>>.fromOpaque(callback).takeUnretainedValue().closure(RustString(ptr: arg0))
+    return {ret.isOwned = false; return ret.ptr;}()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$SomeType$some_method(void* self, void* callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_takes_boxed_fnonce_with_opaque_types() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
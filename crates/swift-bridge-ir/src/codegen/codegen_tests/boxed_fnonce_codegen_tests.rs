@@ -308,7 +308,7 @@ mod test_swift_takes_callback_one_opaque_rust_arg {
             quote! {
                 #[export_name = "__swift_bridge__$some_function$param0"]
                 pub extern "C" fn some_function_param0(some_function_callback: *mut Box<dyn FnOnce(super::ARustType) -> ()>, arg0: *mut super::ARustType) {
-                    unsafe { Box::from_raw(some_function_callback)(unsafe { *Box::from_raw(arg0) }) }
+                    unsafe { Box::from_raw(some_function_callback)(unsafe { *swift_bridge::leak_check::box_from_raw(arg0) }) }
                 }
 
                 #[export_name = "__swift_bridge__$some_function$_free$param0"]
@@ -411,10 +411,10 @@ mod test_swift_takes_callback_return_opaque_rust_type {
             quote! {
                 #[export_name = "__swift_bridge__$some_function$param0"]
                 pub extern "C" fn some_function_param0(some_function_callback: *mut Box<dyn FnOnce() -> super::ARustType>) -> *mut super::ARustType {
-                    Box::into_raw(Box::new({
+                    swift_bridge::leak_check::box_into_raw({
                         let val: super::ARustType = unsafe { Box::from_raw(some_function_callback)() };
                         val
-                    })) as *mut super::ARustType
+                    })
                 }
 
                 #[export_name = "__swift_bridge__$some_function$_free$param0"]
@@ -519,9 +519,9 @@ mod test_swift_takes_callback_with_result_arg {
                 pub extern "C" fn some_function_param0(some_function_callback: *mut Box<dyn FnOnce(Result<super::ARustType, super::ARustType>) -> ()>, arg0: swift_bridge::result::ResultPtrAndPtr) {
                     unsafe { Box::from_raw(some_function_callback)(
                         if arg0.is_ok {
-                            std::result::Result::Ok(unsafe { *Box::from_raw(arg0.ok_or_err as *mut super::ARustType) })
+                            std::result::Result::Ok(unsafe { *swift_bridge::leak_check::box_from_raw(arg0.ok_or_err as *mut super::ARustType) })
                         } else {
-                            std::result::Result::Err(unsafe { *Box::from_raw(arg0.ok_or_err as *mut super::ARustType) })
+                            std::result::Result::Err(unsafe { *swift_bridge::leak_check::box_from_raw(arg0.ok_or_err as *mut super::ARustType) })
                         }
                     )}
                 }
@@ -750,7 +750,7 @@ mod test_swift_takes_callback_multiple_args {
             quote! {
                 #[export_name = "__swift_bridge__$some_function$param0"]
                 pub extern "C" fn some_function_param0(some_function_callback: *mut Box<dyn FnOnce(super::ARustType, u32) -> ()>, arg0: *mut super::ARustType, arg1: u32) {
-                    unsafe { Box::from_raw(some_function_callback)(unsafe { *Box::from_raw(arg0) }, arg1) }
+                    unsafe { Box::from_raw(some_function_callback)(unsafe { *swift_bridge::leak_check::box_from_raw(arg0) }, arg1) }
                 }
 
                 #[export_name = "__swift_bridge__$some_function$_free$param0"]
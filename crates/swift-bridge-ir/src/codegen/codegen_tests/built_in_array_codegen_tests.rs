@@ -0,0 +1,139 @@
+//! See also: crates/swift-integration-tests/src/fixed_size_array.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can use a fixed size array of primitives as a Rust function arg and return type.
+mod extern_rust_fn_array_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: [u8; 3]) -> [f64; 2];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(arg: __swift_bridge__array_U8_3) -> __swift_bridge__array_F64_2 {
+                    __swift_bridge__array_F64_2(super::some_function(arg.0))
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__array_U8_3([u8; 3]);
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__array_F64_2([f64; 2]);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: (UInt8, UInt8, UInt8)) -> (Double, Double) {
+    __swift_bridge__$some_function(__swift_bridge__$array$U8$3(_0: arg))._0
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+#include <stdint.h>
+"#,
+            r#"
+struct __swift_bridge__$array$F64$2 __swift_bridge__$some_function(struct __swift_bridge__$array$U8$3 arg);
+"#,
+            r#"
+typedef struct __swift_bridge__$array$U8$3 { uint8_t _0[3]; } __swift_bridge__$array$U8$3;
+"#,
+            r#"
+typedef struct __swift_bridge__$array$F64$2 { double _0[2]; } __swift_bridge__$array$F64$2;
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_array_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can use a fixed size array of primitives as a Swift function arg and return
+/// type.
+mod extern_swift_fn_array_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: [u8; 4]) -> [u8; 4];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: [u8; 4]) -> [u8; 4] {
+                    unsafe { __swift_bridge__some_function(__swift_bridge__array_U8_4(arg)) }.0
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: __swift_bridge__array_U8_4) -> __swift_bridge__array_U8_4;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __swift_bridge__$array$U8$4) -> __swift_bridge__$array$U8$4 {
+    __swift_bridge__$array$U8$4(_0: some_function(arg: arg._0))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$array$U8$4 { uint8_t _0[4]; } __swift_bridge__$array$U8$4;
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_array_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
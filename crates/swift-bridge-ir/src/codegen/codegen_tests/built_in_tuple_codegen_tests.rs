@@ -154,11 +154,11 @@ mod extern_rust_tuple_opaque_rust_primitive {
         ExpectedRustTokens::ContainsMany(vec![
             quote! {
                 pub extern "C" fn __swift_bridge__some_function (arg1: __swift_bridge__tuple_SomeTypeU32) -> __swift_bridge__tuple_SomeTypeU32 {
-                    { let val = super::some_function({let val = arg1; (unsafe { * Box::from_raw(val.0) }, val.1)});
-                    __swift_bridge__tuple_SomeTypeU32(Box::into_raw(Box::new({
+                    { let val = super::some_function({let val = arg1; (unsafe { * swift_bridge::leak_check::box_from_raw(val.0) }, val.1)});
+                    __swift_bridge__tuple_SomeTypeU32(swift_bridge::leak_check::box_into_raw({
                         let val: super::SomeType = val.0;
                         val
-                    })) as *mut super::SomeType, val.1) }
+                    }), val.1) }
                 }
             },
             quote! {
@@ -365,11 +365,11 @@ mod extern_swift_tuple_opaque_and_string {
             quote! {
                 pub fn some_function (arg: (super::SomeType, String)) -> (super::SomeType, String) {
                     {
-                        let val = unsafe { __swift_bridge__some_function ({ let val = arg ; __swift_bridge__tuple_SomeTypeString (Box::into_raw(Box::new({
+                        let val = unsafe { __swift_bridge__some_function ({ let val = arg ; __swift_bridge__tuple_SomeTypeString (swift_bridge::leak_check::box_into_raw({
                             let val: super::SomeType = val.0;
                             val
-                        })) as *mut super::SomeType , swift_bridge::string::RustString(val.1).box_into_raw()) }) };
-                        (unsafe { * Box::from_raw(val.0) }, unsafe { Box::from_raw(val.1).0 })
+                        }) , swift_bridge::string::RustString(val.1).box_into_raw()) }) };
+                        (unsafe { * swift_bridge::leak_check::box_from_raw(val.0) }, unsafe { Box::from_raw(val.1).0 })
                     }
                 }
             },
@@ -20,6 +20,8 @@ mod cfg_feature_bridge_module_feature_enabled {
         BridgeModule {
             tokens,
             enabled_crate_features: vec!["some-feature"],
+            enabled_cfgs: vec![],
+            default_swift_access: None,
         }
     }
 
@@ -80,6 +82,8 @@ mod cfg_feature_bridge_module_feature_disabled {
         BridgeModule {
             tokens,
             enabled_crate_features: vec![],
+            enabled_cfgs: vec![],
+            default_swift_access: None,
         }
     }
 
@@ -120,3 +124,225 @@ some_function
         .test();
     }
 }
+
+/// Verify that we annotate the generated Rust code for a function with the function's
+/// `#[cfg(...)]` attributes, and that we only generate Swift and C code for it when the cfg is
+/// set for the target.
+mod cfg_on_function {
+    use super::*;
+    use crate::codegen::codegen_tests::BridgeModule;
+
+    fn bridge_module(enabled_cfgs: Vec<(&'static str, Option<&'static str>)>) -> BridgeModule {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[cfg(target_os = "ios")]
+                    fn ios_function();
+
+                    #[cfg(not(debug_assertions))]
+                    fn release_function();
+                }
+            }
+        };
+        BridgeModule {
+            tokens,
+            enabled_crate_features: vec![],
+            enabled_cfgs,
+            default_swift_access: None,
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[cfg(target_os = "ios")]
+                #[export_name = "__swift_bridge__$ios_function"]
+                pub extern "C" fn __swift_bridge__ios_function() {
+                    super::ios_function()
+                }
+            },
+            quote! {
+                #[cfg(not(debug_assertions))]
+                #[export_name = "__swift_bridge__$release_function"]
+                pub extern "C" fn __swift_bridge__release_function() {
+                    super::release_function()
+                }
+            },
+        ])
+    }
+
+    #[test]
+    fn cfg_on_function_enabled() {
+        CodegenTest {
+            bridge_module: bridge_module(vec![("target_os", Some("ios"))]),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+                "public func ios_function()",
+                "public func release_function()",
+            ]),
+            expected_c_header: ExpectedCHeader::ContainsManyAfterTrim(vec![
+                "void __swift_bridge__$ios_function(void);",
+                "void __swift_bridge__$release_function(void);",
+            ]),
+        }
+        .test();
+    }
+
+    #[test]
+    fn cfg_on_function_disabled() {
+        CodegenTest {
+            bridge_module: bridge_module(vec![
+                ("target_os", Some("macos")),
+                ("debug_assertions", None),
+            ]),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainManyAfterTrim(vec![
+                "ios_function",
+                "release_function",
+            ]),
+            expected_c_header: ExpectedCHeader::DoesNotContainManyAfterTrim(vec![
+                "ios_function",
+                "release_function",
+            ]),
+        }
+        .test();
+    }
+}
+
+/// Verify that the `#[cfg(...)]` attributes on an extern block apply to its types and
+/// functions.
+mod cfg_on_extern_block {
+    use super::*;
+    use crate::codegen::codegen_tests::BridgeModule;
+
+    fn bridge_module(enabled_cfgs: Vec<(&'static str, Option<&'static str>)>) -> BridgeModule {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[cfg(any(target_os = "ios", target_os = "macos"))]
+                extern "Rust" {
+                    type AppleType;
+                    fn apple_method(&self);
+                }
+            }
+        };
+        BridgeModule {
+            tokens,
+            enabled_crate_features: vec![],
+            enabled_cfgs,
+            default_swift_access: None,
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[cfg(any(target_os = "ios", target_os = "macos"))]
+                #[export_name = "__swift_bridge__$AppleType$apple_method"]
+                pub extern "C" fn __swift_bridge__AppleType_apple_method(
+                    this: *mut super::AppleType
+                ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "AppleType");
+                    (unsafe { &*this }).apple_method()
+                }
+            },
+            quote! {
+                #[cfg(any(target_os = "ios", target_os = "macos"))]
+                #[export_name = "__swift_bridge__$AppleType$_free"]
+                pub extern "C" fn __swift_bridge__AppleType__free (this: *mut super::AppleType) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "AppleType");
+                    let this = unsafe { swift_bridge::leak_check::box_from_raw(this) };
+                    drop(this);
+                }
+            },
+        ])
+    }
+
+    #[test]
+    fn cfg_on_extern_block_enabled() {
+        CodegenTest {
+            bridge_module: bridge_module(vec![("target_os", Some("macos"))]),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+                "public class AppleType: AppleTypeRefMut {",
+                "public func apple_method()",
+            ]),
+            expected_c_header: ExpectedCHeader::ContainsManyAfterTrim(vec![
+                "typedef struct AppleType AppleType;",
+                "void __swift_bridge__$AppleType$apple_method(void* self);",
+            ]),
+        }
+        .test();
+    }
+
+    #[test]
+    fn cfg_on_extern_block_disabled() {
+        CodegenTest {
+            bridge_module: bridge_module(vec![("target_os", Some("linux"))]),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("AppleType"),
+            expected_c_header: ExpectedCHeader::DoesNotContainAfterTrim("AppleType"),
+        }
+        .test();
+    }
+}
+
+/// Verify that we annotate the generated Rust code for a shared struct with the struct's
+/// `#[cfg(...)]` attributes, and skip its Swift and C code when the cfg isn't set.
+mod cfg_on_shared_struct {
+    use super::*;
+    use crate::codegen::codegen_tests::BridgeModule;
+
+    fn bridge_module() -> BridgeModule {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[cfg(feature = "some-feature")]
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+            }
+        };
+        BridgeModule {
+            tokens,
+            enabled_crate_features: vec![],
+            enabled_cfgs: vec![],
+            default_swift_access: None,
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[cfg(feature = "some-feature")]
+                #[derive()]
+                pub struct SomeStruct {
+                    pub field: u8
+                }
+            },
+            quote! {
+                #[cfg(feature = "some-feature")]
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__SomeStruct {
+                    field: u8
+                }
+            },
+        ])
+    }
+
+    #[test]
+    fn cfg_on_shared_struct() {
+        CodegenTest {
+            bridge_module: bridge_module(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("SomeStruct"),
+            expected_c_header: ExpectedCHeader::DoesNotContainAfterTrim("SomeStruct"),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,211 @@
+//! See also: crates/swift-integration-tests/src/core_graphics.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we pass the CoreGraphics types to and from Rust functions by value, without any
+/// conversions, and that the C header uses CoreGraphics' declarations.
+mod extern_rust_fn_core_graphics {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(origin: CGPoint, size: CGSize, scale: CGFloat) -> CGRect;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                origin: swift_bridge::core_graphics::CGPoint,
+                size: swift_bridge::core_graphics::CGSize,
+                scale: swift_bridge::core_graphics::CGFloat
+            ) -> swift_bridge::core_graphics::CGRect {
+                super::some_function(origin, size, scale)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ origin: CGPoint, _ size: CGSize, _ scale: CGFloat) -> CGRect {
+    __swift_bridge__$some_function(origin, size, scale)
+}
+"#,
+            "import CoreGraphics",
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "#include <CoreGraphics/CoreGraphics.h>",
+            "CGRect __swift_bridge__$some_function(CGPoint origin, CGSize size, CGFloat scale);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_core_graphics() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we pass the CoreGraphics types to and from Swift functions.
+mod extern_swift_fn_core_graphics {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: CGRect) -> CGSize;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(
+                    arg: swift_bridge::core_graphics::CGRect
+                ) -> swift_bridge::core_graphics::CGSize {
+                    unsafe { __swift_bridge__some_function(arg) }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::core_graphics::CGRect
+                ) -> swift_bridge::core_graphics::CGSize;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: CGRect) -> CGSize {
+    some_function(arg: arg)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_core_graphics() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a shared struct can have CoreGraphics fields.
+///
+/// Shared struct fields keep the type that was written in the bridge module, so the fields use
+/// the types' full paths.
+mod shared_struct_core_graphics_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                struct Layer {
+                    frame: swift_bridge::core_graphics::CGRect,
+                    corner_radius: swift_bridge::core_graphics::CGFloat,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub struct __swift_bridge__Layer {
+                frame: swift_bridge::core_graphics::CGRect,
+                corner_radius: swift_bridge::core_graphics::CGFloat
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public struct Layer {
+    public var frame: CGRect
+    public var corner_radius: CGFloat
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "#include <CoreGraphics/CoreGraphics.h>",
+            "typedef struct __swift_bridge__$Layer { CGRect frame; CGFloat corner_radius; } __swift_bridge__$Layer;",
+        ])
+    }
+
+    #[test]
+    fn shared_struct_core_graphics_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `CGPoint` type that the bridge module declares is not treated as a
+/// `swift_bridge::core_graphics::CGPoint`.
+mod declared_cg_point_type_takes_precedence {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type CGPoint;
+                    fn some_function(arg: CGPoint);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *mut super::CGPoint) {
+                super::some_function(unsafe { *swift_bridge::leak_check::box_from_raw(arg) })
+            }
+        })
+    }
+
+    #[test]
+    fn declared_cg_point_type_takes_precedence() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
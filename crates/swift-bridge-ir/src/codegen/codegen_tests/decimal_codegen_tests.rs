@@ -0,0 +1,156 @@
+//! See also: crates/swift-integration-tests/src/decimal.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we pass a Decimal to and from Rust functions as its mantissa, exponent and sign,
+/// and that it is seen on the Swift side as a `Decimal`.
+mod extern_rust_fn_decimal {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Decimal) -> rust_decimal::Decimal;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::decimal_support::FfiDecimal
+            ) -> swift_bridge::decimal_support::FfiDecimal {
+                swift_bridge::decimal_support::FfiDecimal::from(
+                    super::some_function(rust_decimal::Decimal::from(arg))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: Decimal) -> Decimal {
+    __swift_bridge__$some_function(__private__FfiDecimal(arg)).intoSwiftRepr()
+}
+"#,
+            "import Foundation",
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__FfiDecimal __swift_bridge__$some_function(struct __private__FfiDecimal arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_decimal() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we pass a Decimal to and from Swift functions.
+mod extern_swift_fn_decimal {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: Decimal) -> Decimal;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: rust_decimal::Decimal) -> rust_decimal::Decimal {
+                    rust_decimal::Decimal::from(unsafe {
+                        __swift_bridge__some_function(swift_bridge::decimal_support::FfiDecimal::from(arg))
+                    })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::decimal_support::FfiDecimal
+                ) -> swift_bridge::decimal_support::FfiDecimal;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__FfiDecimal) -> __private__FfiDecimal {
+    __private__FfiDecimal(some_function(arg: arg.intoSwiftRepr()))
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_decimal() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Decimal` type that the bridge module declares is not treated as a
+/// `rust_decimal::Decimal`.
+mod declared_decimal_type_takes_precedence {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Decimal;
+                    fn some_function(arg: Decimal);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *mut super::Decimal) {
+                super::some_function(unsafe { *swift_bridge::leak_check::box_from_raw(arg) })
+            }
+        })
+    }
+
+    #[test]
+    fn declared_decimal_type_takes_precedence() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
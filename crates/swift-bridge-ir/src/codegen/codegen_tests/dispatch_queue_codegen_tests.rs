@@ -0,0 +1,121 @@
+//! See also: crates/swift-integration-tests/src/dispatch_queue.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that Swift calls `dispatch_queue` Rust functions and methods on their queue.
+mod extern_rust_fn_dispatch_queue {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Database;
+
+                    #[swift_bridge(dispatch_queue = "Database.queue")]
+                    fn row_count() -> u32;
+
+                    #[swift_bridge(dispatch_queue = "self.queue")]
+                    fn insert(&self, name: &str);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$row_count"]
+            pub extern "C" fn __swift_bridge__row_count() -> u32 {
+                super::row_count()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func row_count() -> UInt32 {
+    return __swift_bridge__callOnQueue(Database.queue) {
+        __swift_bridge__$row_count()
+    }
+}
+"#,
+            r#"
+    public func insert<GenericToRustStr: ToRustStr>(_ name: GenericToRustStr) {
+        __swift_bridge__callOnQueue(self.queue) {
+            name.toRustStr({ nameAsRustStr in
+                __swift_bridge__$Database$insert(ptr, nameAsRustStr)
+            })
+        }
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_fn_dispatch_queue() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the glue for `dispatch_queue` Swift functions runs them on their queue.
+mod extern_swift_fn_dispatch_queue {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(dispatch_queue = "CaptureSession.queue")]
+                    fn start_capture(device: u8) -> bool;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn start_capture(device: u8) -> bool {
+                unsafe { __swift_bridge__start_capture(device) }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$start_capture")
+func __swift_bridge__start_capture (_ device: UInt8) -> Bool {
+    __swift_bridge__callOnQueue(CaptureSession.queue) { start_capture(device: device) }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_swift_fn_dispatch_queue() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
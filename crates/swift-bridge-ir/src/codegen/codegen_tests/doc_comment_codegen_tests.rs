@@ -0,0 +1,137 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we emit the doc comments of bridged functions as Swift documentation.
+mod function_doc_comment {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    /// Create a new SomeType.
+                    #[swift_bridge(init)]
+                    fn new() -> SomeType;
+
+                    /// Get the name.
+                    ///
+                    /// The name is never empty.
+                    fn name(&self) -> String;
+
+                    /// A freestanding function.
+                    fn some_function();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+/// A freestanding function.
+public func some_function() {
+    __swift_bridge__$some_function()
+}
+"#,
+            r#"
+    /// Create a new SomeType.
+    public convenience init() {
+        self.init(ptr: __swift_bridge__$SomeType$new())
+    }
+"#,
+            r#"
+    /// Get the name.
+    ///
+    /// The name is never empty.
+    public func name() -> RustString {
+        RustString(ptr: __swift_bridge__$SomeType$name(ptr))
+    }
+"#,
+        ])
+    }
+
+    #[test]
+    fn function_doc_comment() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we emit the doc comments of bridged types as Swift documentation.
+mod type_doc_comment {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                /// A shared struct.
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                /// A shared enum.
+                enum SomeEnum {
+                    Variant,
+                }
+
+                extern "Rust" {
+                    /// An opaque Rust type.
+                    type SomeType;
+
+                    /// An opaque Rust Copy type.
+                    #[swift_bridge(Copy(4))]
+                    type SomeCopyType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+/// A shared struct.
+public struct SomeStruct {
+"#,
+            r#"
+/// A shared enum.
+public enum SomeEnum {
+"#,
+            r#"
+/// An opaque Rust type.
+public class SomeType: SomeTypeRefMut {
+"#,
+            r#"
+/// An opaque Rust Copy type.
+public struct SomeCopyType {
+"#,
+        ])
+    }
+
+    #[test]
+    fn type_doc_comment() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
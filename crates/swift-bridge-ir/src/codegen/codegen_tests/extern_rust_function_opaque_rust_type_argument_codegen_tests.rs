@@ -25,7 +25,7 @@ mod test_extern_rust_function_owned_opaque_rust_type_argument {
             pub extern "C" fn __swift_bridge__some_function (
                 arg: *mut super::SomeType
             ) {
-                super::some_function(unsafe { * Box::from_raw(arg) })
+                super::some_function(unsafe { * swift_bridge::leak_check::box_from_raw(arg) })
             }
         })
     }
@@ -23,10 +23,10 @@ mod test_extern_rust_function_owned_opaque_rust_type_return {
         ExpectedRustTokens::Contains(quote! {
             #[export_name = "__swift_bridge__$some_function"]
             pub extern "C" fn __swift_bridge__some_function () -> *mut super::SomeType {
-                Box::into_raw(Box::new({
+                swift_bridge::leak_check::box_into_raw({
                     let val: super::SomeType = super::some_function();
                     val
-                })) as *mut super::SomeType
+                })
             }
         })
     }
@@ -72,7 +72,7 @@ mod test_extern_rust_function_ref_opaque_rust_type_return {
                 extern "Rust" {
                     type SomeType;
 
-                    fn some_function() -> &SomeType;
+                    fn some_function() -> &'static SomeType;
                 }
             }
         }
@@ -128,7 +128,7 @@ mod test_extern_rust_function_ref_mut_opaque_rust_type_return {
                 extern "Rust" {
                     type SomeType;
 
-                    fn some_function() -> &mut SomeType;
+                    fn some_function() -> &'static mut SomeType;
                 }
             }
         }
@@ -46,6 +46,9 @@ public class SomeType: SomeTypeRefMut {
     deinit {
         if isOwned {
             __swift_bridge__$SomeType$_free(ptr)
+            #if DEBUG
+            ptr = __swift_bridge__freedPointer
+            #endif
         }
     }
 }
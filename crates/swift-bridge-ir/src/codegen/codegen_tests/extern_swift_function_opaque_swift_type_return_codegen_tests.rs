@@ -119,3 +119,64 @@ func __swift_bridge__SomeType_some_method (_ this: UnsafeMutableRawPointer) -> U
         .test();
     }
 }
+
+/// Verify that we generate the proper code for extern "Swift" functions that return an
+/// `Option` of an opaque Swift type.
+mod test_extern_swift_freestanding_function_option_opaque_swift_type_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    type SomeType;
+
+                    fn some_function() -> Option<SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function () -> Option<SomeType> {
+                    {
+                        let val = unsafe { __swift_bridge__some_function() };
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some(SomeType(val))
+                        }
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function() -> *mut std::ffi::c_void;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function () -> UnsafeMutableRawPointer? {
+    { if let val = some_function() { return Unmanaged.passRetained(val).toOpaque() } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn test_extern_swift_freestanding_function_option_opaque_swift_type_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -63,6 +63,63 @@ func some_function(_ some_arg: SomeStruct, _ another_arg: AnotherStruct, _ arg3:
     }
 }
 
+/// Verify that we use the `#[swift_bridge(args_into = (arg1, another_arg))]` attribute on
+/// methods.
+mod method_args_into_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Wallet;
+
+                    #[swift_bridge(args_into = (amount, currency))]
+                    fn deposit(&mut self, amount: u64, currency: String, note: u8);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__Wallet_deposit(
+                this: *mut super::Wallet,
+                amount: u64,
+                currency: *mut swift_bridge::string::RustString,
+                note: u8
+            ) {
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "Wallet");
+                (unsafe { &mut *this }).deposit(
+                    amount.into(),
+                    unsafe { Box::from_raw(currency).0 }.into(),
+                    note
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::SkipTest
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn method_args_into_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we explicitly case to the struct when generating `return_into` code for a
 /// Rust function that returns a shared struct.
 /// This explicit conversion into the struct avoids a type inference issues when converting the
@@ -186,6 +243,55 @@ mod return_with {
     }
 }
 
+/// Verify that async functions apply `return_into` and `return_with` to the awaited value
+/// instead of to the returned future.
+mod async_return_into_and_return_with {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(return_into)]
+                    async fn some_function() -> u32;
+
+                    #[swift_bridge(return_with = path::to::convert_fn)]
+                    async fn another_function() -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                let fut = super::some_function();
+            },
+            quote! {
+                let val = fut.await.into();
+            },
+            quote! {
+                let fut = super::another_function();
+            },
+            quote! {
+                let val = super::path::to::convert_fn(fut.await);
+            },
+        ])
+    }
+
+    #[test]
+    fn async_return_into_and_return_with() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
 /// Verify that we can annotate that a function should serve as the Identifiable protocol extension.
 mod protocol_identifiable {
     use super::*;
@@ -289,6 +395,8 @@ mod get {
                 pub extern "C" fn __swift_bridge__SomeType_some_function(
                     this: *mut super::SomeType
                 ) -> u16 {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
                     (unsafe { &*this }).field
                 }
             },
@@ -296,6 +404,8 @@ mod get {
                 pub extern "C" fn __swift_bridge__SomeType_some_function_ref(
                     this: *mut super::SomeType
                 ) -> i16 {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
                     &(unsafe { &*this }).field
                 }
             },
@@ -303,6 +413,8 @@ mod get {
                 pub extern "C" fn __swift_bridge__SomeType_some_function_ref_mut(
                     this: *mut super::SomeType
                 ) -> u8 {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
                     &mut (unsafe { &mut *this }).field
                 }
             },
@@ -359,6 +471,8 @@ mod get_with {
                 pub extern "C" fn __swift_bridge__SomeType_some_function(
                     this: *mut super::SomeType
                 ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
                     super::a::b::c( (unsafe { &*this }).field )
                 }
             },
@@ -366,6 +480,8 @@ mod get_with {
                 pub extern "C" fn __swift_bridge__SomeType_some_function_ref(
                     this: *mut super::SomeType
                 ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
                     super::a::b::c( & (unsafe { &*this }).field )
                 }
             },
@@ -373,6 +489,8 @@ mod get_with {
                 pub extern "C" fn __swift_bridge__SomeType_some_function_ref_mut(
                     this: *mut super::SomeType
                 ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
                     super::a::b::c( &mut (unsafe { &mut *this }).field )
                 }
             },
@@ -467,3 +585,409 @@ func __swift_bridge__call_swift_from_rust () -> UnsafeMutableRawPointer {
         .test();
     }
 }
+
+/// Verify that the `rust_name` function attribute renames the Rust function that calls an
+/// extern "Swift" function, while the Swift function keeps its declared name.
+mod function_attribute_rust_name_extern_swift {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    type SwiftView;
+
+                    #[swift_bridge(rust_name = "create_view")]
+                    fn makeView() -> SwiftView;
+
+                    #[swift_bridge(rust_name = "set_title")]
+                    fn setTitle(&self, title: u8);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn create_view() -> SwiftView {
+                    unsafe { __swift_bridge__makeView() }
+                }
+            },
+            quote! {
+                impl SwiftView {
+                    pub fn set_title(&self, title: u8) {
+                        unsafe { __swift_bridge__SwiftView_setTitle(swift_bridge::PointerToSwiftType(self.0), title) }
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_cdecl("__swift_bridge__$makeView")
+func __swift_bridge__makeView () -> UnsafeMutableRawPointer {
+    Unmanaged.passRetained(makeView()).toOpaque()
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$SwiftView$setTitle")
+func __swift_bridge__SwiftView_setTitle (_ this: UnsafeMutableRawPointer, _ title: UInt8) {
+    Unmanaged<SwiftView>.fromOpaque(this).takeUnretainedValue().setTitle(title: title)
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn function_attribute_rust_name_extern_swift() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate one extern function and one Swift overload per type in a generic
+/// function's `#[swift_bridge(instantiate(...))]` attribute.
+mod instantiate {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(instantiate(u32, f64))]
+                    fn some_function<T>(arg: T) -> T;
+
+                    #[swift_bridge(instantiate(SomeType))]
+                    fn some_method<T>(&self, arg: T);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function$U32"]
+                pub extern "C" fn __swift_bridge__some_function_U32(arg: u32) -> u32 {
+                    super::some_function::<u32>(arg)
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function$F64"]
+                pub extern "C" fn __swift_bridge__some_function_F64(arg: f64) -> f64 {
+                    super::some_function::<f64>(arg)
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$some_method$SomeType"]
+                pub extern "C" fn __swift_bridge__SomeType_some_method_SomeType(
+                    this: *mut super::SomeType,
+                    arg: *mut super::SomeType
+                ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
+                    (unsafe { &*this }).some_method::<super::SomeType>(unsafe {
+                        *swift_bridge::leak_check::box_from_raw(arg)
+                    })
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: UInt32) -> UInt32 {
+    __swift_bridge__$some_function$U32(arg)
+}
+public func some_function(_ arg: Double) -> Double {
+    __swift_bridge__$some_function$F64(arg)
+}
+"#,
+            r#"
+    public func some_method(_ arg: SomeType) {
+        __swift_bridge__$SomeType$some_method$SomeType(ptr, {arg.isOwned = false; return arg.ptr;}())
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "uint32_t __swift_bridge__$some_function$U32(uint32_t arg);",
+            "double __swift_bridge__$some_function$F64(double arg);",
+            "void __swift_bridge__$SomeType$some_method$SomeType(void* self, void* arg);",
+        ])
+    }
+
+    #[test]
+    fn instantiate() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the `available` attribute is emitted as an `@available` attribute on the generated
+/// Swift functions.
+mod function_available_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(available = "iOS 16.0, macOS 13.0")]
+                    fn some_function();
+
+                    #[swift_bridge(available = "iOS 17.0, *")]
+                    fn some_method(&self);
+                }
+
+                extern "Swift" {
+                    #[swift_bridge(available = "iOS 16.0")]
+                    fn swift_function();
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@available(iOS 16.0, macOS 13.0, *)
+public func some_function() {
+    __swift_bridge__$some_function()
+}
+"#,
+            r#"
+extension SomeTypeRef {
+    @available(iOS 17.0, *)
+    public func some_method() {
+        __swift_bridge__$SomeType$some_method(ptr)
+    }
+}
+"#,
+            r#"
+@available(iOS 16.0, *)
+@_cdecl("__swift_bridge__$swift_function")
+func __swift_bridge__swift_function () {
+    swift_function()
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn function_available_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `swift_access` attribute sets the access level of the generated Swift function.
+mod function_swift_access_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(swift_access = "internal")]
+                    fn some_function();
+
+                    #[swift_bridge(swift_access = "fileprivate")]
+                    fn some_method(&self);
+
+                    fn public_method(&self);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+internal func some_function() {
+    __swift_bridge__$some_function()
+}
+"#,
+            r#"
+extension SomeTypeRef {
+    fileprivate func some_method() {
+        __swift_bridge__$SomeType$some_method(ptr)
+    }
+
+    public func public_method() {
+        __swift_bridge__$SomeType$public_method(ptr)
+    }
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn function_swift_access_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that `subscript` methods are exposed as a Swift subscript, with the `&mut self` setter
+/// overriding the `&self` getter's subscript.
+mod function_subscript_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(subscript)]
+                    fn get(&self, index: usize) -> u8;
+
+                    #[swift_bridge(subscript)]
+                    fn set(&mut self, index: usize, value: u8);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public class SomeTypeRefMut: SomeTypeRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    public override subscript(_ index: UInt) -> UInt8 {
+        get {
+            super[index]
+        }
+        set(value) {
+            __swift_bridge__$SomeType$set(ptr, index, value)
+        }
+    }
+}
+"#,
+            r#"
+public class SomeTypeRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    public subscript(_ index: UInt) -> UInt8 {
+        __swift_bridge__$SomeType$get(ptr, index)
+    }
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn function_subscript_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that `swift_property` methods are exposed as Swift computed properties, with the
+/// `&mut self` setter overriding the `&self` getter's property.
+mod function_swift_property_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(swift_property)]
+                    fn name(&self) -> String;
+
+                    #[swift_bridge(swift_property)]
+                    fn set_name(&mut self, value: String);
+
+                    #[swift_bridge(swift_property, swift_name = "isEnabled")]
+                    fn is_enabled(&self) -> bool;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public override var name: RustString {
+        get {
+            super.name
+        }
+        set(value) {
+            __swift_bridge__$SomeType$set_name(ptr, { let rustString = value.intoRustString(); rustString.isOwned = false; return rustString.ptr }())
+        }
+    }
+"#,
+            r#"
+    public var name: RustString {
+        RustString(ptr: __swift_bridge__$SomeType$name(ptr))
+    }
+
+    public var isEnabled: Bool {
+        __swift_bridge__$SomeType$is_enabled(ptr)
+    }
+"#,
+        ])
+    }
+
+    #[test]
+    fn function_swift_property_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
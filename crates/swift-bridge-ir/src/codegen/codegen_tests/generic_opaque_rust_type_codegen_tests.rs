@@ -39,6 +39,9 @@ public class SomeType<A>: SomeTypeRefMut<A> {
     deinit {
         if isOwned {
             (self as! SwiftBridgeGenericFreer).rust_free()
+            #if DEBUG
+            ptr = __swift_bridge__freedPointer
+            #endif
         }
     }
 }
@@ -98,7 +101,9 @@ mod monomorphized_generic_opaque_rust_type {
             pub extern "C" fn __swift_bridge__SomeType_u32__free (
                 this: *mut super::SomeType<u32>
             ) {
-                let this = unsafe { Box::from_raw(this) };
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
+                let this = unsafe { swift_bridge::leak_check::box_from_raw(this) };
                 drop(this);
             }
         })
@@ -161,7 +166,7 @@ mod generic_opaque_rust_type_arg {
             pub extern "C" fn __swift_bridge__some_function (
                 arg: *mut super::SomeType<u32>
             ) {
-                super::some_function(unsafe { * Box::from_raw(arg) })
+                super::some_function(unsafe { * swift_bridge::leak_check::box_from_raw(arg) })
             }
         })
     }
@@ -218,10 +223,10 @@ mod generic_opaque_rust_type_return {
         ExpectedRustTokens::Contains(quote! {
             #[export_name = "__swift_bridge__$some_function"]
             pub extern "C" fn __swift_bridge__some_function () -> *mut super::SomeType<u32> {
-                Box::into_raw(Box::new({
+                swift_bridge::leak_check::box_into_raw({
                     let val: super::SomeType<u32> = super::some_function();
                     val
-                })) as *mut super::SomeType<u32>
+                })
             }
         })
     }
@@ -488,7 +493,9 @@ mod generic_opaque_rust_type_inner_opaque_ty {
             pub extern "C" fn __swift_bridge__SomeType_AnotherType__free (
                 this: *mut super::SomeType<super::AnotherType>
             ) {
-                let this = unsafe { Box::from_raw(this) };
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
+                let this = unsafe { swift_bridge::leak_check::box_from_raw(this) };
                 drop(this);
             }
         })
@@ -513,3 +520,167 @@ mod generic_opaque_rust_type_inner_opaque_ty {
         .test();
     }
 }
+
+/// Verify that we can declare initializers and methods on monomorphized generic opaque Rust
+/// types, and that each monomorphization gets its own Swift extension.
+mod generic_opaque_rust_type_methods {
+    use super::*;
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(declare_generic)]
+                    type SomeType<A>;
+                    type SomeType<u32>;
+                    type SomeType<u64>;
+
+                    #[swift_bridge(init)]
+                    fn new(value: u32) -> SomeType<u32>;
+                    fn value(self: &SomeType<u32>) -> u32;
+                    fn set_value(self: &mut SomeType<u64>, value: u64);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$u32$new"]
+                pub extern "C" fn __swift_bridge__SomeType_u32_new (
+                    value: u32
+                ) -> *mut super::SomeType<u32> {
+                    swift_bridge::leak_check::box_into_raw({
+                        let val: super::SomeType<u32> = super::SomeType::<u32>::new(value);
+                        val
+                    })
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$u32$value"]
+                pub extern "C" fn __swift_bridge__SomeType_u32_value (
+                    this: *mut super::SomeType<u32>
+                ) -> u32 {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
+                    (unsafe { &*this }).value()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$u64$set_value"]
+                pub extern "C" fn __swift_bridge__SomeType_u64_set_value (
+                    this: *mut super::SomeType<u64>,
+                    value: u64
+                ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
+                    (unsafe { &mut *this }).set_value(value)
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeType where A == UInt32 {
+    public convenience init(_ value: UInt32) {
+        self.init(ptr: __swift_bridge__$SomeType$u32$new(value))
+    }
+}
+extension SomeTypeRef where A == UInt32 {
+    public func value() -> UInt32 {
+        __swift_bridge__$SomeType$u32$value(ptr)
+    }
+}
+"#,
+            r#"
+extension SomeTypeRefMut where A == UInt64 {
+    public func set_value(_ value: UInt64) {
+        __swift_bridge__$SomeType$u64$set_value(ptr, value)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void* __swift_bridge__$SomeType$u32$new(uint32_t value);",
+            "uint32_t __swift_bridge__$SomeType$u32$value(void* self);",
+            "void __swift_bridge__$SomeType$u64$set_value(void* self, uint64_t value);",
+        ])
+    }
+
+    #[test]
+    fn generic_opaque_rust_type_methods() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can declare methods on a monomorphized generic opaque Rust Copy type.
+mod generic_opaque_rust_type_copy_methods {
+    use super::*;
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4))]
+                    type SomeType<u32>;
+
+                    fn value(self: &SomeType<u32>) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$u32$value"]
+            pub extern "C" fn __swift_bridge__SomeType_u32_value (
+                this: __swift_bridge__SomeType_u32
+            ) -> u32 {
+                this.into_rust_repr().value()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType where A == UInt32 {
+    public func value() -> UInt32 {
+        __swift_bridge__$SomeType$u32$value(self.intoFfiRepr())
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint32_t __swift_bridge__$SomeType$u32$value(struct __swift_bridge__$SomeType$u32 this);
+"#,
+        )
+    }
+
+    #[test]
+    fn generic_opaque_rust_type_copy_methods() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
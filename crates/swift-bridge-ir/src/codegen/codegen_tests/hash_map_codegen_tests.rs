@@ -0,0 +1,241 @@
+//! See also: crates/swift-integration-tests/src/hash_map.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use crate::test_utils::parse_ok;
+use crate::CodegenConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can return a HashMap<String, u64> from Rust and that we generate the
+/// functions that Swift uses to work with the map.
+mod extern_rust_fn_return_hash_map_string_u64 {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> HashMap<String, u64>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() -> *mut std::collections::HashMap<String, u64> {
+                    Box::into_raw(Box::new(super::some_function()))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$ffi$HashMap$String$U64$new"]
+                #[doc(hidden)]
+                pub extern "C" fn _new() -> *mut std::collections::HashMap<String, u64> {
+                    Box::into_raw(Box::new(std::collections::HashMap::new()))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$ffi$HashMap$String$U64$_free"]
+                #[doc(hidden)]
+                pub extern "C" fn _free(map: *mut std::collections::HashMap<String, u64>) {
+                    let _ = unsafe { Box::from_raw(map) };
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$ffi$HashMap$String$U64$get"]
+                #[doc(hidden)]
+                pub extern "C" fn _get(
+                    map: *const std::collections::HashMap<String, u64>,
+                    key: *mut swift_bridge::string::RustString
+                ) -> swift_bridge::option::OptionU64 {
+                    let val = unsafe { &*map }.get(&unsafe { Box::from_raw(key).0 }).cloned();
+                    swift_bridge::option::OptionU64::from_option(val)
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$ffi$HashMap$String$U64$keys"]
+                #[doc(hidden)]
+                pub extern "C" fn _keys(
+                    map: *const std::collections::HashMap<String, u64>
+                ) -> *mut Vec<swift_bridge::string::RustString> {
+                    let keys: Vec<swift_bridge::string::RustString> = unsafe { &*map }
+                        .keys()
+                        .cloned()
+                        .map(|key| swift_bridge::string::RustString(key))
+                        .collect();
+                    Box::into_raw(Box::new(keys))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function() -> RustMap<RustString, UInt64> {
+    RustMap<RustString, UInt64>(ptr: __swift_bridge__$some_function())
+}
+"#,
+            r#"
+extension RustMap where K == RustString, V == UInt64 {
+    public convenience init(ptr: UnsafeMutableRawPointer) {
+        self.init(ptr: ptr, freeFn: { __swift_bridge__$ffi$HashMap$String$U64$_free($0) })
+    }
+
+    public convenience init() {
+        self.init(ptr: __swift_bridge__$ffi$HashMap$String$U64$new())
+    }
+
+    public convenience init(_ dictionary: Dictionary<String, UInt64>) {
+        self.init()
+        for (key, value) in dictionary {
+            let _ = self.insert(key, value)
+        }
+    }
+"#,
+            r#"
+    public func get<GenericIntoRustString: IntoRustString>(_ key: GenericIntoRustString) -> Optional<UInt64> {
+        __swift_bridge__$ffi$HashMap$String$U64$get(ptr, { let rustString = key.intoRustString(); rustString.isOwned = false; return rustString.ptr }()).intoSwiftRepr()
+    }
+
+    public subscript<GenericIntoRustString: IntoRustString>(key: GenericIntoRustString) -> Optional<UInt64> {
+        self.get(key)
+    }
+"#,
+            r#"
+    public func keys() -> RustVec<RustString> {
+        RustVec(ptr: __swift_bridge__$ffi$HashMap$String$U64$keys(ptr))
+    }
+
+    public func values() -> RustVec<UInt64> {
+        RustVec(ptr: __swift_bridge__$ffi$HashMap$String$U64$values(ptr))
+    }
+
+    public func toDictionary() -> Dictionary<String, UInt64> {
+        var dictionary = Dictionary<String, UInt64>()
+        for (key, value) in zip(self.keys(), self.values()) {
+            dictionary[key.toString()] = value
+        }
+        return dictionary
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+#include <stdint.h>
+"#,
+            r#"
+void* __swift_bridge__$some_function(void);
+"#,
+            r#"
+void* __swift_bridge__$ffi$HashMap$String$U64$new(void);
+void __swift_bridge__$ffi$HashMap$String$U64$_free(void* map);
+uintptr_t __swift_bridge__$ffi$HashMap$String$U64$len(void* map);
+struct __private__OptionU64 __swift_bridge__$ffi$HashMap$String$U64$get(void* map, void* key);
+struct __private__OptionU64 __swift_bridge__$ffi$HashMap$String$U64$insert(void* map, void* key, uint64_t value);
+struct __private__OptionU64 __swift_bridge__$ffi$HashMap$String$U64$remove(void* map, void* key);
+void* __swift_bridge__$ffi$HashMap$String$U64$keys(void* map);
+void* __swift_bridge__$ffi$HashMap$String$U64$values(void* map);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_hash_map_string_u64() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a HashMap from Swift to Rust, and that a function that takes a map
+/// with String keys does not get an unused `GenericIntoRustString` generic parameter.
+mod extern_rust_fn_hash_map_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(map: HashMap<String, u8>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                map: *mut std::collections::HashMap<String, u8>
+            ) {
+                super::some_function(unsafe { * Box::from_raw(map) })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ map: RustMap<RustString, UInt8>) {
+    __swift_bridge__$some_function({ let val = map; val.isOwned = false; return val.ptr }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* map);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_hash_map_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the map's Swift extension is marked as shared code, so that only one of the bridge
+/// modules that use the same `HashMap<K, V>` keeps it.
+#[test]
+fn hash_map_swift_extension_is_shared() {
+    let module = parse_ok(quote! {
+        mod ffi {
+            extern "Rust" {
+                fn some_function() -> HashMap<String, u64>;
+                fn another_function(map: HashMap<String, u64>);
+            }
+        }
+    });
+    let generated = module.generate_swift_code_and_c_header(CodegenConfig::no_features_enabled());
+
+    assert_eq!(generated.shared_swift.len(), 1);
+    assert_eq!(generated.shared_swift[0].key, "RustMap<RustString, UInt64>");
+    assert!(!generated.swift.contains(&generated.shared_swift[0].code));
+    assert!(generated
+        .swift_with_shared_code()
+        .contains(&generated.shared_swift[0].code));
+    assert!(generated.shared_swift[0]
+        .code
+        .trim_start()
+        .starts_with("extension RustMap where K == RustString, V == UInt64 {"));
+}
@@ -0,0 +1,250 @@
+//! See also: crates/swift-integration-tests/src/hash_set.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use crate::test_utils::parse_ok;
+use crate::CodegenConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can return a HashSet<String> from Rust and that we generate the functions that
+/// Swift uses to work with the set.
+mod extern_rust_fn_return_hash_set_string {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> HashSet<String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() -> *mut std::collections::HashSet<String> {
+                    Box::into_raw(Box::new(super::some_function()))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$ffi$HashSet$String$new"]
+                #[doc(hidden)]
+                pub extern "C" fn _new() -> *mut std::collections::HashSet<String> {
+                    Box::into_raw(Box::new(std::collections::HashSet::new()))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$ffi$HashSet$String$contains"]
+                #[doc(hidden)]
+                pub extern "C" fn _contains(
+                    set: *const std::collections::HashSet<String>,
+                    value: *mut swift_bridge::string::RustString
+                ) -> bool {
+                    unsafe { &*set }.contains(&unsafe { Box::from_raw(value).0 })
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$ffi$HashSet$String$insert"]
+                #[doc(hidden)]
+                pub extern "C" fn _insert(
+                    set: *mut std::collections::HashSet<String>,
+                    value: *mut swift_bridge::string::RustString
+                ) -> bool {
+                    unsafe { &mut *set }.insert(unsafe { Box::from_raw(value).0 })
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$ffi$HashSet$String$values"]
+                #[doc(hidden)]
+                pub extern "C" fn _values(
+                    set: *const std::collections::HashSet<String>
+                ) -> *mut Vec<swift_bridge::string::RustString> {
+                    let values: Vec<swift_bridge::string::RustString> = unsafe { &*set }
+                        .iter()
+                        .cloned()
+                        .map(|value| swift_bridge::string::RustString(value))
+                        .collect();
+                    Box::into_raw(Box::new(values))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function() -> RustSet<RustString> {
+    RustSet<RustString>(ptr: __swift_bridge__$some_function())
+}
+"#,
+            r#"
+extension RustSet where T == RustString {
+    public convenience init(ptr: UnsafeMutableRawPointer) {
+        self.init(ptr: ptr, freeFn: { __swift_bridge__$ffi$HashSet$String$_free($0) }, valuesFn: { __swift_bridge__$ffi$HashSet$String$values($0) })
+    }
+
+    public convenience init() {
+        self.init(ptr: __swift_bridge__$ffi$HashSet$String$new())
+    }
+
+    public convenience init(_ set: Set<String>) {
+        self.init()
+        for value in set {
+            self.insert(value)
+        }
+    }
+"#,
+            r#"
+    public func contains<GenericIntoRustString: IntoRustString>(_ value: GenericIntoRustString) -> Bool {
+        __swift_bridge__$ffi$HashSet$String$contains(ptr, { let rustString = value.intoRustString(); rustString.isOwned = false; return rustString.ptr }())
+    }
+"#,
+            r#"
+    public func toSet() -> Set<String> {
+        var set = Set<String>()
+        for value in self.values() {
+            set.insert(value.toString())
+        }
+        return set
+    }
+}
+
+public protocol IntoRustSetOfRustString {
+    func intoRustSet() -> RustSet<RustString>
+}
+extension RustSet: IntoRustSetOfRustString where T == RustString {
+    public func intoRustSet() -> RustSet<RustString> {
+        self
+    }
+}
+extension Set: IntoRustSetOfRustString where Element == String {
+    public func intoRustSet() -> RustSet<RustString> {
+        RustSet(self)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+#include <stdint.h>
+#include <stdbool.h>
+"#,
+            r#"
+void* __swift_bridge__$some_function(void);
+"#,
+            r#"
+void* __swift_bridge__$ffi$HashSet$String$new(void);
+void __swift_bridge__$ffi$HashSet$String$_free(void* set);
+uintptr_t __swift_bridge__$ffi$HashSet$String$len(void* set);
+bool __swift_bridge__$ffi$HashSet$String$contains(void* set, void* value);
+bool __swift_bridge__$ffi$HashSet$String$insert(void* set, void* value);
+bool __swift_bridge__$ffi$HashSet$String$remove(void* set, void* value);
+void* __swift_bridge__$ffi$HashSet$String$values(void* set);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_hash_set_string() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function that takes a HashSet accepts anything that can be converted into a
+/// `RustSet`, such as a Swift `Set`.
+mod extern_rust_fn_hash_set_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(set: HashSet<u32>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                set: *mut std::collections::HashSet<u32>
+            ) {
+                super::some_function(unsafe { * Box::from_raw(set) })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ set: IntoRustSetOfUInt32) {
+    __swift_bridge__$some_function({ let val = set.intoRustSet(); val.isOwned = false; return val.ptr }())
+}
+"#,
+            r#"
+extension Set: IntoRustSetOfUInt32 where Element == UInt32 {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+void __swift_bridge__$some_function(void* set);
+"#,
+            r#"
+bool __swift_bridge__$ffi$HashSet$U32$contains(void* set, uint32_t value);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_hash_set_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the set's Swift extension and `IntoRustSetOf..` protocol are marked as shared code,
+/// so that only one of the bridge modules that use the same `HashSet<T>` keeps them.
+#[test]
+fn hash_set_swift_extension_is_shared() {
+    let module = parse_ok(quote! {
+        mod ffi {
+            extern "Rust" {
+                fn some_function() -> HashSet<u32>;
+                fn another_function(set: HashSet<u32>);
+            }
+        }
+    });
+    let generated = module.generate_swift_code_and_c_header(CodegenConfig::no_features_enabled());
+
+    assert_eq!(generated.shared_swift.len(), 1);
+    let shared = &generated.shared_swift[0];
+    assert_eq!(shared.key, "RustSet<UInt32>");
+    assert!(!generated.swift.contains(&shared.code));
+    assert!(generated.swift_with_shared_code().contains(&shared.code));
+    assert!(shared
+        .code
+        .contains("extension RustSet where T == UInt32 {"));
+    assert!(shared.code.contains("protocol IntoRustSetOfUInt32 {"));
+}
@@ -0,0 +1,115 @@
+//! See also: crates/swift-integration-tests/src/primitive.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we pass u128 and i128 to and from Rust functions as two 64 bit limbs.
+mod extern_rust_fn_int128 {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: u128) -> i128;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::int128::RustUInt128
+            ) -> swift_bridge::int128::RustInt128 {
+                swift_bridge::int128::RustInt128::from(super::some_function(u128::from(arg)))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: RustUInt128) -> RustInt128 {
+    __swift_bridge__$some_function(arg)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct RustInt128 __swift_bridge__$some_function(struct RustUInt128 arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_int128() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we pass u128 and i128 to and from Swift functions as two 64 bit limbs.
+mod extern_swift_fn_int128 {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: i128) -> u128;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: i128) -> u128 {
+                    u128::from(unsafe {
+                        __swift_bridge__some_function(swift_bridge::int128::RustInt128::from(arg))
+                    })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::int128::RustInt128
+                ) -> swift_bridge::int128::RustUInt128;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: RustInt128) -> RustUInt128 {
+    some_function(arg: arg)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_int128() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,129 @@
+//! See also: crates/swift-integration-tests/src/main_actor.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the glue for synchronous `main_actor` functions and methods waits for the call to
+/// run on the main actor.
+mod extern_swift_main_actor_sync_fn {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    type Window;
+
+                    #[swift_bridge(main_actor)]
+                    fn screen_count(extra: u8) -> u8;
+
+                    #[swift_bridge(main_actor)]
+                    fn set_title(&self, title: String);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn screen_count(extra: u8) -> u8 {
+                unsafe { __swift_bridge__screen_count(extra) }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+@_cdecl("__swift_bridge__$screen_count")
+func __swift_bridge__screen_count (_ extra: UInt8) -> UInt8 {
+    __swift_bridge__callOnMainActor { screen_count(extra: extra) }
+}
+"#,
+            r#"
+@available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+@_cdecl("__swift_bridge__$Window$set_title")
+func __swift_bridge__Window_set_title (_ this: UnsafeMutableRawPointer, _ title: UnsafeMutableRawPointer) {
+    __swift_bridge__callOnMainActor { Unmanaged<Window>.fromOpaque(this).takeUnretainedValue().set_title(title: RustString(ptr: title)) }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_swift_main_actor_sync_fn() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the glue for `async` `main_actor` functions runs the call in a task that is
+/// isolated to the main actor.
+mod extern_swift_main_actor_async_fn {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(main_actor)]
+                    async fn present_alert(code: u32) -> bool;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn present_alert(code: u32) -> impl std::future::Future<Output = bool> {
+                extern "C" fn on_complete(completion: *mut std::ffi::c_void, val: bool) {
+                    unsafe { swift_bridge::async_support::SwiftFuture::<bool>::complete(completion, val) }
+                }
+
+                let (future, completion) = swift_bridge::async_support::SwiftFuture::<bool>::new();
+                unsafe { __swift_bridge__present_alert(completion, on_complete, code) };
+                future
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$present_alert")
+func __swift_bridge__present_alert (_ completion: UnsafeMutableRawPointer, _ onComplete: @escaping @convention(c) (UnsafeMutableRawPointer, Bool) -> Void, _ code: UInt32) {
+    Task { @MainActor in
+        let val = await present_alert(code: code)
+        onComplete(completion, val)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_swift_main_actor_async_fn() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
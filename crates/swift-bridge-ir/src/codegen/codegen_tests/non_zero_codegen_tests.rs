@@ -0,0 +1,108 @@
+//! See also: crates/swift-integration-tests/src/non_zero.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we pass NonZero integers to and from Rust functions as plain integers.
+mod extern_rust_fn_non_zero {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: NonZeroU32) -> std::num::NonZero<i64>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: u32) -> i64 {
+                super::some_function(
+                    std::num::NonZeroU32::new(arg).expect("NonZeroU32 cannot be zero")
+                ).get()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: UInt32) -> Int64 {
+    __swift_bridge__$some_function({ let val = arg; assert(val != 0, "NonZeroU32 cannot be zero"); return val }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+int64_t __swift_bridge__$some_function(uint32_t arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_non_zero() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we pass NonZero integers to and from Swift functions as plain integers.
+mod extern_swift_fn_non_zero {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: NonZeroU8) -> NonZeroU8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: std::num::NonZeroU8) -> std::num::NonZeroU8 {
+                std::num::NonZeroU8::new(
+                    unsafe { __swift_bridge__some_function(arg.get()) }
+                ).expect("NonZeroU8 cannot be zero")
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UInt8) -> UInt8 {
+    { let val = some_function(arg: arg); assert(val != 0, "NonZeroU8 cannot be zero"); return val }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_non_zero() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
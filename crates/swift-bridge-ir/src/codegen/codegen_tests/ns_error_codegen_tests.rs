@@ -0,0 +1,143 @@
+//! See also: crates/swift-integration-tests/src/ns_error.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that Rust functions that return a `Result<T, NsError>` throw an `NSError`, and that an
+/// `NsError` can be passed to Rust.
+mod extern_rust_fn_ns_error {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn load() -> Result<String, NsError>;
+                    fn save() -> Result<(), NsError>;
+                    fn log_error(error: NsError);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$save"]
+                pub extern "C" fn __swift_bridge__save() -> *mut swift_bridge::ns_error::NsError {
+                    match super::save() {
+                        Ok(ok) => std::ptr::null_mut(),
+                        Err(err) => Box::into_raw(Box::new(err))
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$log_error"]
+                pub extern "C" fn __swift_bridge__log_error(
+                    error: *mut swift_bridge::ns_error::NsError
+                ) {
+                    super::log_error(unsafe { *Box::from_raw(error) })
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func load() throws -> RustString {
+    try { let val = __swift_bridge__$load(); if val.is_ok { return RustString(ptr: val.ok_or_err!) } else { throw __swift_bridge__NSErrorFromRust(val.ok_or_err!) } }()
+}
+"#,
+            r#"
+public func save() throws -> () {
+    try { let val = __swift_bridge__$save(); if val != nil { throw __swift_bridge__NSErrorFromRust(val!) } else { return } }()
+}
+"#,
+            r#"
+public func log_error(_ error: NSError) {
+    __swift_bridge__$log_error(__swift_bridge__NSErrorIntoRust(error))
+}
+"#,
+            "import Foundation",
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "struct __private__ResultPtrAndPtr __swift_bridge__$load(void);",
+            "void* __swift_bridge__$save(void);",
+            "void __swift_bridge__$log_error(void* error);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_ns_error() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `catch_errors` Swift function that returns a `Result<T, NsError>` returns the
+/// `NSError`s that it throws to Rust.
+mod extern_swift_fn_catch_ns_errors {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(catch_errors)]
+                    fn save(path: String) -> Result<(), swift_bridge::ns_error::NsError>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn save(path: String) -> Result<(), swift_bridge::ns_error::NsError> {
+                    let val = unsafe { __swift_bridge__save(swift_bridge::string::RustString(path).box_into_raw()) };
+                    if val.is_null() {
+                        std::result::Result::Ok(())
+                    } else {
+                        std::result::Result::Err(unsafe { *Box::from_raw(val) })
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$save"]
+                fn __swift_bridge__save(path: *mut swift_bridge::string::RustString) -> *mut swift_bridge::ns_error::NsError;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$save")
+func __swift_bridge__save (_ path: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer? {
+    { switch __swift_bridge__catchSwiftNSError({ try save(path: RustString(ptr: path)) }) { case .Ok: return nil case .Err(let err): return __swift_bridge__NSErrorIntoRust(err) } }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_catch_ns_errors() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
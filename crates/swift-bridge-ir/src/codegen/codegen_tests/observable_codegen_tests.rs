@@ -0,0 +1,182 @@
+//! See also: crates/swift-integration-tests/src/observable.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that an `observable` type gets an `ObservableObject` that publishes its Swift
+/// properties, and that Swift can subscribe to the type's change notifier.
+mod extern_rust_observable_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(observable, get(count: u32), set(count))]
+                    type Counter;
+
+                    /// The counter's name.
+                    #[swift_bridge(swift_property)]
+                    fn name(&self) -> String;
+                    #[swift_bridge(swift_property)]
+                    fn set_name(&mut self, name: String);
+
+                    fn increment(&mut self);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Counter$_observe"]
+            pub extern "C" fn __swift_bridge__Counter__observe(
+                this: *const super::Counter,
+                observer: *mut std::ffi::c_void,
+                on_change: extern "C" fn(*mut std::ffi::c_void),
+                free_observer: extern "C" fn(*mut std::ffi::c_void),
+            ) {
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "Counter");
+                swift_bridge::observable::Observable::change_notifier(
+                    unsafe { &*this }
+                ).subscribe(observer, on_change, free_observer)
+            }
+
+            #[export_name = "__swift_bridge__$Counter$_unobserve"]
+            pub extern "C" fn __swift_bridge__Counter__unobserve(
+                this: *const super::Counter,
+                observer: *mut std::ffi::c_void,
+            ) {
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "Counter");
+                swift_bridge::observable::Observable::change_notifier(
+                    unsafe { &*this }
+                ).unsubscribe(observer)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+public final class ObservableCounter: ObservableObject, __private__RefreshableObservableObject {
+    /// The Rust value whose properties get published.
+    public let value: Counter
+    private var isRefreshing = false
+    private var observer: UnsafeMutableRawPointer?
+
+    /// The counter's name.
+    @Published public var name: String {
+        didSet {
+            if !isRefreshing {
+                value.name = name.intoRustString()
+            }
+        }
+    }
+    @Published public var count: UInt32 {
+        didSet {
+            if !isRefreshing {
+                value.count = count
+            }
+        }
+    }
+
+    public init(_ value: Counter) {
+        self.value = value
+        self.name = value.name.toString()
+        self.count = value.count
+        observer = __private__ObservableObjectObserver.observe(self) {
+            __swift_bridge__$Counter$_observe(value.ptr, $0, $1, $2)
+        }
+    }
+
+    deinit {
+        __swift_bridge__$Counter$_unobserve(value.ptr, observer)
+    }
+
+    func refresh() {
+        isRefreshing = true
+        defer { isRefreshing = false }
+        name = value.name.toString()
+        count = value.count
+    }
+}
+"#,
+            "import Combine",
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void __swift_bridge__$Counter$_observe(void* self, void* observer, void (*on_change)(void*), void (*free_observer)(void*));",
+            "void __swift_bridge__$Counter$_unobserve(void* self, void* observer);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_observable_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that properties without a setter are published as read-only, and that types without
+/// the `observable` attribute don't get an `ObservableObject`.
+mod extern_rust_read_only_observable_property {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(observable, get(progress: f64))]
+                    type Download;
+
+                    #[swift_bridge(get(progress: f64))]
+                    type Upload;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsManyAndDoesNotContainMany {
+            contains: vec![quote! {
+                #[export_name = "__swift_bridge__$Download$_observe"]
+            }],
+            does_not_contain: vec![quote! {
+                #[export_name = "__swift_bridge__$Upload$_observe"]
+            }],
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "public final class ObservableDownload",
+            "@Published public private(set) var progress: Double",
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_read_only_observable_property() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -24,7 +24,9 @@ mod extern_rust_type {
             pub extern "C" fn __swift_bridge__SomeType__free (
                 this: *mut super::SomeType
             ) {
-                let this = unsafe { Box::from_raw(this) };
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
+                let this = unsafe { swift_bridge::leak_check::box_from_raw(this) };
                 drop(this);
             }
         })
@@ -43,6 +45,9 @@ public class SomeType: SomeTypeRefMut {
     deinit {
         if isOwned {
             __swift_bridge__$SomeType$_free(ptr)
+            #if DEBUG
+            ptr = __swift_bridge__freedPointer
+            #endif
         }
     }
 }
@@ -102,6 +107,8 @@ mod extern_rust_hashable_type {
         pub extern "C" fn __swift_bridge__HashableType__hash (
             this: *const super::HashableType,
         ) -> u64 {
+            #[cfg(debug_assertions)]
+            swift_bridge::pointer_support::assert_valid_pointer(this, "HashableType");
             use std::hash::{Hash, Hasher};
             use std::collections::hash_map::DefaultHasher;
             let mut s = DefaultHasher::new();
@@ -145,6 +152,73 @@ uint64_t __swift_bridge__$HashableType$_hash(void* self);
     }
 }
 
+/// Test code generation for an extern "Rust" type that is both Equatable and Hashable.
+/// Each helper function gets its own line in the C header.
+mod extern_rust_equatable_and_hashable_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Equatable, Hashable)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$_hash"]
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$_partial_eq"]
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeTypeRef: Equatable {
+    public static func == (lhs: SomeTypeRef, rhs: SomeTypeRef) -> Bool {
+        __swift_bridge__$SomeType$_partial_eq(lhs.ptr, rhs.ptr)
+    }
+}
+"#,
+            r#"
+extension SomeTypeRef: Hashable{
+    public func hash(into hasher: inout Hasher){
+        hasher.combine(__swift_bridge__$SomeType$_hash(self.ptr))
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint64_t __swift_bridge__$SomeType$_hash(void* self);
+bool __swift_bridge__$SomeType$_partial_eq(void* lhs, void* rhs);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_equatable_and_hashable_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for an extern "Rust" type that implements Equatable.
 mod extern_rust_equatable_type {
     use super::*;
@@ -167,6 +241,10 @@ mod extern_rust_equatable_type {
             lhs: *const super::EquatableType,
             rhs: *const super::EquatableType
         ) -> bool {
+            #[cfg(debug_assertions)]
+            swift_bridge::pointer_support::assert_valid_pointer(lhs, "EquatableType");
+            #[cfg(debug_assertions)]
+            swift_bridge::pointer_support::assert_valid_pointer(rhs, "EquatableType");
             unsafe { &*lhs == &*rhs }
         }
         })
@@ -177,7 +255,7 @@ mod extern_rust_equatable_type {
             r#"
 extension EquatableTypeRef: Equatable {
     public static func == (lhs: EquatableTypeRef, rhs: EquatableTypeRef) -> Bool {
-        __swift_bridge__$EquatableType$_partial_eq(rhs.ptr, lhs.ptr)
+        __swift_bridge__$EquatableType$_partial_eq(lhs.ptr, rhs.ptr)
     }
 }
 "#,
@@ -208,6 +286,249 @@ bool __swift_bridge__$EquatableType$_partial_eq(void* lhs, void* rhs);
     }
 }
 
+/// Test code generation for an extern "Rust" type that exposes its `PartialOrd` and `Add`
+/// implementations as Swift operators.
+mod extern_rust_comparable_and_add_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Comparable, Add)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+            #[export_name = "__swift_bridge__$SomeType$_partial_eq"]
+            pub extern "C" fn __swift_bridge__SomeType__partial_eq (
+                lhs: *const super::SomeType,
+                rhs: *const super::SomeType
+            ) -> bool {
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(lhs, "SomeType");
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(rhs, "SomeType");
+                unsafe { &*lhs == &*rhs }
+            }
+            },
+            quote! {
+            #[export_name = "__swift_bridge__$SomeType$_partial_lt"]
+            pub extern "C" fn __swift_bridge__SomeType__partial_lt (
+                lhs: *const super::SomeType,
+                rhs: *const super::SomeType
+            ) -> bool {
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(lhs, "SomeType");
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(rhs, "SomeType");
+                unsafe { &*lhs < &*rhs }
+            }
+            },
+            quote! {
+            #[export_name = "__swift_bridge__$SomeType$_add"]
+            pub extern "C" fn __swift_bridge__SomeType__add (
+                lhs: *const super::SomeType,
+                rhs: *const super::SomeType
+            ) -> *mut super::SomeType {
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(lhs, "SomeType");
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(rhs, "SomeType");
+                swift_bridge::leak_check::box_into_raw(unsafe { &*lhs + &*rhs })
+            }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeTypeRef: Comparable {
+    public static func < (lhs: SomeTypeRef, rhs: SomeTypeRef) -> Bool {
+        __swift_bridge__$SomeType$_partial_lt(lhs.ptr, rhs.ptr)
+    }
+}
+"#,
+            r#"
+extension SomeTypeRef {
+    public static func + (lhs: SomeTypeRef, rhs: SomeTypeRef) -> SomeType {
+        SomeType(ptr: __swift_bridge__$SomeType$_add(lhs.ptr, rhs.ptr))
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+bool __swift_bridge__$SomeType$_partial_eq(void* lhs, void* rhs);
+bool __swift_bridge__$SomeType$_partial_lt(void* lhs, void* rhs);
+void* __swift_bridge__$SomeType$_add(void* lhs, void* rhs);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_comparable_and_add_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that exposes its `Default` implementation as a
+/// parameterless Swift initializer.
+mod extern_rust_default_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Default)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+        #[export_name = "__swift_bridge__$SomeType$_default"]
+        pub extern "C" fn __swift_bridge__SomeType__default () -> *mut super::SomeType {
+            swift_bridge::leak_check::box_into_raw(<super::SomeType as Default>::default())
+        }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType {
+    public convenience init() {
+        self.init(ptr: __swift_bridge__$SomeType$_default())
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeType$_default(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_default_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that exposes its `Debug` and `Display`
+/// implementations to Swift.
+mod extern_rust_debug_and_display_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Debug, Display)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$_debug"]
+                pub extern "C" fn __swift_bridge__SomeType__debug (
+                    this: *const super::SomeType,
+                ) -> *mut swift_bridge::string::RustString {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
+                    swift_bridge::string::RustString(
+                        format!("{:?}", unsafe { &*this })
+                    ).box_into_raw()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$_display"]
+                pub extern "C" fn __swift_bridge__SomeType__display (
+                    this: *const super::SomeType,
+                ) -> *mut swift_bridge::string::RustString {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
+                    swift_bridge::string::RustString(
+                        format!("{}", unsafe { &*this })
+                    ).box_into_raw()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeTypeRef: CustomDebugStringConvertible {
+    public var debugDescription: String {
+        RustString(ptr: __swift_bridge__$SomeType$_debug(self.ptr)).toString()
+    }
+}
+"#,
+            r#"
+extension SomeTypeRef: CustomStringConvertible {
+    public var description: String {
+        RustString(ptr: __swift_bridge__$SomeType$_display(self.ptr)).toString()
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeType$_debug(void* self);
+void* __swift_bridge__$SomeType$_display(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_debug_and_display_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for an extern "Rust" type that implements Copy.
 mod extern_rust_copy_type {
     use super::*;
@@ -416,10 +737,10 @@ mod extern_swift_freestanding_fn_with_owned_opaque_rust_type_arg {
     fn expected_rust_tokens() -> ExpectedRustTokens {
         ExpectedRustTokens::Contains(quote! {
             pub fn some_function (arg: super::MyType) {
-                unsafe { __swift_bridge__some_function( Box::into_raw(Box::new({
+                unsafe { __swift_bridge__some_function( swift_bridge::leak_check::box_into_raw({
                     let val: super::MyType = arg;
                     val
-                })) as *mut super::MyType ) }
+                }) ) }
             }
 
             #[allow(improper_ctypes)]
@@ -456,3 +777,731 @@ typedef struct MyType MyType;
         .test();
     }
 }
+
+/// Test code generation for freestanding Swift function that takes a `&mut` opaque Rust type
+/// argument.
+mod extern_swift_freestanding_fn_with_ref_mut_opaque_rust_type_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    type MyType;
+                }
+
+                extern "Swift" {
+                    fn some_function (arg: &mut MyType);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function (arg: &mut super::MyType) {
+                unsafe { __swift_bridge__some_function(arg as *mut super::MyType) }
+            }
+        })
+    }
+
+    const EXPECTED_SWIFT: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) {
+    some_function(arg: MyTypeRefMut(ptr: arg))
+}
+"#,
+    );
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
+        r#"
+typedef struct MyType MyType;
+"#,
+    );
+
+    #[test]
+    fn extern_swift_freestanding_fn_with_ref_mut_opaque_rust_type_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: EXPECTED_SWIFT,
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that an opaque Rust type that uses `#[swift_bridge(swift_name = "...")]` gets its Swift
+/// classes generated under the Swift name while its FFI symbols keep using the Rust name.
+mod extern_rust_type_swift_name {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(swift_name = "UserSession")]
+                    type RustSession;
+
+                    fn make_session() -> RustSession;
+                    fn name(self: &RustSession) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$RustSession$_free"]
+            pub extern "C" fn __swift_bridge__RustSession__free (
+                this: *mut super::RustSession
+            ) {
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "RustSession");
+                let this = unsafe { swift_bridge::leak_check::box_from_raw(this) };
+                drop(this);
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func make_session() -> UserSession {
+    UserSession(ptr: __swift_bridge__$make_session())
+}
+"#,
+            r#"
+public class UserSession: UserSessionRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$RustSession$_free(ptr)
+            #if DEBUG
+            ptr = __swift_bridge__freedPointer
+            #endif
+        }
+    }
+}
+public class UserSessionRefMut: UserSessionRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+}
+public class UserSessionRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+}
+extension UserSessionRef {
+    public func name() -> UInt8 {
+        __swift_bridge__$RustSession$name(ptr)
+    }
+}
+extension UserSession: Vectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$Vec_RustSession$new()
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct RustSession RustSession;
+void __swift_bridge__$RustSession$_free(void* self);
+"#,
+            r#"
+void* __swift_bridge__$make_session(void);
+uint8_t __swift_bridge__$RustSession$name(void* self);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_type_swift_name() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the fields listed in an opaque Rust type's `get(...)` and `set(...)` attributes
+/// get getter and setter functions that are exposed as Swift computed properties.
+mod extern_rust_type_field_accessors {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(get(name: &str, age: u8), set(age))]
+                    type Person;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$Person$name"]
+                pub extern "C" fn __swift_bridge__Person_name(
+                    this: *mut super::Person
+                ) -> swift_bridge::string::RustStr {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "Person");
+                    swift_bridge::string::RustStr::from_str(&(unsafe { &*this }).name)
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Person$age"]
+                pub extern "C" fn __swift_bridge__Person_age(this: *mut super::Person) -> u8 {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "Person");
+                    (unsafe { &*this }).age
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Person$set_age"]
+                pub extern "C" fn __swift_bridge__Person_set_age(
+                    this: *mut super::Person,
+                    age: u8
+                ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "Person");
+                    (unsafe { &mut *this }).age = age
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public class PersonRefMut: PersonRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    public override var age: UInt8 {
+        get {
+            super.age
+        }
+        set(age) {
+            __swift_bridge__$Person$set_age(ptr, age)
+        }
+    }
+}
+public class PersonRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    public var name: RustStr {
+        __swift_bridge__$Person$name(ptr)
+    }
+
+    public var age: UInt8 {
+        __swift_bridge__$Person$age(ptr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+struct RustStr __swift_bridge__$Person$name(void* self);
+uint8_t __swift_bridge__$Person$age(void* self);
+void __swift_bridge__$Person$set_age(void* self, uint8_t age);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_type_field_accessors() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a setter for a field that does not have a getter is exposed as a Swift method,
+/// since Swift does not have set-only properties.
+mod extern_rust_type_field_setter_without_getter {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(set(age: u8))]
+                    type Person;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Person$set_age"]
+            pub extern "C" fn __swift_bridge__Person_set_age(
+                this: *mut super::Person,
+                age: u8
+            ) {
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "Person");
+                (unsafe { &mut *this }).age = age
+            }
+        })
+    }
+
+    const EXPECTED_SWIFT_CODE: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+extension PersonRefMut {
+    public func set_age(_ age: UInt8) {
+        __swift_bridge__$Person$set_age(ptr, age)
+    }
+}
+"#,
+    );
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
+        r#"
+void __swift_bridge__$Person$set_age(void* self, uint8_t age);
+"#,
+    );
+
+    #[test]
+    fn extern_rust_type_field_setter_without_getter() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: EXPECTED_SWIFT_CODE,
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that an opaque Rust type annotated with `Identifiable` uses its generated `id`
+/// property to conform to Swift's `Identifiable` protocol.
+mod extern_rust_type_identifiable {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Identifiable, get(id: u64))]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    const EXPECTED_SWIFT_CODE: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+public class SomeTypeRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    public var id: UInt64 {
+        __swift_bridge__$SomeType$id(ptr)
+    }
+}
+extension SomeTypeRef: Identifiable {}
+"#,
+    );
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::SkipTest;
+
+    #[test]
+    fn extern_rust_type_identifiable() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: EXPECTED_SWIFT_CODE,
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that an opaque Rust type annotated with `Sendable` conforms its Swift classes to
+/// `Sendable`, and that the Rust side checks that the type is `Send + Sync`.
+mod extern_rust_type_sendable {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Sendable)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            const _: () = {
+                fn _assert_send_sync() {
+                    swift_bridge::sendable_support::assert_send_sync::<super::SomeType>();
+                }
+            };
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef: @unchecked Sendable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_type_sendable() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an opaque Rust type without the `Sendable` attribute does not conform to
+/// `Sendable`.
+mod extern_rust_type_not_sendable {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            swift_bridge::sendable_support::assert_send_sync
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("Sendable")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_type_not_sendable() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the `drop_on = "main"` attribute frees the Rust value on the main thread, hopping
+/// to the main queue when the Swift class is deinitialized on another thread.
+mod extern_rust_type_drop_on_main {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(drop_on = "main")]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+import Foundation
+
+public class SomeType: SomeTypeRefMut {
+"#,
+            r#"
+    deinit {
+        if isOwned {
+            if Thread.isMainThread {
+                __swift_bridge__$SomeType$_free(ptr)
+            } else {
+                DispatchQueue.main.async { [ptr = self.ptr] in __swift_bridge__$SomeType$_free(ptr) }
+            }
+            #if DEBUG
+            ptr = __swift_bridge__freedPointer
+            #endif
+        }
+    }
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_type_drop_on_main() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `drop_on` attribute dispatches the free to a custom queue.
+mod extern_rust_type_drop_on_custom_queue {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(drop_on = "Renderer.queue")]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    deinit {
+        if isOwned {
+            Renderer.queue.async { [ptr = self.ptr] in __swift_bridge__$SomeType$_free(ptr) }
+            #if DEBUG
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_type_drop_on_custom_queue() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `available` attribute marks every top-level declaration that is generated
+/// for an opaque Rust type, since Swift rejects extensions of a type that is not available on
+/// every deployment target.
+mod extern_rust_type_available_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(available = "iOS 16.0")]
+                    type SomeType;
+
+                    fn some_method(&self);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@available(iOS 16.0, *)
+public class SomeType: SomeTypeRefMut {
+"#,
+            r#"
+@available(iOS 16.0, *)
+public class SomeTypeRefMut: SomeTypeRef {
+"#,
+            r#"
+@available(iOS 16.0, *)
+public class SomeTypeRef {
+"#,
+            r#"
+@available(iOS 16.0, *)
+extension SomeTypeRef {
+    public func some_method() {
+"#,
+            r#"
+@available(iOS 16.0, *)
+extension SomeType: Vectorizable {
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_type_available_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `swift_access` attribute sets the access level of all of the Swift
+/// declarations that are generated for the type.
+mod extern_rust_type_swift_access_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(swift_access = "internal")]
+                    type SomeType;
+
+                    fn some_method(&self);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "internal class SomeType: SomeTypeRefMut {",
+            "internal class SomeTypeRefMut: SomeTypeRef {",
+            r#"
+internal class SomeTypeRef {
+    var ptr: UnsafeMutableRawPointer
+
+    internal init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+}
+extension SomeTypeRef {
+    internal func some_method() {
+        __swift_bridge__$SomeType$some_method(ptr)
+    }
+}
+"#,
+            r#"
+extension SomeType: Vectorizable {
+    internal static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_type_swift_access_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that declarations without a `swift_access` attribute use the default Swift access
+/// level, while declarations with one keep it.
+mod extern_rust_type_default_swift_access {
+    use super::*;
+    use crate::codegen::codegen_tests::BridgeModule;
+
+    fn bridge_module() -> BridgeModule {
+        let tokens = quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(swift_access = "public")]
+                    type PublicType;
+
+                    fn some_function();
+
+                    #[swift_bridge(swift_access = "public")]
+                    fn public_function();
+                }
+            }
+        };
+        BridgeModule {
+            tokens,
+            enabled_crate_features: vec![],
+            enabled_cfgs: vec![],
+            default_swift_access: Some("internal"),
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "internal struct SomeStruct {",
+            "internal class SomeType: SomeTypeRefMut {",
+            "public class PublicType: PublicTypeRefMut {",
+            "internal func some_function() {",
+            "public func public_function() {",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_type_default_swift_access() {
+        CodegenTest {
+            bridge_module: bridge_module(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
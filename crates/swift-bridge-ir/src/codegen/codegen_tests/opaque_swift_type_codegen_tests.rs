@@ -24,7 +24,7 @@ mod extern_swift_freestanding_fn_with_owned_opaque_swift_type_arg {
             }
 
             #[repr(C)]
-            pub struct MyType(*mut std::ffi::c_void);
+            pub struct MyType(pub(crate) *mut std::ffi::c_void);
 
             impl Drop for MyType {
                 fn drop (&mut self) {
@@ -65,3 +65,236 @@ func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) {
         .test();
     }
 }
+
+/// Verify that Rust lends an opaque Swift type to a Swift function without giving up ownership
+/// when the argument is a `&mut` reference.
+mod extern_swift_freestanding_fn_with_ref_mut_opaque_swift_type_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    type MyType;
+                    fn some_function (arg: &mut MyType);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function (arg: &mut MyType) {
+                    unsafe { __swift_bridge__some_function (swift_bridge::PointerToSwiftType(arg.0)) }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function (arg: swift_bridge::PointerToSwiftType);
+            },
+        ])
+    }
+
+    const EXPECTED_SWIFT_CODE: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) {
+    some_function(arg: Unmanaged<MyType>.fromOpaque(arg).takeUnretainedValue())
+}
+"#,
+    );
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(r#""#);
+
+    #[test]
+    fn extern_swift_freestanding_fn_with_ref_mut_opaque_swift_type_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: EXPECTED_SWIFT_CODE,
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that Swift lends an opaque Swift type to a Rust function without giving up ownership
+/// when the argument is a `&mut` reference.
+mod extern_rust_fn_with_ref_mut_opaque_swift_type_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: &mut MyType);
+                }
+
+                extern "Swift" {
+                    type MyType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function (arg: swift_bridge::PointerToSwiftType) {
+                super::some_function(&mut *std::mem::ManuallyDrop::new(MyType(arg.0)))
+            }
+        })
+    }
+
+    const EXPECTED_SWIFT_CODE: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+public func some_function(_ arg: MyType) {
+    __swift_bridge__$some_function(Unmanaged.passUnretained(arg).toOpaque())
+}
+"#,
+    );
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
+        r#"
+void __swift_bridge__$some_function(void* arg);
+"#,
+    );
+
+    #[test]
+    fn extern_rust_fn_with_ref_mut_opaque_swift_type_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: EXPECTED_SWIFT_CODE,
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that an opaque Swift type that uses `#[swift_bridge(swift_name = "...")]` refers to the
+/// Swift class by its Swift name while its FFI symbols keep using the Rust name.
+mod extern_swift_type_swift_name {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    #[swift_bridge(swift_name = "SwiftCallbackHandler")]
+                    type CallbackHandler;
+
+                    #[swift_bridge(init)]
+                    fn new() -> CallbackHandler;
+                    fn handle(&self, value: u8);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[link_name = "__swift_bridge__$CallbackHandler$_free"]
+            fn __swift_bridge__CallbackHandler__free (this: *mut std::ffi::c_void);
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_cdecl("__swift_bridge__$CallbackHandler$new")
+func __swift_bridge__CallbackHandler_new () -> UnsafeMutableRawPointer {
+    Unmanaged.passRetained(SwiftCallbackHandler()).toOpaque()
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$CallbackHandler$handle")
+func __swift_bridge__CallbackHandler_handle (_ this: UnsafeMutableRawPointer, _ value: UInt8) {
+    Unmanaged<SwiftCallbackHandler>.fromOpaque(this).takeUnretainedValue().handle(value: value)
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$CallbackHandler$_free")
+func __swift_bridge__CallbackHandler__free (ptr: UnsafeMutableRawPointer) {
+    let _ = Unmanaged<SwiftCallbackHandler>.fromOpaque(ptr).takeRetainedValue()
+}
+"#,
+        ])
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(r#""#);
+
+    #[test]
+    fn extern_swift_type_swift_name() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that a failable Swift `init?` with a labeled parameter becomes a Rust associated
+/// function that returns `Option<Self>`.
+mod extern_swift_type_failable_labeled_init {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    type MyType;
+
+                    #[swift_bridge(init)]
+                    fn new(#[swift_bridge(label = "validating")] value: u8) -> Option<MyType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                impl MyType {
+                    pub fn new (value: u8) -> Option<MyType> {
+                        {
+                            let val = unsafe { __swift_bridge__MyType_new(value) };
+                            if val.is_null() {
+                                None
+                            } else {
+                                Some(MyType(val))
+                            }
+                        }
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$MyType$new"]
+                fn __swift_bridge__MyType_new (value: u8) -> *mut std::ffi::c_void;
+            },
+        ])
+    }
+
+    const EXPECTED_SWIFT_CODE: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+@_cdecl("__swift_bridge__$MyType$new")
+func __swift_bridge__MyType_new (validating value: UInt8) -> UnsafeMutableRawPointer? {
+    { if let val = MyType(validating: value) { return Unmanaged.passRetained(val).toOpaque() } else { return nil } }()
+}
+"#,
+    );
+
+    #[test]
+    fn extern_swift_type_failable_labeled_init() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: EXPECTED_SWIFT_CODE,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -25,20 +25,9 @@ mod extern_rust_fn_option_primitive {
             pub extern "C" fn __swift_bridge__some_function(
                 arg: swift_bridge::option::OptionU8
             ) -> swift_bridge::option::OptionF32 {
-                if let Some(val) = super::some_function(
-                    {
-                        let val = arg;
-                        if val.is_some {
-                            Some(val.val)
-                        } else {
-                            None
-                        }
-                    }
-                ) {
-                    swift_bridge::option::OptionF32 { val, is_some: true}
-                } else {
-                    swift_bridge::option::OptionF32 { val: 123.4, is_some: false}
-                }
+                swift_bridge::option::OptionF32::from_option(super::some_function(
+                    arg.into_option()
+                ))
             }
         })
     }
@@ -90,22 +79,11 @@ mod extern_swift_fn_option_primitive {
         ExpectedRustTokens::ContainsMany(vec![
             quote! {
                 pub fn some_function(arg: Option<u8>) -> Option<f32> {
-                    {
-                        let val = unsafe {
-                            __swift_bridge__some_function(
-                                if let Some(val) = arg {
-                                   swift_bridge::option::OptionU8 { val, is_some: true }
-                                } else {
-                                   swift_bridge::option::OptionU8 { val: 123, is_some: false }
-                                }
-                            )
-                        };
-                        if val.is_some {
-                            Some(val.val)
-                        } else {
-                            None
-                        }
-                    }
+                    unsafe {
+                        __swift_bridge__some_function(
+                            swift_bridge::option::OptionU8::from_option(arg)
+                        )
+                    }.into_option()
                 }
             },
             quote! {
@@ -161,17 +139,9 @@ mod extern_rust_fn_option_vector_primitive {
             pub extern "C" fn __swift_bridge__some_function(
                 arg: *mut Vec<i32>
             ) -> *mut Vec<u32> {
-                if let Some(value) = super::some_function(
-                    if arg.is_null() {
-                        None
-                    } else {
-                        Some( unsafe { * Box::from_raw(arg) } )
-                    }
-                ) {
-                    Box::into_raw(Box::new(value))
-                } else {
-                    std::ptr::null_mut()
-                }
+                swift_bridge::option::box_into_raw_or_null(super::some_function(
+                    unsafe { swift_bridge::option::unbox_raw_or_null(arg) }
+                ))
             }
         })
     }
@@ -224,17 +194,9 @@ mod extern_rust_fn_option_string {
             pub extern "C" fn __swift_bridge__some_function(
                 arg: *mut swift_bridge::string::RustString
             ) -> *mut swift_bridge::string::RustString {
-                if let Some(val) = super::some_function(
-                    if arg.is_null() {
-                        None
-                    } else {
-                        Some(unsafe { Box::from_raw(arg).0 })
-                    }
-                ) {
-                    swift_bridge::string::RustString(val).box_into_raw()
-                } else {
-                    std::ptr::null::<swift_bridge::string::RustString>() as *mut swift_bridge::string::RustString
-                }
+                swift_bridge::string::RustString::option_box_into_raw(super::some_function(
+                    unsafe { swift_bridge::string::RustString::option_from_raw(arg) }
+                ))
             }
         })
     }
@@ -275,7 +237,7 @@ mod extern_rust_fn_return_option_str {
         quote! {
             mod ffi {
                 extern "Rust" {
-                    fn some_function () -> Option<&str>;
+                    fn some_function () -> Option<&'static str>;
                     fn another_function () -> Option<&'static str>;
                 }
             }
@@ -286,21 +248,13 @@ mod extern_rust_fn_return_option_str {
         let no_lifetime = quote! {
             #[export_name = "__swift_bridge__$some_function"]
             pub extern "C" fn __swift_bridge__some_function() -> swift_bridge::string::RustStr {
-                if let Some(val) = super::some_function() {
-                    swift_bridge::string::RustStr::from_str(val)
-                } else {
-                    swift_bridge::string::RustStr { start: std::ptr::null::<u8>(), len: 0}
-                }
+                swift_bridge::string::RustStr::from_option_str(super::some_function())
             }
         };
         let lifetime = quote! {
             #[export_name = "__swift_bridge__$another_function"]
             pub extern "C" fn __swift_bridge__another_function() -> swift_bridge::string::RustStr {
-                if let Some(val) = super::another_function() {
-                    swift_bridge::string::RustStr::from_str(val)
-                } else {
-                    swift_bridge::string::RustStr { start: std::ptr::null::<u8>(), len: 0}
-                }
+                swift_bridge::string::RustStr::from_option_str(super::another_function())
             }
         };
 
@@ -362,11 +316,7 @@ mod extern_rust_fn_arg_option_str {
                arg: swift_bridge::string::RustStr
             ) {
                 super::some_function(
-                    if arg.start.is_null() {
-                        None
-                    } else {
-                        Some(arg.to_str())
-                    }
+                    arg.to_option_str()
                 )
             }
         })
@@ -421,11 +371,7 @@ mod extern_rust_fn_return_option_opaque_rust_type {
         ExpectedRustTokens::Contains(quote! {
             #[export_name = "__swift_bridge__$some_function"]
             pub extern "C" fn __swift_bridge__some_function() -> *mut super::SomeType {
-                if let Some(val) = super::some_function() {
-                    Box::into_raw(Box::new(val))
-                } else {
-                    std::ptr::null_mut()
-                }
+                if let Some(val) = super::some_function() { swift_bridge::leak_check::box_into_raw(val) } else { std::ptr::null_mut() }
             }
         })
     }
@@ -469,7 +415,7 @@ mod extern_rust_fn_return_option_ref_opaque_rust_type {
             mod ffi {
                 extern "Rust" {
                     type SomeType;
-                    fn some_function () -> Option<&SomeType>;
+                    fn some_function () -> Option<&'static SomeType>;
                 }
             }
         }
@@ -538,10 +484,13 @@ mod extern_rust_fn_arg_option_ref_opaque_rust_type {
             #[export_name = "__swift_bridge__$some_function"]
             pub extern "C" fn __swift_bridge__some_function(arg: *const super::SomeType) {
                 super::some_function(
-                    if arg.is_null() {
-                        None
-                    } else {
-                        Some( unsafe { & * arg })
+                    {
+                        let val = arg;
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some( unsafe { & * val })
+                        }
                     }
                 )
             }
@@ -578,6 +527,123 @@ void __swift_bridge__$some_function(void* arg);
     }
 }
 
+/// Test code generation for a Swift function that accepts and returns an Option<&str>.
+/// We verify that the result of the Swift call is only evaluated once.
+mod extern_swift_fn_option_str {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function (arg: Option<&str>) -> Option<&str>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: Option<&str>) -> Option<&str> {
+                unsafe {
+                    __swift_bridge__some_function(
+                        swift_bridge::string::RustStr::from_option_str(arg)
+                    )
+                }.to_option_str()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: RustStr) -> RustStr {
+    optionalRustStrToRustStr(some_function(arg: { let val = arg; if val.start != nil { return val; } else { return nil; } }()), { $0 })
+}
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(r#""#);
+
+    #[test]
+    fn extern_swift_fn_option_str() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Swift function that accepts and returns an Option<&OpaqueRustType>.
+/// We verify that the result of the Swift call is only evaluated once.
+mod extern_swift_fn_option_ref_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                }
+
+                extern "Swift" {
+                    fn some_function (arg: Option<&SomeType>) -> Option<&SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: Option<&super::SomeType>) -> Option<&super::SomeType> {
+                {
+                    let val = unsafe {
+                        __swift_bridge__some_function(
+                            if let Some(val) = arg {
+                                val as *const super::SomeType
+                            } else {
+                                std::ptr::null()
+                            }
+                        )
+                    };
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some(unsafe { & * val })
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer?) -> UnsafeMutableRawPointer? {
+    { if let val = some_function(arg: { let val = arg; if val != nil { return SomeTypeRef(ptr: val!) } else { return nil } }()) { return val.ptr } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_option_ref_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that takes an Option<OpaqueRustType> argument.
 mod extern_rust_fn_with_option_opaque_rust_type_arg {
     use super::*;
@@ -600,11 +666,7 @@ mod extern_rust_fn_with_option_opaque_rust_type_arg {
                 arg: *mut super::SomeType
             ) {
                 super::some_function(
-                    if arg.is_null() {
-                        None
-                    } else {
-                        Some( unsafe { * Box::from_raw(arg) } )
-                    }
+                    { let val = arg; if val.is_null() { None } else { Some(unsafe { * swift_bridge::leak_check::box_from_raw(val) }) } }
                 )
             }
         })
@@ -662,11 +724,7 @@ mod extern_rust_fn_return_option_generic_opaque_rust_type {
         ExpectedRustTokens::Contains(quote! {
             #[export_name = "__swift_bridge__$some_function"]
             pub extern "C" fn __swift_bridge__some_function() -> *mut super::SomeType<u32> {
-                if let Some(val) = super::some_function() {
-                    Box::into_raw(Box::new(val))
-                } else {
-                    std::ptr::null_mut()
-                }
+                if let Some(val) = super::some_function() { swift_bridge::leak_check::box_into_raw(val) } else { std::ptr::null_mut() }
             }
         })
     }
@@ -726,11 +784,7 @@ mod extern_rust_fn_with_option_generic_opaque_rust_type_arg {
                 arg: *mut super::SomeType<u32>
             ) {
                 super::some_function(
-                    if arg.is_null() {
-                        None
-                    } else {
-                        Some( unsafe { * Box::from_raw(arg) } )
-                    }
+                    { let val = arg; if val.is_null() { None } else { Some(unsafe { * swift_bridge::leak_check::box_from_raw(val) }) } }
                 )
             }
         })
@@ -854,10 +908,13 @@ mod extern_rust_fn_with_option_opaque_copy_rust_type_arg {
                 arg: __swift_bridge__Option_SomeType
             ) {
                 super::some_function(
-                    if arg.is_some {
-                        Some( unsafe { arg.val.assume_init() }.into_rust_repr() )
-                    } else {
-                        None
+                    {
+                        let val = arg;
+                        if val.is_some {
+                            Some( unsafe { val.val.assume_init() }.into_rust_repr() )
+                        } else {
+                            None
+                        }
                     }
                 )
             }
@@ -990,10 +1047,13 @@ mod extern_rust_fn_with_option_generic_opaque_copy_rust_type_arg {
                 arg: __swift_bridge__Option_SomeType_u32
             ) {
                 super::some_function(
-                    if arg.is_some {
-                        Some( unsafe { arg.val.assume_init() }.into_rust_repr() )
-                    } else {
-                        None
+                    {
+                        let val = arg;
+                        if val.is_some {
+                            Some( unsafe { val.val.assume_init() }.into_rust_repr() )
+                        } else {
+                            None
+                        }
                     }
                 )
             }
@@ -1059,17 +1119,7 @@ mod shared_struct_with_option_field_ffi_repr {
                         {
                             let val = self;
                             __swift_bridge__SomeStruct {
-                                field: if let Some(val) = val.field {
-                                    swift_bridge::option::OptionU8 {
-                                        val,
-                                        is_some: true
-                                    }
-                                } else {
-                                    swift_bridge::option::OptionU8 {
-                                        val: 123,
-                                        is_some: false
-                                    }
-                                }
+                                field: swift_bridge::option::OptionU8::from_option(val.field)
                             }
                         }
                     }
@@ -1083,14 +1133,7 @@ mod shared_struct_with_option_field_ffi_repr {
                          {
                             let val = self;
                             SomeStruct {
-                                field: {
-                                    let val = val.field;
-                                    if val.is_some {
-                                        Some(val.val)
-                                    } else {
-                                        None
-                                    }
-                                }
+                                field: val.field.into_option()
                             }
                         }
                     }
@@ -1143,3 +1186,70 @@ typedef struct __swift_bridge__$SomeStruct { struct __private__OptionU8 field; }
         .test();
     }
 }
+
+/// Verify that a shared struct field of type `Option<T>`, where T is an opaque Rust type, is
+/// represented as a nullable pointer instead of an `is_some` + value wrapper.
+mod shared_struct_with_option_opaque_rust_type_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: Option<SomeType>,
+                }
+
+                extern "Rust" {
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__SomeStruct {
+                    field: *mut super::SomeType
+                }
+            },
+            quote! {
+                field: if let Some(val) = val.field { swift_bridge::leak_check::box_into_raw(val) } else { std::ptr::null_mut() }
+            },
+            quote! {
+                field: { let val = val.field; if val.is_null() { None } else { Some(unsafe { * swift_bridge::leak_check::box_from_raw(val) }) } }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+__swift_bridge__$SomeStruct(field: { if let val = val.field { val.isOwned = false; return val.ptr } else { return nil } }())
+"#,
+            r#"
+SomeStruct(field: { let val = val.field; if val != nil { return SomeType(ptr: val!) } else { return nil } }())
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { void* field; } __swift_bridge__$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_with_option_opaque_rust_type_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,184 @@
+//! See also: crates/swift-integration-tests/src/path.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that PathBuf and &Path are seen by Swift as a String when calling Rust functions.
+mod extern_rust_fn_path {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg1: PathBuf, arg2: &std::path::Path) -> PathBuf;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg1: *mut swift_bridge::string::RustString,
+                arg2: *mut swift_bridge::string::RustString
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(
+                    super::some_function(
+                        std::path::PathBuf::from(unsafe { Box::from_raw(arg1).0 }),
+                        &std::path::PathBuf::from(unsafe { Box::from_raw(arg2).0 })
+                    )
+                    .into_os_string()
+                    .into_string()
+                    .unwrap_or_else(|path| path.to_string_lossy().into_owned())
+                )
+                .box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg1: String, _ arg2: String) -> String {
+    RustString(ptr: __swift_bridge__$some_function({ let rustString = arg1.intoRustString(); rustString.isOwned = false; return rustString.ptr }(), { let rustString = arg2.intoRustString(); rustString.isOwned = false; return rustString.ptr }())).toString()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg1, void* arg2);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_path() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we lossily convert a returned &Path to a String, since it might not be UTF-8.
+mod extern_rust_method_return_ref_path {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_method(&self) -> &Path;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$some_method"]
+            pub extern "C" fn __swift_bridge__SomeType_some_method(
+                this: *mut super::SomeType
+            ) -> *mut swift_bridge::string::RustString {
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
+                swift_bridge::string::RustString(
+                    (unsafe { &*this }).some_method().to_string_lossy().into_owned()
+                )
+                .box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func some_method() -> String {
+        RustString(ptr: __swift_bridge__$SomeType$some_method(ptr)).toString()
+    }
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_return_ref_path() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass paths to and from Swift functions.
+mod extern_swift_fn_path {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: &Path) -> PathBuf;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: &std::path::Path) -> std::path::PathBuf {
+                    std::path::PathBuf::from(unsafe {
+                        Box::from_raw(unsafe {
+                            __swift_bridge__some_function(
+                                swift_bridge::string::RustString(arg.to_string_lossy().into_owned())
+                                    .box_into_raw()
+                            )
+                        })
+                        .0
+                    })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: *mut swift_bridge::string::RustString
+                ) -> *mut swift_bridge::string::RustString;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+    { let rustString = some_function(arg: RustString(ptr: arg).toString()).intoRustString(); rustString.isOwned = false; return rustString.ptr }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_path() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
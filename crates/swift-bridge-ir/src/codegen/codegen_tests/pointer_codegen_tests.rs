@@ -0,0 +1,110 @@
+//! See also: crates/swift-integration-tests/src/pointer.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a pointee that is not fully qualified gets resolved relative to the parent module.
+mod extern_rust_fn_c_void_pointer {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(unsafe_pointer)]
+                    fn some_function(arg: *const c_void) -> *mut u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *const super::c_void) -> *mut u8 {
+                super::some_function(arg)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: UnsafeRawPointer) -> UnsafeMutablePointer<UInt8> {
+    __swift_bridge__$some_function(UnsafeMutableRawPointer(mutating: arg))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+uint8_t* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_c_void_pointer() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not prefix a fully qualified pointee such as `std::ffi::c_void` with
+/// `super::`.
+mod fully_qualified_pointee {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(unsafe_pointer)]
+                    fn some_function(arg: *mut std::ffi::c_void);
+                }
+                extern "Swift" {
+                    #[swift_bridge(unsafe_pointer)]
+                    fn another_function(arg: *const core::ffi::c_void);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(arg: *mut std::ffi::c_void) {
+                    super::some_function(arg)
+                }
+            },
+            quote! {
+                pub fn another_function(arg: *const core::ffi::c_void) {
+                    unsafe { __swift_bridge__another_function(arg) }
+                }
+            },
+            quote! {
+                fn __swift_bridge__another_function(arg: *const core::ffi::c_void);
+            },
+        ])
+    }
+
+    #[test]
+    fn fully_qualified_pointee() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
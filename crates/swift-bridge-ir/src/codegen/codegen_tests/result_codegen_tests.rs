@@ -89,9 +89,9 @@ mod extern_rust_fn_arg_result_opaque_rust {
             ) {
                 super::some_function(
                     if arg.is_ok {
-                        std::result::Result::Ok(unsafe { *Box::from_raw(arg.ok_or_err as *mut super::SomeType) })
+                        std::result::Result::Ok(unsafe { *swift_bridge::leak_check::box_from_raw(arg.ok_or_err as *mut super::SomeType) })
                     } else {
-                        std::result::Result::Err(unsafe { *Box::from_raw(arg.ok_or_err as *mut super::SomeType) })
+                        std::result::Result::Err(unsafe { *swift_bridge::leak_check::box_from_raw(arg.ok_or_err as *mut super::SomeType) })
                     }
                 )
             }
@@ -151,19 +151,19 @@ mod extern_rust_fn_return_result_opaque_rust {
                     Ok(ok) => {
                         swift_bridge::result::ResultPtrAndPtr {
                             is_ok: true,
-                            ok_or_err: Box::into_raw(Box::new({
+                            ok_or_err: swift_bridge::leak_check::box_into_raw({
                                 let val: super::SomeType = ok;
                                 val
-                            })) as *mut super::SomeType as *mut std::ffi::c_void
+                            }) as *mut std::ffi::c_void
                         }
                     }
                     Err(err) => {
                         swift_bridge::result::ResultPtrAndPtr {
                             is_ok: false,
-                            ok_or_err: Box::into_raw(Box::new({
+                            ok_or_err: swift_bridge::leak_check::box_into_raw({
                                 let val: super::SomeType = err;
                                 val
-                            })) as *mut super::SomeType as *mut std::ffi::c_void
+                            }) as *mut std::ffi::c_void
                         }
                     }
                 }
@@ -287,10 +287,10 @@ mod extern_rust_fn_return_result_null_and_opaque_rust {
             pub extern "C" fn __swift_bridge__some_function() -> *mut super::SomeType {
                 match super::some_function() {
                     Ok(ok) => std::ptr::null_mut(),
-                    Err(err) => Box::into_raw(Box::new({
+                    Err(err) => swift_bridge::leak_check::box_into_raw({
                         let val: super::SomeType = err;
                         val
-                    })) as *mut super::SomeType
+                    })
                 }
             }
         })
@@ -348,10 +348,10 @@ mod extern_rust_fn_return_result_unit_and_opaque_rust {
             pub extern "C" fn __swift_bridge__some_function() -> *mut super::SomeType {
                 match super::some_function() {
                     Ok(ok) => std::ptr::null_mut(),
-                    Err(err) => Box::into_raw(Box::new({
+                    Err(err) => swift_bridge::leak_check::box_into_raw({
                         let val: super::SomeType = err;
                         val
-                    })) as *mut super::SomeType
+                    })
                 }
             }
         })
@@ -385,6 +385,78 @@ void* __swift_bridge__$some_function(void);
     }
 }
 
+/// Test code generation for Rust function that returns a Result<T, E> where T is a primitive and
+/// E is an opaque Rust type.
+mod extern_rust_fn_return_result_primitive_and_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeErrType;
+                }
+                extern "Rust" {
+                    fn some_function() -> Result<u32, SomeErrType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[repr(C)]
+            pub enum ResultU32AndSomeErrType{
+                Ok(u32),
+                Err(*mut super::SomeErrType),
+            }
+
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function() -> ResultU32AndSomeErrType{
+                match super::some_function() {
+                    Ok(ok) => ResultU32AndSomeErrType::Ok(ok),
+                    Err(err) => ResultU32AndSomeErrType::Err(swift_bridge::leak_check::box_into_raw({
+                        let val: super::SomeErrType = err;
+                        val
+                    })),
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() throws -> UInt32 {
+    try { let val = __swift_bridge__$some_function(); switch val.tag { case __swift_bridge__$ResultU32AndSomeErrType$ResultOk: return val.payload.ok case __swift_bridge__$ResultU32AndSomeErrType$ResultErr: throw SomeErrType(ptr: val.payload.err) default: fatalError() } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef enum __swift_bridge__$ResultU32AndSomeErrType$Tag {__swift_bridge__$ResultU32AndSomeErrType$ResultOk, __swift_bridge__$ResultU32AndSomeErrType$ResultErr} __swift_bridge__$ResultU32AndSomeErrType$Tag;
+union __swift_bridge__$ResultU32AndSomeErrType$Fields {uint32_t ok; void* err;};
+typedef struct __swift_bridge__$ResultU32AndSomeErrType{__swift_bridge__$ResultU32AndSomeErrType$Tag tag; union __swift_bridge__$ResultU32AndSomeErrType$Fields payload;} __swift_bridge__$ResultU32AndSomeErrType;
+"#,
+            r#"struct __swift_bridge__$ResultU32AndSomeErrType __swift_bridge__$some_function(void)"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_primitive_and_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that returns a Result<T, E> where T is a opaque Rust type and
 /// E is a transparent enum type.
 mod extern_rust_fn_return_result_opaque_rust_type_and_transparent_enum_type {
@@ -419,10 +491,10 @@ mod extern_rust_fn_return_result_opaque_rust_type_and_transparent_enum_type {
             #[export_name = "__swift_bridge__$some_function"]
             pub extern "C" fn __swift_bridge__some_function() -> ResultSomeOkTypeAndSomeErrEnum{
                 match super::some_function() {
-                    Ok(ok) => ResultSomeOkTypeAndSomeErrEnum::Ok(Box::into_raw(Box::new({
+                    Ok(ok) => ResultSomeOkTypeAndSomeErrEnum::Ok(swift_bridge::leak_check::box_into_raw({
                         let val: super::SomeOkType = ok;
                         val
-                    })) as *mut super::SomeOkType),
+                    })),
                     Err(err) => ResultSomeOkTypeAndSomeErrEnum::Err(err.into_ffi_repr()),
                 }
             }
@@ -497,10 +569,10 @@ mod extern_rust_fn_return_result_transparent_enum_type_and_opaque_rust_type {
             pub extern "C" fn __swift_bridge__some_function() -> ResultSomeOkEnumAndSomeErrType{
                 match super::some_function() {
                     Ok(ok) => ResultSomeOkEnumAndSomeErrType::Ok(ok.into_ffi_repr()),
-                    Err(err) => ResultSomeOkEnumAndSomeErrType::Err(Box::into_raw(Box::new({
+                    Err(err) => ResultSomeOkEnumAndSomeErrType::Err(swift_bridge::leak_check::box_into_raw({
                         let val: super::SomeErrType = err;
                         val
-                    })) as *mut super::SomeErrType),
+                    })),
                 }
             }
         })
@@ -687,3 +759,85 @@ typedef struct __swift_bridge__$ResultTupleI32U32AndSomeErrEnum{__swift_bridge__
         .test();
     }
 }
+
+/// Test code generation for a Swift function that uses the `catch_errors` attribute to return the
+/// errors that it throws to Rust.
+mod extern_swift_fn_catch_errors {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(catch_errors)]
+                    fn load(path: String) -> Result<String, String>;
+                    #[swift_bridge(catch_errors)]
+                    fn save(path: String) -> Result<(), String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn load(path: String) -> Result<String, String> {
+                    let val = unsafe { __swift_bridge__load(swift_bridge::string::RustString(path).box_into_raw()) };
+                    if val.is_ok {
+                        std::result::Result::Ok(unsafe { Box::from_raw(val.ok_or_err as *mut swift_bridge::string::RustString).0 })
+                    } else {
+                        std::result::Result::Err(unsafe { Box::from_raw(val.ok_or_err as *mut swift_bridge::string::RustString).0 })
+                    }
+                }
+            },
+            quote! {
+                pub fn save(path: String) -> Result<(), String> {
+                    let val = unsafe { __swift_bridge__save(swift_bridge::string::RustString(path).box_into_raw()) };
+                    if val.is_null() {
+                        std::result::Result::Ok(())
+                    } else {
+                        std::result::Result::Err(unsafe { Box::from_raw(val).0 })
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$load"]
+                fn __swift_bridge__load(path: *mut swift_bridge::string::RustString) -> swift_bridge::result::ResultPtrAndPtr;
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$save"]
+                fn __swift_bridge__save(path: *mut swift_bridge::string::RustString) -> *mut swift_bridge::string::RustString;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_cdecl("__swift_bridge__$load")
+func __swift_bridge__load (_ path: UnsafeMutableRawPointer) -> __private__ResultPtrAndPtr {
+    { switch __swift_bridge__catchSwiftError({ try load(path: RustString(ptr: path)) }) { case .Ok(let ok): return __private__ResultPtrAndPtr(is_ok: true, ok_or_err: { let rustString = ok.intoRustString(); rustString.isOwned = false; return rustString.ptr }()) case .Err(let err): return __private__ResultPtrAndPtr(is_ok: false, ok_or_err: { let rustString = err.intoRustString(); rustString.isOwned = false; return rustString.ptr }()) } }()
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$save")
+func __swift_bridge__save (_ path: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer? {
+    { switch __swift_bridge__catchSwiftError({ try save(path: RustString(ptr: path)) }) { case .Ok: return nil case .Err(let err): return { let rustString = err.intoRustString(); rustString.isOwned = false; return rustString.ptr }() } }()
+}
+"#,
+        ])
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::SkipTest;
+
+    #[test]
+    fn extern_swift_fn_catch_errors() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,153 @@
+//! See also: crates/swift-integration-tests/src/slice.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust method can return a slice of opaque Rust types, which Swift sees as a
+/// `RustSlice` of the opaque type.
+mod extern_rust_method_return_slice_of_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Item;
+                }
+                extern "Rust" {
+                    type SomeList;
+                    fn items(&self) -> &[Item];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__SomeList_items(
+                    this: *mut super::SomeList
+                ) -> swift_bridge::FfiSlice<super::Item> {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "SomeList");
+                    swift_bridge::FfiSlice::from_slice((unsafe { &*this }).items())
+                }
+            },
+            quote! {
+                const _: () = {
+                    #[export_name = "__swift_bridge__$Item$_slice_get"]
+                    #[doc(hidden)]
+                    pub extern "C" fn _slice_get(
+                        start: *const super::Item,
+                        index: usize
+                    ) -> *const super::Item {
+                        unsafe { start.add(index) }
+                    }
+                };
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeListRef {
+    public func items() -> RustSlice<Item> {
+        RustSlice(__swift_bridge__$SomeList$items(ptr))
+    }
+}
+"#,
+            r#"
+extension Item: RustSliceElement {
+    public static func sliceOfSelfGet(start: UnsafeMutableRawPointer, index: UInt) -> ItemRef {
+        ItemRef(ptr: __swift_bridge__$Item$_slice_get(start, index)!)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "struct __private__FfiSlice __swift_bridge__$SomeList$items(void* self);",
+            "void* __swift_bridge__$Item$_slice_get(void* start, uintptr_t index);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_method_return_slice_of_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a slice of opaque Rust types can be passed to Rust and Swift functions.
+mod fn_arg_slice_of_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Item;
+                    fn rust_function(items: &[Item]);
+                }
+                extern "Swift" {
+                    fn swift_function(items: &[Item]);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__rust_function(
+                    items: swift_bridge::FfiSlice<super::Item>
+                ) {
+                    super::rust_function(items.as_slice())
+                }
+            },
+            quote! {
+                pub fn swift_function(items: &[super::Item]) {
+                    unsafe {
+                        __swift_bridge__swift_function(swift_bridge::FfiSlice::from_slice(items))
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func rust_function(_ items: RustSlice<Item>) {
+    __swift_bridge__$rust_function(items.toFfiSlice())
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$swift_function")
+func __swift_bridge__swift_function (_ items: __private__FfiSlice) {
+    swift_function(items: RustSlice(items))
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn fn_arg_slice_of_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
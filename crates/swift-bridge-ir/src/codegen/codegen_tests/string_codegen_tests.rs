@@ -171,7 +171,7 @@ mod extern_rust_fn_return_str {
         quote! {
             mod foo {
                 extern "Rust" {
-                    fn some_function () -> &str;
+                    fn some_function () -> &'static str;
                 }
             }
         }
@@ -309,3 +309,196 @@ func __swift_bridge__some_function (_ value: UnsafeMutableRawPointer) -> UnsafeM
         .test();
     }
 }
+
+/// Verify that the `unchecked_utf8` attribute skips UTF-8 validation for `&str` and
+/// `Option<&str>` arguments.
+mod extern_rust_fn_unchecked_utf8_str_arguments {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(unchecked_utf8)]
+                    fn some_function (arg: &str, maybe_arg: Option<&str>, other: u8);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::string::RustStr,
+                maybe_arg: swift_bridge::string::RustStr,
+                other: u8
+            ) {
+                super::some_function(
+                    unsafe { arg.to_str_unchecked() },
+                    unsafe { maybe_arg.to_option_str_unchecked() },
+                    other
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::SkipTest
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_fn_unchecked_utf8_str_arguments() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `return_scratch` function writes into the thread-local scratch buffer and that
+/// Verify that a `&'static str` is returned to Swift as a `RustStr` without being copied, including
+/// when it is inside of a `Result`.
+mod extern_rust_fn_return_static_str {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function () -> &'static str;
+                    fn another_function () -> Result<(), &'static str>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() -> swift_bridge::string::RustStr {
+                    swift_bridge::string::RustStr::from_str(super::some_function())
+                }
+            },
+            quote! {
+                #[repr(C)]
+                pub enum ResultVoidAndRustStr {
+                    Ok,
+                    Err(swift_bridge::string::RustStr),
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$another_function"]
+                pub extern "C" fn __swift_bridge__another_function() -> ResultVoidAndRustStr {
+                    match super::another_function() {
+                        Ok(ok) => ResultVoidAndRustStr::Ok,
+                        Err(err) => ResultVoidAndRustStr::Err(swift_bridge::string::RustStr::from_str(err)),
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+func some_function() -> RustStr {
+    __swift_bridge__$some_function()
+}
+"#,
+            r#"
+func another_function() throws -> () {
+    try { let val = __swift_bridge__$another_function(); switch val.tag { case __swift_bridge__$ResultVoidAndRustStr$ResultOk: return case __swift_bridge__$ResultVoidAndRustStr$ResultErr: throw val.payload.err default: fatalError() } }()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+union __swift_bridge__$ResultVoidAndRustStr$Fields {struct RustStr err;};
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_static_str() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// the Swift side copies the returned string into a Swift `String`.
+mod extern_rust_fn_return_scratch {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(return_scratch)]
+                    fn some_function (arg: u8) -> &str;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: u8) -> swift_bridge::string::RustStr {
+                swift_bridge::string::RustStr::from_str(
+                    unsafe {
+                        swift_bridge::scratch_support::write_scratch_str(|scratch| {
+                            super::some_function(arg, scratch);
+                        })
+                    }
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: UInt8) -> String {
+    __swift_bridge__$some_function(arg).toString()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+struct RustStr __swift_bridge__$some_function(uint8_t arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_scratch() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
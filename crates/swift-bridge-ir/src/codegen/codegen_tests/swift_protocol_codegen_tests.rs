@@ -0,0 +1,185 @@
+//! See also: crates/swift-integration-tests/src/swift_protocol.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that Rust calls a Swift protocol's methods on the existential that Swift boxed up, and
+/// that dropping the Rust handle releases the box.
+mod extern_swift_protocol_methods {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(protocol)]
+                    type Logger;
+
+                    fn level(&self) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[repr(C)]
+            pub struct Logger(pub(crate) *mut std::ffi::c_void);
+
+            impl Logger {
+                pub fn level(&self) -> u8 {
+                    unsafe { __swift_bridge__Logger_level(swift_bridge::PointerToSwiftType(self.0)) }
+                }
+            }
+
+            impl Drop for Logger {
+                fn drop (&mut self) {
+                    unsafe { __swift_bridge__Logger__free(self.0) }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_cdecl("__swift_bridge__$Logger$level")
+func __swift_bridge__Logger_level (_ this: UnsafeMutableRawPointer) -> UInt8 {
+    Unmanaged<__swift_bridge__LoggerBox>.fromOpaque(this).takeUnretainedValue().value.level()
+}
+"#,
+            r#"
+final class __swift_bridge__LoggerBox {
+    let value: Logger
+
+    init(_ value: Logger) {
+        self.value = value
+    }
+}
+
+@_cdecl("__swift_bridge__$Logger$_free")
+func __swift_bridge__Logger__free (ptr: UnsafeMutableRawPointer) {
+    let _ = Unmanaged<__swift_bridge__LoggerBox>.fromOpaque(ptr).takeRetainedValue()
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_swift_protocol_methods() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::ExactAfterTrim(""),
+        }
+        .test();
+    }
+}
+
+/// Verify that Swift boxes a protocol existential before handing it to Rust.
+mod extern_rust_fn_takes_swift_protocol {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(protocol)]
+                    type Logger;
+                }
+
+                extern "Rust" {
+                    fn set_logger(logger: Logger);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$set_logger"]
+            pub extern "C" fn __swift_bridge__set_logger(logger: Logger) {
+                super::set_logger(logger)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func set_logger(_ logger: Logger) {
+    __swift_bridge__$set_logger(Unmanaged.passRetained(__swift_bridge__LoggerBox(logger)).toOpaque())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+void __swift_bridge__$set_logger(void* logger);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_takes_swift_protocol() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that Swift functions box the protocol existentials that they return to Rust and unbox
+/// the ones that Rust passes to them.
+mod extern_swift_fn_swift_protocol {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(protocol)]
+                    type Logger;
+
+                    fn make_logger() -> Logger;
+                    fn use_logger(logger: Logger);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_cdecl("__swift_bridge__$make_logger")
+func __swift_bridge__make_logger () -> UnsafeMutableRawPointer {
+    Unmanaged.passRetained(__swift_bridge__LoggerBox(make_logger())).toOpaque()
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$use_logger")
+func __swift_bridge__use_logger (_ logger: UnsafeMutableRawPointer) {
+    use_logger(logger: Unmanaged<__swift_bridge__LoggerBox>.fromOpaque(logger).takeRetainedValue().value)
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_swift_fn_swift_protocol() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,203 @@
+//! See also: crates/swift-integration-tests/src/time.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we pass Duration and SystemTime to and from Rust functions as seconds plus
+/// nanoseconds, and that they are seen on the Swift side as `TimeInterval` and `Date`.
+mod extern_rust_fn_time {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Duration) -> std::time::SystemTime;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::time::FfiDuration
+            ) -> swift_bridge::time::FfiSystemTime {
+                swift_bridge::time::FfiSystemTime::from(
+                    super::some_function(std::time::Duration::from(arg))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: TimeInterval) -> Date {
+    __swift_bridge__$some_function(__private__FfiDuration(arg)).intoSwiftRepr()
+}
+"#,
+            "import Foundation",
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__FfiSystemTime __swift_bridge__$some_function(struct __private__FfiDuration arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_time() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we pass SystemTime and Duration to and from Swift functions.
+mod extern_swift_fn_time {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: SystemTime) -> Duration;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: std::time::SystemTime) -> std::time::Duration {
+                    std::time::Duration::from(unsafe {
+                        __swift_bridge__some_function(swift_bridge::time::FfiSystemTime::from(arg))
+                    })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::time::FfiSystemTime
+                ) -> swift_bridge::time::FfiDuration;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__FfiSystemTime) -> __private__FfiDuration {
+    __private__FfiDuration(some_function(arg: arg.intoSwiftRepr()))
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_time() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Date` that a Swift function or method returns is seen on the Rust side as a
+/// `SystemTime`.
+mod extern_swift_fn_returns_system_time {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    type Calendar;
+
+                    fn now() -> SystemTime;
+                    fn add(&self, date: SystemTime, interval: Duration) -> std::time::SystemTime;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn now() -> std::time::SystemTime {
+                    std::time::SystemTime::from(unsafe { __swift_bridge__now() })
+                }
+            },
+            quote! {
+                pub fn add(
+                    &self,
+                    date: std::time::SystemTime,
+                    interval: std::time::Duration
+                ) -> std::time::SystemTime {
+                    std::time::SystemTime::from(unsafe {
+                        __swift_bridge__Calendar_add(
+                            swift_bridge::PointerToSwiftType(self.0),
+                            swift_bridge::time::FfiSystemTime::from(date),
+                            swift_bridge::time::FfiDuration::from(interval)
+                        )
+                    })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$now"]
+                fn __swift_bridge__now() -> swift_bridge::time::FfiSystemTime;
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$Calendar$add"]
+                fn __swift_bridge__Calendar_add(
+                    this: swift_bridge::PointerToSwiftType,
+                    date: swift_bridge::time::FfiSystemTime,
+                    interval: swift_bridge::time::FfiDuration
+                ) -> swift_bridge::time::FfiSystemTime;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_cdecl("__swift_bridge__$now")
+func __swift_bridge__now () -> __private__FfiSystemTime {
+    __private__FfiSystemTime(now())
+}
+
+@_cdecl("__swift_bridge__$Calendar$add")
+func __swift_bridge__Calendar_add (_ this: UnsafeMutableRawPointer, _ date: __private__FfiSystemTime, _ interval: __private__FfiDuration) -> __private__FfiSystemTime {
+    __private__FfiSystemTime(Unmanaged<Calendar>.fromOpaque(this).takeUnretainedValue().add(date: date.intoSwiftRepr(), interval: interval.intoSwiftRepr()))
+}
+"#,
+            "import Foundation",
+        ])
+    }
+
+    #[test]
+    fn extern_swift_fn_returns_system_time() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
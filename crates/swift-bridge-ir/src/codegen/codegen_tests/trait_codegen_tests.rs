@@ -0,0 +1,286 @@
+//! See also: crates/swift-integration-tests/src/trait_object.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a bridged trait's methods are called on a `Box<dyn Trait>`, and that Swift gets a
+/// protocol that the trait's class conforms to.
+mod trait_methods {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                trait Shape {
+                    fn area(&self) -> f64;
+                    fn scale(&mut self, factor: f64);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$Shape$area"]
+                pub extern "C" fn __swift_bridge__Shape_area(
+                    this: *mut Box<dyn super::Shape>
+                ) -> f64 {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "Shape");
+                    (unsafe { &*this }).area()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Shape$scale"]
+                pub extern "C" fn __swift_bridge__Shape_scale(
+                    this: *mut Box<dyn super::Shape>,
+                    factor: f64
+                ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "Shape");
+                    (unsafe { &mut *this }).scale(factor)
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Shape$_free"]
+                pub extern "C" fn __swift_bridge__Shape__free(this: *mut Box<dyn super::Shape>) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::pointer_support::assert_valid_pointer(this, "Shape");
+                    let this = unsafe { swift_bridge::leak_check::box_from_raw(this) };
+                    drop(this);
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public class Shape: ShapeRefMut {
+"#,
+            r#"
+public protocol ShapeProtocol {
+    func area() -> Double
+    func scale(_ factor: Double)
+}
+extension ShapeRefMut: ShapeProtocol {}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct Shape Shape;
+void __swift_bridge__$Shape$_free(void* self);
+"#,
+            r#"
+double __swift_bridge__$Shape$area(void* self);
+void __swift_bridge__$Shape$scale(void* self, double factor);
+"#,
+        ])
+    }
+
+    #[test]
+    fn trait_methods() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a trait with only `&self` methods conforms its `{Trait}Ref` class to the protocol.
+mod trait_with_only_ref_self_methods {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                trait Shape {
+                    fn area(&self) -> f64;
+                    fn name(&self) -> String;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Shape$name"]
+            pub extern "C" fn __swift_bridge__Shape_name(
+                this: *mut Box<dyn super::Shape>
+            ) -> *mut swift_bridge::string::RustString {
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "Shape");
+                swift_bridge::string::RustString((unsafe { &*this }).name()).box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public protocol ShapeProtocol {
+    func area() -> Double
+    func name() -> RustString
+}
+extension ShapeRef: ShapeProtocol {}
+"#,
+        )
+    }
+
+    #[test]
+    fn trait_with_only_ref_self_methods() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a `Box<dyn Trait>` to and from Rust functions.
+mod extern_rust_fn_boxed_trait_object {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                trait Shape {
+                    fn area(&self) -> f64;
+                }
+
+                extern "Rust" {
+                    fn make_shape() -> Box<dyn Shape>;
+                    fn take_shape(shape: Box<dyn Shape>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$make_shape"]
+                pub extern "C" fn __swift_bridge__make_shape() -> *mut Box<dyn super::Shape> {
+                    swift_bridge::leak_check::box_into_raw({
+                        let val: Box<dyn super::Shape> = super::make_shape();
+                        val
+                    })
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$take_shape"]
+                pub extern "C" fn __swift_bridge__take_shape(shape: *mut Box<dyn super::Shape>) {
+                    super::take_shape(unsafe { *swift_bridge::leak_check::box_from_raw(shape) })
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func make_shape() -> Shape {
+    Shape(ptr: __swift_bridge__$make_shape())
+}
+"#,
+            r#"
+public func take_shape(_ shape: Shape) {
+    __swift_bridge__$take_shape({shape.isOwned = false; return shape.ptr;}())
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+void* __swift_bridge__$make_shape(void);
+"#,
+            r#"
+void __swift_bridge__$take_shape(void* shape);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_boxed_trait_object() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a `Vec<Box<dyn Trait>>` to Rust.
+mod extern_rust_fn_vec_of_boxed_trait_objects {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                trait Shape {
+                    fn area(&self) -> f64;
+                }
+
+                extern "Rust" {
+                    fn total_area(shapes: Vec<Box<dyn Shape>>) -> f64;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$total_area"]
+                pub extern "C" fn __swift_bridge__total_area(
+                    shapes: *mut Vec<Box<dyn super::Shape>>
+                ) -> f64 {
+                    super::total_area(unsafe { *Box::from_raw(shapes) })
+                }
+            },
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_Shape$push"]
+                pub extern "C" fn _push(
+                    vec: *mut Vec<Box<dyn super::Shape>>,
+                    val: *mut Box<dyn super::Shape>
+                ) {
+                    unsafe { &mut *vec }.push(unsafe { *swift_bridge::leak_check::box_from_raw(val) })
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func total_area(_ shapes: RustVec<Shape>) -> Double {
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_vec_of_boxed_trait_objects() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -177,6 +177,68 @@ struct __swift_bridge__$SomeEnum __swift_bridge__$some_function(struct __swift_b
     }
 }
 
+/// Verify that we generate the correct code for an extern "Swift" function that has an enum as an
+/// argument and returns an enum.
+mod using_enum_in_extern_swift_fn {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum SomeEnum {
+                    Variant1,
+                    Variant2,
+                }
+
+                extern "Swift" {
+                    fn some_function(arg: SomeEnum) -> SomeEnum;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: SomeEnum) -> SomeEnum {
+                    unsafe { __swift_bridge__some_function(arg.into_ffi_repr()) }.into_rust_repr()
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: __swift_bridge__SomeEnum) -> __swift_bridge__SomeEnum;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __swift_bridge__$SomeEnum) -> __swift_bridge__$SomeEnum {
+    some_function(arg: arg.intoSwiftRepr()).intoFfiRepr()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("some_function")
+    }
+
+    #[test]
+    fn using_enum_in_extern_swift_fn() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we can use `Option<Enum>` as Rust function arg and return type.
 mod extern_rust_option_enum {
     use super::*;
@@ -762,14 +824,14 @@ mod generates_enum_with_opaque_rust_data {
                 #[inline(always)]
                 pub fn into_ffi_repr(self) -> __swift_bridge__SomeEnum {
                     match self {
-                        SomeEnum::Unnamed(_0) => __swift_bridge__SomeEnum::Unnamed(Box::into_raw(Box::new({
+                        SomeEnum::Unnamed(_0) => __swift_bridge__SomeEnum::Unnamed(swift_bridge::leak_check::box_into_raw({
                             let val: super::SomeType = _0;
                             val
-                        })) as *mut super::SomeType),
-                        SomeEnum::Named{data} => __swift_bridge__SomeEnum::Named{data: Box::into_raw(Box::new({
+                        })),
+                        SomeEnum::Named{data} => __swift_bridge__SomeEnum::Named{data: swift_bridge::leak_check::box_into_raw({
                             let val: super::SomeType = data;
                             val
-                        })) as *mut super::SomeType}
+                        })}
                     }
                 }
             }
@@ -779,8 +841,8 @@ mod generates_enum_with_opaque_rust_data {
                 #[inline(always)]
                 pub fn into_rust_repr(self) -> SomeEnum {
                     match self {
-                        __swift_bridge__SomeEnum::Unnamed(_0) => SomeEnum::Unnamed(unsafe { * Box::from_raw(_0) }),
-                        __swift_bridge__SomeEnum::Named{data} => SomeEnum::Named{data: unsafe { * Box::from_raw(data) }}
+                        __swift_bridge__SomeEnum::Unnamed(_0) => SomeEnum::Unnamed(unsafe { * swift_bridge::leak_check::box_from_raw(_0) }),
+                        __swift_bridge__SomeEnum::Named{data} => SomeEnum::Named{data: unsafe { * swift_bridge::leak_check::box_from_raw(data) }}
                     }
                 }
             }
@@ -847,3 +909,53 @@ typedef struct __swift_bridge__$Option$SomeEnum { bool is_some; __swift_bridge__
         .test();
     }
 }
+
+/// Verify that we conform an enum annotated with `Sendable` to Swift's `Sendable` protocol.
+mod sendable_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(Sendable)]
+                enum SomeEnum {
+                    Variant1,
+                    Variant2,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            const _: () = {
+                fn _assert_send_sync() {
+                    swift_bridge::sendable_support::assert_send_sync::<SomeEnum>();
+                }
+            };
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum: Sendable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn sendable_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -277,6 +277,100 @@ typedef struct __swift_bridge__$AnotherStruct { uint8_t _0; } __swift_bridge__$A
     }
 }
 
+/// Test code generation for a struct that has multiple primitive fields and
+/// swift_repr = "struct".
+mod struct_with_multiple_primitive_fields {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Point {
+                    x: f64,
+                    y: f64
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct Pair(u8, u16);
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct Point {
+                    pub x: f64,
+                    pub y: f64
+                }
+
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Point {
+                    x: f64,
+                    y: f64
+                }
+            },
+            quote! {
+                pub struct Pair(pub u8, pub u16);
+
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Pair(u8, u16);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+struct Point {
+    public var x: Double
+    public var y: Double
+
+    public init(x: Double, y: Double) {
+        self.x = x
+        self.y = y
+    }
+"#,
+            r#"
+struct Pair {
+    public var _0: UInt8
+    public var _1: UInt16
+
+    public init(_0: UInt8, _1: UInt16) {
+        self._0 = _0
+        self._1 = _1
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$Point { double x; double y; } __swift_bridge__$Point;
+    "#,
+            r#"
+typedef struct __swift_bridge__$Pair { uint8_t _0; uint16_t _1; } __swift_bridge__$Pair;
+    "#,
+        ])
+    }
+
+    #[test]
+    fn struct_with_multiple_primitive_fields() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for passing a `swift_repr = "struct"` as an argument to a
 /// extern "Rust" fn.
 mod extern_rust_fn_arg_swift_repr_struct {
@@ -702,3 +796,102 @@ struct __swift_bridge__$Option$SomeStruct __swift_bridge__$some_function(struct
         .test();
     }
 }
+
+/// Verify that we conform a struct annotated with `Identifiable` to Swift's `Identifiable`
+/// protocol.
+mod identifiable_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Identifiable)]
+                struct SomeStruct {
+                    id: u32,
+                    name: String,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+            return __swift_bridge__$Option$SomeStruct(is_some: false, val: __swift_bridge__$SomeStruct())
+        }
+    }
+}
+extension SomeStruct: Identifiable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn identifiable_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we conform a struct annotated with `Sendable(unchecked)` to Swift's `Sendable`
+/// protocol without having Swift check its fields.
+mod sendable_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Sendable(unchecked))]
+                struct SomeStruct {
+                    name: String,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            const _: () = {
+                fn _assert_send_sync() {
+                    swift_bridge::sendable_support::assert_send_sync::<SomeStruct>();
+                }
+            };
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: @unchecked Sendable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn sendable_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
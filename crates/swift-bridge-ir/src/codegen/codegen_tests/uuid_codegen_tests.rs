@@ -0,0 +1,155 @@
+//! See also: crates/swift-integration-tests/src/uuid.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we pass a Uuid to and from Rust functions as its 16 bytes, and that it is seen
+/// on the Swift side as a `UUID`.
+mod extern_rust_fn_uuid {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Uuid) -> uuid::Uuid;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::uuid_support::FfiUuid
+            ) -> swift_bridge::uuid_support::FfiUuid {
+                swift_bridge::uuid_support::FfiUuid::from(
+                    super::some_function(uuid::Uuid::from(arg))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: UUID) -> UUID {
+    __swift_bridge__$some_function(__private__FfiUuid(arg)).intoSwiftRepr()
+}
+"#,
+            "import Foundation",
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__FfiUuid __swift_bridge__$some_function(struct __private__FfiUuid arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_uuid() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we pass a Uuid to and from Swift functions.
+mod extern_swift_fn_uuid {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: Uuid) -> Uuid;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: uuid::Uuid) -> uuid::Uuid {
+                    uuid::Uuid::from(unsafe {
+                        __swift_bridge__some_function(swift_bridge::uuid_support::FfiUuid::from(arg))
+                    })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::uuid_support::FfiUuid
+                ) -> swift_bridge::uuid_support::FfiUuid;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__FfiUuid) -> __private__FfiUuid {
+    __private__FfiUuid(some_function(arg: arg.intoSwiftRepr()))
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_uuid() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Uuid` type that the bridge module declares is not treated as a `uuid::Uuid`.
+mod declared_uuid_type_takes_precedence {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Uuid;
+                    fn some_function(arg: Uuid);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *mut super::Uuid) {
+                super::some_function(unsafe { *swift_bridge::leak_check::box_from_raw(arg) })
+            }
+        })
+    }
+
+    #[test]
+    fn declared_uuid_type_takes_precedence() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
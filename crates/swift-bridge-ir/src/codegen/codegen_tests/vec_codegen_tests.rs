@@ -66,7 +66,7 @@ mod extern_rust_type_vec_support {
                 #[doc(hidden)]
                 #[export_name = "__swift_bridge__$Vec_MyRustType$push"]
                 pub extern "C" fn _push(vec: *mut Vec<super::MyRustType>, val: *mut super::MyRustType) {
-                    unsafe { &mut *vec }.push(unsafe { *Box::from_raw(val) })
+                    unsafe { &mut *vec }.push(unsafe { *swift_bridge::leak_check::box_from_raw(val) })
                 }
 
                 #[doc(hidden)]
@@ -74,7 +74,7 @@ mod extern_rust_type_vec_support {
                 pub extern "C" fn _pop(vec: *mut Vec<super::MyRustType>) -> *mut super::MyRustType {
                     let vec = unsafe { &mut *vec };
                     if let Some(val) = vec.pop() {
-                        Box::into_raw(Box::new(val))
+                        swift_bridge::leak_check::box_into_raw(val)
                     } else {
                         std::ptr::null::<super::MyRustType>() as *mut super::MyRustType
                     }
@@ -283,6 +283,167 @@ void __swift_bridge__$some_function(void* arg);
     }
 }
 
+/// Test code generation for a Rust method that returns a Vec<T> where T is an opaque Rust type.
+mod extern_rust_method_return_vec_of_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Item;
+                }
+                extern "Rust" {
+                    type SomeList;
+                    fn list(&self) -> Vec<Item>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__SomeList_list(
+                this: *mut super::SomeList
+            ) -> *mut Vec<super::Item> {
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "SomeList");
+                Box::into_raw(Box::new((unsafe { &*this }).list()))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeListRef {
+    public func list() -> RustVec<Item> {
+        RustVec(ptr: __swift_bridge__$SomeList$list(ptr))
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_return_vec_of_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a Vec<String>.
+mod extern_rust_fn_return_vec_of_string {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> Vec<String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function() -> *mut Vec<String> {
+                Box::into_raw(Box::new(super::some_function()))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() -> RustVec<RustString> {
+    RustVec(ptr: __swift_bridge__$some_function())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_vec_of_string() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Vec<String> argument is seen as a RustVec<RustString>, without any
+/// `IntoRustString` generics.
+mod extern_rust_fn_arg_vec_of_string {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Vec<String>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut Vec<String>
+            ) {
+                super::some_function(unsafe { * Box::from_raw(arg) })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: RustVec<RustString>) {
+    __swift_bridge__$some_function({ let val = arg; val.isOwned = false; return val.ptr }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_vec_of_string() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we emit Rust, Swift and C header code that allows a transparent enum be used
 /// within a Vec<T>.
 mod transparent_enum_vec_support {
@@ -0,0 +1,177 @@
+use crate::bridged_type::{pat_type_pat_is_self, BridgedType, TypePosition};
+use crate::codegen::{cfg_attrs_are_active, CodegenConfig};
+use crate::parse::{HostLang, TypeDeclaration};
+use crate::SwiftBridgeModule;
+use proc_macro2::Span;
+use quote::{format_ident, ToTokens};
+use syn::FnArg;
+
+/// The Swift types that we check the signatures of the Swift implementations against.
+///
+/// The generated code converts most other types before handing them to Swift, so the type that
+/// the Swift implementation uses can't be told from the Rust declaration alone.
+const CHECKED_SWIFT_TYPES: [&str; 13] = [
+    "UInt8", "Int8", "UInt16", "Int16", "UInt32", "Int32", "UInt64", "Int64", "UInt", "Int",
+    "Float", "Double", "Bool",
+];
+
+/// The Swift function that the generated code calls for a function declared in an
+/// `extern "Swift"` block.
+#[derive(Debug)]
+pub struct ExternSwiftSignature {
+    /// The Swift type that the function is a method, static function or initializer of.
+    pub associated_type: Option<String>,
+    /// The Swift function's name, or `init` for an initializer.
+    pub name: String,
+    /// The labels that the function gets called with. `None` for an unlabeled argument.
+    pub labels: Vec<Option<String>>,
+    /// The Swift type of each argument, when it's a type that we check.
+    pub arg_types: Vec<Option<String>>,
+    /// The Swift type that the function returns, when it's a type that we check.
+    pub return_type: Option<String>,
+    /// The span of the function's declaration in the bridge module.
+    pub span: Span,
+}
+
+impl SwiftBridgeModule {
+    /// The Swift functions that the module's generated code calls, which the Swift code base
+    /// needs to implement.
+    ///
+    /// Functions whose `#[cfg(...)]` attributes aren't active, along with Swift properties and
+    /// subscripts, are skipped.
+    pub fn extern_swift_signatures(&self, config: &CodegenConfig) -> Vec<ExternSwiftSignature> {
+        if !self.module_will_be_compiled(config) {
+            return vec![];
+        }
+
+        self.functions
+            .iter()
+            .filter(|func| func.host_lang.is_swift())
+            .filter(|func| cfg_attrs_are_active(&func.cfg_attrs, config))
+            .filter(|func| !func.is_swift_property && !func.is_swift_subscript)
+            .map(|func| {
+                let associated_type = match &func.associated_type {
+                    Some(TypeDeclaration::Opaque(opaque)) => Some(opaque.swift_name_string()),
+                    _ => None,
+                };
+                let name = if func.is_swift_initializer {
+                    "init".to_string()
+                } else {
+                    match func.swift_name_override.as_ref() {
+                        Some(swift_name) => swift_name.value(),
+                        None => func.sig.ident.to_string(),
+                    }
+                };
+
+                let mut labels = vec![];
+                let mut arg_types = vec![];
+                for (idx, arg) in func.sig.inputs.iter().enumerate() {
+                    let pat_ty = match arg {
+                        FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => pat_ty,
+                        _ => continue,
+                    };
+
+                    let arg_name = pat_ty.pat.to_token_stream().to_string();
+                    let label = match func.argument_labels.get(&format_ident!("{}", arg_name)) {
+                        Some(label) if label.value() == "_" => None,
+                        Some(label) => Some(label.value()),
+                        None => Some(arg_name),
+                    };
+                    labels.push(label);
+
+                    arg_types.push(checked_swift_type(
+                        BridgedType::new_with_type(&pat_ty.ty, &self.types),
+                        TypePosition::FnArg(HostLang::Rust, idx),
+                        self,
+                    ));
+                }
+
+                let return_type = checked_swift_type(
+                    BridgedType::new_with_return_type(&func.sig.output, &self.types),
+                    TypePosition::FnReturn(HostLang::Rust),
+                    self,
+                );
+
+                ExternSwiftSignature {
+                    associated_type,
+                    name,
+                    labels,
+                    arg_types,
+                    return_type,
+                    span: func.sig.ident.span(),
+                }
+            })
+            .collect()
+    }
+}
+
+fn checked_swift_type(
+    ty: Option<BridgedType>,
+    position: TypePosition,
+    module: &SwiftBridgeModule,
+) -> Option<String> {
+    let swift_ty = ty?.to_swift_type(position, &module.types);
+    CHECKED_SWIFT_TYPES
+        .contains(&swift_ty.as_str())
+        .then_some(swift_ty)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse_ok;
+    use quote::quote;
+
+    /// Verify that we list the names, labels and checked types of the Swift functions that a
+    /// module calls.
+    #[test]
+    fn lists_extern_swift_signatures() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn rust_function(arg: u8);
+                }
+
+                extern "Swift" {
+                    type SomeType;
+
+                    fn some_function(
+                        arg: u8,
+                        #[swift_bridge(label = "_")] unlabeled: bool,
+                        #[swift_bridge(label = "with")] labeled: SomeType,
+                    ) -> u32;
+                    #[swift_bridge(swift_name = "someMethod")]
+                    fn some_method(&self) -> SomeType;
+                    #[swift_bridge(init)]
+                    fn new(value: f64) -> SomeType;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let signatures = module.extern_swift_signatures(&CodegenConfig::no_features_enabled());
+        assert_eq!(signatures.len(), 3);
+
+        assert_eq!(signatures[0].associated_type, None);
+        assert_eq!(signatures[0].name, "some_function");
+        assert_eq!(
+            signatures[0].labels,
+            vec![Some("arg".to_string()), None, Some("with".to_string())]
+        );
+        assert_eq!(
+            signatures[0].arg_types,
+            vec![Some("UInt8".to_string()), Some("Bool".to_string()), None]
+        );
+        assert_eq!(signatures[0].return_type, Some("UInt32".to_string()));
+
+        assert_eq!(signatures[1].associated_type, Some("SomeType".to_string()));
+        assert_eq!(signatures[1].name, "someMethod");
+        assert!(signatures[1].labels.is_empty());
+        assert_eq!(signatures[1].return_type, None);
+
+        assert_eq!(signatures[2].associated_type, Some("SomeType".to_string()));
+        assert_eq!(signatures[2].name, "init");
+        assert_eq!(signatures[2].labels, vec![Some("value".to_string())]);
+        assert_eq!(signatures[2].arg_types, vec![Some("Double".to_string())]);
+    }
+}
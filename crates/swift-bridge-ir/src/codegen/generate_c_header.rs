@@ -2,9 +2,9 @@
 
 use crate::bridged_type::shared_struct::StructField;
 use crate::bridged_type::{BridgeableType, BridgedType, CFfiStruct, StdLibType, StructFields};
-use crate::codegen::CodegenConfig;
+use crate::codegen::{cfg_attrs_are_active, CodegenConfig};
 use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
-use crate::parsed_extern_fn::ParsedExternFn;
+use crate::parsed_extern_fn::{stream_output_and_failure, ParsedExternFn};
 use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
 use std::collections::{BTreeSet, HashSet};
 use syn::{FnArg, ReturnType};
@@ -13,7 +13,7 @@ const NOTICE: &'static str = "// File automatically generated by swift-bridge.";
 
 struct Bookkeeping {
     includes: BTreeSet<&'static str>,
-    slice_types: HashSet<String>,
+    slice_types: BTreeSet<String>,
 }
 
 /// Used to manage the structures declaration order in a C header file. In the C header file, it is necessary to declare fields of a structure before declaring the structure itself.
@@ -50,10 +50,14 @@ impl SwiftBridgeModule {
             includes: BTreeSet::new(),
             // TODO: Delete this.
             //  Don't think we're using it.
-            slice_types: HashSet::new(),
+            slice_types: BTreeSet::new(),
         };
 
         for ty in self.types.types() {
+            if !cfg_attrs_are_active(ty.cfg_attrs(), config) {
+                continue;
+            }
+
             match ty {
                 TypeDeclaration::Shared(ty) => match ty {
                     SharedTypeDeclaration::Struct(ty_struct) => {
@@ -274,7 +278,9 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         let ty_name = ty.ty_name_ident();
                         let hash_ty =
                             format!("uint64_t __swift_bridge__${}$_hash(void* self);", ty_name);
+                        bookkeeping.includes.insert("stdint.h");
                         header += &hash_ty;
+                        header += "\n";
                     }
                     if ty.attributes.equatable {
                         let ty_name = ty.ty_name_ident();
@@ -287,6 +293,45 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         header += &equal_ty;
                         header += "\n";
                     }
+                    if ty.attributes.comparable {
+                        let ty_name = ty.ty_name_ident();
+                        header += &format!(
+                            "bool __swift_bridge__${}$_partial_lt(void* lhs, void* rhs);\n",
+                            ty_name
+                        );
+                    }
+                    if ty.attributes.add {
+                        let ty_name = ty.ty_name_ident();
+                        header += &format!(
+                            "void* __swift_bridge__${}$_add(void* lhs, void* rhs);\n",
+                            ty_name
+                        );
+                    }
+                    if ty.attributes.default {
+                        let ty_name = ty.ty_name_ident();
+                        header += &format!("void* __swift_bridge__${}$_default(void);\n", ty_name);
+                    }
+                    if ty.attributes.debug {
+                        let ty_name = ty.ty_name_ident();
+                        header +=
+                            &format!("void* __swift_bridge__${}$_debug(void* self);\n", ty_name);
+                    }
+                    if ty.attributes.display {
+                        let ty_name = ty.ty_name_ident();
+                        header +=
+                            &format!("void* __swift_bridge__${}$_display(void* self);\n", ty_name);
+                    }
+                    if ty.attributes.observable {
+                        let ty_name = ty.ty_name_ident();
+                        header += &format!(
+                            "void __swift_bridge__${}$_observe(void* self, void* observer, void (*on_change)(void*), void (*free_observer)(void*));\n",
+                            ty_name
+                        );
+                        header += &format!(
+                            "void __swift_bridge__${}$_unobserve(void* self, void* observer);\n",
+                            ty_name
+                        );
+                    }
                     let ty_name = ty.to_string();
 
                     if let Some(copy) = ty.attributes.copy {
@@ -345,6 +390,10 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
         };
 
         for func in self.functions.iter() {
+            if !cfg_attrs_are_active(&func.cfg_attrs, config) {
+                continue;
+            }
+
             declare_custom_c_ffi_types(func, &self.types, &mut c_ffi_struct_bookkeeping);
             if func.host_lang.is_swift() {
                 for (idx, boxed_fn) in func.args_filtered_to_boxed_fns(&self.types) {
@@ -489,7 +538,26 @@ fn declare_func(
         }
     }
 
-    let declaration = if func.sig.asyncness.is_some() {
+    let declaration = if let Some(item) = func.stream_item_ty(types) {
+        let (output, failure) = stream_output_and_failure(&item);
+        let maybe_on_error = match failure {
+            Some(failure) => format!(
+                ", void {name}$on_error(void* subscriber, {} error)",
+                failure.to_c_type(types)
+            ),
+            None => "".to_string(),
+        };
+        let maybe_params = if func.sig.inputs.is_empty() {
+            "".to_string()
+        } else {
+            format!(", {}", params)
+        };
+
+        format!(
+            "void* {name}(void {name}$on_item(void* subscriber, {output} item){maybe_on_error}, void {name}$on_complete(void* subscriber){maybe_params});\n",
+            output = output.to_c_type(types),
+        )
+    } else if func.sig.asyncness.is_some() {
         let maybe_ret = BridgedType::new_with_return_type(&func.sig.output, types).unwrap();
         let maybe_ret = if maybe_ret.is_null() {
             "".to_string()
@@ -619,6 +687,32 @@ void __swift_bridge__$foo(uint8_t arg1);
         );
     }
 
+    /// Verify that `small_string_ffi` declares `&str` arguments as `SmallRustStr`s.
+    #[test]
+    fn small_string_ffi() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn foo(arg1: &str, arg2: u8);
+                }
+            }
+        };
+        let expected = r#"
+#include <stdint.h>
+void __swift_bridge__$foo$small_str(struct SmallRustStr arg1, uint8_t arg2);
+        "#;
+
+        let mut module = parse_ok(tokens);
+        module.set_small_string_ffi(true);
+        assert_eq!(
+            module
+                .generate_c_header_inner(&CodegenConfig::no_features_enabled())
+                .trim(),
+            expected.trim()
+        );
+    }
+
     /// Verify that we generate a type definition for a freestanding function that returns a value.
     #[test]
     fn freestanding_function_with_return() {
@@ -864,4 +958,30 @@ void* __swift_bridge__$some_function(void);
             &expected,
         );
     }
+
+    /// Verify that the slice typedefs are declared in the same order every time that we generate
+    /// the header.
+    #[test]
+    fn slice_typedefs_are_in_a_deterministic_order() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a() -> &'static [u8];
+                    fn b() -> &'static [u32];
+                    fn c() -> &'static [i64];
+                    fn d() -> &'static [f32];
+                }
+            }
+        };
+        let module: SwiftBridgeModuleAndErrors = syn::parse2(tokens).unwrap();
+        let module = module.module;
+
+        let header = module.generate_c_header_inner(&CodegenConfig::no_features_enabled());
+        for _ in 0..10 {
+            assert_eq!(
+                module.generate_c_header_inner(&CodegenConfig::no_features_enabled()),
+                header
+            );
+        }
+    }
 }
@@ -1,8 +1,8 @@
 //! More tests can be found in src/codegen/codegen_tests.rs and its submodules.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
 use quote::{quote, quote_spanned};
 
@@ -10,6 +10,7 @@ use self::vec::vec_of_opaque_rust_type::generate_vec_of_opaque_rust_type_functio
 use crate::bridge_module_attributes::CfgAttr;
 use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration};
 use crate::SwiftBridgeModule;
+use syn::Path;
 
 mod shared_enum;
 mod shared_struct;
@@ -26,7 +27,7 @@ impl ToTokens for SwiftBridgeModule {
 
         let mut shared_struct_definitions = vec![];
         let mut shared_enum_definitions = vec![];
-        let mut custom_type_definitions: HashMap<String, TokenStream> = HashMap::new();
+        let mut custom_type_definitions: BTreeMap<String, TokenStream> = BTreeMap::new();
         let mut impl_fn_tokens: HashMap<String, Vec<TokenStream>> = HashMap::new();
         let mut callbacks_support = vec![];
         let mut freestanding_rust_call_swift_fn_tokens = vec![];
@@ -35,17 +36,23 @@ impl ToTokens for SwiftBridgeModule {
         for func in &self.functions {
             match func.host_lang {
                 HostLang::Rust => {
-                    extern_rust_fn_tokens.push(func.to_extern_c_function_tokens(
-                        &self.swift_bridge_path,
-                        &self.types,
-                        &mut custom_type_definitions,
+                    extern_rust_fn_tokens.push(with_cfg_attrs(
+                        func.to_extern_c_function_tokens(
+                            &self.swift_bridge_path,
+                            &self.types,
+                            &mut custom_type_definitions,
+                        ),
+                        &func.cfg_attrs,
                     ));
                 }
                 HostLang::Swift => {
                     let tokens = func
                         .to_rust_fn_that_calls_a_swift_extern(&self.swift_bridge_path, &self.types);
-                    callbacks_support
-                        .push(func.callbacks_support(&self.swift_bridge_path, &self.types));
+                    let tokens = with_cfg_attrs(tokens, &func.cfg_attrs);
+                    callbacks_support.push(with_cfg_attrs(
+                        func.callbacks_support(&self.swift_bridge_path, &self.types),
+                        &func.cfg_attrs,
+                    ));
 
                     if let Some(ty) = func.associated_type.as_ref() {
                         match ty {
@@ -65,10 +72,13 @@ impl ToTokens for SwiftBridgeModule {
                         freestanding_rust_call_swift_fn_tokens.push(tokens);
                     }
 
-                    extern_swift_fn_tokens.push(func.to_extern_c_function_tokens(
-                        &self.swift_bridge_path,
-                        &self.types,
-                        &mut custom_type_definitions,
+                    extern_swift_fn_tokens.push(foreign_items_with_cfg_attrs(
+                        func.to_extern_c_function_tokens(
+                            &self.swift_bridge_path,
+                            &self.types,
+                            &mut custom_type_definitions,
+                        ),
+                        &func.cfg_attrs,
                     ));
                 }
             };
@@ -78,14 +88,16 @@ impl ToTokens for SwiftBridgeModule {
             match ty {
                 TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
                     if let Some(definition) = self.generate_shared_struct_tokens(shared_struct) {
-                        shared_struct_definitions.push(definition);
+                        shared_struct_definitions
+                            .push(with_cfg_attrs(definition, &shared_struct.cfg_attrs));
                     }
                 }
                 TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
                     if let Some(definition) =
                         self.generate_shared_enum_tokens(shared_enum, &self.types)
                     {
-                        shared_enum_definitions.push(definition);
+                        shared_enum_definitions
+                            .push(with_cfg_attrs(definition, &shared_enum.cfg_attrs));
                     }
                 }
                 TypeDeclaration::Opaque(ty) => {
@@ -95,22 +107,43 @@ impl ToTokens for SwiftBridgeModule {
 
                     let link_name = ty.free_rust_opaque_type_ffi_name();
                     let free_mem_func_name = ty.free_rust_opaque_type_ident();
-                    let this = &ty.ty;
                     let ty_name = &ty.ty;
+                    let cfg_attrs = &ty.attributes.cfg_attrs;
+
+                    let ty_name_string = ty_name.to_string();
+                    let assert_valid_pointer = |ptr: TokenStream| {
+                        quote! {
+                            #[cfg(debug_assertions)]
+                            #swift_bridge_path::pointer_support::assert_valid_pointer(#ptr, #ty_name_string);
+                        }
+                    };
 
                     match ty.host_lang {
                         HostLang::Rust => {
+                            if ty.attributes.sendable.is_some() {
+                                let generics = ty
+                                    .generics
+                                    .angle_bracketed_concrete_generics_tokens(&self.types);
+                                let tokens = assert_send_sync(
+                                    &ty.rust_type_tokens(&generics),
+                                    swift_bridge_path,
+                                    ty.ty.span(),
+                                );
+                                extern_rust_fn_tokens.push(with_cfg_attrs(tokens, cfg_attrs));
+                            }
                             if ty.attributes.hashable {
                                 let export_name = format!("__swift_bridge__${}$_hash", ty_name);
                                 let function_name = syn::Ident::new(
                                     &format!("__swift_bridge__{}__hash", ty_name),
                                     ty.ty.span(),
                                 );
+                                let assert_valid_this = assert_valid_pointer(quote! { this });
                                 let tokens = quote! {
                                 #[export_name = #export_name]
                                 pub extern "C" fn #function_name (
                                     this: *const super::#ty_name,
                                 ) -> u64 {
+                                    #assert_valid_this
                                     use std::hash::{Hash, Hasher};
                                     use std::collections::hash_map::DefaultHasher;
                                     let mut s = DefaultHasher::new();
@@ -118,7 +151,7 @@ impl ToTokens for SwiftBridgeModule {
                                     s.finish()
                                 }
                                 };
-                                extern_rust_fn_tokens.push(tokens);
+                                extern_rust_fn_tokens.push(with_cfg_attrs(tokens, cfg_attrs));
                             }
                             if ty.attributes.equatable {
                                 let export_name =
@@ -127,16 +160,160 @@ impl ToTokens for SwiftBridgeModule {
                                     &format!("__swift_bridge__{}__partial_eq", ty_name),
                                     ty.ty.span(),
                                 );
+                                let assert_valid_lhs = assert_valid_pointer(quote! { lhs });
+                                let assert_valid_rhs = assert_valid_pointer(quote! { rhs });
                                 let tokens = quote! {
                                     #[export_name = #export_name]
                                     pub extern "C" fn #function_name (
                                         lhs: *const super::#ty_name,
                                         rhs: *const super::#ty_name
                                     ) -> bool {
+                                        #assert_valid_lhs
+                                        #assert_valid_rhs
                                         unsafe { &*lhs == &*rhs }
                                     }
                                 };
-                                extern_rust_fn_tokens.push(tokens);
+                                extern_rust_fn_tokens.push(with_cfg_attrs(tokens, cfg_attrs));
+                            }
+                            if ty.attributes.comparable {
+                                let export_name =
+                                    format!("__swift_bridge__${}$_partial_lt", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__partial_lt", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let assert_valid_lhs = assert_valid_pointer(quote! { lhs });
+                                let assert_valid_rhs = assert_valid_pointer(quote! { rhs });
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        lhs: *const super::#ty_name,
+                                        rhs: *const super::#ty_name
+                                    ) -> bool {
+                                        #assert_valid_lhs
+                                        #assert_valid_rhs
+                                        unsafe { &*lhs < &*rhs }
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(with_cfg_attrs(tokens, cfg_attrs));
+                            }
+                            if ty.attributes.add {
+                                let export_name = format!("__swift_bridge__${}$_add", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__add", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let assert_valid_lhs = assert_valid_pointer(quote! { lhs });
+                                let assert_valid_rhs = assert_valid_pointer(quote! { rhs });
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        lhs: *const super::#ty_name,
+                                        rhs: *const super::#ty_name
+                                    ) -> *mut super::#ty_name {
+                                        #assert_valid_lhs
+                                        #assert_valid_rhs
+                                        #swift_bridge_path::leak_check::box_into_raw(unsafe { &*lhs + &*rhs })
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(with_cfg_attrs(tokens, cfg_attrs));
+                            }
+                            if ty.attributes.default {
+                                let export_name = format!("__swift_bridge__${}$_default", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__default", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name () -> *mut super::#ty_name {
+                                        #swift_bridge_path::leak_check::box_into_raw(<super::#ty_name as Default>::default())
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(with_cfg_attrs(tokens, cfg_attrs));
+                            }
+                            if ty.attributes.debug {
+                                let export_name = format!("__swift_bridge__${}$_debug", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__debug", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let assert_valid_this = assert_valid_pointer(quote! { this });
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                    ) -> *mut #swift_bridge_path::string::RustString {
+                                        #assert_valid_this
+                                        #swift_bridge_path::string::RustString(
+                                            format!("{:?}", unsafe { &*this })
+                                        ).box_into_raw()
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(with_cfg_attrs(tokens, cfg_attrs));
+                            }
+                            if ty.attributes.display {
+                                let export_name = format!("__swift_bridge__${}$_display", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__display", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let assert_valid_this = assert_valid_pointer(quote! { this });
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                    ) -> *mut #swift_bridge_path::string::RustString {
+                                        #assert_valid_this
+                                        #swift_bridge_path::string::RustString(
+                                            format!("{}", unsafe { &*this })
+                                        ).box_into_raw()
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(with_cfg_attrs(tokens, cfg_attrs));
+                            }
+                            if ty.attributes.observable {
+                                let export_name = format!("__swift_bridge__${}$_observe", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__observe", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let assert_valid_this = assert_valid_pointer(quote! { this });
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                        observer: *mut std::ffi::c_void,
+                                        on_change: extern "C" fn(*mut std::ffi::c_void),
+                                        free_observer: extern "C" fn(*mut std::ffi::c_void),
+                                    ) {
+                                        #assert_valid_this
+                                        #swift_bridge_path::observable::Observable::change_notifier(
+                                            unsafe { &*this }
+                                        ).subscribe(observer, on_change, free_observer)
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(with_cfg_attrs(tokens, cfg_attrs));
+
+                                let export_name =
+                                    format!("__swift_bridge__${}$_unobserve", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__unobserve", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                        observer: *mut std::ffi::c_void,
+                                    ) {
+                                        #assert_valid_this
+                                        #swift_bridge_path::observable::Observable::change_notifier(
+                                            unsafe { &*this }
+                                        ).unsubscribe(observer)
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(with_cfg_attrs(tokens, cfg_attrs));
                             }
                             if let Some(copy) = ty.attributes.copy {
                                 let size = copy.size_bytes;
@@ -186,8 +363,8 @@ impl ToTokens for SwiftBridgeModule {
                                     }
                                 };
 
-                                extern_rust_fn_tokens.push(assert_size);
-                                extern_rust_fn_tokens.push(copy_ty);
+                                extern_rust_fn_tokens.push(with_cfg_attrs(assert_size, cfg_attrs));
+                                extern_rust_fn_tokens.push(with_cfg_attrs(copy_ty, cfg_attrs));
                             }
 
                             if !ty.attributes.already_declared {
@@ -195,24 +372,32 @@ impl ToTokens for SwiftBridgeModule {
                                     let generics = ty
                                         .generics
                                         .angle_bracketed_concrete_generics_tokens(&self.types);
+                                    let rust_ty = ty.rust_type_tokens(&generics);
 
+                                    let assert_valid_this = assert_valid_pointer(quote! { this });
                                     let free = quote! {
                                         #[export_name = #link_name]
-                                        pub extern "C" fn #free_mem_func_name (this: *mut super::#this #generics) {
-                                            let this = unsafe { Box::from_raw(this) };
+                                        pub extern "C" fn #free_mem_func_name (this: *mut #rust_ty) {
+                                            #assert_valid_this
+                                            let this = unsafe { #swift_bridge_path::leak_check::box_from_raw(this) };
                                             drop(this);
                                         }
                                     };
 
-                                    extern_rust_fn_tokens.push(free);
+                                    extern_rust_fn_tokens.push(with_cfg_attrs(free, cfg_attrs));
 
                                     // TODO: Support Vec<OpaqueCopyType>. Add codegen tests and then
                                     //  make them pass.
                                     // TODO: Support Vec<GenericOpaqueRustType
                                     if ty.generics.len() == 0 {
                                         let vec_functions =
-                                            generate_vec_of_opaque_rust_type_functions(ty_name);
-                                        extern_rust_fn_tokens.push(vec_functions);
+                                            generate_vec_of_opaque_rust_type_functions(
+                                                ty_name,
+                                                &rust_ty,
+                                                swift_bridge_path,
+                                            );
+                                        extern_rust_fn_tokens
+                                            .push(with_cfg_attrs(vec_functions, cfg_attrs));
                                     }
                                 }
                             }
@@ -233,9 +418,34 @@ impl ToTokens for SwiftBridgeModule {
                                 }
                             };
 
+                            // The type's struct was generated by the bridge module that
+                            // declared it, which needs to be in scope in the parent module.
+                            if ty.attributes.already_declared {
+                                let tokens = quote! {
+                                    use super::#ty_name;
+
+                                    #impls
+                                };
+                                structs_for_swift_classes.push(with_cfg_attrs(tokens, cfg_attrs));
+                                continue;
+                            }
+
+                            // Swift actors are `Sendable`, and Rust only calls into them through
+                            // futures that hop onto the actor.
+                            let maybe_send_sync = if ty.attributes.actor {
+                                quote! {
+                                    unsafe impl Send for #ty_name {}
+                                    unsafe impl Sync for #ty_name {}
+                                }
+                            } else {
+                                quote! {}
+                            };
+
+                            // The pointer is visible to the rest of the crate so that bridge
+                            // modules that use `already_declared` can implement methods.
                             let struct_tokens = quote! {
                                 #[repr(C)]
-                                pub struct #ty_name(*mut std::ffi::c_void);
+                                pub struct #ty_name(pub(crate) *mut std::ffi::c_void);
 
                                 #impls
 
@@ -244,14 +454,18 @@ impl ToTokens for SwiftBridgeModule {
                                         unsafe { #free_mem_func_name(self.0) }
                                     }
                                 }
+
+                                #maybe_send_sync
                             };
-                            structs_for_swift_classes.push(struct_tokens);
+                            structs_for_swift_classes
+                                .push(with_cfg_attrs(struct_tokens, cfg_attrs));
 
                             let free = quote! {
                                 #[link_name = #link_name]
                                 fn #free_mem_func_name (this: *mut std::ffi::c_void);
                             };
-                            extern_swift_fn_tokens.push(free);
+                            extern_swift_fn_tokens
+                                .push(foreign_items_with_cfg_attrs(free, cfg_attrs));
                         }
                     };
                 }
@@ -267,14 +481,9 @@ impl ToTokens for SwiftBridgeModule {
         let mut module_attributes = vec![];
 
         for cfg in &self.cfg_attrs {
-            match cfg {
-                CfgAttr::Feature(feature_name) => {
-                    //
-                    module_attributes.push(quote! {
-                        #[cfg(feature = #feature_name)]
-                    });
-                }
-            };
+            module_attributes.push(quote! {
+                #[cfg(#cfg)]
+            });
         }
         let custom_type_definitions = custom_type_definitions.into_values();
         let module_inner = quote! {
@@ -383,6 +592,57 @@ fn generate_extern_c_block(extern_swift_fn_tokens: Vec<TokenStream>) -> TokenStr
     }
 }
 
+/// Annotate each of the items that were generated for a bridge module function or type with
+/// that function or type's `#[cfg(...)]` attributes.
+///
+/// The bridge macro receives the module before any of the `#[cfg(...)]` attributes inside of it
+/// are evaluated, so we pass them along in order to only compile the FFI glue for items that
+/// get compiled.
+fn with_cfg_attrs(tokens: TokenStream, cfg_attrs: &[CfgAttr]) -> TokenStream {
+    if cfg_attrs.is_empty() {
+        return tokens;
+    }
+
+    let file: syn::File = syn::parse2(tokens).expect("Generated tokens are valid items");
+    let items = file.items.into_iter().map(|item| {
+        quote! {
+            #(#[cfg(#cfg_attrs)])*
+            #item
+        }
+    });
+
+    quote! { #(#items)* }
+}
+
+/// Fail to compile unless a type that is `Sendable` on the Swift side is `Send + Sync`.
+fn assert_send_sync(ty: &TokenStream, swift_bridge_path: &Path, span: Span) -> TokenStream {
+    quote_spanned! {span=>
+        const _: () = {
+            fn _assert_send_sync() {
+                #swift_bridge_path::sendable_support::assert_send_sync::<#ty>();
+            }
+        };
+    }
+}
+
+/// Like [`with_cfg_attrs`], but for the items that go into an `extern "C"` block.
+fn foreign_items_with_cfg_attrs(tokens: TokenStream, cfg_attrs: &[CfgAttr]) -> TokenStream {
+    if cfg_attrs.is_empty() {
+        return tokens;
+    }
+
+    let foreign_mod: syn::ItemForeignMod = syn::parse2(quote! { extern "C" { #tokens } })
+        .expect("Generated tokens are valid foreign items");
+    let items = foreign_mod.items.into_iter().map(|item| {
+        quote! {
+            #(#[cfg(#cfg_attrs)])*
+            #item
+        }
+    });
+
+    quote! { #(#items)* }
+}
+
 #[cfg(test)]
 mod tests {
     //! More tests can be found in src/codegen/codegen_tests.rs and its submodules.
@@ -391,6 +651,7 @@ mod tests {
 
     use crate::parse::SwiftBridgeModuleAndErrors;
     use crate::test_utils::{assert_tokens_contain, assert_tokens_eq};
+    use crate::PanicBehavior;
 
     use super::*;
 
@@ -591,10 +852,10 @@ mod tests {
         let expected_func = quote! {
             #[export_name = "__swift_bridge__$some_function"]
             pub extern "C" fn __swift_bridge__some_function () -> *mut super::Foo {
-                Box::into_raw(Box::new({
+                swift_bridge::leak_check::box_into_raw({
                     let val: super::Foo = super::another_function();
                     val
-                })) as *mut super::Foo
+                })
             }
         };
 
@@ -618,10 +879,10 @@ mod tests {
         let expected_func = quote! {
             #[export_name = "__swift_bridge__$some_function"]
             pub extern "C" fn __swift_bridge__some_function () -> *mut super::Foo {
-                Box::into_raw(Box::new({
+                swift_bridge::leak_check::box_into_raw({
                     let val: super::Foo = super::some_function().into();
                     val
-                })) as *mut super::Foo
+                })
             }
         };
 
@@ -667,6 +928,8 @@ mod tests {
             pub extern "C" fn __swift_bridge__Foo_some_function (
                 this: *mut super::Foo
             ) -> *mut super::Foo {
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "Foo");
                 (unsafe { &mut * this }).some_function() as *mut super::Foo
             }
         };
@@ -687,7 +950,7 @@ mod tests {
         };
         let expected = quote! {
             #[repr(C)]
-            pub struct Foo(*mut std::ffi::c_void);
+            pub struct Foo(pub(crate) *mut std::ffi::c_void);
 
             impl Drop for Foo {
                 fn drop (&mut self) {
@@ -721,10 +984,10 @@ mod tests {
         let expected = quote! {
             #[export_name = "__swift_bridge__$SomeType$new"]
             pub extern "C" fn __swift_bridge__SomeType_new () -> *mut super::SomeType {
-                Box::into_raw(Box::new({
+                swift_bridge::leak_check::box_into_raw({
                     let val: super::SomeType = super::SomeType::new();
                     val
-                })) as *mut super::SomeType
+                })
             }
         };
 
@@ -747,7 +1010,7 @@ mod tests {
         };
         let expected = quote! {
             #[repr(C)]
-            pub struct Foo(*mut std::ffi::c_void);
+            pub struct Foo(pub(crate) *mut std::ffi::c_void);
 
             impl Foo {
                 pub fn new () -> Foo {
@@ -836,6 +1099,8 @@ mod tests {
             pub extern "C" fn __swift_bridge__MyType_increment (
                 this: *mut super::MyType
             ) {
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "MyType");
                 (unsafe { &mut *this }).increment()
             }
         };
@@ -860,7 +1125,7 @@ mod tests {
         };
         let expected = quote! {
             #[repr(C)]
-            pub struct Foo(*mut std::ffi::c_void);
+            pub struct Foo(pub(crate) *mut std::ffi::c_void);
 
             impl Foo {
                 pub fn notify (&self) {
@@ -918,6 +1183,8 @@ mod tests {
                 this: *mut super::SomeType,
                 val: u8
             ) {
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
                 (unsafe { &*this }).message(val)
             }
         };
@@ -931,6 +1198,7 @@ mod tests {
         let start = quote! {
             mod foo {
                 extern "Rust" {
+                    #[swift_bridge(unsafe_pointer)]
                     fn void_pointers (arg1: *const c_void, arg2: *mut c_void) -> *const c_void;
                 }
             }
@@ -954,6 +1222,7 @@ mod tests {
         let start = quote! {
             mod foo {
                 extern "Swift" {
+                    #[swift_bridge(unsafe_pointer)]
                     fn built_in_pointers (arg1: *const u8, arg2: *mut i16) -> *const u32;
                 }
             }
@@ -982,6 +1251,7 @@ mod tests {
         let start = quote! {
             mod foo {
                 extern "Swift" {
+                    #[swift_bridge(unsafe_pointer)]
                     fn void_pointers (arg1: *const c_void, arg2: *mut c_void) -> *const c_void;
                 }
             }
@@ -1020,13 +1290,143 @@ mod tests {
             pub extern "C" fn __swift_bridge__SomeType_consume (
                 this: *mut super::SomeType
             ) {
-                (* unsafe { Box::from_raw(this) }).consume()
+                #[cfg(debug_assertions)]
+                swift_bridge::pointer_support::assert_valid_pointer(this, "SomeType");
+                (* unsafe { swift_bridge::leak_check::box_from_raw(this) }).consume()
             }
         };
 
         assert_to_extern_c_function_tokens(start, &expected);
     }
 
+    /// Verify that the custom FFI types, such as the ones for tuples, are generated in the same
+    /// order every time that we generate the module's tokens.
+    #[test]
+    fn custom_type_definitions_are_in_a_deterministic_order() {
+        let start = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a() -> (u8, u16);
+                    fn b() -> (i32, bool);
+                    fn c() -> (u64, f32);
+                    fn d() -> (i8, f64);
+                }
+            }
+        };
+        let module = parse_ok(start);
+
+        let tokens = module.to_token_stream().to_string();
+        for _ in 0..10 {
+            assert_eq!(module.to_token_stream().to_string(), tokens);
+        }
+    }
+
+    /// Verify that `inline_shims` annotates the generated extern functions, and the functions that
+    /// call into Swift, with `#[inline]`.
+    #[test]
+    fn inline_shims() {
+        let start = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn add(a: u32, b: u32) -> u32;
+                }
+                extern "Swift" {
+                    fn count() -> u32;
+                }
+            }
+        };
+        let mut module = parse_ok(start);
+        module.set_inline_shims(true);
+        let tokens = module.to_token_stream();
+
+        assert_tokens_contain(
+            &tokens,
+            &quote! {
+                #[inline]
+                #[export_name = "__swift_bridge__$add"]
+                pub extern "C" fn __swift_bridge__add
+            },
+        );
+        assert_tokens_contain(
+            &tokens,
+            &quote! {
+                #[inline]
+                pub fn count() -> u32
+            },
+        );
+    }
+
+    /// Verify that `small_string_ffi` makes synchronous Rust functions take their `&str`
+    /// arguments as `SmallRustStr`s under a different symbol, and leaves other functions alone.
+    #[test]
+    fn small_string_ffi() {
+        let start = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn greet(name: &str, times: u8);
+                    fn add(a: u32, b: u32) -> u32;
+                    async fn fetch(url: &str);
+                }
+            }
+        };
+        let mut module = parse_ok(start);
+        module.set_small_string_ffi(true);
+        let tokens = module.to_token_stream();
+
+        assert_tokens_contain(
+            &tokens,
+            &quote! {
+                #[export_name = "__swift_bridge__$greet$small_str"]
+                pub extern "C" fn __swift_bridge__greet(
+                    name: swift_bridge::string::SmallRustStr,
+                    times: u8
+                ) {
+                    super::greet(name.to_str(), times)
+                }
+            },
+        );
+        assert_tokens_contain(
+            &tokens,
+            &quote! {
+                #[export_name = "__swift_bridge__$add"]
+            },
+        );
+        assert_tokens_contain(
+            &tokens,
+            &quote! {
+                #[export_name = "__swift_bridge__$fetch"]
+            },
+        );
+        assert_tokens_contain(&tokens, &quote! { url: swift_bridge::string::RustStr });
+    }
+
+    /// Verify that `catch_panics` wraps the calls in the generated extern functions with
+    /// `catch_panic`.
+    #[test]
+    fn catch_panics() {
+        let start = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn add(a: u32, b: u32) -> u32;
+                }
+            }
+        };
+        let mut module = parse_ok(start);
+        module.set_catch_panics(PanicBehavior::Throw);
+        let tokens = module.to_token_stream();
+
+        assert_tokens_contain(
+            &tokens,
+            &quote! {
+                #[export_name = "__swift_bridge__$add"]
+                pub extern "C" fn __swift_bridge__add(a: u32, b: u32) -> u32 {
+                    let call = move || { super::add(a, b) };
+                    unsafe { swift_bridge::panic_support::catch_panic(call) }
+                }
+            },
+        );
+    }
+
     fn parse_ok(tokens: TokenStream) -> SwiftBridgeModule {
         let module_and_errors: SwiftBridgeModuleAndErrors = syn::parse2(tokens).unwrap();
         module_and_errors.module
@@ -1040,7 +1440,7 @@ mod tests {
             &function.to_extern_c_function_tokens(
                 &module.swift_bridge_path,
                 &module.types,
-                &mut HashMap::new(),
+                &mut BTreeMap::new(),
             ),
             &expected_fn,
         );
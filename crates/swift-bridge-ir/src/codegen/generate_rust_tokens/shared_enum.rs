@@ -158,6 +158,13 @@ impl SwiftBridgeModule {
             });
         }
 
+        let assert_send_sync = match shared_enum.sendable {
+            Some(_) => {
+                super::assert_send_sync(&quote! { #enum_name }, swift_bridge_path, enum_name.span())
+            }
+            None => quote! {},
+        };
+
         let vec_support = if shared_enum.has_one_or_more_variants_with_data() {
             // Enums with variants that contain data are not yet supported.
             quote! {}
@@ -238,6 +245,8 @@ impl SwiftBridgeModule {
 
             #vec_support
 
+            #assert_send_sync
+
             #(#derive_impl_ffi_bridges),*
         };
 
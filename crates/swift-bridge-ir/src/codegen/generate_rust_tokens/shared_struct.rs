@@ -99,6 +99,15 @@ impl SwiftBridgeModule {
             derives.push(quote! {Clone});
         }
 
+        let assert_send_sync = match shared_struct.sendable {
+            Some(_) => super::assert_send_sync(
+                &quote! { #struct_name },
+                swift_bridge_path,
+                struct_name.span(),
+            ),
+            None => quote! {},
+        };
+
         let definition = quote! {
             #[derive(#(#derives),*)]
             pub struct #struct_name #struct_fields
@@ -111,6 +120,8 @@ impl SwiftBridgeModule {
 
             #into_ffi_repr_impl
 
+            #assert_send_sync
+
             impl #struct_ffi_name {
                 #[doc(hidden)]
                 #[inline(always)]
@@ -1,11 +1,18 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
+use syn::Path;
 
 /// Generate the functions that Swift calls uses inside of the corresponding class for an opaque
 /// Rust type's Vectorizable implementation.
 ///
 /// So inside of `extension MyRustType: Vectorizable {}` on the Swift side.
-pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(ty: &Ident) -> TokenStream {
+///
+/// `rust_ty` is the Rust type that the opaque type is stored as, such as `super::MyRustType`.
+pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(
+    ty: &Ident,
+    rust_ty: &TokenStream,
+    swift_bridge_path: &Path,
+) -> TokenStream {
     // examples:
     // "__swift_bridge__$Vec_MyRustType$new"
     // "__swift_bridge__$Vec_MyRustType$drop"
@@ -23,29 +30,29 @@ pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(ty: &Ident) -
         const _: () = {
             #[doc(hidden)]
             #[export_name = #export_name_new]
-            pub extern "C" fn _new() -> *mut Vec<super::#ty> {
+            pub extern "C" fn _new() -> *mut Vec<#rust_ty> {
                 Box::into_raw(Box::new(Vec::new()))
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_drop]
-            pub extern "C" fn _drop(vec: *mut Vec<super::#ty>) {
+            pub extern "C" fn _drop(vec: *mut Vec<#rust_ty>) {
                 let vec = unsafe { Box::from_raw(vec) };
                 drop(vec)
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_len]
-            pub extern "C" fn _len(vec: *const Vec<super::#ty>) -> usize {
+            pub extern "C" fn _len(vec: *const Vec<#rust_ty>) -> usize {
                 unsafe { &*vec }.len()
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_get]
-            pub extern "C" fn _get(vec: *const Vec<super::#ty>, index: usize) -> *const super::#ty {
+            pub extern "C" fn _get(vec: *const Vec<#rust_ty>, index: usize) -> *const #rust_ty {
                 let vec = unsafe { & *vec };
                 if let Some(val) = vec.get(index) {
-                    val as *const super::#ty
+                    val as *const #rust_ty
                 } else {
                     std::ptr::null()
                 }
@@ -53,35 +60,35 @@ pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(ty: &Ident) -
 
             #[doc(hidden)]
             #[export_name = #export_name_get_mut]
-            pub extern "C" fn _get_mut(vec: *mut Vec<super::#ty>, index: usize) -> *mut super::#ty {
+            pub extern "C" fn _get_mut(vec: *mut Vec<#rust_ty>, index: usize) -> *mut #rust_ty {
                 let vec = unsafe { &mut *vec };
                 if let Some(val) = vec.get_mut(index) {
-                    val as *mut super::#ty
+                    val as *mut #rust_ty
                 } else {
-                    std::ptr::null::<super::#ty>() as *mut super::#ty
+                    std::ptr::null::<#rust_ty>() as *mut #rust_ty
                 }
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_push]
-            pub extern "C" fn _push(vec: *mut Vec<super::#ty>, val: *mut super::#ty) {
-                unsafe { &mut *vec }.push( unsafe { *Box::from_raw(val) } )
+            pub extern "C" fn _push(vec: *mut Vec<#rust_ty>, val: *mut #rust_ty) {
+                unsafe { &mut *vec }.push( unsafe { *#swift_bridge_path::leak_check::box_from_raw(val) } )
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_pop]
-            pub extern "C" fn _pop(vec: *mut Vec<super::#ty>) -> *mut super::#ty {
+            pub extern "C" fn _pop(vec: *mut Vec<#rust_ty>) -> *mut #rust_ty {
                 let vec = unsafe { &mut *vec };
                 if let Some(val) = vec.pop() {
-                    Box::into_raw(Box::new(val))
+                    #swift_bridge_path::leak_check::box_into_raw(val)
                 } else {
-                    std::ptr::null::<super::#ty>() as *mut super::#ty
+                    std::ptr::null::<#rust_ty>() as *mut #rust_ty
                 }
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_as_ptr]
-            pub extern "C" fn _as_ptr(vec: *const Vec<super::#ty>) -> *const super::#ty {
+            pub extern "C" fn _as_ptr(vec: *const Vec<#rust_ty>) -> *const #rust_ty {
                 unsafe { & *vec }.as_ptr()
             }
         };
@@ -145,7 +152,7 @@ mod tests {
                 #[doc(hidden)]
                 #[export_name = "__swift_bridge__$Vec_ARustType$push"]
                 pub extern "C" fn _push(vec: *mut Vec<super::ARustType>, val: *mut super::ARustType) {
-                    unsafe { &mut *vec }.push(unsafe { * Box::from_raw(val) })
+                    unsafe { &mut *vec }.push(unsafe { * swift_bridge::leak_check::box_from_raw(val) })
                 }
 
                 #[doc(hidden)]
@@ -153,7 +160,7 @@ mod tests {
                 pub extern "C" fn _pop(vec: *mut Vec<super::ARustType>) -> *mut super::ARustType {
                     let vec = unsafe { &mut *vec };
                     if let Some(val) = vec.pop() {
-                        Box::into_raw(Box::new(val))
+                        swift_bridge::leak_check::box_into_raw(val)
                     } else {
                         std::ptr::null::<super::ARustType>() as *mut super::ARustType
                     }
@@ -168,10 +175,11 @@ mod tests {
         };
 
         assert_tokens_eq(
-            &generate_vec_of_opaque_rust_type_functions(&Ident::new(
-                "ARustType",
-                Span::call_site(),
-            )),
+            &generate_vec_of_opaque_rust_type_functions(
+                &Ident::new("ARustType", Span::call_site()),
+                &quote! { super::ARustType },
+                &syn::parse2(quote! { swift_bridge }).unwrap(),
+            ),
             &expected,
         );
     }
@@ -169,6 +169,9 @@ mod tests {
             already_declared: false,
             swift_name: None,
             derive: DeriveAttrs::default(),
+            sendable: None,
+            doc_comment: None,
+            cfg_attrs: vec![],
         };
         assert_tokens_eq(
             &generate_vec_of_transparent_enum_functions(&shared_enum),
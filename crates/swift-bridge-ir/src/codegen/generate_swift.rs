@@ -1,16 +1,19 @@
 use std::collections::HashMap;
 
-use syn::Path;
+use syn::{LitStr, Path};
 
-use crate::bridged_type::{BridgeableType, BridgedType, TypePosition};
-use crate::codegen::generate_swift::generate_function_swift_calls_rust::gen_func_swift_calls_rust;
+use crate::bridged_type::{BridgeableType, BridgedType, StdLibType, TypePosition};
+use crate::codegen::generate_swift::generate_function_swift_calls_rust::{
+    gen_func_swift_calls_rust, gen_swift_closures_support,
+};
+use crate::codegen::generate_swift::observable_object::generate_observable_object;
 use crate::codegen::generate_swift::opaque_copy_type::generate_opaque_copy_struct;
 use crate::codegen::generate_swift::swift_class::generate_swift_class;
 use crate::codegen::generate_swift::vec::generate_vectorizable_extension;
-use crate::codegen::CodegenConfig;
+use crate::codegen::{cfg_attrs_are_active, CodegenConfig, SharedSwiftCode};
 use crate::parse::{
     HostLang, OpaqueForeignTypeDeclaration, SharedTypeDeclaration, TypeDeclaration,
-    TypeDeclarations,
+    TypeDeclarations, SWIFT_ACCESS_LEVELS,
 };
 use crate::parsed_extern_fn::ParsedExternFn;
 use crate::SwiftBridgeModule;
@@ -18,26 +21,57 @@ use crate::SwiftBridgeModule;
 mod vec;
 
 mod generate_function_swift_calls_rust;
+mod observable_object;
 mod opaque_copy_type;
 mod shared_enum;
 mod shared_struct;
 mod swift_class;
 
 impl SwiftBridgeModule {
-    /// Generate the corresponding Swift code for the bridging module.
+    /// Generate the corresponding Swift code for the bridging module, including its shared code.
+    #[cfg(test)]
     pub(crate) fn generate_swift(&self, config: &CodegenConfig) -> String {
+        let mut swift = self.generate_swift_without_shared_code(config);
+
+        for shared in self.shared_swift_code(config) {
+            swift += &shared.code;
+            swift += "\n";
+        }
+
+        swift
+    }
+
+    /// The module's Swift code, minus the [`SharedSwiftCode`] that other bridge modules generate
+    /// as well.
+    pub(crate) fn generate_swift_without_shared_code(&self, config: &CodegenConfig) -> String {
         let mut swift = "".to_string();
 
         if !self.module_will_be_compiled(config) {
             return swift;
         }
 
+        let default_access = config.default_swift_access.as_deref();
+        if let Some(access) = default_access {
+            assert!(
+                SWIFT_ACCESS_LEVELS.contains(&access),
+                "Unsupported default Swift access level {access:?}. Expected one of: {}.",
+                SWIFT_ACCESS_LEVELS.join(", ")
+            );
+        }
+
         let mut associated_funcs_and_methods: HashMap<String, Vec<&ParsedExternFn>> =
             HashMap::new();
         let mut class_protocols: HashMap<String, ClassProtocols> = HashMap::new();
+        let mut swift_closures_support = "".to_string();
 
         for function in &self.functions {
+            if !cfg_attrs_are_active(&function.cfg_attrs, config) {
+                continue;
+            }
+
             if function.host_lang.is_rust() {
+                swift_closures_support += &gen_swift_closures_support(function, &self.types);
+
                 if let Some(ty) = function.associated_type.as_ref() {
                     match ty {
                         TypeDeclaration::Shared(_) => {
@@ -46,7 +80,7 @@ impl SwiftBridgeModule {
                         }
                         TypeDeclaration::Opaque(opaque_ty) => {
                             associated_funcs_and_methods
-                                .entry(opaque_ty.to_string())
+                                .entry(opaque_ty.ty_name_with_generics())
                                 .or_default()
                                 .push(function);
 
@@ -77,34 +111,50 @@ impl SwiftBridgeModule {
                 HostLang::Rust => {
                     gen_func_swift_calls_rust(function, &self.types, &self.swift_bridge_path)
                 }
-                HostLang::Swift => gen_function_exposes_swift_to_rust(
-                    function,
-                    &self.types,
-                    &self.swift_bridge_path,
+                HostLang::Swift => mark_declarations_available(
+                    gen_function_exposes_swift_to_rust(
+                        function,
+                        &self.types,
+                        &self.swift_bridge_path,
+                    ),
+                    function.available.as_ref(),
                 ),
             };
+            // Functions with their own `swift_access` had it applied when they were generated.
+            let func_definition = match function.swift_access {
+                Some(_) => func_definition,
+                None => set_swift_access(func_definition, default_access),
+            };
             swift += &func_definition;
             swift += "\n";
         }
 
+        swift += &set_swift_access(swift_closures_support, default_access);
+
         for ty in self.types.types() {
+            if !cfg_attrs_are_active(ty.cfg_attrs(), config) {
+                continue;
+            }
+
             match ty {
                 TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
                     if let Some(swift_struct) = self.generate_shared_struct_string(shared_struct) {
-                        swift += &swift_struct;
+                        swift += &set_swift_access(swift_struct, default_access);
                         swift += "\n";
                     }
                 }
                 TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
                     if let Some(swift_enum) = self.generate_shared_enum_string(shared_enum) {
-                        swift += &swift_enum;
+                        swift += &set_swift_access(swift_enum, default_access);
                         swift += "\n";
                     }
                 }
                 TypeDeclaration::Opaque(ty) => match ty.host_lang {
                     HostLang::Rust => {
+                        let mut ty_swift = "".to_string();
+
                         if let Some(_copy) = ty.attributes.copy {
-                            swift += &generate_opaque_copy_struct(
+                            ty_swift += &generate_opaque_copy_struct(
                                 ty,
                                 &associated_funcs_and_methods,
                                 &self.types,
@@ -115,7 +165,7 @@ impl SwiftBridgeModule {
                             let default_cp = ClassProtocols::default();
                             let class_protocols = class_protocols.unwrap_or(&default_cp);
 
-                            swift += &generate_swift_class(
+                            ty_swift += &generate_swift_class(
                                 ty,
                                 &associated_funcs_and_methods,
                                 class_protocols,
@@ -124,28 +174,139 @@ impl SwiftBridgeModule {
                             );
                         }
 
-                        swift += "\n";
+                        ty_swift += "\n";
 
                         if !ty.attributes.already_declared {
                             // TODO: Support Vec<OpaqueCopyType>. Add codegen tests and then
                             //  make them pass.
                             // TODO: Support Vec<GenericOpaqueRustType
                             if ty.attributes.copy.is_none() && ty.generics.len() == 0 {
-                                swift += &generate_vectorizable_extension(&ty);
-                                swift += "\n";
+                                ty_swift +=
+                                    &generate_vectorizable_extension(ty, &ty.swift_name_string());
+                                ty_swift += "\n";
                             }
                         }
+
+                        if ty.attributes.observable {
+                            ty_swift += &generate_observable_object(
+                                ty,
+                                &associated_funcs_and_methods,
+                                &self.types,
+                            );
+                        }
+
+                        let access = ty.attributes.swift_access.as_ref().map(LitStr::value);
+                        swift += &set_swift_access(
+                            mark_declarations_available(ty_swift, ty.attributes.available.as_ref()),
+                            access.as_deref().or(default_access),
+                        );
                     }
                     HostLang::Swift => {
-                        swift += &generate_drop_swift_instance_reference_count(ty);
-                        swift += "\n";
+                        if ty.attributes.already_declared {
+                            continue;
+                        }
+
+                        let mut ty_swift = "".to_string();
+                        if ty.attributes.protocol {
+                            ty_swift += &ty.generate_swift_protocol_box();
+                        }
+                        ty_swift += &generate_drop_swift_instance_reference_count(ty);
+                        ty_swift += "\n";
+
+                        let access = ty.attributes.swift_access.as_ref().map(LitStr::value);
+                        swift += &set_swift_access(
+                            mark_declarations_available(ty_swift, ty.attributes.available.as_ref()),
+                            access.as_deref().or(default_access),
+                        );
                     }
                 },
             };
         }
 
+        for (_, custom_swift_code) in self
+            .custom_swift_code(config)
+            .into_iter()
+            .filter(|(key, _)| key.is_none())
+        {
+            swift += &set_swift_access(custom_swift_code, default_access);
+            swift += "\n";
+        }
+
         swift
     }
+
+    /// The custom Swift code that other bridge modules that use the same types generate as well.
+    pub(crate) fn shared_swift_code(&self, config: &CodegenConfig) -> Vec<SharedSwiftCode> {
+        if !self.module_will_be_compiled(config) {
+            return vec![];
+        }
+
+        let default_access = config.default_swift_access.as_deref();
+        self.custom_swift_code(config)
+            .into_iter()
+            .filter_map(|(key, code)| {
+                Some(SharedSwiftCode {
+                    key: key?,
+                    code: set_swift_access(code, default_access),
+                })
+            })
+            .collect()
+    }
+
+    /// Support code for types such as `HashMap<K, V>` that need to generate Swift code for each
+    /// combination of generic arguments that appears in the module's function signatures, along
+    /// with the [`SharedSwiftCode::key`] of the code that other modules generate as well.
+    fn custom_swift_code(&self, config: &CodegenConfig) -> Vec<(Option<String>, String)> {
+        let mut custom_swift_code: Vec<(Option<String>, String)> = vec![];
+        let import_combine = (None, "import Combine".to_string());
+
+        for function in &self.functions {
+            if !cfg_attrs_are_active(&function.cfg_attrs, config) {
+                continue;
+            }
+
+            let mut bridged_types = vec![];
+            for arg in &function.func.sig.inputs {
+                if let Some(ty) = BridgedType::new_with_fn_arg(arg, &self.types) {
+                    bridged_types.push(ty);
+                }
+            }
+            if let Some(ty) =
+                BridgedType::new_with_return_type(&function.func.sig.output, &self.types)
+            {
+                bridged_types.push(ty);
+            }
+
+            if function.publisher && !custom_swift_code.contains(&import_combine) {
+                custom_swift_code.push(import_combine.clone());
+            }
+            if let Some(ty) = function.stream_item_ty(&self.types) {
+                bridged_types.push(ty);
+            }
+
+            for ty in bridged_types {
+                if let Some(code) = ty.generate_custom_swift_code(&self.types) {
+                    if !custom_swift_code
+                        .iter()
+                        .any(|(_, existing)| existing == &code)
+                    {
+                        custom_swift_code.push((ty.shared_swift_code_key(&self.types), code));
+                    }
+                }
+            }
+        }
+
+        // `ObservableObject` is declared in Combine.
+        let has_observable_types = self.types.types().iter().any(|ty| {
+            matches!(ty, TypeDeclaration::Opaque(ty) if ty.attributes.observable)
+                && cfg_attrs_are_active(ty.cfg_attrs(), config)
+        });
+        if has_observable_types && !custom_swift_code.contains(&import_combine) {
+            custom_swift_code.push(import_combine);
+        }
+
+        custom_swift_code
+    }
 }
 
 #[derive(Default)]
@@ -158,6 +319,101 @@ struct IdentifiableProtocol {
     return_ty: String,
 }
 
+/// Convert a Rust doc comment into Swift `///` documentation so that it shows up in Xcode's Quick
+/// Help.
+///
+/// `Some(" Line one\n Line two")` becomes `/// Line one\n/// Line two\n`.
+fn swift_doc_comment(doc_comment: Option<&str>, indentation: &str) -> String {
+    let doc_comment = match doc_comment {
+        Some(doc_comment) => doc_comment,
+        None => return "".to_string(),
+    };
+
+    doc_comment
+        .lines()
+        .map(|line| format!("{indentation}///{line}\n"))
+        .collect()
+}
+
+/// `available = "iOS 16.0"` becomes `@available(iOS 16.0, *)`.
+///
+/// The `*` covers the platforms that were not listed, so it is only added if it wasn't already.
+fn swift_available_attribute(available: &LitStr) -> String {
+    let platforms = available.value();
+    let platforms = platforms.trim();
+
+    if platforms.ends_with('*') {
+        format!("@available({platforms})")
+    } else {
+        format!("@available({platforms}, *)")
+    }
+}
+
+/// Mark each top-level declaration in a type's generated Swift code with the type's
+/// `@available(...)` attribute.
+///
+/// Extensions and subclasses are marked along with the type itself, since Swift rejects
+/// declarations that use a type that is not available on every deployment target.
+fn mark_declarations_available(swift: String, available: Option<&LitStr>) -> String {
+    let available = match available {
+        Some(available) => swift_available_attribute(available),
+        None => return swift,
+    };
+
+    swift
+        .split('\n')
+        .map(|line| {
+            let is_declaration = [
+                "public class ",
+                "public struct ",
+                "final class ",
+                "extension ",
+            ]
+            .iter()
+            .any(|prefix| line.starts_with(prefix))
+                || line.starts_with("@_cdecl(");
+
+            if is_declaration {
+                format!("{available}\n{line}")
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Replace the `public` access modifiers in generated Swift code with another access level, such
+/// as `internal`.
+///
+/// `None` leaves the code `public`.
+fn set_swift_access(swift: String, access: Option<&str>) -> String {
+    let access = match access {
+        Some("public") | None => return swift,
+        Some(access) => access,
+    };
+
+    swift
+        .split('\n')
+        .map(|line| {
+            let declaration = line.trim_start();
+            if declaration.starts_with("//") {
+                return line.to_string();
+            }
+
+            let indentation = &line[..line.len() - declaration.len()];
+            let declaration = match declaration.strip_prefix("public ") {
+                Some(rest) => format!("{access} {rest}"),
+                None => declaration.to_string(),
+            };
+            let declaration = declaration.replace(" public ", &format!(" {access} "));
+
+            format!("{indentation}{declaration}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 // Generate functions to drop the reference count on a Swift class instance.
 //
 // # Example
@@ -176,12 +432,12 @@ fn generate_drop_swift_instance_reference_count(ty: &OpaqueForeignTypeDeclaratio
         r##"
 @_cdecl("{link_name}")
 func {fn_name} (ptr: UnsafeMutableRawPointer) {{
-    let _ = Unmanaged<{ty_name}>.fromOpaque(ptr).takeRetainedValue()
+    let _ = Unmanaged<{class_name}>.fromOpaque(ptr).takeRetainedValue()
 }}
 "##,
         link_name = link_name,
         fn_name = fn_name,
-        ty_name = ty.ty_name_ident()
+        class_name = ty.swift_retained_class_name()
     )
 }
 
@@ -190,6 +446,10 @@ fn gen_function_exposes_swift_to_rust(
     types: &TypeDeclarations,
     swift_bridge_path: &Path,
 ) -> String {
+    if func.sig.asyncness.is_some() {
+        return gen_async_function_exposes_swift_to_rust(func, types, swift_bridge_path);
+    }
+
     let link_name = func.link_name();
     let prefixed_fn_name = func.prefixed_fn_name();
     let fn_name = if let Some(swift_name) = func.swift_name_override.as_ref() {
@@ -203,6 +463,24 @@ fn gen_function_exposes_swift_to_rust(
 
     let args = func.to_swift_call_args(false, true, types, swift_bridge_path);
     let mut call_fn = format!("{}({})", fn_name, args);
+    // `Result<T, NsError>`s keep the caught error's domain, code and user info.
+    let catches_ns_error = BridgedType::new_with_return_type(&func.sig.output, types)
+        .as_ref()
+        .and_then(|ty| ty.as_result())
+        .map(|result| {
+            result.err_ty.to_rust_type_path(types).to_string()
+                == "swift_bridge :: ns_error :: NsError"
+        })
+        .unwrap_or(false);
+    let maybe_catch_errors = |call_fn: String| {
+        if !func.catch_errors {
+            call_fn
+        } else if catches_ns_error {
+            format!("__swift_bridge__catchSwiftNSError({{ try {call_fn} }})")
+        } else {
+            format!("__swift_bridge__catchSwiftError({{ try {call_fn} }})")
+        }
+    };
     if let Some(built_in) = BridgedType::new_with_return_type(&func.sig.output, types) {
         if let Some(associated_type) = func.associated_type.as_ref() {
             let ty_name = match associated_type {
@@ -210,17 +488,28 @@ fn gen_function_exposes_swift_to_rust(
                     //
                     todo!()
                 }
-                TypeDeclaration::Opaque(associated_type) => associated_type.to_string(),
+                TypeDeclaration::Opaque(associated_type) => associated_type.swift_name_string(),
             };
 
             if func.is_method() {
                 call_fn = format!(
-                    "Unmanaged<{ty_name}>.fromOpaque(this).takeUnretainedValue().{call_fn}",
-                    ty_name = ty_name,
+                    "{instance}.{call_fn}",
+                    instance = associated_type
+                        .as_opaque()
+                        .unwrap()
+                        .swift_take_unretained_value("this"),
                     call_fn = call_fn
                 );
                 call_fn = built_in.convert_swift_expression_to_ffi_type(
-                    &call_fn,
+                    &maybe_catch_errors(call_fn),
+                    types,
+                    TypePosition::FnReturn(func.host_lang),
+                );
+            } else if func.is_swift_initializer
+                && matches!(built_in, BridgedType::StdLib(StdLibType::Option(_)))
+            {
+                call_fn = built_in.convert_swift_expression_to_ffi_type(
+                    &format!("{}({})", ty_name, args),
                     types,
                     TypePosition::FnReturn(func.host_lang),
                 );
@@ -231,7 +520,7 @@ fn gen_function_exposes_swift_to_rust(
             }
         } else {
             call_fn = built_in.convert_swift_expression_to_ffi_type(
-                &call_fn,
+                &maybe_catch_errors(call_fn),
                 types,
                 TypePosition::FnReturn(func.host_lang),
             );
@@ -315,8 +604,23 @@ class __private__RustFnOnceCallback{maybe_associated_ty}${fn_name}$param{idx} {{
         call_fn = format!("{{ {callback_initializers} {maybe_ret}{call_fn} }}()")
     }
 
+    if let Some(queue) = func.dispatch_queue.as_ref() {
+        call_fn = format!(
+            "__swift_bridge__callOnQueue({queue}) {{ {call_fn} }}",
+            queue = queue.value()
+        );
+    }
+
+    // Rust can call us from any thread, so `main_actor` functions hop onto the main actor first.
+    let maybe_available = if func.main_actor {
+        call_fn = format!("__swift_bridge__callOnMainActor {{ {call_fn} }}");
+        "@available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)\n"
+    } else {
+        ""
+    };
+
     let generated_func = format!(
-        r#"@_cdecl("{link_name}")
+        r#"{maybe_available}@_cdecl("{link_name}")
 func {prefixed_fn_name} ({params}){ret} {{
     {call_fn}
 }}{rust_fn_once_callback_classes}
@@ -331,11 +635,123 @@ func {prefixed_fn_name} ({params}){ret} {{
     generated_func
 }
 
+// Rust passes us a pointer to the future that it is awaiting along with a function that we call
+// with that pointer once the async Swift function returns.
+//
+// @_cdecl("__swift_bridge__$some_function")
+// func __swift_bridge__some_function (_ completion: UnsafeMutableRawPointer, _ onComplete: @escaping @convention(c) (UnsafeMutableRawPointer, UInt32) -> Void, _ arg: UInt8) {
+//     Task {
+//         let val = await some_function(arg: arg)
+//         onComplete(completion, val)
+//     }
+// }
+fn gen_async_function_exposes_swift_to_rust(
+    func: &ParsedExternFn,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let link_name = func.link_name();
+    let prefixed_fn_name = func.prefixed_fn_name();
+    let fn_name = if let Some(swift_name) = func.swift_name_override.as_ref() {
+        swift_name.value()
+    } else {
+        func.sig.ident.to_string()
+    };
+
+    let params = func.to_swift_param_names_and_types(true, types);
+    let args = func.to_swift_call_args(false, true, types, swift_bridge_path);
+
+    // Methods retain the instance before spawning the task since Rust is free to drop its handle
+    // to the instance as soon as this function returns.
+    let mut maybe_retain_instance = "".to_string();
+    let mut call_fn = format!("{}({})", fn_name, args);
+    if let Some(associated_type) = func.associated_type.as_ref() {
+        let ty_name = match associated_type {
+            TypeDeclaration::Shared(_) => {
+                //
+                todo!()
+            }
+            TypeDeclaration::Opaque(associated_type) => associated_type.swift_name_string(),
+        };
+
+        call_fn = if func.is_method() {
+            maybe_retain_instance = format!(
+                r#"
+    let instance = {}"#,
+                associated_type
+                    .as_opaque()
+                    .unwrap()
+                    .swift_take_unretained_value("this")
+            );
+            format!("instance.{call_fn}")
+        } else {
+            format!("{ty_name}.{call_fn}")
+        };
+    }
+
+    let ret = if let Some(built_in) = BridgedType::new_with_return_type(&func.sig.output, types) {
+        built_in
+    } else {
+        todo!("Push to ParsedErrors")
+    };
+
+    let (on_complete_ty, body) = if ret.is_null() {
+        (
+            "(UnsafeMutableRawPointer) -> Void".to_string(),
+            format!(
+                r#"await {call_fn}
+        onComplete(completion)"#
+            ),
+        )
+    } else {
+        let ffi_ty = ret.to_swift_type(TypePosition::FnReturn(func.host_lang), types);
+        let val = ret.convert_swift_expression_to_ffi_type(
+            "val",
+            types,
+            TypePosition::FnReturn(func.host_lang),
+        );
+
+        (
+            format!("(UnsafeMutableRawPointer, {ffi_ty}) -> Void"),
+            format!(
+                r#"let val = await {call_fn}
+        onComplete(completion, {val})"#
+            ),
+        )
+    };
+
+    let maybe_params = if params.is_empty() {
+        "".to_string()
+    } else {
+        format!(", {params}")
+    };
+
+    let maybe_main_actor = if func.main_actor {
+        " @MainActor in"
+    } else {
+        ""
+    };
+
+    format!(
+        r#"@_cdecl("{link_name}")
+func {prefixed_fn_name} (_ completion: UnsafeMutableRawPointer, _ onComplete: @escaping @convention(c) {on_complete_ty}{maybe_params}) {{{maybe_retain_instance}
+    Task {{{maybe_main_actor}
+        {body}
+    }}
+}}
+"#
+    )
+}
+
 struct ClassMethods {
     initializers: Vec<String>,
     owned_self_methods: Vec<String>,
     ref_self_methods: Vec<String>,
     ref_mut_self_methods: Vec<String>,
+    /// Computed properties that get declared inside of the `Ref` class.
+    ref_self_properties: Vec<String>,
+    /// Computed properties that get declared inside of the `RefMut` class.
+    ref_mut_self_properties: Vec<String>,
 }
 
 fn generate_swift_class_methods(
@@ -348,6 +764,8 @@ fn generate_swift_class_methods(
     let mut owned_self_methods = vec![];
     let mut ref_self_methods = vec![];
     let mut ref_mut_self_methods = vec![];
+    let mut ref_self_properties = vec![];
+    let mut ref_mut_self_properties = vec![];
 
     if let Some(methods) = associated_funcs_and_methods.get(type_name) {
         for type_method in methods {
@@ -357,6 +775,12 @@ fn generate_swift_class_methods(
 
             if type_method.is_swift_initializer {
                 initializers.push(func_definition);
+            } else if type_method.is_swift_property || type_method.is_swift_subscript {
+                if type_method.self_mutability().is_some() {
+                    ref_mut_self_properties.push(func_definition);
+                } else {
+                    ref_self_properties.push(func_definition);
+                }
             } else if is_class_func {
                 ref_self_methods.push(func_definition);
             } else {
@@ -378,6 +802,8 @@ fn generate_swift_class_methods(
         owned_self_methods,
         ref_self_methods,
         ref_mut_self_methods,
+        ref_self_properties,
+        ref_mut_self_properties,
     }
 }
 
@@ -390,7 +816,7 @@ mod tests {
     use syn::parse_quote;
 
     use crate::test_utils::assert_trimmed_generated_contains_trimmed_expected;
-    use crate::SwiftBridgeModule;
+    use crate::{PanicBehavior, SwiftBridgeModule};
 
     /// Verify that we generated a Swift function to call our freestanding function.
     #[test]
@@ -535,7 +961,7 @@ public func foo() -> UInt32 {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    fn foo () -> &[u8];
+                    fn foo () -> &'static [u8];
                 }
             }
         };
@@ -629,6 +1055,9 @@ public class Foo: FooRefMut {
     deinit {
         if isOwned {
             __swift_bridge__$Foo$_free(ptr)
+            #if DEBUG
+            ptr = __swift_bridge__freedPointer
+            #endif
         }
     }
 }
@@ -669,6 +1098,9 @@ public class Foo: FooRefMut {
     deinit {
         if isOwned {
             __swift_bridge__$Foo$_free(ptr)
+            #if DEBUG
+            ptr = __swift_bridge__freedPointer
+            #endif
         }
     }
 }
@@ -870,6 +1302,7 @@ public func foo() -> RustString {
         let start = quote! {
             mod foo {
                 extern "Rust" {
+                    #[swift_bridge(unsafe_pointer)]
                     fn void_pointer (arg1: *const c_void);
                 }
             }
@@ -893,6 +1326,7 @@ func void_pointer(_ arg1: UnsafeRawPointer) {
         let start = quote! {
             mod foo {
                 extern "Rust" {
+                    #[swift_bridge(unsafe_pointer)]
                     fn void_pointer () -> *const c_void;
                 }
             }
@@ -916,6 +1350,7 @@ func void_pointer() -> UnsafeRawPointer {
         let start = quote! {
             mod foo {
                 extern "Swift" {
+                    #[swift_bridge(unsafe_pointer)]
                     fn void_pointer (arg: *const c_void);
                 }
             }
@@ -1011,4 +1446,175 @@ func __swift_bridge__some_function () {
 
         assert_trimmed_generated_contains_trimmed_expected(&generated, &expected);
     }
+
+    /// Verify that the module's `swift_type_prefix` gets prepended to the Swift names of its
+    /// Rust types, but not to renamed types or to opaque Swift types.
+    #[test]
+    fn swift_type_prefix() {
+        let start = quote! {
+            mod foo {
+                enum Message {
+                    Hello
+                }
+
+                extern "Rust" {
+                    type Session;
+                    #[swift_bridge(swift_name = "Renamed")]
+                    type OtherType;
+
+                    fn message(self: &Session) -> Message;
+                }
+
+                extern "Swift" {
+                    type SwiftType;
+                }
+            }
+        };
+        let mut module: SwiftBridgeModule = syn::parse2(start).unwrap();
+        module.set_swift_type_prefix("MyLib");
+        let generated = module.generate_swift(&CodegenConfig::no_features_enabled());
+
+        for expected in [
+            "public enum MyLibMessage {",
+            "public class MyLibSession: MyLibSessionRefMut {",
+            "extension MyLibSessionRef {\n    public func message() -> MyLibMessage {",
+            "public class Renamed: RenamedRefMut {",
+            "Unmanaged<SwiftType>",
+        ] {
+            assert_trimmed_generated_contains_trimmed_expected(&generated, expected);
+        }
+    }
+
+    /// Verify that `inline_shims` annotates the generated Swift functions and initializers with
+    /// `@inline(__always)`.
+    #[test]
+    fn inline_shims() {
+        let start = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Counter;
+
+                    #[swift_bridge(init)]
+                    fn new() -> Counter;
+                    fn count(&self) -> u32;
+                }
+                extern "Rust" {
+                    fn add(a: u32, b: u32) -> u32;
+                }
+            }
+        };
+        let mut module: SwiftBridgeModule = syn::parse2(start).unwrap();
+        module.set_inline_shims(true);
+        let generated = module.generate_swift(&CodegenConfig::no_features_enabled());
+
+        for expected in [
+            "@inline(__always)\npublic func add(_ a: UInt32, _ b: UInt32) -> UInt32 {",
+            "    @inline(__always)\n    public convenience init() {",
+            "    @inline(__always)\n    public func count() -> UInt32 {",
+        ] {
+            assert_trimmed_generated_contains_trimmed_expected(&generated, expected);
+        }
+    }
+
+    /// Verify that `small_string_ffi` makes the generated Swift pass `&str` arguments of
+    /// synchronous Rust functions as `SmallRustStr`s.
+    #[test]
+    fn small_string_ffi() {
+        let start = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Greeter;
+
+                    fn greet(&self, name: &str);
+                }
+                extern "Rust" {
+                    async fn fetch(url: &str);
+                }
+            }
+        };
+        let mut module: SwiftBridgeModule = syn::parse2(start).unwrap();
+        module.set_small_string_ffi(true);
+        let generated = module.generate_swift(&CodegenConfig::no_features_enabled());
+
+        for expected in [
+            r#"
+    public func greet<GenericToRustStr: ToRustStr>(_ name: GenericToRustStr) {
+        name.toSmallRustStr({ nameAsRustStr in
+            __swift_bridge__$Greeter$greet$small_str(ptr, nameAsRustStr)
+        })
+    }
+"#,
+            "url.toRustStr({ urlAsRustStr in",
+        ] {
+            assert_trimmed_generated_contains_trimmed_expected(&generated, expected);
+        }
+    }
+
+    /// Verify that `catch_panics = "throw"` makes the generated Swift functions and initializers
+    /// throw a `RustPanic`, checking for the panic before converting the returned value.
+    #[test]
+    fn catch_panics_throw() {
+        let start = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Parser;
+
+                    #[swift_bridge(init)]
+                    fn new() -> Parser;
+                    fn parse(&self, input: &str) -> String;
+                }
+                extern "Rust" {
+                    fn validate(input: u32) -> Result<u32, String>;
+                }
+            }
+        };
+        let mut module: SwiftBridgeModule = syn::parse2(start).unwrap();
+        module.set_catch_panics(PanicBehavior::Throw);
+        let generated = module.generate_swift(&CodegenConfig::no_features_enabled());
+
+        for expected in [
+            r#"
+    public convenience init() throws {
+        self.init(ptr: try __swift_bridge__rethrowRustPanic(__swift_bridge__$Parser$new()))
+    }
+"#,
+            r#"
+    public func parse<GenericToRustStr: ToRustStr>(_ input: GenericToRustStr) throws -> RustString {
+        return try input.toRustStr({ inputAsRustStr in
+            try RustString(ptr: try __swift_bridge__rethrowRustPanic(__swift_bridge__$Parser$parse(ptr, inputAsRustStr)))
+        })
+    }
+"#,
+            r#"
+public func validate(_ input: UInt32) throws -> UInt32 {
+    try { let val = try __swift_bridge__rethrowRustPanic(__swift_bridge__$validate(input)); switch val.tag {"#,
+        ] {
+            assert_trimmed_generated_contains_trimmed_expected(&generated, expected);
+        }
+    }
+
+    /// Verify that `catch_panics = "fatal_error"` checks for a panic before converting the
+    /// returned value, without making the function throw.
+    #[test]
+    fn catch_panics_fatal_error() {
+        let start = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn parse(input: u32) -> String;
+                }
+            }
+        };
+        let mut module: SwiftBridgeModule = syn::parse2(start).unwrap();
+        module.set_catch_panics(PanicBehavior::FatalError);
+        let generated = module.generate_swift(&CodegenConfig::no_features_enabled());
+
+        assert_trimmed_generated_contains_trimmed_expected(
+            &generated,
+            r#"
+public func parse(_ input: UInt32) -> RustString {
+    RustString(ptr: __swift_bridge__fatalErrorOnRustPanic(__swift_bridge__$parse(input)))
+}
+"#,
+        );
+    }
 }
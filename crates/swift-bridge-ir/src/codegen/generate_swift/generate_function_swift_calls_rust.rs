@@ -1,9 +1,13 @@
 use crate::bridged_type::{fn_arg_name, BridgeableType, BridgedType, StdLibType, TypePosition};
+use crate::codegen::generate_swift::{
+    set_swift_access, swift_available_attribute, swift_doc_comment,
+};
 use crate::parse::{HostLang, TypeDeclaration};
+use crate::parsed_extern_fn::stream_output_and_failure;
 use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use quote::ToTokens;
 use std::ops::Deref;
-use syn::{Path, ReturnType, Type};
+use syn::{LitStr, Path, ReturnType, Type};
 
 pub(super) fn gen_func_swift_calls_rust(
     function: &ParsedExternFn,
@@ -11,18 +15,37 @@ pub(super) fn gen_func_swift_calls_rust(
     swift_bridge_path: &Path,
 ) -> String {
     let fn_name = function.sig.ident.to_string();
+    let linked_fn_name = format!(
+        "{}{}",
+        function.linked_fn_name("$"),
+        function.maybe_small_str_link_name_suffix()
+    );
     let params = function.to_swift_param_names_and_types(false, types);
     let call_args = function.to_swift_call_args(true, false, types, swift_bridge_path);
-    let call_fn = if function.sig.asyncness.is_some() {
+    let stream_item = function.stream_item_ty(types);
+    let call_fn = if let Some(item) = stream_item.as_ref() {
+        let maybe_on_error = if stream_output_and_failure(item).1.is_some() {
+            "onError, "
+        } else {
+            ""
+        };
+        let maybe_args = if function.sig.inputs.is_empty() {
+            "".to_string()
+        } else {
+            format!(", {}", call_args)
+        };
+
+        format!("{linked_fn_name}(onItem, {maybe_on_error}onComplete{maybe_args})")
+    } else if function.sig.asyncness.is_some() {
         let maybe_args = if function.sig.inputs.is_empty() {
             "".to_string()
         } else {
             format!(", {}", call_args)
         };
 
-        format!("{}(wrapperPtr, onComplete{})", fn_name, maybe_args)
+        format!("{}(wrapperPtr, onComplete{})", linked_fn_name, maybe_args)
     } else {
-        format!("{}({})", fn_name, call_args)
+        format!("{}({})", linked_fn_name, call_args)
     };
 
     let maybe_type_name_segment = if let Some(ty) = function.associated_type.as_ref() {
@@ -32,7 +55,11 @@ pub(super) fn gen_func_swift_calls_rust(
                 todo!()
             }
             TypeDeclaration::Opaque(ty) => {
-                format!("${}", ty.to_string())
+                format!(
+                    "${}{}",
+                    ty.ty,
+                    ty.generics.dollar_prefixed_generics_string()
+                )
             }
         }
     } else {
@@ -77,9 +104,32 @@ pub(super) fn gen_func_swift_calls_rust(
         type_name_segment = maybe_type_name_segment,
         call_fn = call_fn
     );
-    let mut call_rust = if function.sig.asyncness.is_some() {
+    // After a panic Rust returns a zeroed value, so we need to check for the panic before we
+    // convert the returned value into a Swift type.
+    let call_rust = if function.throws_rust_panics() {
+        format!("try __swift_bridge__rethrowRustPanic({call_rust})")
+    } else if function.catch_panics.is_some()
+        && function.sig.asyncness.is_none()
+        && stream_item.is_none()
+    {
+        format!("__swift_bridge__fatalErrorOnRustPanic({call_rust})")
+    } else {
         call_rust
-    } else if function.is_swift_initializer {
+    };
+    let mut call_rust = if let Some((output, failure)) = function.stream_swift_types(types) {
+        if function.publisher {
+            format!(
+                "__private__RustStreamPublisher<{output}, {failure}>(stream: {call_rust}).eraseToAnyPublisher()"
+            )
+        } else {
+            let sequence = if failure == "Never" {
+                "asyncStream"
+            } else {
+                "asyncThrowingStream"
+            };
+            format!("__private__RustStreamIterator<{output}, {failure}>(stream: {call_rust}).{sequence}()")
+        }
+    } else if function.sig.asyncness.is_some() || function.is_swift_initializer {
         call_rust
     } else if let Some(built_in) = function.return_ty_built_in(types) {
         built_in.convert_ffi_value_to_swift_value(
@@ -127,9 +177,27 @@ pub(super) fn gen_func_swift_calls_rust(
             }
         }
     };
-    let returns_null = BridgedType::new_with_return_type(&function.func.sig.output, types)
-        .map(|b| b.is_null())
-        .unwrap_or(false);
+    // The returned `RustStr` points into a scratch buffer that the next `return_scratch` call
+    // overwrites, so we copy it into a Swift `String` right away.
+    if function.return_scratch {
+        call_rust = format!("{call_rust}.toString()");
+    }
+
+    // The conversion might have wrapped the call in a closure, so we also need to try the
+    // whole expression.
+    let maybe_try = if function.throws_rust_panics() {
+        "try "
+    } else {
+        ""
+    };
+    if function.throws_rust_panics() && !call_rust.starts_with("try ") {
+        call_rust = format!("try {call_rust}");
+    }
+
+    let returns_null = stream_item.is_none()
+        && BridgedType::new_with_return_type(&function.func.sig.output, types)
+            .map(|b| b.is_null())
+            .unwrap_or(false);
 
     let maybe_return = if returns_null || function.is_swift_initializer {
         ""
@@ -149,8 +217,13 @@ pub(super) fn gen_func_swift_calls_rust(
         // TODO: Refactor to make less duplicative
         match bridged_arg {
             BridgedType::StdLib(StdLibType::Str) => {
+                let to_rust_str = if function.passes_small_strs() {
+                    "toSmallRustStr"
+                } else {
+                    "toRustStr"
+                };
                 call_rust = format!(
-                    r#"{maybe_return}{arg}.toRustStr({{ {arg}AsRustStr in
+                    r#"{maybe_return}{maybe_try}{arg}.{to_rust_str}({{ {arg}AsRustStr in
 {indentation}        {call_rust}
 {indentation}    }})"#,
                     maybe_return = maybe_return,
@@ -161,7 +234,7 @@ pub(super) fn gen_func_swift_calls_rust(
             }
             BridgedType::StdLib(StdLibType::Option(briged_opt)) if briged_opt.ty.is_str() => {
                 call_rust = format!(
-                    r#"{maybe_return}optionalRustStrToRustStr({arg}, {{ {arg}AsRustStr in
+                    r#"{maybe_return}{maybe_try}optionalRustStrToRustStr({arg}, {{ {arg}AsRustStr in
 {indentation}        {call_rust}
 {indentation}    }})"#,
                     maybe_return = maybe_return,
@@ -174,6 +247,25 @@ pub(super) fn gen_func_swift_calls_rust(
         }
     }
 
+    // Calls that have to happen on a specific queue wait for the queue to run them.
+    if let Some(queue) = function.dispatch_queue.as_ref() {
+        let maybe_try = if function.throws_rust_panics()
+            || function.to_swift_return_type(types).starts_with(" throws")
+        {
+            "try "
+        } else {
+            ""
+        };
+        let call_rust_indented = call_rust.replace('\n', "\n    ");
+
+        call_rust = format!(
+            r#"{maybe_return}{maybe_try}__swift_bridge__callOnQueue({queue}) {{
+{indentation}        {call_rust_indented}
+{indentation}    }}"#,
+            queue = queue.value()
+        );
+    }
+
     if function.is_swift_initializer {
         if function.is_copy_method_on_opaque_type() {
             call_rust = format!("self.bytes = {}", call_rust)
@@ -184,9 +276,16 @@ pub(super) fn gen_func_swift_calls_rust(
 
     let maybe_return = if function.is_swift_initializer {
         "".to_string()
+    } else if function.return_scratch {
+        " -> String".to_string()
     } else {
         function.to_swift_return_type(types)
     };
+    let maybe_return = if function.throws_rust_panics() && !maybe_return.starts_with(" throws") {
+        format!(" throws{maybe_return}")
+    } else {
+        maybe_return
+    };
 
     let maybe_generics = function.maybe_swift_generics(types);
 
@@ -293,6 +392,92 @@ return{maybe_try}await {with_checked_continuation_function_name}({{ (continuatio
             fn_body_indented = fn_body_indented,
             callback_wrapper = callback_wrapper
         )
+    } else if let Some(item) = stream_item.as_ref() {
+        let (output, failure) = stream_output_and_failure(item);
+        let (output_ty, failure_ty) = function.stream_swift_types(types).unwrap();
+        // Rust calls these back with the stream's items, so they forward them to the Swift object
+        // that it was subscribed with.
+        let (receiver, on_complete) = if function.publisher {
+            (
+                format!("__private__RustStreamSubscription<{output_ty}, {failure_ty}>"),
+                "receive(completion: .finished)",
+            )
+        } else {
+            (
+                format!("__private__RustStreamReceiver<{output_ty}, {failure_ty}>"),
+                "receive(.success(nil))",
+            )
+        };
+
+        let on_item_ty =
+            output.to_swift_type(TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy, types);
+        let on_item_val = output.convert_ffi_expression_to_swift_type(
+            "item",
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy,
+            types,
+        );
+        let on_item = if function.publisher {
+            format!("receive({on_item_val})")
+        } else {
+            format!("receive(.success({on_item_val}))")
+        };
+        let maybe_on_error = match failure {
+            Some(failure) => {
+                let on_error_ty = failure
+                    .to_swift_type(TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy, types);
+                let on_error_val = failure.convert_ffi_expression_to_swift_type(
+                    "error",
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy,
+                    types,
+                );
+                let on_error = if function.publisher {
+                    format!("receive(completion: .failure({on_error_val}))")
+                } else {
+                    format!("receive(.failure({on_error_val}))")
+                };
+                format!(
+                    r#"
+func onError(subscriberPtr: UnsafeMutableRawPointer?, error: {on_error_ty}) {{
+    {receiver}.fromOpaque(subscriberPtr!).{on_error}
+}}"#
+                )
+            }
+            None => "".to_string(),
+        };
+
+        // `&str` arguments already wrapped the call in a `return`.
+        let maybe_return_keyword = if call_rust.starts_with("return ") {
+            ""
+        } else {
+            "return "
+        };
+        let callbacks = format!(
+            r#"func onItem(subscriberPtr: UnsafeMutableRawPointer?, item: {on_item_ty}) {{
+    {receiver}.fromOpaque(subscriberPtr!).{on_item}
+}}{maybe_on_error}
+func onComplete(subscriberPtr: UnsafeMutableRawPointer?) {{
+    {receiver}.fromOpaque(subscriberPtr!).{on_complete}
+}}"#
+        );
+
+        let mut callbacks_indented = "".to_string();
+        for line in callbacks.lines() {
+            callbacks_indented += &format!("{}    {}\n", indentation, line);
+        }
+
+        format!(
+            r#"{indentation}@available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+{indentation}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}){maybe_ret} {{
+{callbacks_indented}
+{indentation}    {maybe_return_keyword}{call_rust}
+{indentation}}}"#,
+            swift_class_func_name = public_func_fn_name,
+            maybe_ret = maybe_return,
+        )
+    } else if function.is_swift_property {
+        gen_swift_property(function, &call_rust, types)
+    } else if function.is_swift_subscript {
+        gen_swift_subscript(function, &call_rust, types)
     } else {
         format!(
             r#"{indentation}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}){maybe_ret} {{
@@ -307,5 +492,250 @@ return{maybe_try}await {with_checked_continuation_function_name}({{ (continuatio
             call_rust = call_rust,
         )
     };
-    func_definition
+
+    let available = match function.available.as_ref() {
+        Some(available) => format!("{indentation}{}\n", swift_available_attribute(available)),
+        None => "".to_string(),
+    };
+
+    // Computed properties and subscripts would need the attribute on each of their accessors, and
+    // async functions don't benefit from it, so we only hint plain functions and initializers.
+    let maybe_inline = if function.inline_shims
+        && function.sig.asyncness.is_none()
+        && !function.is_swift_property
+        && !function.is_swift_subscript
+    {
+        format!("{indentation}@inline(__always)\n")
+    } else {
+        "".to_string()
+    };
+
+    let func_definition = set_swift_access(
+        func_definition,
+        function.swift_access.as_ref().map(LitStr::value).as_deref(),
+    );
+
+    swift_doc_comment(function.doc_comment.as_deref(), indentation)
+        + &available
+        + &maybe_inline
+        + &func_definition
+}
+
+/// Generate a computed property for a getter or setter that is exposed as a Swift property.
+///
+/// Setters live on the `RefMut` class, so they override the getter's read-only property from the
+/// `Ref` class with a read-write one.
+///
+/// ```swift
+/// public var age: UInt8 {
+///     __swift_bridge__$Person$age(ptr)
+/// }
+///
+/// public override var age: UInt8 {
+///     get {
+///         super.age
+///     }
+///     set(age) {
+///         __swift_bridge__$Person$set_age(ptr, age)
+///     }
+/// }
+/// ```
+fn gen_swift_property(
+    function: &ParsedExternFn,
+    call_rust: &str,
+    types: &TypeDeclarations,
+) -> String {
+    let field_name = function.swift_property_name();
+
+    if function.self_mutability().is_some() {
+        let value_arg = function.func.sig.inputs.iter().last().unwrap();
+        let value_name = fn_arg_name(value_arg).unwrap().to_string();
+        // The setter has to override the getter's property, so we use the type that the getter
+        // returns instead of the setter's generic argument type, such as `GenericIntoRustString`.
+        let field_ty = BridgedType::new_with_fn_arg(value_arg, types)
+            .unwrap()
+            .to_swift_type(TypePosition::FnReturn(function.host_lang), types);
+
+        format!(
+            r#"    public override var {field_name}: {field_ty} {{
+        get {{
+            super.{field_name}
+        }}
+        set({value_name}) {{
+            {call_rust}
+        }}
+    }}"#
+        )
+    } else {
+        let field_ty = function
+            .return_ty_built_in(types)
+            .unwrap()
+            .to_swift_type(TypePosition::FnReturn(function.host_lang), types);
+
+        format!(
+            r#"    public var {field_name}: {field_ty} {{
+        {call_rust}
+    }}"#
+        )
+    }
+}
+
+/// Generate a subscript for a `#[swift_bridge(subscript)]` getter or setter.
+///
+/// Like computed properties, setters live on the `RefMut` class and override the getter's
+/// read-only subscript from the `Ref` class with a read-write one.
+///
+/// ```swift
+/// public subscript(_ index: UInt) -> UInt8 {
+///     __swift_bridge__$Buffer$get(ptr, index)
+/// }
+///
+/// public override subscript(_ index: UInt) -> UInt8 {
+///     get {
+///         super[index]
+///     }
+///     set(value) {
+///         __swift_bridge__$Buffer$set(ptr, index, value)
+///     }
+/// }
+/// ```
+fn gen_swift_subscript(
+    function: &ParsedExternFn,
+    call_rust: &str,
+    types: &TypeDeclarations,
+) -> String {
+    let mut params = function.to_swift_params(false, types);
+
+    if function.self_mutability().is_some() {
+        let value_arg = function.func.sig.inputs.iter().last().unwrap();
+        let value_name = fn_arg_name(value_arg).unwrap().to_string();
+        let value_ty = BridgedType::new_with_fn_arg(value_arg, types)
+            .unwrap()
+            .to_swift_type(TypePosition::FnReturn(function.host_lang), types);
+
+        params.pop();
+        let index_names: Vec<String> = function
+            .func
+            .sig
+            .inputs
+            .iter()
+            .skip(1)
+            .take(params.len())
+            .map(|arg| fn_arg_name(arg).unwrap().to_string())
+            .collect();
+
+        format!(
+            r#"    public override subscript({params}) -> {value_ty} {{
+        get {{
+            super[{index_names}]
+        }}
+        set({value_name}) {{
+            {call_rust}
+        }}
+    }}"#,
+            params = params.join(", "),
+            index_names = index_names.join(", "),
+        )
+    } else {
+        let ret_ty = function
+            .return_ty_built_in(types)
+            .unwrap()
+            .to_swift_type(TypePosition::FnReturn(function.host_lang), types);
+
+        format!(
+            r#"    public subscript({params}) -> {ret_ty} {{
+        {call_rust}
+    }}"#,
+            params = params.join(", "),
+        )
+    }
+}
+
+/// Generate the functions that Rust uses to call and release the Swift closures that get passed
+/// to a Rust function's `Box<dyn Fn(A, B) -> C>` arguments.
+///
+/// ```swift
+/// @_cdecl("__swift_bridge__$some_function$param0")
+/// func __swift_bridge__some_function_param0 (_ callback: UnsafeMutableRawPointer, _ arg0: UInt8) {
+///     Unmanaged<__private__SwiftClosure<(UInt8) -> ()>>.fromOpaque(callback).takeUnretainedValue().closure(arg0)
+/// }
+///
+/// @_cdecl("__swift_bridge__$some_function$_free$param0")
+/// func __swift_bridge__some_function_param0__free (_ callback: UnsafeMutableRawPointer) {
+///     let _ = Unmanaged<__private__SwiftClosure<(UInt8) -> ()>>.fromOpaque(callback).takeRetainedValue()
+/// }
+/// ```
+pub(super) fn gen_swift_closures_support(
+    function: &ParsedExternFn,
+    types: &TypeDeclarations,
+) -> String {
+    let mut support = "".to_string();
+
+    let prefixed_fn_name = function.prefixed_fn_name();
+
+    for (idx, boxed_fn) in function.args_filtered_to_boxed_fns(types) {
+        let call_link_name = function.call_boxed_fn_link_name(idx);
+        let free_link_name = function.free_boxed_fn_link_name(idx);
+
+        let closure_ty = boxed_fn.to_swift_closure_type(types);
+
+        let mut params = "".to_string();
+        let mut args = vec![];
+        for (param_idx, ty) in boxed_fn.params.iter().enumerate() {
+            let arg_name = format!("arg{}", param_idx);
+            let ffi_ty = ty.to_swift_type(TypePosition::FnArg(HostLang::Swift, param_idx), types);
+
+            params += &format!(", _ {}: {}", arg_name, ffi_ty);
+            args.push(ty.convert_ffi_value_to_swift_value(
+                &arg_name,
+                TypePosition::FnReturn(HostLang::Rust),
+                types,
+            ));
+        }
+
+        let maybe_ret = if boxed_fn.ret.is_null() {
+            "".to_string()
+        } else {
+            format!(
+                " -> {}",
+                boxed_fn
+                    .ret
+                    .to_swift_type(TypePosition::FnReturn(HostLang::Swift), types)
+            )
+        };
+
+        let call_closure = format!(
+            "Unmanaged<__private__SwiftClosure<{closure_ty}>>.fromOpaque(callback).takeUnretainedValue().closure({})",
+            args.join(", ")
+        );
+        let call_closure = if boxed_fn.ret.is_null() {
+            call_closure
+        } else {
+            let ret = boxed_fn.ret.convert_swift_expression_to_ffi_type(
+                "ret",
+                types,
+                TypePosition::FnArg(HostLang::Rust, 0),
+            );
+            format!(
+                r#"let ret = {call_closure}
+    return {ret}"#
+            )
+        };
+
+        support += &format!(
+            r#"
+@_cdecl("{call_link_name}")
+func {prefixed_fn_name}_param{idx} (_ callback: UnsafeMutableRawPointer{params}){maybe_ret} {{
+    {call_closure}
+}}
+
+@_cdecl("{free_link_name}")
+func {prefixed_fn_name}_param{idx}__free (_ callback: UnsafeMutableRawPointer) {{
+    let _ = Unmanaged<__private__SwiftClosure<{closure_ty}>>.fromOpaque(callback).takeRetainedValue()
+}}
+"#
+        );
+    }
+
+    support
 }
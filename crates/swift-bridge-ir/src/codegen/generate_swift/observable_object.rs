@@ -0,0 +1,182 @@
+use crate::bridged_type::TypePosition;
+use crate::codegen::generate_swift::swift_doc_comment;
+use crate::parse::OpaqueForeignTypeDeclaration;
+use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
+use std::collections::HashMap;
+
+/// Generate the `ObservableObject` that wraps a `#[swift_bridge(observable)]` type.
+///
+/// Each Swift property of the type becomes a `@Published` property, which is read-only unless the
+/// type has a setter for it. The properties are re-read whenever Rust notifies the type's
+/// `ChangeNotifier`. The wrapper stops observing when it is deinitialized.
+///
+/// ```swift
+/// @available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+/// public final class ObservableCounter: ObservableObject, __private__RefreshableObservableObject {
+///     public let value: Counter
+///     private var isRefreshing = false
+///     private var observer: UnsafeMutableRawPointer?
+///
+///     @Published public var count: UInt32 {
+///         didSet {
+///             if !isRefreshing {
+///                 value.count = count
+///             }
+///         }
+///     }
+///
+///     public init(_ value: Counter) {
+///         self.value = value
+///         self.count = value.count
+///         observer = __private__ObservableObjectObserver.observe(self) {
+///             __swift_bridge__$Counter$_observe(value.ptr, $0, $1, $2)
+///         }
+///     }
+///
+///     deinit {
+///         __swift_bridge__$Counter$_unobserve(value.ptr, observer)
+///     }
+///
+///     func refresh() {
+///         isRefreshing = true
+///         defer { isRefreshing = false }
+///         count = value.count
+///     }
+/// }
+/// ```
+pub(super) fn generate_observable_object(
+    ty: &OpaqueForeignTypeDeclaration,
+    associated_funcs_and_methods: &HashMap<String, Vec<&ParsedExternFn>>,
+    types: &TypeDeclarations,
+) -> String {
+    let type_name = ty.swift_name_string();
+    let methods = associated_funcs_and_methods
+        .get(&ty.ty_name_with_generics())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let properties: Vec<ObservableProperty> = methods
+        .iter()
+        .filter(|getter| getter.is_swift_property && getter.self_mutability().is_none())
+        .map(|getter| {
+            let name = getter.swift_property_name();
+            let has_setter = methods.iter().any(|setter| {
+                setter.is_swift_property
+                    && setter.self_mutability().is_some()
+                    && setter.swift_property_name() == name
+            });
+            let ty = getter
+                .return_ty_built_in(types)
+                .unwrap()
+                .to_swift_type(TypePosition::FnReturn(getter.host_lang), types);
+
+            ObservableProperty {
+                name,
+                ty,
+                has_setter,
+                doc_comment: getter.doc_comment.as_deref(),
+            }
+        })
+        .collect();
+
+    let mut published = "".to_string();
+    let mut initial_values = "".to_string();
+    let mut refreshed_values = "".to_string();
+    for property in properties.iter() {
+        let name = &property.name;
+        let ty = property.published_ty();
+        let read = property.read_from("value");
+
+        published += &swift_doc_comment(property.doc_comment, "    ");
+        if property.has_setter {
+            let write = property.write_to("value");
+            published += &format!(
+                r#"    @Published public var {name}: {ty} {{
+        didSet {{
+            if !isRefreshing {{
+                {write}
+            }}
+        }}
+    }}
+"#
+            );
+        } else {
+            published += &format!("    @Published public private(set) var {name}: {ty}\n");
+        }
+
+        initial_values += &format!("\n        self.{name} = {read}");
+        refreshed_values += &format!("\n        {name} = {read}");
+    }
+
+    let observe_fn = format!("{SWIFT_BRIDGE_PREFIX}${}$_observe", ty.ty);
+    let unobserve_fn = format!("{SWIFT_BRIDGE_PREFIX}${}$_unobserve", ty.ty);
+
+    format!(
+        r#"@available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *)
+public final class Observable{type_name}: ObservableObject, __private__RefreshableObservableObject {{
+    /// The Rust value whose properties get published.
+    public let value: {type_name}
+    private var isRefreshing = false
+    private var observer: UnsafeMutableRawPointer?
+
+{published}
+    public init(_ value: {type_name}) {{
+        self.value = value{initial_values}
+        observer = __private__ObservableObjectObserver.observe(self) {{
+            {observe_fn}(value.ptr, $0, $1, $2)
+        }}
+    }}
+
+    deinit {{
+        {unobserve_fn}(value.ptr, observer)
+    }}
+
+    func refresh() {{
+        isRefreshing = true
+        defer {{ isRefreshing = false }}{refreshed_values}
+    }}
+}}
+"#
+    )
+}
+
+struct ObservableProperty<'a> {
+    name: String,
+    /// The Swift type that the getter returns.
+    ty: String,
+    has_setter: bool,
+    doc_comment: Option<&'a str>,
+}
+
+impl ObservableProperty<'_> {
+    /// Strings are published as Swift `String`s, which SwiftUI controls such as `TextField` can
+    /// bind to.
+    fn is_string(&self) -> bool {
+        self.ty == "RustString" || self.ty == "RustStr"
+    }
+
+    fn published_ty(&self) -> &str {
+        if self.is_string() {
+            "String"
+        } else {
+            &self.ty
+        }
+    }
+
+    fn read_from(&self, value: &str) -> String {
+        if self.is_string() {
+            format!("{value}.{}.toString()", self.name)
+        } else {
+            format!("{value}.{}", self.name)
+        }
+    }
+
+    fn write_to(&self, value: &str) -> String {
+        let name = &self.name;
+        if self.is_string() {
+            format!("{value}.{name} = {name}.intoRustString()")
+        } else {
+            format!("{value}.{name} = {name}")
+        }
+    }
+}
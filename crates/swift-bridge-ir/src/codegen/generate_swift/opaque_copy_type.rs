@@ -1,4 +1,4 @@
-use crate::codegen::generate_swift::generate_swift_class_methods;
+use crate::codegen::generate_swift::{generate_swift_class_methods, swift_doc_comment};
 use crate::parse::OpaqueForeignTypeDeclaration;
 use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use std::collections::HashMap;
@@ -10,29 +10,42 @@ pub(super) fn generate_opaque_copy_struct(
     types: &TypeDeclarations,
     swift_bridge_path: &Path,
 ) -> String {
-    let type_name = &ty.ty.to_string();
+    let type_name = &ty.swift_name_string();
 
     let class_methods = generate_swift_class_methods(
-        &type_name,
+        &ty.ty_name_with_generics(),
         associated_funcs_and_methods,
         types,
         swift_bridge_path,
     );
 
+    let extended_type = format!("{}{}", type_name, ty.generics.swift_where_clause(types));
+
     let mut extensions = "".to_string();
 
-    append_methods_extension(&mut extensions, type_name, &class_methods.initializers);
+    append_methods_extension(&mut extensions, &extended_type, &class_methods.initializers);
     append_methods_extension(
         &mut extensions,
-        type_name,
+        &extended_type,
         &class_methods.owned_self_methods,
     );
-    append_methods_extension(&mut extensions, type_name, &class_methods.ref_self_methods);
+    append_methods_extension(
+        &mut extensions,
+        &extended_type,
+        &class_methods.ref_self_methods,
+    );
 
     if class_methods.owned_self_methods.len() > 0 {};
 
     let struct_definition = if !ty.attributes.already_declared {
-        generate_struct_definition(ty, types)
+        let mut struct_definition = generate_struct_definition(ty, types);
+        if let Some(sendable) = ty.attributes.sendable {
+            struct_definition += &format!(
+                "\nextension {type_name}: {} {{}}",
+                sendable.swift_conformance()
+            );
+        }
+        struct_definition
     } else {
         "".to_string()
     };
@@ -48,15 +61,17 @@ fn generate_struct_definition(
     ty: &OpaqueForeignTypeDeclaration,
     types: &TypeDeclarations,
 ) -> String {
-    let type_name = ty.ty.to_string();
+    let type_name = ty.swift_name_string();
+    let ffi_type_name = &ty.ty;
     let generics = ty.generics.angle_bracketed_generic_placeholders_string();
+    let doc_comment = swift_doc_comment(ty.attributes.doc_comment.as_deref(), "");
 
     let declare_struct = if ty.generics.is_empty() {
         format!(
-            r#"public struct {type_name} {{
-    fileprivate var bytes: {prefix}${type_name}
+            r#"{doc_comment}public struct {type_name} {{
+    fileprivate var bytes: {prefix}${ffi_type_name}
 
-    func intoFfiRepr() -> {prefix}${type_name} {{
+    func intoFfiRepr() -> {prefix}${ffi_type_name} {{
         bytes
     }}
 }}"#,
@@ -65,7 +80,7 @@ fn generate_struct_definition(
         )
     } else {
         format!(
-            r#"public struct {type_name}{generics} {{
+            r#"{doc_comment}public struct {type_name}{generics} {{
     fileprivate var bytes: SwiftBridgeGenericCopyTypeFfiRepr
 }}"#,
             type_name = type_name,
@@ -75,7 +90,7 @@ fn generate_struct_definition(
 
     let ffi_repr_conversion = if ty.generics.is_empty() {
         format!(
-            r#"extension {prefix}${type_name} {{
+            r#"extension {prefix}${ffi_type_name} {{
     func intoSwiftRepr() -> {type_name} {{
         {type_name}(bytes: self)
     }}
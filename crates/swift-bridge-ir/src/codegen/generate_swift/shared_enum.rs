@@ -1,4 +1,5 @@
 use crate::bridged_type::{BridgedType, SharedEnum, StructFields, TypePosition};
+use crate::codegen::generate_swift::swift_doc_comment;
 use crate::SwiftBridgeModule;
 
 impl SwiftBridgeModule {
@@ -147,8 +148,18 @@ extension {enum_name}: CustomDebugStringConvertible {{
             "".to_string()
         };
 
+        let sendable_impl = match shared_enum.sendable {
+            Some(sendable) => format!(
+                "\nextension {enum_name}: {} {{}}",
+                sendable.swift_conformance()
+            ),
+            None => "".to_string(),
+        };
+
+        let doc_comment = swift_doc_comment(shared_enum.doc_comment.as_deref(), "");
+
         let swift_enum = format!(
-            r#"public enum {enum_name} {{{variants}}}
+            r#"{doc_comment}public enum {enum_name} {{{variants}}}
 extension {enum_name} {{
     func intoFfiRepr() -> {ffi_repr_name} {{
         switch self {{{convert_swift_to_ffi_repr}}}
@@ -176,7 +187,7 @@ extension {option_ffi_name} {{
             return {option_ffi_name}(is_some: false, val: {ffi_repr_name}())
         }}
     }}
-}}{vectorizable_impl}{derive_debug_impl}"#,
+}}{vectorizable_impl}{derive_debug_impl}{sendable_impl}"#,
             enum_name = enum_name,
             enum_ffi_name = enum_ffi_name,
             option_ffi_name = option_ffi_name,
@@ -1,5 +1,6 @@
 use crate::bridged_type::shared_struct::StructField;
 use crate::bridged_type::{BridgedType, SharedStruct, StructFields, StructSwiftRepr, TypePosition};
+use crate::codegen::generate_swift::swift_doc_comment;
 use crate::SwiftBridgeModule;
 
 impl SwiftBridgeModule {
@@ -50,7 +51,7 @@ impl SwiftBridgeModule {
                 // No need to generate any code. Swift will automatically generate a
                 //  struct from our C header typedef that we generate for this struct.
                 let swift_struct = format!(
-                    r#"public struct {struct_name} {{{fields}
+                    r#"{doc_comment}public struct {struct_name} {{{fields}
     public init({initializer_params}) {{{initializer_body}}}
 
     @inline(__always)
@@ -83,6 +84,7 @@ extension {option_ffi_name} {{
         }}
     }}
 }}"#,
+                    doc_comment = swift_doc_comment(shared_struct.doc_comment.as_deref(), ""),
                     struct_name = struct_name,
                     initializer_params = initializer_params,
                     initializer_body = initializer_body,
@@ -93,7 +95,21 @@ extension {option_ffi_name} {{
                     convert_ffi_repr_to_swift = convert_ffi_repr_to_swift
                 );
 
-                Some(swift_struct)
+                let identifiable = if shared_struct.identifiable {
+                    format!("\nextension {struct_name}: Identifiable {{}}")
+                } else {
+                    "".to_string()
+                };
+
+                let sendable = match shared_struct.sendable {
+                    Some(sendable) => format!(
+                        "\nextension {struct_name}: {} {{}}",
+                        sendable.swift_conformance()
+                    ),
+                    None => "".to_string(),
+                };
+
+                Some(swift_struct + &identifiable + &sendable)
             }
         }
     }
@@ -105,23 +121,19 @@ extension {option_ffi_name} {{
     where
         T: StructField + 'a,
     {
-        let mut params = "".to_string();
+        let mut params = vec![];
 
         for field in struct_fields.into_iter() {
             let bridged_ty = BridgedType::new_with_type(field.field_type(), &self.types).unwrap();
 
-            params += &format!(
-                "{}: {},",
+            params.push(format!(
+                "{}: {}",
                 field.swift_name_string(),
                 bridged_ty.to_swift_type(TypePosition::SharedStructField, &self.types)
-            );
-        }
-
-        if !params.is_empty() {
-            params.pop();
+            ));
         }
 
-        params
+        params.join(", ")
     }
 
     fn convert_fields_to_initializer_body<'a, T>(
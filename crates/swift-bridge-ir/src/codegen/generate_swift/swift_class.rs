@@ -1,4 +1,6 @@
-use crate::codegen::generate_swift::{generate_swift_class_methods, ClassProtocols};
+use crate::codegen::generate_swift::{
+    generate_swift_class_methods, swift_doc_comment, ClassMethods, ClassProtocols,
+};
 use crate::parse::OpaqueForeignTypeDeclaration;
 use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use std::collections::HashMap;
@@ -14,44 +16,127 @@ pub(super) fn generate_swift_class(
     let type_name = ty.to_string();
 
     let class_methods = generate_swift_class_methods(
-        &type_name,
+        &ty.ty_name_with_generics(),
         associated_funcs_and_methods,
         types,
         swift_bridge_path,
     );
 
-    create_class_declaration(
-        ty,
-        class_protocols,
-        &class_methods.initializers,
-        &class_methods.owned_self_methods,
-        &class_methods.ref_self_methods,
-        &class_methods.ref_mut_self_methods,
-        types,
+    let mut class = create_class_declaration(ty, class_protocols, &class_methods, types);
+
+    // `DispatchQueue` and `Thread` are declared in Foundation.
+    if ty.attributes.drop_on.is_some() {
+        class = format!("import Foundation\n{class}");
+    }
+
+    if ty.trait_object {
+        class += &create_trait_protocol(
+            &type_name,
+            &ty.swift_name_string(),
+            associated_funcs_and_methods,
+            types,
+        );
+    }
+
+    class
+}
+
+/// Generate the `{Trait}Protocol` protocol for a bridged trait, along with an extension that
+/// conforms the trait's class to it.
+///
+/// ```swift
+/// public protocol ShapeProtocol {
+///     func area() -> Double
+/// }
+/// extension ShapeRef: ShapeProtocol {}
+/// ```
+fn create_trait_protocol(
+    type_name: &str,
+    swift_type_name: &str,
+    associated_funcs_and_methods: &HashMap<String, Vec<&ParsedExternFn>>,
+    types: &TypeDeclarations,
+) -> String {
+    let methods = associated_funcs_and_methods
+        .get(type_name)
+        .map(|methods| methods.as_slice())
+        .unwrap_or(&[]);
+
+    let requirements: Vec<String> = methods
+        .iter()
+        .map(|method| {
+            let fn_name = match &method.swift_name_override {
+                Some(swift_name) => swift_name.value(),
+                None => method.sig.ident.to_string(),
+            };
+            let maybe_async = if method.sig.asyncness.is_some() {
+                " async"
+            } else {
+                ""
+            };
+
+            format!(
+                "    func {fn_name}({params}){maybe_async}{maybe_ret}",
+                params = method.to_swift_param_names_and_types(false, types),
+                maybe_ret = method.to_swift_return_type(types),
+            )
+        })
+        .collect();
+
+    // `&mut self` methods live on the `{Trait}RefMut` class, which also inherits all of the
+    // `&self` methods from `{Trait}Ref`.
+    let conforming_class = if methods.iter().any(|m| m.self_mutability().is_some()) {
+        format!("{swift_type_name}RefMut")
+    } else {
+        format!("{swift_type_name}Ref")
+    };
+
+    format!(
+        r#"
+public protocol {swift_type_name}Protocol {{
+{requirements}
+}}
+extension {conforming_class}: {swift_type_name}Protocol {{}}"#,
+        requirements = requirements.join("\n"),
     )
 }
 
 fn create_class_declaration(
     ty: &OpaqueForeignTypeDeclaration,
     class_protocols: &ClassProtocols,
-    initializers: &[String],
-    owned_self_methods: &[String],
-    ref_self_methods: &[String],
-    ref_mut_self_methods: &[String],
+    class_methods: &ClassMethods,
     types: &TypeDeclarations,
 ) -> String {
-    let type_name = &ty.ty_name_ident().to_string();
+    let ClassMethods {
+        initializers,
+        owned_self_methods,
+        ref_self_methods,
+        ref_mut_self_methods,
+        ref_self_properties,
+        ref_mut_self_properties,
+    } = class_methods;
+    let type_name = &ty.swift_name_string();
     let generics = ty.generics.angle_bracketed_generic_placeholders_string();
 
+    let is_concrete_generic = !ty.generics.is_empty() && !ty.attributes.declare_generic;
+    let where_clause = if is_concrete_generic {
+        ty.generics.swift_where_clause(types)
+    } else {
+        "".to_string()
+    };
+
     let mut class_decl = {
         let free_func_call = if ty.generics.len() == 0 {
-            format!("{}${}$_free(ptr)", SWIFT_BRIDGE_PREFIX, type_name)
+            format!("{}${}$_free(ptr)", SWIFT_BRIDGE_PREFIX, ty.ty_name_ident())
         } else {
             "(self as! SwiftBridgeGenericFreer).rust_free()".to_string()
         };
+        let free_func_call = match &ty.attributes.drop_on {
+            Some(queue) => free_on_queue(&queue.value(), &free_func_call),
+            None => free_func_call,
+        };
 
         format!(
-            r#"public class {type_name}{generics}: {type_name}RefMut{generics} {{
+            r#"{doc_comment}public class {type_name}{generics}: {type_name}RefMut{generics} {{
     var isOwned: Bool = true
 
     public override init(ptr: UnsafeMutableRawPointer) {{
@@ -61,9 +146,13 @@ fn create_class_declaration(
     deinit {{
         if isOwned {{
             {free_func_call}
+            #if DEBUG
+            ptr = __swift_bridge__freedPointer
+            #endif
         }}
     }}
 }}"#,
+            doc_comment = swift_doc_comment(ty.attributes.doc_comment.as_deref(), ""),
             type_name = type_name,
             generics = generics,
             free_func_call = free_func_call
@@ -76,10 +165,11 @@ fn create_class_declaration(
 public class {type_name}RefMut{generics}: {type_name}Ref{generics} {{
     public override init(ptr: UnsafeMutableRawPointer) {{
         super.init(ptr: ptr)
-    }}
+    }}{properties}
 }}"#,
             type_name = type_name,
-            generics = generics
+            generics = generics,
+            properties = class_body_properties(ref_mut_self_properties)
         )
     };
     let mut class_ref_decl = {
@@ -90,10 +180,11 @@ public class {type_name}Ref{generics} {{
 
     public init(ptr: UnsafeMutableRawPointer) {{
         self.ptr = ptr
-    }}
+    }}{properties}
 }}"#,
             type_name = type_name,
-            generics = generics
+            generics = generics,
+            properties = class_body_properties(ref_self_properties)
         )
     };
     if let Some(identifiable) = class_protocols.identifiable.as_ref() {
@@ -117,6 +208,12 @@ extension {type_name}Ref: Identifiable {{{identifiable_var}}}"#,
             type_name = type_name,
             identifiable_var = identifiable_var,
         );
+    } else if ty.attributes.identifiable {
+        // The `id` property generated from `get(id: ...)` satisfies the protocol.
+        class_ref_decl += &format!(
+            r#"
+extension {type_name}Ref: Identifiable {{}}"#
+        );
     }
 
     let initializers = if initializers.len() == 0 {
@@ -125,10 +222,11 @@ extension {type_name}Ref: Identifiable {{{identifiable_var}}}"#,
         let initializers: String = initializers.join("\n\n");
         format!(
             r#"
-extension {type_name} {{
+extension {type_name}{where_clause} {{
 {initializers}
 }}"#,
             type_name = type_name,
+            where_clause = where_clause,
             initializers = initializers
         )
     };
@@ -139,10 +237,11 @@ extension {type_name} {{
         let owned_instance_methods: String = owned_self_methods.join("\n\n");
         format!(
             r#"
-extension {type_name} {{
+extension {type_name}{where_clause} {{
 {owned_instance_methods}
 }}"#,
             type_name = type_name,
+            where_clause = where_clause,
             owned_instance_methods = owned_instance_methods
         )
     };
@@ -153,10 +252,11 @@ extension {type_name} {{
         let ref_instance_methods: String = ref_self_methods.join("\n\n");
         format!(
             r#"
-extension {type_name}Ref {{
+extension {type_name}Ref{where_clause} {{
 {ref_instance_methods}
 }}"#,
             type_name = type_name,
+            where_clause = where_clause,
             ref_instance_methods = ref_instance_methods
         )
     };
@@ -167,16 +267,15 @@ extension {type_name}Ref {{
         let ref_mut_instance_methods: String = ref_mut_self_methods.join("\n\n");
         format!(
             r#"
-extension {type_name}RefMut {{
+extension {type_name}RefMut{where_clause} {{
 {ref_mut_instance_methods}
 }}"#,
             type_name = type_name,
+            where_clause = where_clause,
             ref_mut_instance_methods = ref_mut_instance_methods
         )
     };
 
-    let is_concrete_generic = ty.generics.len() > 0 && !ty.attributes.declare_generic;
-
     if ty.attributes.already_declared || is_concrete_generic {
         class_decl = "".to_string();
         class_ref_decl = "".to_string();
@@ -203,9 +302,54 @@ where {swift_generic_bounds} {{
             let ty_name = ty.ty_name_ident();
             format!(
                 r#"
-extension {ty_name}Ref: Equatable {{
-    public static func == (lhs: {ty_name}Ref, rhs: {ty_name}Ref) -> Bool {{
-        __swift_bridge__${ty_name}$_partial_eq(rhs.ptr, lhs.ptr)
+extension {type_name}Ref: Equatable {{
+    public static func == (lhs: {type_name}Ref, rhs: {type_name}Ref) -> Bool {{
+        __swift_bridge__${ty_name}$_partial_eq(lhs.ptr, rhs.ptr)
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let comparable_method: String = {
+        if ty.attributes.comparable {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {type_name}Ref: Comparable {{
+    public static func < (lhs: {type_name}Ref, rhs: {type_name}Ref) -> Bool {{
+        __swift_bridge__${ty_name}$_partial_lt(lhs.ptr, rhs.ptr)
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let add_method: String = {
+        if ty.attributes.add {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {type_name}Ref {{
+    public static func + (lhs: {type_name}Ref, rhs: {type_name}Ref) -> {type_name} {{
+        {type_name}(ptr: __swift_bridge__${ty_name}$_add(lhs.ptr, rhs.ptr))
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let default_initializer: String = {
+        if ty.attributes.default {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {type_name} {{
+    public convenience init() {{
+        self.init(ptr: __swift_bridge__${ty_name}$_default())
     }}
 }}"#,
             )
@@ -218,7 +362,7 @@ extension {ty_name}Ref: Equatable {{
             let ty_name = ty.ty_name_ident();
             format!(
                 r#"
-extension {ty_name}Ref: Hashable{{
+extension {type_name}Ref: Hashable{{
     public func hash(into hasher: inout Hasher){{
         hasher.combine(__swift_bridge__${ty_name}$_hash(self.ptr))
     }}
@@ -229,9 +373,50 @@ extension {ty_name}Ref: Hashable{{
             "".to_string()
         }
     };
+    let debug_description: String = {
+        if ty.attributes.debug {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {type_name}Ref: CustomDebugStringConvertible {{
+    public var debugDescription: String {{
+        RustString(ptr: __swift_bridge__${ty_name}$_debug(self.ptr)).toString()
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let description: String = {
+        if ty.attributes.display {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {type_name}Ref: CustomStringConvertible {{
+    public var description: String {{
+        RustString(ptr: __swift_bridge__${ty_name}$_display(self.ptr)).toString()
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let is_declared_here = !ty.attributes.already_declared && !is_concrete_generic;
+    // Swift can't check the `Sendable` conformance of a class that holds a pointer, so the
+    // generated Rust code checks that the type is `Send + Sync` instead.
+    let sendable = if ty.attributes.sendable.is_some() && is_declared_here {
+        format!(
+            r#"
+extension {type_name}Ref: @unchecked Sendable {{}}"#
+        )
+    } else {
+        "".to_string()
+    };
     let class = format!(
         r#"
-{class_decl}{initializers}{owned_instance_methods}{class_ref_decl}{ref_mut_instance_methods}{class_ref_mut_decl}{ref_instance_methods}{generic_freer}{equatable_method}{hashable_method}"#,
+{class_decl}{initializers}{owned_instance_methods}{class_ref_decl}{ref_mut_instance_methods}{class_ref_mut_decl}{ref_instance_methods}{generic_freer}{equatable_method}{comparable_method}{add_method}{default_initializer}{hashable_method}{debug_description}{description}{sendable}"#,
         class_decl = class_decl,
         class_ref_decl = class_ref_mut_decl,
         class_ref_mut_decl = class_ref_decl,
@@ -240,8 +425,45 @@ extension {ty_name}Ref: Hashable{{
         ref_mut_instance_methods = ref_mut_instance_methods,
         ref_instance_methods = ref_instance_methods,
         equatable_method = equatable_method,
+        comparable_method = comparable_method,
+        add_method = add_method,
+        default_initializer = default_initializer,
         hashable_method = hashable_method,
     );
 
     return class;
 }
+
+/// Properties are declared in the class body instead of in an extension, since a `RefMut`
+/// class's setter needs to override the `Ref` class's getter.
+fn class_body_properties(properties: &[String]) -> String {
+    properties
+        .iter()
+        .map(|property| format!("\n\n{property}"))
+        .collect()
+}
+
+/// Free the Rust value on the `drop_on` dispatch queue.
+///
+/// `"main"` frees right away when `deinit` runs on the main thread and hops to the main queue
+/// otherwise. Any other queue is a Swift expression, such as `"Renderer.queue"`, that the free is
+/// always dispatched to, since we can't tell whether we're already running on it.
+///
+/// The closure captures the pointer instead of `self`, since `self` is gone once `deinit` returns.
+fn free_on_queue(queue: &str, free_func_call: &str) -> String {
+    let dispatch =
+        |queue: &str| format!("{queue}.async {{ [ptr = self.ptr] in {free_func_call} }}");
+
+    if queue == "main" {
+        format!(
+            r#"if Thread.isMainThread {{
+                {free_func_call}
+            }} else {{
+                {dispatch}
+            }}"#,
+            dispatch = dispatch("DispatchQueue.main")
+        )
+    } else {
+        dispatch(queue)
+    }
+}
@@ -1,9 +1,12 @@
 use proc_macro2::Ident;
 
 /// Generate the `extension MyRustType: Vectorizable {}` for the Swift side.
-pub(super) fn generate_vectorizable_extension(ty: &Ident) -> String {
+///
+/// `swift_name` is the name of the type's Swift class, which might differ from the Rust `ty` that
+/// the FFI functions are named after.
+pub(super) fn generate_vectorizable_extension(ty: &Ident, swift_name: &str) -> String {
     format!(
-        r#"extension {ty}: Vectorizable {{
+        r#"extension {swift_name}: Vectorizable {{
     public static func vecOfSelfNew() -> UnsafeMutableRawPointer {{
         __swift_bridge__$Vec_{ty}$new()
     }}
@@ -12,7 +15,7 @@ pub(super) fn generate_vectorizable_extension(ty: &Ident) -> String {
         __swift_bridge__$Vec_{ty}$drop(vecPtr)
     }}
 
-    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: {ty}) {{
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: {swift_name}) {{
         __swift_bridge__$Vec_{ty}$push(vecPtr, {{value.isOwned = false; return value.ptr;}}())
     }}
 
@@ -21,30 +24,30 @@ pub(super) fn generate_vectorizable_extension(ty: &Ident) -> String {
         if pointer == nil {{
             return nil
         }} else {{
-            return ({ty}(ptr: pointer!) as! Self)
+            return ({swift_name}(ptr: pointer!) as! Self)
         }}
     }}
 
-    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{ty}Ref> {{
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{swift_name}Ref> {{
         let pointer = __swift_bridge__$Vec_{ty}$get(vecPtr, index)
         if pointer == nil {{
             return nil
         }} else {{
-            return {ty}Ref(ptr: pointer!)
+            return {swift_name}Ref(ptr: pointer!)
         }}
     }}
 
-    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{ty}RefMut> {{
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{swift_name}RefMut> {{
         let pointer = __swift_bridge__$Vec_{ty}$get_mut(vecPtr, index)
         if pointer == nil {{
             return nil
         }} else {{
-            return {ty}RefMut(ptr: pointer!)
+            return {swift_name}RefMut(ptr: pointer!)
         }}
     }}
 
-    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<{ty}Ref> {{
-        UnsafePointer<{ty}Ref>(OpaquePointer(__swift_bridge__$Vec_{ty}$as_ptr(vecPtr)))
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<{swift_name}Ref> {{
+        UnsafePointer<{swift_name}Ref>(OpaquePointer(__swift_bridge__$Vec_{ty}$as_ptr(vecPtr)))
     }}
 
     public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
@@ -52,7 +55,8 @@ pub(super) fn generate_vectorizable_extension(ty: &Ident) -> String {
     }}
 }}
 "#,
-        ty = ty.to_string()
+        ty = ty.to_string(),
+        swift_name = swift_name
     )
 }
 
@@ -118,7 +122,10 @@ extension ARustType: Vectorizable {
 "#;
 
         assert_trimmed_generated_equals_trimmed_expected(
-            &generate_vectorizable_extension(&Ident::new("ARustType", Span::call_site())),
+            &generate_vectorizable_extension(
+                &Ident::new("ARustType", Span::call_site()),
+                "ARustType",
+            ),
             &expected,
         );
     }
@@ -0,0 +1,232 @@
+use crate::bridged_type::{BridgeableType, BridgedType};
+use crate::codegen::{cfg_attrs_are_active, CodegenConfig};
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration};
+use crate::SwiftBridgeModule;
+use proc_macro2::Span;
+use syn::spanned::Spanned;
+use syn::{FnArg, ReturnType};
+
+/// A name that a bridge module's generated code declares, such as a Swift class or an exported
+/// symbol.
+///
+/// Two bridge modules that declare the same name can't be linked into the same binary.
+#[derive(Debug)]
+pub struct GeneratedDeclaration {
+    /// What the name is declared as.
+    pub kind: GeneratedDeclarationKind,
+    /// The declared name, such as `SomeType` or `__swift_bridge__$some_function`.
+    pub name: String,
+    /// The span of the type or function in the bridge module that the name was generated for.
+    pub span: Span,
+}
+
+/// What a [`GeneratedDeclaration`] is declared as.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GeneratedDeclarationKind {
+    /// A Swift type, such as the class for an opaque Rust type or the struct for a shared struct.
+    SwiftType,
+    /// A symbol that one side of the bridge exports for the other to link to.
+    Symbol,
+}
+
+impl SwiftBridgeModule {
+    /// The Swift types and symbols that the module's generated code declares.
+    ///
+    /// Types that use `#[swift_bridge(already_declared)]` and items whose `#[cfg(...)]`
+    /// attributes aren't active are skipped, since no code gets generated for them.
+    pub fn generated_declarations(&self, config: &CodegenConfig) -> Vec<GeneratedDeclaration> {
+        let mut declarations = vec![];
+
+        if !self.module_will_be_compiled(config) {
+            return declarations;
+        }
+
+        for ty in self.types.types() {
+            if !cfg_attrs_are_active(ty.cfg_attrs(), config) {
+                continue;
+            }
+
+            match ty {
+                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                    if shared_struct.already_declared {
+                        continue;
+                    }
+                    declarations.push(GeneratedDeclaration {
+                        kind: GeneratedDeclarationKind::SwiftType,
+                        name: shared_struct.swift_name_string(),
+                        span: shared_struct.name.span(),
+                    });
+                }
+                TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
+                    if shared_enum.already_declared {
+                        continue;
+                    }
+                    declarations.push(GeneratedDeclaration {
+                        kind: GeneratedDeclarationKind::SwiftType,
+                        name: shared_enum.swift_name_string(),
+                        span: shared_enum.name.span(),
+                    });
+                }
+                TypeDeclaration::Opaque(opaque) => {
+                    if opaque.attributes.already_declared {
+                        continue;
+                    }
+
+                    if opaque.host_lang.is_rust() {
+                        // The concrete instantiations of a generic type extend the class that
+                        // the `declare_generic` declaration generates.
+                        if opaque.generics.is_empty() || opaque.attributes.declare_generic {
+                            declarations.push(GeneratedDeclaration {
+                                kind: GeneratedDeclarationKind::SwiftType,
+                                name: opaque.swift_name_string(),
+                                span: opaque.ty.span(),
+                            });
+                        }
+                        if opaque.attributes.copy.is_none() && !opaque.attributes.declare_generic {
+                            declarations.push(GeneratedDeclaration {
+                                kind: GeneratedDeclarationKind::Symbol,
+                                name: opaque.free_rust_opaque_type_ffi_name(),
+                                span: opaque.ty.span(),
+                            });
+                        }
+                    } else {
+                        declarations.push(GeneratedDeclaration {
+                            kind: GeneratedDeclarationKind::Symbol,
+                            name: opaque.free_swift_class_link_name(),
+                            span: opaque.ty.span(),
+                        });
+                    }
+                }
+            }
+        }
+
+        let mut custom_ffi_symbols = vec![];
+        for function in &self.functions {
+            if !cfg_attrs_are_active(&function.cfg_attrs, config) {
+                continue;
+            }
+            if let Some(TypeDeclaration::Shared(_)) = function.associated_type {
+                continue;
+            }
+
+            declarations.push(GeneratedDeclaration {
+                kind: GeneratedDeclarationKind::Symbol,
+                name: function.link_name(),
+                span: function.func.sig.ident.span(),
+            });
+
+            let mut bridged_types = vec![];
+            for arg in &function.func.sig.inputs {
+                if let FnArg::Typed(pat_ty) = arg {
+                    if let Some(ty) = BridgedType::new_with_type(&pat_ty.ty, &self.types) {
+                        bridged_types.push((ty, pat_ty.ty.span()));
+                    }
+                }
+            }
+            if let ReturnType::Type(_, return_ty) = &function.func.sig.output {
+                if let Some(ty) = BridgedType::new_with_type(return_ty, &self.types) {
+                    bridged_types.push((ty, return_ty.span()));
+                }
+            }
+
+            // Two of the module's functions can use the same `HashMap<K, V>`, which only gets one
+            // set of FFI functions.
+            for (ty, span) in bridged_types {
+                for name in ty.custom_ffi_symbols(&self.types) {
+                    if !custom_ffi_symbols.contains(&name) {
+                        custom_ffi_symbols.push(name.clone());
+                        declarations.push(GeneratedDeclaration {
+                            kind: GeneratedDeclarationKind::Symbol,
+                            name,
+                            span,
+                        });
+                    }
+                }
+            }
+        }
+
+        declarations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse_ok;
+    use quote::quote;
+
+    /// Verify that we list the Swift types and symbols that a module declares, skipping the ones
+    /// that aren't generated, and that the FFI functions of built-in types such as `HashMap<K, V>` are
+    /// listed once.
+    #[test]
+    fn lists_generated_declarations() {
+        let tokens = quote! {
+            mod foo {
+                struct SomeStruct;
+                #[swift_bridge(already_declared)]
+                struct AlreadyDeclaredStruct;
+
+                extern "Rust" {
+                    type SomeRustType;
+                    #[swift_bridge(Copy(4))]
+                    type SomeCopyType;
+
+                    #[swift_bridge(declare_generic)]
+                    type SomeGenericType<A>;
+                    type SomeGenericType<u32>;
+
+                    fn some_function();
+                    fn some_method(self: &SomeRustType);
+
+                    fn some_map() -> HashMap<String, u64>;
+                    fn same_map(map: HashMap<String, u64>);
+                    fn some_arc() -> Arc<SomeRustType>;
+
+                    #[cfg(feature = "disabled")]
+                    fn disabled_function();
+                }
+
+                extern "Swift" {
+                    type SomeSwiftType;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let declarations: Vec<(GeneratedDeclarationKind, String)> = module
+            .generated_declarations(&CodegenConfig::no_features_enabled())
+            .into_iter()
+            .map(|declaration| (declaration.kind, declaration.name))
+            .collect();
+
+        let swift_type = |name: &str| (GeneratedDeclarationKind::SwiftType, name.to_string());
+        let symbol = |name: &str| (GeneratedDeclarationKind::Symbol, name.to_string());
+        assert_eq!(
+            declarations,
+            vec![
+                swift_type("SomeStruct"),
+                swift_type("SomeCopyType"),
+                swift_type("SomeRustType"),
+                symbol("__swift_bridge__$SomeRustType$_free"),
+                swift_type("SomeGenericType"),
+                symbol("__swift_bridge__$SomeGenericType$u32$_free"),
+                symbol("__swift_bridge__$SomeSwiftType$_free"),
+                symbol("__swift_bridge__$some_function"),
+                symbol("__swift_bridge__$SomeRustType$some_method"),
+                symbol("__swift_bridge__$some_map"),
+                symbol("__swift_bridge__$foo$HashMap$String$U64$new"),
+                symbol("__swift_bridge__$foo$HashMap$String$U64$_free"),
+                symbol("__swift_bridge__$foo$HashMap$String$U64$len"),
+                symbol("__swift_bridge__$foo$HashMap$String$U64$get"),
+                symbol("__swift_bridge__$foo$HashMap$String$U64$insert"),
+                symbol("__swift_bridge__$foo$HashMap$String$U64$remove"),
+                symbol("__swift_bridge__$foo$HashMap$String$U64$keys"),
+                symbol("__swift_bridge__$foo$HashMap$String$U64$values"),
+                symbol("__swift_bridge__$same_map"),
+                symbol("__swift_bridge__$some_arc"),
+                symbol("__swift_bridge__$Arc$SomeRustType$_retain"),
+                symbol("__swift_bridge__$Arc$SomeRustType$_free"),
+            ]
+        );
+    }
+}
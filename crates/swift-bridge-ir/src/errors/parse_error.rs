@@ -1,6 +1,7 @@
 use proc_macro2::Ident;
 use quote::ToTokens;
-use syn::{Error, FnArg, Item, Receiver};
+use std::ops::Deref;
+use syn::{Error, FnArg, GenericParam, Generics, Item, Pat, PatType, Receiver, TraitItem};
 use syn::{ForeignItemFn, ForeignItemType, LitStr};
 use syn::{Token, Type};
 
@@ -29,6 +30,73 @@ pub(crate) enum ParseError {
     /// If Bar wasn't declared using a `type Bar` declaration.
     UndeclaredType { ty: Type },
     // <!-- ANCHOR_END: mdbook-parse-error-enum -->
+    /// A type that can't be bridged is close to one that can.
+    /// `fn foo (names: Vec<&str>);` instead of `fn foo (names: Vec<String>);`
+    UnsupportedTypeWithSuggestion {
+        ty: Type,
+        suggestion: Box<Type>,
+        /// The book chapter that covers the suggested type, such as `"built-in/string"`.
+        chapter: Option<&'static str>,
+    },
+    /// An `Option` of a type that can be bridged, but not inside of an `Option`.
+    /// `fn foo (id: Option<Uuid>);`
+    UnsupportedOption {
+        /// `Option<Uuid>`
+        ty: Type,
+        /// `Uuid`
+        inner: Box<Type>,
+    },
+    /// An `Option` of an `Option`, which Swift can't tell apart from a single `Option`.
+    /// `fn foo (arg: Option<Option<u8>>);`
+    NestedOption {
+        /// `Option<Option<u8>>`
+        ty: Type,
+    },
+    /// A fixed size array whose element type isn't a primitive or whose length isn't an integer
+    /// literal greater than zero.
+    /// `fn foo (arg: [String; 2]);`
+    UnsupportedArray {
+        /// `[String; 2]`
+        ty: Type,
+    },
+    /// A `Vec` of a type that can be bridged, but not inside of a `Vec`.
+    /// `fn foo (maps: Vec<HashMap<String, u8>>);`
+    UnsupportedVec {
+        /// `Vec<HashMap<String, u8>>`
+        ty: Type,
+        /// `HashMap<String, u8>`
+        inner: Box<Type>,
+    },
+    /// A `Result` whose `T` or `E` can be bridged, but not inside of a `Result`.
+    /// `fn foo () -> Result<HashMap<String, u8>, String>;`
+    UnsupportedResult {
+        /// `Result<HashMap<String, u8>, String>`
+        ty: Type,
+        /// `HashMap<String, u8>`
+        inner: Box<Type>,
+    },
+    /// An `Arc` of a type that another bridge module declares, which is the module that generates
+    /// the `Arc`'s Swift class and FFI functions.
+    /// `#[swift_bridge(already_declared)] type Foo; fn foo () -> Arc<Foo>;`
+    ArcOfAlreadyDeclaredType {
+        /// `Arc<Foo>`
+        ty: Type,
+        /// `Foo`
+        inner: Box<Type>,
+    },
+    /// A shared struct or enum field whose type can be bridged, but not as a field.
+    /// `struct Foo { map: HashMap<String, u8> }`
+    UnsupportedStructField {
+        /// `HashMap<String, u8>`
+        ty: Type,
+    },
+    /// A type that can only be passed from Rust to Swift was used as an `extern "Rust"`
+    /// function's argument or an `extern "Swift"` function's return type.
+    /// `fn foo (name: Cow<'static, str>);`
+    UnsupportedSwiftToRust {
+        /// `Cow<'static, str>`
+        ty: Type,
+    },
     /// Declared a type that we already support.
     /// Example: `type u32`
     DeclaredBuiltInType { ty: ForeignItemType },
@@ -58,11 +126,103 @@ pub(crate) enum ParseError {
     InvalidModuleItem { item: Item },
     /// The associated_to attribute is used for only an associated method.
     InvalidAssociatedTo { self_: FnArg },
+    /// An initializer does not return the type that it initializes.
+    /// `#[swift_bridge(init)] fn new() -> u8;`
+    InvalidInitializerReturn {
+        fn_ident: Ident,
+        /// `None` if the initializer does not return anything.
+        ret: Option<Box<Type>>,
+        /// The type named by the initializer's `associated_to` attribute.
+        associated_to: Option<Ident>,
+        /// Whether the initializer can return an `Option`, which is the case for failable
+        /// `extern "Swift"` initializers.
+        failable: bool,
+    },
+    /// A generic opaque type had a lifetime or const generic parameter.
+    /// `type SomeType<'a>;`
+    UnsupportedGenericParam { param: Box<GenericParam> },
+    /// A `self` argument's type was not a type path or a reference to one.
+    /// `fn foo (self: [SomeType; 2]);`
+    InvalidSelfType { ty: Type },
+    /// A function argument was not a plain name.
+    /// `fn foo (&self: SomeType);`
+    InvalidArgPattern { arg: Box<PatType> },
+    /// A bridged trait contained something other than a `&self` or `&mut self` method.
+    InvalidTraitItem { item: Box<TraitItem> },
+    /// A bridged trait had generic parameters, so it could not be used as a `dyn Trait`.
+    GenericTrait { generics: Generics },
+    /// A field in an opaque type's `get(...)` or `set(...)` attribute did not have a type, and
+    /// its type could not be taken from the `get(...)` attribute.
+    /// `#[swift_bridge(set(age))] type Person;`
+    MissingFieldType {
+        field: Ident,
+        /// "get" or "set"
+        attribute: &'static str,
+    },
+    /// A type was annotated with `#[swift_bridge(Identifiable)]` but does not expose an `id`.
+    /// `#[swift_bridge(Identifiable)] struct Foo { name: String }`
+    IdentifiableMissingId { ty: Ident },
+    /// An extern "Swift" type was annotated with `#[swift_bridge(Sendable)]`. Swift classes
+    /// declare their own `Sendable` conformance.
+    /// `extern "Swift" { #[swift_bridge(Sendable)] type Foo; }`
+    SendableExternSwiftType { ty: Ident },
+    /// An extern "Rust" type was annotated with `#[swift_bridge(actor)]`. Only Swift types can
+    /// be actors.
+    /// `extern "Rust" { #[swift_bridge(actor)] type Foo; }`
+    ActorExternRustType { ty: Ident },
+    /// A type that the generated Swift class does not free was annotated with
+    /// `#[swift_bridge(drop_on = "...")]`, such as an extern "Swift" type or a `Copy` type.
+    /// `extern "Swift" { #[swift_bridge(drop_on = "main")] type Foo; }`
+    UnsupportedDropOn { ty: Ident },
+    /// A type that does not get a generated Swift class was annotated with
+    /// `#[swift_bridge(observable)]`, such as an extern "Swift" type or a `Copy` type.
+    /// `extern "Swift" { #[swift_bridge(observable)] type Foo; }`
+    UnsupportedObservable { ty: Ident },
+    /// A function returns a borrow but does not take any borrowed arguments, so the returned
+    /// reference has nothing to borrow from.
+    /// `fn name() -> &str;`
+    ReturnsBorrowWithoutBorrowedArgs {
+        fn_ident: Ident,
+        ret: Box<Type>,
+        /// The owned type to suggest returning instead, such as `String` for `&str`.
+        owned_alternative: Box<Type>,
+    },
+    /// An extern "Rust" function takes a `&mut` borrow along with a callback. The Swift closure
+    /// could call back into Rust and reach the same value while it is mutably borrowed.
+    /// `fn update(&mut self, callback: Box<dyn Fn()>);`
+    MutableBorrowWithCallback {
+        fn_ident: Ident,
+        mutable_borrow: Box<FnArg>,
+    },
+    /// An async extern "Swift" function takes a borrowed argument. Swift uses its arguments
+    /// inside of a `Task` that keeps running after the Rust call returns, by which point the
+    /// borrowed value might have been freed.
+    /// `async fn name(arg: &str);`
+    AsyncSwiftFnBorrowedArg {
+        fn_ident: Ident,
+        arg: Box<PatType>,
+        /// The owned type to suggest taking instead, such as `String` for `&str`.
+        owned_alternative: Box<Type>,
+    },
+    /// An async extern "Rust" function returns a slice. We don't support returning slices from
+    /// async functions yet.
+    /// `async fn items(&self) -> &[Item];`
+    AsyncRustFnReturnsSlice { fn_ident: Ident, ret: Box<Type> },
 }
 
 /// An error while parsing a function attribute.
 pub(crate) enum FunctionAttributeParseError {
     Identifiable(IdentifiableParseError),
+    Instantiate(InstantiateParseError),
+    Subscript(SubscriptParseError),
+    SwiftProperty(SwiftPropertyParseError),
+    UncheckedUtf8(UncheckedUtf8ParseError),
+    UnsafePointer(UnsafePointerParseError),
+    ReturnScratch(ReturnScratchParseError),
+    CatchErrors(CatchErrorsParseError),
+    Publisher(PublisherParseError),
+    MainActor(MainActorParseError),
+    DispatchQueue(DispatchQueueParseError),
 }
 
 /// An error while parsing a function's `Identifiable` attribute.
@@ -73,6 +233,101 @@ pub(crate) enum IdentifiableParseError {
     MissingReturnType { fn_ident: Ident },
 }
 
+/// An error while parsing a function's `instantiate` attribute.
+pub(crate) enum InstantiateParseError {
+    /// A generic function must list the types to instantiate it with.
+    MissingInstantiations { fn_ident: Ident },
+    /// An instantiated function must have exactly one generic type parameter.
+    RequiresOneTypeParam { fn_ident: Ident },
+    /// Only `extern "Rust"` functions can be instantiated, since Rust does not support overloading
+    /// the functions that we would generate for an `extern "Swift"` function.
+    ExternSwiftFunction { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `subscript` attribute.
+pub(crate) enum SubscriptParseError {
+    /// A subscript getter must take `&self` and at least one index argument, and must return a
+    /// value.
+    InvalidGetter { fn_ident: Ident },
+    /// A subscript setter must take `&mut self`, at least one index argument and the new value,
+    /// and must not return a value.
+    InvalidSetter { fn_ident: Ident },
+    /// A subscript setter overrides a getter, so there must be a getter that takes the same index
+    /// arguments and returns the setter's value type.
+    MissingGetter { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `unchecked_utf8` attribute.
+pub(crate) enum UncheckedUtf8ParseError {
+    /// Only the strings that Swift passes to an `extern "Rust"` function skip UTF-8 validation,
+    /// so the attribute has no meaning on an `extern "Swift"` function.
+    ExternSwiftFunction { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `unsafe_pointer` attribute.
+pub(crate) enum UnsafePointerParseError {
+    /// Raw pointers can only be used in the signature of a function that opts in with
+    /// `#[swift_bridge(unsafe_pointer)]`, since we can't check who owns the memory that they
+    /// point to.
+    /// `fn some_function(arg: *const c_void);`
+    MissingAttribute { fn_ident: Ident, ty: Box<Type> },
+}
+
+/// An error while parsing a function's `return_scratch` attribute.
+pub(crate) enum ReturnScratchParseError {
+    /// The scratch buffer lives on the Rust side, so only `extern "Rust"` functions can use it.
+    ExternSwiftFunction { fn_ident: Ident },
+    /// A `return_scratch` function must be a synchronous function that is declared as returning
+    /// `&str`.
+    InvalidSignature { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `catch_errors` attribute.
+pub(crate) enum CatchErrorsParseError {
+    /// The errors are thrown by a Swift implementation, so only `extern "Swift"` functions can
+    /// catch them.
+    ExternRustFunction { fn_ident: Ident },
+    /// A `catch_errors` function must be a synchronous function that returns a
+    /// `Result<T, String>` or a `Result<T, NsError>`, where `T` is `()`, `String` or an opaque
+    /// type.
+    InvalidSignature { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `publisher` attribute.
+pub(crate) enum PublisherParseError {
+    /// The stream is polled on the Rust side, so only `extern "Rust"` functions can return one.
+    ExternSwiftFunction { fn_ident: Ident },
+    /// A `publisher` function must be a synchronous function that is declared as returning
+    /// `impl Stream<Item = T>`.
+    InvalidSignature { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `main_actor` attribute.
+pub(crate) enum MainActorParseError {
+    /// Only the glue that calls into Swift can hop onto the main actor, so the attribute has no
+    /// meaning on an `extern "Rust"` function.
+    ExternRustFunction { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `dispatch_queue` attribute.
+pub(crate) enum DispatchQueueParseError {
+    /// Only synchronous functions and methods can be called on a queue. Initializers, properties,
+    /// subscripts, async functions and streams are not supported.
+    UnsupportedFunction { fn_ident: Ident },
+    /// A function can be called on a dispatch queue or on the main actor, but not both.
+    WithMainActor { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `swift_property` attribute.
+pub(crate) enum SwiftPropertyParseError {
+    /// A property getter must take `&self` as its only argument and must return a value.
+    InvalidGetter { fn_ident: Ident },
+    /// A property setter must take `&mut self` and the new value, and must not return a value.
+    InvalidSetter { fn_ident: Ident },
+    /// A property setter overrides a getter, so there must be a getter for the same property.
+    MissingGetter { fn_ident: Ident },
+}
+
 // <!-- ANCHOR: mdbook-parse-error-message -->
 impl Into<syn::Error> for ParseError {
     fn into(self) -> Error {
@@ -123,6 +378,83 @@ self: &mut SomeType
                 Error::new_spanned(ty, message)
             }
             // <!-- ANCHOR_END: mdbook-parse-error-message -->
+            ParseError::UnsupportedTypeWithSuggestion {
+                ty,
+                suggestion,
+                chapter,
+            } => {
+                let mut message = format!(
+                    "Type `{}` is not supported. Did you mean `{}`?",
+                    type_to_string(&ty),
+                    type_to_string(&suggestion)
+                );
+                if let Some(chapter) = chapter {
+                    message += &format!(
+                        "\nSee https://chinedufn.github.io/swift-bridge/{chapter}/index.html"
+                    );
+                }
+                Error::new_spanned(ty, message)
+            }
+            ParseError::UnsupportedOption { ty, inner } => {
+                let message = format!(
+                    "Type `{}` is not supported, since `{}` can't be used inside of an `Option` yet.",
+                    type_to_string(&ty),
+                    type_to_string(&inner)
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::NestedOption { ty } => {
+                let message = format!(
+                    "Type `{}` is not supported, since nested optionals aren't supported yet.",
+                    type_to_string(&ty)
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::UnsupportedArray { ty } => {
+                let message = format!(
+                    "Type `{}` is not supported. Arrays must have a primitive element type, such as `u8` or `f64`, and a length that is an integer literal greater than zero.",
+                    type_to_string(&ty)
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::UnsupportedVec { ty, inner } => {
+                let message = format!(
+                    "Type `{}` is not supported, since `{}` can't be used inside of a `Vec` yet.",
+                    type_to_string(&ty),
+                    type_to_string(&inner)
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::UnsupportedResult { ty, inner } => {
+                let message = format!(
+                    "Type `{}` is not supported, since `{}` can't be used inside of a `Result` yet.",
+                    type_to_string(&ty),
+                    type_to_string(&inner)
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::ArcOfAlreadyDeclaredType { ty, inner } => {
+                let message = format!(
+                    "Type `{}` can only be used in the bridge module that declares `type {}`.",
+                    type_to_string(&ty),
+                    type_to_string(&inner)
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::UnsupportedStructField { ty } => {
+                let message = format!(
+                    "Type `{}` can't be used as the type of a shared struct's or enum's field yet.",
+                    type_to_string(&ty)
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::UnsupportedSwiftToRust { ty } => {
+                let message = format!(
+                    r#"Type `{}` can only be passed from Rust to Swift, so it can't be used as an extern "Rust" function's argument or an extern "Swift" function's return type yet."#,
+                    type_to_string(&ty)
+                );
+                Error::new_spanned(ty, message)
+            }
             ParseError::DeclaredBuiltInType { ty } => {
                 let message = format!(
                     r#"Type {} is already supported
@@ -198,6 +530,173 @@ struct {struct_name};
                         Error::new_spanned(fn_ident, message)
                     }
                 },
+                FunctionAttributeParseError::Instantiate(instantiate) => match instantiate {
+                    InstantiateParseError::MissingInstantiations { fn_ident } => {
+                        let message = format!(
+                            r#"Generic function {} must list the types to instantiate it with, such as `#[swift_bridge(instantiate(u32, f64))]`."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    InstantiateParseError::RequiresOneTypeParam { fn_ident } => {
+                        let message = format!(
+                            r#"Instantiated function {} must have exactly one generic type parameter."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    InstantiateParseError::ExternSwiftFunction { fn_ident } => {
+                        let message = format!(
+                            r#"Function {} can not be instantiated. Only extern "Rust" functions support the instantiate attribute."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::SwiftProperty(property) => match property {
+                    SwiftPropertyParseError::InvalidGetter { fn_ident } => {
+                        let message = format!(
+                            r#"Swift property getter {} must take `&self` as its only argument, and must return a value."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    SwiftPropertyParseError::InvalidSetter { fn_ident } => {
+                        let message = format!(
+                            r#"Swift property setter {} must take `&mut self` and the new value, and must not return a value."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    SwiftPropertyParseError::MissingGetter { fn_ident } => {
+                        let message = format!(
+                            r#"Swift property setter {} has no Swift property getter. A setter named `set_some_property` needs a getter named `some_property`."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::Subscript(subscript) => match subscript {
+                    SubscriptParseError::InvalidGetter { fn_ident } => {
+                        let message = format!(
+                            r#"Subscript getter {} must take `&self` and at least one index argument, and must return a value."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    SubscriptParseError::InvalidSetter { fn_ident } => {
+                        let message = format!(
+                            r#"Subscript setter {} must take `&mut self`, at least one index argument and the new value, and must not return a value."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    SubscriptParseError::MissingGetter { fn_ident } => {
+                        let message = format!(
+                            r#"Subscript setter {} has no subscript getter with the same index arguments that returns the new value's type."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::ReturnScratch(return_scratch) => {
+                    match return_scratch {
+                        ReturnScratchParseError::ExternSwiftFunction { fn_ident } => {
+                            let message = format!(
+                                r#"Function {} can not use the return_scratch attribute. Only extern "Rust" functions support it."#,
+                                fn_ident
+                            );
+                            Error::new_spanned(fn_ident, message)
+                        }
+                        ReturnScratchParseError::InvalidSignature { fn_ident } => {
+                            let message = format!(
+                                r#"return_scratch function {} must not be async and must be declared as returning `&str`."#,
+                                fn_ident
+                            );
+                            Error::new_spanned(fn_ident, message)
+                        }
+                    }
+                }
+                FunctionAttributeParseError::CatchErrors(catch_errors) => match catch_errors {
+                    CatchErrorsParseError::ExternRustFunction { fn_ident } => {
+                        let message = format!(
+                            r#"Function {} can not use the catch_errors attribute. Only extern "Swift" functions support it."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    CatchErrorsParseError::InvalidSignature { fn_ident } => {
+                        let message = format!(
+                            r#"catch_errors function {} must not be async and must return a `Result<T, String>` or a `Result<T, NsError>`, where `T` is `()`, `String` or an opaque type."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::Publisher(publisher) => match publisher {
+                    PublisherParseError::ExternSwiftFunction { fn_ident } => {
+                        let message = format!(
+                            r#"Function {} can not use the publisher attribute. Only extern "Rust" functions support it."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    PublisherParseError::InvalidSignature { fn_ident } => {
+                        let message = format!(
+                            r#"publisher function {} must not be async and must be declared as returning `impl Stream<Item = T>`."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::MainActor(main_actor) => match main_actor {
+                    MainActorParseError::ExternRustFunction { fn_ident } => {
+                        let message = format!(
+                            r#"Function {} can not use the main_actor attribute. Only extern "Swift" functions support it."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::DispatchQueue(dispatch_queue) => match dispatch_queue {
+                    DispatchQueueParseError::UnsupportedFunction { fn_ident } => {
+                        let message = format!(
+                            r#"dispatch_queue function {} must be a synchronous function or method. Initializers, properties, subscripts, async functions and streams are not supported."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    DispatchQueueParseError::WithMainActor { fn_ident } => {
+                        let message = format!(
+                            r#"Function {} can not use both the dispatch_queue and the main_actor attributes."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::UncheckedUtf8(unchecked_utf8) => {
+                    match unchecked_utf8 {
+                        UncheckedUtf8ParseError::ExternSwiftFunction { fn_ident } => {
+                            let message = format!(
+                                r#"Function {} can not use the unchecked_utf8 attribute. Only extern "Rust" functions support it."#,
+                                fn_ident
+                            );
+                            Error::new_spanned(fn_ident, message)
+                        }
+                    }
+                }
+                FunctionAttributeParseError::UnsafePointer(unsafe_pointer) => {
+                    match unsafe_pointer {
+                        UnsafePointerParseError::MissingAttribute { fn_ident, ty } => {
+                            let message = format!(
+                                r#"Function {} uses the raw pointer type `{}`, so it needs the #[swift_bridge(unsafe_pointer)] attribute. swift-bridge can't check who owns the memory that a raw pointer points to, so it is up to you to keep that memory alive for as long as it is used."#,
+                                fn_ident,
+                                type_to_string(&ty)
+                            );
+                            Error::new_spanned(ty, message)
+                        }
+                    }
+                }
             },
             ParseError::ArgCopyAndRefMut { arg } => {
                 let message =
@@ -205,7 +704,8 @@ struct {struct_name};
                 Error::new_spanned(arg, message)
             }
             ParseError::InvalidModuleItem { item } => {
-                let message = format!(r#"Only `extern` blocks, structs and enums are supported."#);
+                let message =
+                    r#"Only `extern` blocks, structs, enums and traits are supported."#.to_string();
                 Error::new_spanned(item, message)
             }
             ParseError::InvalidAssociatedTo { self_ } => {
@@ -213,6 +713,162 @@ struct {struct_name};
                     format!(r#"The associated_to attribute can only be used on static methods."#);
                 Error::new_spanned(self_, message)
             }
+            ParseError::InvalidInitializerReturn {
+                fn_ident,
+                ret,
+                associated_to,
+                failable,
+            } => {
+                let expected = match associated_to {
+                    Some(ty) if failable => format!("`{ty}` or `Option<{ty}>`"),
+                    Some(ty) => format!("`{ty}`"),
+                    None if failable => {
+                        r#"an opaque type that is declared in the bridge module, or an `Option` of an extern "Swift" type"#
+                            .to_string()
+                    }
+                    None => "an opaque type that is declared in the bridge module".to_string(),
+                };
+
+                match ret {
+                    Some(ret) => {
+                        let message = format!(
+                            r#"Initializer {} must return {}, but it returns `{}`."#,
+                            fn_ident,
+                            expected,
+                            type_to_string(&ret)
+                        );
+                        Error::new_spanned(ret, message)
+                    }
+                    None => {
+                        let message = format!(
+                            r#"Initializer {} must return {}, such as `fn {}() -> SomeType`."#,
+                            fn_ident, expected, fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                }
+            }
+            ParseError::UnsupportedGenericParam { param } => Error::new_spanned(
+                param,
+                "Only type parameters are supported on generic opaque types, such as `type SomeType<T>`.",
+            ),
+            ParseError::InvalidSelfType { ty } => Error::new_spanned(
+                ty,
+                r#"Unsupported self type. Try one of:
+self: SomeType
+self: &SomeType
+self: &mut SomeType
+"#,
+            ),
+            ParseError::InvalidArgPattern { arg } => {
+                let message = match arg.pat.deref() {
+                    Pat::Reference(pat_ref) => format!(
+                        "Arguments can't be borrowed patterns. Move the `&` to the type, as in `{}: &{}{}`.",
+                        pat_ref.pat.to_token_stream(),
+                        if pat_ref.mutability.is_some() { "mut " } else { "" },
+                        arg.ty.to_token_stream()
+                    ),
+                    _ => "Arguments must be a plain name, as in `arg: SomeType`.".to_string(),
+                };
+                Error::new_spanned(&arg.pat, message)
+            }
+            ParseError::InvalidTraitItem { item } => {
+                let message = r#"Only methods that take `&self` or `&mut self` are supported in bridged traits."#;
+                Error::new_spanned(item, message)
+            }
+            ParseError::GenericTrait { generics } => {
+                let message = r#"Generic traits are not yet supported."#;
+                Error::new_spanned(generics, message)
+            }
+            ParseError::MissingFieldType { field, attribute } => {
+                let message = format!(
+                    r#"Could not determine the type of the `{field}` field. Specify it using `{attribute}({field}: SomeType)`."#
+                );
+                Error::new_spanned(field, message)
+            }
+            ParseError::IdentifiableMissingId { ty } => {
+                let message = format!(
+                    r#"`{ty}` cannot be Identifiable since it does not have an `id`. Add an `id` field to the struct, or use `get(id: SomeType)` on an opaque type."#
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::SendableExternSwiftType { ty } => {
+                let message = format!(
+                    r#"The Sendable attribute is only supported on extern "Rust" types. Conform the `{ty}` Swift class to `Sendable` in Swift instead."#
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::ActorExternRustType { ty } => {
+                let message = format!(
+                    r#"The actor attribute is only supported on extern "Swift" types. `{ty}` is a Rust type, so its Swift class can not be an actor."#
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::UnsupportedDropOn { ty } => {
+                let message = format!(
+                    r#"The drop_on attribute is only supported on non-generic extern "Rust" types that are not Copy, since those are the types that `{ty}`'s Swift class frees."#
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::UnsupportedObservable { ty } => {
+                let message = format!(
+                    r#"The observable attribute is only supported on non-generic extern "Rust" types that are not Copy or already_declared, since `{ty}`'s ObservableObject wraps its generated Swift class."#
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::ReturnsBorrowWithoutBorrowedArgs {
+                fn_ident,
+                ret,
+                owned_alternative,
+            } => {
+                let message = format!(
+                    r#"Function {} returns a borrow but does not take any borrowed arguments, so there is nothing for the returned reference to borrow from. Return `{}` instead, or use a `&'static` reference."#,
+                    fn_ident,
+                    type_to_string(&owned_alternative)
+                );
+                Error::new_spanned(ret, message)
+            }
+            ParseError::MutableBorrowWithCallback {
+                fn_ident,
+                mutable_borrow,
+            } => {
+                let message = format!(
+                    r#"Function {} takes a mutable borrow along with a callback. The Swift callback could call back into Rust and access the borrowed value while it is mutably borrowed. Take `&self` and use interior mutability, such as a `RefCell`, instead."#,
+                    fn_ident,
+                );
+                Error::new_spanned(mutable_borrow, message)
+            }
+            ParseError::AsyncSwiftFnBorrowedArg {
+                fn_ident,
+                arg,
+                owned_alternative,
+            } => {
+                let message = format!(
+                    r#"Async extern "Swift" function {} can't take a borrowed argument, since Swift keeps using its arguments after the Rust call that lent them has returned. Take `{}` instead."#,
+                    fn_ident,
+                    type_to_string(&owned_alternative)
+                );
+                Error::new_spanned(arg, message)
+            }
+            ParseError::AsyncRustFnReturnsSlice { fn_ident, ret } => {
+                let message = format!(
+                    r#"Async extern "Rust" function {} can't return a slice yet. Return a `Vec` instead."#,
+                    fn_ident
+                );
+                Error::new_spanned(ret, message)
+            }
         }
     }
 }
+
+/// Render a type the way that it would be written, such as `Vec<String>` instead of the
+/// `Vec < String >` that its tokens print as.
+fn type_to_string(ty: &Type) -> String {
+    ty.to_token_stream()
+        .to_string()
+        .replace(" < ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+        .replace(" :: ", "::")
+}
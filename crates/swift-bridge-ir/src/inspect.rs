@@ -0,0 +1,268 @@
+//! A read-only view of what a parsed bridge module declares.
+//!
+//! Together with [`SwiftBridgeModule::generate_swift_code_and_c_header`] and the module's
+//! `ToTokens` implementation, this lets crates that use `swift-bridge` write unit tests that
+//! assert on how their bridge modules get parsed and on the code that gets generated for them.
+
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration};
+use crate::SwiftBridgeModule;
+
+/// A type that a bridge module declares.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeclaredType {
+    /// The type's Rust name, such as `SomeType` or `SomeType<u32>`.
+    pub name: String,
+    /// The type's name in the generated Swift code.
+    pub swift_name: String,
+    /// What kind of type this is.
+    pub kind: DeclaredTypeKind,
+    /// Whether the type uses `#[swift_bridge(already_declared)]`, in which case another bridge
+    /// module generates its code.
+    pub already_declared: bool,
+}
+
+/// What kind of type a [`DeclaredType`] is.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DeclaredTypeKind {
+    /// A struct declared in the bridge module.
+    SharedStruct,
+    /// An enum declared in the bridge module.
+    SharedEnum,
+    /// A type declared in an `extern "Rust"` block, or a bridged trait.
+    OpaqueRustType,
+    /// A type declared in an `extern "Swift"` block.
+    OpaqueSwiftType,
+}
+
+/// A function that a bridge module declares.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeclaredFunction {
+    /// The function's Rust name.
+    pub name: String,
+    /// The function's name in the Swift code, which is `init` for initializers.
+    pub swift_name: String,
+    /// The block that the function was declared in.
+    pub extern_block: ExternBlock,
+    /// The Rust name of the type that the function is a method or associated function of.
+    pub associated_type: Option<String>,
+    /// Whether the function takes `self`, `&self` or `&mut self`.
+    pub is_method: bool,
+    /// Whether the function is exposed as an initializer of its associated type.
+    pub is_initializer: bool,
+    /// Whether the function is `async`.
+    pub is_async: bool,
+    /// The symbol that the generated code uses to call the function across the FFI boundary,
+    /// such as `__swift_bridge__$SomeType$some_method`.
+    pub link_name: String,
+}
+
+/// The kind of `extern` block that a function was declared in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ExternBlock {
+    /// `extern "Rust"`, for functions that are implemented in Rust and called from Swift.
+    Rust,
+    /// `extern "Swift"`, for functions that are implemented in Swift and called from Rust.
+    Swift,
+}
+
+impl SwiftBridgeModule {
+    /// The name of the bridge module, such as `ffi`.
+    pub fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    /// The types that the module declares, in the order that they were parsed.
+    pub fn declared_types(&self) -> Vec<DeclaredType> {
+        self.types
+            .types()
+            .into_iter()
+            .map(|ty| match ty {
+                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                    DeclaredType {
+                        name: shared_struct.name.to_string(),
+                        swift_name: shared_struct.swift_name_string(),
+                        kind: DeclaredTypeKind::SharedStruct,
+                        already_declared: shared_struct.already_declared,
+                    }
+                }
+                TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => DeclaredType {
+                    name: shared_enum.name.to_string(),
+                    swift_name: shared_enum.swift_name_string(),
+                    kind: DeclaredTypeKind::SharedEnum,
+                    already_declared: shared_enum.already_declared,
+                },
+                TypeDeclaration::Opaque(opaque) => DeclaredType {
+                    name: opaque.ty_name_with_generics(),
+                    swift_name: opaque.swift_name_string(),
+                    kind: if opaque.host_lang.is_rust() {
+                        DeclaredTypeKind::OpaqueRustType
+                    } else {
+                        DeclaredTypeKind::OpaqueSwiftType
+                    },
+                    already_declared: opaque.attributes.already_declared,
+                },
+            })
+            .collect()
+    }
+
+    /// The functions that the module declares, in the order that they were parsed.
+    ///
+    /// Each instantiation of a generic function that uses `#[swift_bridge(instantiate(...))]` is
+    /// its own function.
+    pub fn declared_functions(&self) -> Vec<DeclaredFunction> {
+        self.functions
+            .iter()
+            .map(|func| {
+                let swift_name = if func.is_swift_initializer {
+                    "init".to_string()
+                } else {
+                    match func.swift_name_override.as_ref() {
+                        Some(swift_name) => swift_name.value(),
+                        None => func.linked_fn_name("_"),
+                    }
+                };
+                let associated_type = match &func.associated_type {
+                    Some(TypeDeclaration::Opaque(opaque)) => Some(opaque.ty_name_with_generics()),
+                    _ => None,
+                };
+                let link_name = match &func.associated_type {
+                    Some(TypeDeclaration::Shared(_)) => "".to_string(),
+                    _ => func.link_name(),
+                };
+
+                DeclaredFunction {
+                    name: func.sig.ident.to_string(),
+                    swift_name,
+                    extern_block: if func.host_lang.is_rust() {
+                        ExternBlock::Rust
+                    } else {
+                        ExternBlock::Swift
+                    },
+                    associated_type,
+                    is_method: func.is_method(),
+                    is_initializer: func.is_swift_initializer,
+                    is_async: func.sig.asyncness.is_some(),
+                    link_name,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse_ok;
+    use quote::quote;
+
+    /// Verify that we describe each kind of type that a module can declare.
+    #[test]
+    fn declared_types() {
+        let tokens = quote! {
+            mod foo {
+                #[swift_bridge(swift_name = "RenamedStruct")]
+                struct SomeStruct;
+                enum SomeEnum {
+                    Variant
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(already_declared)]
+                    type SomeRustType;
+                }
+                extern "Swift" {
+                    type SomeSwiftType;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.name(), "foo");
+        assert_eq!(
+            module.declared_types(),
+            vec![
+                DeclaredType {
+                    name: "SomeStruct".to_string(),
+                    swift_name: "RenamedStruct".to_string(),
+                    kind: DeclaredTypeKind::SharedStruct,
+                    already_declared: false,
+                },
+                DeclaredType {
+                    name: "SomeEnum".to_string(),
+                    swift_name: "SomeEnum".to_string(),
+                    kind: DeclaredTypeKind::SharedEnum,
+                    already_declared: false,
+                },
+                DeclaredType {
+                    name: "SomeRustType".to_string(),
+                    swift_name: "SomeRustType".to_string(),
+                    kind: DeclaredTypeKind::OpaqueRustType,
+                    already_declared: true,
+                },
+                DeclaredType {
+                    name: "SomeSwiftType".to_string(),
+                    swift_name: "SomeSwiftType".to_string(),
+                    kind: DeclaredTypeKind::OpaqueSwiftType,
+                    already_declared: false,
+                },
+            ]
+        );
+    }
+
+    /// Verify that we describe the functions that a module declares.
+    #[test]
+    fn declared_functions() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new() -> SomeType;
+                    #[swift_bridge(swift_name = "someMethod")]
+                    async fn some_method(&self);
+                }
+                extern "Swift" {
+                    fn swift_function();
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module.declared_functions(),
+            vec![
+                DeclaredFunction {
+                    name: "new".to_string(),
+                    swift_name: "init".to_string(),
+                    extern_block: ExternBlock::Rust,
+                    associated_type: Some("SomeType".to_string()),
+                    is_method: false,
+                    is_initializer: true,
+                    is_async: false,
+                    link_name: "__swift_bridge__$SomeType$new".to_string(),
+                },
+                DeclaredFunction {
+                    name: "some_method".to_string(),
+                    swift_name: "someMethod".to_string(),
+                    extern_block: ExternBlock::Rust,
+                    associated_type: Some("SomeType".to_string()),
+                    is_method: true,
+                    is_initializer: false,
+                    is_async: true,
+                    link_name: "__swift_bridge__$SomeType$some_method".to_string(),
+                },
+                DeclaredFunction {
+                    name: "swift_function".to_string(),
+                    swift_name: "swift_function".to_string(),
+                    extern_block: ExternBlock::Swift,
+                    associated_type: None,
+                    is_method: false,
+                    is_initializer: false,
+                    is_async: false,
+                    link_name: "__swift_bridge__$swift_function".to_string(),
+                },
+            ]
+        );
+    }
+}
@@ -4,6 +4,45 @@
 //!
 //! This IR is then used to generate the C header files, Objective-C bridging headers, Swift code,
 //! and Rust code needed to power Rust + Swift interop.
+//!
+//! # Testing bridge modules
+//!
+//! Crates that use `swift-bridge` can parse their bridge modules, inspect what they declare and
+//! render the Rust, Swift and C code that gets generated for them, which lets them assert on
+//! their generated code in their own unit tests.
+//!
+//! ```
+//! use swift_bridge_ir::{CodegenConfig, DeclaredTypeKind, ExternBlock, SwiftBridgeModule};
+//! use quote::ToTokens;
+//!
+//! let module: SwiftBridgeModule = syn::parse_quote! {
+//!     mod ffi {
+//!         extern "Rust" {
+//!             type Counter;
+//!
+//!             #[swift_bridge(init)]
+//!             fn new() -> Counter;
+//!             fn increment(&mut self);
+//!         }
+//!     }
+//! };
+//!
+//! let types = module.declared_types();
+//! assert_eq!(types[0].name, "Counter");
+//! assert_eq!(types[0].kind, DeclaredTypeKind::OpaqueRustType);
+//!
+//! let functions = module.declared_functions();
+//! assert_eq!(functions[1].name, "increment");
+//! assert_eq!(functions[1].extern_block, ExternBlock::Rust);
+//! assert_eq!(functions[1].associated_type.as_deref(), Some("Counter"));
+//!
+//! let generated = module.generate_swift_code_and_c_header(CodegenConfig::default());
+//! assert!(generated.swift.contains("public func increment()"));
+//! assert!(generated.c_header.contains("__swift_bridge__$Counter$increment"));
+//!
+//! let rust = module.to_token_stream().to_string();
+//! assert!(rust.contains("__swift_bridge__Counter_increment"));
+//! ```
 
 #![deny(missing_docs)]
 
@@ -11,11 +50,18 @@ use proc_macro2::Ident;
 use syn::Path;
 
 use crate::bridge_module_attributes::CfgAttr;
-use crate::parse::TypeDeclarations;
+use crate::parse::{TypeDeclaration, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
 
-pub use self::bridge_macro_attributes::{SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs};
-pub use self::codegen::CodegenConfig;
+pub use self::bridge_macro_attributes::{
+    PanicBehavior, SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs,
+};
+pub use self::codegen::{
+    CfgLookup, CodegenConfig, ExternSwiftSignature, GeneratedDeclaration, GeneratedDeclarationKind,
+    SharedSwiftCode,
+};
+pub use self::inspect::{DeclaredFunction, DeclaredType, DeclaredTypeKind, ExternBlock};
+pub use self::stub_module::generate_stub_module;
 
 mod errors;
 mod parse;
@@ -26,12 +72,19 @@ mod bridged_type;
 mod parsed_extern_fn;
 
 mod codegen;
+mod inspect;
+mod stub_module;
 
 #[cfg(test)]
 mod test_utils;
 
 const SWIFT_BRIDGE_PREFIX: &'static str = "__swift_bridge__";
 
+/// Whether Swift passes the `&str` arguments of Rust functions as a `SmallRustStr`, which holds
+/// short strings inline instead of pointing to a heap allocated copy of them.
+/// Enabled using the `small-string-ffi` feature.
+pub const SMALL_STRING_FFI: bool = cfg!(feature = "small-string-ffi");
+
 /// Represents a type definition within an `extern "Rust"` module, as well as all of its methods.
 ///
 /// ```no_run,ignore
@@ -44,6 +97,7 @@ const SWIFT_BRIDGE_PREFIX: &'static str = "__swift_bridge__";
 ///
 ///         fn pop(self: &mut Stack) -> Option<u8>;
 ///
+///         #[swift_bridge(unsafe_pointer)]
 ///         fn as_ptr(&self) -> *const u8;
 ///
 ///         fn len(self: &Stack) -> usize;
@@ -70,6 +124,68 @@ impl SwiftBridgeModule {
     pub fn set_swift_bridge_path(&mut self, path: Path) {
         self.swift_bridge_path = path;
     }
+
+    /// Prefix the Swift names of the Rust types that this module generates Swift code for,
+    /// such as `User` -> `MyLibUser`, to avoid collisions with types in the Swift codebase.
+    ///
+    /// Types renamed using `#[swift_bridge(swift_name = "...")]` and opaque Swift types keep
+    /// their names.
+    pub fn set_swift_type_prefix(&mut self, prefix: &str) {
+        self.types.set_swift_type_prefix(prefix);
+
+        for function in self.functions.iter_mut() {
+            if let Some(TypeDeclaration::Opaque(associated_type)) = &function.associated_type {
+                let ty_name = associated_type.ty_name_with_generics();
+                function.associated_type = self.types.get(&ty_name).cloned();
+            }
+        }
+    }
+
+    /// Annotate the generated Rust extern wrappers with `#[inline]` and the generated Swift
+    /// wrappers with `@inline(__always)`, so that calling a trivial bridged function doesn't cost
+    /// two function calls.
+    pub fn set_inline_shims(&mut self, inline_shims: bool) {
+        for function in self.functions.iter_mut() {
+            function.inline_shims = inline_shims;
+        }
+    }
+
+    /// Pass the `&str` arguments of synchronous Rust functions as `SmallRustStr`s, which hold
+    /// strings of up to 24 bytes inline. Defaults to [`SMALL_STRING_FFI`].
+    pub fn set_small_string_ffi(&mut self, small_string_ffi: bool) {
+        for function in self.functions.iter_mut() {
+            function.small_string_ffi = small_string_ffi;
+        }
+    }
+
+    /// Catch panics in the generated Rust extern functions, since unwinding into Swift is
+    /// undefined behavior, and handle them on the Swift side as described by `behavior`.
+    pub fn set_catch_panics(&mut self, behavior: PanicBehavior) {
+        for function in self.functions.iter_mut() {
+            function.catch_panics = Some(behavior);
+        }
+    }
+
+    /// Apply the arguments of the `#[swift_bridge::bridge(...)]` attribute that the module is
+    /// annotated with, using the setters above.
+    pub fn apply_attributes(&mut self, attrs: SwiftBridgeModuleAttrs) {
+        for attr in attrs.attributes {
+            match attr {
+                SwiftBridgeModuleAttr::SwiftBridgePath(path) => {
+                    self.set_swift_bridge_path(path);
+                }
+                SwiftBridgeModuleAttr::SwiftTypePrefix(prefix) => {
+                    self.set_swift_type_prefix(&prefix.value());
+                }
+                SwiftBridgeModuleAttr::InlineShims => {
+                    self.set_inline_shims(true);
+                }
+                SwiftBridgeModuleAttr::CatchPanics(behavior) => {
+                    self.set_catch_panics(behavior);
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
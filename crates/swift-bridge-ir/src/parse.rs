@@ -1,18 +1,25 @@
 use crate::bridge_module_attributes::CfgAttr;
-use crate::bridged_type::BridgedType;
 use crate::errors::{ParseError, ParseErrors};
 use crate::parse::parse_enum::SharedEnumDeclarationParser;
-use crate::parse::parse_extern_mod::ForeignModParser;
+use crate::parse::parse_extern_mod::{
+    validate_catch_errors, validate_swift_properties, validate_swift_subscripts, ForeignModParser,
+};
 use crate::parse::parse_struct::SharedStructDeclarationParser;
+use crate::parse::parse_trait::TraitDeclarationParser;
+use crate::parse::type_suggestion::{can_bridge, suggest_supported_type};
+use crate::parse::unsupported_type::{find_unsupported_field_types, find_unsupported_type};
 use crate::SwiftBridgeModule;
-use proc_macro2::TokenTree;
+use proc_macro2::{Ident, TokenTree};
 use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
-use syn::{Item, ItemMod, Token};
+use syn::{Attribute, Item, ItemMod, Lit, LitStr, Meta, Token};
 
 mod parse_enum;
 mod parse_extern_mod;
 mod parse_struct;
+mod parse_trait;
+mod type_suggestion;
+mod unsupported_type;
 
 mod type_declarations;
 pub(crate) use self::type_declarations::*;
@@ -59,7 +66,7 @@ impl Parse for SwiftBridgeModuleAndErrors {
             let module_name = item_mod.ident;
 
             let mut functions = vec![];
-            let mut type_declarations = TypeDeclarations::default();
+            let mut type_declarations = TypeDeclarations::new(module_name.to_string());
             let mut unresolved_types = vec![];
             let mut cfg_attrs = vec![];
 
@@ -73,7 +80,17 @@ impl Parse for SwiftBridgeModuleAndErrors {
                 };
             }
 
-            for outer_mod_item in item_mod.content.unwrap().1 {
+            let items = match item_mod.content {
+                Some((_, items)) => items,
+                None => {
+                    return Err(syn::Error::new_spanned(
+                        item_mod.semi,
+                        "Bridge modules must be declared inline, as in `mod ffi { ... }`.",
+                    ))
+                }
+            };
+
+            for outer_mod_item in items {
                 match outer_mod_item {
                     Item::ForeignMod(foreign_mod) => {
                         ForeignModParser {
@@ -106,6 +123,16 @@ impl Parse for SwiftBridgeModuleAndErrors {
                             TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)),
                         );
                     }
+                    Item::Trait(item_trait) => {
+                        TraitDeclarationParser {
+                            item_trait,
+                            errors: &mut errors,
+                            type_declarations: &mut type_declarations,
+                            functions: &mut functions,
+                            unresolved_types: &mut unresolved_types,
+                        }
+                        .parse()?;
+                    }
                     invalid_item => {
                         let error = ParseError::InvalidModuleItem { item: invalid_item };
                         errors.push(error);
@@ -113,14 +140,32 @@ impl Parse for SwiftBridgeModuleAndErrors {
                 };
             }
 
+            validate_swift_properties(&functions, &mut errors);
+            validate_swift_subscripts(&functions, &mut errors);
+            validate_catch_errors(&functions, &type_declarations, &mut errors);
+            for error in find_unsupported_field_types(&type_declarations) {
+                errors.push(error);
+            }
+
             for unresolved_type in unresolved_types.into_iter() {
-                if BridgedType::new_with_type(&unresolved_type, &type_declarations).is_some() {
+                if can_bridge(&unresolved_type, &type_declarations) {
+                    continue;
+                }
+                if let Some(error) = find_unsupported_type(&unresolved_type, &type_declarations) {
+                    errors.push(error);
                     continue;
                 }
 
-                errors.push(ParseError::UndeclaredType {
-                    ty: unresolved_type.clone(),
-                });
+                match suggest_supported_type(&unresolved_type, &type_declarations) {
+                    Some(suggestion) => errors.push(ParseError::UnsupportedTypeWithSuggestion {
+                        ty: unresolved_type,
+                        suggestion: Box::new(suggestion.ty),
+                        chapter: suggestion.chapter,
+                    }),
+                    None => errors.push(ParseError::UndeclaredType {
+                        ty: unresolved_type.clone(),
+                    }),
+                };
             }
 
             let module = SwiftBridgeModule {
@@ -161,6 +206,94 @@ fn move_input_cursor_to_next_comma(input: ParseStream) {
     }
 }
 
+/// Collect the lines of an item's `/// ...` doc comment.
+///
+/// Each `///` line reaches us as its own `#[doc = "..."]` attribute. The lines are joined with a
+/// newline, keeping the leading space that follows the `///`.
+pub(crate) fn parse_doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(name_val)) => match name_val.lit {
+                Lit::Str(comment) => Some(comment.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+/// The Swift access levels that generated declarations can be given.
+///
+/// `private` isn't supported since the generated Swift types call each other's initializers.
+pub(crate) const SWIFT_ACCESS_LEVELS: [&str; 4] = ["public", "package", "internal", "fileprivate"];
+
+/// Parse the `= "internal"` in `#[swift_bridge(swift_access = "internal")]`.
+pub(crate) fn parse_swift_access(input: ParseStream) -> syn::Result<LitStr> {
+    input.parse::<Token![=]>()?;
+    let access: LitStr = input.parse()?;
+
+    if !SWIFT_ACCESS_LEVELS.contains(&access.value().as_str()) {
+        return Err(syn::Error::new(
+            access.span(),
+            format!(
+                "Unsupported Swift access level. Expected one of: {}.",
+                SWIFT_ACCESS_LEVELS.join(", ")
+            ),
+        ));
+    }
+
+    Ok(access)
+}
+
+/// How a bridged type conforms to Swift's `Sendable` protocol.
+///
+/// `#[swift_bridge(Sendable)]` or `#[swift_bridge(Sendable(unchecked))]`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum SwiftSendable {
+    /// `Sendable`, which Swift checks.
+    Checked,
+    /// `@unchecked Sendable`, which Swift takes our word for.
+    Unchecked,
+}
+
+impl SwiftSendable {
+    /// The protocol as it appears in a Swift conformance, such as `@unchecked Sendable`.
+    pub(crate) fn swift_conformance(&self) -> &'static str {
+        match self {
+            SwiftSendable::Checked => "Sendable",
+            SwiftSendable::Unchecked => "@unchecked Sendable",
+        }
+    }
+}
+
+/// Parse the optional `(unchecked)` in `#[swift_bridge(Sendable(unchecked))]`.
+pub(crate) fn parse_sendable(input: ParseStream) -> syn::Result<SwiftSendable> {
+    if !input.peek(syn::token::Paren) {
+        return Ok(SwiftSendable::Checked);
+    }
+
+    let content;
+    syn::parenthesized!(content in input);
+    let mode: Ident = content.parse()?;
+
+    if mode != "unchecked" {
+        return Err(syn::Error::new(
+            mode.span(),
+            "Unsupported Sendable mode. Expected `Sendable` or `Sendable(unchecked)`.",
+        ));
+    }
+
+    Ok(SwiftSendable::Unchecked)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,9 +316,62 @@ mod tests {
             CfgAttr::Feature(feature) => {
                 assert_eq!(feature.value(), "some-feature")
             }
+            _ => panic!(),
         };
     }
 
+    /// Verify that we can parse cfg attributes on functions and on extern blocks.
+    #[test]
+    fn parse_item_cfg_attributes() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                #[cfg(target_os = "ios")]
+                extern "Rust" {
+                    type SomeType;
+
+                    #[cfg(all(feature = "some-feature", not(debug_assertions)))]
+                    fn some_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap();
+        assert_eq!(ty.cfg_attrs().len(), 1);
+        match &ty.cfg_attrs()[0] {
+            CfgAttr::KeyValue(key, value) => {
+                assert_eq!(key, "target_os");
+                assert_eq!(value.value(), "ios");
+            }
+            _ => panic!(),
+        };
+
+        let cfg_attrs = &module.functions[0].cfg_attrs;
+        assert_eq!(cfg_attrs.len(), 2);
+        match &cfg_attrs[0] {
+            CfgAttr::All(cfgs) => {
+                assert!(matches!(cfgs[0], CfgAttr::Feature(_)));
+                assert!(matches!(&cfgs[1], CfgAttr::Not(cfg) if matches!(**cfg, CfgAttr::Key(_))));
+            }
+            _ => panic!(),
+        };
+        assert!(matches!(cfg_attrs[1], CfgAttr::KeyValue(..)));
+    }
+
+    /// Verify that we get an error instead of a panic for a module that is declared without a
+    /// body.
+    #[test]
+    fn error_if_module_has_no_body() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo;
+        };
+
+        assert!(syn::parse2::<SwiftBridgeModule>(tokens).is_err());
+    }
+
     /// Verify that we get an error when parsing an unsupported module item, such as a
     /// `use` statement.
     #[test]
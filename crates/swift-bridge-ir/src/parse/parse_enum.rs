@@ -34,6 +34,9 @@ impl<'a> SharedEnumDeclarationParser<'a> {
             already_declared: attribs.swift_bridge.already_declared,
             swift_name: attribs.swift_bridge.swift_name,
             derive: attribs.derive,
+            sendable: attribs.swift_bridge.sendable,
+            doc_comment: attribs.doc_comment,
+            cfg_attrs: attribs.cfg_attrs,
         };
 
         Ok(shared_enum)
@@ -115,6 +118,25 @@ mod tests {
         }
     }
 
+    /// Verify that we can parse a doc comment from an enum.
+    #[test]
+    fn parse_enum_doc_comment() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                /// Some comment
+                enum SomeEnum {
+                    Variant
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert_eq!(ty.doc_comment.as_deref(), Some(" Some comment"));
+    }
+
     /// Verify that we can parse the `#[swift_bridge(swift_name = "...")`] attribute.
     #[test]
     fn swift_name_attribute() {
@@ -184,6 +206,40 @@ mod tests {
         };
     }
 
+    /// Verify that unsupported attributes and derives on shared enums are errors instead of
+    /// panics.
+    #[test]
+    fn error_if_unsupported_attribute_or_derive() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[serde(rename_all = "camelCase")]
+                enum SomeEnum {
+                    Variant
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::EnumUnrecognizedAttribute { attribute } => {
+                assert_eq!(&attribute.to_string(), "serde");
+            }
+            _ => panic!(),
+        };
+
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(Clone)]
+                enum SomeEnum {
+                    Variant
+                }
+            }
+        };
+        assert!(syn::parse2::<crate::SwiftBridgeModule>(tokens).is_err());
+    }
+
     /// Verify that we can parse #[derive(Debug)] on enums
     #[test]
     fn derive_debug() {
@@ -1,6 +1,9 @@
+use crate::bridge_module_attributes::CfgAttr;
 use crate::bridged_type::DeriveAttrs;
 use crate::errors::ParseError;
-use crate::parse::move_input_cursor_to_next_comma;
+use crate::parse::{
+    move_input_cursor_to_next_comma, parse_doc_comment, parse_sendable, SwiftSendable,
+};
 use proc_macro2::Ident;
 use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
@@ -11,11 +14,16 @@ pub(super) struct SharedEnumAllAttributes {
     pub errors: Vec<ParseError>,
     pub swift_bridge: SharedEnumSwiftBridgeAttributes,
     pub derive: DeriveAttrs,
+    pub doc_comment: Option<String>,
+    pub cfg_attrs: Vec<CfgAttr>,
 }
 
 impl SharedEnumAllAttributes {
     pub(super) fn from_attributes(attribs: &[Attribute]) -> Result<Self, syn::Error> {
-        let mut attributes = SharedEnumAllAttributes::default();
+        let mut attributes = SharedEnumAllAttributes {
+            doc_comment: parse_doc_comment(attribs),
+            ..SharedEnumAllAttributes::default()
+        };
 
         for attr in attribs {
             let attribute_name = attr.path.to_token_stream().to_string();
@@ -26,19 +34,38 @@ impl SharedEnumAllAttributes {
                         syn::punctuated::Punctuated::<Path, syn::Token![,]>::parse_terminated;
                     let args = attr.parse_args_with(parser)?;
                     for arg in args.into_iter() {
-                        match arg.get_ident().unwrap().to_string().as_str() {
+                        match arg.to_token_stream().to_string().as_str() {
                             "Debug" => attributes.derive.debug = true,
-                            _ => todo!("Unsupported derive macro; please see https://github.com/chinedufn/swift-bridge/issues/190#issuecomment-1463234027"),
+                            _ => {
+                                // See https://github.com/chinedufn/swift-bridge/issues/190#issuecomment-1463234027
+                                return Err(syn::Error::new_spanned(
+                                    arg,
+                                    "Unsupported derive on a shared enum. Only `Debug` can be derived.",
+                                ));
+                            }
                         }
                     }
                 }
+                // Collected by `parse_doc_comment`.
+                "doc" => {}
+                "cfg" => {
+                    attributes.cfg_attrs.push(syn::parse2(attr.tokens.clone())?);
+                }
                 "swift_bridge" => {
                     attributes.swift_bridge = attr.parse_args()?;
                     attributes
                         .errors
                         .append(&mut attributes.swift_bridge.errors);
                 }
-                _ => todo!("Push unsupported attribute error."),
+                _ => {
+                    if let Some(attribute) = attr.path.segments.last() {
+                        attributes
+                            .errors
+                            .push(ParseError::EnumUnrecognizedAttribute {
+                                attribute: attribute.ident.clone(),
+                            });
+                    }
+                }
             };
         }
 
@@ -49,6 +76,7 @@ impl SharedEnumAllAttributes {
 pub(super) enum EnumAttr {
     AlreadyDeclared,
     Error(ParseError),
+    Sendable(SwiftSendable),
     SwiftName(LitStr),
 }
 
@@ -56,6 +84,7 @@ pub(super) enum EnumAttr {
 pub(super) struct SharedEnumSwiftBridgeAttributes {
     pub errors: Vec<ParseError>,
     pub already_declared: bool,
+    pub sendable: Option<SwiftSendable>,
     pub swift_name: Option<LitStr>,
 }
 
@@ -64,6 +93,7 @@ impl SharedEnumSwiftBridgeAttributes {
         match attrib {
             EnumAttr::AlreadyDeclared => self.already_declared = true,
             EnumAttr::Error(error) => self.errors.push(error),
+            EnumAttr::Sendable(sendable) => self.sendable = Some(sendable),
             EnumAttr::SwiftName(name) => self.swift_name = Some(name),
         };
         Ok(())
@@ -91,6 +121,7 @@ impl Parse for EnumAttr {
 
         let attr = match key.to_string().as_str() {
             "already_declared" => EnumAttr::AlreadyDeclared,
+            "Sendable" => EnumAttr::Sendable(parse_sendable(input)?),
             "swift_name" => {
                 input.parse::<Token![=]>()?;
 
@@ -1,24 +1,34 @@
 use self::argument_attributes::ArgumentAttributes;
 pub(crate) use self::opaque_type_attributes::OpaqueTypeAllAttributes;
+use crate::bridge_module_attributes::CfgAttr;
+use crate::bridged_type::boxed_fn::BridgeableBoxedFnOnce;
 use crate::bridged_type::{
     bridgeable_type_from_fn_arg, pat_type_pat_is_self, BridgeableType, BridgedType,
 };
-use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError, ParseErrors};
+use crate::errors::{
+    CatchErrorsParseError, DispatchQueueParseError, FunctionAttributeParseError,
+    IdentifiableParseError, InstantiateParseError, MainActorParseError, ParseError, ParseErrors,
+    PublisherParseError, ReturnScratchParseError, SubscriptParseError, SwiftPropertyParseError,
+    UncheckedUtf8ParseError, UnsafePointerParseError,
+};
 use crate::parse::parse_extern_mod::function_attributes::FunctionAttributes;
 use crate::parse::parse_extern_mod::generics::GenericOpaqueType;
 use crate::parse::type_declarations::{
     OpaqueForeignTypeDeclaration, TypeDeclaration, TypeDeclarations,
 };
-use crate::parse::{HostLang, OpaqueRustTypeGenerics};
-use crate::parsed_extern_fn::fn_arg_is_mutable_reference;
+use crate::parse::type_suggestion::{can_bridge, owned_alternative};
+use crate::parse::unsupported_type::find_rust_to_swift_only_type;
+use crate::parse::{parse_doc_comment, HostLang, OpaqueRustTypeGenerics};
+use crate::parsed_extern_fn::{fn_arg_is_mutable_reference, GenericFnInstantiation};
 use crate::ParsedExternFn;
-use proc_macro2::Ident;
+use proc_macro2::{Group, Ident, TokenStream, TokenTree};
 use quote::{format_ident, ToTokens};
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 use syn::{
-    FnArg, ForeignItem, ForeignItemFn, GenericParam, ItemForeignMod, LitStr, Pat, ReturnType, Type,
+    FnArg, ForeignItem, ForeignItemFn, GenericArgument, GenericParam, Generics, ItemForeignMod,
+    LitStr, Pat, PathArguments, ReturnType, Type, TypeParam, TypeParamBound,
 };
 
 mod argument_attributes;
@@ -46,6 +56,9 @@ impl<'a> ForeignModParser<'a> {
 
         let abi_name = foreign_mod.abi.name.unwrap();
 
+        // Items inside of a `#[cfg(...)]` extern block are only compiled when it is.
+        let block_cfg_attrs = CfgAttr::from_attributes(&foreign_mod.attrs)?;
+
         let host_lang = match abi_name.value().as_str() {
             "Rust" => HostLang::Rust,
             "Swift" => HostLang::Swift,
@@ -55,6 +68,14 @@ impl<'a> ForeignModParser<'a> {
             }
         };
 
+        let mut swift_properties = HashSet::new();
+        if host_lang.is_rust() {
+            let accessors = self.field_accessor_fns(&foreign_mod.items, &mut swift_properties)?;
+            foreign_mod
+                .items
+                .extend(accessors.into_iter().map(ForeignItem::Fn));
+        }
+
         foreign_mod.items.sort_by(|a, _b| {
             if matches!(a, ForeignItem::Type(_)) {
                 Ordering::Less
@@ -82,11 +103,44 @@ impl<'a> ForeignModParser<'a> {
                         }
                     }
 
+                    let mut attributes =
+                        OpaqueTypeAllAttributes::from_attributes(&foreign_ty.attrs)?;
+                    attributes.cfg_attrs.extend(block_cfg_attrs.iter().cloned());
+
+                    if attributes.sendable.is_some() && host_lang.is_swift() {
+                        self.errors.push(ParseError::SendableExternSwiftType {
+                            ty: foreign_ty.ident.clone(),
+                        });
+                    }
+                    if attributes.actor && host_lang.is_rust() {
+                        self.errors.push(ParseError::ActorExternRustType {
+                            ty: foreign_ty.ident.clone(),
+                        });
+                    }
+                    if attributes.drop_on.is_some()
+                        && (host_lang.is_swift() || attributes.copy.is_some())
+                    {
+                        self.errors.push(ParseError::UnsupportedDropOn {
+                            ty: foreign_ty.ident.clone(),
+                        });
+                    }
+
+                    if attributes.observable
+                        && (host_lang.is_swift()
+                            || attributes.copy.is_some()
+                            || attributes.already_declared)
+                    {
+                        self.errors.push(ParseError::UnsupportedObservable {
+                            ty: foreign_ty.ident.clone(),
+                        });
+                    }
+
                     let foreign_type = OpaqueForeignTypeDeclaration {
                         ty: foreign_ty.ident.clone(),
                         host_lang,
-                        attributes: OpaqueTypeAllAttributes::from_attributes(&foreign_ty.attrs)?,
+                        attributes,
                         generics: OpaqueRustTypeGenerics::new(),
+                        trait_object: false,
                     };
                     self.type_declarations.insert(
                         ty_name.clone(),
@@ -95,82 +149,328 @@ impl<'a> ForeignModParser<'a> {
                     local_type_declarations.insert(ty_name, foreign_type);
                 }
                 ForeignItem::Fn(func) => {
-                    let mut attributes = FunctionAttributes::default();
-
-                    for attr in func.attrs.iter() {
-                        attributes = attr.parse_args()?;
-                    }
+                    for (mut func, instantiation) in self.instantiate_generic_fn(func, host_lang)? {
+                        let mut attributes = FunctionAttributes::default();
+
+                        for attr in func.attrs.iter() {
+                            // Doc comments are collected by `parse_doc_comment` and cfg
+                            // attributes by `CfgAttr::from_attributes`.
+                            if attr.path.is_ident("doc") || attr.path.is_ident("cfg") {
+                                continue;
+                            }
+                            attributes = attr.parse_args()?;
+                        }
 
-                    for arg in func.sig.inputs.iter() {
-                        if let FnArg::Typed(pat_ty) = arg {
-                            let ty = &pat_ty.ty;
-                            if BridgedType::new_with_type(&ty, &self.type_declarations).is_none() {
-                                self.unresolved_types.push(ty.deref().clone());
+                        // Rust functions that return an `impl Stream<Item = T>` hand the stream
+                        // to Swift as an `AsyncSequence`, or as a Combine publisher when they use
+                        // `#[swift_bridge(publisher)]`.
+                        let stream_item = if host_lang.is_rust() && func.sig.asyncness.is_none() {
+                            stream_item_type(&func.sig.output)
+                        } else {
+                            None
+                        };
+                        if attributes.publisher {
+                            let fn_ident = func.sig.ident.clone();
+
+                            if host_lang.is_swift() {
+                                self.errors.push(ParseError::FunctionAttribute(
+                                    FunctionAttributeParseError::Publisher(
+                                        PublisherParseError::ExternSwiftFunction { fn_ident },
+                                    ),
+                                ));
+                            } else if stream_item.is_none() {
+                                self.errors.push(ParseError::FunctionAttribute(
+                                    FunctionAttributeParseError::Publisher(
+                                        PublisherParseError::InvalidSignature { fn_ident },
+                                    ),
+                                ));
                             }
                         }
-                    }
+                        if attributes.main_actor && host_lang.is_rust() {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::MainActor(
+                                    MainActorParseError::ExternRustFunction {
+                                        fn_ident: func.sig.ident.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+                        if attributes.dispatch_queue.is_some() {
+                            let fn_ident = func.sig.ident.clone();
+
+                            if func.sig.asyncness.is_some()
+                                || stream_item.is_some()
+                                || attributes.is_swift_initializer
+                                || attributes.is_swift_property
+                                || attributes.is_swift_subscript
+                            {
+                                self.errors.push(ParseError::FunctionAttribute(
+                                    FunctionAttributeParseError::DispatchQueue(
+                                        DispatchQueueParseError::UnsupportedFunction { fn_ident },
+                                    ),
+                                ));
+                            } else if attributes.main_actor {
+                                self.errors.push(ParseError::FunctionAttribute(
+                                    FunctionAttributeParseError::DispatchQueue(
+                                        DispatchQueueParseError::WithMainActor { fn_ident },
+                                    ),
+                                ));
+                            }
+                        }
+                        if stream_item.is_some() || attributes.publisher {
+                            // Swift receives the stream through the generated sequence or
+                            // publisher, so the `impl Stream` is never bridged as a return value.
+                            func.sig.output = ReturnType::Default;
+                        }
 
-                    let return_type = &func.sig.output;
-                    if let ReturnType::Type(_, return_ty) = return_type {
-                        if BridgedType::new_with_type(return_ty.deref(), &self.type_declarations)
-                            .is_none()
-                        {
-                            self.unresolved_types.push(return_ty.deref().clone());
+                        for arg in func.sig.inputs.iter() {
+                            if let FnArg::Typed(pat_ty) = arg {
+                                if !matches!(pat_ty.pat.deref(), Pat::Ident(_)) {
+                                    self.errors.push(ParseError::InvalidArgPattern {
+                                        arg: Box::new(pat_ty.clone()),
+                                    });
+                                }
+                                if pat_type_pat_is_self(pat_ty)
+                                    && !matches!(
+                                        pat_ty.ty.deref(),
+                                        Type::Path(_) | Type::Reference(_)
+                                    )
+                                {
+                                    self.errors.push(ParseError::InvalidSelfType {
+                                        ty: pat_ty.ty.deref().clone(),
+                                    });
+                                    continue;
+                                }
+
+                                let ty = &pat_ty.ty;
+                                if !can_bridge(ty, self.type_declarations) {
+                                    self.unresolved_types.push(ty.deref().clone());
+                                }
+                            }
                         }
-                    }
 
-                    let first_input = func.sig.inputs.iter().next();
+                        let return_type = &func.sig.output;
+                        if let ReturnType::Type(_, return_ty) = return_type {
+                            if !can_bridge(return_ty, self.type_declarations) {
+                                self.unresolved_types.push(return_ty.deref().clone());
+                            }
+                        }
+                        if let Some(item) = stream_item.as_ref() {
+                            if !can_bridge(item, self.type_declarations) {
+                                self.unresolved_types.push(item.clone());
+                            }
+                        }
 
-                    let associated_type = self.get_associated_type(
-                        first_input,
-                        func.clone(),
-                        &attributes,
-                        &mut local_type_declarations,
-                    )?;
+                        let first_input = func.sig.inputs.iter().next();
 
-                    if attributes.is_swift_identifiable {
-                        let args = &func.sig.inputs;
+                        let associated_type = self.get_associated_type(
+                            first_input,
+                            func.clone(),
+                            host_lang,
+                            &attributes,
+                            &mut local_type_declarations,
+                        )?;
 
-                        let mut is_ref_self_no_args = args.len() == 1;
-                        if is_ref_self_no_args {
-                            is_ref_self_no_args = match args.iter().next().unwrap() {
-                                FnArg::Receiver(receiver) => {
-                                    receiver.reference.is_some() && receiver.mutability.is_none()
-                                }
-                                FnArg::Typed(pat_ty) => {
-                                    pat_type_pat_is_self(pat_ty)
-                                        && pat_ty.ty.to_token_stream().to_string().starts_with("&")
-                                }
-                            };
-                        }
+                        if attributes.is_swift_identifiable {
+                            let args = &func.sig.inputs;
 
-                        let has_return_type = matches!(&func.sig.output, ReturnType::Type(_, _));
+                            let mut is_ref_self_no_args = args.len() == 1;
+                            if is_ref_self_no_args {
+                                is_ref_self_no_args = match args.iter().next().unwrap() {
+                                    FnArg::Receiver(receiver) => {
+                                        receiver.reference.is_some()
+                                            && receiver.mutability.is_none()
+                                    }
+                                    FnArg::Typed(pat_ty) => {
+                                        pat_type_pat_is_self(pat_ty)
+                                            && pat_ty
+                                                .ty
+                                                .to_token_stream()
+                                                .to_string()
+                                                .starts_with("&")
+                                    }
+                                };
+                            }
 
-                        if !is_ref_self_no_args {
+                            let has_return_type =
+                                matches!(&func.sig.output, ReturnType::Type(_, _));
+
+                            if !is_ref_self_no_args {
+                                self.errors.push(ParseError::FunctionAttribute(
+                                    FunctionAttributeParseError::Identifiable(
+                                        IdentifiableParseError::MustBeRefSelf {
+                                            fn_ident: func.sig.ident.clone(),
+                                        },
+                                    ),
+                                ));
+                            }
+                            if !has_return_type {
+                                self.errors.push(ParseError::FunctionAttribute(
+                                    FunctionAttributeParseError::Identifiable(
+                                        IdentifiableParseError::MissingReturnType {
+                                            fn_ident: func.sig.ident.clone(),
+                                        },
+                                    ),
+                                ));
+                            }
+                        }
+                        if attributes.unchecked_utf8 && host_lang.is_swift() {
                             self.errors.push(ParseError::FunctionAttribute(
-                                FunctionAttributeParseError::Identifiable(
-                                    IdentifiableParseError::MustBeRefSelf {
+                                FunctionAttributeParseError::UncheckedUtf8(
+                                    UncheckedUtf8ParseError::ExternSwiftFunction {
                                         fn_ident: func.sig.ident.clone(),
                                     },
                                 ),
                             ));
                         }
-                        if !has_return_type {
+                        if !attributes.unsafe_pointer {
+                            if let Some(ty) = raw_pointer_type(&func.sig) {
+                                self.errors.push(ParseError::FunctionAttribute(
+                                    FunctionAttributeParseError::UnsafePointer(
+                                        UnsafePointerParseError::MissingAttribute {
+                                            fn_ident: func.sig.ident.clone(),
+                                            ty: Box::new(ty.clone()),
+                                        },
+                                    ),
+                                ));
+                            }
+                        }
+                        if attributes.return_scratch {
+                            let fn_ident = func.sig.ident.clone();
+                            let returns_str = match &func.sig.output {
+                                ReturnType::Type(_, ty) => match ty.deref() {
+                                    Type::Reference(reference) => {
+                                        reference.elem.to_token_stream().to_string() == "str"
+                                    }
+                                    _ => false,
+                                },
+                                ReturnType::Default => false,
+                            };
+
+                            if host_lang.is_swift() {
+                                self.errors.push(ParseError::FunctionAttribute(
+                                    FunctionAttributeParseError::ReturnScratch(
+                                        ReturnScratchParseError::ExternSwiftFunction { fn_ident },
+                                    ),
+                                ));
+                            } else if func.sig.asyncness.is_some() || !returns_str {
+                                self.errors.push(ParseError::FunctionAttribute(
+                                    FunctionAttributeParseError::ReturnScratch(
+                                        ReturnScratchParseError::InvalidSignature { fn_ident },
+                                    ),
+                                ));
+                            }
+                        }
+                        if let ReturnType::Type(_, ret) = &func.sig.output {
+                            // `return_scratch` functions return a borrow of their scratch buffer.
+                            let returns_borrow = !attributes.return_scratch
+                                && contains_borrow(ret.to_token_stream(), false);
+                            if returns_borrow
+                                && !contains_borrow(func.sig.inputs.to_token_stream(), true)
+                            {
+                                self.errors
+                                    .push(ParseError::ReturnsBorrowWithoutBorrowedArgs {
+                                        fn_ident: func.sig.ident.clone(),
+                                        ret: ret.clone(),
+                                        owned_alternative: Box::new(owned_alternative(ret)),
+                                    });
+                            }
+                        }
+                        if host_lang.is_swift() && func.sig.asyncness.is_some() {
+                            // The Swift function runs in a `Task` that outlives the Rust call, so
+                            // a borrowed argument could be freed while Swift is still using it.
+                            // `'static` borrows are rejected too, since the generated Rust
+                            // function takes the argument without its lifetime.
+                            let borrowed = func.sig.inputs.iter().find_map(|arg| match arg {
+                                FnArg::Typed(pat_ty)
+                                    if contains_borrow(pat_ty.ty.to_token_stream(), true) =>
+                                {
+                                    Some(pat_ty)
+                                }
+                                _ => None,
+                            });
+                            if let Some(borrowed) = borrowed {
+                                self.errors.push(ParseError::AsyncSwiftFnBorrowedArg {
+                                    fn_ident: func.sig.ident.clone(),
+                                    arg: Box::new(borrowed.clone()),
+                                    owned_alternative: Box::new(owned_alternative(&borrowed.ty)),
+                                });
+                            }
+                        }
+                        if host_lang.is_rust() {
+                            // A Swift callback can call back into Rust while the function is
+                            // running, so it must not be able to alias a `&mut` argument.
+                            let mutable_borrow = func
+                                .sig
+                                .inputs
+                                .iter()
+                                .find(|arg| fn_arg_is_mutable_reference(arg));
+                            let takes_callback = func.sig.inputs.iter().any(fn_arg_is_boxed_fn);
+                            if let (Some(mutable_borrow), true) = (mutable_borrow, takes_callback) {
+                                self.errors.push(ParseError::MutableBorrowWithCallback {
+                                    fn_ident: func.sig.ident.clone(),
+                                    mutable_borrow: Box::new(mutable_borrow.clone()),
+                                });
+                            }
+                        }
+                        let swift_to_rust_types: Vec<&Type> = if host_lang.is_rust() {
+                            func.sig
+                                .inputs
+                                .iter()
+                                .filter_map(|arg| match arg {
+                                    FnArg::Typed(pat_ty) => Some(pat_ty.ty.deref()),
+                                    FnArg::Receiver(_) => None,
+                                })
+                                .collect()
+                        } else {
+                            match &func.sig.output {
+                                ReturnType::Type(_, ret) => vec![ret.deref()],
+                                ReturnType::Default => vec![],
+                            }
+                        };
+                        // Types that can't be bridged at all get their own errors.
+                        for ty in swift_to_rust_types
+                            .into_iter()
+                            .filter(|ty| can_bridge(ty, self.type_declarations))
+                        {
+                            if let Some(ty) =
+                                find_rust_to_swift_only_type(ty, self.type_declarations)
+                            {
+                                self.errors.push(ParseError::UnsupportedSwiftToRust { ty });
+                            }
+                        }
+                        if host_lang.is_rust() && func.sig.asyncness.is_some() {
+                            if let ReturnType::Type(_, ret) = &func.sig.output {
+                                let returns_slice = match ret.deref() {
+                                    Type::Reference(reference) => {
+                                        matches!(reference.elem.deref(), Type::Slice(_))
+                                    }
+                                    _ => false,
+                                };
+                                if returns_slice {
+                                    self.errors.push(ParseError::AsyncRustFnReturnsSlice {
+                                        fn_ident: func.sig.ident.clone(),
+                                        ret: ret.clone(),
+                                    });
+                                }
+                            }
+                        }
+                        if attributes.catch_errors && host_lang.is_rust() {
                             self.errors.push(ParseError::FunctionAttribute(
-                                FunctionAttributeParseError::Identifiable(
-                                    IdentifiableParseError::MissingReturnType {
+                                FunctionAttributeParseError::CatchErrors(
+                                    CatchErrorsParseError::ExternRustFunction {
                                         fn_ident: func.sig.ident.clone(),
                                     },
                                 ),
                             ));
                         }
-                    }
-                    let mut argument_labels: HashMap<Ident, LitStr> = HashMap::new();
-                    for arg in func.sig.inputs.iter() {
-                        let is_mutable_ref = fn_arg_is_mutable_reference(arg);
+                        let mut argument_labels: HashMap<Ident, LitStr> = HashMap::new();
+                        for arg in func.sig.inputs.iter() {
+                            let is_mutable_ref = fn_arg_is_mutable_reference(arg);
 
-                        let is_copy_opaque_type =
-                            if let Some(TypeDeclaration::Opaque(o)) = associated_type.as_ref() {
+                            let is_copy_opaque_type = if let Some(TypeDeclaration::Opaque(o)) =
+                                associated_type.as_ref()
+                            {
                                 o.attributes.copy.is_some()
                             } else if let Some(ty) =
                                 bridgeable_type_from_fn_arg(arg, &self.type_declarations)
@@ -180,12 +480,11 @@ impl<'a> ForeignModParser<'a> {
                                 false
                             };
 
-                        if is_mutable_ref && is_copy_opaque_type {
-                            self.errors
-                                .push(ParseError::ArgCopyAndRefMut { arg: arg.clone() });
-                        }
-                        match arg {
-                            syn::FnArg::Typed(ty) => {
+                            if is_mutable_ref && is_copy_opaque_type {
+                                self.errors
+                                    .push(ParseError::ArgCopyAndRefMut { arg: arg.clone() });
+                            }
+                            if let syn::FnArg::Typed(ty) = arg {
                                 for attr in ty.attrs.iter() {
                                     let attribute: ArgumentAttributes = attr.parse_args()?;
                                     if let Some(label) = attribute.label {
@@ -199,47 +498,84 @@ impl<'a> ForeignModParser<'a> {
                                     }
                                 }
                             }
-                            _ => {}
                         }
-                    }
-                    if let Some(ref args) = attributes.args_into {
-                        let mut func_sig_args = HashSet::with_capacity(args.len());
-                        for fn_arg in func.sig.inputs.iter() {
-                            match fn_arg {
-                                FnArg::Receiver(_) => {}
-                                FnArg::Typed(pat_ty) => {
-                                    let fn_arg_name = pat_ty.pat.to_token_stream().to_string();
-                                    func_sig_args.insert(fn_arg_name);
+                        if let Some(ref args) = attributes.args_into {
+                            let mut func_sig_args = HashSet::with_capacity(args.len());
+                            for fn_arg in func.sig.inputs.iter() {
+                                match fn_arg {
+                                    FnArg::Receiver(_) => {}
+                                    FnArg::Typed(pat_ty) => {
+                                        let fn_arg_name = pat_ty.pat.to_token_stream().to_string();
+                                        func_sig_args.insert(fn_arg_name);
+                                    }
                                 }
                             }
-                        }
 
-                        for arg in args.iter() {
-                            let arg_name = arg.to_token_stream().to_string();
+                            for arg in args.iter() {
+                                let arg_name = arg.to_token_stream().to_string();
 
-                            if !func_sig_args.contains(&arg_name) {
-                                self.errors.push(ParseError::ArgsIntoArgNotFound {
-                                    func: func.clone(),
-                                    missing_arg: arg.clone(),
-                                })
+                                if !func_sig_args.contains(&arg_name) {
+                                    self.errors.push(ParseError::ArgsIntoArgNotFound {
+                                        func: func.clone(),
+                                        missing_arg: arg.clone(),
+                                    })
+                                }
                             }
                         }
+                        let is_swift_property = attributes.is_swift_property
+                            || match associated_type.as_ref() {
+                                Some(TypeDeclaration::Opaque(ty)) => swift_properties
+                                    .contains(&(ty.ty.to_string(), func.sig.ident.to_string())),
+                                _ => false,
+                            };
+                        // Calls into an actor have to hop onto it, so Rust awaits every method of
+                        // an `actor` type, whether or not the Swift method is `async`.
+                        let is_actor_method = host_lang.is_swift()
+                            && func.sig.receiver().is_some()
+                            && matches!(
+                                associated_type.as_ref(),
+                                Some(TypeDeclaration::Opaque(ty)) if ty.attributes.actor
+                            );
+                        if is_actor_method && func.sig.asyncness.is_none() {
+                            func.sig.asyncness = Some(Default::default());
+                        }
+                        let doc_comment = parse_doc_comment(&func.attrs);
+                        let mut cfg_attrs = CfgAttr::from_attributes(&func.attrs)?;
+                        cfg_attrs.extend(block_cfg_attrs.iter().cloned());
+                        let func = ParsedExternFn {
+                            func,
+                            associated_type,
+                            is_swift_initializer: attributes.is_swift_initializer,
+                            is_swift_identifiable: attributes.is_swift_identifiable,
+                            host_lang,
+                            rust_name_override: attributes.rust_name,
+                            swift_name_override: attributes.swift_name,
+                            return_into: attributes.return_into,
+                            return_with: attributes.return_with,
+                            args_into: attributes.args_into,
+                            get_field: attributes.get_field,
+                            set_field: attributes.set_field,
+                            is_swift_property,
+                            is_swift_subscript: attributes.is_swift_subscript,
+                            argument_labels,
+                            instantiation,
+                            doc_comment,
+                            available: attributes.available,
+                            swift_access: attributes.swift_access,
+                            cfg_attrs,
+                            inline_shims: false,
+                            small_string_ffi: crate::SMALL_STRING_FFI,
+                            catch_panics: None,
+                            unchecked_utf8: attributes.unchecked_utf8,
+                            return_scratch: attributes.return_scratch,
+                            catch_errors: attributes.catch_errors,
+                            stream_item,
+                            publisher: attributes.publisher,
+                            main_actor: attributes.main_actor,
+                            dispatch_queue: attributes.dispatch_queue,
+                        };
+                        self.functions.push(func);
                     }
-                    let func = ParsedExternFn {
-                        func,
-                        associated_type,
-                        is_swift_initializer: attributes.is_swift_initializer,
-                        is_swift_identifiable: attributes.is_swift_identifiable,
-                        host_lang,
-                        rust_name_override: attributes.rust_name,
-                        swift_name_override: attributes.swift_name,
-                        return_into: attributes.return_into,
-                        return_with: attributes.return_with,
-                        args_into: attributes.args_into,
-                        get_field: attributes.get_field,
-                        argument_labels: argument_labels,
-                    };
-                    self.functions.push(func);
                 }
                 ForeignItem::Verbatim(foreign_item_verbatim) => {
                     if let Ok(generic_foreign_type) =
@@ -247,26 +583,41 @@ impl<'a> ForeignModParser<'a> {
                     {
                         let ty_name = generic_foreign_type.ident.to_string();
 
+                        let mut attributes = OpaqueTypeAllAttributes::from_attributes(
+                            &generic_foreign_type.attributes,
+                        )?;
+
+                        let mut generics = vec![];
+                        for param in generic_foreign_type.generics.params.iter() {
+                            match param {
+                                GenericParam::Type(generic_ty) => generics.push(generic_ty.clone()),
+                                _ => self.errors.push(ParseError::UnsupportedGenericParam {
+                                    param: Box::new(param.clone()),
+                                }),
+                            }
+                        }
+                        attributes.cfg_attrs.extend(block_cfg_attrs.iter().cloned());
+
+                        // Generic classes free their value through a `SwiftBridgeGenericFreer`
+                        // protocol method, which can't be called once `deinit` has returned.
+                        if attributes.drop_on.is_some() {
+                            self.errors.push(ParseError::UnsupportedDropOn {
+                                ty: generic_foreign_type.ident.clone(),
+                            });
+                        }
+
+                        if attributes.observable {
+                            self.errors.push(ParseError::UnsupportedObservable {
+                                ty: generic_foreign_type.ident.clone(),
+                            });
+                        }
+
                         let foreign_ty = OpaqueForeignTypeDeclaration {
                             ty: generic_foreign_type.ident,
                             host_lang,
-                            attributes: OpaqueTypeAllAttributes::from_attributes(
-                                &generic_foreign_type.attributes,
-                            )?,
-                            generics: OpaqueRustTypeGenerics {
-                                generics: generic_foreign_type
-                                    .generics
-                                    .params
-                                    .clone()
-                                    .into_iter()
-                                    .map(|p| match p {
-                                        GenericParam::Type(generic_ty) => generic_ty,
-                                        _ => todo!(
-                                            "Push a ParseError for non-concrete generic types"
-                                        ),
-                                    })
-                                    .collect(),
-                            },
+                            attributes,
+                            generics: OpaqueRustTypeGenerics { generics },
+                            trait_object: false,
                         };
                         let generics: Vec<String> = foreign_ty
                             .generics
@@ -288,10 +639,216 @@ impl<'a> ForeignModParser<'a> {
         Ok(())
     }
 
+    /// Generate getter and setter functions for the fields listed in the
+    /// `#[swift_bridge(get(...), set(...))]` attributes of a foreign module's types.
+    ///
+    /// `#[swift_bridge(get(age: u8), set(age))] type Person;` generates
+    ///
+    /// ```ignore
+    /// #[swift_bridge(get(age))]
+    /// fn age(self: &Person) -> u8;
+    /// #[swift_bridge(set(age))]
+    /// fn set_age(self: &mut Person, age: u8);
+    /// ```
+    ///
+    /// The `(type name, function name)` of every generated function that should be exposed as a
+    /// Swift computed property gets inserted into `swift_properties`.
+    fn field_accessor_fns(
+        &mut self,
+        items: &[ForeignItem],
+        swift_properties: &mut HashSet<(String, String)>,
+    ) -> syn::Result<Vec<ForeignItemFn>> {
+        let mut accessors = vec![];
+
+        for item in items {
+            let foreign_ty = match item {
+                ForeignItem::Type(foreign_ty) => foreign_ty,
+                _ => continue,
+            };
+            let attributes = OpaqueTypeAllAttributes::from_attributes(&foreign_ty.attrs)?;
+            let ty = &foreign_ty.ident;
+            let first_accessor_idx = accessors.len();
+
+            // Properties are declared inside of the generated Swift classes, so types whose
+            // classes are declared elsewhere only get methods.
+            let can_have_properties = !attributes.already_declared && attributes.copy.is_none();
+
+            for field in attributes.get_fields.iter() {
+                let field_name = &field.name;
+                let field_ty = match &field.ty {
+                    Some(field_ty) => field_ty,
+                    None => {
+                        self.errors.push(ParseError::MissingFieldType {
+                            field: field_name.clone(),
+                            attribute: "get",
+                        });
+                        continue;
+                    }
+                };
+
+                let is_mut_ref = matches!(field_ty, Type::Reference(reference) if reference.mutability.is_some());
+
+                let getter: ForeignItemFn = match field_ty {
+                    _ if is_mut_ref => syn::parse_quote! {
+                        #[swift_bridge(get(&mut #field_name))]
+                        fn #field_name(self: &mut #ty) -> #field_ty;
+                    },
+                    Type::Reference(_) => syn::parse_quote! {
+                        #[swift_bridge(get(&#field_name))]
+                        fn #field_name(self: &#ty) -> #field_ty;
+                    },
+                    _ => syn::parse_quote! {
+                        #[swift_bridge(get(#field_name))]
+                        fn #field_name(self: &#ty) -> #field_ty;
+                    },
+                };
+                accessors.push(getter);
+
+                if can_have_properties && !is_mut_ref {
+                    swift_properties.insert((ty.to_string(), field_name.to_string()));
+                }
+            }
+
+            // `Identifiable` conformance is satisfied by the generated `id` property.
+            if attributes.identifiable
+                && !swift_properties.contains(&(ty.to_string(), "id".to_string()))
+            {
+                self.errors
+                    .push(ParseError::IdentifiableMissingId { ty: ty.clone() });
+            }
+
+            for field in attributes.set_fields.iter() {
+                let field_name = &field.name;
+                let getter = attributes
+                    .get_fields
+                    .iter()
+                    .find(|get_field| &get_field.name == field_name);
+
+                // A `set(age)` uses the `age` type from `get(age: u8)`. References can't be
+                // stored in the field, so those need an explicit type.
+                let field_ty = match (&field.ty, getter.and_then(|g| g.ty.as_ref())) {
+                    (Some(field_ty), _) => field_ty,
+                    (None, Some(field_ty)) if !matches!(field_ty, Type::Reference(_)) => field_ty,
+                    _ => {
+                        self.errors.push(ParseError::MissingFieldType {
+                            field: field_name.clone(),
+                            attribute: "set",
+                        });
+                        continue;
+                    }
+                };
+
+                let setter_name = format_ident!("set_{}", field_name);
+                accessors.push(syn::parse_quote! {
+                    #[swift_bridge(set(#field_name))]
+                    fn #setter_name(self: &mut #ty, #field_name: #field_ty);
+                });
+
+                // A Swift property can't be set-only, so the setter is only a property when it
+                // can override the getter's property.
+                if swift_properties.contains(&(ty.to_string(), field_name.to_string())) {
+                    swift_properties.insert((ty.to_string(), setter_name.to_string()));
+                }
+            }
+
+            // The accessors only exist when their type does.
+            let cfg_attrs = foreign_ty
+                .attrs
+                .iter()
+                .filter(|attr| attr.path.is_ident("cfg"));
+            for accessor in accessors[first_accessor_idx..].iter_mut() {
+                accessor.attrs.extend(cfg_attrs.clone().cloned());
+            }
+        }
+
+        Ok(accessors)
+    }
+
+    /// Returns one function per type listed in a generic function's
+    /// `#[swift_bridge(instantiate(...))]` attribute, with the function's generic type parameter
+    /// replaced by that type.
+    ///
+    /// Non-generic functions are returned as is.
+    fn instantiate_generic_fn(
+        &mut self,
+        func: ForeignItemFn,
+        host_lang: HostLang,
+    ) -> syn::Result<Vec<(ForeignItemFn, Option<GenericFnInstantiation>)>> {
+        let mut attributes = FunctionAttributes::default();
+        for attr in func.attrs.iter() {
+            if attr.path.is_ident("doc") || attr.path.is_ident("cfg") {
+                continue;
+            }
+            attributes = attr.parse_args()?;
+        }
+
+        let fn_ident = func.sig.ident.clone();
+        let type_params: Vec<&TypeParam> = func.sig.generics.type_params().collect();
+
+        if attributes.instantiate.is_empty() {
+            if !type_params.is_empty() {
+                self.errors.push(ParseError::FunctionAttribute(
+                    FunctionAttributeParseError::Instantiate(
+                        InstantiateParseError::MissingInstantiations { fn_ident },
+                    ),
+                ));
+                return Ok(vec![]);
+            }
+
+            return Ok(vec![(func, None)]);
+        }
+
+        if type_params.len() != 1 || func.sig.generics.params.len() != 1 {
+            self.errors.push(ParseError::FunctionAttribute(
+                FunctionAttributeParseError::Instantiate(
+                    InstantiateParseError::RequiresOneTypeParam { fn_ident },
+                ),
+            ));
+            return Ok(vec![]);
+        }
+        if host_lang.is_swift() {
+            self.errors.push(ParseError::FunctionAttribute(
+                FunctionAttributeParseError::Instantiate(
+                    InstantiateParseError::ExternSwiftFunction { fn_ident },
+                ),
+            ));
+            return Ok(vec![]);
+        }
+
+        let generic = type_params[0].ident.clone();
+
+        let mut instantiations = Vec::with_capacity(attributes.instantiate.len());
+        for ty in attributes.instantiate {
+            let mut instantiated_sig = func.sig.clone();
+            instantiated_sig.generics = Generics::default();
+            let instantiated_sig = replace_ident(
+                instantiated_sig.to_token_stream(),
+                &generic,
+                &ty.to_token_stream(),
+            );
+
+            let mut instantiated = func.clone();
+            instantiated.sig = syn::parse2(instantiated_sig)?;
+
+            let name = match BridgedType::new_with_type(&ty, self.type_declarations) {
+                Some(bridged) => bridged.to_alpha_numeric_underscore_name(self.type_declarations),
+                None => {
+                    self.unresolved_types.push(ty);
+                    continue;
+                }
+            };
+
+            instantiations.push((instantiated, Some(GenericFnInstantiation { ty, name })));
+        }
+
+        Ok(instantiations)
+    }
+
     fn get_associated_type(
         &mut self,
         first: Option<&FnArg>,
         func: ForeignItemFn,
+        host_lang: HostLang,
         attributes: &FunctionAttributes,
         local_type_declarations: &mut HashMap<String, OpaqueForeignTypeDeclaration>,
     ) -> syn::Result<Option<TypeDeclaration>> {
@@ -319,145 +876,505 @@ impl<'a> ForeignModParser<'a> {
                         let self_ty = match arg.ty.deref() {
                             Type::Path(ty_path) => ty_path.path.segments.to_token_stream(),
                             Type::Reference(type_ref) => type_ref.elem.deref().to_token_stream(),
-                            _ => {
-                                todo!("Add a test that hits this branch")
-                            }
+                            // Already pushed as an `InvalidSelfType`.
+                            _ => return Ok(None),
                         };
 
                         let self_ty_string = self_ty.to_string();
                         // Handles generics. i.e. "SomeType< u32, u64 >" -> "SomeType<u32,u64>";
                         let self_ty_string = self_ty_string.replace(" ", "");
 
-                        let ty = self.type_declarations.get(&self_ty_string).unwrap();
-                        let associated_type = Some(ty.clone());
-                        associated_type
+                        // An undeclared `self` type was already pushed to the unresolved types.
+                        self.type_declarations.get(&self_ty_string).cloned()
                     } else {
                         let associated_type = self.get_associated_type(
                             None,
                             func.clone(),
+                            host_lang,
                             attributes,
                             local_type_declarations,
                         )?;
                         associated_type
                     }
                 }
-                _ => {
-                    todo!(
-                        r#"
-One way to hit this block is with a `fn foo (&self: SomeType)`.
-Note that this is an invalid signature since the `&` should be in front of `SomeType`, not `self`.
-i.e., this would be correct: `fn foo (self: &SomeType)`
-We should add a test case like this that hits this block, and verify that we push a parse error
-indicating that the function signature is invalid.
-For common mistakes such as the `&self: SomeType` example, we can have dedicated errors telling you
-exactly how to fix it.
-Otherwise we use a more general error that says that your argument is invalid.
-"#
-                    )
-                }
+                // Such as `fn foo (&self: SomeType)`, which was already pushed as an
+                // `InvalidArgPattern`.
+                _ => return Ok(None),
             },
             None => {
                 let associated_type = if let Some(associated_to) = &attributes.associated_to {
-                    let ty = self
-                        .type_declarations
-                        .get(&associated_to.to_string())
-                        .unwrap();
-                    Some(ty.clone())
-                } else if attributes.is_swift_initializer {
-                    let ty_string = match &func.sig.output {
-                        ReturnType::Default => {
-                            todo!("Push error if initializer does not return a type")
-                        }
-                        ReturnType::Type(_, ty) => {
-                            let ty_string = ty.deref().to_token_stream().to_string();
-                            ty_string
+                    let ty = self.type_declarations.get(&associated_to.to_string());
+                    if ty.is_none() {
+                        self.errors.push(ParseError::UndeclaredType {
+                            ty: syn::parse2(associated_to.to_token_stream())?,
+                        });
+                    }
+                    ty.cloned()
+                } else {
+                    None
+                };
+
+                if attributes.is_swift_initializer {
+                    let initialized = self.get_initialized_type(&func);
+                    let returns_associated_type = match (&associated_type, &initialized) {
+                        (
+                            Some(TypeDeclaration::Opaque(associated)),
+                            Some(TypeDeclaration::Opaque(initialized)),
+                        ) => {
+                            associated.ty_name_with_generics()
+                                == initialized.ty_name_with_generics()
                         }
+                        // An undeclared `associated_to` type was already pushed as an error.
+                        (None, _) if attributes.associated_to.is_some() => true,
+                        (None, initialized) => initialized.is_some(),
+                        (Some(_), _) => false,
+                    };
+                    // A return type that can't be bridged was already pushed as an error.
+                    let returns_unresolved_type = match &func.sig.output {
+                        ReturnType::Default => false,
+                        ReturnType::Type(_, ty) => !can_bridge(ty, self.type_declarations),
                     };
 
-                    let ty = self.type_declarations.get(&ty_string);
+                    if !returns_associated_type && !returns_unresolved_type {
+                        self.errors.push(ParseError::InvalidInitializerReturn {
+                            fn_ident: func.sig.ident.clone(),
+                            ret: match &func.sig.output {
+                                ReturnType::Default => None,
+                                ReturnType::Type(_, ty) => Some(ty.clone()),
+                            },
+                            associated_to: attributes.associated_to.clone(),
+                            failable: host_lang.is_swift(),
+                        });
+                        return Ok(None);
+                    }
 
-                    ty.map(|ty| ty.clone())
+                    associated_type.or(initialized)
                 } else {
-                    None
-                };
-
-                associated_type
+                    associated_type
+                }
             }
         };
 
         Ok(associated_type)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::errors::ParseError;
-    use crate::test_utils::{parse_errors, parse_ok};
-    use crate::SwiftBridgeModule;
-    use quote::{quote, ToTokens};
-    use syn::parse_quote;
+    /// The opaque type that an initializer returns, which is `SomeType` for both `-> SomeType`
+    /// and, for a failable Swift initializer, `-> Option<SomeType>`.
+    fn get_initialized_type(&self, func: &ForeignItemFn) -> Option<TypeDeclaration> {
+        let ty_string = match &func.sig.output {
+            ReturnType::Default => return None,
+            ReturnType::Type(_, ty) => {
+                let ty_string = ty.deref().to_token_stream().to_string();
+                // Handles generics. i.e. "SomeType< u32 >" -> "SomeType<u32>";
+                ty_string.replace(" ", "")
+            }
+        };
 
-    /// Verify that we can parse a SwiftBridgeModule from an empty module.
-    #[test]
-    fn parse_empty_module() {
-        let tokens = quote! {
-            mod foo { }
+        // A failable Swift `init?` returns `Option<SomeType>`.
+        let failable_ty_string = ty_string
+            .strip_prefix("Option<")
+            .and_then(|ty| ty.strip_suffix(">"));
+
+        let ty = match failable_ty_string {
+            Some(ty_string) => self
+                .type_declarations
+                .get(ty_string)
+                .filter(|ty| matches!(ty, TypeDeclaration::Opaque(ty) if ty.host_lang.is_swift())),
+            None => self.type_declarations.get(&ty_string),
         };
-        let module: SwiftBridgeModule = parse_quote!(#tokens);
 
-        assert_eq!(module.name.to_string(), "foo");
+        // Shared structs and enums are initialized using their fields instead.
+        ty.filter(|ty| matches!(ty, TypeDeclaration::Opaque(_)))
+            .cloned()
     }
+}
 
-    /// Verify that we store an error if no abi name was provided.
-    #[test]
-    fn error_if_no_abi_name_provided_for_an_extern_block() {
-        let tokens = quote! {
-            mod foo {
-                extern {}
+/// Replace every occurrence of an identifier, such as a generic type parameter `T`, with the
+/// given tokens.
+fn replace_ident(tokens: TokenStream, ident: &Ident, replacement: &TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Ident(i) if &i == ident => replacement.clone(),
+            TokenTree::Group(group) => {
+                let mut replaced = Group::new(
+                    group.delimiter(),
+                    replace_ident(group.stream(), ident, replacement),
+                );
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced).into()
+            }
+            other => other.into(),
+        })
+        .collect()
+}
+
+/// Push errors for `#[swift_bridge(swift_property)]` methods that can't be exposed as a Swift
+/// computed property.
+///
+/// The getters and setters that get generated for `#[swift_bridge(get(...), set(...))]` fields
+/// are always valid, so we only check the methods that were declared with the attribute.
+/// Check that `catch_errors` functions return a `Result` that Swift is able to build out of a
+/// caught error.
+///
+/// This has to wait until all of the module's types have been declared, since the `Ok` value
+/// can be an opaque type.
+pub(super) fn validate_catch_errors(
+    functions: &[ParsedExternFn],
+    types: &TypeDeclarations,
+    errors: &mut ParseErrors,
+) {
+    for func in functions
+        .iter()
+        .filter(|func| func.catch_errors && func.host_lang.is_swift())
+    {
+        let result = BridgedType::new_with_return_type(&func.sig.output, types);
+        let is_valid = match result.as_ref().and_then(|ty| ty.as_result()) {
+            Some(result) => {
+                let is_type = |ty: &dyn BridgeableType, name: &str| {
+                    ty.to_rust_type_path(types).to_string() == name
+                };
+                let ok_is_opaque =
+                    result.ok_ty.is_custom_type() && result.ok_ty.is_passed_via_pointer();
+
+                (result.ok_ty.is_null() || ok_is_opaque || is_type(result.ok_ty.as_ref(), "String"))
+                    && (is_type(result.err_ty.as_ref(), "String")
+                        || is_type(
+                            result.err_ty.as_ref(),
+                            "swift_bridge :: ns_error :: NsError",
+                        ))
             }
+            None => false,
         };
-        let errors = parse_errors(tokens);
 
-        assert_eq!(errors.len(), 1);
-        match errors[0] {
-            ParseError::AbiNameMissing { .. } => {}
-            _ => panic!(),
+        if func.sig.asyncness.is_some() || !is_valid {
+            errors.push(ParseError::FunctionAttribute(
+                FunctionAttributeParseError::CatchErrors(CatchErrorsParseError::InvalidSignature {
+                    fn_ident: func.sig.ident.clone(),
+                }),
+            ));
         }
     }
+}
 
-    /// Verify that we store an error if the abi name isn't Rust or Swift.
-    #[test]
-    fn error_if_invalid_abi_name() {
-        let tokens = quote! {
-            mod foo {
-                extern "SomeAbi" {}
-            }
-        };
-        let errors = parse_errors(tokens);
+pub(super) fn validate_swift_properties(functions: &[ParsedExternFn], errors: &mut ParseErrors) {
+    let properties = functions.iter().filter(|func| {
+        func.is_swift_property && func.get_field.is_none() && func.set_field.is_none()
+    });
+
+    let (setters, getters): (Vec<&ParsedExternFn>, Vec<&ParsedExternFn>) =
+        properties.partition(|func| func.self_mutability().is_some());
+
+    for getter in getters.iter() {
+        let has_return_type = matches!(&getter.sig.output, ReturnType::Type(_, _));
+
+        if getter.self_reference().is_none()
+            || !method_arg_types(getter).is_empty()
+            || !has_return_type
+        {
+            errors.push(ParseError::FunctionAttribute(
+                FunctionAttributeParseError::SwiftProperty(
+                    SwiftPropertyParseError::InvalidGetter {
+                        fn_ident: getter.sig.ident.clone(),
+                    },
+                ),
+            ));
+        }
+    }
 
-        assert_eq!(errors.len(), 1);
-        match &errors[0] {
-            ParseError::AbiNameInvalid { abi_name } => {
-                assert_eq!(abi_name.value(), "SomeAbi");
-            }
-            _ => panic!(),
+    for setter in setters {
+        let has_return_type = matches!(&setter.sig.output, ReturnType::Type(_, _));
+
+        if method_arg_types(setter).len() != 1 || has_return_type {
+            errors.push(ParseError::FunctionAttribute(
+                FunctionAttributeParseError::SwiftProperty(
+                    SwiftPropertyParseError::InvalidSetter {
+                        fn_ident: setter.sig.ident.clone(),
+                    },
+                ),
+            ));
+            continue;
+        }
+
+        let has_getter = getters.iter().any(|getter| {
+            opaque_type_name(getter) == opaque_type_name(setter)
+                && getter.swift_property_name() == setter.swift_property_name()
+        });
+
+        if !has_getter {
+            errors.push(ParseError::FunctionAttribute(
+                FunctionAttributeParseError::SwiftProperty(
+                    SwiftPropertyParseError::MissingGetter {
+                        fn_ident: setter.sig.ident.clone(),
+                    },
+                ),
+            ));
         }
     }
+}
 
-    /// Verify that we can parse a Rust type declaration.
-    #[test]
-    fn rust_type_declaration() {
-        let tokens = quote! {
-            mod foo {
-                extern "Rust" {
-                    type Foo;
-                }
-            }
-        };
+/// Push errors for `#[swift_bridge(subscript)]` methods that can't be exposed as a Swift subscript.
+///
+/// A setter becomes the `set` half of an overridden subscript, so it needs a getter on the same
+/// type whose index arguments match the setter's leading arguments.
+pub(super) fn validate_swift_subscripts(functions: &[ParsedExternFn], errors: &mut ParseErrors) {
+    let subscripts = functions.iter().filter(|func| func.is_swift_subscript);
+
+    let (setters, getters): (Vec<&ParsedExternFn>, Vec<&ParsedExternFn>) =
+        subscripts.partition(|func| func.self_mutability().is_some());
+
+    for getter in getters.iter() {
+        let has_return_type = matches!(&getter.sig.output, ReturnType::Type(_, _));
+
+        if getter.self_reference().is_none()
+            || method_arg_types(getter).is_empty()
+            || !has_return_type
+        {
+            errors.push(ParseError::FunctionAttribute(
+                FunctionAttributeParseError::Subscript(SubscriptParseError::InvalidGetter {
+                    fn_ident: getter.sig.ident.clone(),
+                }),
+            ));
+        }
+    }
 
-        let module = parse_ok(tokens);
+    for setter in setters {
+        let mut arg_types = method_arg_types(setter);
+        let has_return_type = matches!(&setter.sig.output, ReturnType::Type(_, _));
+
+        if arg_types.len() < 2 || has_return_type {
+            errors.push(ParseError::FunctionAttribute(
+                FunctionAttributeParseError::Subscript(SubscriptParseError::InvalidSetter {
+                    fn_ident: setter.sig.ident.clone(),
+                }),
+            ));
+            continue;
+        }
 
-        assert_eq!(module.types.types()[0].unwrap_opaque().to_string(), "Foo");
+        let value_ty = arg_types.pop().unwrap();
+        let has_getter = getters.iter().any(|getter| {
+            let return_ty = match &getter.sig.output {
+                ReturnType::Type(_, ty) => ty.to_token_stream().to_string(),
+                ReturnType::Default => return false,
+            };
+
+            opaque_type_name(getter) == opaque_type_name(setter)
+                && method_arg_types(getter) == arg_types
+                && return_ty == value_ty
+        });
+
+        if !has_getter {
+            errors.push(ParseError::FunctionAttribute(
+                FunctionAttributeParseError::Subscript(SubscriptParseError::MissingGetter {
+                    fn_ident: setter.sig.ident.clone(),
+                }),
+            ));
+        }
+    }
+}
+
+/// Whether the argument is a callback, such as `callback: Box<dyn Fn(u8)>`.
+fn fn_arg_is_boxed_fn(fn_arg: &FnArg) -> bool {
+    match fn_arg {
+        FnArg::Receiver(_) => false,
+        FnArg::Typed(pat_ty) => BridgeableBoxedFnOnce::can_parse_token_stream_str(
+            &pat_ty.ty.to_token_stream().to_string(),
+        ),
+    }
+}
+
+/// `impl Stream<Item = T>` -> `T`
+fn stream_item_type(output: &ReturnType) -> Option<Type> {
+    let impl_trait = match output {
+        ReturnType::Type(_, ty) => match ty.deref() {
+            Type::ImplTrait(impl_trait) => impl_trait,
+            _ => return None,
+        },
+        ReturnType::Default => return None,
+    };
+
+    impl_trait.bounds.iter().find_map(|bound| {
+        let segment = match bound {
+            TypeParamBound::Trait(bound) => bound.path.segments.last()?,
+            TypeParamBound::Lifetime(_) => return None,
+        };
+        if segment.ident != "Stream" {
+            return None;
+        }
+
+        match &segment.arguments {
+            PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+                GenericArgument::Binding(binding) if binding.ident == "Item" => {
+                    Some(binding.ty.clone())
+                }
+                _ => None,
+            }),
+            _ => None,
+        }
+    })
+}
+
+/// Whether the tokens contain a `&` borrow.
+///
+/// `&'static` borrows are only counted if `count_static` is true, since they don't need to borrow
+/// from anything.
+fn contains_borrow(tokens: TokenStream, count_static: bool) -> bool {
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '&' => {
+                let is_static = match tokens.peek() {
+                    Some(TokenTree::Punct(lifetime)) if lifetime.as_char() == '\'' => {
+                        let mut lifetime_name = tokens.clone().skip(1);
+                        matches!(lifetime_name.next(), Some(TokenTree::Ident(name)) if name == "static")
+                    }
+                    _ => false,
+                };
+                if count_static || !is_static {
+                    return true;
+                }
+            }
+            TokenTree::Group(group) if contains_borrow(group.stream(), count_static) => {
+                return true;
+            }
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// The first argument or return type in the signature that is or contains a raw pointer, such as
+/// `*const c_void` or `Box<dyn FnOnce(*mut u8)>`.
+fn raw_pointer_type(sig: &syn::Signature) -> Option<&Type> {
+    let args = sig.inputs.iter().filter_map(|arg| match arg {
+        FnArg::Typed(pat_ty) => Some(pat_ty.ty.deref()),
+        FnArg::Receiver(_) => None,
+    });
+    let ret = match &sig.output {
+        ReturnType::Type(_, ty) => Some(ty.deref()),
+        ReturnType::Default => None,
+    };
+
+    args.chain(ret)
+        .find(|ty| contains_raw_pointer(ty.to_token_stream()))
+}
+
+/// Whether the tokens contain a `*const` or `*mut`.
+fn contains_raw_pointer(tokens: TokenStream) -> bool {
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '*' => {
+                if matches!(tokens.peek(), Some(TokenTree::Ident(ident)) if ident == "const" || ident == "mut")
+                {
+                    return true;
+                }
+            }
+            TokenTree::Group(group) if contains_raw_pointer(group.stream()) => {
+                return true;
+            }
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// The name of the opaque type that a method belongs to.
+fn opaque_type_name(func: &ParsedExternFn) -> Option<String> {
+    match func.associated_type.as_ref() {
+        Some(TypeDeclaration::Opaque(ty)) => Some(ty.ty.to_string()),
+        _ => None,
+    }
+}
+
+/// The types of a method's arguments, not including `self`.
+fn method_arg_types(func: &ParsedExternFn) -> Vec<String> {
+    func.sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => {
+                Some(pat_ty.ty.to_token_stream().to_string())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::ParseError;
+    use crate::parse::SwiftSendable;
+    use crate::test_utils::{parse_errors, parse_ok};
+    use crate::SwiftBridgeModule;
+    use quote::{quote, ToTokens};
+    use syn::parse_quote;
+
+    /// Verify that we can parse a SwiftBridgeModule from an empty module.
+    #[test]
+    fn parse_empty_module() {
+        let tokens = quote! {
+            mod foo { }
+        };
+        let module: SwiftBridgeModule = parse_quote!(#tokens);
+
+        assert_eq!(module.name.to_string(), "foo");
+    }
+
+    /// Verify that we store an error if no abi name was provided.
+    #[test]
+    fn error_if_no_abi_name_provided_for_an_extern_block() {
+        let tokens = quote! {
+            mod foo {
+                extern {}
+            }
+        };
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match errors[0] {
+            ParseError::AbiNameMissing { .. } => {}
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we store an error if the abi name isn't Rust or Swift.
+    #[test]
+    fn error_if_invalid_abi_name() {
+        let tokens = quote! {
+            mod foo {
+                extern "SomeAbi" {}
+            }
+        };
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::AbiNameInvalid { abi_name } => {
+                assert_eq!(abi_name.value(), "SomeAbi");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we can parse a Rust type declaration.
+    #[test]
+    fn rust_type_declaration() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.types.types()[0].unwrap_opaque().to_string(), "Foo");
     }
 
     /// Verify that we return an error if the declared type is a built in type.
@@ -532,270 +1449,1033 @@ mod tests {
         assert_eq!(errors.len(), 0,);
     }
 
-    /// Verify that we can parse a freestanding Rust function declaration.
+    /// Verify that we push an error for functions that return a borrow without taking any
+    /// borrowed arguments, unless the borrow is `'static`.
     #[test]
-    fn rust_freestanding_function_no_args() {
+    fn error_if_returns_borrow_without_borrowed_args() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    fn bar () -> u8;
+                    type Foo;
+
+                    fn a () -> &str;
+                    fn b (arg: u8) -> Option<&[u8]>;
+                    fn c () -> &mut Foo;
+
+                    fn d () -> &'static str;
+                    fn e (&self) -> &str;
+                    fn f (foo: &Foo) -> Option<&Foo>;
                 }
             }
         };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
 
-        let module = parse_ok(tokens);
-
-        assert_eq!(module.functions.len(), 1);
+        let expected = [
+            ("a", "String"),
+            ("b", "Option < Vec < u8 > >"),
+            ("c", "Foo"),
+        ];
+        for (error, (expected_fn, expected_owned)) in errors.iter().zip(expected) {
+            match error {
+                ParseError::ReturnsBorrowWithoutBorrowedArgs {
+                    fn_ident,
+                    owned_alternative,
+                    ..
+                } => {
+                    assert_eq!(fn_ident, expected_fn);
+                    assert_eq!(
+                        owned_alternative.to_token_stream().to_string(),
+                        expected_owned
+                    );
+                }
+                _ => panic!(),
+            }
+        }
     }
 
-    /// Verify that we can parse a freestanding Rust function declaration that has one arg.
+    /// Verify that we push an error for extern "Rust" functions that take a mutable borrow along
+    /// with a callback, since the callback could call back into Rust and alias the borrow.
     #[test]
-    fn rust_freestanding_function_one_arg() {
+    fn error_if_mutable_borrow_with_callback() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    fn bar (bazz: u32);
+                    type Foo;
+
+                    fn a (&mut self, callback: Box<dyn Fn()>);
+                    fn b (foo: &mut Foo, callback: Box<dyn FnOnce(u8) -> u8>);
+
+                    fn c (&self, callback: Box<dyn Fn()>);
+                    fn d (&mut self);
+                }
+
+                extern "Swift" {
+                    type Bar;
+
+                    fn e (&mut self, callback: Box<dyn Fn()>);
                 }
             }
         };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
 
-        let module = parse_ok(tokens);
-
-        assert_eq!(module.functions.len(), 1);
+        for (error, expected_fn) in errors.iter().zip(["a", "b"]) {
+            match error {
+                ParseError::MutableBorrowWithCallback { fn_ident, .. } => {
+                    assert_eq!(fn_ident, expected_fn);
+                }
+                _ => panic!(),
+            }
+        }
     }
 
-    /// Verify that if a freestanding function has argument types that were not declared in the
-    /// module we return an error.
+    /// Verify that we push an error for async extern "Swift" functions that take borrowed
+    /// arguments, since Swift keeps using the arguments after the Rust call has returned.
     #[test]
-    fn freestanding_function_argument_undeclared_type() {
+    fn error_if_async_extern_swift_fn_takes_borrowed_arg() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
                     type Foo;
+                }
 
-                    fn a (bar: Bar);
-                    fn b (bar: &Bar);
-                    fn c (bar: &mut Bar);
-                    // Counts as two errors.
-                    fn d (multiple: Bar, args: Bar);
+                extern "Swift" {
+                    type Bar;
+
+                    async fn a (arg: &str);
+                    async fn b (arg: u8, foo: &Foo);
+                    async fn c (&self, arg: &[u8]);
+                    async fn d (arg: &'static str);
+
+                    async fn e (&self, arg: String);
+                    fn f (arg: &str);
                 }
             }
         };
         let errors = parse_errors(tokens);
-        assert_eq!(errors.len(), 5);
+        assert_eq!(errors.len(), 4);
+
+        let expected = [
+            ("a", "String"),
+            ("b", "Foo"),
+            ("c", "Vec < u8 >"),
+            ("d", "String"),
+        ];
+        for (error, (expected_fn, expected_owned)) in errors.iter().zip(expected) {
+            match error {
+                ParseError::AsyncSwiftFnBorrowedArg {
+                    fn_ident,
+                    owned_alternative,
+                    ..
+                } => {
+                    assert_eq!(fn_ident, expected_fn);
+                    assert_eq!(
+                        owned_alternative.to_token_stream().to_string(),
+                        expected_owned
+                    );
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error when Swift would need to pass a `Cow<'static, str>` to Rust,
+    /// which we only support passing from Rust to Swift.
+    #[test]
+    fn error_if_cow_str_passed_from_swift_to_rust() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a (arg: Cow<'static, str>);
+                    fn b (arg: (Cow<'static, str>, u8));
+                    fn c () -> Cow<'static, str>;
+                }
+
+                extern "Swift" {
+                    fn d () -> Cow<'static, str>;
+                    fn e () -> Result<Cow<'static, str>, String>;
+                    fn f (arg: Cow<'static, str>);
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
 
         for error in errors.iter() {
             match error {
-                ParseError::UndeclaredType { ty } => {
-                    let ty_name = ty.to_token_stream().to_string();
-                    // "& Bar" -> "Bar"
-                    let ty_name = ty_name.split_whitespace().last().unwrap();
+                ParseError::UnsupportedSwiftToRust { ty } => {
+                    assert_eq!(ty.to_token_stream().to_string(), "Cow < 'static , str >");
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error for async extern "Rust" functions that return slices.
+    #[test]
+    fn error_if_async_extern_rust_fn_returns_slice() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    async fn a (&self) -> &[SomeType];
+                    async fn b (&self) -> &[u8];
+                    fn c (&self) -> &[SomeType];
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for (error, expected_fn) in errors.iter().zip(["a", "b"]) {
+            match error {
+                ParseError::AsyncRustFnReturnsSlice { fn_ident, .. } => {
+                    assert_eq!(fn_ident, expected_fn);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push errors that point at the offending tokens for unsupported generic
+    /// parameters, `self` types and argument patterns, instead of panicking.
+    #[test]
+    fn error_if_unsupported_signature() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+                    type Bar<'a>;
+
+                    fn a (self: [Foo; 2]);
+                    fn b (&self: Foo);
+                    fn c (arg: u8, (d, e): (u8, u8));
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+
+        match &errors[0] {
+            ParseError::UnsupportedGenericParam { param } => {
+                assert!(matches!(**param, syn::GenericParam::Lifetime(_)))
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::InvalidSelfType { ty } => {
+                assert_eq!(ty.to_token_stream().to_string(), "[Foo ; 2]")
+            }
+            _ => panic!(),
+        };
+        for error in &errors[2..] {
+            assert!(matches!(error, ParseError::InvalidArgPattern { .. }));
+        }
+    }
+
+    /// Verify that we push an error that points at the type named in an `associated_to`
+    /// attribute if it isn't declared.
+    #[test]
+    fn error_if_associated_to_type_undeclared() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(associated_to = Missing)]
+                    fn new () -> u8;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::UndeclaredType { ty } => {
+                assert_eq!(ty.to_token_stream().to_string(), "Missing")
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we push an error if an initializer doesn't return a type, instead of
+    /// panicking.
+    #[test]
+    fn error_if_initializer_does_not_return_a_type() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+
+                    #[swift_bridge(init)]
+                    fn new();
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::InvalidInitializerReturn { fn_ident, ret, .. } => {
+                assert_eq!(fn_ident, "new");
+                assert!(ret.is_none());
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we push an error if an initializer returns something other than an opaque
+    /// type that the module declares, such as a primitive or a shared struct.
+    #[test]
+    fn error_if_initializer_returns_non_opaque_type() {
+        let tokens = quote! {
+            mod foo {
+                struct SomeStruct;
+
+                extern "Rust" {
+                    type Foo;
+
+                    #[swift_bridge(init)]
+                    fn new_u8() -> u8;
+                    #[swift_bridge(init)]
+                    fn new_struct() -> SomeStruct;
+                    #[swift_bridge(init)]
+                    fn new_option() -> Option<Foo>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        let returned: Vec<String> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::InvalidInitializerReturn {
+                    ret: Some(ret),
+                    failable,
+                    ..
+                } => {
+                    assert!(!failable);
+                    ret.to_token_stream().to_string()
+                }
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(returned, vec!["u8", "SomeStruct", "Option < Foo >"]);
+    }
+
+    /// Verify that we push an error if an initializer returns a type other than the one named by
+    /// its `associated_to` attribute.
+    #[test]
+    fn error_if_initializer_does_not_return_associated_to_type() {
+        let tokens = quote! {
+            mod foo {
+                extern "Swift" {
+                    type Foo;
+                    type Bar;
+
+                    #[swift_bridge(init, associated_to = Foo)]
+                    fn new() -> Bar;
+                    #[swift_bridge(init, associated_to = Foo)]
+                    fn new_failable() -> Option<Foo>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::InvalidInitializerReturn {
+                fn_ident,
+                associated_to,
+                failable,
+                ..
+            } => {
+                assert_eq!(fn_ident, "new");
+                assert_eq!(associated_to.as_ref().unwrap(), "Foo");
+                assert!(failable);
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that unsupported attributes on opaque types and invalid `Copy` sizes are errors
+    /// instead of panics.
+    #[test]
+    fn error_if_invalid_opaque_type_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[derive(Clone)]
+                    type Foo;
+                }
+            }
+        };
+        assert!(syn::parse2::<crate::SwiftBridgeModule>(tokens).is_err());
+
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Copy(99999999999999999999999))]
+                    type Foo;
+                }
+            }
+        };
+        assert!(syn::parse2::<crate::SwiftBridgeModule>(tokens).is_err());
+    }
+
+    /// Verify that we can parse a freestanding Rust function declaration.
+    #[test]
+    fn rust_freestanding_function_no_args() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn bar () -> u8;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.functions.len(), 1);
+    }
+
+    /// Verify that we can parse a freestanding Rust function declaration that has one arg.
+    #[test]
+    fn rust_freestanding_function_one_arg() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn bar (bazz: u32);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.functions.len(), 1);
+    }
+
+    /// Verify that if a freestanding function has argument types that were not declared in the
+    /// module we return an error.
+    #[test]
+    fn freestanding_function_argument_undeclared_type() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+
+                    fn a (bar: Bar);
+                    fn b (bar: &Bar);
+                    fn c (bar: &mut Bar);
+                    // Counts as two errors.
+                    fn d (multiple: Bar, args: Bar);
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 5);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::UndeclaredType { ty } => {
+                    let ty_name = ty.to_token_stream().to_string();
+                    // "& Bar" -> "Bar"
+                    let ty_name = ty_name.split_whitespace().last().unwrap();
+
+                    assert_eq!(ty_name, "Bar");
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that a freestanding function can return a declared type.
+    #[test]
+    fn freestanding_function_return_declared_type() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Rust" {
+                    type Bar;
+
+                    fn a () -> Bar;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+        assert_eq!(module.functions.len(), 1);
+    }
+
+    /// Verify that if a freestanding function returns a type that was not declared in the module
+    /// we return an error.
+    #[test]
+    fn freestanding_function_returns_undeclared_type() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+
+                    fn a () -> Bar;
+                    fn a (foo: &Foo) -> &Bar;
+                    fn a (foo: &mut Foo) -> &mut Bar;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::UndeclaredType { ty } => {
+                    let ty_name = ty.to_token_stream().to_string();
+                    // "& Bar" -> "Bar"
+                    let ty_name = ty_name.split_whitespace().last().unwrap();
+
+                    assert_eq!(ty_name, "Bar");
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that if an extern Rust block has more than one type, we push errors for any methods
+    /// that have an ambiguous self.
+    #[test]
+    fn error_if_method_has_ambiguous_self() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+                    type AnotherType;
+
+                    fn a (self);
+                    fn b (&self);
+                    fn c (&mut self);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 3);
+
+        for idx in 0..3 {
+            match &errors[idx] {
+                ParseError::AmbiguousSelf { self_: _ } => {}
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that annotated self methods get parsed.
+    #[test]
+    fn disambiguate_method() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+                    type AnotherType;
+
+                    fn a (self: SomeType);
+                    fn b (self: &SomeType);
+                    fn c (self: &mut AnotherType);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let functions = &module.functions;
+
+        for (ty_name, expected_count) in vec![("SomeType", 2), ("AnotherType", 1)] {
+            assert_eq!(
+                functions
+                    .iter()
+                    .filter(|f| f.associated_type.as_ref().unwrap().unwrap_opaque().ty == ty_name)
+                    .count(),
+                expected_count
+            );
+        }
+    }
+
+    /// Verify that if we have multiple externs types can be inferred within each.
+    #[test]
+    fn infer_type_with_multiple_externs() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn a (&self);
+                }
+
+                extern "Rust" {
+                    type AnotherType;
+
+                    fn b (&self);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let functions = &module.functions;
+
+        for (ty_name, expected_count) in vec![("SomeType", 1), ("AnotherType", 1)] {
+            assert_eq!(
+                functions
+                    .iter()
+                    .filter(|f| f.associated_type.as_ref().unwrap().unwrap_opaque().ty == ty_name)
+                    .count(),
+                expected_count
+            );
+        }
+    }
+
+    /// Verify that we we do not get any parsing errors when we use a type that is declared in
+    /// an extern block that comes after the block that it is used in.
+    #[test]
+    fn type_declared_in_separate_extern_block_after_use() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a () -> AnotherType;
+                    fn b () -> Vec<AnotherType>;
+                    // TODO: (Dec 2021) Uncomment this when we support Option<OpaqueRustType>
+                    // fn c () -> Option<AnotherType>;
+                    fn d (arg: AnotherType);
+                }
+
+                extern "Rust" {
+                    type AnotherType;
+                }
+            }
+        };
+
+        assert_eq!(parse_errors(tokens).len(), 0,);
+    }
+
+    /// Verify that we can parse the `already_declared` attribute.
+    #[test]
+    fn parse_already_declared_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(already_declared)]
+                    type AnotherType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("AnotherType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .already_declared
+        );
+    }
+
+    /// Verify that we can parse the `swift_name` attribute on an opaque type.
+    #[test]
+    fn parse_opaque_type_swift_name_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(swift_name = "RenamedType")]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert_eq!(
+            ty.attributes.swift_name.as_ref().unwrap().value(),
+            "RenamedType"
+        );
+        assert_eq!(ty.swift_name_string(), "RenamedType");
+    }
+
+    /// Verify that we generate getter and setter functions for the fields listed in an opaque
+    /// type's `get(...)` and `set(...)` attributes.
+    #[test]
+    fn parse_opaque_type_field_accessor_attributes() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(get(name: &str, age: u8), set(age, nickname: String))]
+                    type Person;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let functions: Vec<(String, bool)> = module
+            .functions
+            .iter()
+            .map(|f| (f.sig.ident.to_string(), f.is_swift_property))
+            .collect();
+        assert_eq!(
+            functions,
+            vec![
+                ("name".to_string(), true),
+                ("age".to_string(), true),
+                ("set_age".to_string(), true),
+                ("set_nickname".to_string(), false),
+            ]
+        );
+
+        let name = &module.functions[0];
+        assert!(name
+            .get_field
+            .as_ref()
+            .unwrap()
+            .unwrap_direct()
+            .maybe_ref
+            .is_some());
+
+        let set_age = &module.functions[2];
+        assert_eq!(set_age.set_field.as_ref().unwrap().to_string(), "age");
+        assert!(set_age.self_mutability().is_some());
+    }
+
+    /// Verify that we push an error if we can't determine the type of an accessor's field.
+    #[test]
+    fn error_if_field_accessor_type_missing() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(get(name: &str, age), set(name, height))]
+                    type Person;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+        for error in errors.iter() {
+            assert!(matches!(error, ParseError::MissingFieldType { .. }));
+        }
+    }
+
+    /// Verify that we parse the `Sendable` attribute on an opaque Rust type and push an error if
+    /// it is used on an opaque Swift type.
+    #[test]
+    fn parse_opaque_type_sendable_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Sendable)]
+                    type SomeType;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert_eq!(ty.attributes.sendable, Some(SwiftSendable::Checked));
+
+        let tokens = quote! {
+            mod foo {
+                extern "Swift" {
+                    #[swift_bridge(Sendable)]
+                    type SomeType;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::SendableExternSwiftType { ty } => assert_eq!(ty, "SomeType"),
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we parse the `actor` attribute on an opaque Swift type, that its methods become
+    /// `async`, and that we push an error if it is used on an opaque Rust type.
+    #[test]
+    fn parse_opaque_type_actor_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Swift" {
+                    #[swift_bridge(actor)]
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new() -> SomeType;
+                    fn method(&self);
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert!(ty.attributes.actor);
+        assert!(module.functions[0].sig.asyncness.is_none());
+        assert!(module.functions[1].sig.asyncness.is_some());
+
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(actor)]
+                    type SomeType;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::ActorExternRustType { ty } => assert_eq!(ty, "SomeType"),
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we parse the `drop_on` attribute on an opaque Rust type and push an error if
+    /// it is used on a type whose Swift class does not free a Rust value.
+    #[test]
+    fn parse_opaque_type_drop_on_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(drop_on = "main")]
+                    type SomeType;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert_eq!(ty.attributes.drop_on.as_ref().unwrap().value(), "main");
+
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4), drop_on = "main")]
+                    type CopyType;
+                    #[swift_bridge(drop_on = "main")]
+                    type GenericType<T>;
+                }
 
-                    assert_eq!(ty_name, "Bar");
+                extern "Swift" {
+                    #[swift_bridge(drop_on = "main")]
+                    type SwiftType;
                 }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+        for (error, expected_ty) in errors.iter().zip(["CopyType", "GenericType", "SwiftType"]) {
+            match error {
+                ParseError::UnsupportedDropOn { ty } => assert_eq!(ty, expected_ty),
                 _ => panic!(),
             }
         }
     }
 
-    /// Verify that a freestanding function can return a declared type.
+    /// Verify that we parse the `observable` attribute on an opaque Rust type and push an error
+    /// if it is used on a type that doesn't get a generated Swift class.
     #[test]
-    fn freestanding_function_return_declared_type() {
+    fn parse_opaque_type_observable_attribute() {
         let tokens = quote! {
-            #[swift_bridge::bridge]
             mod foo {
                 extern "Rust" {
-                    type Bar;
-
-                    fn a () -> Bar;
+                    #[swift_bridge(observable)]
+                    type SomeType;
                 }
             }
         };
         let module = parse_ok(tokens);
-        assert_eq!(module.functions.len(), 1);
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert!(ty.attributes.observable);
+
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4), observable)]
+                    type CopyType;
+                    #[swift_bridge(already_declared, observable)]
+                    type DeclaredType;
+                    #[swift_bridge(observable)]
+                    type GenericType<T>;
+                }
+
+                extern "Swift" {
+                    #[swift_bridge(observable)]
+                    type SwiftType;
+                }
+            }
+        };
+        let mut error_tys: Vec<String> = parse_errors(tokens)
+            .iter()
+            .map(|error| match error {
+                ParseError::UnsupportedObservable { ty } => ty.to_string(),
+                _ => panic!(),
+            })
+            .collect();
+        error_tys.sort();
+        assert_eq!(
+            error_tys,
+            vec!["CopyType", "DeclaredType", "GenericType", "SwiftType"]
+        );
     }
 
-    /// Verify that if a freestanding function returns a type that was not declared in the module
-    /// we return an error.
+    /// Verify that we parse the `Identifiable` attribute on an opaque type and push an error if
+    /// the type does not have an `id` property.
     #[test]
-    fn freestanding_function_returns_undeclared_type() {
+    fn parse_opaque_type_identifiable_attribute() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    type Foo;
-
-                    fn a () -> Bar;
-                    fn a () -> &Bar;
-                    fn a () -> &mut Bar;
+                    #[swift_bridge(Identifiable, get(id: u64))]
+                    type SomeType;
                 }
             }
         };
-        let errors = parse_errors(tokens);
-        assert_eq!(errors.len(), 3);
+        let module = parse_ok(tokens);
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert!(ty.attributes.identifiable);
 
-        for error in errors.iter() {
-            match error {
-                ParseError::UndeclaredType { ty } => {
-                    let ty_name = ty.to_token_stream().to_string();
-                    // "& Bar" -> "Bar"
-                    let ty_name = ty_name.split_whitespace().last().unwrap();
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Identifiable, get(name: &str))]
+                    type SomeType;
 
-                    assert_eq!(ty_name, "Bar");
+                    #[swift_bridge(Identifiable, Copy(4), get(id: u32))]
+                    type SomeCopyType;
                 }
-                _ => panic!(),
             }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+        for error in errors.iter() {
+            assert!(matches!(error, ParseError::IdentifiableMissingId { .. }));
         }
     }
 
-    /// Verify that if an extern Rust block has more than one type, we push errors for any methods
-    /// that have an ambiguous self.
+    //Verify that we can parse the `hashable` attribute.
     #[test]
-    fn error_if_method_has_ambiguous_self() {
+    fn parse_hashable_attribute() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
+                    #[swift_bridge(Hashable)]
                     type SomeType;
-                    type AnotherType;
-
-                    fn a (self);
-                    fn b (&self);
-                    fn c (&mut self);
                 }
             }
         };
 
-        let errors = parse_errors(tokens);
-
-        assert_eq!(errors.len(), 3);
+        let module = parse_ok(tokens);
 
-        for idx in 0..3 {
-            match &errors[idx] {
-                ParseError::AmbiguousSelf { self_: _ } => {}
-                _ => panic!(),
-            };
-        }
+        assert_eq!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .hashable,
+            true
+        );
     }
 
-    /// Verify that annotated self methods get parsed.
+    /// Verify that the `Hashable` attribute implies `Equatable`, since Swift's `Hashable`
+    /// protocol inherits from `Equatable`.
     #[test]
-    fn disambiguate_method() {
+    fn hashable_attribute_implies_equatable() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
+                    #[swift_bridge(Hashable)]
                     type SomeType;
-                    type AnotherType;
-
-                    fn a (self: SomeType);
-                    fn b (self: &SomeType);
-                    fn c (self: &mut AnotherType);
                 }
             }
         };
 
         let module = parse_ok(tokens);
 
-        let functions = &module.functions;
-
-        for (ty_name, expected_count) in vec![("SomeType", 2), ("AnotherType", 1)] {
-            assert_eq!(
-                functions
-                    .iter()
-                    .filter(|f| f.associated_type.as_ref().unwrap().unwrap_opaque().ty == ty_name)
-                    .count(),
-                expected_count
-            );
-        }
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert!(ty.attributes.hashable);
+        assert!(ty.attributes.equatable);
     }
 
-    /// Verify that if we have multiple externs types can be inferred within each.
+    /// Verify that we can parse the `Comparable` and `Add` attributes, and that `Comparable`
+    /// implies `Equatable` since Swift's `Comparable` protocol inherits from `Equatable`.
     #[test]
-    fn infer_type_with_multiple_externs() {
+    fn parse_comparable_and_add_attributes() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
+                    #[swift_bridge(Comparable, Add)]
                     type SomeType;
-
-                    fn a (&self);
-                }
-
-                extern "Rust" {
-                    type AnotherType;
-
-                    fn b (&self);
                 }
             }
         };
 
         let module = parse_ok(tokens);
 
-        let functions = &module.functions;
-
-        for (ty_name, expected_count) in vec![("SomeType", 1), ("AnotherType", 1)] {
-            assert_eq!(
-                functions
-                    .iter()
-                    .filter(|f| f.associated_type.as_ref().unwrap().unwrap_opaque().ty == ty_name)
-                    .count(),
-                expected_count
-            );
-        }
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert!(ty.attributes.comparable);
+        assert!(ty.attributes.equatable);
+        assert!(ty.attributes.add);
     }
 
-    /// Verify that we we do not get any parsing errors when we use a type that is declared in
-    /// an extern block that comes after the block that it is used in.
+    /// Verify that we can parse the `Default` attribute.
     #[test]
-    fn type_declared_in_separate_extern_block_after_use() {
+    fn parse_default_attribute() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    fn a () -> AnotherType;
-                    fn b () -> Vec<AnotherType>;
-                    // TODO: (Dec 2021) Uncomment this when we support Option<OpaqueRustType>
-                    // fn c () -> Option<AnotherType>;
-                    fn d (arg: AnotherType);
-                }
-
-                extern "Rust" {
-                    type AnotherType;
+                    #[swift_bridge(Default)]
+                    type SomeType;
                 }
             }
         };
 
-        assert_eq!(parse_errors(tokens).len(), 0,);
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert!(ty.attributes.default);
     }
 
-    /// Verify that we can parse the `already_declared` attribute.
+    /// Verify that we can parse the `equatable` attribute.
     #[test]
-    fn parse_already_declared_attribute() {
+    fn parse_equatable_attribute() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    #[swift_bridge(already_declared)]
-                    type AnotherType;
+                    #[swift_bridge(Equatable)]
+                    type SomeType;
                 }
             }
         };
 
         let module = parse_ok(tokens);
 
-        assert!(
+        assert_eq!(
             module
                 .types
-                .get("AnotherType")
+                .get("SomeType")
                 .unwrap()
                 .unwrap_opaque()
                 .attributes
-                .already_declared
+                .equatable,
+            true
         );
     }
 
-    //Verify that we can parse the `hashable` attribute.
+    /// Verify that we can parse the `available` attribute on an opaque type.
     #[test]
-    fn parse_hashable_attribute() {
+    fn parse_available_attribute() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    #[swift_bridge(Hashable)]
+                    #[swift_bridge(available = "iOS 16.0")]
                     type SomeType;
                 }
             }
@@ -803,25 +2483,20 @@ mod tests {
 
         let module = parse_ok(tokens);
 
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
         assert_eq!(
-            module
-                .types
-                .get("SomeType")
-                .unwrap()
-                .unwrap_opaque()
-                .attributes
-                .hashable,
-            true
+            ty.attributes.available.as_ref().unwrap().value(),
+            "iOS 16.0"
         );
     }
 
-    /// Verify that we can parse the `equatable` attribute.
+    /// Verify that we can parse the `swift_access` attribute on an opaque type.
     #[test]
-    fn parse_equatable_attribute() {
+    fn parse_swift_access_attribute() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    #[swift_bridge(Equatable)]
+                    #[swift_bridge(swift_access = "fileprivate")]
                     type SomeType;
                 }
             }
@@ -829,15 +2504,61 @@ mod tests {
 
         let module = parse_ok(tokens);
 
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
         assert_eq!(
+            ty.attributes.swift_access.as_ref().unwrap().value(),
+            "fileprivate"
+        );
+    }
+
+    /// Verify that we can parse the `Debug` and `Display` attributes.
+    #[test]
+    fn parse_debug_and_display_attributes() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Debug)]
+                    type SomeType;
+
+                    #[swift_bridge(Debug, Display)]
+                    type AnotherType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert!(ty.attributes.debug);
+        assert!(!ty.attributes.display);
+
+        let ty = module.types.get("AnotherType").unwrap().unwrap_opaque();
+        assert!(ty.attributes.debug);
+        assert!(ty.attributes.display);
+    }
+
+    /// Verify that we can parse the `protocol` attribute.
+    #[test]
+    fn parse_protocol_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Swift" {
+                    #[swift_bridge(protocol)]
+                    type SomeProtocol;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
             module
                 .types
-                .get("SomeType")
+                .get("SomeProtocol")
                 .unwrap()
                 .unwrap_opaque()
                 .attributes
-                .equatable,
-            true
+                .protocol
         );
     }
 
@@ -916,6 +2637,79 @@ mod tests {
         );
     }
 
+    /// Verify that we join the lines of a multi-line doc comment.
+    #[test]
+    fn parse_multi_line_doc_comment() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    /// First line
+                    /// Second line
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert_eq!(
+            ty.attributes.doc_comment.as_deref(),
+            Some(" First line\n Second line")
+        );
+    }
+
+    /// Verify that we can parse a doc comment from a function.
+    #[test]
+    fn parse_function_doc_comment() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    /// Some comment
+                    #[swift_bridge(swift_name = "someFunction")]
+                    fn some_function();
+
+                    fn another_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module.functions[0].doc_comment.as_deref(),
+            Some(" Some comment")
+        );
+        assert!(module.functions[1].doc_comment.is_none());
+    }
+
+    /// Verify that we parse the item type of a stream that an extern "Rust" function returns.
+    #[test]
+    fn parse_function_returns_stream() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a() -> impl Stream<Item = u8>;
+                    #[swift_bridge(publisher)]
+                    fn b() -> impl Stream<Item = u8>;
+                    fn c() -> u8;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        let functions = &module.functions;
+
+        assert_eq!(functions[0].stream_item.to_token_stream().to_string(), "u8");
+        assert!(!functions[0].publisher);
+        assert!(matches!(functions[0].sig.output, syn::ReturnType::Default));
+
+        assert_eq!(functions[1].stream_item.to_token_stream().to_string(), "u8");
+        assert!(functions[1].publisher);
+
+        assert!(functions[2].stream_item.is_none());
+    }
+
     /// Verify that we push errors for unknown arguments in a function
     #[test]
     fn error_args_into_arg_not_found_in_function() {
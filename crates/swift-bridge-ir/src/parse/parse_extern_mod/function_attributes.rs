@@ -1,19 +1,33 @@
+use crate::parse::parse_swift_access;
 use crate::parsed_extern_fn::{GetField, GetFieldDirect, GetFieldWith};
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
-use syn::{LitStr, Path, Token};
+use syn::{LitStr, Path, Token, Type};
 
 #[derive(Default)]
 pub(super) struct FunctionAttributes {
     pub associated_to: Option<Ident>,
     pub is_swift_initializer: bool,
     pub is_swift_identifiable: bool,
+    pub is_swift_subscript: bool,
+    pub is_swift_property: bool,
     pub rust_name: Option<LitStr>,
     pub swift_name: Option<LitStr>,
     pub return_into: bool,
     pub return_with: Option<Path>,
     pub args_into: Option<Vec<Ident>>,
     pub get_field: Option<GetField>,
+    pub set_field: Option<Ident>,
+    pub instantiate: Vec<Type>,
+    pub available: Option<LitStr>,
+    pub swift_access: Option<LitStr>,
+    pub unchecked_utf8: bool,
+    pub unsafe_pointer: bool,
+    pub return_scratch: bool,
+    pub catch_errors: bool,
+    pub publisher: bool,
+    pub main_actor: bool,
+    pub dispatch_queue: Option<LitStr>,
 }
 
 impl FunctionAttributes {
@@ -39,10 +53,23 @@ impl FunctionAttributes {
             FunctionAttr::Identifiable => {
                 self.is_swift_identifiable = true;
             }
+            FunctionAttr::Subscript => self.is_swift_subscript = true,
+            FunctionAttr::SwiftProperty => self.is_swift_property = true,
             FunctionAttr::GetField(get_field) => self.get_field = Some(GetField::Direct(get_field)),
             FunctionAttr::GetFieldWith(get_field) => {
                 self.get_field = Some(GetField::With(get_field))
             }
+            FunctionAttr::SetField(field) => self.set_field = Some(field),
+            FunctionAttr::Instantiate(types) => self.instantiate = types,
+            FunctionAttr::Available(available) => self.available = Some(available),
+            FunctionAttr::SwiftAccess(access) => self.swift_access = Some(access),
+            FunctionAttr::UncheckedUtf8 => self.unchecked_utf8 = true,
+            FunctionAttr::UnsafePointer => self.unsafe_pointer = true,
+            FunctionAttr::ReturnScratch => self.return_scratch = true,
+            FunctionAttr::CatchErrors => self.catch_errors = true,
+            FunctionAttr::Publisher => self.publisher = true,
+            FunctionAttr::MainActor => self.main_actor = true,
+            FunctionAttr::DispatchQueue(queue) => self.dispatch_queue = Some(queue),
         }
     }
 }
@@ -53,11 +80,24 @@ pub(super) enum FunctionAttr {
     RustName(LitStr),
     Init,
     Identifiable,
+    Subscript,
+    SwiftProperty,
     ReturnInto,
     ReturnWith(Path),
     ArgsInto(Vec<Ident>),
     GetField(GetFieldDirect),
     GetFieldWith(GetFieldWith),
+    SetField(Ident),
+    Instantiate(Vec<Type>),
+    Available(LitStr),
+    SwiftAccess(LitStr),
+    UncheckedUtf8,
+    UnsafePointer,
+    ReturnScratch,
+    CatchErrors,
+    Publisher,
+    MainActor,
+    DispatchQueue(LitStr),
 }
 
 impl Parse for FunctionAttributes {
@@ -94,11 +134,31 @@ impl Parse for FunctionAttr {
                 FunctionAttr::SwiftName(value)
             }
             "init" => FunctionAttr::Init,
+            // swift_access = "internal"
+            "swift_access" => FunctionAttr::SwiftAccess(parse_swift_access(input)?),
+            // available = "iOS 16.0, macOS 13.0"
+            "available" => {
+                input.parse::<Token![=]>()?;
+                FunctionAttr::Available(input.parse()?)
+            }
+            "catch_errors" => FunctionAttr::CatchErrors,
+            // dispatch_queue = "Database.queue"
+            "dispatch_queue" => {
+                input.parse::<Token![=]>()?;
+                FunctionAttr::DispatchQueue(input.parse()?)
+            }
             "Identifiable" => FunctionAttr::Identifiable,
+            "main_actor" => FunctionAttr::MainActor,
+            "publisher" => FunctionAttr::Publisher,
+            "subscript" => FunctionAttr::Subscript,
+            "swift_property" => FunctionAttr::SwiftProperty,
+            "unchecked_utf8" => FunctionAttr::UncheckedUtf8,
+            "unsafe_pointer" => FunctionAttr::UnsafePointer,
             // TODO: Right before we release 0.2.0 we should remove this
             //  "into_return_type" variant since it is deprecated.
             //
             "return_into" | "into_return_type" => FunctionAttr::ReturnInto,
+            "return_scratch" => FunctionAttr::ReturnScratch,
             "return_with" => {
                 input.parse::<Token![=]>()?;
                 FunctionAttr::ReturnWith(input.parse()?)
@@ -149,6 +209,20 @@ impl Parse for FunctionAttr {
                     path,
                 })
             }
+            "set" => {
+                let content;
+                syn::parenthesized!(content in input);
+
+                FunctionAttr::SetField(content.parse()?)
+            }
+            "instantiate" => {
+                let content;
+                syn::parenthesized!(content in input);
+
+                let types =
+                    syn::punctuated::Punctuated::<_, Token![,]>::parse_terminated(&content)?;
+                FunctionAttr::Instantiate(types.into_iter().collect())
+            }
             _ => {
                 let attrib = key.to_string();
                 Err(syn::Error::new_spanned(
@@ -164,9 +238,15 @@ impl Parse for FunctionAttr {
 
 #[cfg(test)]
 mod tests {
-    use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError};
+    use crate::errors::{
+        CatchErrorsParseError, DispatchQueueParseError, FunctionAttributeParseError,
+        IdentifiableParseError, InstantiateParseError, MainActorParseError, ParseError,
+        PublisherParseError, ReturnScratchParseError, SubscriptParseError, SwiftPropertyParseError,
+        UncheckedUtf8ParseError, UnsafePointerParseError,
+    };
     use crate::test_utils::{parse_errors, parse_ok};
     use quote::{quote, ToTokens};
+    use syn::ReturnType;
 
     /// Verify that we can parse the return_into attribute from extern "Rust" blocks.
     #[test]
@@ -534,4 +614,665 @@ mod tests {
         assert_eq!(func.args_into.as_ref().unwrap().len(), 1);
         assert_eq!(func.return_into, true);
     }
+
+    /// Verify that we can parse the `available` attribute.
+    #[test]
+    fn parses_available_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(available = "iOS 16.0, macOS 13.0")]
+                    fn some_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let func = &module.functions[0];
+        assert_eq!(
+            func.available.as_ref().unwrap().value(),
+            "iOS 16.0, macOS 13.0"
+        );
+    }
+
+    /// Verify that we can parse the `unchecked_utf8` attribute, and that we push an error if it is
+    /// used on an extern "Swift" function.
+    #[test]
+    fn parses_unchecked_utf8_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(unchecked_utf8)]
+                    fn some_function(arg: &str);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        assert!(module.functions[0].unchecked_utf8);
+
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(unchecked_utf8)]
+                    fn some_function(arg: &str);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ParseError::FunctionAttribute(FunctionAttributeParseError::UncheckedUtf8(
+                UncheckedUtf8ParseError::ExternSwiftFunction { fn_ident }
+            )) if fn_ident == "some_function"
+        ));
+    }
+
+    /// Verify that functions that use raw pointers parse when they have the `unsafe_pointer`
+    /// attribute, and that we push an error for the first raw pointer type of each function that
+    /// doesn't have it.
+    #[test]
+    fn parses_unsafe_pointer_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(unsafe_pointer)]
+                    fn a(arg: *const c_void) -> *mut u8;
+                }
+                extern "Swift" {
+                    #[swift_bridge(unsafe_pointer)]
+                    fn b(arg: *mut std::ffi::c_void);
+                }
+            }
+        };
+        parse_ok(tokens);
+
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Foo;
+
+                    fn a(arg: u8, ptr: *const c_void);
+                    fn b(&self) -> *mut u8;
+                    fn c(callback: Box<dyn FnOnce(*const u8)>);
+                    fn d(arg: u8) -> u8;
+                }
+                extern "Swift" {
+                    fn e(arg: *mut u8) -> *const u8;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+        let expected = [
+            ("a", "* const c_void"),
+            ("b", "* mut u8"),
+            ("c", "Box < dyn FnOnce (* const u8) >"),
+            ("e", "* mut u8"),
+        ];
+        for (error, (expected_fn, expected_ty)) in errors.iter().zip(expected) {
+            match error {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::UnsafePointer(
+                    UnsafePointerParseError::MissingAttribute { fn_ident, ty },
+                )) => {
+                    assert_eq!(fn_ident, expected_fn);
+                    assert_eq!(ty.to_token_stream().to_string(), expected_ty);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we can parse the `return_scratch` attribute, and that we push an error if the
+    /// function does not return `&str`.
+    #[test]
+    fn parses_return_scratch_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(return_scratch)]
+                    fn some_function() -> &str;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        assert!(module.functions[0].return_scratch);
+
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(return_scratch)]
+                    fn some_function() -> String;
+                }
+                extern "Swift" {
+                    #[swift_bridge(return_scratch)]
+                    fn another_function() -> &str;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(
+            &errors[0],
+            ParseError::FunctionAttribute(FunctionAttributeParseError::ReturnScratch(
+                ReturnScratchParseError::InvalidSignature { fn_ident }
+            )) if fn_ident == "some_function"
+        ));
+        assert!(matches!(
+            &errors[1],
+            ParseError::FunctionAttribute(FunctionAttributeParseError::ReturnScratch(
+                ReturnScratchParseError::ExternSwiftFunction { fn_ident }
+            )) if fn_ident == "another_function"
+        ));
+    }
+
+    /// Verify that we can parse the `catch_errors` attribute, and that we push an error if the
+    /// function is implemented in Rust or does not return a supported `Result`.
+    #[test]
+    fn parses_catch_errors_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    type SomeType;
+
+                    #[swift_bridge(catch_errors)]
+                    fn a() -> Result<(), String>;
+                    #[swift_bridge(catch_errors)]
+                    fn b() -> Result<String, String>;
+                    #[swift_bridge(catch_errors)]
+                    fn c() -> Result<SomeType, String>;
+                    #[swift_bridge(catch_errors)]
+                    fn d() -> Result<String, NsError>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        assert!(module.functions.iter().all(|func| func.catch_errors));
+
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(catch_errors)]
+                    fn a() -> Result<(), String>;
+                }
+                extern "Swift" {
+                    #[swift_bridge(catch_errors)]
+                    fn b() -> String;
+                    #[swift_bridge(catch_errors)]
+                    fn c() -> Result<u8, String>;
+                    #[swift_bridge(catch_errors)]
+                    async fn d() -> Result<(), String>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+        assert!(matches!(
+            &errors[0],
+            ParseError::FunctionAttribute(FunctionAttributeParseError::CatchErrors(
+                CatchErrorsParseError::ExternRustFunction { fn_ident }
+            )) if fn_ident == "a"
+        ));
+        for (error, expected) in errors[1..].iter().zip(["b", "c", "d"]) {
+            assert!(matches!(
+                error,
+                ParseError::FunctionAttribute(FunctionAttributeParseError::CatchErrors(
+                    CatchErrorsParseError::InvalidSignature { fn_ident }
+                )) if fn_ident == expected
+            ));
+        }
+    }
+
+    /// Verify that we can parse the `publisher` attribute, and that we push an error if the
+    /// function is implemented in Swift or does not return an `impl Stream<Item = T>`.
+    #[test]
+    fn parses_publisher_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(publisher)]
+                    fn a() -> impl Stream<Item = u8>;
+                    #[swift_bridge(publisher)]
+                    fn b() -> impl futures::Stream<Item = Result<String, String>> + Send;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        let items: Vec<String> = module
+            .functions
+            .iter()
+            .map(|func| func.stream_item.to_token_stream().to_string())
+            .collect();
+        assert_eq!(items, ["u8", "Result < String , String >"]);
+        assert!(module
+            .functions
+            .iter()
+            .all(|func| matches!(func.sig.output, ReturnType::Default)));
+
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(publisher)]
+                    fn a() -> impl Stream<Item = u8>;
+                }
+                extern "Rust" {
+                    #[swift_bridge(publisher)]
+                    fn b() -> Vec<u8>;
+                    #[swift_bridge(publisher)]
+                    fn c() -> impl Iterator<Item = u8>;
+                    #[swift_bridge(publisher)]
+                    async fn d() -> impl Stream<Item = u8>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+        assert!(matches!(
+            &errors[0],
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Publisher(
+                PublisherParseError::ExternSwiftFunction { fn_ident }
+            )) if fn_ident == "a"
+        ));
+        for (error, expected) in errors[1..].iter().zip(["b", "c", "d"]) {
+            assert!(matches!(
+                error,
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Publisher(
+                    PublisherParseError::InvalidSignature { fn_ident }
+                )) if fn_ident == expected
+            ));
+        }
+    }
+
+    /// Verify that we can parse the `main_actor` attribute, and that we push an error if it is
+    /// used on an extern "Rust" function.
+    #[test]
+    fn parses_main_actor_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(main_actor)]
+                    fn some_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        assert!(module.functions[0].main_actor);
+
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(main_actor)]
+                    fn some_function();
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ParseError::FunctionAttribute(FunctionAttributeParseError::MainActor(
+                MainActorParseError::ExternRustFunction { fn_ident }
+            )) if fn_ident == "some_function"
+        ));
+    }
+
+    /// Verify that we can parse the `dispatch_queue` attribute on functions in either direction.
+    #[test]
+    fn parses_dispatch_queue_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(dispatch_queue = "Database.queue")]
+                    fn some_function();
+                }
+
+                extern "Swift" {
+                    #[swift_bridge(dispatch_queue = "Session.queue")]
+                    fn another_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        let queues: Vec<String> = module
+            .functions
+            .iter()
+            .map(|f| f.dispatch_queue.as_ref().unwrap().value())
+            .collect();
+        assert_eq!(queues, vec!["Database.queue", "Session.queue"]);
+    }
+
+    /// Verify that we push errors for `dispatch_queue` functions that can't be called on a queue.
+    #[test]
+    fn error_if_dispatch_queue_fn_is_not_supported() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Database;
+
+                    #[swift_bridge(init, dispatch_queue = "Database.queue")]
+                    fn new() -> Database;
+
+                    #[swift_bridge(dispatch_queue = "Database.queue")]
+                    async fn load(&self);
+
+                    #[swift_bridge(dispatch_queue = "Database.queue")]
+                    fn rows(&self) -> impl Stream<Item = u8>;
+                }
+
+                extern "Swift" {
+                    #[swift_bridge(main_actor, dispatch_queue = "Session.queue")]
+                    fn start();
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+
+        for (error, expected_fn) in errors[0..3].iter().zip(["new", "load", "rows"]) {
+            assert!(matches!(
+                error,
+                ParseError::FunctionAttribute(FunctionAttributeParseError::DispatchQueue(
+                    DispatchQueueParseError::UnsupportedFunction { fn_ident }
+                )) if fn_ident == expected_fn
+            ));
+        }
+        assert!(matches!(
+            &errors[3],
+            ParseError::FunctionAttribute(FunctionAttributeParseError::DispatchQueue(
+                DispatchQueueParseError::WithMainActor { fn_ident }
+            )) if fn_ident == "start"
+        ));
+    }
+
+    /// Verify that we can parse the `swift_access` attribute, and that we reject unsupported
+    /// access levels.
+    #[test]
+    fn parses_swift_access_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_access = "internal")]
+                    fn some_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        assert_eq!(
+            module.functions[0].swift_access.as_ref().unwrap().value(),
+            "internal"
+        );
+
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_access = "private")]
+                    fn some_function();
+                }
+            }
+        };
+        assert!(syn::parse2::<crate::SwiftBridgeModule>(tokens).is_err());
+    }
+
+    /// Verify that we parse one function per type in the instantiate attribute, with the generic
+    /// type parameter replaced by that type.
+    #[test]
+    fn parses_instantiate_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(instantiate(u32, String))]
+                    fn some_function<T>(arg: T) -> Vec<T>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        assert_eq!(module.functions.len(), 2);
+
+        for (func, expected) in module
+            .functions
+            .iter()
+            .zip([quote! { u32 }, quote! { String }])
+        {
+            let instantiation = func.instantiation.as_ref().unwrap();
+            assert_eq!(
+                instantiation.ty.to_token_stream().to_string(),
+                expected.to_string()
+            );
+
+            assert_eq!(
+                func.func.sig.to_token_stream().to_string(),
+                quote! { fn some_function(arg: #expected) -> Vec<#expected> }.to_string()
+            );
+        }
+    }
+
+    /// Verify that we push errors for generic functions that can't be instantiated.
+    #[test]
+    fn error_if_invalid_generic_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn a<T>(arg: T);
+
+                    #[swift_bridge(instantiate(u32))]
+                    fn b<T, U>(arg: T, arg2: U);
+
+                    #[swift_bridge(instantiate(u32))]
+                    fn c(arg: u32);
+                }
+                extern "Swift" {
+                    #[swift_bridge(instantiate(u32))]
+                    fn d<T>(arg: T);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+
+        assert!(matches!(
+            &errors[0],
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Instantiate(
+                InstantiateParseError::MissingInstantiations { fn_ident }
+            )) if fn_ident == "a"
+        ));
+        for (idx, expected) in ["b", "c"].into_iter().enumerate() {
+            assert!(matches!(
+                &errors[idx + 1],
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Instantiate(
+                    InstantiateParseError::RequiresOneTypeParam { fn_ident }
+                )) if fn_ident == expected
+            ));
+        }
+        assert!(matches!(
+            &errors[3],
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Instantiate(
+                InstantiateParseError::ExternSwiftFunction { fn_ident }
+            )) if fn_ident == "d"
+        ));
+    }
+
+    /// Verify that we can parse the subscript attribute.
+    #[test]
+    fn parse_subscript_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(subscript)]
+                    fn get(&self, index: usize) -> u8;
+
+                    #[swift_bridge(subscript)]
+                    fn set(&mut self, index: usize, value: u8);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        assert!(module.functions.iter().all(|func| func.is_swift_subscript));
+    }
+
+    /// Verify that we push errors for subscript methods that can't be exposed as a Swift
+    /// subscript.
+    #[test]
+    fn error_if_invalid_subscript() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(subscript)]
+                    fn no_index(&self) -> u8;
+
+                    #[swift_bridge(subscript)]
+                    fn no_value(&mut self, index: usize);
+
+                    #[swift_bridge(subscript)]
+                    fn no_getter(&mut self, key: &str, value: u8);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        assert!(matches!(
+            &errors[0],
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Subscript(
+                SubscriptParseError::InvalidGetter { fn_ident }
+            )) if fn_ident == "no_index"
+        ));
+        assert!(matches!(
+            &errors[1],
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Subscript(
+                SubscriptParseError::InvalidSetter { fn_ident }
+            )) if fn_ident == "no_value"
+        ));
+        assert!(matches!(
+            &errors[2],
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Subscript(
+                SubscriptParseError::MissingGetter { fn_ident }
+            )) if fn_ident == "no_getter"
+        ));
+    }
+
+    /// Verify that we can parse the swift_property attribute.
+    #[test]
+    fn parse_swift_property_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(swift_property)]
+                    fn name(&self) -> String;
+
+                    #[swift_bridge(swift_property)]
+                    fn set_name(&mut self, name: String);
+
+                    fn age(&self) -> u8;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let properties: Vec<(String, bool)> = module
+            .functions
+            .iter()
+            .map(|func| (func.swift_property_name(), func.is_swift_property))
+            .collect();
+        assert_eq!(
+            properties,
+            vec![
+                ("name".to_string(), true),
+                ("name".to_string(), true),
+                ("age".to_string(), false)
+            ]
+        );
+    }
+
+    /// Verify that we push errors for swift_property methods that can't be exposed as a Swift
+    /// computed property.
+    #[test]
+    fn error_if_invalid_swift_property() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(swift_property)]
+                    fn has_args(&self, arg: u8) -> u8;
+
+                    #[swift_bridge(swift_property)]
+                    fn set_has_return(&mut self, value: u8) -> u8;
+
+                    #[swift_bridge(swift_property)]
+                    fn set_no_getter(&mut self, value: u8);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        assert!(matches!(
+            &errors[0],
+            ParseError::FunctionAttribute(FunctionAttributeParseError::SwiftProperty(
+                SwiftPropertyParseError::InvalidGetter { fn_ident }
+            )) if fn_ident == "has_args"
+        ));
+        assert!(matches!(
+            &errors[1],
+            ParseError::FunctionAttribute(FunctionAttributeParseError::SwiftProperty(
+                SwiftPropertyParseError::InvalidSetter { fn_ident }
+            )) if fn_ident == "set_has_return"
+        ));
+        assert!(matches!(
+            &errors[2],
+            ParseError::FunctionAttribute(FunctionAttributeParseError::SwiftProperty(
+                SwiftPropertyParseError::MissingGetter { fn_ident }
+            )) if fn_ident == "set_no_getter"
+        ));
+    }
 }
@@ -1,67 +1,129 @@
-use crate::parse::OpaqueCopy;
+use crate::bridge_module_attributes::CfgAttr;
+use crate::parse::{
+    parse_doc_comment, parse_sendable, parse_swift_access, OpaqueCopy, SwiftSendable,
+};
 use proc_macro2::Ident;
 use quote::ToTokens;
 use std::ops::Deref;
 use syn::parse::{Parse, ParseStream};
-use syn::{Attribute, LitInt, Meta};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, LitInt, LitStr, Token, Type};
 
 #[derive(Default, Clone)]
 pub(crate) struct OpaqueTypeAllAttributes {
     pub swift_bridge: OpaqueTypeSwiftBridgeAttributes,
     /// A doc comment.
-    // TODO: Use this to generate doc comment for the generated Swift type.
-    #[allow(unused)]
     pub doc_comment: Option<String>,
+    /// The `#[cfg(...)]` attributes on the type and on its `extern` block.
+    pub cfg_attrs: Vec<CfgAttr>,
 }
 
 #[derive(Default, Clone)]
 pub(crate) struct OpaqueTypeSwiftBridgeAttributes {
+    /// `#[swift_bridge(actor)]`
+    /// Used to declare that an opaque Swift type is an actor. Rust calls its methods through
+    /// futures that run on the actor.
+    pub actor: bool,
+    /// `#[swift_bridge(Add)]`
+    /// Used to determine if a Swift `+` operator needs to be implemented.
+    pub add: bool,
     /// Whether or not the `#[swift_bridge(already_declared)]` attribute was present on the type.
     /// If it was, we won't generate Swift and C type declarations for this type, since we
     /// will elsewhere.
     pub already_declared: bool,
+    /// `#[swift_bridge(available = "iOS 16.0")]`
+    /// The platforms that the generated Swift declarations are available on.
+    pub available: Option<LitStr>,
+    /// `#[swift_bridge(Comparable)]`
+    /// Used to determine if Comparable need to be implemented.
+    /// Implies `Equatable`.
+    pub comparable: bool,
     /// `#[swift_bridge(Copy(...)]`
     /// Describes the type's Copy semantics.
     pub copy: Option<OpaqueCopy>,
+    /// `#[swift_bridge(Debug)]`
+    /// Used to determine if CustomDebugStringConvertible need to be implemented.
+    pub debug: bool,
+    /// `#[swift_bridge(Default)]`
+    /// Used to determine if a parameterless Swift initializer needs to be implemented.
+    pub default: bool,
     /// `#[swift_bridge(declare_generic)]`
     /// Used to declare a generic type.
     pub declare_generic: bool,
+    /// `#[swift_bridge(Display)]`
+    /// Used to determine if CustomStringConvertible need to be implemented.
+    pub display: bool,
+    /// `#[swift_bridge(drop_on = "main")]`
+    /// The dispatch queue that the Swift class frees the Rust value on.
+    pub drop_on: Option<LitStr>,
     /// `#[swift_bridge(Equatable)]`
     /// Used to determine if Equatable need to be implemented.
     pub equatable: bool,
     /// `#[swift_bridge(Hashable)]`
     /// Used to determine if Hashable need to be implemented.
+    /// Implies `Equatable`.
     pub hashable: bool,
+    /// `#[swift_bridge(Identifiable)]`
+    /// Used to conform the Swift class to `Identifiable` using its `get(id: ...)` field.
+    pub identifiable: bool,
+    /// `#[swift_bridge(observable)]`
+    /// Used to generate a Swift `ObservableObject` that publishes the type's Swift properties.
+    pub observable: bool,
+    /// `#[swift_bridge(Sendable)]`
+    /// Used to conform the Swift class to `Sendable`. The Rust type must be `Send + Sync`.
+    pub sendable: Option<SwiftSendable>,
+    /// `#[swift_bridge(protocol)]`
+    /// Used to declare that an opaque Swift type is a protocol instead of a class.
+    pub protocol: bool,
+    /// `#[swift_bridge(swift_access = "internal")]`
+    /// The access level of the generated Swift declarations.
+    pub swift_access: Option<LitStr>,
+    /// `#[swift_bridge(swift_name = "SomeName")]`
+    /// The name that the type is known by on the Swift side.
+    pub swift_name: Option<LitStr>,
+    /// `#[swift_bridge(get(name: &str, age: u8))]`
+    /// Fields that get a generated getter.
+    pub get_fields: Vec<OpaqueTypeField>,
+    /// `#[swift_bridge(set(age))]` or `#[swift_bridge(set(age: u8))]`
+    /// Fields that get a generated setter.
+    pub set_fields: Vec<OpaqueTypeField>,
+}
+
+/// A field of an opaque Rust type that was listed in a `get(...)` or `set(...)` type attribute.
+#[derive(Clone)]
+pub(crate) struct OpaqueTypeField {
+    pub name: Ident,
+    /// `None` when the type was left off, as in `set(age)`.
+    pub ty: Option<Type>,
 }
 
 impl OpaqueTypeAllAttributes {
     pub(super) fn from_attributes(attribs: &[Attribute]) -> Result<Self, syn::Error> {
-        let mut attributes = OpaqueTypeAllAttributes::default();
+        let mut attributes = OpaqueTypeAllAttributes {
+            doc_comment: parse_doc_comment(attribs),
+            ..OpaqueTypeAllAttributes::default()
+        };
 
         for attr in attribs.iter() {
             let attribute_name = attr.path.to_token_stream().to_string();
 
             match attribute_name.as_str() {
-                "doc" => {
-                    let meta = attr.parse_meta()?;
-                    let doc = match meta {
-                        Meta::NameValue(name_val) => match name_val.lit {
-                            syn::Lit::Str(comment) => comment.value(),
-                            _ => {
-                                todo!("Push parse error that doc attribute is in incorrect format")
-                            }
-                        },
-                        _ => {
-                            todo!("Push parse error that doc attribute is in incorrect format")
-                        }
-                    };
-
-                    attributes.doc_comment = Some(doc);
+                // Collected by `parse_doc_comment`.
+                "doc" => {}
+                "cfg" => {
+                    attributes.cfg_attrs.push(syn::parse2(attr.tokens.clone())?);
                 }
                 "swift_bridge" => {
                     attributes.swift_bridge = attr.parse_args()?;
                 }
-                _ => todo!("Push unsupported attribute error."),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        &attr.path,
+                        format!(
+                            r#"Unsupported attribute "{attribute_name}" on an opaque type. Use `#[swift_bridge(...)]` attributes instead."#
+                        ),
+                    ))
+                }
             };
         }
 
@@ -72,21 +134,62 @@ impl OpaqueTypeAllAttributes {
 impl OpaqueTypeSwiftBridgeAttributes {
     pub(super) fn store_attrib(&mut self, attrib: OpaqueTypeAttr) {
         match attrib {
+            OpaqueTypeAttr::Actor => self.actor = true,
+            OpaqueTypeAttr::Add => self.add = true,
             OpaqueTypeAttr::AlreadyDeclared => self.already_declared = true,
+            OpaqueTypeAttr::Available(available) => self.available = Some(available),
+            OpaqueTypeAttr::Comparable => {
+                // Swift's `Comparable` protocol inherits from `Equatable`.
+                self.comparable = true;
+                self.equatable = true;
+            }
             OpaqueTypeAttr::Copy { size } => self.copy = Some(OpaqueCopy { size_bytes: size }),
+            OpaqueTypeAttr::Debug => self.debug = true,
+            OpaqueTypeAttr::Default => self.default = true,
             OpaqueTypeAttr::DeclareGeneric => self.declare_generic = true,
+            OpaqueTypeAttr::Display => self.display = true,
+            OpaqueTypeAttr::DropOn(queue) => self.drop_on = Some(queue),
             OpaqueTypeAttr::Equatable => self.equatable = true,
-            OpaqueTypeAttr::Hashable => self.hashable = true,
+            OpaqueTypeAttr::Hashable => {
+                // Swift's `Hashable` protocol inherits from `Equatable`, so a `Hashable` type
+                // also needs an `==` implementation.
+                self.hashable = true;
+                self.equatable = true;
+            }
+            OpaqueTypeAttr::Identifiable => self.identifiable = true,
+            OpaqueTypeAttr::Observable => self.observable = true,
+            OpaqueTypeAttr::Protocol => self.protocol = true,
+            OpaqueTypeAttr::Sendable(sendable) => self.sendable = Some(sendable),
+            OpaqueTypeAttr::SwiftAccess(access) => self.swift_access = Some(access),
+            OpaqueTypeAttr::SwiftName(name) => self.swift_name = Some(name),
+            OpaqueTypeAttr::Get(fields) => self.get_fields.extend(fields),
+            OpaqueTypeAttr::Set(fields) => self.set_fields.extend(fields),
         }
     }
 }
 
 pub(crate) enum OpaqueTypeAttr {
+    Actor,
+    Add,
     AlreadyDeclared,
+    Available(LitStr),
+    Comparable,
     Copy { size: usize },
+    Debug,
+    Default,
     DeclareGeneric,
+    Display,
+    DropOn(LitStr),
     Equatable,
     Hashable,
+    Identifiable,
+    Observable,
+    Protocol,
+    Sendable(SwiftSendable),
+    SwiftAccess(LitStr),
+    SwiftName(LitStr),
+    Get(Vec<OpaqueTypeField>),
+    Set(Vec<OpaqueTypeField>),
 }
 
 impl Parse for OpaqueTypeSwiftBridgeAttributes {
@@ -109,7 +212,14 @@ impl Parse for OpaqueTypeAttr {
         let key: Ident = input.parse()?;
 
         let attrib = match key.to_string().as_str() {
+            "actor" => OpaqueTypeAttr::Actor,
+            "Add" => OpaqueTypeAttr::Add,
             "already_declared" => OpaqueTypeAttr::AlreadyDeclared,
+            "available" => {
+                input.parse::<Token![=]>()?;
+                OpaqueTypeAttr::Available(input.parse()?)
+            }
+            "Comparable" => OpaqueTypeAttr::Comparable,
             // Copy(10)
             "Copy" => {
                 let content;
@@ -117,13 +227,46 @@ impl Parse for OpaqueTypeAttr {
 
                 let size: LitInt = content.parse()?;
                 OpaqueTypeAttr::Copy {
-                    // TODO: Return an error if the integer cannot be parsed into a usize.
-                    size: size.to_string().parse().unwrap(),
+                    size: size.base10_parse()?,
                 }
             }
+            "Debug" => OpaqueTypeAttr::Debug,
+            "Default" => OpaqueTypeAttr::Default,
             "declare_generic" => OpaqueTypeAttr::DeclareGeneric,
+            "Display" => OpaqueTypeAttr::Display,
+            "drop_on" => {
+                input.parse::<Token![=]>()?;
+                OpaqueTypeAttr::DropOn(input.parse()?)
+            }
             "Equatable" => OpaqueTypeAttr::Equatable,
             "Hashable" => OpaqueTypeAttr::Hashable,
+            "Identifiable" => OpaqueTypeAttr::Identifiable,
+            "observable" => OpaqueTypeAttr::Observable,
+            "protocol" => OpaqueTypeAttr::Protocol,
+            "Sendable" => OpaqueTypeAttr::Sendable(parse_sendable(input)?),
+            "swift_access" => OpaqueTypeAttr::SwiftAccess(parse_swift_access(input)?),
+            "swift_name" => {
+                input.parse::<Token![=]>()?;
+
+                let name = input.parse()?;
+                OpaqueTypeAttr::SwiftName(name)
+            }
+            // get(name: &str, age: u8)
+            "get" => {
+                let content;
+                syn::parenthesized!(content in input);
+
+                let fields = Punctuated::<OpaqueTypeField, Token![,]>::parse_terminated(&content)?;
+                OpaqueTypeAttr::Get(fields.into_iter().collect())
+            }
+            // set(age)
+            "set" => {
+                let content;
+                syn::parenthesized!(content in input);
+
+                let fields = Punctuated::<OpaqueTypeField, Token![,]>::parse_terminated(&content)?;
+                OpaqueTypeAttr::Set(fields.into_iter().collect())
+            }
             _ => {
                 let attrib = key.to_string();
                 Err(syn::Error::new_spanned(
@@ -137,6 +280,21 @@ impl Parse for OpaqueTypeAttr {
     }
 }
 
+impl Parse for OpaqueTypeField {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+
+        let ty = if input.peek(Token![:]) {
+            input.parse::<Token![:]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(OpaqueTypeField { name, ty })
+    }
+}
+
 impl Deref for OpaqueTypeAllAttributes {
     type Target = OpaqueTypeSwiftBridgeAttributes;
 
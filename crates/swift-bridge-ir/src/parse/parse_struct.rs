@@ -2,7 +2,9 @@ use crate::bridged_type::{
     shared_struct::StructDerives, SharedStruct, StructFields, StructSwiftRepr,
 };
 use crate::errors::{ParseError, ParseErrors};
-use crate::parse::move_input_cursor_to_next_comma;
+use crate::parse::{
+    move_input_cursor_to_next_comma, parse_doc_comment, parse_sendable, SwiftSendable,
+};
 use proc_macro2::Ident;
 use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
@@ -18,6 +20,8 @@ enum StructAttr {
     SwiftName(LitStr),
     Error(StructAttrParseError),
     AlreadyDeclared,
+    Identifiable,
+    Sendable(SwiftSendable),
 }
 
 enum StructAttrParseError {
@@ -31,6 +35,8 @@ struct StructAttribs {
     swift_name: Option<LitStr>,
     already_declared: bool,
     derives: StructDerives,
+    identifiable: bool,
+    sendable: Option<SwiftSendable>,
 }
 
 impl Default for StructDerives {
@@ -77,6 +83,8 @@ impl Parse for StructAttr {
                 StructAttr::SwiftName(name)
             }
             "already_declared" => StructAttr::AlreadyDeclared,
+            "Identifiable" => StructAttr::Identifiable,
+            "Sendable" => StructAttr::Sendable(parse_sendable(input)?),
             _ => {
                 move_input_cursor_to_next_comma(input);
                 StructAttr::Error(StructAttrParseError::UnrecognizedAttribute(key))
@@ -92,11 +100,18 @@ impl<'a> SharedStructDeclarationParser<'a> {
         let item_struct = self.item_struct;
 
         let mut attribs = StructAttribs::default();
+        let doc_comment = parse_doc_comment(&item_struct.attrs);
+        let mut cfg_attrs = vec![];
 
         for attr in item_struct.attrs {
             let attribute_name = attr.path.to_token_stream().to_string();
 
             match attribute_name.as_str() {
+                // Collected by `parse_doc_comment`.
+                "doc" => {}
+                "cfg" => {
+                    cfg_attrs.push(syn::parse2(attr.tokens)?);
+                }
                 "swift_bridge" => {
                     let sections: ParsedAttribs = attr.parse_args()?;
 
@@ -124,6 +139,12 @@ impl<'a> SharedStructDeclarationParser<'a> {
                             StructAttr::AlreadyDeclared => {
                                 attribs.already_declared = true;
                             }
+                            StructAttr::Identifiable => {
+                                attribs.identifiable = true;
+                            }
+                            StructAttr::Sendable(sendable) => {
+                                attribs.sendable = Some(sendable);
+                            }
                         };
                     }
                 }
@@ -137,14 +158,19 @@ impl<'a> SharedStructDeclarationParser<'a> {
                             }
                         }
                     }
-                    _ => todo!("Push parse error that derive attribute is in incorrect format"),
+                    meta => {
+                        return Err(syn::Error::new_spanned(
+                            meta,
+                            "Expected a list of derives, such as `#[derive(Copy, Clone)]`.",
+                        ))
+                    }
                 },
-                attr_name => {
-                    todo!(
-                        "Push unsupported attribute error. Found unsupported attribute \"{}\" on struct \"{}\". Consult the swift-bridge manual for supported struct attributes.",
-                        attr_name,
-                        item_struct.ident.to_string(),
-                    )
+                _ => {
+                    if let Some(attribute) = attr.path.segments.last() {
+                        self.errors.push(ParseError::StructUnrecognizedAttribute {
+                            attribute: attribute.ident.clone(),
+                        });
+                    }
                 }
             }
         }
@@ -170,13 +196,31 @@ impl<'a> SharedStructDeclarationParser<'a> {
             StructSwiftRepr::Structure
         };
 
+        let fields = StructFields::from_syn_fields(item_struct.fields);
+
+        if attribs.identifiable {
+            let has_id_field = match &fields {
+                StructFields::Named(named) => named.iter().any(|field| field.name == "id"),
+                _ => false,
+            };
+            if !has_id_field {
+                self.errors.push(ParseError::IdentifiableMissingId {
+                    ty: item_struct.ident.clone(),
+                });
+            }
+        }
+
         let shared_struct = SharedStruct {
             name: item_struct.ident,
             swift_repr,
-            fields: StructFields::from_syn_fields(item_struct.fields),
+            fields,
             swift_name: attribs.swift_name,
             already_declared: attribs.already_declared,
             derives: attribs.derives,
+            identifiable: attribs.identifiable,
+            sendable: attribs.sendable,
+            doc_comment,
+            cfg_attrs,
         };
 
         Ok(shared_struct)
@@ -429,6 +473,94 @@ mod tests {
         assert!(ty.already_declared);
     }
 
+    /// Verify that we can parse a doc comment from a struct.
+    #[test]
+    fn parse_struct_doc_comment() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                /// Some comment
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeType {
+                    field: u8,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert_eq!(ty.doc_comment.as_deref(), Some(" Some comment"));
+    }
+
+    /// Verify that we parse the `Sendable` and `Sendable(unchecked)` attributes.
+    #[test]
+    fn parses_struct_sendable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Sendable)]
+                struct Checked;
+
+                #[swift_bridge(swift_repr = "struct", Sendable(unchecked))]
+                struct Unchecked;
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let checked = module.types.get("Checked").unwrap().unwrap_shared_struct();
+        assert_eq!(checked.sendable, Some(SwiftSendable::Checked));
+        let unchecked = module
+            .types
+            .get("Unchecked")
+            .unwrap()
+            .unwrap_shared_struct();
+        assert_eq!(unchecked.sendable, Some(SwiftSendable::Unchecked));
+    }
+
+    /// Verify that we parse the `Identifiable` attribute.
+    #[test]
+    fn parses_struct_identifiable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Identifiable)]
+                struct SomeType {
+                    id: u32,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.identifiable);
+    }
+
+    /// Verify that we push an error if an `Identifiable` struct does not have an `id` field.
+    #[test]
+    fn error_if_identifiable_struct_missing_id_field() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Identifiable)]
+                struct SomeType {
+                    name: String,
+                }
+
+                #[swift_bridge(swift_repr = "struct", Identifiable)]
+                struct AnotherType(u32);
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+        for error in errors.iter() {
+            assert!(matches!(error, ParseError::IdentifiableMissingId { .. }));
+        }
+    }
+
     /// Verify that we return an error if an attribute isn't recognized.
     #[test]
     fn error_if_attribute_unrecognized() {
@@ -457,4 +589,28 @@ mod tests {
             _ => panic!(),
         };
     }
+
+    /// Verify that we push an error for unsupported attributes on a struct instead of
+    /// panicking.
+    #[test]
+    fn error_if_unsupported_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[serde(rename_all = "camelCase")]
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeType;
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::StructUnrecognizedAttribute { attribute } => {
+                assert_eq!(&attribute.to_string(), "serde");
+            }
+            _ => panic!(),
+        };
+    }
 }
@@ -0,0 +1,174 @@
+use crate::errors::{ParseError, ParseErrors};
+use crate::parse::parse_extern_mod::ForeignModParser;
+use crate::parse::{TypeDeclaration, TypeDeclarations};
+use crate::ParsedExternFn;
+use syn::{FnArg, ForeignItemFn, ItemForeignMod, ItemTrait, TraitItem, Type, Visibility};
+
+/// Parses a `trait SomeTrait { ... }` declaration inside of a bridge module.
+///
+/// The trait is bridged as an opaque Rust type that Rust holds as a `Box<dyn SomeTrait>`.
+/// Its methods are bridged the same way that methods in an `extern "Rust"` block are.
+pub(super) struct TraitDeclarationParser<'a> {
+    pub item_trait: ItemTrait,
+    pub errors: &'a mut ParseErrors,
+    pub type_declarations: &'a mut TypeDeclarations,
+    pub functions: &'a mut Vec<ParsedExternFn>,
+    pub unresolved_types: &'a mut Vec<Type>,
+}
+
+impl<'a> TraitDeclarationParser<'a> {
+    pub fn parse(self) -> syn::Result<()> {
+        let item_trait = self.item_trait;
+
+        if !item_trait.generics.params.is_empty() {
+            self.errors.push(ParseError::GenericTrait {
+                generics: item_trait.generics,
+            });
+            return Ok(());
+        }
+
+        let mut methods = vec![];
+        for item in item_trait.items {
+            let method = match item {
+                TraitItem::Method(method) if is_ref_self_method(&method.sig.inputs.first()) => {
+                    method
+                }
+                item => {
+                    self.errors.push(ParseError::InvalidTraitItem {
+                        item: Box::new(item),
+                    });
+                    continue;
+                }
+            };
+
+            methods.push(ForeignItemFn {
+                attrs: method.attrs,
+                vis: Visibility::Inherited,
+                sig: method.sig,
+                semi_token: Default::default(),
+            });
+        }
+
+        let trait_attrs = item_trait.attrs;
+        let trait_name = item_trait.ident;
+        let foreign_mod: ItemForeignMod = syn::parse_quote! {
+            extern "Rust" {
+                #(#trait_attrs)*
+                type #trait_name;
+
+                #(#methods)*
+            }
+        };
+
+        let first_method_idx = self.functions.len();
+        ForeignModParser {
+            errors: self.errors,
+            type_declarations: self.type_declarations,
+            functions: self.functions,
+            unresolved_types: self.unresolved_types,
+        }
+        .parse(foreign_mod)?;
+
+        if let Some(TypeDeclaration::Opaque(opaque)) =
+            self.type_declarations.get_mut(&trait_name.to_string())
+        {
+            opaque.trait_object = true;
+
+            let associated_type = TypeDeclaration::Opaque(opaque.clone());
+            for method in self.functions[first_method_idx..].iter_mut() {
+                method.associated_type = Some(associated_type.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether the method's first argument is `&self` or `&mut self`.
+///
+/// Trait methods that take `self` by value can not be called on a `Box<dyn SomeTrait>`.
+fn is_ref_self_method(first_arg: &Option<&FnArg>) -> bool {
+    match first_arg {
+        Some(FnArg::Receiver(receiver)) => receiver.reference.is_some(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{parse_errors, parse_ok};
+    use quote::quote;
+
+    /// Verify that a trait gets parsed into an opaque Rust trait object type along with its
+    /// methods.
+    #[test]
+    fn parse_trait() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Shape {
+                    fn area(&self) -> f64;
+                    fn scale(&mut self, factor: f64);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("Shape").unwrap().unwrap_opaque();
+        assert!(ty.host_lang.is_rust());
+        assert!(ty.trait_object);
+
+        assert_eq!(module.functions.len(), 2);
+        for func in module.functions.iter() {
+            assert!(func.host_lang.is_rust());
+            assert!(
+                func.associated_type
+                    .as_ref()
+                    .unwrap()
+                    .unwrap_opaque()
+                    .trait_object
+            );
+        }
+    }
+
+    /// Verify that we push errors for trait items that can't be called on a trait object.
+    #[test]
+    fn error_if_trait_item_is_not_a_ref_self_method() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Shape {
+                    const SIDES: u8;
+                    fn new() -> Self;
+                    fn consume(self);
+                    fn area(&self) -> f64;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+        for error in errors.iter() {
+            assert!(matches!(error, ParseError::InvalidTraitItem { .. }));
+        }
+    }
+
+    /// Verify that we push an error for generic traits.
+    #[test]
+    fn error_if_generic_trait() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Shape<T> {
+                    fn area(&self) -> T;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0], ParseError::GenericTrait { .. }));
+    }
+}
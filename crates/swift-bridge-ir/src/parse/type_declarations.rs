@@ -1,3 +1,4 @@
+use crate::bridge_module_attributes::CfgAttr;
 use crate::bridged_type::{
     BridgedType, CustomBridgedType, OpaqueForeignType, SharedEnum, SharedStruct, SharedType,
 };
@@ -8,7 +9,7 @@ use proc_macro2::{Ident, TokenStream};
 use quote::ToTokens;
 use std::collections::HashMap;
 use std::ops::Deref;
-use syn::{PatType, Type, TypePath};
+use syn::{LitStr, PatType, Type, TypePath};
 
 mod generics;
 pub(crate) use self::generics::*;
@@ -17,6 +18,7 @@ pub(crate) use self::generics::*;
 pub(crate) struct TypeDeclarations {
     decls: HashMap<String, TypeDeclaration>,
     order: Vec<String>,
+    module_name: String,
 }
 
 #[derive(Clone)]
@@ -54,11 +56,13 @@ impl TypeDeclaration {
         match self {
             TypeDeclaration::Opaque(opaque) => Some(OpaqueForeignType {
                 ty: opaque.ty.clone(),
+                swift_ty_name: opaque.swift_name_string(),
                 host_lang: opaque.host_lang,
                 reference,
                 mutable,
                 has_swift_bridge_copy_annotation: opaque.attributes.copy.is_some(),
                 generics: opaque.generics.clone(),
+                trait_object: opaque.trait_object,
             }),
             _ => None,
         }
@@ -71,20 +75,38 @@ pub(crate) struct OpaqueForeignTypeDeclaration {
     pub host_lang: HostLang,
     pub attributes: OpaqueTypeAllAttributes,
     pub generics: OpaqueRustTypeGenerics,
+    /// Whether this type was declared using a `trait SomeTrait { ... }` item, in which case
+    /// Rust owns it as a `Box<dyn SomeTrait>`.
+    pub trait_object: bool,
 }
 
 impl OpaqueForeignTypeDeclaration {
-    pub(crate) fn ffi_repr_type_tokens(&self) -> TokenStream {
+    pub(crate) fn ffi_repr_type_tokens(&self, types: &TypeDeclarations) -> TokenStream {
         if self.attributes.copy.is_some() {
             self.ffi_copy_repr_ident().to_token_stream()
         } else {
-            let ty_name = &self.ty;
+            let generics = self
+                .generics
+                .angle_bracketed_concrete_generics_tokens(types);
+            let ty = self.rust_type_tokens(&generics);
             quote::quote! {
-                *mut super::#ty_name
+                *mut #ty
             }
         }
     }
 
+    /// The Rust type that values of this opaque Rust type are stored as.
+    ///
+    /// `super::SomeType<u32>`, or `Box<dyn super::SomeTrait>` for a bridged trait.
+    pub(crate) fn rust_type_tokens(&self, generics: &TokenStream) -> TokenStream {
+        let ty_name = &self.ty;
+        if self.trait_object {
+            quote::quote! { Box<dyn super::#ty_name> }
+        } else {
+            quote::quote! { super::#ty_name #generics }
+        }
+    }
+
     /// The name of the FFI representation for an opaque Rust type.
     /// __swift_bridge__$SomeType
     pub(crate) fn ffi_repr_name_string(&self) -> String {
@@ -205,9 +227,116 @@ impl OpaqueForeignTypeDeclaration {
     pub fn ty_name_ident(&self) -> &Ident {
         &self.ty
     }
+
+    /// The name of this type on the Swift side.
+    ///
+    /// This is the Rust name unless the type was renamed using
+    /// `#[swift_bridge(swift_name = "...")]`.
+    pub fn swift_name_string(&self) -> String {
+        match self.attributes.swift_name.as_ref() {
+            Some(swift_name) => swift_name.value(),
+            None => self.ty.to_string(),
+        }
+    }
+
+    /// The name that this type was declared with, including any generics.
+    ///
+    /// `SomeType` or `SomeType<u32,u64>`
+    pub(crate) fn ty_name_with_generics(&self) -> String {
+        if self.generics.is_empty() {
+            return self.ty.to_string();
+        }
+
+        let generics: Vec<String> = self.generics.iter().map(|g| g.ident.to_string()).collect();
+        format!("{}<{}>", self.ty, generics.join(","))
+    }
+
+    /// The Swift class that Rust holds a retained reference to for an opaque Swift type.
+    ///
+    /// Protocol existentials aren't classes, so a `#[swift_bridge(protocol)] type SomeProtocol`
+    /// gets boxed in a `__swift_bridge__SomeProtocolBox` class.
+    pub fn swift_retained_class_name(&self) -> String {
+        if self.attributes.protocol {
+            format!("{}{}Box", SWIFT_BRIDGE_PREFIX, self.ty)
+        } else {
+            self.swift_name_string()
+        }
+    }
+
+    /// `Unmanaged<SomeType>.fromOpaque(ptr).takeUnretainedValue()`
+    pub fn swift_take_unretained_value(&self, ptr: &str) -> String {
+        format!(
+            "Unmanaged<{}>.fromOpaque({ptr}).takeUnretainedValue(){}",
+            self.swift_retained_class_name(),
+            self.swift_maybe_unbox()
+        )
+    }
+
+    /// `Unmanaged<SomeType>.fromOpaque(ptr).takeRetainedValue()`
+    pub fn swift_take_retained_value(&self, ptr: &str) -> String {
+        format!(
+            "Unmanaged<{}>.fromOpaque({ptr}).takeRetainedValue(){}",
+            self.swift_retained_class_name(),
+            self.swift_maybe_unbox()
+        )
+    }
+
+    /// `Unmanaged.passRetained(value).toOpaque()`
+    pub fn swift_pass_retained(&self, value: &str) -> String {
+        if self.attributes.protocol {
+            format!(
+                "Unmanaged.passRetained({}({value})).toOpaque()",
+                self.swift_retained_class_name()
+            )
+        } else {
+            format!("Unmanaged.passRetained({value}).toOpaque()")
+        }
+    }
+
+    fn swift_maybe_unbox(&self) -> &'static str {
+        if self.attributes.protocol {
+            ".value"
+        } else {
+            ""
+        }
+    }
+
+    /// The class that a `#[swift_bridge(protocol)]` type's existentials get boxed in when they
+    /// are passed to Rust.
+    pub fn generate_swift_protocol_box(&self) -> String {
+        format!(
+            r#"
+final class {box_name} {{
+    let value: {ty}
+
+    init(_ value: {ty}) {{
+        self.value = value
+    }}
+}}
+"#,
+            box_name = self.swift_retained_class_name(),
+            ty = self.swift_name_string()
+        )
+    }
 }
 
 impl TypeDeclarations {
+    /// Declarations for the bridge module with the given name, such as `ffi`.
+    pub(crate) fn new(module_name: String) -> Self {
+        TypeDeclarations {
+            module_name,
+            ..TypeDeclarations::default()
+        }
+    }
+
+    /// The name of the bridge module that the types are declared in.
+    ///
+    /// Support code that every module that uses a type generates, such as the FFI functions of a
+    /// `HashMap<K, V>`, includes it in its symbols so that two modules can use the same type.
+    pub(crate) fn module_name(&self) -> &str {
+        &self.module_name
+    }
+
     pub(crate) fn get<Q: ?Sized>(&self, type_name: &Q) -> Option<&TypeDeclaration>
     where
         Q: std::hash::Hash + Eq,
@@ -216,6 +345,10 @@ impl TypeDeclarations {
         self.decls.get(type_name)
     }
 
+    pub(crate) fn get_mut(&mut self, type_name: &str) -> Option<&mut TypeDeclaration> {
+        self.decls.get_mut(type_name)
+    }
+
     pub(crate) fn get_with_pat_type(&self, pat_ty: &PatType) -> Option<&TypeDeclaration> {
         self.get_with_type(&pat_ty.ty)
     }
@@ -233,8 +366,12 @@ impl TypeDeclarations {
         let ty = match ty {
             Type::Reference(reference) => reference.elem.to_token_stream().to_string(),
             Type::Path(path) => path.to_token_stream().to_string(),
-            _ => todo!("Handle other cases"),
+            _ => return None,
         };
+
+        // Handles generics. i.e. "SomeType < u32, u64 >" -> SomeType<u32,u64>
+        let ty = ty.replace(" ", "");
+
         self.get(&ty)
     }
 
@@ -243,12 +380,45 @@ impl TypeDeclarations {
         self.order.push(type_name);
     }
 
+    /// The names of the declared types, in the order that they were declared.
+    pub(crate) fn type_names(&self) -> impl Iterator<Item = &str> {
+        self.order.iter().map(|name| name.as_str())
+    }
+
     pub fn types(&self) -> Vec<&TypeDeclaration> {
         self.order
             .iter()
             .map(|ty| self.decls.get(ty).unwrap())
             .collect()
     }
+
+    /// See [`crate::SwiftBridgeModule::set_swift_type_prefix`].
+    pub(crate) fn set_swift_type_prefix(&mut self, prefix: &str) {
+        let prefixed = |name: &Ident| LitStr::new(&format!("{}{}", prefix, name), name.span());
+
+        for ty in self.decls.values_mut() {
+            match ty {
+                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                    if shared_struct.swift_name.is_none() {
+                        shared_struct.swift_name = Some(prefixed(&shared_struct.name));
+                    }
+                }
+                TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
+                    if shared_enum.swift_name.is_none() {
+                        shared_enum.swift_name = Some(prefixed(&shared_enum.name));
+                    }
+                }
+                TypeDeclaration::Opaque(opaque) => {
+                    if opaque.host_lang.is_rust()
+                        && opaque.generics.is_empty()
+                        && opaque.attributes.swift_name.is_none()
+                    {
+                        opaque.attributes.swift_bridge.swift_name = Some(prefixed(&opaque.ty));
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl TypeDeclaration {
@@ -258,6 +428,15 @@ impl TypeDeclaration {
             _ => None,
         }
     }
+
+    /// The `#[cfg(...)]` attributes that the type was declared with.
+    pub(crate) fn cfg_attrs(&self) -> &[CfgAttr] {
+        match self {
+            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(s)) => &s.cfg_attrs,
+            TypeDeclaration::Shared(SharedTypeDeclaration::Enum(e)) => &e.cfg_attrs,
+            TypeDeclaration::Opaque(o) => &o.attributes.cfg_attrs,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -45,6 +45,19 @@ impl OpaqueRustTypeGenerics {
         bounds.join(", ")
     }
 
+    /// " where A == UInt32, B == UInt64" if there are generics.
+    /// "" if there are no generics.
+    pub(crate) fn swift_where_clause(&self, types: &TypeDeclarations) -> String {
+        if self.generics.is_empty() {
+            return "".to_string();
+        }
+
+        format!(
+            " where {}",
+            self.rust_opaque_type_swift_generic_bounds(types)
+        )
+    }
+
     /// "<A, B, C>" if there are generics.
     /// "" if there are no generics.
     pub(crate) fn angle_bracketed_generic_placeholders_string(&self) -> String {
@@ -0,0 +1,369 @@
+//! Suggestions for types that can't be bridged but are close to a type that can, such as
+//! `Vec<&str>` or `std::string::String`.
+
+use crate::bridged_type::BridgedType;
+use crate::parse::TypeDeclarations;
+use proc_macro2::Ident;
+use quote::ToTokens;
+use std::ops::Deref;
+use syn::{GenericArgument, PathArguments, Type};
+
+/// The names of the built-in types that a misspelled type might have been meant to be.
+const BUILT_IN_TYPE_NAMES: [&str; 33] = [
+    "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "u128", "i128", "usize", "isize", "f32",
+    "f64", "bool", "String", "Vec", "Option", "Result", "HashMap", "HashSet", "Box", "Arc", "Cow",
+    "PathBuf", "Duration", "Url", "Uuid", "Decimal", "CGFloat", "CGPoint", "CGSize", "CGRect",
+];
+
+/// A type that can be bridged and that is close to one that can't.
+pub(crate) struct TypeSuggestion {
+    pub ty: Type,
+    /// The book chapter that covers the suggested type, such as `"built-in/string"`.
+    pub chapter: Option<&'static str>,
+}
+
+/// Suggest a type that can be bridged in place of `ty`, which can't be.
+pub(crate) fn suggest_supported_type(
+    ty: &Type,
+    types: &TypeDeclarations,
+) -> Option<TypeSuggestion> {
+    let known_names: Vec<&str> = BUILT_IN_TYPE_NAMES
+        .iter()
+        .copied()
+        .chain(types.type_names())
+        .collect();
+    let base = fix_type_names(ty, &known_names);
+
+    let owned = match &base {
+        // Keep a top level borrow, but own the types inside of it such as `&Vec<&str>`.
+        Type::Reference(reference) => {
+            let mut reference = reference.clone();
+            reference.elem = Box::new(owned_alternative(&reference.elem));
+            Type::Reference(reference)
+        }
+        ty => owned_alternative(ty),
+    };
+    let candidates = [base.clone(), owned, borrowed_alternative(&base)];
+
+    let original = ty.to_token_stream().to_string();
+    candidates
+        .into_iter()
+        .find(|candidate| {
+            candidate.to_token_stream().to_string() != original && can_bridge(candidate, types)
+        })
+        .map(|ty| TypeSuggestion {
+            chapter: book_chapter(&ty),
+            ty,
+        })
+}
+
+/// Whether the type can be used in a bridge module's function signatures.
+pub(crate) fn can_bridge(ty: &Type, types: &TypeDeclarations) -> bool {
+    BridgedType::new_with_type(ty, types).is_some() && !contains_vec_of_borrows(ty)
+}
+
+/// Whether the type contains a `Vec` of borrows, such as `Vec<&str>`, which can't be bridged since
+/// a `Vec` owns its elements.
+fn contains_vec_of_borrows(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => contains_vec_of_borrows(&reference.elem),
+        Type::Slice(slice) => contains_vec_of_borrows(&slice.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().any(contains_vec_of_borrows),
+        Type::Path(path) => path.path.segments.iter().any(|segment| {
+            let generics = match &segment.arguments {
+                PathArguments::AngleBracketed(generics) => generics,
+                _ => return false,
+            };
+            generics.args.iter().any(|arg| match arg {
+                GenericArgument::Type(Type::Reference(_)) if segment.ident == "Vec" => true,
+                GenericArgument::Type(ty) => contains_vec_of_borrows(ty),
+                _ => false,
+            })
+        }),
+        _ => false,
+    }
+}
+
+/// The owned version of a type, used to suggest a fix for returning a borrow.
+///
+/// `&str` -> `String`, `&[T]` -> `Vec<T>` and `&T` -> `T`.
+pub(crate) fn owned_alternative(ty: &Type) -> Type {
+    match ty {
+        Type::Reference(reference) => match reference.elem.deref() {
+            Type::Path(path) if path.path.is_ident("str") => syn::parse_quote! { String },
+            Type::Slice(slice) => {
+                let elem = owned_alternative(&slice.elem);
+                syn::parse_quote! { Vec<#elem> }
+            }
+            elem => owned_alternative(elem),
+        },
+        Type::Path(path) => {
+            let mut path = path.clone();
+            for segment in path.path.segments.iter_mut() {
+                if let PathArguments::AngleBracketed(generics) = &mut segment.arguments {
+                    for arg in generics.args.iter_mut() {
+                        if let GenericArgument::Type(ty) = arg {
+                            *ty = owned_alternative(ty);
+                        }
+                    }
+                }
+            }
+            Type::Path(path)
+        }
+        Type::Tuple(tuple) => {
+            let mut tuple = tuple.clone();
+            for elem in tuple.elems.iter_mut() {
+                *elem = owned_alternative(elem);
+            }
+            Type::Tuple(tuple)
+        }
+        ty => ty.clone(),
+    }
+}
+
+/// The borrowed form that a borrowed owned type is bridged as.
+///
+/// `&String` -> `&str` and `&Vec<T>` -> `&[T]`.
+fn borrowed_alternative(ty: &Type) -> Type {
+    let reference = match ty {
+        Type::Reference(reference) => reference,
+        ty => return ty.clone(),
+    };
+    let path = match reference.elem.deref() {
+        Type::Path(path) => path,
+        _ => return ty.clone(),
+    };
+    let segment = match path.path.segments.last() {
+        Some(segment) => segment,
+        None => return ty.clone(),
+    };
+
+    let elem: Type = if segment.ident == "String" {
+        syn::parse_quote! { str }
+    } else if segment.ident == "Vec" {
+        match &segment.arguments {
+            PathArguments::AngleBracketed(generics) => match generics.args.first() {
+                Some(GenericArgument::Type(inner)) => syn::parse_quote! { [#inner] },
+                _ => return ty.clone(),
+            },
+            _ => return ty.clone(),
+        }
+    } else {
+        return ty.clone();
+    };
+
+    let mut reference = reference.clone();
+    reference.elem = Box::new(elem);
+    Type::Reference(reference)
+}
+
+/// Drop the `std::...` paths from standard library types and fix misspelled type names.
+///
+/// `std::string::String` -> `String` and `Vec<Strng>` -> `Vec<String>`.
+fn fix_type_names(ty: &Type, known_names: &[&str]) -> Type {
+    match ty {
+        Type::Reference(reference) => {
+            let mut reference = reference.clone();
+            reference.elem = Box::new(fix_type_names(&reference.elem, known_names));
+            Type::Reference(reference)
+        }
+        Type::Slice(slice) => {
+            let mut slice = slice.clone();
+            slice.elem = Box::new(fix_type_names(&slice.elem, known_names));
+            Type::Slice(slice)
+        }
+        Type::Tuple(tuple) => {
+            let mut tuple = tuple.clone();
+            for elem in tuple.elems.iter_mut() {
+                *elem = fix_type_names(elem, known_names);
+            }
+            Type::Tuple(tuple)
+        }
+        Type::Path(path) if path.qself.is_none() => {
+            let mut path = path.clone();
+
+            let is_std_path = path.path.segments.len() > 1
+                && ["std", "core", "alloc"]
+                    .iter()
+                    .any(|krate| path.path.segments[0].ident == krate);
+            if is_std_path {
+                let last = path.path.segments.pop().unwrap().into_value();
+                path.path.leading_colon = None;
+                path.path.segments.clear();
+                path.path.segments.push(last);
+            }
+
+            if path.path.segments.len() == 1 {
+                let segment = path.path.segments.first_mut().unwrap();
+                if let Some(name) = closest_name(&segment.ident, known_names) {
+                    segment.ident = Ident::new(name, segment.ident.span());
+                }
+            }
+
+            for segment in path.path.segments.iter_mut() {
+                if let PathArguments::AngleBracketed(generics) = &mut segment.arguments {
+                    for arg in generics.args.iter_mut() {
+                        if let GenericArgument::Type(ty) = arg {
+                            *ty = fix_type_names(ty, known_names);
+                        }
+                    }
+                }
+            }
+
+            Type::Path(path)
+        }
+        ty => ty.clone(),
+    }
+}
+
+/// The known name that a misspelled name is closest to, if it is close enough.
+fn closest_name<'a>(ident: &Ident, known_names: &[&'a str]) -> Option<&'a str> {
+    let name = ident.to_string();
+    if known_names.contains(&name.as_str()) {
+        return None;
+    }
+
+    let max_distance = (name.len() / 3).max(1);
+    known_names
+        .iter()
+        .map(|known| {
+            let distance = if known.eq_ignore_ascii_case(&name) {
+                0
+            } else {
+                edit_distance(&name, known)
+            };
+            (distance, *known)
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, known)| known)
+}
+
+/// The number of single character insertions, deletions or substitutions that turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + if a_char == *b_char { 0 } else { 1 };
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// The book chapter that covers a suggested built-in type.
+fn book_chapter(ty: &Type) -> Option<&'static str> {
+    let path = match ty {
+        Type::Reference(reference) => match reference.elem.deref() {
+            Type::Path(path) if path.path.is_ident("str") => return Some("built-in/str"),
+            Type::Slice(_) => return Some("built-in/slice"),
+            Type::Path(path) => path,
+            _ => return None,
+        },
+        Type::Path(path) => path,
+        _ => return None,
+    };
+
+    let chapter = match path.path.segments.last()?.ident.to_string().as_str() {
+        "String" => "built-in/string",
+        "Vec" => "built-in/vec",
+        "Option" => "built-in/option",
+        "Result" => "built-in/result",
+        "HashMap" => "built-in/hash-map",
+        "HashSet" => "built-in/hash-set",
+        "Arc" => "built-in/arc",
+        "Cow" => "built-in/cow-str",
+        "PathBuf" => "built-in/path",
+        "Duration" | "SystemTime" => "built-in/time",
+        "Url" => "built-in/url",
+        "Uuid" => "built-in/uuid",
+        "Decimal" => "built-in/decimal",
+        "CGFloat" | "CGPoint" | "CGSize" | "CGRect" => "built-in/core-graphics",
+        _ => return None,
+    };
+    Some(chapter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ParseError;
+    use crate::test_utils::parse_errors;
+    use quote::quote;
+
+    /// Verify that `can_bridge` returns `false` instead of panicking for nested `Option`s.
+    #[test]
+    fn cannot_bridge_nested_options() {
+        let types = TypeDeclarations::default();
+
+        for ty in ["Option<Option<u8>>", "Option<Option<Option<String>>>"] {
+            let ty: Type = syn::parse_str(ty).unwrap();
+            assert!(!can_bridge(&ty, &types));
+        }
+        assert!(can_bridge(&syn::parse_str("Option<u8>").unwrap(), &types));
+    }
+
+    /// Verify that we suggest a supported type for types that are close to one.
+    #[test]
+    fn suggests_supported_types() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn a (arg: Vec<&str>);
+                    fn b (arg: std::string::String);
+                    fn c (arg: &String);
+                    fn d (arg: &Vec<u8>);
+                    fn e (arg: Option<Strng>);
+                    fn f (arg: SomeTyp);
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 6);
+
+        let expected = [
+            ("Vec < String >", Some("built-in/vec")),
+            ("String", Some("built-in/string")),
+            ("& str", Some("built-in/str")),
+            ("& [u8]", Some("built-in/slice")),
+            ("Option < String >", Some("built-in/option")),
+            ("SomeType", None),
+        ];
+        for (error, (expected_ty, expected_chapter)) in errors.iter().zip(expected) {
+            match error {
+                ParseError::UnsupportedTypeWithSuggestion {
+                    suggestion,
+                    chapter,
+                    ..
+                } => {
+                    assert_eq!(suggestion.to_token_stream().to_string(), expected_ty);
+                    assert_eq!(*chapter, expected_chapter);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we fall back to the undeclared type error when there is nothing close to
+    /// suggest.
+    #[test]
+    fn no_suggestion_for_unrelated_type() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a (arg: Unrelated);
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0], ParseError::UndeclaredType { .. }));
+    }
+}
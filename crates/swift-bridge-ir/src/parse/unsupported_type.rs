@@ -0,0 +1,577 @@
+//! Types that can be bridged on their own, but not everywhere that they are used, such as `Uuid`
+//! inside of an `Option` or `HashMap<K, V>` inside of a `Vec`.
+
+use crate::bridged_type::BridgedType;
+use crate::errors::ParseError;
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
+use syn::{GenericArgument, PathArguments, Type};
+
+/// An error for a part of the type that can't be bridged even though the types inside of it can,
+/// such as the `Option<Uuid>` in `Vec<Option<Uuid>>`.
+///
+/// Shared struct and enum fields get checked by [`find_unsupported_field_types`].
+pub(crate) fn find_unsupported_type(ty: &Type, types: &TypeDeclarations) -> Option<ParseError> {
+    match ty {
+        Type::Reference(reference) => find_unsupported_type(&reference.elem, types),
+        Type::Slice(slice) => find_unsupported_type(&slice.elem, types),
+        Type::Array(_) if BridgedType::new_with_type(ty, types).is_none() => {
+            Some(ParseError::UnsupportedArray { ty: ty.clone() })
+        }
+        Type::Array(array) => find_unsupported_type(&array.elem, types),
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .find_map(|ty| find_unsupported_type(ty, types)),
+        Type::Path(path) => {
+            let segment = path.path.segments.last()?;
+            let generics = match &segment.arguments {
+                PathArguments::AngleBracketed(generics) => generics,
+                _ => return None,
+            };
+            let generic_types = generics.args.iter().filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            });
+
+            if segment.ident == "Option" {
+                if let Some(Type::Path(inner)) = generic_types.clone().next() {
+                    if inner
+                        .path
+                        .segments
+                        .last()
+                        .is_some_and(|inner| inner.ident == "Option")
+                    {
+                        return Some(ParseError::NestedOption { ty: ty.clone() });
+                    }
+                }
+            }
+
+            if segment.ident == "Arc" {
+                if let Some(inner) = generic_types.clone().next() {
+                    if let Some(TypeDeclaration::Opaque(opaque)) = types.get_with_type(inner) {
+                        if opaque.attributes.already_declared {
+                            return Some(ParseError::ArcOfAlreadyDeclaredType {
+                                ty: ty.clone(),
+                                inner: Box::new(inner.clone()),
+                            });
+                        }
+                    }
+                }
+            }
+
+            for inner in generic_types.clone() {
+                let b = match BridgedType::new_with_type(inner, types) {
+                    Some(BridgedType::Bridgeable(b)) => b,
+                    _ => continue,
+                };
+                let ty = ty.clone();
+                let inner = Box::new(inner.clone());
+
+                let error = match segment.ident.to_string().as_str() {
+                    "Option" if !b.supports_option() => ParseError::UnsupportedOption { ty, inner },
+                    "Vec" if !b.supports_vec() => ParseError::UnsupportedVec { ty, inner },
+                    "Result" if !b.supports_result() => ParseError::UnsupportedResult { ty, inner },
+                    _ => continue,
+                };
+                return Some(error);
+            }
+
+            generic_types
+                .into_iter()
+                .find_map(|ty| find_unsupported_type(ty, types))
+        }
+        _ => None,
+    }
+}
+
+/// The part of a type that Swift passes to Rust which can only be passed from Rust to Swift, such
+/// as the `Cow<'static, str>` in `(Cow<'static, str>, u8)`.
+pub(crate) fn find_rust_to_swift_only_type(ty: &Type, types: &TypeDeclarations) -> Option<Type> {
+    if let Some(BridgedType::Bridgeable(b)) = BridgedType::new_with_type(ty, types) {
+        if !b.supports_swift_to_rust() {
+            return Some(ty.clone());
+        }
+    }
+
+    match ty {
+        Type::Reference(reference) => find_rust_to_swift_only_type(&reference.elem, types),
+        Type::Slice(slice) => find_rust_to_swift_only_type(&slice.elem, types),
+        Type::Array(array) => find_rust_to_swift_only_type(&array.elem, types),
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .find_map(|ty| find_rust_to_swift_only_type(ty, types)),
+        Type::Path(path) => match &path.path.segments.last()?.arguments {
+            PathArguments::AngleBracketed(generics) => {
+                generics.args.iter().find_map(|arg| match arg {
+                    GenericArgument::Type(ty) => find_rust_to_swift_only_type(ty, types),
+                    _ => None,
+                })
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Errors for the fields of the module's shared structs and enums whose types can't be used as
+/// fields, such as a `HashMap<K, V>` field.
+pub(crate) fn find_unsupported_field_types(types: &TypeDeclarations) -> Vec<ParseError> {
+    let mut errors = vec![];
+
+    for ty in types.types() {
+        let fields = match ty {
+            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                shared_struct.fields.normalized_fields()
+            }
+            TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => shared_enum
+                .variants
+                .iter()
+                .flat_map(|variant| variant.fields.normalized_fields())
+                .collect(),
+            TypeDeclaration::Opaque(_) => continue,
+        };
+
+        for field in fields {
+            if let Some(BridgedType::Bridgeable(b)) = BridgedType::new_with_type(&field.ty, types) {
+                if !b.supports_shared_struct_field() {
+                    errors.push(ParseError::UnsupportedStructField { ty: field.ty });
+                    continue;
+                }
+            }
+            if let Some(error) = find_unsupported_type(&field.ty, types) {
+                errors.push(error);
+            }
+        }
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse_errors;
+    use quote::{quote, ToTokens};
+
+    /// Verify that we reject `Option`s of types that don't support `Option`, wherever the
+    /// `Option` is nested.
+    #[test]
+    fn rejects_unsupported_options() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a (arg: Option<Uuid>);
+                    fn b () -> Vec<Option<Uuid>>;
+                    fn c () -> Result<Option<Uuid>, String>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::UnsupportedOption { ty, inner } => {
+                    assert_eq!(ty.to_token_stream().to_string(), "Option < Uuid >");
+                    assert_eq!(inner.to_token_stream().to_string(), "Uuid");
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that each of the types that don't support `Option` are rejected inside of one.
+    #[test]
+    fn rejects_options_of_types_without_option_support() {
+        let unsupported = [
+            "Uuid",
+            "Duration",
+            "SystemTime",
+            "PathBuf",
+            "&Path",
+            "[u8; 4]",
+            "u128",
+            "i128",
+            "&[SomeType]",
+            "Cow<'static, str>",
+            "NonZeroU32",
+            "NonZeroI64",
+            "NsError",
+            "Url",
+            "Decimal",
+            "CGFloat",
+            "CGPoint",
+            "CGSize",
+            "CGRect",
+        ];
+
+        for ty in unsupported {
+            let ty: Type = syn::parse_str(ty).unwrap();
+            let tokens = quote! {
+                mod foo {
+                    extern "Rust" {
+                        type SomeType;
+
+                        fn a (arg: Option<#ty>);
+                    }
+                }
+            };
+            let errors = parse_errors(tokens);
+            assert_eq!(errors.len(), 1, "{}", ty.to_token_stream());
+            assert!(
+                matches!(errors[0], ParseError::UnsupportedOption { .. }),
+                "{}",
+                ty.to_token_stream()
+            );
+        }
+    }
+
+    /// Verify that we reject nested `Option`s, wherever they're used.
+    #[test]
+    fn rejects_nested_options() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a (arg: Option<Option<u8>>);
+                    fn b () -> Vec<Option<Option<String>>>;
+                    fn c () -> Option<Option<Option<u8>>>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        let expected = [
+            "Option < Option < u8 > >",
+            "Option < Option < String > >",
+            "Option < Option < Option < u8 > > >",
+        ];
+        for (error, expected) in errors.iter().zip(expected) {
+            match error {
+                ParseError::NestedOption { ty } => {
+                    assert_eq!(ty.to_token_stream().to_string(), expected);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we reject a `Vec` of a type that can't be stored in a `RustVec`.
+    #[test]
+    fn rejects_unsupported_vec() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn f (a: Vec<Uuid>);
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::UnsupportedVec { ty, inner } => {
+                assert_eq!(ty.to_token_stream().to_string(), "Vec < Uuid >");
+                assert_eq!(inner.to_token_stream().to_string(), "Uuid");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that each of the types that can't be stored in a `RustVec` are rejected inside of
+    /// a `Vec`.
+    #[test]
+    fn rejects_vecs_of_types_without_vec_support() {
+        let unsupported = [
+            "Uuid",
+            "Duration",
+            "SystemTime",
+            "PathBuf",
+            "&Path",
+            "[u8; 4]",
+            "u128",
+            "i128",
+            "&[SomeType]",
+            "Cow<'static, str>",
+            "NonZeroU32",
+            "NonZeroI64",
+            "NsError",
+            "Url",
+            "Decimal",
+            "CGFloat",
+            "CGPoint",
+            "CGSize",
+            "CGRect",
+        ];
+
+        for ty in unsupported {
+            let ty: Type = syn::parse_str(ty).unwrap();
+            let tokens = quote! {
+                mod foo {
+                    extern "Rust" {
+                        type SomeType;
+
+                        fn a (arg: Vec<#ty>);
+                    }
+                }
+            };
+            let errors = parse_errors(tokens);
+            assert_eq!(errors.len(), 1, "{}", ty.to_token_stream());
+            assert!(
+                matches!(errors[0], ParseError::UnsupportedVec { .. }),
+                "{}",
+                ty.to_token_stream()
+            );
+        }
+    }
+
+    /// Verify that we reject `HashMap`s inside of `Option`s, `Vec`s and `Result`s, and `HashMap`
+    /// fields.
+    #[test]
+    fn rejects_unsupported_hash_maps() {
+        let tokens = quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    map: HashMap<String, u8>,
+                }
+
+                extern "Rust" {
+                    fn a (arg: Option<HashMap<String, u8>>);
+                    fn b () -> Vec<HashMap<String, u8>>;
+                    fn c () -> Result<HashMap<String, u8>, String>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        let errors: Vec<String> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::UnsupportedOption { ty, .. } => {
+                    format!("Option {}", ty.to_token_stream())
+                }
+                ParseError::UnsupportedVec { ty, .. } => format!("Vec {}", ty.to_token_stream()),
+                ParseError::UnsupportedResult { ty, .. } => {
+                    format!("Result {}", ty.to_token_stream())
+                }
+                ParseError::UnsupportedStructField { ty } => {
+                    format!("Field {}", ty.to_token_stream())
+                }
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "Field HashMap < String , u8 >",
+                "Option Option < HashMap < String , u8 > >",
+                "Vec Vec < HashMap < String , u8 > >",
+                "Result Result < HashMap < String , u8 > , String >",
+            ]
+        );
+    }
+
+    /// Verify that we reject `HashSet`s inside of `Option`s, `Vec`s and `Result`s, and `HashSet`
+    /// fields.
+    #[test]
+    fn rejects_unsupported_hash_sets() {
+        let tokens = quote! {
+            mod foo {
+                enum SomeEnum {
+                    Variant(HashSet<u8>),
+                }
+
+                extern "Rust" {
+                    fn a (arg: Option<HashSet<u8>>);
+                    fn b () -> Vec<HashSet<u8>>;
+                    fn c () -> Result<(), HashSet<u8>>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+
+        assert!(matches!(
+            errors[0],
+            ParseError::UnsupportedStructField { .. }
+        ));
+        assert!(matches!(errors[1], ParseError::UnsupportedOption { .. }));
+        assert!(matches!(errors[2], ParseError::UnsupportedVec { .. }));
+        assert!(matches!(errors[3], ParseError::UnsupportedResult { .. }));
+    }
+
+    /// Verify that we reject `Arc`s inside of `Option`s, `Vec`s and `Result`s, `Arc` fields and
+    /// `Arc`s of types that another bridge module declares.
+    #[test]
+    fn rejects_unsupported_arcs() {
+        let tokens = quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: Arc<SomeType>,
+                }
+
+                extern "Rust" {
+                    type SomeType;
+                    #[swift_bridge(already_declared)]
+                    type OtherType;
+
+                    fn a (arg: Option<Arc<SomeType>>);
+                    fn b () -> Vec<Arc<SomeType>>;
+                    fn c () -> Result<Arc<SomeType>, String>;
+                    fn d () -> Arc<OtherType>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 5);
+
+        assert!(matches!(
+            errors[0],
+            ParseError::UnsupportedStructField { .. }
+        ));
+        assert!(matches!(errors[1], ParseError::UnsupportedOption { .. }));
+        assert!(matches!(errors[2], ParseError::UnsupportedVec { .. }));
+        assert!(matches!(errors[3], ParseError::UnsupportedResult { .. }));
+        match &errors[4] {
+            ParseError::ArcOfAlreadyDeclaredType { ty, inner } => {
+                assert_eq!(ty.to_token_stream().to_string(), "Arc < OtherType >");
+                assert_eq!(inner.to_token_stream().to_string(), "OtherType");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we reject `PathBuf` and `&Path` fields in shared structs and enum variants.
+    #[test]
+    fn rejects_path_fields() {
+        let tokens = quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: PathBuf,
+                }
+
+                enum SomeEnum {
+                    Variant(PathBuf),
+                    Named { path: &'static Path },
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        for error in errors.iter() {
+            assert!(matches!(error, ParseError::UnsupportedStructField { .. }));
+        }
+    }
+
+    /// Verify that we reject arrays that can't be bridged, instead of treating them as undeclared
+    /// types.
+    #[test]
+    fn rejects_unsupported_arrays() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a (arg: [u8; 0]);
+                    fn b (arg: [[u8; 2]; 2]);
+                    fn c (arg: [String; 2]);
+                    fn d (arg: [u8; N]);
+                    fn e () -> Vec<[u8; 0]>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 5);
+
+        let expected = [
+            "[u8 ; 0]",
+            "[[u8 ; 2] ; 2]",
+            "[String ; 2]",
+            "[u8 ; N]",
+            "[u8 ; 0]",
+        ];
+        for (error, expected) in errors.iter().zip(expected) {
+            match error {
+                ParseError::UnsupportedArray { ty } => {
+                    assert_eq!(ty.to_token_stream().to_string(), expected);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we reject array fields in shared structs and enum variants.
+    #[test]
+    fn rejects_array_fields() {
+        let tokens = quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: [u8; 4],
+                }
+
+                enum SomeEnum {
+                    Variant([u8; 4]),
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for error in errors.iter() {
+            assert!(matches!(error, ParseError::UnsupportedStructField { .. }));
+        }
+    }
+
+    /// Verify that we reject `Cow<'static, str>` fields in shared structs and enum variants.
+    #[test]
+    fn rejects_cow_str_fields() {
+        let tokens = quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: Cow<'static, str>,
+                }
+
+                enum SomeEnum {
+                    Variant(Cow<'static, str>),
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for error in errors.iter() {
+            assert!(matches!(error, ParseError::UnsupportedStructField { .. }));
+        }
+    }
+
+    /// Verify that we reject slice fields in shared structs and enum variants.
+    #[test]
+    fn rejects_opaque_slice_fields() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: &'static [SomeType],
+                }
+
+                enum SomeEnum {
+                    Variant(&'static [SomeType]),
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for error in errors.iter() {
+            assert!(matches!(error, ParseError::UnsupportedStructField { .. }));
+        }
+    }
+}
@@ -1,10 +1,14 @@
+use crate::bridge_macro_attributes::PanicBehavior;
+use crate::bridge_module_attributes::CfgAttr;
 use crate::bridged_type::boxed_fn::BridgeableBoxedFnOnce;
-use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, StdLibType};
+use crate::bridged_type::{
+    pat_type_pat_is_self, BridgeableType, BridgedType, StdLibType, TypePosition,
+};
 use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
 use crate::SWIFT_BRIDGE_PREFIX;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ops::Deref;
 use syn::spanned::Spanned;
 use syn::{FnArg, ForeignItemFn, Lifetime, LitStr, Path, ReturnType, Token, Type};
@@ -92,7 +96,83 @@ pub(crate) struct ParsedExternFn {
     pub args_into: Option<Vec<Ident>>,
     /// Get one of the associated type's fields
     pub get_field: Option<GetField>,
+    /// Set one of the associated type's fields to the function's argument.
+    pub set_field: Option<Ident>,
+    /// Whether this function is exposed as a Swift computed property instead of a method.
+    ///
+    /// A `fn set_some_field(&mut self, ...)` property is used as the setter for the `some_field`
+    /// property.
+    pub is_swift_property: bool,
+    /// Whether this method is exposed as a Swift subscript instead of a method.
+    ///
+    /// A `&mut self` subscript method is used as the setter for the `&self` subscript method that
+    /// takes the same index arguments.
+    pub is_swift_subscript: bool,
     pub argument_labels: HashMap<Ident, LitStr>,
+    /// The concrete type that this function's generic type parameter was replaced with, if it was
+    /// declared using `#[swift_bridge(instantiate(...))]`.
+    pub instantiation: Option<GenericFnInstantiation>,
+    /// The function's `/// ...` doc comment, which gets emitted as Swift documentation.
+    pub doc_comment: Option<String>,
+    /// `#[swift_bridge(available = "iOS 16.0")]`
+    /// The platforms that the generated Swift function is available on.
+    pub available: Option<LitStr>,
+    /// `#[swift_bridge(swift_access = "internal")]`
+    /// The access level of the generated Swift function.
+    pub swift_access: Option<LitStr>,
+    /// The `#[cfg(...)]` attributes on the function and on its `extern` block.
+    pub cfg_attrs: Vec<CfgAttr>,
+    /// `#[swift_bridge(unchecked_utf8)]`
+    /// The `&str` arguments that Swift passes to this function are not validated as UTF-8.
+    pub unchecked_utf8: bool,
+    /// `#[swift_bridge(return_scratch)]`
+    /// The Rust function writes its returned `&str` into a `&mut String` scratch buffer that
+    /// gets reused across calls, and Swift receives a copy of it as a Swift `String`.
+    pub return_scratch: bool,
+    /// `#[swift_bridge(catch_errors)]`
+    /// The Swift implementation `throws`, and the errors that it throws are returned to Rust as
+    /// the `Err` variant of the function's `Result<T, String>` or `Result<T, NsError>`.
+    pub catch_errors: bool,
+    /// The `T` in the `impl Stream<Item = T>` that the function returns, which Swift receives as
+    /// an `AsyncStream` or, for a `publisher` function, as an `AnyPublisher`. The declared return
+    /// type is removed from `func`, so everything that doesn't deal with the stream sees a
+    /// function that returns `()`.
+    pub stream_item: Option<Type>,
+    /// `#[swift_bridge(publisher)]`
+    /// Swift receives the function's stream as a Combine `AnyPublisher`.
+    pub publisher: bool,
+    /// `#[swift_bridge(main_actor)]`
+    /// The glue that Rust calls hops onto the main actor before calling the Swift function.
+    pub main_actor: bool,
+    /// `#[swift_bridge(dispatch_queue = "Database.queue")]`
+    /// A Swift expression for the `DispatchQueue` that the function gets called on.
+    pub dispatch_queue: Option<LitStr>,
+    /// Whether the generated Rust and Swift wrappers get inlining hints.
+    /// Set using `#[swift_bridge::bridge(inline_shims)]`.
+    pub inline_shims: bool,
+    /// Whether the function's `&str` arguments are passed as `SmallRustStr`s when Rust is the
+    /// host. Set using the `small-string-ffi` feature.
+    pub small_string_ffi: bool,
+    /// How the generated Swift code handles the Rust function panicking.
+    /// Set using `#[swift_bridge::bridge(catch_panics = "...")]`.
+    pub catch_panics: Option<PanicBehavior>,
+}
+
+/// One of the instantiations of a generic function.
+///
+/// ```no_run,ignore
+/// // Declaration
+/// #[swift_bridge(instantiate(u32, f64))]
+/// fn some_function<T>(arg: T) -> T;
+///
+/// // Gets bridged as if it were declared as
+/// fn some_function(arg: u32) -> u32;
+/// fn some_function(arg: f64) -> f64;
+/// ```
+pub(crate) struct GenericFnInstantiation {
+    pub ty: Type,
+    /// Used to give each instantiation a unique link name, such as `"U32"` for `u32`.
+    pub name: String,
 }
 
 pub(crate) enum GetField {
@@ -135,6 +215,86 @@ impl ParsedExternFn {
         self.func.sig.receiver().is_some()
     }
 
+    /// Whether Swift passes this function's `&str` arguments as `SmallRustStr`s instead of
+    /// `RustStr`s.
+    ///
+    /// Async and stream functions keep using `RustStr`s since a `SmallRustStr`'s inline bytes
+    /// don't outlive the extern function's stack frame.
+    pub(crate) fn passes_small_strs(&self) -> bool {
+        self.small_string_ffi
+            && self.host_lang.is_rust()
+            && self.sig.asyncness.is_none()
+            && self.stream_item.is_none()
+            && self.sig.inputs.iter().any(|arg| match arg {
+                FnArg::Typed(pat_ty) => match pat_ty.ty.deref() {
+                    Type::Reference(reference) => {
+                        reference.elem.to_token_stream().to_string() == "str"
+                    }
+                    _ => false,
+                },
+                FnArg::Receiver(_) => false,
+            })
+    }
+
+    /// Convert a `&str` or `Option<&str>` argument without validating it as UTF-8, if the
+    /// function is annotated with `#[swift_bridge(unchecked_utf8)]`.
+    ///
+    /// Swift `String`s are always valid UTF-8, so this is only unsafe if the Swift caller builds a
+    /// `RustStr` out of arbitrary bytes.
+    fn unchecked_utf8_str_arg(&self, arg: &TokenStream, ty: &BridgedType) -> Option<TokenStream> {
+        if !self.unchecked_utf8 {
+            return None;
+        }
+
+        match ty {
+            BridgedType::StdLib(StdLibType::Str) => Some(quote! {
+                unsafe { #arg.to_str_unchecked() }
+            }),
+            BridgedType::StdLib(StdLibType::Option(opt)) if opt.ty.is_str() => Some(quote! {
+                unsafe { #arg.to_option_str_unchecked() }
+            }),
+            _ => None,
+        }
+    }
+
+    /// `#[inline]` if the module was annotated with `#[swift_bridge::bridge(inline_shims)]`.
+    pub fn maybe_inline_attribute(&self) -> Option<TokenStream> {
+        if self.inline_shims {
+            Some(quote! { #[inline] })
+        } else {
+            None
+        }
+    }
+
+    /// In debug builds, abort if Swift passes a method a null or already freed `this` pointer to
+    /// an opaque Rust type.
+    pub fn maybe_assert_valid_receiver(&self, swift_bridge_path: &Path) -> Option<TokenStream> {
+        if !self.host_lang.is_rust() || !self.is_method() || self.is_copy_method_on_opaque_type() {
+            return None;
+        }
+
+        let ty_name = self.associated_type.as_ref()?.as_opaque()?.ty.to_string();
+
+        Some(quote! {
+            #[cfg(debug_assertions)]
+            #swift_bridge_path::pointer_support::assert_valid_pointer(this, #ty_name);
+        })
+    }
+
+    /// Whether the generated Swift function throws a `RustPanic` when the Rust function panics.
+    ///
+    /// Computed properties and subscripts can't throw, and panics in `async` functions and in
+    /// returned streams happen on the async runtime's threads instead of unwinding into Swift,
+    /// so those never throw.
+    pub fn throws_rust_panics(&self) -> bool {
+        self.catch_panics == Some(PanicBehavior::Throw)
+            && self.host_lang.is_rust()
+            && self.sig.asyncness.is_none()
+            && self.stream_item.is_none()
+            && !self.is_swift_property
+            && !self.is_swift_subscript
+    }
+
     pub fn self_reference(&self) -> Option<(Token![&], Option<Lifetime>)> {
         match self.func.sig.receiver()? {
             FnArg::Receiver(receiver) => receiver.reference.clone(),
@@ -155,11 +315,53 @@ impl ParsedExternFn {
         }
     }
 
+    /// The name of the Swift computed property that this getter or setter is exposed as.
+    ///
+    /// A `fn set_name(&mut self, ...)` setter is the setter for the `name` property, unless the
+    /// setter was renamed using `#[swift_bridge(swift_name = "...")]`.
+    pub fn swift_property_name(&self) -> String {
+        if let Some(field_name) = self.set_field.as_ref() {
+            return field_name.to_string();
+        }
+        if let Some(swift_name) = self.swift_name_override.as_ref() {
+            return swift_name.value();
+        }
+
+        let fn_name = self.sig.ident.to_string();
+        if self.self_mutability().is_some() {
+            fn_name
+                .strip_prefix("set_")
+                .map(str::to_string)
+                .unwrap_or(fn_name)
+        } else {
+            fn_name
+        }
+    }
+
+    /// The item type of the stream that the function returns.
+    pub(crate) fn stream_item_ty(&self, types: &TypeDeclarations) -> Option<BridgedType> {
+        self.stream_item
+            .as_ref()
+            .map(|item| BridgedType::new_with_type(item, types).unwrap())
+    }
+
+    /// The element type of the Swift sequence or publisher that receives the function's stream,
+    /// along with its failure type, which is `Error` for a stream of `Result`s and `Never`
+    /// otherwise.
+    pub(crate) fn stream_swift_types(&self, types: &TypeDeclarations) -> Option<(String, String)> {
+        let item = self.stream_item_ty(types)?;
+        let (output, failure) = stream_output_and_failure(&item);
+
+        let output = output.to_swift_type(TypePosition::FnReturn(HostLang::Rust), types);
+        let failure = if failure.is_some() { "Error" } else { "Never" };
+        Some((output, failure.to_string()))
+    }
+
     pub(crate) fn rust_fn_sig_return_tokens(
         &self,
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
-        custom_type_definitions: &mut HashMap<String, TokenStream>,
+        custom_type_definitions: &mut BTreeMap<String, TokenStream>,
     ) -> TokenStream {
         let sig = &self.func.sig;
 
@@ -256,7 +458,7 @@ impl ParsedExternFn {
     ) -> TokenStream {
         let mut args = vec![];
         let inputs = &self.func.sig.inputs;
-        for fn_arg in inputs.into_iter() {
+        for (idx, fn_arg) in inputs.into_iter().enumerate() {
             match fn_arg {
                 FnArg::Receiver(_receiver) => {
                     if self.host_lang.is_swift() {
@@ -280,6 +482,18 @@ impl ParsedExternFn {
                         if self.host_lang.is_rust() {
                             arg = if let Some(repr) = built_in.only_encoding() {
                                 repr.rust
+                            } else if let Some(unchecked) =
+                                self.unchecked_utf8_str_arg(&arg, &built_in)
+                            {
+                                unchecked
+                            } else if let BridgedType::StdLib(StdLibType::BoxedFnOnce(boxed_fn)) =
+                                &built_in
+                            {
+                                boxed_fn.convert_ffi_swift_closure_to_rust_type(
+                                    &arg,
+                                    &self.swift_closure_wrapper_name(idx),
+                                    types,
+                                )
                             } else {
                                 built_in.convert_ffi_expression_to_rust_type(
                                     &arg,
@@ -316,6 +530,10 @@ impl ParsedExternFn {
             };
         }
 
+        if self.return_scratch && self.host_lang.is_rust() {
+            args.push(quote! { scratch });
+        }
+
         quote! {
             #(#args),*
         }
@@ -344,7 +562,12 @@ impl ParsedExternFn {
                             continue;
                         }
 
-                        let ty = built_in.to_c(types);
+                        let ty = match built_in {
+                            BridgedType::StdLib(StdLibType::Str) if self.passes_small_strs() => {
+                                "struct SmallRustStr".to_string()
+                            }
+                            _ => built_in.to_c(types),
+                        };
 
                         let arg_name = pat.to_token_stream().to_string();
                         params.push(format!("{} {}", ty, arg_name));
@@ -401,6 +624,12 @@ impl ParsedExternFn {
     pub fn c_includes(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
         let mut includes = vec![];
 
+        if let Some(item) = self.stream_item_ty(types) {
+            if let Some(include) = item.to_c_include(types) {
+                includes.push(include);
+            }
+        }
+
         if let ReturnType::Type(_, ty) = &self.func.sig.output {
             if let Some(ty) = BridgedType::new_with_type(&ty, types) {
                 if let Some(include) = ty.to_c_include(types) {
@@ -429,15 +658,13 @@ impl ParsedExternFn {
     fn push_self_param(&self, params: &mut Vec<String>) {
         let param = if self.is_copy_method_on_opaque_type() {
             format!(
-                "struct {}${} this",
-                SWIFT_BRIDGE_PREFIX,
-                &self
-                    .associated_type
+                "struct {} this",
+                self.associated_type
                     .as_ref()
                     .unwrap()
                     .as_opaque()
                     .unwrap()
-                    .ty
+                    .ffi_repr_name_string()
             )
         } else {
             "void* self".to_string()
@@ -459,17 +686,54 @@ impl ParsedExternFn {
                         todo!()
                     }
                     TypeDeclaration::Opaque(h) => {
-                        format!("${}", h.to_string())
+                        format!("${}{}", h.ty, h.generics.dollar_prefixed_generics_string())
                     }
                 }
             })
             .unwrap_or("".to_string());
 
         format!(
-            "{}{}${}",
+            "{}{}${}{}",
             SWIFT_BRIDGE_PREFIX,
             host_type,
-            self.func.sig.ident.to_string()
+            self.linked_fn_name("$"),
+            self.maybe_small_str_link_name_suffix()
+        )
+    }
+
+    /// Functions that take `SmallRustStr`s get a different symbol so that linking Swift code
+    /// generated without the `small-string-ffi` feature against Rust code generated with it, or
+    /// vice versa, fails instead of passing the wrong struct.
+    pub(crate) fn maybe_small_str_link_name_suffix(&self) -> &'static str {
+        if self.passes_small_strs() {
+            "$small_str"
+        } else {
+            ""
+        }
+    }
+
+    /// The function's name, followed by the instantiation's name if this is an instantiation of a
+    /// generic function.
+    ///
+    /// `some_function`, or `some_function$U32` for `some_function<u32>` with a "$" separator.
+    pub(crate) fn linked_fn_name(&self, separator: &str) -> String {
+        match self.instantiation.as_ref() {
+            Some(instantiation) => {
+                format!("{}{}{}", self.func.sig.ident, separator, instantiation.name)
+            }
+            None => self.func.sig.ident.to_string(),
+        }
+    }
+
+    /// The name of the Rust type that holds onto a Swift closure that was passed to the boxed
+    /// function argument at the given index.
+    ///
+    /// `__swift_bridge__some_function_param0`
+    pub fn swift_closure_wrapper_name(&self, boxed_fn_idx: usize) -> Ident {
+        let prefixed_fn_name = self.prefixed_fn_name();
+        Ident::new(
+            &format!("{}_param{}", prefixed_fn_name, boxed_fn_idx),
+            prefixed_fn_name.span(),
         )
     }
 
@@ -522,7 +786,11 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
                         todo!()
                     }
                     TypeDeclaration::Opaque(h) => {
-                        format!("{}_", h.to_token_stream().to_string())
+                        format!(
+                            "{}{}_",
+                            h.ty,
+                            h.generics.underscore_prefixed_generics_string()
+                        )
                     }
                 }
             })
@@ -533,7 +801,7 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
                 "{}{}{}",
                 SWIFT_BRIDGE_PREFIX,
                 host_type_prefix,
-                fn_name.to_string()
+                self.linked_fn_name("_")
             ),
             fn_name.span(),
         );
@@ -660,6 +928,17 @@ impl Deref for ParsedExternFn {
     }
 }
 
+/// Split a stream's item type into the values that it produces and, for a stream of `Result`s, the
+/// error that it fails with.
+pub(crate) fn stream_output_and_failure(
+    item: &BridgedType,
+) -> (&dyn BridgeableType, Option<&dyn BridgeableType>) {
+    match item.as_result() {
+        Some(result) => (result.ok_ty.deref(), Some(result.err_ty.deref())),
+        None => (item, None),
+    }
+}
+
 pub(crate) fn fn_arg_is_mutable_reference(fn_arg: &FnArg) -> bool {
     match fn_arg {
         FnArg::Receiver(receiver) => receiver.reference.is_some() && receiver.mutability.is_some(),
@@ -723,7 +1002,7 @@ mod tests {
         let module = parse_ok(tokens);
         assert_tokens_eq(
             &module.functions[0].to_call_rust_args(&module.swift_bridge_path, &module.types),
-            &quote! {unsafe { *Box::from_raw(arg) }},
+            &quote! {unsafe { *swift_bridge::leak_check::box_from_raw(arg) }},
         );
     }
 
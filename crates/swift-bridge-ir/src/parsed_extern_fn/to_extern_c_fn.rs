@@ -1,9 +1,11 @@
 use crate::bridged_type::BridgedType;
 use crate::parse::{HostLang, OpaqueCopy, TypeDeclaration, TypeDeclarations};
-use crate::parsed_extern_fn::{GetField, GetFieldDirect, GetFieldWith, ParsedExternFn};
+use crate::parsed_extern_fn::{
+    stream_output_and_failure, GetField, GetFieldDirect, GetFieldWith, ParsedExternFn,
+};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use syn::spanned::Spanned;
 use syn::Path;
 
@@ -28,7 +30,7 @@ impl ParsedExternFn {
         &self,
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
-        custom_type_definitions: &mut HashMap<String, TokenStream>,
+        custom_type_definitions: &mut BTreeMap<String, TokenStream>,
     ) -> TokenStream {
         let link_name = self.link_name();
 
@@ -50,18 +52,123 @@ impl ParsedExternFn {
 
                 let is_async = self.sig.asyncness.is_some();
 
-                if !is_async {
+                let swift_closures_support = self.swift_closures_support(swift_bridge_path, types);
+
+                let maybe_assert_valid_receiver =
+                    self.maybe_assert_valid_receiver(swift_bridge_path);
+
+                if let Some(item) = self.stream_item_ty(types) {
+                    let (output, failure) = stream_output_and_failure(&item);
+                    for ty in std::iter::once(output).chain(failure) {
+                        if let Some(tokens) =
+                            ty.generate_custom_rust_ffi_types(swift_bridge_path, types)
+                        {
+                            for token in tokens.into_iter() {
+                                custom_type_definitions.insert(token.to_string(), token);
+                            }
+                        }
+                    }
+
+                    let output_ffi_ty =
+                        output.to_ffi_compatible_rust_type(swift_bridge_path, types);
+                    let output_val = output.convert_rust_expression_to_ffi_type(
+                        &quote! { item },
+                        swift_bridge_path,
+                        types,
+                        Span::call_site(),
+                    );
+
+                    let (maybe_on_error, on_item) = match failure {
+                        Some(failure) => {
+                            let failure_ffi_ty =
+                                failure.to_ffi_compatible_rust_type(swift_bridge_path, types);
+                            let failure_val = failure.convert_rust_expression_to_ffi_type(
+                                &quote! { error },
+                                swift_bridge_path,
+                                types,
+                                Span::call_site(),
+                            );
+
+                            (
+                                quote! {
+                                    on_error: extern "C" fn(*mut std::ffi::c_void, #failure_ffi_ty),
+                                },
+                                // Swift fails on the first error, so the stream stops there.
+                                quote! {
+                                    move |subscriber, item| match item {
+                                        Ok(item) => {
+                                            on_item(subscriber, #output_val);
+                                            true
+                                        }
+                                        Err(error) => {
+                                            on_error(subscriber, #failure_val);
+                                            false
+                                        }
+                                    }
+                                },
+                            )
+                        }
+                        None => (
+                            quote! {},
+                            quote! {
+                                move |subscriber, item| {
+                                    on_item(subscriber, #output_val);
+                                    true
+                                }
+                            },
+                        ),
+                    };
+
+                    quote! {
+                        #swift_closures_support
+
+                        #[export_name = #link_name]
+                        pub extern "C" fn #prefixed_fn_name (
+                            on_item: extern "C" fn(*mut std::ffi::c_void, #output_ffi_ty),
+                            #maybe_on_error
+                            on_complete: extern "C" fn(*mut std::ffi::c_void),
+                            #params
+                        ) -> *mut #swift_bridge_path::stream_support::RustStream {
+                            #maybe_assert_valid_receiver
+                            #swift_bridge_path::stream_support::RustStream::new(
+                                #call_fn,
+                                #on_item,
+                                move |subscriber| on_complete(subscriber),
+                                |task| #swift_bridge_path::async_support::ASYNC_RUNTIME.spawn_task(task),
+                            )
+                        }
+                    }
+                } else if !is_async {
+                    let maybe_inline = self.maybe_inline_attribute();
+
+                    let call_fn = if self.catch_panics.is_some() {
+                        // The extern function returns an FFI representation, which is valid when
+                        // zeroed. The closure is declared outside of the `unsafe` block so that
+                        // the call doesn't end up in an unsafe context.
+                        quote! {
+                            let call = move || { #call_fn };
+                            unsafe { #swift_bridge_path::panic_support::catch_panic(call) }
+                        }
+                    } else {
+                        call_fn
+                    };
+
                     quote! {
+                        #swift_closures_support
+
+                        #maybe_inline
                         #[export_name = #link_name]
                         pub extern "C" fn #prefixed_fn_name ( #params ) #ret {
+                            #maybe_assert_valid_receiver
                             #call_fn
                         }
                     }
                 } else {
                     let (await_fut, call_callback) = if maybe_return_ty.is_some() {
                         let return_ty = self.return_ty_built_in(types).unwrap();
+                        let awaited_val = self.convert_returned_value(quote! {fut.await}, types);
                         let awaited_val = return_ty.convert_rust_expression_to_ffi_type(
-                            &quote! {fut.await},
+                            &awaited_val,
                             swift_bridge_path,
                             types,
                             // TODO: Add a UI test and then add a better span.
@@ -88,12 +195,15 @@ impl ParsedExternFn {
                     };
 
                     quote! {
+                        #swift_closures_support
+
                         #[export_name = #link_name]
                         pub extern "C" fn #prefixed_fn_name (
                             callback_wrapper: *mut std::ffi::c_void,
                             callback: extern "C" fn(*mut std::ffi::c_void #maybe_return_ty) -> (),
                             #params
                         ) {
+                            #maybe_assert_valid_receiver
                             let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
                             let fut = #call_fn;
                             let task = async move {
@@ -110,14 +220,130 @@ impl ParsedExternFn {
                 }
             }
             HostLang::Swift => {
-                quote! {
-                    #[link_name = #link_name]
-                    fn #prefixed_fn_name ( #params ) #ret;
+                if self.sig.asyncness.is_some() {
+                    let maybe_return_ty =
+                        self.maybe_async_rust_fn_return_ty(swift_bridge_path, types);
+
+                    quote! {
+                        #[link_name = #link_name]
+                        fn #prefixed_fn_name (
+                            completion: *mut std::ffi::c_void,
+                            on_complete: extern "C" fn(*mut std::ffi::c_void #maybe_return_ty),
+                            #params
+                        );
+                    }
+                } else {
+                    quote! {
+                        #[link_name = #link_name]
+                        fn #prefixed_fn_name ( #params ) #ret;
+                    }
                 }
             }
         }
     }
 
+    /// Generates a type for each `Box<dyn Fn(A, B) -> C>` argument that holds onto the Swift
+    /// closure that Swift passed to us, along with the Swift functions that it uses to call and
+    /// release the closure.
+    ///
+    /// ```
+    /// #[doc(hidden)]
+    /// pub struct __swift_bridge__some_function_param0(*mut std::ffi::c_void);
+    /// impl __swift_bridge__some_function_param0 {
+    ///     fn call(&self, arg0: u8) {
+    ///         unsafe { __swift_bridge__some_function_param0__call(self.0, arg0) }
+    ///     }
+    /// }
+    /// impl Drop for __swift_bridge__some_function_param0 {
+    ///     fn drop(&mut self) {
+    ///         unsafe { __swift_bridge__some_function_param0__free(self.0) }
+    ///     }
+    /// }
+    /// extern "C" {
+    ///     #[link_name = "__swift_bridge__$some_function$param0"]
+    ///     fn __swift_bridge__some_function_param0__call(callback: *mut std::ffi::c_void, arg0: u8);
+    ///     #[link_name = "__swift_bridge__$some_function$_free$param0"]
+    ///     fn __swift_bridge__some_function_param0__free(callback: *mut std::ffi::c_void);
+    /// }
+    /// ```
+    fn swift_closures_support(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let mut support = vec![];
+
+        for (idx, boxed_fn) in self.args_filtered_to_boxed_fns(types) {
+            let wrapper = self.swift_closure_wrapper_name(idx);
+            let call_fn = Ident::new(&format!("{}__call", wrapper), wrapper.span());
+            let free_fn = Ident::new(&format!("{}__free", wrapper), wrapper.span());
+
+            let call_link_name = self.call_boxed_fn_link_name(idx);
+            let free_link_name = self.free_boxed_fn_link_name(idx);
+
+            let mut params = vec![];
+            let mut ffi_params = vec![];
+            let mut ffi_args = vec![];
+            for (param_idx, ty) in boxed_fn.params.iter().enumerate() {
+                let param_name = Ident::new(&format!("arg{}", param_idx), Span::call_site());
+                let param_ty = ty.to_rust_type_path(types);
+                let ffi_param_ty = ty.to_ffi_compatible_rust_type(swift_bridge_path, types);
+
+                params.push(quote! { #param_name: #param_ty });
+                ffi_params.push(quote! { , #param_name: #ffi_param_ty });
+                ffi_args.push(ty.convert_rust_expression_to_ffi_type(
+                    &quote! { #param_name },
+                    swift_bridge_path,
+                    types,
+                    Span::call_site(),
+                ));
+            }
+
+            let call_swift = quote! { #call_fn(self.0, #(#ffi_args),*) };
+            let (maybe_ret, maybe_ffi_ret) = if boxed_fn.ret.is_null() {
+                (quote! {}, quote! {})
+            } else {
+                let ret = boxed_fn.ret.to_rust_type_path(types);
+                let ffi_ret = boxed_fn
+                    .ret
+                    .to_ffi_compatible_rust_type(swift_bridge_path, types);
+                (quote! { -> #ret }, quote! { -> #ffi_ret })
+            };
+            let call_swift = boxed_fn.ret.convert_ffi_expression_to_rust_type(
+                &quote! { unsafe { #call_swift } },
+                Span::call_site(),
+                swift_bridge_path,
+                types,
+            );
+
+            support.push(quote! {
+                #[doc(hidden)]
+                #[allow(non_camel_case_types)]
+                pub struct #wrapper(*mut std::ffi::c_void);
+                impl #wrapper {
+                    fn call(&self, #(#params),*) #maybe_ret {
+                        #call_swift
+                    }
+                }
+                impl Drop for #wrapper {
+                    fn drop(&mut self) {
+                        unsafe { #free_fn(self.0) }
+                    }
+                }
+                extern "C" {
+                    #[link_name = #call_link_name]
+                    fn #call_fn(callback: *mut std::ffi::c_void #(#ffi_params)*) #maybe_ffi_ret;
+                    #[link_name = #free_link_name]
+                    fn #free_fn(callback: *mut std::ffi::c_void);
+                }
+            });
+        }
+
+        quote! {
+            #(#support)*
+        }
+    }
+
     fn call_fn_tokens(&self, swift_bridge_path: &Path, types: &TypeDeclarations) -> TokenStream {
         let sig = &self.func.sig;
         let fn_name = if let Some(fn_name) = self.rust_name_override.as_ref() {
@@ -131,6 +357,15 @@ impl ParsedExternFn {
                 #fn_name
             }
         };
+        let fn_name = match self.instantiation.as_ref() {
+            Some(instantiation) => {
+                let ty = BridgedType::new_with_type(&instantiation.ty, types)
+                    .unwrap()
+                    .to_rust_type_path(types);
+                quote! { #fn_name::<#ty> }
+            }
+            None => fn_name,
+        };
 
         let call_args = self.to_call_rust_args(swift_bridge_path, types);
 
@@ -139,25 +374,27 @@ impl ParsedExternFn {
         };
 
         let mut call_fn = if self.is_method() {
-            self.call_method_tokens(&call_fn)
+            self.call_method_tokens(&call_fn, &call_args, swift_bridge_path)
         } else {
-            self.call_function_tokens(&call_fn)
+            self.call_function_tokens(&call_fn, types)
         };
 
-        let return_ty = self.return_ty_built_in(types).unwrap();
-
-        if self.return_into {
-            call_fn = return_ty.rust_expression_into(&call_fn);
-        }
-
-        if let Some(return_with) = self.return_with.as_ref() {
+        if self.return_scratch {
             call_fn = quote! {
-                super:: #return_with ( #call_fn )
-            }
+                unsafe {
+                    #swift_bridge_path::scratch_support::write_scratch_str(|scratch| {
+                        #call_fn;
+                    })
+                }
+            };
         }
 
-        // Async functions get this conversion done after awaiting the returned future.
+        let return_ty = self.return_ty_built_in(types).unwrap();
+
+        // Async functions get these conversions done after awaiting the returned future.
         if self.sig.asyncness.is_none() {
+            call_fn = self.convert_returned_value(call_fn, types);
+
             let fn_span = self.func.span();
             call_fn = return_ty.convert_rust_expression_to_ffi_type(
                 &call_fn,
@@ -170,8 +407,36 @@ impl ParsedExternFn {
         call_fn
     }
 
+    /// Apply the `return_into` and `return_with` attributes to the value that the Rust function
+    /// returned.
+    fn convert_returned_value(
+        &self,
+        returned: TokenStream,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let mut returned = returned;
+
+        if self.return_into {
+            let return_ty = self.return_ty_built_in(types).unwrap();
+            returned = return_ty.rust_expression_into(&returned);
+        }
+
+        if let Some(return_with) = self.return_with.as_ref() {
+            returned = quote! {
+                super:: #return_with ( #returned )
+            }
+        }
+
+        returned
+    }
+
     /// Generate tokens for calling a method.
-    fn call_method_tokens(&self, call_fn: &TokenStream) -> TokenStream {
+    fn call_method_tokens(
+        &self,
+        call_fn: &TokenStream,
+        call_args: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
         let this = if self.is_copy_method_on_opaque_type() {
             quote! {
                 this.into_rust_repr()
@@ -186,11 +451,17 @@ impl ParsedExternFn {
                 }
             } else {
                 quote! {
-                    ( * unsafe { Box::from_raw(this) } )
+                    ( * unsafe { #swift_bridge_path::leak_check::box_from_raw(this) } )
                 }
             }
         };
 
+        if let Some(field_name) = self.set_field.as_ref() {
+            return quote! {
+                #this . #field_name = #call_args
+            };
+        }
+
         match &self.get_field {
             Some(GetField::Direct(get_direct)) => {
                 let GetFieldDirect {
@@ -222,7 +493,7 @@ impl ParsedExternFn {
     }
 
     /// Generate tokens for calling a freestanding or an associated function.
-    fn call_function_tokens(&self, call_fn: &TokenStream) -> TokenStream {
+    fn call_function_tokens(&self, call_fn: &TokenStream, types: &TypeDeclarations) -> TokenStream {
         let maybe_associated_type = self.associated_type.as_ref().map(|ty| {
             match ty {
                 TypeDeclaration::Shared(_) => {
@@ -230,8 +501,13 @@ impl ParsedExternFn {
                     todo!()
                 }
                 TypeDeclaration::Opaque(ty) => {
+                    let generics = ty.generics.angle_bracketed_concrete_generics_tokens(types);
                     let ty = &ty.ty;
-                    quote! {#ty::}
+                    if generics.is_empty() {
+                        quote! {#ty::}
+                    } else {
+                        quote! {#ty::#generics::}
+                    }
                 }
             }
         });
@@ -335,7 +611,7 @@ mod tests {
             &function.to_extern_c_function_tokens(
                 &module.swift_bridge_path,
                 &module.types,
-                &mut HashMap::new(),
+                &mut BTreeMap::new(),
             ),
             &expected_fn,
         );
@@ -1,9 +1,9 @@
-use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType};
+use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, StdLibType};
 use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
 use proc_macro2::{Ident, TokenStream};
-use quote::{quote, ToTokens};
-use std::collections::HashMap;
+use quote::quote;
+use std::collections::BTreeMap;
 use std::ops::Deref;
 use syn::spanned::Spanned;
 use syn::{FnArg, Path, Type};
@@ -13,7 +13,7 @@ impl ParsedExternFn {
         &self,
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
-        custom_type_definitions: &mut HashMap<String, TokenStream>,
+        custom_type_definitions: &mut BTreeMap<String, TokenStream>,
     ) -> TokenStream {
         let mut params = vec![];
         let inputs = &self.func.sig.inputs;
@@ -23,7 +23,7 @@ impl ParsedExternFn {
                     HostLang::Rust => {
                         let this = match self.associated_type.as_ref().unwrap() {
                             TypeDeclaration::Opaque(opaque) => {
-                                let opaque_ty_ffi_repr = opaque.ffi_repr_type_tokens();
+                                let opaque_ty_ffi_repr = opaque.ffi_repr_type_tokens(types);
                                 quote! { this: #opaque_ty_ffi_repr }
                             }
                             TypeDeclaration::Shared(_) => {
@@ -55,7 +55,20 @@ impl ParsedExternFn {
                             }
 
                             let pat = &pat_ty.pat;
-                            let ty = built_in.to_ffi_compatible_rust_type(swift_bridge_path, types);
+                            let ty = match &built_in {
+                                // Swift passes us a pointer to a retained Swift closure.
+                                BridgedType::StdLib(StdLibType::BoxedFnOnce(_))
+                                    if self.host_lang.is_rust() =>
+                                {
+                                    quote! { *mut std::ffi::c_void }
+                                }
+                                BridgedType::StdLib(StdLibType::Str)
+                                    if self.passes_small_strs() =>
+                                {
+                                    quote! { #swift_bridge_path::string::SmallRustStr }
+                                }
+                                _ => built_in.to_ffi_compatible_rust_type(swift_bridge_path, types),
+                            };
 
                             params.push(quote! { #pat: #ty});
 
@@ -85,14 +98,11 @@ impl ParsedExternFn {
                         bridged_type = &ty_ref.elem;
                     };
 
-                    let arg_ty = match types
-                        .get(&bridged_type.to_token_stream().to_string())
-                        .unwrap()
-                    {
+                    let arg_ty = match types.get_with_type(bridged_type).unwrap() {
                         TypeDeclaration::Shared(_) => {
                             todo!("Support methods on shared types.")
                         }
-                        TypeDeclaration::Opaque(opaque) => opaque.ffi_repr_type_tokens(),
+                        TypeDeclaration::Opaque(opaque) => opaque.ffi_repr_type_tokens(types),
                     };
 
                     params.push(quote! {
@@ -139,7 +149,7 @@ mod tests {
                 &method.to_extern_c_param_names_and_types(
                     &module.swift_bridge_path,
                     &module.types,
-                    &mut HashMap::new(),
+                    &mut BTreeMap::new(),
                 ),
                 &quote! { this },
             );
@@ -169,7 +179,7 @@ mod tests {
             &funcs[0].to_extern_c_param_names_and_types(
                 &module.swift_bridge_path,
                 &module.types,
-                &mut HashMap::new(),
+                &mut BTreeMap::new(),
             ),
             expected_params,
         );
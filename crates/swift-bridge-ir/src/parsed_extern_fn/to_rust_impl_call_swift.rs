@@ -35,7 +35,7 @@ impl ParsedExternFn {
         types: &TypeDeclarations,
     ) -> TokenStream {
         let sig = &self.func.sig;
-        let fn_name = &sig.ident;
+        let fn_name = self.extern_swift_rust_fn_name();
 
         let ret = &sig.output;
 
@@ -59,28 +59,149 @@ impl ParsedExternFn {
         let call_args = self.to_call_rust_args(swift_bridge_path, types);
         let linked_fn_name = self.extern_swift_linked_fn_new();
 
+        if sig.asyncness.is_some() {
+            return self.to_rust_fn_that_calls_an_async_swift_extern(
+                &params,
+                &call_args,
+                &linked_fn_name,
+                swift_bridge_path,
+                types,
+            );
+        }
+
         let mut inner = quote! {
             unsafe { #linked_fn_name(#call_args) }
         };
 
         if let Some(built_in) = BridgedType::new_with_return_type(&sig.output, types) {
-            inner = built_in.convert_ffi_expression_to_rust_type(
-                &inner,
-                sig.output.span(),
-                swift_bridge_path,
-                types,
-            );
+            // Converting a `Result` reads the returned value more than once, so we make sure to
+            // only call the Swift function once.
+            if built_in.is_result() {
+                let converted = built_in.convert_ffi_expression_to_rust_type(
+                    &quote! { val },
+                    sig.output.span(),
+                    swift_bridge_path,
+                    types,
+                );
+                inner = quote! {
+                    let val = #inner;
+                    #converted
+                };
+            } else {
+                inner = built_in.convert_ffi_expression_to_rust_type(
+                    &inner,
+                    sig.output.span(),
+                    swift_bridge_path,
+                    types,
+                );
+            }
         } else {
             todo!("Push to ParsedErrors")
         }
 
+        let maybe_inline = self.maybe_inline_attribute();
+
         quote! {
+            #maybe_inline
             pub fn #fn_name(#params) #ret {
                 #inner
             }
         }
     }
 
+    /// Generates a Rust function that returns a future that resolves once the async Swift function
+    /// that it calls completes.
+    ///
+    /// ```ignore
+    /// pub fn some_function(arg: u8) -> impl std::future::Future<Output = u32> {
+    ///     extern "C" fn on_complete(completion: *mut std::ffi::c_void, val: u32) {
+    ///         unsafe { swift_bridge::async_support::SwiftFuture::<u32>::complete(completion, val) }
+    ///     }
+    ///
+    ///     let (future, completion) = swift_bridge::async_support::SwiftFuture::<u32>::new();
+    ///     unsafe { __swift_bridge__some_function(completion, on_complete, arg) };
+    ///     future
+    /// }
+    /// ```
+    fn to_rust_fn_that_calls_an_async_swift_extern(
+        &self,
+        params: &TokenStream,
+        call_args: &TokenStream,
+        linked_fn_name: &Ident,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let sig = &self.func.sig;
+        let fn_name = self.extern_swift_rust_fn_name();
+
+        let ret = if let Some(built_in) = BridgedType::new_with_return_type(&sig.output, types) {
+            built_in
+        } else {
+            todo!("Push to ParsedErrors")
+        };
+        let output_ty = if ret.is_null() {
+            quote! { () }
+        } else {
+            ret.maybe_convert_pointer_to_super_pointer(types)
+        };
+
+        let on_complete = if ret.is_null() {
+            quote! {
+                extern "C" fn on_complete(completion: *mut std::ffi::c_void) {
+                    unsafe { #swift_bridge_path::async_support::SwiftFuture::<()>::complete(completion, ()) }
+                }
+            }
+        } else {
+            let ffi_ty = ret.to_ffi_compatible_rust_type(swift_bridge_path, types);
+            let val = quote! { val };
+            let converted_val = ret.convert_ffi_expression_to_rust_type(
+                &val,
+                sig.output.span(),
+                swift_bridge_path,
+                types,
+            );
+            let maybe_convert_val = if converted_val.to_string() == val.to_string() {
+                quote! {}
+            } else {
+                quote! { let val = #converted_val; }
+            };
+
+            quote! {
+                extern "C" fn on_complete(completion: *mut std::ffi::c_void, val: #ffi_ty) {
+                    #maybe_convert_val
+                    unsafe { #swift_bridge_path::async_support::SwiftFuture::<#output_ty>::complete(completion, val) }
+                }
+            }
+        };
+
+        let maybe_call_args = if call_args.is_empty() {
+            quote! {}
+        } else {
+            quote! { , #call_args }
+        };
+
+        quote! {
+            pub fn #fn_name(#params) -> impl std::future::Future<Output = #output_ty> {
+                #on_complete
+
+                let (future, completion) = #swift_bridge_path::async_support::SwiftFuture::<#output_ty>::new();
+                unsafe { #linked_fn_name(completion, on_complete #maybe_call_args) };
+                future
+            }
+        }
+    }
+
+    /// The name of the Rust function that calls the Swift function.
+    ///
+    /// This is the name that the function was declared with, unless it was renamed using
+    /// `#[swift_bridge(rust_name = "...")]`.
+    fn extern_swift_rust_fn_name(&self) -> Ident {
+        match self.rust_name_override.as_ref() {
+            Some(rust_name) => Ident::new(&rust_name.value(), rust_name.span()),
+            None => self.func.sig.ident.clone(),
+        }
+    }
+
     /// #\[export_name = "__swift_bridge__$SomeType$some_method$param1"]
     /// pub extern "C" fn SomeType_some_method_param1(boxed_fn: *mut dyn FnOnce(u8) -> (), arg0: u8) {
     ///     unsafe { Box::from_raw(boxed_fn) }(arg0)
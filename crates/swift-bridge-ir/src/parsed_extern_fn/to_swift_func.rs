@@ -11,6 +11,16 @@ impl ParsedExternFn {
         include_receiver_if_present: bool,
         types: &TypeDeclarations,
     ) -> String {
+        self.to_swift_params(include_receiver_if_present, types)
+            .join(", ")
+    }
+
+    /// The Swift parameters, such as `_ arg: UInt8`, one entry per parameter.
+    pub fn to_swift_params(
+        &self,
+        include_receiver_if_present: bool,
+        types: &TypeDeclarations,
+    ) -> Vec<String> {
         let mut params: Vec<String> = vec![];
 
         for (arg_idx, arg) in self.func.sig.inputs.iter().enumerate() {
@@ -48,7 +58,12 @@ impl ParsedExternFn {
                     if let Some(argument_label) =
                         self.argument_labels.get(&format_ident!("{}", arg_name))
                     {
-                        format!("{} {}: {}", argument_label.value().as_str(), arg_name, ty)
+                        // `from from: UInt32` is the same as `from: UInt32`.
+                        if argument_label.value() == arg_name {
+                            format!("{}: {}", arg_name, ty)
+                        } else {
+                            format!("{} {}: {}", argument_label.value().as_str(), arg_name, ty)
+                        }
                     } else {
                         format!("_ {}: {}", arg_name, ty)
                     }
@@ -57,7 +72,7 @@ impl ParsedExternFn {
             params.push(param)
         }
 
-        params.join(", ")
+        params
     }
 
     // fn foo (&self, arg1: u8, arg2: u32)
@@ -127,7 +142,11 @@ impl ParsedExternFn {
                             todo!("Push to ParsedErrors")
                         };
                     let arg = if include_var_name {
-                        format!("{}: {}", arg_name, arg)
+                        match self.argument_labels.get(&format_ident!("{}", arg_name)) {
+                            Some(label) if label.value() == "_" => arg,
+                            Some(label) => format!("{}: {}", label.value(), arg),
+                            None => format!("{}: {}", arg_name, arg),
+                        }
                     } else {
                         arg
                     };
@@ -140,6 +159,16 @@ impl ParsedExternFn {
     }
 
     pub fn to_swift_return_type(&self, types: &TypeDeclarations) -> String {
+        if let Some((output, failure)) = self.stream_swift_types(types) {
+            return if self.publisher {
+                format!(" -> AnyPublisher<{output}, {failure}>")
+            } else if failure == "Never" {
+                format!(" -> AsyncStream<{output}>")
+            } else {
+                format!(" -> AsyncThrowingStream<{output}, {failure}>")
+            };
+        }
+
         match &self.func.sig.output {
             ReturnType::Default => "".to_string(),
             ReturnType::Type(_, ty) => {
@@ -150,7 +179,12 @@ impl ParsedExternFn {
                         }
                     }
 
-                    let maybe_throws = if built_in.is_result() { "throws " } else { "" };
+                    // Swift implementations return their `Result` to Rust instead of throwing it.
+                    let maybe_throws = if built_in.is_result() && self.host_lang.is_rust() {
+                        "throws "
+                    } else {
+                        ""
+                    };
 
                     format!(
                         " {}-> {}",
@@ -166,7 +200,20 @@ impl ParsedExternFn {
 
     fn push_receiver_as_arg(&self, args: &mut Vec<String>, is_reference: bool) {
         let arg = if self.is_copy_method_on_opaque_type() {
-            "self.bytes"
+            let is_generic = self
+                .associated_type
+                .as_ref()
+                .and_then(|ty| ty.as_opaque())
+                .map(|ty| !ty.generics.is_empty())
+                .unwrap_or(false);
+
+            // Generic Copy types hold their bytes as an existential, so we use the concretely typed
+            // accessor.
+            if is_generic {
+                "self.intoFfiRepr()"
+            } else {
+                "self.bytes"
+            }
         } else {
             if is_reference {
                 "ptr"
@@ -195,8 +242,8 @@ mod tests {
                 extern "Rust" {
                     type Foo;
                     fn make1 () -> Foo;
-                    fn make2 () -> &Foo;
-                    fn make3 () -> &mut Foo;
+                    fn make2 () -> &'static Foo;
+                    fn make3 () -> &'static mut Foo;
                 }
             }
         };
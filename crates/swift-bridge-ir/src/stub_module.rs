@@ -0,0 +1,312 @@
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::quote;
+use std::collections::BTreeMap;
+use syn::{Abi, Attribute, FnArg, ForeignItem, ForeignItemFn, Item, ItemForeignMod, ItemMod, Type};
+
+/// Generate stand-ins for the items of a bridge module that failed to parse.
+///
+/// The macro only emits a compile error when a bridge module fails to parse, which leaves
+/// rust-analyzer unable to resolve anything that the module declares while it is being edited.
+/// Emitting the stand-ins alongside the error keeps completions and go-to-definition working for
+/// the module's shared structs and enums, its opaque Swift types and the Swift functions that
+/// Rust calls, which are declared with the same signatures as in the bridge module and with
+/// `unimplemented!()` bodies.
+///
+/// Returns `None` if the tokens aren't a module.
+pub fn generate_stub_module(tokens: TokenStream) -> Option<TokenStream> {
+    let item_mod: ItemMod = syn::parse2(tokens).ok()?;
+    let mod_name = &item_mod.ident;
+    let mod_attrs = without_swift_bridge_attrs(&item_mod.attrs);
+
+    let mut items = vec![];
+    let mut impls: BTreeMap<String, (Ident, Vec<TokenStream>)> = BTreeMap::new();
+
+    for item in item_mod.content.iter().flat_map(|(_, items)| items) {
+        match item {
+            Item::Struct(item_struct) => {
+                let mut item_struct = item_struct.clone();
+                item_struct.attrs = without_swift_bridge_attrs(&item_struct.attrs);
+                item_struct.vis = syn::parse_quote! { pub };
+                for field in item_struct.fields.iter_mut() {
+                    field.attrs = without_swift_bridge_attrs(&field.attrs);
+                    field.vis = syn::parse_quote! { pub };
+                }
+                items.push(quote! { #item_struct });
+            }
+            Item::Enum(item_enum) => {
+                let mut item_enum = item_enum.clone();
+                item_enum.attrs = without_swift_bridge_attrs(&item_enum.attrs);
+                item_enum.vis = syn::parse_quote! { pub };
+                for variant in item_enum.variants.iter_mut() {
+                    variant.attrs = without_swift_bridge_attrs(&variant.attrs);
+                }
+                items.push(quote! { #item_enum });
+            }
+            Item::ForeignMod(foreign_mod) if is_extern_swift(&foreign_mod.abi) => {
+                stub_extern_swift_items(foreign_mod, &mut items, &mut impls);
+            }
+            _ => {}
+        }
+    }
+
+    let impls = impls.into_values().map(|(ty, fns)| {
+        quote! {
+            impl #ty {
+                #(#fns)*
+            }
+        }
+    });
+
+    Some(quote! {
+        #[allow(non_snake_case, unused)]
+        #(#mod_attrs)*
+        mod #mod_name {
+            // Types from the parent module, such as opaque Rust types, are used in signatures.
+            use super::*;
+
+            #(#items)*
+            #(#impls)*
+        }
+    })
+}
+
+/// Declare the opaque Swift types and the functions that Rust calls Swift with.
+fn stub_extern_swift_items(
+    foreign_mod: &ItemForeignMod,
+    items: &mut Vec<TokenStream>,
+    impls: &mut BTreeMap<String, (Ident, Vec<TokenStream>)>,
+) {
+    let declared_types: Vec<&Ident> = foreign_mod
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ForeignItem::Type(ty) => Some(&ty.ident),
+            _ => None,
+        })
+        .collect();
+
+    for item in &foreign_mod.items {
+        match item {
+            ForeignItem::Type(ty) => {
+                if has_swift_bridge_arg(&ty.attrs, "already_declared") {
+                    continue;
+                }
+                let ty = &ty.ident;
+                items.push(quote! {
+                    #[repr(C)]
+                    pub struct #ty(pub(crate) *mut std::ffi::c_void);
+                });
+            }
+            ForeignItem::Fn(func) => {
+                let stub = stub_fn(func);
+
+                let associated_type = match func.sig.receiver() {
+                    Some(FnArg::Typed(pat_ty)) => type_ident(&pat_ty.ty),
+                    Some(FnArg::Receiver(_)) if declared_types.len() == 1 => {
+                        Some(declared_types[0].clone())
+                    }
+                    Some(FnArg::Receiver(_)) => None,
+                    None => associated_to(&func.attrs),
+                };
+
+                match associated_type {
+                    Some(ty) => impls
+                        .entry(ty.to_string())
+                        .or_insert_with(|| (ty, vec![]))
+                        .1
+                        .push(stub),
+                    None if func.sig.receiver().is_none() => items.push(stub),
+                    // A method whose type we can't tell can't be declared.
+                    None => {}
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `pub fn some_function(arg: u8) -> u16 { unimplemented!() }`
+fn stub_fn(func: &ForeignItemFn) -> TokenStream {
+    let mut sig = func.sig.clone();
+    for arg in sig.inputs.iter_mut() {
+        match arg {
+            FnArg::Receiver(receiver) => {
+                receiver.attrs = without_swift_bridge_attrs(&receiver.attrs);
+            }
+            FnArg::Typed(pat_ty) => {
+                pat_ty.attrs = without_swift_bridge_attrs(&pat_ty.attrs);
+            }
+        }
+    }
+    let attrs = without_swift_bridge_attrs(&func.attrs);
+
+    quote! {
+        #(#attrs)*
+        pub #sig {
+            unimplemented!()
+        }
+    }
+}
+
+fn is_extern_swift(abi: &Abi) -> bool {
+    abi.name
+        .as_ref()
+        .map(|name| name.value() == "Swift")
+        .unwrap_or(false)
+}
+
+fn without_swift_bridge_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| !attr.path.is_ident("swift_bridge"))
+        .cloned()
+        .collect()
+}
+
+/// Whether one of the `#[swift_bridge(...)]` attributes contains the argument, such as
+/// `already_declared`.
+fn has_swift_bridge_arg(attrs: &[Attribute], arg: &str) -> bool {
+    swift_bridge_attr_tokens(attrs)
+        .any(|token| matches!(token, TokenTree::Ident(ident) if ident == arg))
+}
+
+/// The `SomeType` in `#[swift_bridge(associated_to = SomeType)]`.
+fn associated_to(attrs: &[Attribute]) -> Option<Ident> {
+    let tokens: Vec<TokenTree> = swift_bridge_attr_tokens(attrs).collect();
+
+    tokens.windows(3).find_map(|window| match window {
+        [TokenTree::Ident(key), TokenTree::Punct(eq), TokenTree::Ident(ty)]
+            if key == "associated_to" && eq.as_char() == '=' =>
+        {
+            Some(ty.clone())
+        }
+        _ => None,
+    })
+}
+
+/// The tokens inside of the parentheses of each `#[swift_bridge(...)]` attribute.
+fn swift_bridge_attr_tokens(attrs: &[Attribute]) -> impl Iterator<Item = TokenTree> + '_ {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("swift_bridge"))
+        .flat_map(|attr| attr.tokens.clone())
+        .flat_map(|token| match token {
+            TokenTree::Group(group) => group.stream().into_iter().collect(),
+            token => vec![token],
+        })
+}
+
+/// The `SomeType` in `&SomeType`, `&mut SomeType` or `SomeType`.
+fn type_ident(ty: &Type) -> Option<Ident> {
+    match ty {
+        Type::Reference(reference) => type_ident(&reference.elem),
+        Type::Path(path) => path.path.get_ident().cloned(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_tokens_eq;
+
+    /// Verify that we generate stand-ins for the items that a bridge module declares, without
+    /// the `#[swift_bridge(...)]` attributes.
+    #[test]
+    fn generates_stubs() {
+        let tokens = quote! {
+            #[cfg(feature = "some-feature")]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    #[swift_bridge(swift_name = "someField")]
+                    field: u8,
+                }
+
+                enum SomeEnum {
+                    Variant,
+                }
+
+                extern "Rust" {
+                    type SomeRustType;
+                    fn rust_function(arg: u8);
+                }
+
+                extern "Swift" {
+                    type SomeSwiftType;
+
+                    fn swift_function(#[swift_bridge(label = "argLabel")] arg: u8) -> u16;
+                    fn method(&self, arg: Strng);
+                    #[swift_bridge(associated_to = SomeSwiftType)]
+                    fn associated_function();
+                }
+            }
+        };
+
+        let expected = quote! {
+            #[allow(non_snake_case, unused)]
+            #[cfg(feature = "some-feature")]
+            mod ffi {
+                use super::*;
+
+                pub struct SomeStruct {
+                    pub field: u8,
+                }
+
+                pub enum SomeEnum {
+                    Variant,
+                }
+
+                #[repr(C)]
+                pub struct SomeSwiftType(pub(crate) *mut std::ffi::c_void);
+
+                pub fn swift_function(arg: u8) -> u16 {
+                    unimplemented!()
+                }
+
+                impl SomeSwiftType {
+                    pub fn method(&self, arg: Strng) {
+                        unimplemented!()
+                    }
+
+                    pub fn associated_function() {
+                        unimplemented!()
+                    }
+                }
+            }
+        };
+
+        assert_tokens_eq(&generate_stub_module(tokens).unwrap(), &expected);
+    }
+
+    /// Verify that we don't declare opaque Swift types that are declared in another bridge
+    /// module.
+    #[test]
+    fn skips_already_declared_types() {
+        let tokens = quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(already_declared)]
+                    type SomeSwiftType;
+
+                    fn method(&self);
+                }
+            }
+        };
+
+        let expected = quote! {
+            #[allow(non_snake_case, unused)]
+            mod ffi {
+                use super::*;
+
+                impl SomeSwiftType {
+                    pub fn method(&self) {
+                        unimplemented!()
+                    }
+                }
+            }
+        };
+
+        assert_tokens_eq(&generate_stub_module(tokens).unwrap(), &expected);
+    }
+}
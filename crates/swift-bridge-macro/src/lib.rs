@@ -1,5 +1,5 @@
 use quote::quote;
-use swift_bridge_ir::{SwiftBridgeModule, SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs};
+use swift_bridge_ir::{generate_stub_module, SwiftBridgeModule, SwiftBridgeModuleAttrs};
 use syn::parse_macro_input;
 
 #[proc_macro_attribute]
@@ -8,15 +8,18 @@ pub fn bridge(
     input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
     let args = parse_macro_input!(args as SwiftBridgeModuleAttrs);
-    let mut module = parse_macro_input!(input as SwiftBridgeModule);
-
-    for arg in args.attributes {
-        match arg {
-            SwiftBridgeModuleAttr::SwiftBridgePath(path) => {
-                module.set_swift_bridge_path(path);
-            }
+    let mut module = match syn::parse::<SwiftBridgeModule>(input.clone()) {
+        Ok(module) => module,
+        Err(error) => {
+            // Keep the module's items around so that editors can still resolve them while the
+            // module is being fixed.
+            let mut tokens = error.to_compile_error();
+            tokens.extend(generate_stub_module(input.into()));
+            return tokens.into();
         }
-    }
+    };
+
+    module.apply_attributes(args);
 
     let tokens = quote! {
         #module
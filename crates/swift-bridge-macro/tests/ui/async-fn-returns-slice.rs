@@ -0,0 +1,17 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=async-fn-returns-slice.rs
+//!
+//! Verify that we reject async extern "Rust" functions that return slices.
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SomeType;
+
+        async fn items(&self) -> &[SomeType];
+    }
+}
+
+pub struct SomeType;
+
+fn main() {}
@@ -0,0 +1,24 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=invalid-initializer-return.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SomeType;
+        type AnotherType;
+
+        #[swift_bridge(init)]
+        fn new();
+
+        #[swift_bridge(init)]
+        fn from_u8(value: u8) -> u8;
+
+        #[swift_bridge(init, associated_to = SomeType)]
+        fn another() -> AnotherType;
+    }
+}
+
+pub struct SomeType;
+pub struct AnotherType;
+
+fn main() {}
@@ -0,0 +1,13 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=mutable-borrow-with-callback.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SomeType;
+
+        fn some_method(&mut self, callback: Box<dyn Fn()>);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=nested-option.rs
+//!
+//! Verify that we reject nested `Option`s.
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn some_function(arg: Option<Option<u8>>);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=returns-borrow-without-borrowed-args.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn some_function() -> &str;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=sendable-type-not-send-sync.rs
+
+use std::rc::Rc;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Sendable)]
+        type SomeType;
+    }
+}
+
+pub struct SomeType(Rc<u8>);
+
+fn main() {}
@@ -0,0 +1,35 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=stub-module-on-error.rs
+//!
+//! Verify that a bridge module that fails to parse only reports the parse error, and not errors
+//! for the code that uses the module's items.
+
+#[swift_bridge::bridge]
+mod ffi {
+    struct SomeStruct {
+        field: u8,
+    }
+
+    extern "Rust" {
+        type SomeRustType;
+
+        #[swift_bridge(InvalidAttribute)]
+        fn some_function();
+    }
+
+    extern "Swift" {
+        type SomeSwiftType;
+
+        fn swift_function(arg: SomeStruct) -> u8;
+        fn swift_method(&self, arg: &SomeRustType);
+    }
+}
+
+pub struct SomeRustType;
+
+fn use_items(swift_type: ffi::SomeSwiftType) {
+    let _: u8 = ffi::swift_function(ffi::SomeStruct { field: 1 });
+    swift_type.swift_method(&SomeRustType);
+}
+
+fn main() {}
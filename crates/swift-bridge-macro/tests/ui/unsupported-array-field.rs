@@ -0,0 +1,18 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=unsupported-array-field.rs
+//!
+//! Verify that we reject array fields in shared structs and enum variants.
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct SomeStruct {
+        field: [u8; 4],
+    }
+
+    enum SomeEnum {
+        Variant([u8; 4]),
+    }
+}
+
+fn main() {}
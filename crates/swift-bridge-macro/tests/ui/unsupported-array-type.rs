@@ -0,0 +1,19 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=unsupported-array-type.rs
+//!
+//! Verify that we reject arrays that aren't primitives with an integer literal length greater
+//! than zero.
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn empty_array(arg: [u8; 0]);
+        fn nested_array(arg: [[u8; 2]; 2]);
+        fn array_of_strings(arg: [String; 2]);
+        fn const_length(arg: [u8; N]);
+    }
+}
+
+const N: usize = 4;
+
+fn main() {}
@@ -0,0 +1,20 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=unsupported-path-field.rs
+//!
+//! Verify that we reject `PathBuf` fields in shared structs and enum variants.
+
+use std::path::PathBuf;
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct SomeStruct {
+        field: PathBuf,
+    }
+
+    enum SomeEnum {
+        Variant(PathBuf),
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=unsupported-signature.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SomeType;
+        type GenericType<'a>;
+
+        fn invalid_self(self: [SomeType; 2]);
+        fn borrowed_self_pattern(&self: SomeType);
+
+        #[swift_bridge(associated_to = UndeclaredType)]
+        fn new() -> u8;
+    }
+}
+
+pub struct SomeType;
+
+fn main() {}
@@ -0,0 +1,20 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=unsupported-swift-to-rust-type.rs
+//!
+//! Verify that we reject types that can only be passed from Rust to Swift when Swift would need
+//! to pass them to Rust.
+
+use std::borrow::Cow;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn take_cow(arg: Cow<'static, str>);
+    }
+
+    extern "Swift" {
+        fn return_cow() -> Cow<'static, str>;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=unsupported-type-suggestion.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn vec_of_borrows(arg: Vec<&str>);
+        fn std_path(arg: std::string::String);
+        fn misspelled(arg: Option<Strng>);
+    }
+}
+
+fn vec_of_borrows(_arg: Vec<&str>) {}
+fn std_path(_arg: String) {}
+fn misspelled(_arg: Option<String>) {}
+
+fn main() {}
@@ -0,0 +1,51 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/actor_codegen_tests.rs
+
+use ffi::SwiftCounterActor;
+use std::sync::Arc;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Swift" {
+        #[swift_bridge(actor)]
+        type SwiftCounterActor;
+
+        #[swift_bridge(init)]
+        fn new(start: u32) -> SwiftCounterActor;
+
+        fn increment(&self, by: u32) -> u32;
+        async fn slow_count(&self) -> u32;
+    }
+
+    extern "Rust" {
+        /// Increments the counter `times` times from separate threads, and then returns its count.
+        async fn rust_increment_swift_actor_concurrently(
+            counter: SwiftCounterActor,
+            times: u32,
+        ) -> u32;
+
+        /// Creates a counter in Rust, increments it and then returns its count.
+        async fn rust_create_and_increment_swift_actor(start: u32, by: u32) -> u32;
+    }
+}
+
+async fn rust_increment_swift_actor_concurrently(counter: SwiftCounterActor, times: u32) -> u32 {
+    let counter = Arc::new(counter);
+
+    // Each call starts running on the actor right away, so the increments overlap.
+    let increments: Vec<_> = (0..times)
+        .map(|_| {
+            let counter = counter.clone();
+            std::thread::spawn(move || counter.increment(1))
+        })
+        .collect();
+    for increment in increments {
+        increment.join().unwrap().await;
+    }
+
+    counter.slow_count().await
+}
+
+async fn rust_create_and_increment_swift_actor(start: u32, by: u32) -> u32 {
+    let counter = SwiftCounterActor::new(start);
+    counter.increment(by).await
+}
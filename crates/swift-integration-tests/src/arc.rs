@@ -0,0 +1,85 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type ArcTestType;
+
+        fn value(&self) -> u32;
+
+        fn rust_make_arc_test_type(value: u32) -> Arc<ArcTestType>;
+        fn rust_reflect_arc_test_type(arg: Arc<ArcTestType>) -> Arc<ArcTestType>;
+        fn rust_arc_test_type_strong_count(arg: Arc<ArcTestType>) -> usize;
+        fn rust_store_arc_test_type(arg: Arc<ArcTestType>);
+        fn rust_drop_stored_arc_test_type();
+        fn arc_test_type_live_count() -> u32;
+
+        fn test_rust_calls_swift_fn_reflects_arc();
+    }
+
+    extern "Swift" {
+        fn swift_reflect_arc_test_type(arg: Arc<ArcTestType>) -> Arc<ArcTestType>;
+    }
+}
+
+static LIVE_COUNT: AtomicU32 = AtomicU32::new(0);
+
+thread_local! {
+    static STORED: RefCell<Option<Arc<ArcTestType>>> = const { RefCell::new(None) };
+}
+
+pub struct ArcTestType {
+    value: u32,
+}
+
+impl ArcTestType {
+    fn new(value: u32) -> Self {
+        LIVE_COUNT.fetch_add(1, Ordering::SeqCst);
+        ArcTestType { value }
+    }
+
+    fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+impl Drop for ArcTestType {
+    fn drop(&mut self) {
+        LIVE_COUNT.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn rust_make_arc_test_type(value: u32) -> Arc<ArcTestType> {
+    Arc::new(ArcTestType::new(value))
+}
+
+fn rust_reflect_arc_test_type(arg: Arc<ArcTestType>) -> Arc<ArcTestType> {
+    arg
+}
+
+fn rust_arc_test_type_strong_count(arg: Arc<ArcTestType>) -> usize {
+    Arc::strong_count(&arg)
+}
+
+fn rust_store_arc_test_type(arg: Arc<ArcTestType>) {
+    STORED.with(|stored| *stored.borrow_mut() = Some(arg));
+}
+
+fn rust_drop_stored_arc_test_type() {
+    STORED.with(|stored| stored.borrow_mut().take());
+}
+
+fn arc_test_type_live_count() -> u32 {
+    LIVE_COUNT.load(Ordering::SeqCst)
+}
+
+fn test_rust_calls_swift_fn_reflects_arc() {
+    let arc = Arc::new(ArcTestType::new(10));
+
+    let reflected = ffi::swift_reflect_arc_test_type(arc.clone());
+
+    assert!(Arc::ptr_eq(&arc, &reflected));
+    assert_eq!(Arc::strong_count(&arc), 2);
+}
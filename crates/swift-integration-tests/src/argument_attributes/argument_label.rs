@@ -5,9 +5,22 @@ mod ffi {
             #[swift_bridge(label = "someArg")] some_arg: i32,
             another_arg: i32,
         ) -> i32;
+
+        fn test_extern_swift_argument_label() -> i32;
+    }
+
+    extern "Swift" {
+        fn swift_argument_label(
+            #[swift_bridge(label = "_")] some_arg: i32,
+            #[swift_bridge(label = "to")] another_arg: i32,
+        ) -> i32;
     }
 }
 
 fn test_argument_label(some_arg: i32, another_arg: i32) -> i32 {
     some_arg + another_arg
 }
+
+fn test_extern_swift_argument_label() -> i32 {
+    ffi::swift_argument_label(10, 100)
+}
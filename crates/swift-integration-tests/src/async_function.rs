@@ -9,7 +9,11 @@ mod ffi {
         async fn rust_async_return_null();
         async fn rust_async_reflect_u8(arg: u8) -> u8;
         async fn rust_async_reflect_string(string: String) -> String;
+        async fn rust_async_reflect_option_u64(arg: Option<u64>) -> Option<u64>;
+        async fn rust_async_reflect_option_string(arg: Option<String>) -> Option<String>;
+        async fn rust_async_reflect_vec_u8(arg: Vec<u8>) -> Vec<u8>;
         async fn rust_async_return_struct() -> AsyncRustFnReturnStruct;
+        async fn rust_async_reflect_tuple(arg: (i32, String)) -> (i32, String);
         async fn rust_async_func_reflect_result_opaque_rust(
             arg: Result<AsyncResultOpaqueRustType1, AsyncResultOpaqueRustType2>,
         ) -> Result<AsyncResultOpaqueRustType1, AsyncResultOpaqueRustType2>;
@@ -18,6 +22,25 @@ mod ffi {
         ) -> Result<(), AsyncResultOpaqueRustType2>;
     }
 
+    extern "Swift" {
+        async fn swift_async_return_null();
+        async fn swift_async_reflect_u32(arg: u32) -> u32;
+        async fn swift_async_reflect_string(arg: String) -> String;
+    }
+
+    extern "Rust" {
+        async fn rust_awaits_swift_async_functions(arg: u32, string: String) -> (u32, String);
+    }
+
+    enum AsyncRustFnReturnEnum {
+        WithData { field: f64 },
+        NoData,
+    }
+
+    extern "Rust" {
+        async fn rust_async_return_enum(with_data: bool) -> AsyncRustFnReturnEnum;
+    }
+
     extern "Rust" {
         type TestRustAsyncSelf;
 
@@ -32,6 +55,8 @@ mod ffi {
         #[swift_bridge(init)]
         fn new(val: u32) -> AsyncResultOpaqueRustType1;
         fn val(&self) -> u32;
+
+        async fn rust_async_return_opaque_rust(val: u32) -> AsyncResultOpaqueRustType1;
     }
 
     extern "Rust" {
@@ -79,10 +104,47 @@ async fn rust_async_reflect_string(string: String) -> String {
     string
 }
 
+async fn rust_async_reflect_option_u64(arg: Option<u64>) -> Option<u64> {
+    arg
+}
+
+async fn rust_async_reflect_option_string(arg: Option<String>) -> Option<String> {
+    arg
+}
+
+async fn rust_async_reflect_vec_u8(arg: Vec<u8>) -> Vec<u8> {
+    arg
+}
+
+async fn rust_async_return_opaque_rust(val: u32) -> AsyncResultOpaqueRustType1 {
+    AsyncResultOpaqueRustType1(val)
+}
+
+async fn rust_async_reflect_tuple(arg: (i32, String)) -> (i32, String) {
+    arg
+}
+
+async fn rust_awaits_swift_async_functions(arg: u32, string: String) -> (u32, String) {
+    ffi::swift_async_return_null().await;
+
+    (
+        ffi::swift_async_reflect_u32(arg).await,
+        ffi::swift_async_reflect_string(string).await,
+    )
+}
+
 async fn rust_async_return_struct() -> ffi::AsyncRustFnReturnStruct {
     ffi::AsyncRustFnReturnStruct { field: 123 }
 }
 
+async fn rust_async_return_enum(with_data: bool) -> ffi::AsyncRustFnReturnEnum {
+    if with_data {
+        ffi::AsyncRustFnReturnEnum::WithData { field: 1.5 }
+    } else {
+        ffi::AsyncRustFnReturnEnum::NoData
+    }
+}
+
 pub struct TestRustAsyncSelf;
 
 impl TestRustAsyncSelf {
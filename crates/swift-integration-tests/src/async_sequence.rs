@@ -0,0 +1,78 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/async_sequence_codegen_tests.rs
+
+use futures_util::stream::{self, Stream};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type AsyncSequenceTestError;
+
+        fn number(&self) -> u32;
+
+        fn rust_stream_count_to(count: u32) -> impl Stream<Item = u32>;
+
+        fn rust_stream_words(sentence: &str) -> impl Stream<Item = String>;
+
+        /// Produces the numbers up to `count`, and then fails with an error holding `count + 1`.
+        fn rust_stream_count_to_then_fail(
+            count: u32,
+        ) -> impl Stream<Item = Result<u32, AsyncSequenceTestError>>;
+
+        /// Counts up from 1 without ever ending.
+        fn rust_stream_count_forever() -> impl Stream<Item = u32>;
+
+        /// How many of the streams returned by `rust_stream_count_forever` have been dropped.
+        fn rust_stream_dropped_count() -> u32;
+    }
+}
+
+pub struct AsyncSequenceTestError(u32);
+
+impl AsyncSequenceTestError {
+    fn number(&self) -> u32 {
+        self.0
+    }
+}
+
+static DROPPED_STREAMS: AtomicU32 = AtomicU32::new(0);
+
+/// Counts the streams that were dropped.
+struct DropCounter;
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        DROPPED_STREAMS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn rust_stream_count_to(count: u32) -> impl Stream<Item = u32> {
+    stream::iter(1..=count)
+}
+
+fn rust_stream_words(sentence: &str) -> impl Stream<Item = String> {
+    let words: Vec<String> = sentence.split_whitespace().map(str::to_string).collect();
+    stream::iter(words)
+}
+
+fn rust_stream_count_to_then_fail(
+    count: u32,
+) -> impl Stream<Item = Result<u32, AsyncSequenceTestError>> {
+    stream::iter((1..=count + 1).map(move |number| {
+        if number <= count {
+            Ok(number)
+        } else {
+            Err(AsyncSequenceTestError(number))
+        }
+    }))
+}
+
+fn rust_stream_count_forever() -> impl Stream<Item = u32> {
+    stream::unfold((DropCounter, 0), |(counter, number)| async move {
+        Some((number + 1, (counter, number + 1)))
+    })
+}
+
+fn rust_stream_dropped_count() -> u32 {
+    DROPPED_STREAMS.load(Ordering::SeqCst)
+}
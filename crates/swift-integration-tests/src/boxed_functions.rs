@@ -1,3 +1,5 @@
+use std::cell::RefCell;
+
 #[swift_bridge::bridge]
 mod ffi {
     extern "Swift" {
@@ -34,23 +36,23 @@ mod ffi {
         ) -> u16;
     }
 
-    // TODO
-    // extern "Rust" {
-    //     fn rust_takes_callback_fnonce_no_args_no_return(arg: Box<dyn FnOnce() -> ()>);
-    //     fn rust_takes_callback_fnonce_primitive(doubling_fn: Box<dyn FnOnce(u8) -> u8>);
-    //     fn rust_takes_callback_fnonce_opaque_rust(
-    //         doubling_fn: Box<dyn FnOnce(CallbackTestOpaqueRustType) -> CallbackTestOpaqueRustType>,
-    //     );
-    //
-    //     fn rust_takes_callback_fnonce_two_params(
-    //         arg: Box<dyn FnOnce(i16, CallbackTestOpaqueRustType)>,
-    //     );
-    //
-    //     fn rust_takes_two_callbacks_fnonce_noop(
-    //         arg1: Box<dyn FnOnce()>,
-    //         arg2: Box<dyn FnOnce() -> ()>,
-    //     );
-    // }
+    extern "Rust" {
+        fn rust_takes_callback_fn_no_args_no_return(callback: Box<dyn Fn()>);
+        fn rust_takes_callback_fn_primitive(doubling_fn: Box<dyn Fn(u8) -> u8>);
+        fn rust_takes_callback_fn_string(callback: Box<dyn Fn(String) -> String>) -> String;
+        fn rust_takes_callback_fnonce_opaque_rust(
+            doubling_fn: Box<dyn FnOnce(CallbackTestOpaqueRustType) -> CallbackTestOpaqueRustType>,
+        );
+        fn rust_takes_callback_fn_two_params(callback: Box<dyn Fn(i16, u32) -> u32>);
+
+        fn rust_stores_callback(callback: Box<dyn Fn(u32) -> u32>);
+        fn rust_calls_stored_callback(arg: u32) -> u32;
+        fn rust_drops_stored_callback();
+
+        #[swift_bridge(return_scratch)]
+        fn rust_writes_scratch_with_callback(callback: Box<dyn Fn() -> String>) -> &str;
+        fn rust_calls_nested_callback(depth: u32, callback: Box<dyn Fn(u32) -> u32>) -> u32;
+    }
 
     extern "Rust" {
         type CallbackTestOpaqueRustType;
@@ -66,30 +68,60 @@ mod ffi {
     }
 }
 
-// TODO
-// fn rust_takes_callback_fnonce_no_args_no_return(arg: Box<dyn FnOnce() -> ()>) {
-//     (arg)()
-// }
-// fn rust_takes_callback_fnonce_primitive(doubling_fn: Box<dyn FnOnce(u8) -> u8>) {
-//     let doubling_fn = (doubling_fn)(2);
-//     assert_eq!(doubled, 4)
-// }
-//
-// fn rust_takes_callback_fnonce_opaque_rust(
-//     doubling_fn: Box<dyn FnOnce(CallbackTestOpaqueRustType) -> CallbackTestOpaqueRustType>,
-// ) {
-//     let start = CallbackTestOpaqueRustType { val: 100 };
-//
-//     let doubled = (doubling_fn)(start);
-//     assert_eq!(doubled.val(), 200);
-// }
-//
-// fn rust_takes_callback_fnonce_two_params(arg: Box<dyn FnOnce(i16, CallbackTestOpaqueRustType)>) {
-//     (arg)(123, CallbackTestOpaqueRustType { val: 222 })
-// }
-// fn rust_takes_two_callbacks_fnonce_noop(arg: Box<dyn FnOnce(i16, CallbackTestOpaqueRustType)>) {
-//     (arg)(123, CallbackTestOpaqueRustType { val: 222 })
-// }
+fn rust_takes_callback_fn_no_args_no_return(callback: Box<dyn Fn()>) {
+    (callback)();
+    (callback)();
+}
+
+fn rust_takes_callback_fn_primitive(doubling_fn: Box<dyn Fn(u8) -> u8>) {
+    assert_eq!((doubling_fn)(2), 4);
+    assert_eq!((doubling_fn)(3), 6);
+}
+
+fn rust_takes_callback_fn_string(callback: Box<dyn Fn(String) -> String>) -> String {
+    (callback)("hello".to_string())
+}
+
+fn rust_takes_callback_fnonce_opaque_rust(
+    doubling_fn: Box<dyn FnOnce(CallbackTestOpaqueRustType) -> CallbackTestOpaqueRustType>,
+) {
+    let start = CallbackTestOpaqueRustType { val: 100 };
+
+    let doubled = (doubling_fn)(start);
+    assert_eq!(doubled.val(), 200);
+}
+
+fn rust_takes_callback_fn_two_params(callback: Box<dyn Fn(i16, u32) -> u32>) {
+    assert_eq!((callback)(-5, 10), 5);
+}
+
+type StoredCallback = Box<dyn Fn(u32) -> u32>;
+
+thread_local! {
+    static STORED_CALLBACK: RefCell<Option<StoredCallback>> = RefCell::new(None);
+}
+
+fn rust_stores_callback(callback: Box<dyn Fn(u32) -> u32>) {
+    STORED_CALLBACK.with(|stored| *stored.borrow_mut() = Some(callback));
+}
+
+fn rust_calls_stored_callback(arg: u32) -> u32 {
+    STORED_CALLBACK.with(|stored| (stored.borrow().as_ref().unwrap())(arg))
+}
+
+fn rust_drops_stored_callback() {
+    STORED_CALLBACK.with(|stored| *stored.borrow_mut() = None);
+}
+
+fn rust_writes_scratch_with_callback(callback: Box<dyn Fn() -> String>, scratch: &mut String) {
+    scratch.push_str("outer ");
+    let inner = (callback)();
+    scratch.push_str(&inner);
+}
+
+fn rust_calls_nested_callback(depth: u32, callback: Box<dyn Fn(u32) -> u32>) -> u32 {
+    (callback)(depth)
+}
 
 pub struct CallbackTestOpaqueRustType {
     val: u32,
@@ -0,0 +1,38 @@
+//! Verify that the `#[swift_bridge::bridge(catch_panics = "throw")]` attribute turns Rust panics
+//! into Swift errors.
+//!
+//! See SwiftRustIntegrationTestRunner/SwiftRustIntegrationTestRunnerTests/CatchPanicsTests.swift
+
+#[swift_bridge::bridge(catch_panics = "throw")]
+mod ffi {
+    extern "Rust" {
+        type PanickingCounter;
+
+        #[swift_bridge(init)]
+        fn new(start: u32) -> PanickingCounter;
+        fn increment(&mut self, amount: u32) -> u32;
+        fn name(&self, suffix: &str) -> String;
+    }
+}
+
+pub struct PanickingCounter {
+    count: u32,
+}
+
+impl PanickingCounter {
+    fn new(start: u32) -> Self {
+        PanickingCounter { count: start }
+    }
+
+    fn increment(&mut self, amount: u32) -> u32 {
+        self.count = self.count.checked_add(amount).expect("count overflowed");
+        self.count
+    }
+
+    fn name(&self, suffix: &str) -> String {
+        if suffix.is_empty() {
+            panic!("suffix must not be empty, count is {}", self.count);
+        }
+        format!("counter-{suffix}")
+    }
+}
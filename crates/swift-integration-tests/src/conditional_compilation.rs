@@ -21,3 +21,28 @@ mod not_enabled {
 fn conditionally_exposed_fn() -> u8 {
     123
 }
+
+#[swift_bridge::bridge]
+mod conditional_items {
+    #[cfg(feature = "this_is_not_enabled")]
+    extern "Rust" {
+        // Neither this type nor its method are defined, but it doesn't matter since this
+        // extern block won't be compiled.
+        type UndefinedType;
+        fn undefined_method(&self);
+    }
+
+    extern "Rust" {
+        #[cfg(not(feature = "this_is_not_enabled"))]
+        fn conditionally_exposed_item_fn() -> u8;
+
+        // This function isn't defined, but it doesn't matter since it won't be compiled.
+        #[cfg(all(feature = "this_is_enabled", feature = "this_is_not_enabled"))]
+        fn undefined_item_fn();
+    }
+}
+
+#[cfg(not(feature = "this_is_not_enabled"))]
+fn conditionally_exposed_item_fn() -> u8 {
+    45
+}
@@ -0,0 +1,44 @@
+use swift_bridge::core_graphics::{CGFloat, CGPoint, CGRect, CGSize};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_cg_rect_center(rect: CGRect) -> CGPoint;
+        fn rust_scale_cg_size(size: CGSize, scale: CGFloat) -> CGSize;
+        fn rust_cg_float_is_double() -> bool;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_cg_rect(rect: CGRect) -> CGRect;
+        fn swift_cg_rect_union(a: CGRect, b: CGRect) -> CGRect;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_core_graphics();
+    }
+}
+
+fn rust_cg_rect_center(rect: CGRect) -> CGPoint {
+    CGPoint::new(
+        rect.origin.x + rect.size.width / 2.,
+        rect.origin.y + rect.size.height / 2.,
+    )
+}
+
+fn rust_scale_cg_size(size: CGSize, scale: CGFloat) -> CGSize {
+    CGSize::new(size.width * scale, size.height * scale)
+}
+
+fn rust_cg_float_is_double() -> bool {
+    std::mem::size_of::<CGFloat>() == 8
+}
+
+fn test_rust_calls_swift_core_graphics() {
+    let rect = CGRect::new(1.5, -2., 100.25, 50.);
+    assert_eq!(ffi::swift_reflect_cg_rect(rect), rect);
+
+    assert_eq!(
+        ffi::swift_cg_rect_union(CGRect::new(0., 0., 10., 10.), CGRect::new(5., 5., 10., 20.)),
+        CGRect::new(0., 0., 15., 25.)
+    );
+}
@@ -0,0 +1,16 @@
+use std::borrow::Cow;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_return_cow_str(owned: bool) -> Cow<'static, str>;
+    }
+}
+
+fn rust_return_cow_str(owned: bool) -> Cow<'static, str> {
+    if owned {
+        Cow::Owned("owned cow".to_string())
+    } else {
+        Cow::Borrowed("borrowed cow")
+    }
+}
@@ -0,0 +1,43 @@
+use rust_decimal::Decimal;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_decimal(decimal: Decimal) -> Decimal;
+        fn rust_decimal_to_string(decimal: Decimal) -> String;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_decimal(decimal: Decimal) -> Decimal;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_decimal();
+    }
+}
+
+fn rust_reflect_decimal(decimal: Decimal) -> Decimal {
+    decimal
+}
+
+/// The string includes any trailing zeros, so that Swift can check that the scale was preserved.
+fn rust_decimal_to_string(decimal: Decimal) -> String {
+    decimal.to_string()
+}
+
+fn test_rust_calls_swift_decimal() {
+    for decimal in [
+        Decimal::new(150, 2),
+        Decimal::new(-12345, 3),
+        Decimal::ZERO,
+        Decimal::ONE,
+        Decimal::MAX,
+        Decimal::MIN,
+        Decimal::from_i128_with_scale(1, 28),
+    ] {
+        let reflected = ffi::swift_reflect_decimal(decimal);
+        assert_eq!(reflected, decimal);
+        assert_eq!(reflected.scale(), decimal.scale());
+        assert_eq!(reflected.is_sign_negative(), decimal.is_sign_negative());
+    }
+}
@@ -0,0 +1,32 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/dispatch_queue_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Swift" {
+        /// The name of the test queue that the caller is running on, or "none".
+        fn swift_current_queue() -> String;
+
+        #[swift_bridge(
+            swift_name = "swift_current_queue",
+            dispatch_queue = "TestQueues.swiftQueue"
+        )]
+        fn swift_current_queue_on_swift_queue() -> String;
+    }
+
+    extern "Rust" {
+        /// Returns the name of the test queue that Swift called this function on.
+        #[swift_bridge(dispatch_queue = "TestQueues.rustQueue")]
+        fn rust_fn_on_queue_current_queue() -> String;
+
+        /// Returns the name of the test queue that a `dispatch_queue` Swift function ran on.
+        fn rust_calls_swift_fn_on_queue() -> String;
+    }
+}
+
+fn rust_fn_on_queue_current_queue() -> String {
+    ffi::swift_current_queue()
+}
+
+fn rust_calls_swift_fn_on_queue() -> String {
+    ffi::swift_current_queue_on_swift_queue()
+}
@@ -9,6 +9,7 @@ mod ffi {
         fn push(&mut self, val: u8);
         fn pop(self: &mut ARustStack);
 
+        #[swift_bridge(unsafe_pointer)]
         fn as_ptr(&self) -> *const u8;
         fn len(&self) -> usize;
 
@@ -0,0 +1,46 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_u8_array(arr: [u8; 4]) -> [u8; 4];
+        fn rust_reflect_f64_array(arr: [f64; 3]) -> [f64; 3];
+        fn rust_make_32_byte_array() -> [u8; 32];
+        fn rust_sum_i32_array(arr: [i32; 5]) -> i32;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_u8_array(arr: [u8; 4]) -> [u8; 4];
+        fn swift_reverse_bool_array(arr: [bool; 3]) -> [bool; 3];
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_fixed_size_arrays();
+    }
+}
+
+fn rust_reflect_u8_array(arr: [u8; 4]) -> [u8; 4] {
+    arr
+}
+
+fn rust_reflect_f64_array(arr: [f64; 3]) -> [f64; 3] {
+    arr
+}
+
+fn rust_make_32_byte_array() -> [u8; 32] {
+    let mut arr = [0; 32];
+    for (idx, byte) in arr.iter_mut().enumerate() {
+        *byte = idx as u8;
+    }
+    arr
+}
+
+fn rust_sum_i32_array(arr: [i32; 5]) -> i32 {
+    arr.iter().sum()
+}
+
+fn test_rust_calls_swift_fixed_size_arrays() {
+    assert_eq!(ffi::swift_reflect_u8_array([1, 2, 3, 255]), [1, 2, 3, 255]);
+    assert_eq!(
+        ffi::swift_reverse_bool_array([true, false, false]),
+        [false, false, true]
+    );
+}
@@ -1,8 +1,13 @@
 mod args_into;
+mod available;
+mod catch_errors;
 mod get;
 mod get_with;
 mod identifiable;
 mod return_into;
 mod return_with;
 mod rust_name;
+mod subscript;
+mod swift_access;
 mod swift_name;
+mod swift_property;
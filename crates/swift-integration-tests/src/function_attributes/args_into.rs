@@ -19,6 +19,19 @@ mod ffi {
         #[swift_bridge(args_into = (some_arg, another_arg))]
         fn test_args_into(some_arg: ArgsIntoSomeStruct, another_arg: AnotherStruct);
     }
+
+    extern "Rust" {
+        type ArgsIntoWallet;
+
+        #[swift_bridge(init)]
+        fn new() -> ArgsIntoWallet;
+
+        // The real `deposit` and `balance` methods accept `Cents` and `Currency` newtypes.
+        #[swift_bridge(args_into = (amount, currency))]
+        fn deposit(&mut self, amount: u64, currency: String);
+        #[swift_bridge(args_into = (currency))]
+        fn balance(&self, currency: String) -> u64;
+    }
 }
 
 fn test_args_into(_some_arg: TypeA, _another_arg: TypeB) {}
@@ -40,3 +53,40 @@ impl Into<TypeB> for ffi::AnotherStruct {
         TypeB::Foo(self.foo)
     }
 }
+
+pub struct ArgsIntoWallet {
+    deposits: Vec<(Cents, Currency)>,
+}
+
+struct Cents(u64);
+struct Currency(String);
+
+impl ArgsIntoWallet {
+    fn new() -> Self {
+        ArgsIntoWallet { deposits: vec![] }
+    }
+
+    fn deposit(&mut self, amount: Cents, currency: Currency) {
+        self.deposits.push((amount, currency));
+    }
+
+    fn balance(&self, currency: Currency) -> u64 {
+        self.deposits
+            .iter()
+            .filter(|(_, deposit_currency)| deposit_currency.0 == currency.0)
+            .map(|(amount, _)| amount.0)
+            .sum()
+    }
+}
+
+impl From<u64> for Cents {
+    fn from(cents: u64) -> Self {
+        Cents(cents)
+    }
+}
+
+impl From<String> for Currency {
+    fn from(currency: String) -> Self {
+        Currency(currency)
+    }
+}
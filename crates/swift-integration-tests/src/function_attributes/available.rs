@@ -0,0 +1,38 @@
+/// We declare a type and functions that use the `available` attribute. The integration test
+/// runner's deployment target is newer than the versions used here, so they can be called
+/// without an `if #available` check.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/function_attribute_codegen_tests.rs
+///   - function_available_attribute
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(available = "macOS 10.15, iOS 13.0")]
+        type AvailableAttributeType;
+
+        #[swift_bridge(init)]
+        fn new() -> AvailableAttributeType;
+
+        #[swift_bridge(available = "macOS 10.15, iOS 13.0")]
+        fn value(&self) -> u8;
+
+        #[swift_bridge(available = "macOS 10.15, iOS 13.0")]
+        fn available_attribute_function() -> u8;
+    }
+}
+
+pub struct AvailableAttributeType;
+
+impl AvailableAttributeType {
+    fn new() -> Self {
+        AvailableAttributeType
+    }
+
+    fn value(&self) -> u8 {
+        123
+    }
+}
+
+fn available_attribute_function() -> u8 {
+    123
+}
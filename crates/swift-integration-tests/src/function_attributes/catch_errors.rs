@@ -0,0 +1,34 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Swift" {
+        #[swift_bridge(catch_errors)]
+        fn testCatchErrorsReverseName(name: String) -> Result<String, String>;
+
+        #[swift_bridge(catch_errors)]
+        fn testCatchErrorsRequireName(name: String) -> Result<(), String>;
+    }
+
+    extern "Rust" {
+        #[swift_bridge(swift_name = "testCallSwiftThatThrowsFromRust")]
+        fn test_call_swift_that_throws_from_rust();
+    }
+}
+
+/// The test on the Swift side calls this function, which calls into Swift functions that throw
+/// and checks that the errors that they threw came back to Rust as an `Err`.
+fn test_call_swift_that_throws_from_rust() {
+    assert_eq!(
+        ffi::testCatchErrorsReverseName("abc".to_string()),
+        Ok("cba".to_string())
+    );
+    assert_eq!(
+        ffi::testCatchErrorsReverseName("".to_string()),
+        Err("emptyName".to_string())
+    );
+
+    assert_eq!(ffi::testCatchErrorsRequireName("abc".to_string()), Ok(()));
+    assert_eq!(
+        ffi::testCatchErrorsRequireName("".to_string()),
+        Err("emptyName".to_string())
+    );
+}
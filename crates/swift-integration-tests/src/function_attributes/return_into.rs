@@ -24,6 +24,10 @@ mod ffi {
         #[swift_bridge(return_into)]
         fn get_another_type() -> SomeType;
 
+        // Verify that async functions convert the value that their future resolves to.
+        #[swift_bridge(return_into)]
+        async fn get_another_type_async() -> SomeType;
+
         // Verify that our code compiles when we use `return_into` on a shared struct.
         #[swift_bridge(return_into)]
         fn get_struct() -> ReturnIntoSomeStruct;
@@ -65,6 +69,10 @@ fn get_another_type() -> AnotherType {
     AnotherType
 }
 
+async fn get_another_type_async() -> AnotherType {
+    AnotherType
+}
+
 fn get_struct() -> SomeType {
     SomeType
 }
@@ -5,6 +5,9 @@ mod ffi {
     extern "Rust" {
         #[swift_bridge(return_with = some_module::convert_str_to_u32)]
         fn get_str_value_return_with() -> u32;
+
+        #[swift_bridge(return_with = some_module::convert_str_to_u32)]
+        async fn get_str_value_return_with_async() -> u32;
     }
 }
 
@@ -12,6 +15,10 @@ fn get_str_value_return_with() -> &'static str {
     "123"
 }
 
+async fn get_str_value_return_with_async() -> &'static str {
+    "123"
+}
+
 mod some_module {
     pub fn convert_str_to_u32(val: &str) -> u32 {
         val.parse().unwrap()
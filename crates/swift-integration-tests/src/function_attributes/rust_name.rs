@@ -4,7 +4,21 @@ mod ffi {
         // If this compiles then we're successfully using the `rust_name` during code generation.
         #[swift_bridge(rust_name = "another_function")]
         fn some_function();
+
+        #[swift_bridge(swift_name = "testCallRustFromSwiftByRustNameAttribute")]
+        fn test_call_rust_from_swift_by_rust_name_attribute() -> u8;
+    }
+
+    extern "Swift" {
+        // Calls the Swift `testCallSwiftFromRustByRustNameAttribute` function using the
+        // snake_case `rust_name`.
+        #[swift_bridge(rust_name = "test_call_swift_from_rust_by_rust_name_attribute")]
+        fn testCallSwiftFromRustByRustNameAttribute() -> u8;
     }
 }
 
 fn another_function() {}
+
+fn test_call_rust_from_swift_by_rust_name_attribute() -> u8 {
+    ffi::test_call_swift_from_rust_by_rust_name_attribute()
+}
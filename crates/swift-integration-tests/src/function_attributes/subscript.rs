@@ -0,0 +1,35 @@
+/// We declare a type whose getter and setter use the `subscript` attribute.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/function_attribute_codegen_tests.rs
+///   - function_subscript_attribute
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SubscriptAttributeType;
+
+        #[swift_bridge(init)]
+        fn new() -> SubscriptAttributeType;
+
+        #[swift_bridge(subscript)]
+        fn get(&self, index: usize) -> u8;
+
+        #[swift_bridge(subscript)]
+        fn set(&mut self, index: usize, value: u8);
+    }
+}
+
+pub struct SubscriptAttributeType(Vec<u8>);
+
+impl SubscriptAttributeType {
+    fn new() -> Self {
+        SubscriptAttributeType(vec![1, 2, 3])
+    }
+
+    fn get(&self, index: usize) -> u8 {
+        self.0[index]
+    }
+
+    fn set(&mut self, index: usize, value: u8) {
+        self.0[index] = value;
+    }
+}
@@ -0,0 +1,36 @@
+/// We declare a type and functions that use the `swift_access` attribute. The integration tests
+/// use `@testable import`, so they can call `internal` declarations.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/function_attribute_codegen_tests.rs
+///   - function_swift_access_attribute
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(swift_access = "internal")]
+        type SwiftAccessAttributeType;
+
+        #[swift_bridge(init)]
+        fn new() -> SwiftAccessAttributeType;
+
+        fn value(&self) -> u8;
+
+        #[swift_bridge(swift_access = "internal")]
+        fn swift_access_attribute_function() -> u8;
+    }
+}
+
+pub struct SwiftAccessAttributeType;
+
+impl SwiftAccessAttributeType {
+    fn new() -> Self {
+        SwiftAccessAttributeType
+    }
+
+    fn value(&self) -> u8 {
+        45
+    }
+}
+
+fn swift_access_attribute_function() -> u8 {
+    45
+}
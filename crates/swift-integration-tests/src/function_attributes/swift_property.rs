@@ -0,0 +1,46 @@
+/// We declare a type whose getters and setter use the `swift_property` attribute.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/function_attribute_codegen_tests.rs
+///   - function_swift_property_attribute
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SwiftPropertyAttributeType;
+
+        #[swift_bridge(init)]
+        fn new() -> SwiftPropertyAttributeType;
+
+        #[swift_bridge(swift_property)]
+        fn name(&self) -> String;
+
+        #[swift_bridge(swift_property)]
+        fn set_name(&mut self, name: String);
+
+        #[swift_bridge(swift_property, swift_name = "nameLength")]
+        fn name_length(&self) -> usize;
+    }
+}
+
+pub struct SwiftPropertyAttributeType {
+    name: String,
+}
+
+impl SwiftPropertyAttributeType {
+    fn new() -> Self {
+        SwiftPropertyAttributeType {
+            name: "Rust".to_string(),
+        }
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    fn name_length(&self) -> usize {
+        self.name.len()
+    }
+}
@@ -29,6 +29,47 @@ mod ffi {
             arg: GenericWithOpaqueRustInnerTy<InnerTy>,
         ) -> GenericWithOpaqueRustInnerTy<InnerTy>;
     }
+
+    extern "Rust" {
+        #[swift_bridge(declare_generic)]
+        type GenericStack<A>;
+        type GenericStack<u8>;
+        type GenericStack<u64>;
+
+        #[swift_bridge(init)]
+        fn new() -> GenericStack<u8>;
+        fn push(self: &mut GenericStack<u8>, value: u8);
+        fn len(self: &GenericStack<u8>) -> usize;
+
+        fn new_generic_stack_u64() -> GenericStack<u64>;
+        fn push(self: &mut GenericStack<u64>, value: u64);
+        fn len(self: &GenericStack<u64>) -> usize;
+    }
+
+    extern "Rust" {
+        #[swift_bridge(instantiate(u8, i64, String))]
+        fn reflect_instantiated_generic<T>(arg: T) -> T;
+    }
+}
+
+pub struct GenericStack<T> {
+    items: Vec<T>,
+}
+
+impl GenericStack<u8> {
+    fn new() -> Self {
+        GenericStack { items: vec![] }
+    }
+}
+
+impl<T> GenericStack<T> {
+    fn push(&mut self, value: T) {
+        self.items.push(value);
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
 }
 
 pub struct SomeGenericType<T> {
@@ -71,3 +112,11 @@ fn reflect_generic_with_inner_opaque_type(
 ) -> GenericWithOpaqueRustInnerTy<InnerTy> {
     arg
 }
+
+fn new_generic_stack_u64() -> GenericStack<u64> {
+    GenericStack { items: vec![] }
+}
+
+fn reflect_instantiated_generic<T>(arg: T) -> T {
+    arg
+}
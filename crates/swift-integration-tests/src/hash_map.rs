@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_return_hash_map_string_u64() -> HashMap<String, u64>;
+        fn rust_sum_hash_map_string_u64_values(map: HashMap<String, u64>) -> u64;
+        fn rust_reflect_hash_map_u8_bool(map: HashMap<u8, bool>) -> HashMap<u8, bool>;
+        fn rust_reflect_hash_map_string_string(
+            map: HashMap<String, String>,
+        ) -> HashMap<String, String>;
+    }
+}
+
+fn rust_return_hash_map_string_u64() -> HashMap<String, u64> {
+    let mut map = HashMap::new();
+    map.insert("one".to_string(), 1);
+    map.insert("two".to_string(), 2);
+    map
+}
+
+fn rust_sum_hash_map_string_u64_values(map: HashMap<String, u64>) -> u64 {
+    map.values().sum()
+}
+
+fn rust_reflect_hash_map_u8_bool(map: HashMap<u8, bool>) -> HashMap<u8, bool> {
+    map
+}
+
+fn rust_reflect_hash_map_string_string(map: HashMap<String, String>) -> HashMap<String, String> {
+    map
+}
@@ -0,0 +1,22 @@
+use std::collections::HashSet;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_return_hash_set_string() -> HashSet<String>;
+        fn rust_sum_hash_set_u32(set: HashSet<u32>) -> u32;
+        fn rust_reflect_hash_set_string(set: HashSet<String>) -> HashSet<String>;
+    }
+}
+
+fn rust_return_hash_set_string() -> HashSet<String> {
+    ["one".to_string(), "two".to_string()].into_iter().collect()
+}
+
+fn rust_sum_hash_set_u32(set: HashSet<u32>) -> u32 {
+    set.iter().sum()
+}
+
+fn rust_reflect_hash_set_string(set: HashSet<String>) -> HashSet<String> {
+    set
+}
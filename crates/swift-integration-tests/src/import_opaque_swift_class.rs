@@ -9,11 +9,21 @@ mod ffi {
         fn push(&mut self, val: u8);
         fn pop(self: &mut ASwiftStack);
 
+        #[swift_bridge(unsafe_pointer)]
         fn as_ptr(&self) -> *const u8;
         fn len(&self) -> usize;
         fn as_slice(&self) -> &[u8];
     }
 
+    extern "Swift" {
+        type ASwiftPercentage;
+
+        #[swift_bridge(init)]
+        fn new(#[swift_bridge(label = "validating")] value: u8) -> Option<ASwiftPercentage>;
+
+        fn value(&self) -> u8;
+    }
+
     extern "Rust" {
         fn run_opaque_swift_class_tests();
     }
@@ -40,4 +50,8 @@ fn run_opaque_swift_class_tests() {
 
     stack.pop();
     assert_eq!(stack.len(), 1);
+
+    // A failable `init?` returns `None` when the Swift initializer fails.
+    assert_eq!(ffi::ASwiftPercentage::new(50).unwrap().value(), 50);
+    assert!(ffi::ASwiftPercentage::new(150).is_none());
 }
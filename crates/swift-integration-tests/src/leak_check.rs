@@ -0,0 +1,35 @@
+//! Verify that the `leak-check` feature counts the opaque Rust types that Swift owns.
+//!
+//! See SwiftRustIntegrationTestRunner/SwiftRustIntegrationTestRunnerTests/LeakCheckTests.swift
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type LeakCheckType;
+
+        #[swift_bridge(init)]
+        fn new() -> LeakCheckType;
+
+        fn leak_check_live_count() -> usize;
+        fn leak_check_take_ownership(val: LeakCheckType);
+        fn leak_check_vec(len: usize) -> Vec<LeakCheckType>;
+    }
+}
+
+pub struct LeakCheckType;
+
+impl LeakCheckType {
+    fn new() -> Self {
+        LeakCheckType
+    }
+}
+
+fn leak_check_live_count() -> usize {
+    swift_bridge::leak_check::live_count()
+}
+
+fn leak_check_take_ownership(_val: LeakCheckType) {}
+
+fn leak_check_vec(len: usize) -> Vec<LeakCheckType> {
+    (0..len).map(|_| LeakCheckType).collect()
+}
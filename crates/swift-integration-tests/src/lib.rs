@@ -1,14 +1,33 @@
 mod expose_opaque_rust_type;
 mod import_opaque_swift_class;
 
+mod actor;
+mod arc;
 mod async_function;
+mod async_sequence;
 mod boxed_functions;
+mod catch_panics;
 mod conditional_compilation;
+mod core_graphics;
+mod cow_str;
+mod decimal;
+mod dispatch_queue;
+mod fixed_size_array;
 mod generics;
+mod hash_map;
+mod hash_set;
+mod leak_check;
+mod main_actor;
+mod non_zero;
+mod ns_error;
+mod observable;
 mod option;
+mod path;
 mod pointer;
 mod primitive;
+mod publisher;
 mod result;
+mod round_trip_fuzz;
 mod rust_function_uses_opaque_swift_type;
 mod shared_types;
 mod single_representation_type_elision;
@@ -16,7 +35,13 @@ mod slice;
 mod string;
 mod swift_function_uses_opaque_rust_type;
 mod swift_function_uses_opaque_swift_type;
+mod swift_protocol;
+mod swift_type_prefix;
+mod time;
+mod trait_object;
 mod tuple;
+mod url;
+mod uuid;
 mod vec;
 
 mod enum_attributes;
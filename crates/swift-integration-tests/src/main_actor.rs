@@ -0,0 +1,37 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/main_actor_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Swift" {
+        #[swift_bridge(main_actor)]
+        fn swift_main_actor_is_main_thread() -> bool;
+
+        #[swift_bridge(main_actor)]
+        async fn swift_main_actor_is_main_thread_async() -> bool;
+    }
+
+    extern "Rust" {
+        /// Calls a `main_actor` Swift function from the current thread.
+        fn rust_calls_main_actor_fn() -> bool;
+
+        /// Calls a `main_actor` Swift function from a newly spawned thread.
+        async fn rust_calls_main_actor_fn_from_background_thread() -> bool;
+
+        /// Awaits an async `main_actor` Swift function.
+        async fn rust_awaits_main_actor_async_fn() -> bool;
+    }
+}
+
+fn rust_calls_main_actor_fn() -> bool {
+    ffi::swift_main_actor_is_main_thread()
+}
+
+async fn rust_calls_main_actor_fn_from_background_thread() -> bool {
+    std::thread::spawn(ffi::swift_main_actor_is_main_thread)
+        .join()
+        .unwrap()
+}
+
+async fn rust_awaits_main_actor_async_fn() -> bool {
+    ffi::swift_main_actor_is_main_thread_async().await
+}
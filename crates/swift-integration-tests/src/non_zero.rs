@@ -0,0 +1,30 @@
+use std::num::{NonZeroI64, NonZeroU32, NonZeroU8};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_non_zero_u32(num: NonZeroU32) -> NonZeroU32;
+        fn rust_double_non_zero_i64(num: NonZeroI64) -> std::num::NonZeroI64;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_non_zero_u8(num: NonZeroU8) -> NonZeroU8;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_non_zero();
+    }
+}
+
+fn rust_reflect_non_zero_u32(num: NonZeroU32) -> NonZeroU32 {
+    num
+}
+
+fn rust_double_non_zero_i64(num: NonZeroI64) -> NonZeroI64 {
+    num.checked_mul(NonZeroI64::new(2).unwrap()).unwrap()
+}
+
+fn test_rust_calls_swift_non_zero() {
+    let num = NonZeroU8::new(7).unwrap();
+    assert_eq!(ffi::swift_reflect_non_zero_u8(num), num);
+}
@@ -0,0 +1,49 @@
+use swift_bridge::ns_error::{NsError, NS_LOCALIZED_DESCRIPTION_KEY};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_parse_port(text: &str) -> Result<u16, NsError>;
+        fn rust_reflect_ns_error(error: NsError) -> NsError;
+    }
+
+    extern "Swift" {
+        #[swift_bridge(catch_errors)]
+        fn swift_read_file(path: String) -> Result<String, NsError>;
+        #[swift_bridge(catch_errors)]
+        fn swift_throw_ns_error() -> Result<(), NsError>;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_ns_error();
+    }
+}
+
+fn rust_parse_port(text: &str) -> Result<u16, NsError> {
+    text.parse().map_err(|_| {
+        NsError::new("com.example.ports", 1)
+            .with_localized_description(format!("{} is not a valid port", text))
+            .with_user_info("text", text)
+    })
+}
+
+fn rust_reflect_ns_error(error: NsError) -> NsError {
+    error
+}
+
+fn test_rust_calls_swift_ns_error() {
+    let error = ffi::swift_read_file("/this/file/does/not/exist".to_string()).unwrap_err();
+    assert_eq!(error.domain, "NSCocoaErrorDomain");
+    // NSFileReadNoSuchFileError
+    assert_eq!(error.code, 260);
+    assert!(error.localized_description().is_some());
+
+    let error = ffi::swift_throw_ns_error().unwrap_err();
+    assert_eq!(error.domain, "com.example.integration");
+    assert_eq!(error.code, 7);
+    assert_eq!(
+        error.user_info[NS_LOCALIZED_DESCRIPTION_KEY],
+        "Something went wrong"
+    );
+    assert_eq!(error.user_info["answer"], "42");
+}
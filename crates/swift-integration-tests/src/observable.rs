@@ -0,0 +1,91 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/observable_codegen_tests.rs
+
+use std::sync::{Arc, Mutex};
+use swift_bridge::observable::{ChangeNotifier, Observable};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(observable)]
+        type SharedCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> SharedCounter;
+
+        #[swift_bridge(swift_property)]
+        fn count(&self) -> u32;
+        #[swift_bridge(swift_property)]
+        fn set_count(&mut self, count: u32);
+
+        #[swift_bridge(swift_property)]
+        fn name(&self) -> String;
+        #[swift_bridge(swift_property)]
+        fn set_name(&mut self, name: String);
+
+        fn increment(&mut self);
+
+        /// Increments the count on a newly spawned thread without waiting for it.
+        fn increment_on_background_thread(&self);
+
+        fn observer_count(&self) -> usize;
+    }
+}
+
+pub struct SharedCounter {
+    count: Arc<Mutex<u32>>,
+    name: String,
+    notifier: Arc<ChangeNotifier>,
+}
+
+impl SharedCounter {
+    fn new() -> Self {
+        SharedCounter {
+            count: Arc::new(Mutex::new(0)),
+            name: "counter".to_string(),
+            notifier: Arc::new(ChangeNotifier::new()),
+        }
+    }
+
+    fn count(&self) -> u32 {
+        *self.count.lock().unwrap()
+    }
+
+    fn set_count(&mut self, count: u32) {
+        *self.count.lock().unwrap() = count;
+        self.notifier.notify();
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn set_name(&mut self, name: String) {
+        self.name = name;
+        self.notifier.notify();
+    }
+
+    fn increment(&mut self) {
+        *self.count.lock().unwrap() += 1;
+        self.notifier.notify();
+    }
+
+    fn increment_on_background_thread(&self) {
+        let count = self.count.clone();
+        let notifier = self.notifier.clone();
+
+        std::thread::spawn(move || {
+            *count.lock().unwrap() += 1;
+            notifier.notify();
+        });
+    }
+
+    fn observer_count(&self) -> usize {
+        self.notifier.observer_count()
+    }
+}
+
+impl Observable for SharedCounter {
+    fn change_notifier(&self) -> &ChangeNotifier {
+        &self.notifier
+    }
+}
@@ -1,4 +1,13 @@
 mod already_declared;
+mod already_declared_in_other_file;
 mod copy;
+mod debug_display;
+mod default;
+mod drop_on;
 mod equatable;
+mod field_accessors;
 mod hashable;
+mod identifiable;
+mod operators;
+mod sendable;
+mod swift_name;
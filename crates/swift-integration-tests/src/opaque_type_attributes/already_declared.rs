@@ -4,6 +4,8 @@
 //! If the Xcode project is able to compile then we know that our attribute works,
 //! because otherwise we would get build time errors that the class was defined twice.
 
+use ffi1::AlreadyDeclaredSwiftTypeTest;
+
 #[swift_bridge::bridge]
 mod ffi1 {
     extern "Rust" {
@@ -11,6 +13,10 @@ mod ffi1 {
         #[swift_bridge(Copy(4))]
         type AlreadyDeclaredCopyTypeTest;
     }
+
+    extern "Swift" {
+        type AlreadyDeclaredSwiftTypeTest;
+    }
 }
 
 #[swift_bridge::bridge]
@@ -43,6 +49,22 @@ mod ffi2 {
         #[swift_bridge(associated_to = AlreadyDeclaredCopyTypeTest)]
         fn an_associated_function() -> bool;
     }
+
+    extern "Swift" {
+        #[swift_bridge(already_declared)]
+        type AlreadyDeclaredSwiftTypeTest;
+
+        fn a_swift_ref_method(&self) -> bool;
+    }
+
+    extern "Rust" {
+        fn rust_calls_already_declared_swift_type_method(arg: AlreadyDeclaredSwiftTypeTest)
+            -> bool;
+    }
+}
+
+fn rust_calls_already_declared_swift_type_method(arg: AlreadyDeclaredSwiftTypeTest) -> bool {
+    arg.a_swift_ref_method()
 }
 
 pub struct AlreadyDeclaredTypeTest;
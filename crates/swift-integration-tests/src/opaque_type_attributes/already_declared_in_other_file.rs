@@ -0,0 +1,32 @@
+//! Verify that a bridge module can use an opaque type that a bridge module in another file
+//! declares.
+//!
+//! See crates/swift-integration-tests/src/opaque_type_attributes/already_declared.rs
+
+use super::already_declared::AlreadyDeclaredTypeTest;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(already_declared)]
+        type AlreadyDeclaredTypeTest;
+
+        fn a_method_from_another_file(&self) -> bool;
+
+        fn rust_reflect_already_declared_in_other_file(
+            arg: AlreadyDeclaredTypeTest,
+        ) -> AlreadyDeclaredTypeTest;
+    }
+}
+
+impl AlreadyDeclaredTypeTest {
+    fn a_method_from_another_file(&self) -> bool {
+        true
+    }
+}
+
+fn rust_reflect_already_declared_in_other_file(
+    arg: AlreadyDeclaredTypeTest,
+) -> AlreadyDeclaredTypeTest {
+    arg
+}
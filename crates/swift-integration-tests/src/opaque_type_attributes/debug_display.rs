@@ -0,0 +1,36 @@
+/// We declare an opaque Rust type that exposes its `Debug` and `Display` implementations to
+/// Swift's `CustomDebugStringConvertible` and `CustomStringConvertible` protocols.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_debug_and_display_type
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        /// Doc comments are carried over to the generated Swift.
+        #[swift_bridge(Debug, Display)]
+        type RustDebugDisplayType;
+
+        /// Create a type that greets `name`.
+        #[swift_bridge(init)]
+        fn new(name: &str) -> RustDebugDisplayType;
+    }
+}
+
+#[derive(Debug)]
+pub struct RustDebugDisplayType {
+    name: String,
+}
+
+impl RustDebugDisplayType {
+    fn new(name: &str) -> Self {
+        RustDebugDisplayType {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for RustDebugDisplayType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Hello, {}", self.name)
+    }
+}
@@ -0,0 +1,27 @@
+/// We declare a type that exposes its `Default` implementation as a Swift `init()`.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_default_type
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Default)]
+        type RustDefaultType;
+
+        fn value(&self) -> u32;
+    }
+}
+
+pub struct RustDefaultType(u32);
+
+impl Default for RustDefaultType {
+    fn default() -> Self {
+        RustDefaultType(123)
+    }
+}
+
+impl RustDefaultType {
+    fn value(&self) -> u32 {
+        self.0
+    }
+}
@@ -0,0 +1,33 @@
+/// We declare an opaque Rust type that uses the `drop_on = "main"` attribute and tell Swift which
+/// thread it was dropped on.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_drop_on_main
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(drop_on = "main")]
+        type DropOnMainType;
+
+        #[swift_bridge(init)]
+        fn new() -> DropOnMainType;
+    }
+
+    extern "Swift" {
+        fn drop_on_main_type_dropped();
+    }
+}
+
+pub struct DropOnMainType;
+
+impl DropOnMainType {
+    fn new() -> Self {
+        DropOnMainType
+    }
+}
+
+impl Drop for DropOnMainType {
+    fn drop(&mut self) {
+        ffi::drop_on_main_type_dropped();
+    }
+}
@@ -0,0 +1,29 @@
+/// We declare an opaque Rust type that uses the `get` and `set` attributes to expose its fields
+/// as Swift properties.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_field_accessors
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(get(name: &str, age: u8), set(age))]
+        type OpaqueRustTypeWithFields;
+
+        #[swift_bridge(init)]
+        fn new(name: &str, age: u8) -> OpaqueRustTypeWithFields;
+    }
+}
+
+pub struct OpaqueRustTypeWithFields {
+    name: String,
+    age: u8,
+}
+
+impl OpaqueRustTypeWithFields {
+    fn new(name: &str, age: u8) -> Self {
+        OpaqueRustTypeWithFields {
+            name: name.to_string(),
+            age,
+        }
+    }
+}
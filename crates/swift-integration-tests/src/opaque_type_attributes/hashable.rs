@@ -7,6 +7,15 @@ mod ffi {
         #[swift_bridge(init)]
         fn new(num: isize) -> RustHashableType;
     }
+
+    extern "Rust" {
+        // `Hashable` implies `Equatable`.
+        #[swift_bridge(Hashable)]
+        type RustHashableOnlyType;
+
+        #[swift_bridge(init)]
+        fn new(num: isize) -> RustHashableOnlyType;
+    }
 }
 
 #[derive(Hash, PartialEq)]
@@ -17,3 +26,12 @@ impl RustHashableType {
         RustHashableType(num)
     }
 }
+
+#[derive(Hash, PartialEq)]
+pub struct RustHashableOnlyType(isize);
+
+impl RustHashableOnlyType {
+    fn new(num: isize) -> Self {
+        RustHashableOnlyType(num)
+    }
+}
@@ -0,0 +1,25 @@
+/// We declare an opaque Rust type that uses the `Identifiable` attribute along with a
+/// `get(id: ...)` field so that it can be used with SwiftUI's `List` and `ForEach`.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_identifiable
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Identifiable, get(id: u64))]
+        type IdentifiableOpaqueRustType;
+
+        #[swift_bridge(init)]
+        fn new(id: u64) -> IdentifiableOpaqueRustType;
+    }
+}
+
+pub struct IdentifiableOpaqueRustType {
+    id: u64,
+}
+
+impl IdentifiableOpaqueRustType {
+    fn new(id: u64) -> Self {
+        IdentifiableOpaqueRustType { id }
+    }
+}
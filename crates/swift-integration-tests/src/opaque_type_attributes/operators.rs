@@ -0,0 +1,37 @@
+/// We declare a type that exposes its `PartialOrd` and `Add` implementations as Swift operators.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_comparable_and_add_type
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Comparable, Add)]
+        type RustOperatorsType;
+
+        #[swift_bridge(init)]
+        fn new(value: i32) -> RustOperatorsType;
+
+        fn value(&self) -> i32;
+    }
+}
+
+#[derive(PartialEq, PartialOrd)]
+pub struct RustOperatorsType(i32);
+
+impl RustOperatorsType {
+    fn new(value: i32) -> Self {
+        RustOperatorsType(value)
+    }
+
+    fn value(&self) -> i32 {
+        self.0
+    }
+}
+
+impl std::ops::Add for &RustOperatorsType {
+    type Output = RustOperatorsType;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        RustOperatorsType(self.0 + rhs.0)
+    }
+}
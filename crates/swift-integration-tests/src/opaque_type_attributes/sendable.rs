@@ -0,0 +1,36 @@
+/// We declare an opaque Rust type and a shared struct that use the `Sendable` attribute so that
+/// they can be passed between Swift tasks under strict concurrency checking.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_sendable
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", Sendable)]
+    struct SendableSharedStruct {
+        value: u32,
+    }
+
+    extern "Rust" {
+        #[swift_bridge(Sendable)]
+        type SendableOpaqueRustType;
+
+        #[swift_bridge(init)]
+        fn new(value: u32) -> SendableOpaqueRustType;
+
+        fn value(&self) -> u32;
+    }
+}
+
+pub struct SendableOpaqueRustType {
+    value: u32,
+}
+
+impl SendableOpaqueRustType {
+    fn new(value: u32) -> Self {
+        SendableOpaqueRustType { value }
+    }
+
+    fn value(&self) -> u32 {
+        self.value
+    }
+}
@@ -0,0 +1,33 @@
+/// We declare an opaque Rust type that is renamed using the `swift_name` attribute and then use
+/// it as an initializer, method receiver and function return type.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_swift_name
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(swift_name = "RenamedOpaqueRustType")]
+        type OpaqueRustTypeSwiftName;
+
+        #[swift_bridge(init)]
+        fn new(value: u32) -> OpaqueRustTypeSwiftName;
+        fn value(&self) -> u32;
+        fn incremented(&self) -> OpaqueRustTypeSwiftName;
+    }
+}
+
+pub struct OpaqueRustTypeSwiftName(u32);
+
+impl OpaqueRustTypeSwiftName {
+    fn new(value: u32) -> Self {
+        OpaqueRustTypeSwiftName(value)
+    }
+
+    fn value(&self) -> u32 {
+        self.0
+    }
+
+    fn incremented(&self) -> Self {
+        OpaqueRustTypeSwiftName(self.0 + 1)
+    }
+}
@@ -119,6 +119,7 @@ mod ffi {
         ) -> Option<OptionStruct>;
 
         fn test_rust_calls_swift_option_primitive();
+        fn test_rust_calls_swift_option_references();
     }
 
     extern "Swift" {
@@ -135,6 +136,11 @@ mod ffi {
         fn swift_reflect_option_f32(arg: Option<f32>) -> Option<f32>;
         fn swift_reflect_option_f64(arg: Option<f64>) -> Option<f64>;
         fn swift_reflect_option_bool(arg: Option<bool>) -> Option<bool>;
+
+        fn swift_reflect_option_str(arg: Option<&str>) -> Option<&str>;
+        fn swift_reflect_option_ref_opaque_rust_type(
+            arg: Option<&OptTestOpaqueRustType>,
+        ) -> Option<&OptTestOpaqueRustType>;
     }
 }
 
@@ -174,6 +180,17 @@ fn test_rust_calls_swift_option_primitive() {
     assert_eq!(ffi::swift_reflect_option_bool(None), None);
 }
 
+fn test_rust_calls_swift_option_references() {
+    assert_eq!(ffi::swift_reflect_option_str(Some("hello")), Some("hello"));
+    assert_eq!(ffi::swift_reflect_option_str(Some("")), Some(""));
+    assert_eq!(ffi::swift_reflect_option_str(None), None);
+
+    let opaque = OptTestOpaqueRustType::new(123);
+    let reflected = ffi::swift_reflect_option_ref_opaque_rust_type(Some(&opaque)).unwrap();
+    assert!(std::ptr::eq(reflected, &opaque));
+    assert!(ffi::swift_reflect_option_ref_opaque_rust_type(None).is_none());
+}
+
 pub struct OptTestOpaqueRustType {
     field: u8,
 }
@@ -0,0 +1,55 @@
+use std::path::{Path, PathBuf};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_path_buf(path: PathBuf) -> PathBuf;
+        fn rust_path_file_name(path: &Path) -> String;
+        fn rust_make_non_utf8_path() -> PathBuf;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_path_buf(path: PathBuf) -> PathBuf;
+        fn swift_append_path_component(path: &Path, component: &str) -> PathBuf;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_path();
+    }
+}
+
+fn rust_reflect_path_buf(path: PathBuf) -> PathBuf {
+    path
+}
+
+fn rust_path_file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// A path that is not valid UTF-8 on platforms where paths are arbitrary bytes.
+fn rust_make_non_utf8_path() -> PathBuf {
+    #[cfg(unix)]
+    {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        PathBuf::from(OsStr::from_bytes(b"/tmp/invalid-\xFF"))
+    }
+
+    #[cfg(not(unix))]
+    {
+        PathBuf::from("/tmp/invalid-\u{FFFD}")
+    }
+}
+
+fn test_rust_calls_swift_path() {
+    let path = PathBuf::from("/Users/someone/Documents");
+    assert_eq!(ffi::swift_reflect_path_buf(path.clone()), path);
+
+    assert_eq!(
+        ffi::swift_append_path_component(&path, "notes.txt"),
+        PathBuf::from("/Users/someone/Documents/notes.txt")
+    );
+}
@@ -4,10 +4,18 @@ use std::ffi::c_void;
 mod ffi {
     // Opaque pointers.
     extern "Rust" {
+        #[swift_bridge(unsafe_pointer)]
         fn rust_echo_const_c_void(ptr: *const c_void) -> *const c_void;
+        #[swift_bridge(unsafe_pointer)]
         fn rust_echo_mut_c_void(ptr: *mut c_void) -> *mut c_void;
+        #[swift_bridge(unsafe_pointer)]
+        fn rust_echo_fully_qualified_c_void(
+            ptr: *const std::ffi::c_void,
+        ) -> *const std::ffi::c_void;
 
+        #[swift_bridge(unsafe_pointer)]
         fn rust_echo_const_u8(ptr: *const u8) -> *const u8;
+        #[swift_bridge(unsafe_pointer)]
         fn rust_echo_mut_u8(ptr: *mut u8) -> *mut u8;
 
         fn rust_run_opaque_pointer_tests();
@@ -16,10 +24,14 @@ mod ffi {
 
     // Opaque pointers.
     extern "Swift" {
+        #[swift_bridge(unsafe_pointer)]
         fn swift_echo_const_c_void(ptr: *const c_void) -> *const c_void;
+        #[swift_bridge(unsafe_pointer)]
         fn swift_echo_mut_c_void(ptr: *mut c_void) -> *mut c_void;
 
+        #[swift_bridge(unsafe_pointer)]
         fn swift_echo_const_u8(ptr: *const u8) -> *const u8;
+        #[swift_bridge(unsafe_pointer)]
         fn swift_echo_mut_u8(ptr: *mut u8) -> *mut u8;
     }
 }
@@ -64,6 +76,10 @@ fn rust_echo_mut_c_void(ptr: *mut c_void) -> *mut c_void {
     ptr
 }
 
+fn rust_echo_fully_qualified_c_void(ptr: *const c_void) -> *const c_void {
+    ptr
+}
+
 fn rust_echo_const_u8(ptr: *const u8) -> *const u8 {
     ptr
 }
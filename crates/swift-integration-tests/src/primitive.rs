@@ -14,6 +14,8 @@ mod ffi {
         fn rust_double_f32(arg: f32) -> f32;
         fn rust_double_f64(arg: f64) -> f64;
         fn rust_negate_bool(arg: bool) -> bool;
+        fn rust_double_u128(arg: u128) -> u128;
+        fn rust_double_i128(arg: i128) -> i128;
     }
 
     extern "Swift" {
@@ -28,6 +30,8 @@ mod ffi {
         fn swift_double_f32(arg: f32) -> f32;
         fn swift_double_f64(arg: f64) -> f64;
         fn swift_negate_bool(arg: bool) -> bool;
+        fn swift_reflect_u128(arg: u128) -> u128;
+        fn swift_reflect_i128(arg: i128) -> i128;
     }
 }
 
@@ -44,6 +48,9 @@ fn test_rust_calls_swift_primitives() {
     assert_eq!(ffi::swift_double_f64(5.), 10.);
     assert_eq!(ffi::swift_negate_bool(true), false);
     assert_eq!(ffi::swift_negate_bool(false), true);
+    assert_eq!(ffi::swift_reflect_u128(u128::MAX), u128::MAX);
+    assert_eq!(ffi::swift_reflect_i128(i128::MIN), i128::MIN);
+    assert_eq!(ffi::swift_reflect_i128(-5), -5);
 }
 
 fn rust_double_u8(arg: u8) -> u8 {
@@ -89,3 +96,11 @@ fn rust_double_f64(arg: f64) -> f64 {
 fn rust_negate_bool(arg: bool) -> bool {
     !arg
 }
+
+fn rust_double_u128(arg: u128) -> u128 {
+    arg * 2
+}
+
+fn rust_double_i128(arg: i128) -> i128 {
+    arg * 2
+}
@@ -0,0 +1,51 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/publisher_codegen_tests.rs
+
+use futures_util::stream::{self, Stream};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type PublisherTestError;
+
+        fn number(&self) -> u32;
+
+        #[swift_bridge(publisher)]
+        fn rust_count_to(count: u32) -> impl Stream<Item = u32>;
+
+        #[swift_bridge(publisher)]
+        fn rust_words(sentence: &str) -> impl Stream<Item = String>;
+
+        /// Produces the numbers up to `count`, and then fails with an error holding `count + 1`.
+        #[swift_bridge(publisher)]
+        fn rust_count_to_then_fail(
+            count: u32,
+        ) -> impl Stream<Item = Result<u32, PublisherTestError>>;
+    }
+}
+
+pub struct PublisherTestError(u32);
+
+impl PublisherTestError {
+    fn number(&self) -> u32 {
+        self.0
+    }
+}
+
+fn rust_count_to(count: u32) -> impl Stream<Item = u32> {
+    stream::iter(1..=count)
+}
+
+fn rust_words(sentence: &str) -> impl Stream<Item = String> {
+    let words: Vec<String> = sentence.split_whitespace().map(str::to_string).collect();
+    stream::iter(words)
+}
+
+fn rust_count_to_then_fail(count: u32) -> impl Stream<Item = Result<u32, PublisherTestError>> {
+    stream::iter((1..=count + 1).map(move |number| {
+        if number <= count {
+            Ok(number)
+        } else {
+            Err(PublisherTestError(number))
+        }
+    }))
+}
@@ -21,6 +21,8 @@ mod ffi {
         fn rust_func_return_result_unit_struct_opaque_rust(
             succeed: bool,
         ) -> Result<UnitStruct, ResultTestOpaqueRustType>;
+
+        fn rust_func_return_result_static_str(succeed: bool) -> Result<&'static str, &'static str>;
     }
 
     extern "Rust" {
@@ -73,6 +75,12 @@ mod ffi {
         ) -> Result<Vec<ResultTestOpaqueRustType>, ResultTestOpaqueRustType>;
     }
 
+    extern "Rust" {
+        fn rust_func_return_result_u32_opaque_rust(
+            succeed: bool,
+        ) -> Result<u32, ResultTestOpaqueRustType>;
+    }
+
     extern "Rust" {
         fn rust_func_return_result_tuple_transparent_enum(
             succeed: bool,
@@ -127,6 +135,14 @@ fn rust_func_return_result_null_opaque_rust(succeed: bool) -> Result<(), ResultT
     }
 }
 
+fn rust_func_return_result_static_str(succeed: bool) -> Result<&'static str, &'static str> {
+    if succeed {
+        Ok("OK")
+    } else {
+        Err("E_INVALID")
+    }
+}
+
 fn rust_func_return_result_unit_struct_opaque_rust(
     succeed: bool,
 ) -> Result<ffi::UnitStruct, ResultTestOpaqueRustType> {
@@ -192,6 +208,14 @@ fn rust_func_return_result_of_vec_u32() -> Result<Vec<u32>, ResultTestOpaqueRust
     Ok(vec![0, 1, 2])
 }
 
+fn rust_func_return_result_u32_opaque_rust(succeed: bool) -> Result<u32, ResultTestOpaqueRustType> {
+    if succeed {
+        Ok(123)
+    } else {
+        Err(ResultTestOpaqueRustType::new(456))
+    }
+}
+
 fn rust_func_return_result_of_vec_opaque(
 ) -> Result<Vec<ResultTestOpaqueRustType>, ResultTestOpaqueRustType> {
     Ok(vec![
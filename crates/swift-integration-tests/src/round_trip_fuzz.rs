@@ -0,0 +1,477 @@
+//! Random values of each built-in type get sent across the bridge and back and are checked for
+//! equality, in order to catch conversions that truncate values, swap their bytes or mangle their
+//! encoding.
+//!
+//! Rust sends values to Swift in `fuzz_rust_calls_swift`. Swift sends values to Rust in
+//! `RoundTripFuzzTests.swift`.
+//!
+//! A failed assertion prints the seed that the values were generated with, which reproduces the
+//! failure when passed back into `fuzz_rust_calls_swift`.
+
+use std::borrow::Cow;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use swift_bridge::ns_error::NsError;
+use uuid::Uuid;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn fuzz_rust_calls_swift(seed: u64, iterations: u32);
+
+        fn fuzz_rust_reflect_u8(arg: u8) -> u8;
+        fn fuzz_rust_reflect_i8(arg: i8) -> i8;
+        fn fuzz_rust_reflect_u16(arg: u16) -> u16;
+        fn fuzz_rust_reflect_i16(arg: i16) -> i16;
+        fn fuzz_rust_reflect_u32(arg: u32) -> u32;
+        fn fuzz_rust_reflect_i32(arg: i32) -> i32;
+        fn fuzz_rust_reflect_u64(arg: u64) -> u64;
+        fn fuzz_rust_reflect_i64(arg: i64) -> i64;
+        fn fuzz_rust_reflect_usize(arg: usize) -> usize;
+        fn fuzz_rust_reflect_isize(arg: isize) -> isize;
+        fn fuzz_rust_reflect_u128(arg: u128) -> u128;
+        fn fuzz_rust_reflect_i128(arg: i128) -> i128;
+        fn fuzz_rust_reflect_f32(arg: f32) -> f32;
+        fn fuzz_rust_reflect_f64(arg: f64) -> f64;
+        fn fuzz_rust_reflect_bool(arg: bool) -> bool;
+        fn fuzz_rust_reflect_string(arg: String) -> String;
+        fn fuzz_rust_reflect_vec_u8(arg: Vec<u8>) -> Vec<u8>;
+        fn fuzz_rust_reflect_vec_i64(arg: Vec<i64>) -> Vec<i64>;
+        fn fuzz_rust_reflect_option_u32(arg: Option<u32>) -> Option<u32>;
+        fn fuzz_rust_reflect_option_f64(arg: Option<f64>) -> Option<f64>;
+        fn fuzz_rust_reflect_option_string(arg: Option<String>) -> Option<String>;
+        fn fuzz_rust_reflect_uuid(arg: Uuid) -> Uuid;
+        fn fuzz_rust_reflect_duration(arg: Duration) -> Duration;
+        fn fuzz_rust_reflect_u8_array(arg: [u8; 16]) -> [u8; 16];
+        fn fuzz_rust_reflect_f64_array(arg: [f64; 3]) -> [f64; 3];
+        fn fuzz_rust_reflect_path_buf(arg: PathBuf) -> PathBuf;
+        fn fuzz_rust_owned_cow_str(arg: String) -> Cow<'static, str>;
+        fn fuzz_rust_reflect_result_string(arg: Result<String, String>) -> Result<String, NsError>;
+    }
+
+    extern "Rust" {
+        type RoundTripFuzzValue;
+
+        fn value(&self) -> u64;
+
+        fn fuzz_rust_make_arc(value: u64) -> Arc<RoundTripFuzzValue>;
+        fn fuzz_rust_reflect_arc(arg: Arc<RoundTripFuzzValue>) -> Arc<RoundTripFuzzValue>;
+    }
+
+    extern "Swift" {
+        fn fuzz_swift_reflect_u8(arg: u8) -> u8;
+        fn fuzz_swift_reflect_i8(arg: i8) -> i8;
+        fn fuzz_swift_reflect_u16(arg: u16) -> u16;
+        fn fuzz_swift_reflect_i16(arg: i16) -> i16;
+        fn fuzz_swift_reflect_u32(arg: u32) -> u32;
+        fn fuzz_swift_reflect_i32(arg: i32) -> i32;
+        fn fuzz_swift_reflect_u64(arg: u64) -> u64;
+        fn fuzz_swift_reflect_i64(arg: i64) -> i64;
+        fn fuzz_swift_reflect_usize(arg: usize) -> usize;
+        fn fuzz_swift_reflect_isize(arg: isize) -> isize;
+        fn fuzz_swift_reflect_u128(arg: u128) -> u128;
+        fn fuzz_swift_reflect_i128(arg: i128) -> i128;
+        fn fuzz_swift_reflect_f32(arg: f32) -> f32;
+        fn fuzz_swift_reflect_f64(arg: f64) -> f64;
+        fn fuzz_swift_reflect_bool(arg: bool) -> bool;
+        fn fuzz_swift_reflect_string(arg: String) -> String;
+        fn fuzz_swift_reflect_vec_u8(arg: Vec<u8>) -> Vec<u8>;
+        fn fuzz_swift_reflect_vec_i64(arg: Vec<i64>) -> Vec<i64>;
+        fn fuzz_swift_reflect_option_u32(arg: Option<u32>) -> Option<u32>;
+        fn fuzz_swift_reflect_option_f64(arg: Option<f64>) -> Option<f64>;
+        fn fuzz_swift_reflect_uuid(arg: Uuid) -> Uuid;
+        fn fuzz_swift_reflect_duration(arg: Duration) -> Duration;
+        fn fuzz_swift_reflect_u8_array(arg: [u8; 16]) -> [u8; 16];
+        fn fuzz_swift_reflect_f64_array(arg: [f64; 3]) -> [f64; 3];
+        fn fuzz_swift_reflect_path_buf(arg: PathBuf) -> PathBuf;
+        fn fuzz_swift_reflect_cow_str(arg: Cow<'static, str>) -> String;
+        fn fuzz_swift_reflect_arc(arg: Arc<RoundTripFuzzValue>) -> Arc<RoundTripFuzzValue>;
+        /// Returns the `arg` if the `code` is zero, and otherwise throws an error with the `code`
+        /// that holds the `arg` in its user info.
+        #[swift_bridge(catch_errors)]
+        fn fuzz_swift_reflect_result_string(arg: String, code: isize) -> Result<String, NsError>;
+    }
+}
+
+/// Send `iterations` rounds of random values, generated from the `seed`, to Swift and assert
+/// that Swift hands back the same values.
+fn fuzz_rust_calls_swift(seed: u64, iterations: u32) {
+    let mut rng = Rng::new(seed);
+
+    // Integers are biased towards the values at the edges of their range.
+    macro_rules! assert_integer_round_trips {
+        ($($ty:ty => $swift_fn:path),* $(,)?) => {
+            $(
+                let edges = [0, 1, <$ty>::MIN, <$ty>::MAX];
+                let value = if rng.one_in(4) {
+                    edges[rng.below(edges.len())]
+                } else {
+                    rng.next_u128() as $ty
+                };
+                assert_eq!($swift_fn(value), value, "seed: {}", seed);
+            )*
+        };
+    }
+
+    for _ in 0..iterations {
+        assert_integer_round_trips!(
+            u8 => ffi::fuzz_swift_reflect_u8,
+            i8 => ffi::fuzz_swift_reflect_i8,
+            u16 => ffi::fuzz_swift_reflect_u16,
+            i16 => ffi::fuzz_swift_reflect_i16,
+            u32 => ffi::fuzz_swift_reflect_u32,
+            i32 => ffi::fuzz_swift_reflect_i32,
+            u64 => ffi::fuzz_swift_reflect_u64,
+            i64 => ffi::fuzz_swift_reflect_i64,
+            usize => ffi::fuzz_swift_reflect_usize,
+            isize => ffi::fuzz_swift_reflect_isize,
+            u128 => ffi::fuzz_swift_reflect_u128,
+            i128 => ffi::fuzz_swift_reflect_i128,
+        );
+
+        // Floats are compared bit for bit, so that NaNs and negative zero are checked too.
+        let value = rng.f32();
+        assert_eq!(
+            ffi::fuzz_swift_reflect_f32(value).to_bits(),
+            value.to_bits(),
+            "seed: {}",
+            seed
+        );
+        let value = rng.f64();
+        assert_eq!(
+            ffi::fuzz_swift_reflect_f64(value).to_bits(),
+            value.to_bits(),
+            "seed: {}",
+            seed
+        );
+
+        let value = rng.one_in(2);
+        assert_eq!(ffi::fuzz_swift_reflect_bool(value), value, "seed: {}", seed);
+
+        let value = rng.string();
+        assert_eq!(
+            ffi::fuzz_swift_reflect_string(value.clone()),
+            value,
+            "seed: {}",
+            seed
+        );
+
+        let value: Vec<u8> = (0..rng.below(64)).map(|_| rng.next_u64() as u8).collect();
+        assert_eq!(
+            ffi::fuzz_swift_reflect_vec_u8(value.clone()),
+            value,
+            "seed: {}",
+            seed
+        );
+        let value: Vec<i64> = (0..rng.below(64)).map(|_| rng.next_u64() as i64).collect();
+        assert_eq!(
+            ffi::fuzz_swift_reflect_vec_i64(value.clone()),
+            value,
+            "seed: {}",
+            seed
+        );
+
+        let value = (!rng.one_in(4)).then(|| rng.next_u64() as u32);
+        assert_eq!(
+            ffi::fuzz_swift_reflect_option_u32(value),
+            value,
+            "seed: {}",
+            seed
+        );
+        let value = (!rng.one_in(4)).then(|| rng.f64());
+        assert_eq!(
+            ffi::fuzz_swift_reflect_option_f64(value).map(f64::to_bits),
+            value.map(f64::to_bits),
+            "seed: {}",
+            seed
+        );
+
+        let value = Uuid::from_u128(rng.next_u128());
+        assert_eq!(ffi::fuzz_swift_reflect_uuid(value), value, "seed: {}", seed);
+
+        // A `TimeInterval` has nanosecond precision for durations of up to 2^20 seconds.
+        let value = Duration::new(rng.below(1 << 20) as u64, rng.below(1_000_000_000) as u32);
+        assert_eq!(
+            ffi::fuzz_swift_reflect_duration(value),
+            value,
+            "seed: {}",
+            seed
+        );
+
+        let value: [u8; 16] = std::array::from_fn(|_| rng.next_u64() as u8);
+        assert_eq!(
+            ffi::fuzz_swift_reflect_u8_array(value),
+            value,
+            "seed: {}",
+            seed
+        );
+        let value = [rng.f64(), rng.f64(), rng.f64()];
+        assert_eq!(
+            ffi::fuzz_swift_reflect_f64_array(value).map(f64::to_bits),
+            value.map(f64::to_bits),
+            "seed: {}",
+            seed
+        );
+
+        let value = PathBuf::from(rng.string());
+        assert_eq!(
+            ffi::fuzz_swift_reflect_path_buf(value.clone()),
+            value,
+            "seed: {}",
+            seed
+        );
+
+        let value = rng.string();
+        assert_eq!(
+            ffi::fuzz_swift_reflect_cow_str(Cow::Owned(value.clone())),
+            value,
+            "seed: {}",
+            seed
+        );
+        let value = STATIC_STRS[rng.below(STATIC_STRS.len())];
+        assert_eq!(
+            ffi::fuzz_swift_reflect_cow_str(Cow::Borrowed(value)),
+            value,
+            "seed: {}",
+            seed
+        );
+
+        let value = Arc::new(RoundTripFuzzValue(rng.next_u64()));
+        let reflected = ffi::fuzz_swift_reflect_arc(value.clone());
+        assert!(Arc::ptr_eq(&reflected, &value), "seed: {}", seed);
+        assert_eq!(Arc::strong_count(&value), 2, "seed: {}", seed);
+
+        let value = rng.string();
+        let code = if rng.one_in(2) {
+            0
+        } else {
+            rng.next_u64() as isize
+        };
+        match ffi::fuzz_swift_reflect_result_string(value.clone(), code) {
+            Ok(ok) => {
+                assert_eq!(code, 0, "seed: {}", seed);
+                assert_eq!(ok, value, "seed: {}", seed);
+            }
+            Err(err) => {
+                assert_eq!(err.code, code, "seed: {}", seed);
+                assert_eq!(err.user_info["value"], value, "seed: {}", seed);
+            }
+        }
+    }
+}
+
+/// `&'static str`s for the borrowed `Cow`s, since those can't be generated at runtime.
+const STATIC_STRS: [&str; 5] = ["", "\0", "ascii", "héllo wörld", "🦀 日本語"];
+
+pub struct RoundTripFuzzValue(u64);
+
+impl RoundTripFuzzValue {
+    fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A xorshift generator, so that a failure can be reproduced from its seed.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // A xorshift generator gets stuck at zero.
+        Rng(seed | 1)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn next_u128(&mut self) -> u128 {
+        (self.next_u64() as u128) << 64 | self.next_u64() as u128
+    }
+
+    /// A random number in `0..max`.
+    fn below(&mut self, max: usize) -> usize {
+        (self.next_u64() % max as u64) as usize
+    }
+
+    fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    fn f32(&mut self) -> f32 {
+        let edges = [
+            0.,
+            -0.,
+            f32::MIN,
+            f32::MAX,
+            f32::MIN_POSITIVE,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NAN,
+        ];
+        if self.one_in(4) {
+            edges[self.below(edges.len())]
+        } else {
+            f32::from_bits(self.next_u64() as u32)
+        }
+    }
+
+    fn f64(&mut self) -> f64 {
+        let edges = [
+            0.,
+            -0.,
+            f64::MIN,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+        ];
+        if self.one_in(4) {
+            edges[self.below(edges.len())]
+        } else {
+            f64::from_bits(self.next_u64())
+        }
+    }
+
+    /// A string mixing ASCII, NUL bytes and characters that take two, three and four bytes to
+    /// encode in UTF-8.
+    fn string(&mut self) -> String {
+        (0..self.below(32))
+            .map(|_| {
+                let ranges: [(u32, u32); 4] = [
+                    (0, 0x80),
+                    (0x80, 0x800),
+                    (0x800, 0xD800),
+                    (0x10000, 0x110000),
+                ];
+                let (start, end) = ranges[self.below(ranges.len())];
+                let code_point = start + self.below((end - start) as usize) as u32;
+                char::from_u32(code_point).unwrap()
+            })
+            .collect()
+    }
+}
+
+fn fuzz_rust_reflect_u8(arg: u8) -> u8 {
+    arg
+}
+
+fn fuzz_rust_reflect_i8(arg: i8) -> i8 {
+    arg
+}
+
+fn fuzz_rust_reflect_u16(arg: u16) -> u16 {
+    arg
+}
+
+fn fuzz_rust_reflect_i16(arg: i16) -> i16 {
+    arg
+}
+
+fn fuzz_rust_reflect_u32(arg: u32) -> u32 {
+    arg
+}
+
+fn fuzz_rust_reflect_i32(arg: i32) -> i32 {
+    arg
+}
+
+fn fuzz_rust_reflect_u64(arg: u64) -> u64 {
+    arg
+}
+
+fn fuzz_rust_reflect_i64(arg: i64) -> i64 {
+    arg
+}
+
+fn fuzz_rust_reflect_usize(arg: usize) -> usize {
+    arg
+}
+
+fn fuzz_rust_reflect_isize(arg: isize) -> isize {
+    arg
+}
+
+fn fuzz_rust_reflect_u128(arg: u128) -> u128 {
+    arg
+}
+
+fn fuzz_rust_reflect_i128(arg: i128) -> i128 {
+    arg
+}
+
+fn fuzz_rust_reflect_f32(arg: f32) -> f32 {
+    arg
+}
+
+fn fuzz_rust_reflect_f64(arg: f64) -> f64 {
+    arg
+}
+
+fn fuzz_rust_reflect_bool(arg: bool) -> bool {
+    arg
+}
+
+fn fuzz_rust_reflect_string(arg: String) -> String {
+    arg
+}
+
+fn fuzz_rust_reflect_vec_u8(arg: Vec<u8>) -> Vec<u8> {
+    arg
+}
+
+fn fuzz_rust_reflect_vec_i64(arg: Vec<i64>) -> Vec<i64> {
+    arg
+}
+
+fn fuzz_rust_reflect_option_u32(arg: Option<u32>) -> Option<u32> {
+    arg
+}
+
+fn fuzz_rust_reflect_option_f64(arg: Option<f64>) -> Option<f64> {
+    arg
+}
+
+fn fuzz_rust_reflect_option_string(arg: Option<String>) -> Option<String> {
+    arg
+}
+
+fn fuzz_rust_reflect_uuid(arg: Uuid) -> Uuid {
+    arg
+}
+
+fn fuzz_rust_reflect_duration(arg: Duration) -> Duration {
+    arg
+}
+
+fn fuzz_rust_reflect_u8_array(arg: [u8; 16]) -> [u8; 16] {
+    arg
+}
+
+fn fuzz_rust_reflect_f64_array(arg: [f64; 3]) -> [f64; 3] {
+    arg
+}
+
+fn fuzz_rust_reflect_path_buf(arg: PathBuf) -> PathBuf {
+    arg
+}
+
+fn fuzz_rust_owned_cow_str(arg: String) -> Cow<'static, str> {
+    Cow::Owned(arg)
+}
+
+fn fuzz_rust_reflect_result_string(arg: Result<String, String>) -> Result<String, NsError> {
+    arg.map_err(|value| NsError::new("RoundTripFuzz", 0).with_user_info("value", value))
+}
+
+fn fuzz_rust_make_arc(value: u64) -> Arc<RoundTripFuzzValue> {
+    Arc::new(RoundTripFuzzValue(value))
+}
+
+fn fuzz_rust_reflect_arc(arg: Arc<RoundTripFuzzValue>) -> Arc<RoundTripFuzzValue> {
+    arg
+}
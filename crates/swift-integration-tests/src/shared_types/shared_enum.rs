@@ -7,6 +7,11 @@ mod ffi {
 
     extern "Rust" {
         fn reflect_enum_with_no_data(arg: EnumWithNoData) -> EnumWithNoData;
+        fn test_rust_calls_swift_enum_with_no_data();
+    }
+
+    extern "Swift" {
+        fn swift_reflect_enum_with_no_data(arg: EnumWithNoData) -> EnumWithNoData;
     }
 
     extern "Rust" {
@@ -72,6 +77,14 @@ fn reflect_enum_with_no_data(arg: ffi::EnumWithNoData) -> ffi::EnumWithNoData {
     arg
 }
 
+fn test_rust_calls_swift_enum_with_no_data() {
+    let reflected = ffi::swift_reflect_enum_with_no_data(ffi::EnumWithNoData::Variant1);
+    assert!(matches!(reflected, ffi::EnumWithNoData::Variant1));
+
+    let reflected = ffi::swift_reflect_enum_with_no_data(ffi::EnumWithNoData::Variant2);
+    assert!(matches!(reflected, ffi::EnumWithNoData::Variant2));
+}
+
 fn reflect_enum_with_unnamed_data(arg: ffi::EnumWithUnnamedData) -> ffi::EnumWithUnnamedData {
     arg
 }
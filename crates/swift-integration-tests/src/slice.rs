@@ -1,10 +1,78 @@
-// TODO:
-// - Define struct SliceTestOpaqueRustType
-// - Add a method to create Vec<SliceTestOpaqueRustType>
-// - Add method to reflect &[OpaqueRustType]
-//   - Add test to verify that we can iterate over the slice
-//   - Add test to verify that that we can index into the slice
-//   - Add test to verify that we can get the length of the slice
-// - Create SliceTests.swift
-//   - Add Swift test verifying that we can use an Array<SliceTestOpaqueRustType> as a slice
-//   - Add Swift test verifying that we can use a RustVec<SliceTestOpaqueRustType> as a slice
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SliceTestOpaqueRustType;
+
+        #[swift_bridge(init)]
+        fn new(val: i32) -> SliceTestOpaqueRustType;
+
+        fn val(&self) -> i32;
+    }
+
+    extern "Rust" {
+        type SliceTestOpaqueRustTypeList;
+
+        #[swift_bridge(init)]
+        fn new(count: i32) -> SliceTestOpaqueRustTypeList;
+
+        fn as_slice(&self) -> &[SliceTestOpaqueRustType];
+    }
+
+    extern "Rust" {
+        fn rust_sum_slice_of_opaque_rust_type(slice: &[SliceTestOpaqueRustType]) -> i32;
+        fn rust_make_vec_of_slice_test_opaque_rust_type(count: i32)
+            -> Vec<SliceTestOpaqueRustType>;
+    }
+
+    extern "Swift" {
+        fn swift_sum_slice_of_opaque_rust_type(slice: &[SliceTestOpaqueRustType]) -> i32;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_slice_of_opaque_rust_type();
+    }
+}
+
+pub struct SliceTestOpaqueRustType {
+    val: i32,
+}
+
+impl SliceTestOpaqueRustType {
+    fn new(val: i32) -> Self {
+        SliceTestOpaqueRustType { val }
+    }
+
+    fn val(&self) -> i32 {
+        self.val
+    }
+}
+
+pub struct SliceTestOpaqueRustTypeList {
+    items: Vec<SliceTestOpaqueRustType>,
+}
+
+impl SliceTestOpaqueRustTypeList {
+    fn new(count: i32) -> Self {
+        SliceTestOpaqueRustTypeList {
+            items: rust_make_vec_of_slice_test_opaque_rust_type(count),
+        }
+    }
+
+    fn as_slice(&self) -> &[SliceTestOpaqueRustType] {
+        &self.items
+    }
+}
+
+fn rust_sum_slice_of_opaque_rust_type(slice: &[SliceTestOpaqueRustType]) -> i32 {
+    slice.iter().map(|item| item.val).sum()
+}
+
+fn rust_make_vec_of_slice_test_opaque_rust_type(count: i32) -> Vec<SliceTestOpaqueRustType> {
+    (0..count).map(SliceTestOpaqueRustType::new).collect()
+}
+
+fn test_rust_calls_swift_slice_of_opaque_rust_type() {
+    let items = rust_make_vec_of_slice_test_opaque_rust_type(5);
+    assert_eq!(ffi::swift_sum_slice_of_opaque_rust_type(&items), 10);
+    assert_eq!(ffi::swift_sum_slice_of_opaque_rust_type(&items[..2]), 1);
+}
@@ -4,6 +4,23 @@ mod ffi {
         fn run_string_tests();
 
         fn create_string(str: &str) -> String;
+
+        fn count_str_bytes(str: &str) -> usize;
+
+        #[swift_bridge(unchecked_utf8)]
+        fn count_str_bytes_unchecked(str: &str, maybe_str: Option<&str>) -> usize;
+
+        #[swift_bridge(return_scratch)]
+        fn format_greeting(name: &str) -> &str;
+    }
+
+    extern "Rust" {
+        type RustTypeWithName;
+
+        #[swift_bridge(init)]
+        fn new(name: String) -> RustTypeWithName;
+        fn name(&self) -> String;
+        fn set_name(&mut self, name: String);
     }
 
     extern "Swift" {
@@ -26,3 +43,34 @@ fn run_string_tests() {
 fn create_string(str: &str) -> String {
     str.to_string()
 }
+
+fn count_str_bytes(str: &str) -> usize {
+    str.len()
+}
+
+fn count_str_bytes_unchecked(str: &str, maybe_str: Option<&str>) -> usize {
+    str.len() + maybe_str.map(str::len).unwrap_or(0)
+}
+
+fn format_greeting(name: &str, scratch: &mut String) {
+    scratch.push_str("Hello, ");
+    scratch.push_str(name);
+}
+
+pub struct RustTypeWithName {
+    name: String,
+}
+
+impl RustTypeWithName {
+    fn new(name: String) -> Self {
+        RustTypeWithName { name }
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+}
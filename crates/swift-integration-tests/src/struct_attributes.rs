@@ -1,3 +1,4 @@
 mod already_declared;
 mod derive;
+mod identifiable;
 mod swift_name;
@@ -0,0 +1,13 @@
+/// We declare a shared struct that uses the `Identifiable` attribute so that it can be used
+/// with SwiftUI's `List` and `ForEach`.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/transparent_struct_codegen_tests.rs
+///   - identifiable_struct
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", Identifiable)]
+    struct IdentifiableStructTest {
+        id: u32,
+        name: String,
+    }
+}
@@ -12,10 +12,12 @@ mod ffi {
         fn increment_counter(&mut self, amount: u32);
 
         fn test_call_swift_fn_with_owned_opaque_rust_arg();
+        fn test_call_swift_fn_with_mut_opaque_rust_arg();
     }
 
     extern "Swift" {
         fn increment_some_owned_opaque_rust_type(arg: SomeRustType, amount: u32);
+        fn increment_some_mut_opaque_rust_type(arg: &mut SomeRustType, amount: u32);
     }
 }
 
@@ -35,6 +37,16 @@ fn test_call_swift_fn_with_owned_opaque_rust_arg() {
     assert_eq!(counter.take(), 15);
 }
 
+fn test_call_swift_fn_with_mut_opaque_rust_arg() {
+    let mut some_rust_type = SomeRustType::new(5);
+
+    ffi::increment_some_mut_opaque_rust_type(&mut some_rust_type, 10);
+    ffi::increment_some_mut_opaque_rust_type(&mut some_rust_type, 20);
+
+    // Swift only borrowed `some_rust_type`, so we still own it.
+    assert_eq!(*some_rust_type.counter.borrow(), 35);
+}
+
 pub struct SomeRustType {
     counter: Rc<RefCell<u32>>,
 }
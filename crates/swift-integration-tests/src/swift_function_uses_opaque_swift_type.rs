@@ -3,6 +3,8 @@ mod ffi {
     extern "Rust" {
         fn test_rust_calls_swift_fn_reflects_owned_opaque_swift_type();
         fn test_rust_calls_swift_method_reflects_owned_opaque_swift_type();
+        fn test_rust_calls_swift_fn_with_mut_opaque_swift_type();
+        fn rust_fn_doubles_mut_opaque_swift_type(arg: &mut ASwiftType);
     }
 
     extern "Swift" {
@@ -11,10 +13,13 @@ mod ffi {
         #[swift_bridge(init)]
         fn new(amount: u32) -> ASwiftType;
         fn amount(&self) -> u32;
+        fn set_amount(&mut self, amount: u32);
 
         fn call_swift_fn_reflects_owned_opaque_swift_type(arg: ASwiftType) -> ASwiftType;
         fn call_swift_method_reflects_owned_opaque_swift_type(&self, arg: ASwiftType)
             -> ASwiftType;
+        fn call_swift_fn_increments_mut_opaque_swift_type(arg: &mut ASwiftType, amount: u32);
+        fn call_swift_fn_reads_ref_opaque_swift_type(arg: &ASwiftType) -> u32;
     }
 }
 
@@ -35,3 +40,21 @@ fn test_rust_calls_swift_method_reflects_owned_opaque_swift_type() {
         swift_ty.call_swift_method_reflects_owned_opaque_swift_type(swift_ty2);
     assert_eq!(reflected_via_method.amount(), 333);
 }
+
+fn test_rust_calls_swift_fn_with_mut_opaque_swift_type() {
+    let mut swift_ty = ffi::ASwiftType::new(5);
+
+    ffi::call_swift_fn_increments_mut_opaque_swift_type(&mut swift_ty, 10);
+    assert_eq!(
+        ffi::call_swift_fn_reads_ref_opaque_swift_type(&swift_ty),
+        15
+    );
+
+    swift_ty.set_amount(100);
+    assert_eq!(swift_ty.amount(), 100);
+}
+
+fn rust_fn_doubles_mut_opaque_swift_type(arg: &mut ffi::ASwiftType) {
+    let amount = arg.amount();
+    arg.set_amount(amount * 2);
+}
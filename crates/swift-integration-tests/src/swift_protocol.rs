@@ -0,0 +1,37 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_add_to_swift_protocol_test_counter(
+            counter: SwiftProtocolTestCounter,
+            amounts: Vec<u32>,
+        ) -> u32;
+        fn rust_reflect_swift_protocol_test_counter(
+            counter: SwiftProtocolTestCounter,
+        ) -> SwiftProtocolTestCounter;
+    }
+
+    extern "Swift" {
+        #[swift_bridge(protocol)]
+        type SwiftProtocolTestCounter;
+
+        fn add(&self, amount: u32);
+        fn total(&self) -> u32;
+    }
+}
+
+fn rust_add_to_swift_protocol_test_counter(
+    counter: ffi::SwiftProtocolTestCounter,
+    amounts: Vec<u32>,
+) -> u32 {
+    for amount in amounts {
+        counter.add(amount);
+    }
+
+    counter.total()
+}
+
+fn rust_reflect_swift_protocol_test_counter(
+    counter: ffi::SwiftProtocolTestCounter,
+) -> ffi::SwiftProtocolTestCounter {
+    counter
+}
@@ -0,0 +1,47 @@
+//! Verify that the `#[swift_bridge::bridge(swift_type_prefix = "...")]` attribute prefixes the
+//! Swift names of the module's Rust types.
+//!
+//! See SwiftRustIntegrationTestRunner/SwiftRustIntegrationTestRunnerTests/SwiftTypePrefixTests.swift
+
+#[swift_bridge::bridge(swift_type_prefix = "PrefixTest")]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct User {
+        id: u32,
+    }
+
+    enum Message {
+        Hello,
+        Goodbye,
+    }
+
+    extern "Rust" {
+        type Session;
+
+        #[swift_bridge(init)]
+        fn new(user: User) -> Session;
+        fn user_id(&self) -> u32;
+        fn greet(&self, message: Message) -> Message;
+    }
+}
+
+pub struct Session {
+    user: ffi::User,
+}
+
+impl Session {
+    fn new(user: ffi::User) -> Self {
+        Session { user }
+    }
+
+    fn user_id(&self) -> u32 {
+        self.user.id
+    }
+
+    fn greet(&self, message: ffi::Message) -> ffi::Message {
+        match message {
+            ffi::Message::Hello => ffi::Message::Goodbye,
+            ffi::Message::Goodbye => ffi::Message::Hello,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_duration(duration: Duration) -> Duration;
+        fn rust_reflect_system_time(time: SystemTime) -> SystemTime;
+        fn rust_make_system_time(secs_since_epoch: u64) -> SystemTime;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_duration(duration: Duration) -> Duration;
+        fn swift_reflect_system_time(time: SystemTime) -> SystemTime;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_time();
+    }
+}
+
+fn rust_reflect_duration(duration: Duration) -> Duration {
+    duration
+}
+
+fn rust_reflect_system_time(time: SystemTime) -> SystemTime {
+    time
+}
+
+fn rust_make_system_time(secs_since_epoch: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(secs_since_epoch)
+}
+
+fn test_rust_calls_swift_time() {
+    let duration = Duration::new(5, 250_000_000);
+    assert_eq!(ffi::swift_reflect_duration(duration), duration);
+
+    let time = UNIX_EPOCH + Duration::from_secs(1_000_000);
+    assert_eq!(ffi::swift_reflect_system_time(time), time);
+
+    let before_epoch = UNIX_EPOCH - Duration::from_millis(1_500);
+    assert_eq!(ffi::swift_reflect_system_time(before_epoch), before_epoch);
+}
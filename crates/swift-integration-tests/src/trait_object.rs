@@ -0,0 +1,79 @@
+#[swift_bridge::bridge]
+mod ffi {
+    trait TraitObjectTestShape {
+        fn area(&self) -> f64;
+        fn scale(&mut self, factor: f64);
+        fn name(&self) -> String;
+    }
+
+    extern "Rust" {
+        fn rust_make_trait_object_test_square(side: f64) -> Box<dyn TraitObjectTestShape>;
+        fn rust_make_trait_object_test_circle(radius: f64) -> Box<dyn TraitObjectTestShape>;
+        fn rust_reflect_trait_object_test_shape(
+            shape: Box<dyn TraitObjectTestShape>,
+        ) -> Box<dyn TraitObjectTestShape>;
+        fn rust_total_area_of_trait_object_test_shapes(
+            shapes: Vec<Box<dyn TraitObjectTestShape>>,
+        ) -> f64;
+    }
+}
+
+pub trait TraitObjectTestShape {
+    fn area(&self) -> f64;
+    fn scale(&mut self, factor: f64);
+    fn name(&self) -> String;
+}
+
+struct Square {
+    side: f64,
+}
+
+impl TraitObjectTestShape for Square {
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+
+    fn scale(&mut self, factor: f64) {
+        self.side *= factor;
+    }
+
+    fn name(&self) -> String {
+        "square".to_string()
+    }
+}
+
+struct Circle {
+    radius: f64,
+}
+
+impl TraitObjectTestShape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+
+    fn scale(&mut self, factor: f64) {
+        self.radius *= factor;
+    }
+
+    fn name(&self) -> String {
+        "circle".to_string()
+    }
+}
+
+fn rust_make_trait_object_test_square(side: f64) -> Box<dyn TraitObjectTestShape> {
+    Box::new(Square { side })
+}
+
+fn rust_make_trait_object_test_circle(radius: f64) -> Box<dyn TraitObjectTestShape> {
+    Box::new(Circle { radius })
+}
+
+fn rust_reflect_trait_object_test_shape(
+    shape: Box<dyn TraitObjectTestShape>,
+) -> Box<dyn TraitObjectTestShape> {
+    shape
+}
+
+fn rust_total_area_of_trait_object_test_shapes(shapes: Vec<Box<dyn TraitObjectTestShape>>) -> f64 {
+    shapes.iter().map(|shape| shape.area()).sum()
+}
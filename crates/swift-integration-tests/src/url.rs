@@ -0,0 +1,37 @@
+use url::Url;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_url(url: Url) -> Url;
+        fn rust_url_query_value(url: Url, key: &str) -> Option<String>;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_url(url: Url) -> Url;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_url();
+    }
+}
+
+fn rust_reflect_url(url: Url) -> Url {
+    url
+}
+
+fn rust_url_query_value(url: Url, key: &str) -> Option<String> {
+    url.query_pairs()
+        .find(|(k, _)| k == key)
+        .map(|(_, value)| value.into_owned())
+}
+
+fn test_rust_calls_swift_url() {
+    let url = Url::parse("https://example.com/a%20path/?q=caf%C3%A9&x=1#frag").unwrap();
+    let reflected = ffi::swift_reflect_url(url.clone());
+    assert_eq!(reflected, url);
+    assert_eq!(reflected.as_str(), url.as_str());
+
+    let url = Url::parse("file:///tmp/some%20file.txt").unwrap();
+    assert_eq!(ffi::swift_reflect_url(url.clone()), url);
+}
@@ -0,0 +1,33 @@
+use uuid::Uuid;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_uuid(uuid: Uuid) -> Uuid;
+        fn rust_uuid_to_string(uuid: Uuid) -> String;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_uuid(uuid: Uuid) -> Uuid;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_uuid();
+    }
+}
+
+fn rust_reflect_uuid(uuid: Uuid) -> Uuid {
+    uuid
+}
+
+fn rust_uuid_to_string(uuid: Uuid) -> String {
+    uuid.hyphenated().to_string().to_uppercase()
+}
+
+fn test_rust_calls_swift_uuid() {
+    let uuid = Uuid::from_u128(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210);
+    assert_eq!(ffi::swift_reflect_uuid(uuid), uuid);
+
+    assert_eq!(ffi::swift_reflect_uuid(Uuid::nil()), Uuid::nil());
+    assert_eq!(ffi::swift_reflect_uuid(Uuid::max()), Uuid::max());
+}
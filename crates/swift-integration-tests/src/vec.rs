@@ -26,6 +26,24 @@ mod ffi {
         ) -> Vec<TransparentEnumInsideVecT>;
     }
 
+    extern "Rust" {
+        type ARustTypeWithItems;
+
+        #[swift_bridge(init)]
+        fn new(texts: Vec<String>) -> ARustTypeWithItems;
+
+        fn items(&self) -> Vec<ARustTypeInsideVecT>;
+    }
+
+    extern "Rust" {
+        fn rust_reflect_vec_string(arg: Vec<String>) -> Vec<String>;
+    }
+
+    extern "Rust" {
+        fn rust_reflect_vec_u8(arg: Vec<u8>) -> Vec<u8>;
+        fn rust_sum_bytes(bytes: &[u8]) -> u64;
+    }
+
     extern "Rust" {
         fn run_vec_tests();
     }
@@ -33,6 +51,7 @@ mod ffi {
     extern "Swift" {
         fn swift_return_vec_u8() -> Vec<u8>;
         fn swift_arg_vec_u8(vec: Vec<u8>);
+        fn swift_return_vec_string() -> Vec<String>;
     }
 }
 
@@ -47,6 +66,11 @@ fn run_vec_tests() {
 
     let vec: Vec<u8> = vec![1, 2, 3, 4, 5];
     ffi::swift_arg_vec_u8(vec);
+
+    assert_eq!(
+        ffi::swift_return_vec_string(),
+        vec!["hello".to_string(), "world".to_string()]
+    );
 }
 
 pub struct ARustTypeInsideVecT {
@@ -74,3 +98,32 @@ fn rust_reflect_vec_transparent_enum(
 ) -> Vec<ffi::TransparentEnumInsideVecT> {
     arg
 }
+
+pub struct ARustTypeWithItems {
+    texts: Vec<String>,
+}
+
+impl ARustTypeWithItems {
+    fn new(texts: Vec<String>) -> Self {
+        Self { texts }
+    }
+
+    fn items(&self) -> Vec<ARustTypeInsideVecT> {
+        self.texts
+            .iter()
+            .map(|text| ARustTypeInsideVecT::new(text))
+            .collect()
+    }
+}
+
+fn rust_reflect_vec_string(arg: Vec<String>) -> Vec<String> {
+    arg
+}
+
+fn rust_reflect_vec_u8(arg: Vec<u8>) -> Vec<u8> {
+    arg
+}
+
+fn rust_sum_bytes(bytes: &[u8]) -> u64 {
+    bytes.iter().map(|byte| *byte as u64).sum()
+}
@@ -153,16 +153,14 @@ fn generate_code(bridge_module_source: &str) -> Result<GeneratedCode, String> {
 
     let rust = module.to_token_stream().to_string();
 
-    let config = CodegenConfig {
-        // TODO: Add an way in the visualizer UI to set whether or not a feature is enabled and then
-        //  look up those features here.
-        crate_feature_lookup: Box::new(|_feature_name| false),
-    };
+    // TODO: Add an way in the visualizer UI to set whether or not a feature is enabled and then
+    //  look up those features here.
+    let config = CodegenConfig::new(Box::new(|_feature_name| false));
     let generated = module.generate_swift_code_and_c_header(config);
 
     Ok(GeneratedCode {
         rust,
-        swift: generated.swift,
+        swift: generated.swift_with_shared_code(),
         c_header: generated.c_header,
     })
 }
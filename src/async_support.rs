@@ -1,7 +1,10 @@
 use once_cell::sync::Lazy;
+use std::ffi::c_void;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::mpsc::{Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
 
 #[doc(hidden)]
 pub static ASYNC_RUNTIME: Lazy<TokioRuntime> = Lazy::new(|| {
@@ -50,3 +53,67 @@ impl TokioRuntime {
         });
     }
 }
+
+/// A future that resolves once an async Swift function that was called from Rust completes.
+///
+/// Swift is handed a pointer to the future's shared state and passes it back to
+/// `SwiftFuture::complete` along with the Swift function's return value.
+#[doc(hidden)]
+pub struct SwiftFuture<T> {
+    state: Arc<Mutex<SwiftFutureState<T>>>,
+}
+
+struct SwiftFutureState<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+}
+
+#[doc(hidden)]
+impl<T> SwiftFuture<T> {
+    /// Create a future along with the pointer that Swift will use to complete it.
+    pub fn new() -> (Self, *mut c_void) {
+        let state = Arc::new(Mutex::new(SwiftFutureState {
+            value: None,
+            waker: None,
+        }));
+        let completion = Arc::into_raw(state.clone()) as *mut c_void;
+
+        (SwiftFuture { state }, completion)
+    }
+
+    /// Store the value that the Swift function returned and wake up the task that is awaiting it.
+    ///
+    /// # Safety
+    ///
+    /// `completion` must have been created by `SwiftFuture::<T>::new` and must only be completed
+    /// once.
+    pub unsafe fn complete(completion: *mut c_void, value: T) {
+        let state = Arc::from_raw(completion as *const Mutex<SwiftFutureState<T>>);
+
+        let waker = {
+            let mut state = state.lock().unwrap();
+            state.value = Some(value);
+            state.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Future for SwiftFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+
+        match state.value.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
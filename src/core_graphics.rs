@@ -0,0 +1,85 @@
+//! CoreGraphics' geometry types, which are seen on the Swift side as `CGFloat`, `CGPoint`,
+//! `CGSize` and `CGRect`.
+//!
+//! The types have the same layout as their CoreGraphics counterparts, so they are passed over FFI
+//! by value without any conversions.
+
+/// A `CGFloat`, which is an `f64` on platforms with 64 bit pointers and an `f32` on platforms with
+/// 32 bit pointers, such as watchOS on `arm64_32`.
+#[cfg(target_pointer_width = "64")]
+pub type CGFloat = f64;
+
+/// A `CGFloat`, which is an `f64` on platforms with 64 bit pointers and an `f32` on platforms with
+/// 32 bit pointers, such as watchOS on `arm64_32`.
+#[cfg(not(target_pointer_width = "64"))]
+pub type CGFloat = f32;
+
+/// A point in a two-dimensional coordinate system.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct CGPoint {
+    /// The x coordinate.
+    pub x: CGFloat,
+    /// The y coordinate.
+    pub y: CGFloat,
+}
+
+/// A width and a height.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct CGSize {
+    /// The width.
+    pub width: CGFloat,
+    /// The height.
+    pub height: CGFloat,
+}
+
+/// The location and dimensions of a rectangle.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct CGRect {
+    /// The rectangle's origin, which is its minimum x and y coordinates when its size is
+    /// positive.
+    pub origin: CGPoint,
+    /// The rectangle's width and height.
+    pub size: CGSize,
+}
+
+impl CGPoint {
+    /// The point at `(0, 0)`.
+    pub const ZERO: CGPoint = CGPoint { x: 0., y: 0. };
+
+    /// Create a point.
+    pub fn new(x: CGFloat, y: CGFloat) -> Self {
+        CGPoint { x, y }
+    }
+}
+
+impl CGSize {
+    /// A size with a width and height of zero.
+    pub const ZERO: CGSize = CGSize {
+        width: 0.,
+        height: 0.,
+    };
+
+    /// Create a size.
+    pub fn new(width: CGFloat, height: CGFloat) -> Self {
+        CGSize { width, height }
+    }
+}
+
+impl CGRect {
+    /// A rectangle at `(0, 0)` with a width and height of zero.
+    pub const ZERO: CGRect = CGRect {
+        origin: CGPoint::ZERO,
+        size: CGSize::ZERO,
+    };
+
+    /// Create a rectangle from its origin's coordinates and its size.
+    pub fn new(x: CGFloat, y: CGFloat, width: CGFloat, height: CGFloat) -> Self {
+        CGRect {
+            origin: CGPoint::new(x, y),
+            size: CGSize::new(width, height),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/decimal_support.rs
+
+use rust_decimal::Decimal;
+
+/// A `rust_decimal::Decimal`, which is seen on the Swift side as a `Decimal`.
+///
+/// The fields mirror Foundation's `Decimal`. Its value is `mantissa * 10^exponent`, where the
+/// `mantissa` is a 128 bit unsigned integer made up of 16 bit words, least significant word first.
+#[repr(C)]
+pub struct FfiDecimal {
+    pub mantissa: [u16; 8],
+    pub exponent: i32,
+    pub is_negative: bool,
+}
+
+impl From<Decimal> for FfiDecimal {
+    fn from(decimal: Decimal) -> Self {
+        let mantissa = decimal.mantissa().unsigned_abs();
+
+        let mut words = [0; 8];
+        for (idx, word) in words.iter_mut().enumerate() {
+            *word = (mantissa >> (16 * idx)) as u16;
+        }
+
+        FfiDecimal {
+            mantissa: words,
+            exponent: -(decimal.scale() as i32),
+            is_negative: decimal.is_sign_negative(),
+        }
+    }
+}
+
+/// A Swift `Decimal` can hold more digits than a `Decimal`, so digits past the 28th decimal place
+/// or past the 96 bit mantissa that a `Decimal` can hold are rounded away.
+///
+/// # Panics
+///
+/// Panics if the integer part of the value is too large to fit in a `Decimal`.
+impl From<FfiDecimal> for Decimal {
+    fn from(decimal: FfiDecimal) -> Self {
+        const MAX_SCALE: i32 = 28;
+        const MAX_MANTISSA: u128 = (1 << 96) - 1;
+
+        let mut mantissa = decimal
+            .mantissa
+            .iter()
+            .enumerate()
+            .fold(0u128, |mantissa, (idx, word)| {
+                mantissa | (*word as u128) << (16 * idx)
+            });
+        let mut exponent = decimal.exponent;
+
+        while exponent > 0 {
+            mantissa = mantissa
+                .checked_mul(10)
+                .filter(|mantissa| *mantissa <= MAX_MANTISSA)
+                .unwrap_or_else(|| panic!("Swift Decimal is too large to fit in a Decimal"));
+            exponent -= 1;
+        }
+        while -exponent > MAX_SCALE || mantissa > MAX_MANTISSA {
+            if exponent == 0 {
+                panic!("Swift Decimal is too large to fit in a Decimal");
+            }
+            let remainder = mantissa % 10;
+            mantissa = mantissa / 10 + (remainder >= 5) as u128;
+            exponent += 1;
+        }
+
+        let mut value = Decimal::from_i128_with_scale(mantissa as i128, -exponent as u32);
+        value.set_sign_negative(decimal.is_negative);
+        value
+    }
+}
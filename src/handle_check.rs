@@ -0,0 +1,89 @@
+//! Detection of double frees and of handles that are passed as the wrong type.
+//!
+//! Enable the `handle-check` feature to have every boxed opaque Rust type that gets handed to
+//! Swift tagged with its type. The generated functions that receive a pointer from Swift then
+//! abort with a message if the pointer was already freed, or if it points to a different type
+//! than the one the function expects, instead of corrupting the heap.
+//!
+//! Freed values are dropped but their memory is never deallocated, so that the allocator can't
+//! hand their address out again. This leaks, so only enable the feature for debugging.
+
+#[cfg(feature = "handle-check")]
+use std::collections::BTreeMap;
+#[cfg(feature = "handle-check")]
+use std::sync::Mutex;
+
+/// The handles that were given to Swift, keyed by their address.
+#[cfg(feature = "handle-check")]
+static HANDLES: Mutex<BTreeMap<usize, Handle>> = Mutex::new(BTreeMap::new());
+
+#[cfg(feature = "handle-check")]
+struct Handle {
+    /// `std::any::type_name` of the boxed type.
+    type_name: &'static str,
+    /// Whether Swift freed the value or passed its ownership back to Rust.
+    freed: bool,
+}
+
+/// Tag a newly boxed value with its type.
+#[cfg(feature = "handle-check")]
+pub(crate) fn register<T>(ptr: *mut T) {
+    // Every zero sized value of a type shares the same address, so there is nothing to tell
+    // them apart by.
+    if std::mem::size_of::<T>() == 0 {
+        return;
+    }
+
+    let handle = Handle {
+        type_name: std::any::type_name::<T>(),
+        freed: false,
+    };
+    HANDLES.lock().unwrap().insert(ptr as usize, handle);
+}
+
+/// Abort if Swift passed us a handle that was freed, or that is for a type other than `T`.
+///
+/// Pointers that were never registered, such as references to values that Rust owns, are not
+/// checked.
+#[cfg(feature = "handle-check")]
+pub(crate) fn check<T>(ptr: *const T, expected: &str) {
+    if std::mem::size_of::<T>() == 0 {
+        return;
+    }
+
+    let handles = HANDLES.lock().unwrap();
+    if let Some(handle) = handles.get(&(ptr as usize)) {
+        check_handle::<T>(handle, expected);
+    };
+}
+
+/// Mark a handle as freed, aborting if it was already freed or is for a type other than `T`.
+#[cfg(feature = "handle-check")]
+pub(crate) fn release<T>(ptr: *mut T) {
+    if std::mem::size_of::<T>() == 0 {
+        return;
+    }
+
+    let mut handles = HANDLES.lock().unwrap();
+    if let Some(handle) = handles.get_mut(&(ptr as usize)) {
+        check_handle::<T>(handle, std::any::type_name::<T>());
+        handle.freed = true;
+    };
+}
+
+#[cfg(feature = "handle-check")]
+fn check_handle<T>(handle: &Handle, expected: &str) {
+    if handle.type_name != std::any::type_name::<T>() {
+        eprintln!(
+            "swift-bridge: Swift passed Rust a `{}` where a `{expected}` was expected.",
+            handle.type_name
+        );
+        std::process::abort();
+    }
+    if handle.freed {
+        eprintln!(
+            "swift-bridge: Swift passed Rust a `{expected}` that was already freed or whose ownership was already passed to Rust."
+        );
+        std::process::abort();
+    }
+}
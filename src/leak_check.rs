@@ -0,0 +1,62 @@
+//! Counting of the opaque Rust type instances that are currently owned by Swift.
+//!
+//! Enable the `leak-check` feature to have every boxed opaque Rust type that gets handed to Swift
+//! counted, along with every one that Swift frees or hands back to Rust. A test can then assert
+//! that [`live_count`] returns to its starting value, catching retain cycles or forgotten frees
+//! across the boundary.
+//!
+//! ```ignore
+//! let before = swift_bridge::leak_check::live_count();
+//! run_swift_code_that_uses_rust_types();
+//! assert_eq!(swift_bridge::leak_check::live_count(), before);
+//! ```
+
+#[cfg(feature = "leak-check")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "leak-check")]
+static LIVE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// The number of boxed opaque Rust type instances that Swift currently owns.
+///
+/// The count is global to the process, so tests that assert on it should not run in parallel
+/// with other tests that pass Rust types to Swift.
+#[cfg(feature = "leak-check")]
+pub fn live_count() -> usize {
+    LIVE_COUNT.load(Ordering::SeqCst)
+}
+
+/// Box a value so that its ownership can be passed to Swift.
+#[doc(hidden)]
+#[inline(always)]
+pub fn box_into_raw<T>(val: T) -> *mut T {
+    #[cfg(feature = "leak-check")]
+    LIVE_COUNT.fetch_add(1, Ordering::SeqCst);
+
+    let ptr = Box::into_raw(Box::new(val));
+    #[cfg(feature = "handle-check")]
+    crate::handle_check::register(ptr);
+    ptr
+}
+
+/// Take back a value that was boxed using [`box_into_raw`].
+///
+/// # Safety
+///
+/// The pointer must have come from `box_into_raw`, and must not be used again.
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn box_from_raw<T>(ptr: *mut T) -> Box<T> {
+    #[cfg(feature = "leak-check")]
+    LIVE_COUNT.fetch_sub(1, Ordering::SeqCst);
+
+    // Move the value out and leave its allocation behind, so that its address is never reused
+    // and a second free of it can be detected.
+    #[cfg(feature = "handle-check")]
+    if std::mem::size_of::<T>() != 0 {
+        crate::handle_check::release(ptr);
+        return Box::new(std::ptr::read(ptr));
+    }
+
+    Box::from_raw(ptr)
+}
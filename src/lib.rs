@@ -6,7 +6,7 @@ pub use swift_bridge_macro::bridge;
 
 mod std_bridge;
 
-pub use self::std_bridge::{option, result, string};
+pub use self::std_bridge::{int128, option, result, string, time};
 
 #[doc(hidden)]
 #[cfg(feature = "async")]
@@ -18,6 +18,43 @@ pub mod boxed_fn_support;
 #[doc(hidden)]
 pub mod copy_support;
 
+pub mod core_graphics;
+
+pub mod handle_check;
+
+pub mod leak_check;
+
+pub mod ns_error;
+
+pub mod observable;
+
+#[doc(hidden)]
+pub mod panic_support;
+
+#[doc(hidden)]
+pub mod pointer_support;
+
+#[doc(hidden)]
+pub mod scratch_support;
+
+#[doc(hidden)]
+pub mod sendable_support;
+
+#[doc(hidden)]
+pub mod stream_support;
+
+#[doc(hidden)]
+#[cfg(feature = "rust_decimal")]
+pub mod decimal_support;
+
+#[doc(hidden)]
+#[cfg(feature = "url")]
+pub mod url_support;
+
+#[doc(hidden)]
+#[cfg(feature = "uuid")]
+pub mod uuid_support;
+
 #[doc(hidden)]
 #[repr(C)]
 pub struct FfiSlice<T> {
@@ -0,0 +1,154 @@
+//! An error that is seen on the Swift side as an `NSError`.
+//!
+//! Rust functions that return a `Result<T, NsError>` throw an `NSError` with the same domain, code
+//! and user info, so their errors work with Cocoa's error handling, such as presenting them in
+//! an alert. `extern "Swift"` functions that use `#[swift_bridge(catch_errors)]` can return a
+//! `Result<T, NsError>` in order to receive the `NSError`s that Swift throws.
+//!
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/ns_error_support.rs
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// The `userInfo` key of an `NSError`'s localized description.
+pub const NS_LOCALIZED_DESCRIPTION_KEY: &str = "NSLocalizedDescription";
+
+/// The `userInfo` key of an `NSError`'s localized failure reason.
+pub const NS_LOCALIZED_FAILURE_REASON_ERROR_KEY: &str = "NSLocalizedFailureReason";
+
+/// The `userInfo` key of an `NSError`'s localized recovery suggestion.
+pub const NS_LOCALIZED_RECOVERY_SUGGESTION_ERROR_KEY: &str = "NSLocalizedRecoverySuggestion";
+
+/// An error with the same domain, code and user info as an `NSError`.
+///
+/// Only user info values that are strings are kept when an `NSError` is passed to Rust. Other
+/// values are converted using Swift's `String(describing:)`. The `NSError`'s
+/// `localizedDescription` is always stored under [`NS_LOCALIZED_DESCRIPTION_KEY`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NsError {
+    /// The error domain, such as `"com.example.parser"` or `"NSCocoaErrorDomain"`.
+    pub domain: String,
+    /// The error code, which is unique within the error's domain.
+    pub code: isize,
+    /// The error's user info, such as its [`NS_LOCALIZED_DESCRIPTION_KEY`].
+    pub user_info: HashMap<String, String>,
+}
+
+impl NsError {
+    /// Create an error without any user info.
+    pub fn new(domain: impl Into<String>, code: isize) -> Self {
+        NsError {
+            domain: domain.into(),
+            code,
+            user_info: HashMap::new(),
+        }
+    }
+
+    /// Add an entry to the error's user info.
+    pub fn with_user_info(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.user_info.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the error's localized description.
+    pub fn with_localized_description(self, description: impl Into<String>) -> Self {
+        self.with_user_info(NS_LOCALIZED_DESCRIPTION_KEY, description)
+    }
+
+    /// The error's localized description, if it has one.
+    pub fn localized_description(&self) -> Option<&str> {
+        self.user_info
+            .get(NS_LOCALIZED_DESCRIPTION_KEY)
+            .map(String::as_str)
+    }
+}
+
+impl Display for NsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.localized_description() {
+            Some(description) => write!(f, "{} ({} error {})", description, self.domain, self.code),
+            None => write!(f, "{} error {}", self.domain, self.code),
+        }
+    }
+}
+
+impl std::error::Error for NsError {}
+
+// Private, since these are only called by the generated Swift code.
+mod ffi {
+    use super::NsError;
+    use crate::string::RustString;
+
+    #[export_name = "__swift_bridge__$NsError$new"]
+    #[allow(non_snake_case)]
+    pub extern "C" fn __swift_bridge__NsError_new(
+        domain: *mut RustString,
+        code: isize,
+    ) -> *mut NsError {
+        let domain = unsafe { Box::from_raw(domain).0 };
+        Box::into_raw(Box::new(NsError::new(domain, code)))
+    }
+
+    #[export_name = "__swift_bridge__$NsError$insert_user_info"]
+    #[allow(non_snake_case)]
+    pub extern "C" fn __swift_bridge__NsError_insert_user_info(
+        this: *mut NsError,
+        key: *mut RustString,
+        value: *mut RustString,
+    ) {
+        let this = unsafe { &mut *this };
+        let key = unsafe { Box::from_raw(key).0 };
+        let value = unsafe { Box::from_raw(value).0 };
+        this.user_info.insert(key, value);
+    }
+
+    #[export_name = "__swift_bridge__$NsError$domain"]
+    #[allow(non_snake_case)]
+    pub extern "C" fn __swift_bridge__NsError_domain(this: *const NsError) -> *mut RustString {
+        let this = unsafe { &*this };
+        RustString(this.domain.clone()).box_into_raw()
+    }
+
+    #[export_name = "__swift_bridge__$NsError$code"]
+    #[allow(non_snake_case)]
+    pub extern "C" fn __swift_bridge__NsError_code(this: *const NsError) -> isize {
+        unsafe { &*this }.code
+    }
+
+    #[export_name = "__swift_bridge__$NsError$user_info_len"]
+    #[allow(non_snake_case)]
+    pub extern "C" fn __swift_bridge__NsError_user_info_len(this: *const NsError) -> usize {
+        unsafe { &*this }.user_info.len()
+    }
+
+    /// The key of the user info entry at the `index`, which is in the `HashMap`'s iteration order.
+    #[export_name = "__swift_bridge__$NsError$user_info_key"]
+    #[allow(non_snake_case)]
+    pub extern "C" fn __swift_bridge__NsError_user_info_key(
+        this: *const NsError,
+        index: usize,
+    ) -> *mut RustString {
+        let this = unsafe { &*this };
+        let (key, _) = this.user_info.iter().nth(index).unwrap();
+        RustString(key.clone()).box_into_raw()
+    }
+
+    /// The value of the user info entry at the `index`, which is in the `HashMap`'s iteration order.
+    #[export_name = "__swift_bridge__$NsError$user_info_value"]
+    #[allow(non_snake_case)]
+    pub extern "C" fn __swift_bridge__NsError_user_info_value(
+        this: *const NsError,
+        index: usize,
+    ) -> *mut RustString {
+        let this = unsafe { &*this };
+        let (_, value) = this.user_info.iter().nth(index).unwrap();
+        RustString(value.clone()).box_into_raw()
+    }
+
+    #[export_name = "__swift_bridge__$NsError$free"]
+    #[allow(non_snake_case)]
+    pub extern "C" fn __swift_bridge__NsError_free(this: *mut NsError) {
+        drop(unsafe { Box::from_raw(this) });
+    }
+}
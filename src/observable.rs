@@ -0,0 +1,190 @@
+//! Support for `#[swift_bridge(observable)]` types, whose generated Swift `ObservableObject`
+//! publishes the type's Swift properties.
+//!
+//! The Swift wrapper re-reads its `@Published` properties whenever the type's [`ChangeNotifier`]
+//! is notified, so SwiftUI views that observe it update after Rust changes the value.
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/observable_support.rs
+
+use std::ffi::c_void;
+use std::fmt::{Debug, Formatter};
+use std::sync::Mutex;
+
+/// Implemented by `#[swift_bridge(observable)]` types so that their Swift `ObservableObject`s can
+/// listen for changes.
+///
+/// ```
+/// use swift_bridge::observable::{ChangeNotifier, Observable};
+///
+/// #[derive(Default)]
+/// struct Counter {
+///     count: u32,
+///     notifier: ChangeNotifier,
+/// }
+///
+/// impl Counter {
+///     fn increment(&mut self) {
+///         self.count += 1;
+///         self.notifier.notify();
+///     }
+/// }
+///
+/// impl Observable for Counter {
+///     fn change_notifier(&self) -> &ChangeNotifier {
+///         &self.notifier
+///     }
+/// }
+/// ```
+pub trait Observable {
+    /// The notifier that gets notified after any of the type's Swift properties change.
+    fn change_notifier(&self) -> &ChangeNotifier;
+}
+
+/// Tells the Swift `ObservableObject`s that wrap a Rust value that the value changed.
+///
+/// Notifying can happen on any thread. The Swift side refreshes its properties on the main queue.
+#[derive(Default)]
+pub struct ChangeNotifier {
+    observers: Mutex<Vec<Observer>>,
+}
+
+/// A Swift observer, which Rust keeps retained until it unsubscribes or the notifier is dropped.
+struct Observer {
+    ptr: *mut c_void,
+    on_change: extern "C" fn(*mut c_void),
+    free: extern "C" fn(*mut c_void),
+}
+
+// The Swift observer only holds a weak reference to its `ObservableObject`, which it refreshes on
+// the main queue.
+unsafe impl Send for Observer {}
+
+impl Drop for Observer {
+    fn drop(&mut self) {
+        (self.free)(self.ptr)
+    }
+}
+
+impl ChangeNotifier {
+    /// Create a notifier without any observers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tell every observer that the value changed. Does nothing if there are no observers.
+    pub fn notify(&self) {
+        for observer in self.observers.lock().unwrap().iter() {
+            (observer.on_change)(observer.ptr);
+        }
+    }
+
+    /// The number of Swift `ObservableObject`s that are observing the value.
+    pub fn observer_count(&self) -> usize {
+        self.observers.lock().unwrap().len()
+    }
+
+    /// Add a Swift observer, which gets freed once it unsubscribes or the notifier is dropped.
+    #[doc(hidden)]
+    pub fn subscribe(
+        &self,
+        observer: *mut c_void,
+        on_change: extern "C" fn(*mut c_void),
+        free_observer: extern "C" fn(*mut c_void),
+    ) {
+        self.observers.lock().unwrap().push(Observer {
+            ptr: observer,
+            on_change,
+            free: free_observer,
+        });
+    }
+
+    /// Remove and free a Swift observer. Called when its `ObservableObject` is deinitialized.
+    #[doc(hidden)]
+    pub fn unsubscribe(&self, observer: *mut c_void) {
+        let removed: Vec<Observer> = {
+            let mut observers = self.observers.lock().unwrap();
+            let (removed, kept) = observers.drain(..).partition(|o| o.ptr == observer);
+            *observers = kept;
+            removed
+        };
+        // Freed after the lock is released, since freeing calls into Swift.
+        drop(removed);
+    }
+}
+
+impl Debug for ChangeNotifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChangeNotifier")
+            .field("observers", &self.observer_count())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Counts the calls to `on_change` and `free`, like a Swift observer would refresh its
+    /// `ObservableObject` and release itself.
+    #[derive(Default)]
+    struct CountingObserver {
+        changes: AtomicUsize,
+        frees: AtomicUsize,
+    }
+
+    extern "C" fn on_change(observer: *mut c_void) {
+        let observer = unsafe { &*(observer as *const CountingObserver) };
+        observer.changes.fetch_add(1, Ordering::SeqCst);
+    }
+
+    extern "C" fn free(observer: *mut c_void) {
+        let observer = unsafe { &*(observer as *const CountingObserver) };
+        observer.frees.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn subscribe(notifier: &ChangeNotifier, observer: &CountingObserver) -> *mut c_void {
+        let ptr = observer as *const CountingObserver as *mut c_void;
+        notifier.subscribe(ptr, on_change, free);
+        ptr
+    }
+
+    /// Verify that an unsubscribed observer is freed right away and no longer notified, so that
+    /// re-creating an `ObservableObject` doesn't pile up observers.
+    #[test]
+    fn unsubscribe_frees_the_observer() {
+        let notifier = ChangeNotifier::new();
+        let first = CountingObserver::default();
+        let second = CountingObserver::default();
+
+        let first_ptr = subscribe(&notifier, &first);
+        subscribe(&notifier, &second);
+        notifier.unsubscribe(first_ptr);
+
+        assert_eq!(notifier.observer_count(), 1);
+        assert_eq!(first.frees.load(Ordering::SeqCst), 1);
+
+        notifier.notify();
+        assert_eq!(first.changes.load(Ordering::SeqCst), 0);
+        assert_eq!(second.changes.load(Ordering::SeqCst), 1);
+
+        drop(notifier);
+        assert_eq!(second.frees.load(Ordering::SeqCst), 1);
+    }
+
+    /// Verify that re-creating an observer many times keeps at most one of them alive.
+    #[test]
+    fn resubscribing_does_not_grow_the_observers() {
+        let notifier = ChangeNotifier::new();
+        let observer = CountingObserver::default();
+
+        for _ in 0..100 {
+            let ptr = subscribe(&notifier, &observer);
+            notifier.unsubscribe(ptr);
+        }
+
+        assert_eq!(notifier.observer_count(), 0);
+        assert_eq!(observer.frees.load(Ordering::SeqCst), 100);
+    }
+}
@@ -0,0 +1,53 @@
+//! Catches panics in the generated `extern "C"` functions of bridge modules annotated with
+//! `#[swift_bridge::bridge(catch_panics = "...")]`.
+//!
+//! Unwinding out of an `extern "C"` function is undefined behavior, so instead we store the
+//! panic message and return early. The generated Swift code then calls
+//! `__swift_bridge__$take_panic_message` and either calls `fatalError` or throws a `RustPanic`.
+
+use crate::string::RustString;
+use std::cell::RefCell;
+use std::panic::AssertUnwindSafe;
+
+thread_local! {
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Call `f`, storing the panic message if it panics.
+///
+/// When `f` panics we return an all zero `T`, which Swift never looks at since it checks for the
+/// panic first.
+///
+/// # Safety
+///
+/// An all zero `T` must be a valid value. The generated code only calls this with the FFI
+/// representations of bridged types, such as integers, raw pointers and `#[repr(C)]` structs and
+/// enums of them, which all are. It must not be called with types such as references, `Box`es
+/// or `NonNull`s.
+pub unsafe fn catch_panic<T>(f: impl FnOnce() -> T) -> T {
+    match std::panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(val) => val,
+        Err(payload) => {
+            let message = if let Some(message) = payload.downcast_ref::<&str>() {
+                message.to_string()
+            } else if let Some(message) = payload.downcast_ref::<String>() {
+                message.clone()
+            } else {
+                "Rust panicked.".to_string()
+            };
+            PANIC_MESSAGE.with(|panic_message| *panic_message.borrow_mut() = Some(message));
+
+            unsafe { std::mem::zeroed() }
+        }
+    }
+}
+
+#[export_name = "__swift_bridge__$take_panic_message"]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+pub extern "C" fn __swift_bridge__take_panic_message() -> *mut RustString {
+    match PANIC_MESSAGE.with(|panic_message| panic_message.borrow_mut().take()) {
+        Some(message) => RustString(message).box_into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
@@ -0,0 +1,27 @@
+//! Checks that the generated `extern "C"` functions of bridge modules run in debug builds, so
+//! that a bad pointer from Swift aborts the process right away instead of being dereferenced.
+
+/// The address that generated Swift classes overwrite their pointer with once they have been
+/// deinitialized, in debug builds.
+///
+/// Must match `__swift_bridge__freedPointer` in `SwiftBridgeCore.swift`.
+pub const FREED_POINTER: usize = 0xdead_beef;
+
+/// Abort if Swift passed us a null pointer, or the pointer of an already deinitialized class.
+///
+/// With the `handle-check` feature this also aborts if the pointer is for a value that was
+/// already freed, or for a different type. See `swift_bridge::handle_check`.
+pub fn assert_valid_pointer<T>(ptr: *const T, type_name: &str) {
+    let problem = if ptr.is_null() {
+        "a null pointer"
+    } else if ptr as usize == FREED_POINTER {
+        "a pointer to a value that was already freed"
+    } else {
+        #[cfg(feature = "handle-check")]
+        crate::handle_check::check(ptr, type_name);
+        return;
+    };
+
+    eprintln!("swift-bridge: Swift passed Rust {problem} to a `{type_name}`.");
+    std::process::abort();
+}
@@ -0,0 +1,35 @@
+//! A per-thread scratch buffer that functions annotated with
+//! `#[swift_bridge(return_scratch)]` write their returned string into.
+//!
+//! Reusing the buffer means that, once it has grown large enough, returning a string to Swift
+//! does not allocate.
+
+use std::cell::RefCell;
+
+thread_local! {
+    static SCRATCH_STRING: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Clear this thread's scratch string, let `write` fill it and return its contents.
+///
+/// The buffer is taken out of the thread local while `write` runs, so `write` can call another
+/// `return_scratch` function, such as from a Swift callback. The nested call writes into a
+/// buffer of its own.
+///
+/// # Safety
+///
+/// The returned `&str` points into the scratch string, so it must not be used after the next
+/// call to `write_scratch_str` on this thread.
+pub unsafe fn write_scratch_str(write: impl FnOnce(&mut String)) -> &'static str {
+    let mut scratch = SCRATCH_STRING.with(|scratch| std::mem::take(&mut *scratch.borrow_mut()));
+    scratch.clear();
+    write(&mut scratch);
+
+    SCRATCH_STRING.with(|stored| {
+        let mut stored = stored.borrow_mut();
+        *stored = scratch;
+
+        let str: *const str = stored.as_str();
+        unsafe { &*str }
+    })
+}
@@ -0,0 +1,3 @@
+/// Used by the generated code to check that a type which is `Sendable` in Swift can be sent and
+/// shared across threads in Rust.
+pub fn assert_send_sync<T: Send + Sync>() {}
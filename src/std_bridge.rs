@@ -2,7 +2,9 @@
 //! crates/swift-bridge-build/src/generate_core/*
 #![allow(missing_docs)]
 
+pub mod int128;
 pub mod option;
 pub mod result;
 mod rust_vec;
 pub mod string;
+pub mod time;
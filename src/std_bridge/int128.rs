@@ -0,0 +1,48 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/int128_support.rs
+
+/// A `u128` split into two 64 bit limbs, since `u128` does not have a stable C ABI.
+#[doc(hidden)]
+#[repr(C)]
+pub struct RustUInt128 {
+    pub low: u64,
+    pub high: u64,
+}
+
+/// An `i128` split into two 64 bit limbs, since `i128` does not have a stable C ABI.
+#[doc(hidden)]
+#[repr(C)]
+pub struct RustInt128 {
+    pub low: u64,
+    pub high: i64,
+}
+
+impl From<u128> for RustUInt128 {
+    fn from(val: u128) -> Self {
+        RustUInt128 {
+            low: val as u64,
+            high: (val >> 64) as u64,
+        }
+    }
+}
+
+impl From<RustUInt128> for u128 {
+    fn from(val: RustUInt128) -> Self {
+        ((val.high as u128) << 64) | val.low as u128
+    }
+}
+
+impl From<i128> for RustInt128 {
+    fn from(val: i128) -> Self {
+        RustInt128 {
+            low: val as u64,
+            high: (val >> 64) as i64,
+        }
+    }
+}
+
+impl From<RustInt128> for i128 {
+    fn from(val: RustInt128) -> Self {
+        ((val.high as i128) << 64) | val.low as i128
+    }
+}
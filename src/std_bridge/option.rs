@@ -88,3 +88,74 @@ pub struct OptionBool {
     pub val: bool,
     pub is_some: bool,
 }
+
+// The generated code calls these conversions instead of inlining an `if let` at every place that
+// an `Option<T>` crosses the FFI boundary, which keeps large bridge modules small.
+macro_rules! option_primitive_conversions {
+    ($($option_ty:ident($ty:ty)),*) => {
+        $(
+            impl $option_ty {
+                #[inline(always)]
+                pub fn from_option(val: Option<$ty>) -> Self {
+                    match val {
+                        Some(val) => $option_ty { val, is_some: true },
+                        None => $option_ty {
+                            val: Default::default(),
+                            is_some: false,
+                        },
+                    }
+                }
+
+                #[inline(always)]
+                pub fn into_option(self) -> Option<$ty> {
+                    if self.is_some {
+                        Some(self.val)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
+}
+
+option_primitive_conversions!(
+    OptionU8(u8),
+    OptionI8(i8),
+    OptionU16(u16),
+    OptionI16(i16),
+    OptionU32(u32),
+    OptionI32(i32),
+    OptionU64(u64),
+    OptionI64(i64),
+    OptionUsize(usize),
+    OptionIsize(isize),
+    OptionF32(f32),
+    OptionF64(f64),
+    OptionBool(bool)
+);
+
+/// Box the value, using a null pointer for `None`.
+#[doc(hidden)]
+#[inline(always)]
+pub fn box_into_raw_or_null<T>(val: Option<T>) -> *mut T {
+    match val {
+        Some(val) => Box::into_raw(Box::new(val)),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Take back a value that was boxed using [`box_into_raw_or_null`].
+///
+/// # Safety
+///
+/// The pointer must be null or have come from `box_into_raw_or_null`, and must not be used again.
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn unbox_raw_or_null<T>(ptr: *mut T) -> Option<T> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(*Box::from_raw(ptr))
+    }
+}
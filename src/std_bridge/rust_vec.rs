@@ -17,6 +17,21 @@ vec_externs!(f64, OptionF64, 0.123);
 
 vec_externs!(bool, OptionBool, false);
 
+/// Copy a Swift `Data`'s bytes into a new `Vec<u8>` using a single allocation, instead of pushing
+/// them one at a time.
+#[export_name = "__swift_bridge__$Vec_u8$from_bytes"]
+#[allow(non_snake_case)]
+pub extern "C" fn __swift_bridge__Vec_u8_from_bytes(bytes: *const u8, len: usize) -> *mut Vec<u8> {
+    let vec = if len == 0 {
+        // An empty `Data` can hand us a null pointer.
+        Vec::new()
+    } else {
+        unsafe { std::slice::from_raw_parts(bytes, len) }.to_vec()
+    };
+
+    Box::into_raw(Box::new(vec))
+}
+
 mod macro_ {
     macro_rules! vec_externs {
         ($ty:ty, $option_ty:ident, $unused_none:expr) => {
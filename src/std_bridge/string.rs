@@ -1,6 +1,7 @@
 //! The corresponding C and Swift code can be found in
 //! crates/swift-bridge-build/src/generate_core/rust_string.{c.h,swift}
 pub use self::ffi::*;
+use std::borrow::Cow;
 
 #[swift_bridge_macro::bridge(swift_bridge_path = crate)]
 mod ffi {
@@ -21,7 +22,10 @@ mod ffi {
     }
 }
 
+// `repr(transparent)` so that a `Vec<String>` can be used as a `Vec<RustString>`, which lets
+// Swift see a `Vec<String>` as a `RustVec<RustString>`.
 #[doc(hidden)]
+#[repr(transparent)]
 pub struct RustString(pub String);
 
 #[doc(hidden)]
@@ -53,11 +57,67 @@ impl RustString {
     }
 }
 
+/// A `Cow<'static, str>`, which is seen on the Swift side as a `RustCowStr`.
+///
+/// A borrowed string is passed as a `RustStr` that points into the `'static` string, so no
+/// allocation is needed. An owned string is passed as a boxed `RustString` that Swift takes
+/// ownership of.
+#[doc(hidden)]
+#[repr(C)]
+pub struct FfiCowStr {
+    pub is_owned: bool,
+    pub owned: *mut RustString,
+    pub borrowed: RustStr,
+}
+
+impl From<Cow<'static, str>> for FfiCowStr {
+    fn from(cow: Cow<'static, str>) -> Self {
+        match cow {
+            Cow::Borrowed(str) => FfiCowStr {
+                is_owned: false,
+                owned: std::ptr::null_mut(),
+                borrowed: RustStr::from_str(str),
+            },
+            Cow::Owned(string) => FfiCowStr {
+                is_owned: true,
+                owned: RustString(string).box_into_raw(),
+                borrowed: RustStr {
+                    start: std::ptr::null(),
+                    len: 0,
+                },
+            },
+        }
+    }
+}
+
 impl RustString {
     /// Box::into_raw(Box::new(self))
     pub fn box_into_raw(self) -> *mut RustString {
         Box::into_raw(Box::new(self))
     }
+
+    /// Box the string, using a null pointer for `None`.
+    #[inline(always)]
+    pub fn option_box_into_raw(val: Option<String>) -> *mut RustString {
+        match val {
+            Some(val) => RustString(val).box_into_raw(),
+            None => std::ptr::null_mut(),
+        }
+    }
+
+    /// Take back a string that was boxed using [`RustString::option_box_into_raw`].
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be null or point to a boxed `RustString`, and must not be used again.
+    #[inline(always)]
+    pub unsafe fn option_from_raw(ptr: *mut RustString) -> Option<String> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Box::from_raw(ptr).0)
+        }
+    }
 }
 
 impl RustStr {
@@ -81,6 +141,95 @@ impl RustStr {
             len: str.len(),
         }
     }
+
+    /// A `RustStr` with a null `start` for `None`.
+    #[inline(always)]
+    pub fn from_option_str(str: Option<&str>) -> Self {
+        match str {
+            Some(str) => RustStr::from_str(str),
+            None => RustStr {
+                start: std::ptr::null(),
+                len: 0,
+            },
+        }
+    }
+
+    #[inline(always)]
+    pub fn to_option_str<'a>(self) -> Option<&'a str> {
+        if self.start.is_null() {
+            None
+        } else {
+            Some(self.to_str())
+        }
+    }
+
+    /// [`RustStr::to_str`] without validating that the bytes are UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The bytes must be valid UTF-8, such as the bytes of a Swift `String`.
+    #[inline(always)]
+    pub unsafe fn to_str_unchecked<'a>(self) -> &'a str {
+        let bytes = std::slice::from_raw_parts(self.start, self.len);
+        std::str::from_utf8_unchecked(bytes)
+    }
+
+    /// [`RustStr::to_option_str`] without validating that the bytes are UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The bytes must be valid UTF-8, such as the bytes of a Swift `String`.
+    #[inline(always)]
+    pub unsafe fn to_option_str_unchecked<'a>(self) -> Option<&'a str> {
+        if self.start.is_null() {
+            None
+        } else {
+            Some(self.to_str_unchecked())
+        }
+    }
+}
+
+/// The number of bytes that a [`SmallRustStr`] can hold inline.
+pub const SMALL_RUST_STR_INLINE_CAPACITY: usize = 24;
+
+/// A `&str` that Swift passes to a Rust function when the `small-string-ffi` feature is enabled.
+///
+/// Swift has to copy a `String` into a null terminated buffer before it can hand out a pointer to
+/// its bytes, which costs a heap allocation per argument. Strings of up to
+/// [`SMALL_RUST_STR_INLINE_CAPACITY`] bytes are instead copied into `inline_bytes`, in which case
+/// `start` is null. Longer strings point to their bytes the same way that a [`RustStr`] does.
+#[doc(hidden)]
+#[repr(C)]
+pub struct SmallRustStr {
+    pub start: *const u8,
+    pub len: usize,
+    pub inline_bytes: [u8; SMALL_RUST_STR_INLINE_CAPACITY],
+}
+
+impl SmallRustStr {
+    /// The inline bytes live inside of the `SmallRustStr`, so unlike [`RustStr::to_str`] the
+    /// returned `&str` borrows from `self`.
+    pub fn to_str(&self) -> &str {
+        std::str::from_utf8(self.as_bytes()).expect("Failed to convert SmallRustStr to &str")
+    }
+
+    /// [`SmallRustStr::to_str`] without validating that the bytes are UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The bytes must be valid UTF-8, such as the bytes of a Swift `String`.
+    #[inline(always)]
+    pub unsafe fn to_str_unchecked(&self) -> &str {
+        std::str::from_utf8_unchecked(self.as_bytes())
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        if self.start.is_null() {
+            &self.inline_bytes[..self.len]
+        } else {
+            unsafe { std::slice::from_raw_parts(self.start, self.len) }
+        }
+    }
 }
 
 impl PartialEq for RustStr {
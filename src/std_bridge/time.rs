@@ -0,0 +1,70 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/time_support.rs
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A `std::time::Duration`, which is seen on the Swift side as a `TimeInterval`.
+#[doc(hidden)]
+#[repr(C)]
+pub struct FfiDuration {
+    pub secs: u64,
+    pub nanos: u32,
+}
+
+/// A `std::time::SystemTime`, which is seen on the Swift side as a `Date`.
+///
+/// `secs` is relative to the UNIX epoch and can be negative, while `nanos` is always a
+/// non-negative offset from `secs`.
+#[doc(hidden)]
+#[repr(C)]
+pub struct FfiSystemTime {
+    pub secs: i64,
+    pub nanos: u32,
+}
+
+impl From<Duration> for FfiDuration {
+    fn from(duration: Duration) -> Self {
+        FfiDuration {
+            secs: duration.as_secs(),
+            nanos: duration.subsec_nanos(),
+        }
+    }
+}
+
+impl From<FfiDuration> for Duration {
+    fn from(duration: FfiDuration) -> Self {
+        Duration::new(duration.secs, duration.nanos)
+    }
+}
+
+impl From<SystemTime> for FfiSystemTime {
+    fn from(time: SystemTime) -> Self {
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(since_epoch) => FfiSystemTime {
+                secs: since_epoch.as_secs() as i64,
+                nanos: since_epoch.subsec_nanos(),
+            },
+            Err(err) => {
+                let before_epoch = err.duration();
+                let mut secs = -(before_epoch.as_secs() as i64);
+                let mut nanos = before_epoch.subsec_nanos();
+                if nanos > 0 {
+                    secs -= 1;
+                    nanos = 1_000_000_000 - nanos;
+                }
+                FfiSystemTime { secs, nanos }
+            }
+        }
+    }
+}
+
+impl From<FfiSystemTime> for SystemTime {
+    fn from(time: FfiSystemTime) -> Self {
+        let nanos = Duration::from_nanos(time.nanos as u64);
+        if time.secs >= 0 {
+            UNIX_EPOCH + Duration::from_secs(time.secs as u64) + nanos
+        } else {
+            UNIX_EPOCH - Duration::from_secs(time.secs.unsigned_abs()) + nanos
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! Support for functions that return a Rust `Stream`, which is seen on the Swift side as an
+//! `AsyncStream`, or as a Combine `Publisher` for `#[swift_bridge(publisher)]` functions.
+//!
+//! The stream gets polled on the async runtime, but only while the Swift subscriber has
+//! outstanding demand, so a slow subscriber applies backpressure to the stream instead of having
+//! items pile up. An `AsyncStream` asks for one item per iteration.
+//!
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/stream_support.rs
+
+use std::ffi::c_void;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::Waker;
+
+type StreamTask = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// A Rust `Stream` that Swift has not subscribed to yet.
+pub struct RustStream {
+    into_task: Box<dyn FnOnce(Subscriber, Arc<Mutex<SubscriptionState>>) -> StreamTask + Send>,
+    spawn: fn(StreamTask),
+}
+
+/// The Rust side of a Swift subscription to a `RustStream`.
+pub struct StreamSubscription {
+    state: Arc<Mutex<SubscriptionState>>,
+}
+
+struct SubscriptionState {
+    /// The number of items that the subscriber is ready to receive. `usize::MAX` means unlimited.
+    demand: usize,
+    cancelled: bool,
+    /// Woken when the subscriber requests more items or cancels.
+    waker: Option<Waker>,
+}
+
+/// The Swift subscription object, which Rust keeps retained until the stream's task is done
+/// calling it.
+struct Subscriber {
+    ptr: *mut c_void,
+    free: extern "C" fn(*mut c_void),
+}
+
+// The Swift subscription object synchronizes the calls that it receives.
+unsafe impl Send for Subscriber {}
+
+#[cfg(feature = "async")]
+impl Subscriber {
+    fn ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+impl Drop for Subscriber {
+    fn drop(&mut self) {
+        (self.free)(self.ptr)
+    }
+}
+
+#[cfg(feature = "async")]
+impl RustStream {
+    /// Box a stream up until Swift subscribes to it.
+    ///
+    /// `on_item` returns whether the stream should keep going, which lets a stream of `Result`s
+    /// stop after its first error. `on_complete` is called once the stream ends. Neither is
+    /// called after the subscription is cancelled.
+    pub fn new<S, F, C>(
+        stream: S,
+        mut on_item: F,
+        on_complete: C,
+        spawn: fn(StreamTask),
+    ) -> *mut Self
+    where
+        S: futures_core::Stream + Send + 'static,
+        F: FnMut(*mut c_void, S::Item) -> bool + Send + 'static,
+        C: FnOnce(*mut c_void) + Send + 'static,
+    {
+        let into_task =
+            move |subscriber: Subscriber, state: Arc<Mutex<SubscriptionState>>| -> StreamTask {
+                Box::pin(async move {
+                    let mut stream = Box::pin(stream);
+
+                    while let Some(item) = next_demanded(&state, stream.as_mut()).await {
+                        match item {
+                            Some(item) => {
+                                if !on_item(subscriber.ptr(), item) {
+                                    return;
+                                }
+                            }
+                            None => {
+                                on_complete(subscriber.ptr());
+                                return;
+                            }
+                        }
+                    }
+                })
+            };
+
+        Box::into_raw(Box::new(RustStream {
+            into_task: Box::new(into_task),
+            spawn,
+        }))
+    }
+}
+
+/// The stream's next item once the subscriber has demand for it, or `None` if the subscription
+/// was cancelled.
+#[cfg(feature = "async")]
+fn next_demanded<'a, S: futures_core::Stream + ?Sized>(
+    state: &'a Mutex<SubscriptionState>,
+    mut stream: Pin<&'a mut S>,
+) -> impl Future<Output = Option<Option<S::Item>>> + 'a {
+    std::future::poll_fn(move |cx| {
+        use std::task::Poll;
+
+        {
+            let mut state = state.lock().unwrap();
+            if state.cancelled {
+                return Poll::Ready(None);
+            }
+            state.waker = Some(cx.waker().clone());
+            if state.demand == 0 {
+                return Poll::Pending;
+            }
+        }
+
+        let item = std::task::ready!(stream.as_mut().poll_next(cx));
+
+        let mut state = state.lock().unwrap();
+        if state.cancelled {
+            return Poll::Ready(None);
+        }
+        if item.is_some() && state.demand != usize::MAX {
+            state.demand -= 1;
+        }
+        Poll::Ready(Some(item))
+    })
+}
+
+impl StreamSubscription {
+    fn wake(&self, update: impl FnOnce(&mut SubscriptionState)) {
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            update(&mut state);
+            state.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+// Private, since these are only called by the generated Swift code.
+mod ffi {
+    use super::{RustStream, StreamSubscription, Subscriber, SubscriptionState};
+    use std::ffi::c_void;
+    use std::sync::{Arc, Mutex};
+
+    /// Start polling the stream on behalf of the Swift `subscriber`, which gets freed once the
+    /// stream is done with it.
+    #[export_name = "__swift_bridge__$RustStream$subscribe"]
+    #[allow(non_snake_case)]
+    pub extern "C" fn __swift_bridge__RustStream_subscribe(
+        stream: *mut RustStream,
+        subscriber: *mut c_void,
+        free_subscriber: extern "C" fn(*mut c_void),
+    ) -> *mut StreamSubscription {
+        let stream = unsafe { Box::from_raw(stream) };
+        let state = Arc::new(Mutex::new(SubscriptionState {
+            demand: 0,
+            cancelled: false,
+            waker: None,
+        }));
+
+        let subscriber = Subscriber {
+            ptr: subscriber,
+            free: free_subscriber,
+        };
+        let task = (stream.into_task)(subscriber, state.clone());
+        (stream.spawn)(task);
+
+        Box::into_raw(Box::new(StreamSubscription { state }))
+    }
+
+    /// Free a stream that Swift never subscribed to.
+    #[export_name = "__swift_bridge__$RustStream$free"]
+    #[allow(non_snake_case)]
+    pub extern "C" fn __swift_bridge__RustStream_free(stream: *mut RustStream) {
+        drop(unsafe { Box::from_raw(stream) });
+    }
+
+    /// Let the stream produce `demand` more items.
+    #[export_name = "__swift_bridge__$StreamSubscription$request"]
+    #[allow(non_snake_case)]
+    pub extern "C" fn __swift_bridge__StreamSubscription_request(
+        subscription: *const StreamSubscription,
+        demand: usize,
+    ) {
+        let subscription = unsafe { &*subscription };
+        subscription.wake(|state| state.demand = state.demand.saturating_add(demand));
+    }
+
+    /// Stop polling the stream, which drops it, and free the subscription.
+    #[export_name = "__swift_bridge__$StreamSubscription$cancel"]
+    #[allow(non_snake_case)]
+    pub extern "C" fn __swift_bridge__StreamSubscription_cancel(
+        subscription: *mut StreamSubscription,
+    ) {
+        let subscription = unsafe { Box::from_raw(subscription) };
+        subscription.wake(|state| state.cancelled = true);
+    }
+}
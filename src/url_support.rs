@@ -0,0 +1,26 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/url_support.rs
+//!
+//! A `url::Url` is passed over FFI as its serialization, which is already percent-encoded, and is
+//! seen on the Swift side as a `URL`.
+
+use crate::string::RustString;
+use url::Url;
+
+/// Box the `Url`'s serialization so that Swift can create a `URL` out of it.
+pub fn box_into_raw(url: Url) -> *mut RustString {
+    RustString(url.into()).box_into_raw()
+}
+
+/// Parse the `absoluteString` of a Swift `URL`.
+///
+/// # Safety
+///
+/// `string` must be a pointer to a boxed `RustString`, which this takes ownership of.
+pub unsafe fn from_raw(string: *mut RustString) -> Url {
+    let string = Box::from_raw(string).0;
+    match Url::parse(&string) {
+        Ok(url) => url,
+        Err(err) => panic!("Swift passed a URL that is not a valid url::Url ({err}): {string}"),
+    }
+}
@@ -0,0 +1,26 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/uuid_support.rs
+
+use uuid::Uuid;
+
+/// A `uuid::Uuid`, which is seen on the Swift side as a `UUID`.
+///
+/// The bytes are in the same big endian order as `Uuid::as_bytes` and Swift's `UUID.uuid`.
+#[repr(C)]
+pub struct FfiUuid {
+    pub bytes: [u8; 16],
+}
+
+impl From<Uuid> for FfiUuid {
+    fn from(uuid: Uuid) -> Self {
+        FfiUuid {
+            bytes: *uuid.as_bytes(),
+        }
+    }
+}
+
+impl From<FfiUuid> for Uuid {
+    fn from(uuid: FfiUuid) -> Self {
+        Uuid::from_bytes(uuid.bytes)
+    }
+}